@@ -29,15 +29,26 @@ use crate::{
     },
 };
 use alloc::vec::Vec;
-use core::{fmt::Debug, hash::Hash};
+use core::{fmt::Debug, hash::Hash, time::Duration};
 use manta_crypto::{
     arkworks::serialize::{
         CanonicalDeserialize, CanonicalSerialize, Read, SerializationError, Write,
     },
+    constraint::measure::Size,
     rand::{CryptoRng, RngCore},
 };
 use manta_util::{create_seal, seal};
 
+#[cfg(feature = "std")]
+use {
+    crate::transfer::{
+        utxo::{auth::DeriveContext, DeriveAddress, DeriveDecryptionKey},
+        SpendingKey, UtxoAccumulatorItem, UtxoAccumulatorModel,
+    },
+    manta_crypto::{accumulator::Accumulator, constraint::measure::Measure},
+    std::time::Instant,
+};
+
 #[cfg(feature = "serde")]
 use manta_util::serde::{Deserialize, Serialize};
 
@@ -411,6 +422,16 @@ where
         }
     }
 
+    /// Returns the [`Asset`] being transfered in `self`.
+    #[inline]
+    pub fn asset(&self) -> &Asset<C> {
+        match self {
+            Self::ToPrivate(asset) => asset,
+            Self::PrivateTransfer(asset, _) => asset,
+            Self::ToPublic(asset, _) => asset,
+        }
+    }
+
     /// Returns the amount of value being transfered in `self`.
     #[inline]
     pub fn value(&self) -> &C::AssetValue {
@@ -587,6 +608,251 @@ where
     }
 }
 
+impl<C> MultiProvingContext<C>
+where
+    C: Configuration,
+{
+    /// Estimates the cost of proving each transfer shape on the current machine by running a
+    /// calibration round, which proves one transfer of each shape over zero-valued assets of
+    /// `asset_id` owned by `spending_key`.
+    ///
+    /// The size of each post is measured with `post_size`, so that it reflects the encoding used
+    /// to submit posts to the ledger. The calibration transfers spend UTXOs inserted into
+    /// `utxo_accumulator`, which should be a throwaway accumulator and not the one tracking the
+    /// ledger. The resulting posts are discarded and never leave this function.
+    #[cfg(feature = "std")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn estimate<A, F, R>(
+        &self,
+        parameters: &Parameters<C>,
+        utxo_accumulator: &mut A,
+        spending_key: &SpendingKey<C>,
+        asset_id: C::AssetId,
+        mut post_size: F,
+        rng: &mut R,
+    ) -> Result<ProofEstimates, ProofSystemError<C>>
+    where
+        A: Accumulator<Item = UtxoAccumulatorItem<C>, Model = UtxoAccumulatorModel<C>>,
+        C::AccountId: Default,
+        C::Compiler: Measure,
+        F: FnMut(&TransferPost<C>) -> usize,
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        let mut authorization_context = parameters.derive_context(spending_key);
+        let address = parameters
+            .derive_address(&parameters.derive_decryption_key(&mut authorization_context));
+        let asset = Asset::<C>::zero(asset_id);
+        let mut to_private = None;
+        let mut senders = Vec::with_capacity(2 * PrivateTransferShape::SENDERS);
+        for _ in 0..2 * PrivateTransferShape::SENDERS {
+            let (transfer, pre_sender) = ToPrivate::internal_pair(
+                parameters,
+                &mut authorization_context,
+                address.clone(),
+                asset.clone(),
+                Default::default(),
+                rng,
+            );
+            to_private.get_or_insert(transfer);
+            senders.push(
+                pre_sender
+                    .insert_and_upgrade(parameters, utxo_accumulator)
+                    .expect("Inserting into a throwaway accumulator is not allowed to fail."),
+            );
+        }
+        let mut receiver = || {
+            Receiver::<C>::sample(
+                parameters,
+                address.clone(),
+                asset.clone(),
+                Default::default(),
+                rng,
+            )
+        };
+        let private_transfer_receivers = [receiver(), receiver()];
+        let to_public_receivers = [receiver()];
+        let mut senders = senders.into_iter();
+        let mut next_senders = || {
+            [senders.next(), senders.next()]
+                .map(|sender| sender.expect("Exactly enough senders were generated above."))
+        };
+        let private_transfer_senders = next_senders();
+        let to_public_senders = next_senders();
+        let parameters_ref = FullParametersRef::<C>::new(parameters, utxo_accumulator.model());
+        Ok(ProofEstimates {
+            to_private: ShapeEstimate::measure(
+                parameters_ref,
+                &self.to_private,
+                to_private.expect("At least one transfer was generated above."),
+                None,
+                Vec::new(),
+                &mut post_size,
+                rng,
+            )?,
+            private_transfer: ShapeEstimate::measure(
+                parameters_ref,
+                &self.private_transfer,
+                PrivateTransfer::build(
+                    Authorization::<C>::from_spending_key(parameters, spending_key, rng),
+                    private_transfer_senders,
+                    private_transfer_receivers,
+                ),
+                Some(spending_key),
+                Vec::new(),
+                &mut post_size,
+                rng,
+            )?,
+            to_public: ShapeEstimate::measure(
+                parameters_ref,
+                &self.to_public,
+                ToPublic::build(
+                    Authorization::<C>::from_spending_key(parameters, spending_key, rng),
+                    to_public_senders,
+                    to_public_receivers,
+                    asset,
+                ),
+                Some(spending_key),
+                Vec::from([Default::default()]),
+                &mut post_size,
+                rng,
+            )?,
+        })
+    }
+}
+
+/// Transfer Shape Estimate
+///
+/// Cost of proving and posting a transfer of a given shape, measured on the current machine.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ShapeEstimate {
+    /// Transfer Shape
+    pub shape: TransferShape,
+
+    /// Constraint System Size
+    pub constraints: Size,
+
+    /// Proving Time
+    pub proving_time: Duration,
+
+    /// Size of the Serialized [`TransferPost`] in Bytes
+    pub post_size: usize,
+}
+
+impl ShapeEstimate {
+    /// Measures the constraint system size of `transfer` and the time it takes to turn it into
+    /// a [`TransferPost`], together with the size of that post according to `post_size`.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn measure<
+        C,
+        F,
+        R,
+        const SOURCES: usize,
+        const SENDERS: usize,
+        const RECEIVERS: usize,
+        const SINKS: usize,
+    >(
+        parameters: FullParametersRef<C>,
+        proving_context: &ProvingContext<C>,
+        transfer: Transfer<C, SOURCES, SENDERS, RECEIVERS, SINKS>,
+        spending_key: Option<&SpendingKey<C>>,
+        sink_accounts: Vec<C::AccountId>,
+        post_size: &mut F,
+        rng: &mut R,
+    ) -> Result<Self, ProofSystemError<C>>
+    where
+        C: Configuration,
+        C::Compiler: Measure,
+        F: FnMut(&TransferPost<C>) -> usize,
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        let constraints =
+            Transfer::<C, SOURCES, SENDERS, RECEIVERS, SINKS>::unknown_constraints(parameters)
+                .measure();
+        let start = Instant::now();
+        let post = transfer
+            .into_post(
+                parameters,
+                proving_context,
+                spending_key,
+                sink_accounts,
+                rng,
+            )?
+            .expect("Calibration transfers are constructed with the correct authorization.");
+        Ok(Self {
+            shape: TransferShape::from_post(&post)
+                .expect("Calibration transfers have canonical shapes."),
+            constraints,
+            proving_time: start.elapsed(),
+            post_size: post_size(&post),
+        })
+    }
+}
+
+/// Proof Estimates
+///
+/// [`ShapeEstimate`]s for each of the canonical transfer shapes, which wallets can use to warn
+/// users before starting a slow transaction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ProofEstimates {
+    /// [`ToPrivate`] Estimate
+    pub to_private: ShapeEstimate,
+
+    /// [`PrivateTransfer`] Estimate
+    pub private_transfer: ShapeEstimate,
+
+    /// [`ToPublic`] Estimate
+    pub to_public: ShapeEstimate,
+}
+
+impl ProofEstimates {
+    /// Selects a [`ShapeEstimate`] based on `shape`.
+    #[inline]
+    pub fn select(&self, shape: TransferShape) -> &ShapeEstimate {
+        match shape {
+            TransferShape::ToPrivate => &self.to_private,
+            TransferShape::PrivateTransfer => &self.private_transfer,
+            TransferShape::ToPublic => &self.to_public,
+        }
+    }
+
+    /// Returns the estimated time to prove a transfer of each shape in `shapes`, one after the
+    /// other.
+    #[inline]
+    pub fn proving_time<I>(&self, shapes: I) -> Duration
+    where
+        I: IntoIterator<Item = TransferShape>,
+    {
+        shapes
+            .into_iter()
+            .map(|shape| self.select(shape).proving_time)
+            .sum()
+    }
+
+    /// Returns the estimated total size in bytes of the posts for each shape in `shapes`.
+    #[inline]
+    pub fn post_size<I>(&self, shapes: I) -> usize
+    where
+        I: IntoIterator<Item = TransferShape>,
+    {
+        shapes
+            .into_iter()
+            .map(|shape| self.select(shape).post_size)
+            .sum()
+    }
+}
+
 /// Canonical Multi-Verifying Contexts
 #[cfg_attr(
     feature = "serde",
@@ -0,0 +1,435 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Transfer Public Input Builder
+//!
+//! The public input of a transfer proof is a flat vector whose layout is fixed by the circuit: the
+//! authorization key, the visible asset id, the sources, the sender posts, the receiver posts, the
+//! sinks and the withdrawal tag, in that order. Extending a [`ProofInput`] by hand is order-dependent and a mistake
+//! only shows up as an opaque verification failure. The [`ProofInputBuilder`] collects each
+//! component by name and checks them against the [`InputLayout`] of the circuit before assembling
+//! the input in the canonical order.
+
+use crate::transfer::{
+    canonical::{PrivateTransferShape, Shape, ToPrivateShape, ToPublicShape, TransferShape},
+    has_public_participants, requires_authorization, AssetId, AssetValue, AuthorizationKey,
+    CircuitVersion, Configuration, Note, Nullifier, ProofInput, TransferPost, Utxo,
+    UtxoAccumulatorOutput,
+};
+use manta_crypto::constraint::HasInput;
+use manta_util::vec::Vec;
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Public Input Layout
+///
+/// Describes which components appear in the public input of a transfer circuit and how many of
+/// each it expects.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct InputLayout {
+    /// Authorization Key Flag
+    pub has_authorization: bool,
+
+    /// Visible Asset Id Flag
+    pub has_visible_asset_id: bool,
+
+    /// Number of Sources
+    pub sources: usize,
+
+    /// Number of Senders
+    pub senders: usize,
+
+    /// Number of Receivers
+    pub receivers: usize,
+
+    /// Number of Sinks
+    pub sinks: usize,
+
+    /// Withdrawal Tag Flag
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub has_tag: bool,
+}
+
+impl InputLayout {
+    /// Builds the [`InputLayout`] of a transfer with the given number of participants.
+    #[inline]
+    pub const fn new(sources: usize, senders: usize, receivers: usize, sinks: usize) -> Self {
+        Self {
+            has_authorization: requires_authorization(senders),
+            has_visible_asset_id: has_public_participants(sources, sinks),
+            sources,
+            senders,
+            receivers,
+            sinks,
+            has_tag: false,
+        }
+    }
+
+    /// Returns the [`InputLayout`] of the tagged variant of the circuit of `self`, which takes a
+    /// withdrawal tag as its last public input.
+    #[inline]
+    pub const fn tagged(mut self) -> Self {
+        self.has_tag = true;
+        self
+    }
+
+    /// Builds the [`InputLayout`] of the canonical shape `S`.
+    #[inline]
+    pub const fn from_shape<S>() -> Self
+    where
+        S: Shape,
+    {
+        Self::new(S::SOURCES, S::SENDERS, S::RECEIVERS, S::SINKS)
+    }
+
+    /// Builds the [`InputLayout`] of the canonical transfer `shape`.
+    #[inline]
+    pub const fn from_transfer_shape(shape: TransferShape) -> Self {
+        match shape {
+            TransferShape::ToPrivate => Self::from_shape::<ToPrivateShape>(),
+            TransferShape::PrivateTransfer => Self::from_shape::<PrivateTransferShape>(),
+            TransferShape::ToPublic => Self::from_shape::<ToPublicShape>(),
+        }
+    }
+
+    /// Builds the [`InputLayout`] that the proof of `post` is expected to have been generated
+    /// against.
+    #[inline]
+    pub fn from_post<C>(post: &TransferPost<C>) -> Self
+    where
+        C: Configuration + ?Sized,
+    {
+        Self {
+            has_authorization: post.authorization_signature.is_some(),
+            has_visible_asset_id: post.body.asset_id.is_some(),
+            sources: post.body.sources.len(),
+            senders: post.body.sender_posts.len(),
+            receivers: post.body.receiver_posts.len(),
+            sinks: post.body.sinks.len(),
+            has_tag: post.body.tag.is_some(),
+        }
+    }
+}
+
+impl From<TransferShape> for InputLayout {
+    #[inline]
+    fn from(shape: TransferShape) -> Self {
+        Self::from_transfer_shape(shape)
+    }
+}
+
+/// Public Input Component
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum InputComponent {
+    /// Authorization Key
+    AuthorizationKey,
+
+    /// Visible Asset Id
+    AssetId,
+
+    /// Sources
+    Sources,
+
+    /// Senders
+    Senders,
+
+    /// Receivers
+    Receivers,
+
+    /// Sinks
+    Sinks,
+
+    /// Withdrawal Tag
+    Tag,
+}
+
+/// Public Input Layout Error
+///
+/// This error is returned by [`ProofInputBuilder::build`] when the components that were supplied
+/// do not match the [`InputLayout`] of the circuit.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InputLayoutError {
+    /// Circuit Version
+    pub version: CircuitVersion,
+
+    /// Mismatched Component
+    pub component: InputComponent,
+
+    /// Number of Entries Expected by the Layout
+    pub expected: usize,
+
+    /// Number of Entries Supplied to the Builder
+    pub found: usize,
+}
+
+/// Public Input Builder
+///
+/// Collects the named components of the public input of a transfer proof, in any order, and
+/// assembles them into a [`ProofInput`] with [`build`](Self::build).
+pub struct ProofInputBuilder<'i, C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Circuit Version
+    version: CircuitVersion,
+
+    /// Expected Layout
+    layout: InputLayout,
+
+    /// Authorization Key
+    authorization_key: Option<&'i AuthorizationKey<C>>,
+
+    /// Visible Asset Id
+    asset_id: Option<&'i AssetId<C>>,
+
+    /// Sources
+    sources: Vec<&'i AssetValue<C>>,
+
+    /// Senders
+    senders: Vec<(&'i UtxoAccumulatorOutput<C>, &'i Nullifier<C>)>,
+
+    /// Receivers
+    receivers: Vec<(&'i Utxo<C>, &'i Note<C>)>,
+
+    /// Sinks
+    sinks: Vec<&'i AssetValue<C>>,
+
+    /// Withdrawal Tag
+    tag: Option<&'i C::WithdrawalTag>,
+}
+
+impl<'i, C> ProofInputBuilder<'i, C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Builds a new [`ProofInputBuilder`] for the circuit of `version` with the given `layout`.
+    #[inline]
+    pub fn new(version: CircuitVersion, layout: InputLayout) -> Self {
+        Self {
+            version,
+            layout,
+            authorization_key: None,
+            asset_id: None,
+            sources: Vec::with_capacity(layout.sources),
+            senders: Vec::with_capacity(layout.senders),
+            receivers: Vec::with_capacity(layout.receivers),
+            sinks: Vec::with_capacity(layout.sinks),
+            tag: None,
+        }
+    }
+
+    /// Builds a new [`ProofInputBuilder`] with every component of `post`, checked against the
+    /// layout of the canonical shape `shape`, or of its tagged variant if `post` has a withdrawal
+    /// tag.
+    #[inline]
+    pub fn from_post(post: &'i TransferPost<C>, shape: TransferShape) -> Self {
+        let layout = InputLayout::from_transfer_shape(shape);
+        let mut builder = Self::new(
+            post.version,
+            if post.body.tag.is_some() {
+                layout.tagged()
+            } else {
+                layout
+            },
+        );
+        if let Some(signature) = &post.authorization_signature {
+            builder = builder.authorization_key(&signature.authorization_key);
+        }
+        if let Some(asset_id) = &post.body.asset_id {
+            builder = builder.asset_id(asset_id);
+        }
+        for source in &post.body.sources {
+            builder = builder.source(source);
+        }
+        for sender in &post.body.sender_posts {
+            builder = builder.sender(&sender.utxo_accumulator_output, &sender.nullifier);
+        }
+        for receiver in &post.body.receiver_posts {
+            builder = builder.receiver(&receiver.utxo, &receiver.note);
+        }
+        for sink in &post.body.sinks {
+            builder = builder.sink(sink);
+        }
+        if let Some(tag) = &post.body.tag {
+            builder = builder.tag(tag);
+        }
+        builder
+    }
+
+    /// Returns the circuit version that `self` builds inputs for.
+    #[inline]
+    pub fn version(&self) -> CircuitVersion {
+        self.version
+    }
+
+    /// Returns the layout that `self` checks the components against.
+    #[inline]
+    pub fn layout(&self) -> InputLayout {
+        self.layout
+    }
+
+    /// Sets the authorization key of the transfer.
+    #[inline]
+    pub fn authorization_key(mut self, authorization_key: &'i AuthorizationKey<C>) -> Self {
+        self.authorization_key = Some(authorization_key);
+        self
+    }
+
+    /// Sets the visible asset id of the transfer.
+    #[inline]
+    pub fn asset_id(mut self, asset_id: &'i AssetId<C>) -> Self {
+        self.asset_id = Some(asset_id);
+        self
+    }
+
+    /// Appends the value withdrawn from the next source account.
+    #[inline]
+    pub fn source(mut self, value: &'i AssetValue<C>) -> Self {
+        self.sources.push(value);
+        self
+    }
+
+    /// Appends the next sender, given by the accumulator root its UTXO was proven against and the
+    /// nullifier it reveals.
+    #[inline]
+    pub fn sender(
+        mut self,
+        accumulator_root: &'i UtxoAccumulatorOutput<C>,
+        nullifier: &'i Nullifier<C>,
+    ) -> Self {
+        self.senders.push((accumulator_root, nullifier));
+        self
+    }
+
+    /// Appends the next receiver, given by the UTXO it creates and its encrypted note.
+    #[inline]
+    pub fn receiver(mut self, utxo: &'i Utxo<C>, note: &'i Note<C>) -> Self {
+        self.receivers.push((utxo, note));
+        self
+    }
+
+    /// Appends the value deposited into the next sink account.
+    #[inline]
+    pub fn sink(mut self, value: &'i AssetValue<C>) -> Self {
+        self.sinks.push(value);
+        self
+    }
+
+    /// Sets the withdrawal tag of the transfer.
+    #[inline]
+    pub fn tag(mut self, tag: &'i C::WithdrawalTag) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Checks the supplied components against the layout of `self`, returning the first mismatch.
+    #[inline]
+    pub fn check(&self) -> Result<(), InputLayoutError> {
+        let counts = [
+            (
+                InputComponent::AuthorizationKey,
+                self.layout.has_authorization as usize,
+                self.authorization_key.is_some() as usize,
+            ),
+            (
+                InputComponent::AssetId,
+                self.layout.has_visible_asset_id as usize,
+                self.asset_id.is_some() as usize,
+            ),
+            (
+                InputComponent::Sources,
+                self.layout.sources,
+                self.sources.len(),
+            ),
+            (
+                InputComponent::Senders,
+                self.layout.senders,
+                self.senders.len(),
+            ),
+            (
+                InputComponent::Receivers,
+                self.layout.receivers,
+                self.receivers.len(),
+            ),
+            (InputComponent::Sinks, self.layout.sinks, self.sinks.len()),
+            (
+                InputComponent::Tag,
+                self.layout.has_tag as usize,
+                self.tag.is_some() as usize,
+            ),
+        ];
+        match counts
+            .into_iter()
+            .find(|(_, expected, found)| expected != found)
+        {
+            Some((component, expected, found)) => Err(InputLayoutError {
+                version: self.version,
+                component,
+                expected,
+                found,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Assembles the public input in the canonical order after checking the supplied components
+    /// against the layout of `self`.
+    #[inline]
+    pub fn build(self) -> Result<ProofInput<C>, InputLayoutError> {
+        self.check()?;
+        let mut input = Default::default();
+        if let Some(authorization_key) = self.authorization_key {
+            C::ProofSystem::extend(&mut input, authorization_key);
+        }
+        if let Some(asset_id) = self.asset_id {
+            C::ProofSystem::extend(&mut input, asset_id);
+        }
+        for source in self.sources {
+            C::ProofSystem::extend(&mut input, source);
+        }
+        for (accumulator_root, nullifier) in self.senders {
+            C::ProofSystem::extend(&mut input, accumulator_root);
+            C::ProofSystem::extend(&mut input, nullifier);
+        }
+        for (utxo, note) in self.receivers {
+            C::ProofSystem::extend(&mut input, utxo);
+            C::ProofSystem::extend(&mut input, note);
+        }
+        for sink in self.sinks {
+            C::ProofSystem::extend(&mut input, sink);
+        }
+        if let Some(tag) = self.tag {
+            C::ProofSystem::extend(&mut input, tag);
+        }
+        Ok(input)
+    }
+}
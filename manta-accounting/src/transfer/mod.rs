@@ -24,6 +24,7 @@
 //! - Receiver Abstraction: [`Receiver`], [`ReceiverPost`], [`ReceiverLedger`]
 //! - Transfer Abstraction: [`Transfer`], [`TransferPost`], [`TransferLedger`]
 //! - Canonical Transactions: [`canonical`]
+//! - Public Input Builder: [`input`]
 //! - Batched Transactions: [`batch`]
 //!
 //! See the [`crate::wallet`] module for more on how this transfer protocol is used in a wallet
@@ -65,6 +66,7 @@ use manta_util::serde::{Deserialize, Serialize};
 
 pub mod batch;
 pub mod canonical;
+pub mod input;
 pub mod receiver;
 pub mod sender;
 pub mod utxo;
@@ -114,6 +116,12 @@ pub trait Configuration {
     /// Account Identifier
     type AccountId: Clone;
 
+    /// Withdrawal Tag Type
+    ///
+    /// Public reference which can be attached to transfers with sinks, see
+    /// [`Transfer::with_tag`].
+    type WithdrawalTag: Clone;
+
     /// Associated Data Type
     type AssociatedData: Default;
 
@@ -169,7 +177,8 @@ pub trait Configuration {
             Secret = Self::SpendSecret,
             Nullifier = Self::Nullifier,
             Identifier = Self::Identifier,
-        > + utxo::NullifierOpen
+        > + utxo::NoteDetect
+        + utxo::NullifierOpen
         + utxo::UtxoReconstruct;
 
     /// Authorization Context Variable Type
@@ -193,6 +202,9 @@ pub trait Configuration {
         + Add<Self::AssetValueVar, Self::Compiler, Output = Self::AssetValueVar>
         + eclair::cmp::PartialEq<Self::AssetValueVar, Self::Compiler>;
 
+    /// Withdrawal Tag Variable Type
+    type WithdrawalTagVar: Variable<Public, Self::Compiler, Type = Self::WithdrawalTag>;
+
     /// Unspent Transaction Output Variable Type
     type UtxoVar: Variable<Secret, Self::Compiler, Type = Self::Utxo>
         + Variable<Public, Self::Compiler, Type = Self::Utxo>;
@@ -259,7 +271,8 @@ pub trait Configuration {
         + HasInput<UtxoAccumulatorOutput<Self>>
         + HasInput<Utxo<Self>>
         + HasInput<Note<Self>>
-        + HasInput<Nullifier<Self>>;
+        + HasInput<Nullifier<Self>>
+        + HasInput<Self::WithdrawalTag>;
 }
 
 /// Compiler Type
@@ -380,6 +393,12 @@ pub type Note<C> = utxo::Note<Parameters<C>>;
 /// Nullifier Type
 pub type Nullifier<C> = utxo::Nullifier<Parameters<C>>;
 
+/// Detection Key Type
+pub type DetectionKey<C> = <Parameters<C> as utxo::NoteDetect>::DetectionKey;
+
+/// Note Filter Type
+pub type NoteFilter<C> = <Parameters<C> as utxo::NoteDetect>::NoteFilter;
+
 /// Identifier Type
 pub type Identifier<C> = utxo::Identifier<Parameters<C>>;
 
@@ -464,36 +483,42 @@ where
         Authorization<C>: Clone,
         C::AssetId: Clone,
         C::AssetValue: Clone,
+        C::WithdrawalTag: Clone,
         Sender<C>: Clone,
         Receiver<C>: Clone"),
     Copy(bound = r"
         Authorization<C>: Copy,
         C::AssetId: Copy,
         C::AssetValue: Copy,
+        C::WithdrawalTag: Copy,
         Sender<C>: Copy,
         Receiver<C>: Copy"),
     Debug(bound = r"
         Authorization<C>: Debug,
         C::AssetId: Debug,
         C::AssetValue: Debug,
+        C::WithdrawalTag: Debug,
         Sender<C>: Debug,
         Receiver<C>: Debug"),
     Eq(bound = r"
         Authorization<C>: Eq,
         C::AssetId: Eq,
         C::AssetValue: Eq,
+        C::WithdrawalTag: Eq,
         Sender<C>: Eq,
         Receiver<C>: Eq"),
     Hash(bound = r"
         Authorization<C>: Hash,
         C::AssetId: Hash,
         C::AssetValue: Hash,
+        C::WithdrawalTag: Hash,
         Sender<C>: Hash,
         Receiver<C>: Hash"),
     PartialEq(bound = r"
         Authorization<C>: PartialEq,
         C::AssetId: PartialEq,
         C::AssetValue: PartialEq,
+        C::WithdrawalTag: PartialEq,
         Sender<C>: PartialEq,
         Receiver<C>: PartialEq")
 )]
@@ -523,6 +548,9 @@ pub struct Transfer<
 
     /// Sinks
     sinks: [C::AssetValue; SINKS],
+
+    /// Withdrawal Tag
+    tag: Option<C::WithdrawalTag>,
 }
 
 impl<C, const SOURCES: usize, const SENDERS: usize, const RECEIVERS: usize, const SINKS: usize>
@@ -620,9 +648,39 @@ where
             senders,
             receivers,
             sinks,
+            tag: None,
         }
     }
 
+    /// Attaches the public withdrawal `tag` to `self`.
+    ///
+    /// The tag is an extra public input of the validity proof, so it can't be changed in the
+    /// resulting [`TransferPost`] without invalidating its proof. Tagged transfers are proven
+    /// against their own circuit, see [`generate_tagged_context`](Self::generate_tagged_context).
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the transfer has no sinks.
+    #[inline]
+    pub fn with_tag(mut self, tag: C::WithdrawalTag) -> Self {
+        assert!(has_sinks(SINKS), "Only transfers with sinks can be tagged.");
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Returns the withdrawal tag of `self`, if it has one.
+    #[inline]
+    pub fn tag(&self) -> Option<&C::WithdrawalTag> {
+        self.tag.as_ref()
+    }
+
+    /// Returns a mutable reference to the senders of `self`, for instance to refresh their
+    /// membership proofs before proving.
+    #[inline]
+    pub fn senders_mut(&mut self) -> &mut [Sender<C>; SENDERS] {
+        &mut self.senders
+    }
+
     /// Constructs an [`Asset`] against the `asset_id` of `self` and `value`.
     #[inline]
     fn construct_asset(&self, value: &C::AssetValue) -> Option<Asset<C>> {
@@ -662,6 +720,23 @@ where
         compiler
     }
 
+    /// Builds a constraint system which asserts constraints against unknown variables for a
+    /// transfer with a withdrawal tag.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the transfer has no sinks.
+    #[inline]
+    pub fn tagged_unknown_constraints(parameters: FullParametersRef<C>) -> C::Compiler {
+        assert!(has_sinks(SINKS), "Only transfers with sinks can be tagged.");
+        let mut compiler = C::ProofSystem::context_compiler();
+        let mut transfer =
+            TransferVar::<C, SOURCES, SENDERS, RECEIVERS, SINKS>::new_unknown(&mut compiler);
+        transfer.tag = Some(compiler.allocate_unknown::<Public, _>());
+        transfer.build_validity_constraints(&parameters.as_constant(&mut compiler), &mut compiler);
+        compiler
+    }
+
     /// Builds a constraint system which asserts constraints against known variables.
     #[inline]
     pub fn known_constraints(&self, parameters: FullParametersRef<C>) -> C::Compiler {
@@ -689,6 +764,27 @@ where
         )
     }
 
+    /// Generates a proving and verifying context for this transfer shape with a withdrawal tag.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the transfer has no sinks.
+    #[inline]
+    pub fn generate_tagged_context<R>(
+        public_parameters: &ProofSystemPublicParameters<C>,
+        parameters: FullParametersRef<C>,
+        rng: &mut R,
+    ) -> Result<(ProvingContext<C>, VerifyingContext<C>), ProofSystemError<C>>
+    where
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        C::ProofSystem::compile(
+            public_parameters,
+            Self::tagged_unknown_constraints(parameters),
+            rng,
+        )
+    }
+
     /// Converts `self` into its [`TransferPostBody`] by building the [`Transfer`] validity proof.
     #[allow(clippy::type_complexity)] // FIXME: Use a better abstraction here.
     #[inline]
@@ -709,6 +805,7 @@ where
                 self.senders,
                 self.receivers,
                 self.sinks,
+                self.tag,
             ),
             self.authorization,
         ))
@@ -787,6 +884,39 @@ where
             _ => Ok(None),
         }
     }
+
+    /// Converts `self` into an [`UnsignedTransferPost`] by building the [`Transfer`] validity
+    /// proof, leaving the authorization signature to be produced by the holder of the spending
+    /// key.
+    ///
+    /// Returns `Ok(None)` when `self` does not require or does not carry an authorization. Returns
+    /// `Err` when proof generation fails.
+    #[inline]
+    pub fn into_unsigned_post<R>(
+        self,
+        parameters: FullParametersRef<C>,
+        proving_context: &ProvingContext<C>,
+        sink_accounts: Vec<C::AccountId>,
+        rng: &mut R,
+    ) -> Result<Option<UnsignedTransferPost<C>>, ProofSystemError<C>>
+    where
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        if !requires_authorization(SENDERS) || self.authorization.is_none() {
+            return Ok(None);
+        }
+        let (body, authorization) =
+            self.into_post_body_with_authorization(parameters, proving_context, rng)?;
+        Ok(Some(UnsignedTransferPost::new_unchecked(
+            authorization.expect("It is known to be `Some` from the check above."),
+            body,
+            if has_sinks(SINKS) {
+                sink_accounts
+            } else {
+                Vec::new()
+            },
+        )))
+    }
 }
 
 impl<C, const SOURCES: usize, const SENDERS: usize, const RECEIVERS: usize, const SINKS: usize>
@@ -814,6 +944,9 @@ where
         self.sinks
             .iter()
             .for_each(|sink| C::ProofSystem::extend(input, sink));
+        if let Some(tag) = &self.tag {
+            C::ProofSystem::extend(input, tag);
+        }
     }
 }
 
@@ -824,30 +957,35 @@ where
             AuthorizationVar<C>: Clone,
             C::AssetIdVar: Clone,
             C::AssetValueVar: Clone,
+            C::WithdrawalTagVar: Clone,
             SenderVar<C>: Clone,
             ReceiverVar<C>: Clone"),
     Debug(bound = r"
             AuthorizationVar<C>: Debug,
             C::AssetIdVar: Debug,
             C::AssetValueVar: Debug,
+            C::WithdrawalTagVar: Debug,
             SenderVar<C>: Debug,
             ReceiverVar<C>: Debug"),
     Eq(bound = r"
             AuthorizationVar<C>: Eq,
             C::AssetIdVar: Eq,
             C::AssetValueVar: Eq,
+            C::WithdrawalTagVar: Eq,
             SenderVar<C>: Eq,
             ReceiverVar<C>: Eq"),
     Hash(bound = r"
             AuthorizationVar<C>: Hash,
             C::AssetIdVar: Hash,
             C::AssetValueVar: Hash,
+            C::WithdrawalTagVar: Hash,
             SenderVar<C>: Hash,
             ReceiverVar<C>: Hash"),
     PartialEq(bound = r"
             AuthorizationVar<C>: PartialEq,
             C::AssetIdVar: PartialEq,
             C::AssetValueVar: PartialEq,
+            C::WithdrawalTagVar: PartialEq,
             SenderVar<C>: PartialEq,
             ReceiverVar<C>: PartialEq")
 )]
@@ -877,6 +1015,9 @@ struct TransferVar<
 
     /// Sinks
     sinks: Vec<C::AssetValueVar>,
+
+    /// Withdrawal Tag
+    tag: Option<C::WithdrawalTagVar>,
 }
 
 impl<C, const SOURCES: usize, const SENDERS: usize, const RECEIVERS: usize, const SINKS: usize>
@@ -908,6 +1049,9 @@ where
             compiler,
         );
         compiler.assert_eq(&input_sum, &output_sum);
+        // NOTE: The withdrawal tag takes part in no constraint. Allocating it as a public input is
+        //       enough to bind it to the proof, since the proof system commits to every public
+        //       input, used or not.
         match self.asset_id {
             Some(asset_id) => compiler.assert_all_eq_to_base(&asset_id, secret_asset_ids.iter()),
             _ => compiler.assert_all_eq(secret_asset_ids.iter()),
@@ -1006,6 +1150,7 @@ where
             sinks: (0..SINKS)
                 .map(|_| compiler.allocate_unknown::<Public, _>())
                 .collect(),
+            tag: None,
         }
     }
 
@@ -1040,6 +1185,10 @@ where
                 .iter()
                 .map(|sink| sink.as_known::<Public, _>(compiler))
                 .collect(),
+            tag: this
+                .tag
+                .as_ref()
+                .map(|tag| tag.as_known::<Public, _>(compiler)),
         }
     }
 }
@@ -1412,6 +1561,7 @@ where
             deserialize = r"
                 C::AssetId: Deserialize<'de>,
                 C::AssetValue: Deserialize<'de>,
+                C::WithdrawalTag: Deserialize<'de>,
                 SenderPost<C>: Deserialize<'de>,
                 ReceiverPost<C>: Deserialize<'de>,
                 Proof<C>: Deserialize<'de>,
@@ -1419,6 +1569,7 @@ where
             serialize = r"
                 C::AssetId: Serialize,
                 C::AssetValue: Serialize,
+                C::WithdrawalTag: Serialize,
                 SenderPost<C>: Serialize,
                 ReceiverPost<C>: Serialize,
                 Proof<C>: Serialize,
@@ -1433,6 +1584,7 @@ where
     Clone(bound = r"
         C::AssetId: Clone,
         C::AssetValue: Clone,
+        C::WithdrawalTag: Clone,
         SenderPost<C>: Clone,
         ReceiverPost<C>: Clone,
         Proof<C>: Clone
@@ -1440,6 +1592,7 @@ where
     Debug(bound = r"
         C::AssetId: Debug,
         C::AssetValue: Debug,
+        C::WithdrawalTag: Debug,
         SenderPost<C>: Debug,
         ReceiverPost<C>: Debug,
         Proof<C>: Debug
@@ -1447,6 +1600,7 @@ where
     Eq(bound = r"
         C::AssetId: Eq,
         C::AssetValue: Eq,
+        C::WithdrawalTag: Eq,
         SenderPost<C>: Eq,
         ReceiverPost<C>: Eq,
         Proof<C>: Eq
@@ -1454,6 +1608,7 @@ where
     Hash(bound = r"
         C::AssetId: Hash,
         C::AssetValue: Hash,
+        C::WithdrawalTag: Hash,
         SenderPost<C>: Hash,
         ReceiverPost<C>: Hash,
         Proof<C>: Hash
@@ -1461,6 +1616,7 @@ where
     PartialEq(bound = r"
         C::AssetId: PartialEq,
         C::AssetValue: PartialEq,
+        C::WithdrawalTag: PartialEq,
         SenderPost<C>: PartialEq,
         ReceiverPost<C>: PartialEq,
         Proof<C>: PartialEq
@@ -1487,6 +1643,15 @@ where
 
     /// Proof
     pub proof: Proof<C>,
+
+    /// Withdrawal Tag
+    ///
+    /// Public reference attached by the sender of a transfer with sinks, bound to [`proof`] as
+    /// its last public input.
+    ///
+    /// [`proof`]: Self::proof
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tag: Option<C::WithdrawalTag>,
 }
 
 impl<C> TransferPostBody<C>
@@ -1507,6 +1672,7 @@ where
         senders: [Sender<C>; SENDERS],
         receivers: [Receiver<C>; RECEIVERS],
         sinks: [C::AssetValue; SINKS],
+        tag: Option<C::WithdrawalTag>,
     ) -> Self {
         Self {
             asset_id,
@@ -1518,6 +1684,7 @@ where
                 .collect(),
             sinks: sinks.into(),
             proof,
+            tag,
         }
     }
 
@@ -1549,6 +1716,7 @@ where
     C: Configuration + ?Sized,
     C::AssetId: Encode,
     C::AssetValue: Encode,
+    C::WithdrawalTag: Encode,
     SenderPost<C>: Encode,
     ReceiverPost<C>: Encode,
     Proof<C>: Encode,
@@ -1564,6 +1732,7 @@ where
         self.receiver_posts.encode(&mut writer)?;
         self.sinks.encode(&mut writer)?;
         self.proof.encode(&mut writer)?;
+        self.tag.encode(&mut writer)?;
         Ok(())
     }
 }
@@ -1589,6 +1758,9 @@ where
         self.sinks
             .iter()
             .for_each(|sink| C::ProofSystem::extend(input, sink));
+        if let Some(tag) = &self.tag {
+            C::ProofSystem::extend(input, tag);
+        }
     }
 }
 
@@ -1635,6 +1807,123 @@ where
     }
 }
 
+/// Unsigned Transfer Post
+///
+/// Proven [`TransferPostBody`] together with the [`Authorization`] it was proven with, waiting
+/// for the [`AuthorizationSignature`] which only the holder of the spending key can produce. This
+/// lets a device without the spending key build and prove transfers which are then signed
+/// elsewhere.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = r"
+                Authorization<C>: Deserialize<'de>,
+                TransferPostBody<C>: Deserialize<'de>,
+                C::AccountId: Deserialize<'de>,
+            ",
+            serialize = r"
+                Authorization<C>: Serialize,
+                TransferPostBody<C>: Serialize,
+                C::AccountId: Serialize
+            ",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "Authorization<C>: Clone, TransferPostBody<C>: Clone, C::AccountId: Clone"),
+    Debug(bound = "Authorization<C>: Debug, TransferPostBody<C>: Debug, C::AccountId: Debug"),
+    Eq(bound = "Authorization<C>: Eq, TransferPostBody<C>: Eq, C::AccountId: Eq"),
+    Hash(bound = "Authorization<C>: Hash, TransferPostBody<C>: Hash, C::AccountId: Hash"),
+    PartialEq(
+        bound = "Authorization<C>: PartialEq, TransferPostBody<C>: PartialEq, C::AccountId: PartialEq"
+    )
+)]
+pub struct UnsignedTransferPost<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Authorization
+    pub authorization: Authorization<C>,
+
+    /// Transfer Post Body
+    pub body: TransferPostBody<C>,
+
+    /// Sink Accounts
+    pub sink_accounts: Vec<C::AccountId>,
+}
+
+impl<C> UnsignedTransferPost<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Builds a new [`UnsignedTransferPost`] without checking the consistency conditions between
+    /// the `authorization`, the `body` and the `sink_accounts`.
+    #[inline]
+    fn new_unchecked(
+        authorization: Authorization<C>,
+        body: TransferPostBody<C>,
+        sink_accounts: Vec<C::AccountId>,
+    ) -> Self {
+        Self {
+            authorization,
+            body,
+            sink_accounts,
+        }
+    }
+
+    /// Signs `self` with `spending_key`, returning `None` if the authorization of `self` was not
+    /// derived from `spending_key`.
+    #[inline]
+    pub fn sign<R>(
+        &self,
+        parameters: &Parameters<C>,
+        spending_key: &SpendingKey<C>,
+        rng: &mut R,
+    ) -> Option<AuthorizationSignature<C>>
+    where
+        Authorization<C>: Clone,
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        auth::sign(
+            parameters,
+            spending_key,
+            self.authorization.clone(),
+            &BodyWithAccountsRef::new(&self.body, &self.sink_accounts),
+            rng,
+        )
+    }
+
+    /// Attaches `authorization_signature` to `self`, returning the finished [`TransferPost`] if the
+    /// signature is valid for `self`.
+    #[inline]
+    pub fn into_post(
+        self,
+        parameters: &Parameters<C>,
+        authorization_signature: AuthorizationSignature<C>,
+    ) -> Option<TransferPost<C>> {
+        let post = TransferPost::new_unchecked_with_sinks(
+            Some(authorization_signature),
+            self.body,
+            self.sink_accounts,
+        );
+        post.has_valid_authorization_signature(parameters)
+            .is_ok()
+            .then_some(post)
+    }
+}
+
+/// Circuit Version
+///
+/// Identifies the version of the transfer circuits, and so of the proving and verifying contexts,
+/// that the proof of a [`TransferPost`] was generated with. Posts built before the tag was
+/// introduced deserialize with version `0`.
+pub type CircuitVersion = u8;
+
 /// Transfer Post
 #[cfg_attr(
     feature = "serde",
@@ -1682,6 +1971,10 @@ where
 
     /// Sink Accounts
     pub sink_accounts: Vec<C::AccountId>,
+
+    /// Circuit Version
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub version: CircuitVersion,
 }
 
 impl<C> TransferPost<C>
@@ -1700,6 +1993,7 @@ where
             authorization_signature,
             body,
             sink_accounts,
+            version: Default::default(),
         }
     }
 
@@ -1713,6 +2007,13 @@ where
         Self::new_unchecked_with_sinks(authorization_signature, body, Vec::new())
     }
 
+    /// Tags `self` with the circuit `version` its proof was generated with.
+    #[inline]
+    pub fn with_version(mut self, version: CircuitVersion) -> Self {
+        self.version = version;
+        self
+    }
+
     /// Returns the `k`-th source in the transfer.
     #[inline]
     pub fn source(&self, k: usize) -> Option<Asset<C>> {
@@ -1882,6 +2183,7 @@ where
                 receivers: &receiver_posting_keys,
                 sinks: &sink_posting_keys,
                 proof: self.body.proof,
+                tag: &self.body.tag,
             })
             .map_err(|x| x.into())?;
         Ok(TransferPostingKey {
@@ -2094,6 +2396,7 @@ where
     Debug(bound = r"
         AuthorizationKey<C>: Debug,
         C::AssetId: Debug,
+        C::WithdrawalTag: Debug,
         SourcePostingKey<C, L>: Debug,
         SenderPostingKey<C, L>: Debug,
         ReceiverPostingKey<C, L>: Debug,
@@ -2102,6 +2405,7 @@ where
     Eq(bound = r"
         AuthorizationKey<C>: Eq,
         C::AssetId: Eq,
+        C::WithdrawalTag: Eq,
         SourcePostingKey<C, L>: Eq,
         SenderPostingKey<C, L>: Eq,
         ReceiverPostingKey<C, L>: Eq,
@@ -2110,6 +2414,7 @@ where
     Hash(bound = r"
         AuthorizationKey<C>: Hash,
         C::AssetId: Hash,
+        C::WithdrawalTag: Hash,
         SourcePostingKey<C, L>: Hash,
         SenderPostingKey<C, L>: Hash,
         ReceiverPostingKey<C, L>: Hash,
@@ -2118,6 +2423,7 @@ where
     PartialEq(bound = r"
         AuthorizationKey<C>: PartialEq,
         C::AssetId: PartialEq,
+        C::WithdrawalTag: PartialEq,
         SourcePostingKey<C, L>: PartialEq,
         SenderPostingKey<C, L>: PartialEq,
         ReceiverPostingKey<C, L>: PartialEq,
@@ -2149,6 +2455,9 @@ where
 
     /// Proof
     pub proof: Proof<C>,
+
+    /// Withdrawal Tag
+    pub tag: &'k Option<C::WithdrawalTag>,
 }
 
 impl<'k, C, L> TransferPostingKeyRef<'k, C, L>
@@ -2199,6 +2508,9 @@ where
         self.sinks
             .iter()
             .for_each(|sink| C::ProofSystem::extend(input, sink.as_ref()));
+        if let Some(tag) = self.tag {
+            C::ProofSystem::extend(input, tag);
+        }
     }
 }
 
@@ -180,6 +180,27 @@ where
         Some(self.get_proof(parameters, utxo_accumulator)?.upgrade(self))
     }
 
+    /// Tries to convert `self` into a [`Sender`] by getting a proof from `utxo_accumulator` which
+    /// targets one of the recent roots of `history`. See [`prove_against_recent_root`] for more.
+    ///
+    /// [`prove_against_recent_root`]: accumulator::prove_against_recent_root
+    #[inline]
+    pub fn try_upgrade_with_history<A, H>(
+        self,
+        parameters: &S,
+        utxo_accumulator: &A,
+        history: &H,
+    ) -> Option<Sender<S>>
+    where
+        A: Accumulator<Item = UtxoAccumulatorItem<S>, Model = S::UtxoAccumulatorModel>,
+        H: accumulator::RootHistory<UtxoAccumulatorOutput<S>> + ?Sized,
+    {
+        let proof = self.get_proof(parameters, utxo_accumulator)?;
+        history
+            .is_recent_root(proof.utxo_membership_proof.output())
+            .then(|| proof.upgrade(self))
+    }
+
     /// Inserts the [`Utxo`] corresponding to `self` into the `utxo_accumulator` and upgrades to a
     /// full [`Sender`] if the insertion succeeded.
     ///
@@ -377,6 +398,31 @@ where
         self.secret.query_asset(&self.utxo)
     }
 
+    /// Replaces the membership proof of `self` with the one returned by `refresh`, which is given
+    /// the accumulator item of the [`Utxo`] of `self` and its current proof, returning `true` if
+    /// `refresh` returned a new proof.
+    ///
+    /// [`Utxo`]: crate::transfer::utxo::UtxoType::Utxo
+    #[inline]
+    pub fn refresh_membership_proof<F>(&mut self, parameters: &S, refresh: F) -> bool
+    where
+        F: FnOnce(
+            &UtxoAccumulatorItem<S>,
+            &UtxoMembershipProof<S>,
+        ) -> Option<UtxoMembershipProof<S>>,
+    {
+        let item = parameters
+            .utxo_accumulator_item_hash()
+            .item_hash(&self.utxo, &mut ());
+        match refresh(&item, &self.utxo_membership_proof) {
+            Some(proof) => {
+                self.utxo_membership_proof = proof;
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Extracts the ledger posting data from `self`.
     #[inline]
     pub fn into_post(self) -> SenderPost<S> {
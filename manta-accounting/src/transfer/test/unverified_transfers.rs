@@ -230,6 +230,7 @@ where
             receiver_posts: unsafe_transfer_post_body.receiver_posts,
             sinks: unsafe_transfer_post_body.sinks,
             proof: Default::default(),
+            tag: None,
         }
     }
 }
@@ -368,6 +369,7 @@ where
             authorization_signature: None,
             body: unsafe_transfer_post.body.into(),
             sink_accounts: unsafe_transfer_post.sink_accounts,
+            version: Default::default(),
         }
     }
 }
@@ -561,6 +563,7 @@ where
             receivers: &receiver_posting_keys,
             sinks: &sink_posting_keys,
             proof: post.body.proof,
+            tag: &None,
         });
         TransferPostingKey {
             asset_id: post.body.asset_id,
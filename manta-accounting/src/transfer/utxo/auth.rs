@@ -104,6 +104,20 @@ pub trait ProveAuthorization:
         R: RngCore + ?Sized;
 }
 
+/// Authorization Context Randomization
+pub trait RandomizeAuthorization: AuthorizationContextType + AuthorizationProofType {
+    /// Generates an authorization proof for `authorization_context` without access to the spending
+    /// key it was derived from. Only the holder of that spending key can sign with the resulting
+    /// authorization.
+    fn randomize<R>(
+        &self,
+        authorization_context: &Self::AuthorizationContext,
+        rng: &mut R,
+    ) -> Self::AuthorizationProof
+    where
+        R: RngCore + ?Sized;
+}
+
 /// Authorization Context Verification
 pub trait VerifyAuthorization:
     AuthorizationContextType + AuthorizationProofType + SpendingKeyType
@@ -173,6 +187,59 @@ pub trait VerifySignature<M>: AuthorizationKeyType + SignatureType {
     ) -> bool;
 }
 
+/// Partial Authorization
+///
+/// In a multi-party setup the spending key is split into additive shares held by different
+/// parties. Each party proves a partial authorization for its own share using randomness shared
+/// by all parties. The partial authorizations are then combined into the single [`Authorization`]
+/// checked by the circuit, so no party ever holds the full spending key.
+pub trait PartialAuthorizationType {
+    /// Authorization Randomness Type
+    type AuthorizationRandomness;
+
+    /// Partial Authorization Type
+    type PartialAuthorization;
+}
+
+/// Authorization Randomness Type
+pub type AuthorizationRandomness<T> = <T as PartialAuthorizationType>::AuthorizationRandomness;
+
+/// Partial Authorization Type
+pub type PartialAuthorization<T> = <T as PartialAuthorizationType>::PartialAuthorization;
+
+/// Partial Authorization Proving
+pub trait ProvePartialAuthorization: PartialAuthorizationType + SpendingKeyType {
+    /// Samples the authorization randomness which all parties use for their partial
+    /// authorizations.
+    fn sample_authorization_randomness<R>(&self, rng: &mut R) -> Self::AuthorizationRandomness
+    where
+        R: RngCore + ?Sized;
+
+    /// Generates the partial authorization for `spending_key_share` under the shared
+    /// `randomness`.
+    fn prove_partial(
+        &self,
+        spending_key_share: &Self::SpendingKey,
+        randomness: &Self::AuthorizationRandomness,
+    ) -> Self::PartialAuthorization;
+}
+
+/// Partial Authorization Combination
+pub trait CombineAuthorization:
+    AuthorizationContextType + AuthorizationProofType + PartialAuthorizationType
+{
+    /// Combines `partial_authorizations` into the [`Authorization`] for the sum of their spending
+    /// key shares, returning `None` if there are no partial authorizations or if any of them was
+    /// not generated under `randomness`.
+    fn combine<I>(
+        &self,
+        randomness: &Self::AuthorizationRandomness,
+        partial_authorizations: I,
+    ) -> Option<Authorization<Self>>
+    where
+        I: IntoIterator<Item = Self::PartialAuthorization>;
+}
+
 /// Authorization
 #[cfg_attr(
     feature = "serde",
@@ -229,6 +296,32 @@ where
         Self::new(context, proof)
     }
 
+    /// Builds a new [`Authorization`] from `parameters` and `context` without the spending key.
+    #[inline]
+    pub fn from_context<R>(parameters: &T, context: T::AuthorizationContext, rng: &mut R) -> Self
+    where
+        T: RandomizeAuthorization,
+        R: RngCore + ?Sized,
+    {
+        let proof = parameters.randomize(&context, rng);
+        Self::new(context, proof)
+    }
+
+    /// Builds a new [`Authorization`] by combining `partial_authorizations` generated under the
+    /// shared `randomness`. See [`CombineAuthorization::combine`] for more.
+    #[inline]
+    pub fn from_partial_authorizations<I>(
+        parameters: &T,
+        randomness: &T::AuthorizationRandomness,
+        partial_authorizations: I,
+    ) -> Option<Self>
+    where
+        T: CombineAuthorization,
+        I: IntoIterator<Item = T::PartialAuthorization>,
+    {
+        parameters.combine(randomness, partial_authorizations)
+    }
+
     /// Verifies that `self` is derived from `spending_key`.
     #[inline]
     pub fn verify(&self, parameters: &T, spending_key: &T::SpendingKey) -> bool
@@ -448,4 +541,29 @@ pub mod test {
             .expect("Unable to sign message.");
         signature.verify(parameters, message)
     }
+
+    /// Verifies that combining the partial authorizations of `spending_key_shares` yields an
+    /// authorization which is valid for `spending_key`, the sum of the shares.
+    #[inline]
+    pub fn partial_authorization_correctness<T, R>(
+        parameters: &T,
+        spending_key_shares: &[T::SpendingKey],
+        spending_key: &T::SpendingKey,
+        rng: &mut R,
+    ) -> bool
+    where
+        T: CombineAuthorization + ProvePartialAuthorization + VerifyAuthorization,
+        R: RngCore + ?Sized,
+    {
+        let randomness = parameters.sample_authorization_randomness(rng);
+        Authorization::from_partial_authorizations(
+            parameters,
+            &randomness,
+            spending_key_shares
+                .iter()
+                .map(|share| parameters.prove_partial(share, &randomness)),
+        )
+        .map(|authorization| authorization.verify(parameters, spending_key))
+        .unwrap_or(false)
+    }
 }
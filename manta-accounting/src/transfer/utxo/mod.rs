@@ -279,6 +279,40 @@ pub trait NoteOpen: AssetType + DeriveDecryptionKey + IdentifierType + NoteType
     }
 }
 
+/// Note Detection
+///
+/// Light clients can download the compact [`NoteFilter`] of every note instead of the full notes,
+/// and only fetch and trial-decrypt the notes whose filter is matched by their detection key.
+/// Detection has false positives, so a match must still be confirmed with [`NoteOpen::open`],
+/// but it has no false negatives. The detection key cannot open notes, so light clients can hand
+/// it to a third party which filters notes on their behalf.
+///
+/// [`NoteFilter`]: Self::NoteFilter
+pub trait NoteDetect: DeriveDecryptionKey + NoteType {
+    /// Detection Key Type
+    type DetectionKey;
+
+    /// Note Filter Type
+    type NoteFilter;
+
+    /// Derives the detection key for note filters from `decryption_key`. The detection key must
+    /// not reveal `decryption_key`.
+    fn derive_detection_key(&self, decryption_key: &Self::DecryptionKey) -> Self::DetectionKey;
+
+    /// Returns the compact filter of `note` which is published by the ledger.
+    fn note_filter(&self, note: &Self::Note) -> Self::NoteFilter;
+
+    /// Returns `true` if `note_filter` is matched by `detection_key`, meaning that the note it
+    /// belongs to may be opened by the corresponding decryption key.
+    fn detect(&self, detection_key: &Self::DetectionKey, note_filter: &Self::NoteFilter) -> bool;
+
+    /// Returns `true` if the filter of `note` is matched by `detection_key`.
+    #[inline]
+    fn detect_note(&self, detection_key: &Self::DetectionKey, note: &Self::Note) -> bool {
+        self.detect(detection_key, &self.note_filter(note))
+    }
+}
+
 /// Derive Address
 pub trait DeriveAddress: AddressType {
     /// Secret Key Type
@@ -29,7 +29,7 @@ use core::{cmp, fmt::Debug, hash::Hash};
 use manta_crypto::{
     accumulator::{self, ItemHashFunction, MembershipProof},
     algebra::{
-        diffie_hellman::StandardDiffieHellman, security::ComputationalDiffieHellmanHardness,
+        diffie_hellman::StandardDiffieHellman, security::ComputationalDiffieHellmanHardness, Group,
         HasGenerator, Ring, ScalarMul, ScalarMulGroup,
     },
     constraint::{HasInput, Input},
@@ -41,13 +41,13 @@ use manta_crypto::{
         bool::{Assert, AssertEq, Bool, ConditionalSelect},
         cmp::PartialEq,
         num::Zero,
-        ops::{BitAnd, BitOr},
+        ops::{BitAnd, BitOr, ConstantTimeEq},
         Has,
     },
     encryption::{
         self,
         hybrid::{Hybrid, Randomness},
-        Decrypt, Encrypt, EncryptedMessage,
+        Decrypt, Encrypt, EncryptedMessage, SupportedHeader,
     },
     rand::{Rand, RngCore, Sample},
     signature::{self, schnorr, Sign, Verify},
@@ -64,6 +64,12 @@ use manta_util::serde::{Deserialize, Serialize};
 /// UTXO Version Number
 pub const VERSION: u8 = 1;
 
+/// Full Incoming Note Encoding Version
+///
+/// Version `0` notes were encoded without a detection tag, while notes of version `1` carry the
+/// tag of their [`DetectionTagFunction`].
+pub const FULL_INCOMING_NOTE_VERSION: u8 = 1;
+
 /// UTXO Visibility
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub enum Visibility {
@@ -319,17 +325,69 @@ pub trait AddressPartitionFunction {
     type Address;
 
     /// Partition Type
-    type Partition: core::cmp::PartialEq;
+    ///
+    /// Partitions are compared in constant time when opening notes, since they are derived from
+    /// the receiving key.
+    type Partition: core::cmp::PartialEq + ConstantTimeEq;
 
     /// Returns the partition for the `address`.
     fn partition(&self, address: &Self::Address) -> Self::Partition;
 }
 
+/// Detection Key Derivation Function
+///
+/// Derives the detection key of an address from its viewing key. The detection key must not
+/// reveal the viewing key, so that it can be handed to a third party which filters notes on behalf
+/// of a light client without being able to decrypt them.
+pub trait DetectionKeyDerivationFunction {
+    /// Viewing Key Type
+    type ViewingKey;
+
+    /// Detection Key Type
+    type DetectionKey;
+
+    /// Returns the detection key for `viewing_key`.
+    fn detection_key(&self, viewing_key: &Self::ViewingKey) -> Self::DetectionKey;
+}
+
+/// Detection Tag Function
+///
+/// Computes the detection tag of a note from the Diffie-Hellman shared secret between the
+/// ephemeral key of the note and the detection key of its address. Tags should be short, since
+/// they are downloaded for every note, but every bit of tag halves the number of false positives
+/// a light client has to trial-decrypt.
+pub trait DetectionTagFunction {
+    /// Shared Secret Type
+    type SharedSecret;
+
+    /// Tag Type
+    ///
+    /// Tags are compared in constant time when detecting notes, since they are derived from the
+    /// detection key.
+    type Tag: core::cmp::PartialEq + ConstantTimeEq;
+
+    /// Returns the detection tag for `shared_secret`.
+    fn tag(&self, shared_secret: &Self::SharedSecret) -> Self::Tag;
+}
+
 /// UTXO Configuration
 pub trait Configuration: BaseConfiguration<Bool = bool> {
     /// Address Partition Function Type
     type AddressPartitionFunction: AddressPartitionFunction<Address = Address<Self>>;
 
+    /// Detection Key Derivation Function Type
+    ///
+    /// The detection key derivation function has no parameters, so it is built with [`Default`]
+    /// instead of being stored in [`Parameters`].
+    type DetectionKeyDerivationFunction: Default
+        + DetectionKeyDerivationFunction<ViewingKey = Self::Scalar, DetectionKey = Self::Scalar>;
+
+    /// Detection Tag Function Type
+    ///
+    /// The detection tag function has no parameters, so it is built with [`Default`] instead of
+    /// being stored in [`Parameters`].
+    type DetectionTagFunction: Default + DetectionTagFunction<SharedSecret = Self::Group>;
+
     /// Schnorr Hash Function
     type SchnorrHashFunction: Clone
         + schnorr::HashFunction<Scalar = Self::Scalar, Group = Self::Group, Message = Vec<u8>>;
@@ -424,6 +482,10 @@ pub type OutgoingNote<C, COM = ()> = EncryptedMessage<OutgoingEncryptionScheme<C
 pub type AddressPartition<C> =
     <<C as Configuration>::AddressPartitionFunction as AddressPartitionFunction>::Partition;
 
+/// Detection Tag
+pub type DetectionTag<C> =
+    <<C as Configuration>::DetectionTagFunction as DetectionTagFunction>::Tag;
+
 /// Signature Scheme
 pub type SignatureScheme<C> = schnorr::Schnorr<<C as Configuration>::SchnorrHashFunction>;
 
@@ -896,13 +958,23 @@ where
     where
         C: Configuration,
     {
+        let generator = self.base.group_generator.generator();
+        self.address_from_viewing_key(
+            &self
+                .base
+                .viewing_key_derivation_function
+                .viewing_key(&generator.scalar_mul(spending_key, &mut ()), &mut ()),
+        )
+    }
+
+    /// Computes the [`Address`] corresponding to `viewing_key`.
+    #[inline]
+    pub fn address_from_viewing_key(&self, viewing_key: &C::Scalar) -> Address<C> {
         let generator = self.base.group_generator.generator();
         Address::new(
+            generator.scalar_mul(viewing_key, &mut ()),
             generator.scalar_mul(
-                &self
-                    .base
-                    .viewing_key_derivation_function
-                    .viewing_key(&generator.scalar_mul(spending_key, &mut ()), &mut ()),
+                &C::DetectionKeyDerivationFunction::default().detection_key(viewing_key),
                 &mut (),
             ),
         )
@@ -1031,6 +1103,24 @@ where
         R: RngCore + ?Sized,
     {
         let _ = spending_key;
+        auth::RandomizeAuthorization::randomize(self, authorization_context, rng)
+    }
+}
+
+impl<C> auth::RandomizeAuthorization for Parameters<C>
+where
+    C: Configuration<Bool = bool>,
+    C::Scalar: Sample,
+{
+    #[inline]
+    fn randomize<R>(
+        &self,
+        authorization_context: &Self::AuthorizationContext,
+        rng: &mut R,
+    ) -> Self::AuthorizationProof
+    where
+        R: RngCore + ?Sized,
+    {
         let randomness = rng.gen();
         let randomized_proof_authorization_key = authorization_context
             .proof_authorization_key
@@ -1042,7 +1132,7 @@ where
 impl<C> auth::VerifyAuthorization for Parameters<C>
 where
     C: Configuration<Bool = bool>,
-    C::Group: cmp::PartialEq,
+    C::Group: ConstantTimeEq,
 {
     #[inline]
     fn verify(
@@ -1051,11 +1141,99 @@ where
         authorization_context: &Self::AuthorizationContext,
         authorization_proof: &Self::AuthorizationProof,
     ) -> bool {
-        (authorization_context == &self.derive_context(spending_key))
-            && (authorization_proof.randomized_proof_authorization_key
-                == authorization_context
+        let is_derived_context = authorization_context.proof_authorization_key.ct_eq(
+            &self.derive_context(spending_key).proof_authorization_key,
+            &mut (),
+        );
+        let is_randomized_key = authorization_proof
+            .randomized_proof_authorization_key
+            .ct_eq(
+                &authorization_context
                     .proof_authorization_key
-                    .scalar_mul(&authorization_proof.randomness, &mut ()))
+                    .scalar_mul(&authorization_proof.randomness, &mut ()),
+                &mut (),
+            );
+        is_derived_context & is_randomized_key
+    }
+}
+
+impl<C> auth::PartialAuthorizationType for Parameters<C>
+where
+    C: Configuration<Bool = bool>,
+{
+    type AuthorizationRandomness = C::Scalar;
+    type PartialAuthorization = PartialAuthorization<C>;
+}
+
+impl<C> auth::ProvePartialAuthorization for Parameters<C>
+where
+    C: Configuration<Bool = bool>,
+    C::Scalar: Sample,
+{
+    #[inline]
+    fn sample_authorization_randomness<R>(&self, rng: &mut R) -> Self::AuthorizationRandomness
+    where
+        R: RngCore + ?Sized,
+    {
+        rng.gen()
+    }
+
+    #[inline]
+    fn prove_partial(
+        &self,
+        spending_key_share: &Self::SpendingKey,
+        randomness: &Self::AuthorizationRandomness,
+    ) -> Self::PartialAuthorization {
+        let proof_authorization_key_share = self
+            .derive_context(spending_key_share)
+            .proof_authorization_key;
+        let randomized_proof_authorization_key_share =
+            proof_authorization_key_share.scalar_mul(randomness, &mut ());
+        PartialAuthorization::new(
+            proof_authorization_key_share,
+            randomized_proof_authorization_key_share,
+        )
+    }
+}
+
+impl<C> auth::CombineAuthorization for Parameters<C>
+where
+    C: Configuration<Bool = bool>,
+    C::Group: ConstantTimeEq,
+{
+    #[inline]
+    fn combine<I>(
+        &self,
+        randomness: &Self::AuthorizationRandomness,
+        partial_authorizations: I,
+    ) -> Option<auth::Authorization<Self>>
+    where
+        I: IntoIterator<Item = Self::PartialAuthorization>,
+    {
+        let mut partial_authorizations = partial_authorizations.into_iter();
+        let first = partial_authorizations.next()?;
+        if !first.is_randomized_by(randomness) {
+            return None;
+        }
+        let mut proof_authorization_key = first.proof_authorization_key_share;
+        let mut randomized_proof_authorization_key = first.randomized_proof_authorization_key_share;
+        for partial_authorization in partial_authorizations {
+            if !partial_authorization.is_randomized_by(randomness) {
+                return None;
+            }
+            proof_authorization_key.add_assign(
+                &partial_authorization.proof_authorization_key_share,
+                &mut (),
+            );
+            randomized_proof_authorization_key.add_assign(
+                &partial_authorization.randomized_proof_authorization_key_share,
+                &mut (),
+            );
+        }
+        Some(auth::Authorization::new(
+            AuthorizationContext::new(proof_authorization_key),
+            AuthorizationProof::new(randomness.clone(), randomized_proof_authorization_key),
+        ))
     }
 }
 
@@ -1195,6 +1373,11 @@ where
             &secret.plaintext,
             &mut (),
         );
+        let detection_tag = C::DetectionTagFunction::default().tag(
+            &address
+                .detection_key
+                .scalar_mul(&secret.incoming_randomness.ephemeral_secret_key, &mut ()),
+        );
         (
             secret,
             Utxo::new(
@@ -1202,7 +1385,12 @@ where
                 associated_data.public(&asset),
                 utxo_commitment,
             ),
-            FullIncomingNote::new(address_partition, incoming_note, light_incoming_note),
+            FullIncomingNote::new(
+                address_partition,
+                incoming_note,
+                light_incoming_note,
+                detection_tag,
+            ),
         )
     }
 }
@@ -1371,6 +1559,7 @@ where
 impl<C> utxo::NullifierOpen for Parameters<C>
 where
     C: Configuration<Bool = bool>,
+    C::OutgoingHeader: SupportedHeader,
     C::OutgoingBaseEncryptionScheme:
         Decrypt<DecryptionKey = C::Group, DecryptedPlaintext = Option<Asset<C>>>,
 {
@@ -1380,13 +1569,16 @@ where
         nullifier: &Self::Nullifier,
         decryption_key: &Self::DecryptionKey,
     ) -> Option<Self::Asset> {
+        if !nullifier.outgoing_note.header.is_supported() {
+            return None;
+        }
         Hybrid::new(
             StandardDiffieHellman::new(self.base.group_generator.generator().clone()),
             self.base.outgoing_base_encryption_scheme.clone(),
         )
         .decrypt(
             decryption_key,
-            &C::OutgoingHeader::default(),
+            &nullifier.outgoing_note.header,
             &nullifier.outgoing_note.ciphertext,
             &mut (),
         )
@@ -1396,6 +1588,7 @@ where
 impl<C> utxo::NoteOpen for Parameters<C>
 where
     C: Configuration<Bool = bool>,
+    C::LightIncomingHeader: SupportedHeader,
     C::LightIncomingBaseEncryptionScheme:
         Decrypt<DecryptionKey = C::Group, DecryptedPlaintext = Option<IncomingPlaintext<C>>>,
 {
@@ -1406,20 +1599,19 @@ where
         utxo: &Self::Utxo,
         note: Self::Note,
     ) -> Option<(Self::Identifier, Self::Asset)> {
-        let address_partition = self.address_partition_function.partition(&Address::new(
-            self.base
-                .group_generator
-                .generator()
-                .scalar_mul(decryption_key, &mut ()),
-        ));
-        if address_partition == note.address_partition {
+        let address_partition = self
+            .address_partition_function
+            .partition(&self.address_from_viewing_key(decryption_key));
+        if address_partition.ct_eq(&note.address_partition, &mut ())
+            && note.light_incoming_note.header.is_supported()
+        {
             let plaintext = Hybrid::new(
                 StandardDiffieHellman::new(self.base.group_generator.generator().clone()),
                 self.base.light_incoming_base_encryption_scheme.clone(),
             )
             .decrypt(
                 decryption_key,
-                &C::LightIncomingHeader::default(),
+                &note.light_incoming_note.header,
                 &note.light_incoming_note.ciphertext,
                 &mut (),
             )?;
@@ -1433,6 +1625,47 @@ where
     }
 }
 
+impl<C> utxo::NoteDetect for Parameters<C>
+where
+    C: Configuration<Bool = bool>,
+    DetectionTag<C>: Clone,
+{
+    type DetectionKey = C::Scalar;
+    type NoteFilter = NoteFilter<C>;
+
+    /// The detection key is derived from the viewing key with the
+    /// [`DetectionKeyDerivationFunction`] of `C`, and its public point is part of the
+    /// [`Address`], so it can be delegated without revealing the decryption key.
+    #[inline]
+    fn derive_detection_key(&self, decryption_key: &Self::DecryptionKey) -> Self::DetectionKey {
+        C::DetectionKeyDerivationFunction::default().detection_key(decryption_key)
+    }
+
+    /// Notes encoded before [`FULL_INCOMING_NOTE_VERSION`] `1` have no detection tag, so their
+    /// filters are matched by every detection key.
+    #[inline]
+    fn note_filter(&self, note: &Self::Note) -> Self::NoteFilter {
+        NoteFilter::new(
+            note.incoming_note.ciphertext.ephemeral_public_key.clone(),
+            (note.version > 0).then(|| note.detection_tag.clone()),
+        )
+    }
+
+    #[inline]
+    fn detect(&self, detection_key: &Self::DetectionKey, note_filter: &Self::NoteFilter) -> bool {
+        match &note_filter.detection_tag {
+            Some(detection_tag) => C::DetectionTagFunction::default()
+                .tag(
+                    &note_filter
+                        .ephemeral_public_key
+                        .scalar_mul(detection_key, &mut ()),
+                )
+                .ct_eq(detection_tag, &mut ()),
+            _ => true,
+        }
+    }
+}
+
 impl<C> utxo::DeriveAddress for Parameters<C>
 where
     C: Configuration<Bool = bool>,
@@ -1441,18 +1674,14 @@ where
 
     #[inline]
     fn derive_address(&self, decryption_key: &Self::SecretKey) -> Self::Address {
-        Address::new(
-            self.base
-                .group_generator
-                .generator()
-                .scalar_mul(decryption_key, &mut ()),
-        )
+        self.address_from_viewing_key(decryption_key)
     }
 }
 
 impl<C> utxo::UtxoReconstruct for Parameters<C>
 where
     C: Configuration<Bool = bool>,
+    C::LightIncomingHeader: SupportedHeader,
     C::LightIncomingBaseEncryptionScheme:
         Decrypt<DecryptionKey = C::Group, DecryptedPlaintext = Option<IncomingPlaintext<C>>>,
     Asset<C>: Clone + Default,
@@ -1549,6 +1778,12 @@ where
 {
     /// Receiving Key
     pub receiving_key: C::Group,
+
+    /// Detection Key
+    ///
+    /// Public point of the detection key derived from the viewing key, which keys the detection
+    /// tags of the notes sent to this address.
+    pub detection_key: C::Group,
 }
 
 impl<C, COM> Address<C, COM>
@@ -1556,10 +1791,13 @@ where
     C: BaseConfiguration<COM> + ?Sized,
     COM: Has<bool, Type = C::Bool>,
 {
-    /// Builds a new [`Address`] from `receiving_key`.
+    /// Builds a new [`Address`] from `receiving_key` and `detection_key`.
     #[inline]
-    pub fn new(receiving_key: C::Group) -> Self {
-        Self { receiving_key }
+    pub fn new(receiving_key: C::Group, detection_key: C::Group) -> Self {
+        Self {
+            receiving_key,
+            detection_key,
+        }
     }
 }
 
@@ -1573,7 +1811,7 @@ where
     where
         R: RngCore + ?Sized,
     {
-        Self::new(rng.gen())
+        Self::new(rng.gen(), rng.gen())
     }
 }
 
@@ -1664,8 +1902,8 @@ where
     derive(Deserialize, Serialize),
     serde(
         bound(
-            deserialize = "AddressPartition<C>: Deserialize<'de>, IncomingNote<C>: Deserialize<'de>, LightIncomingNote<C>: Deserialize<'de>",
-            serialize = "AddressPartition<C>: Serialize, IncomingNote<C>: Serialize, LightIncomingNote<C>: Serialize",
+            deserialize = "AddressPartition<C>: Deserialize<'de>, IncomingNote<C>: Deserialize<'de>, LightIncomingNote<C>: Deserialize<'de>, DetectionTag<C>: Default + Deserialize<'de>",
+            serialize = "AddressPartition<C>: Serialize, IncomingNote<C>: Serialize, LightIncomingNote<C>: Serialize, DetectionTag<C>: Serialize",
         ),
         crate = "manta_util::serde",
         deny_unknown_fields
@@ -1674,25 +1912,38 @@ where
 #[derive(derivative::Derivative)]
 #[derivative(
     Clone(
-        bound = "AddressPartition<C>: Clone, IncomingNote<C>: Clone, LightIncomingNote<C>: Clone"
+        bound = "AddressPartition<C>: Clone, IncomingNote<C>: Clone, LightIncomingNote<C>: Clone, DetectionTag<C>: Clone"
+    ),
+    Copy(
+        bound = "AddressPartition<C>: Copy, IncomingNote<C>: Copy, LightIncomingNote<C>: Copy, DetectionTag<C>: Copy"
     ),
-    Copy(bound = "AddressPartition<C>: Copy, IncomingNote<C>: Copy, LightIncomingNote<C>: Copy"),
     Debug(
-        bound = "AddressPartition<C>: Debug, IncomingNote<C>: Debug, LightIncomingNote<C>: Debug"
+        bound = "AddressPartition<C>: Debug, IncomingNote<C>: Debug, LightIncomingNote<C>: Debug, DetectionTag<C>: Debug"
     ),
     Default(
-        bound = "AddressPartition<C>: Default, IncomingNote<C>: Default, LightIncomingNote<C>: Default"
+        bound = "AddressPartition<C>: Default, IncomingNote<C>: Default, LightIncomingNote<C>: Default, DetectionTag<C>: Default"
+    ),
+    Eq(
+        bound = "AddressPartition<C>: Eq, IncomingNote<C>: Eq, LightIncomingNote<C>: Eq, DetectionTag<C>: Eq"
+    ),
+    Hash(
+        bound = "AddressPartition<C>: Hash, IncomingNote<C>: Hash, LightIncomingNote<C>: Hash, DetectionTag<C>: Hash"
     ),
-    Eq(bound = "AddressPartition<C>: Eq, IncomingNote<C>: Eq, LightIncomingNote<C>: Eq"),
-    Hash(bound = "AddressPartition<C>: Hash, IncomingNote<C>: Hash, LightIncomingNote<C>: Hash"),
     PartialEq(
-        bound = "AddressPartition<C>: cmp::PartialEq, IncomingNote<C>: cmp::PartialEq, LightIncomingNote<C>: cmp::PartialEq"
+        bound = "AddressPartition<C>: cmp::PartialEq, IncomingNote<C>: cmp::PartialEq, LightIncomingNote<C>: cmp::PartialEq, DetectionTag<C>: cmp::PartialEq"
     )
 )]
 pub struct FullIncomingNote<C>
 where
     C: Configuration<Bool = bool> + ?Sized,
 {
+    /// Encoding Version
+    ///
+    /// Notes serialized before the [`detection_tag`](Self::detection_tag) was introduced have no
+    /// version and are read as version `0`. See [`FULL_INCOMING_NOTE_VERSION`] for more.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub version: u8,
+
     /// Address Partition
     pub address_partition: AddressPartition<C>,
 
@@ -1701,23 +1952,36 @@ where
 
     /// Light Incoming Note
     pub light_incoming_note: LightIncomingNote<C>,
+
+    /// Detection Tag
+    ///
+    /// Tag of the shared secret between the ephemeral key of the [`IncomingNote`] and the
+    /// detection key of the address, which lets light clients filter notes with
+    /// [`NoteDetect`](utxo::NoteDetect) before downloading them. It is only meaningful for notes
+    /// of version `1` and above.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub detection_tag: DetectionTag<C>,
 }
 
 impl<C> FullIncomingNote<C>
 where
     C: Configuration<Bool = bool> + ?Sized,
 {
-    /// Builds a new [`FullIncomingNote`] from `address_partition`, `incoming_note` and `light_incoming_note`.
+    /// Builds a new [`FullIncomingNote`] from `address_partition`, `incoming_note`,
+    /// `light_incoming_note` and `detection_tag` at the [`FULL_INCOMING_NOTE_VERSION`].
     #[inline]
     pub fn new(
         address_partition: AddressPartition<C>,
         incoming_note: IncomingNote<C>,
         light_incoming_note: LightIncomingNote<C>,
+        detection_tag: DetectionTag<C>,
     ) -> Self {
         Self {
+            version: FULL_INCOMING_NOTE_VERSION,
             address_partition,
             incoming_note,
             light_incoming_note,
+            detection_tag,
         }
     }
 }
@@ -1728,15 +1992,91 @@ where
     AddressPartition<C>: Encode,
     IncomingNote<C>: Encode,
     LightIncomingNote<C>: Encode,
+    DetectionTag<C>: Encode,
 {
     #[inline]
     fn encode<W>(&self, mut writer: W) -> Result<(), W::Error>
     where
         W: Write,
     {
+        self.version.encode(&mut writer)?;
         self.address_partition.encode(&mut writer)?;
         self.incoming_note.encode(&mut writer)?;
         self.light_incoming_note.encode(&mut writer)?;
+        if self.version > 0 {
+            self.detection_tag.encode(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Note Filter
+///
+/// Compact view of a [`FullIncomingNote`] which is enough to run [`NoteDetect::detect`] on it.
+///
+/// [`NoteDetect::detect`]: utxo::NoteDetect::detect
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "C::Group: Deserialize<'de>, DetectionTag<C>: Deserialize<'de>",
+            serialize = "C::Group: Serialize, DetectionTag<C>: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "C::Group: Clone, DetectionTag<C>: Clone"),
+    Copy(bound = "C::Group: Copy, DetectionTag<C>: Copy"),
+    Debug(bound = "C::Group: Debug, DetectionTag<C>: Debug"),
+    Default(bound = "C::Group: Default, DetectionTag<C>: Default"),
+    Eq(bound = "C::Group: Eq, DetectionTag<C>: Eq"),
+    Hash(bound = "C::Group: Hash, DetectionTag<C>: Hash"),
+    PartialEq(bound = "C::Group: cmp::PartialEq, DetectionTag<C>: cmp::PartialEq")
+)]
+pub struct NoteFilter<C>
+where
+    C: Configuration<Bool = bool> + ?Sized,
+{
+    /// Ephemeral Public Key
+    pub ephemeral_public_key: C::Group,
+
+    /// Detection Tag
+    ///
+    /// Notes without a detection tag are matched by every detection key.
+    pub detection_tag: Option<DetectionTag<C>>,
+}
+
+impl<C> NoteFilter<C>
+where
+    C: Configuration<Bool = bool> + ?Sized,
+{
+    /// Builds a new [`NoteFilter`] from `ephemeral_public_key` and `detection_tag`.
+    #[inline]
+    pub fn new(ephemeral_public_key: C::Group, detection_tag: Option<DetectionTag<C>>) -> Self {
+        Self {
+            ephemeral_public_key,
+            detection_tag,
+        }
+    }
+}
+
+impl<C> Encode for NoteFilter<C>
+where
+    C: Configuration<Bool = bool> + ?Sized,
+    C::Group: Encode,
+    DetectionTag<C>: Encode,
+{
+    #[inline]
+    fn encode<W>(&self, mut writer: W) -> Result<(), W::Error>
+    where
+        W: Write,
+    {
+        self.ephemeral_public_key.encode(&mut writer)?;
+        self.detection_tag.encode(&mut writer)?;
         Ok(())
     }
 }
@@ -2408,6 +2748,91 @@ where
     }
 }
 
+/// Partial Authorization
+///
+/// Contribution of a single spending key share to a multi-party [`Authorization`]. The
+/// proof authorization key and the randomized proof authorization key of the full spending key
+/// are the sums of the respective keys of its shares.
+///
+/// [`Authorization`]: auth::Authorization
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "C::Group: Deserialize<'de>",
+            serialize = "C::Group: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "C::Group: Clone"),
+    Copy(bound = "C::Group: Copy"),
+    Debug(bound = "C::Group: Debug"),
+    Default(bound = "C::Group: Default"),
+    Eq(bound = "C::Group: Eq"),
+    Hash(bound = "C::Group: Hash"),
+    PartialEq(bound = "C::Group: cmp::PartialEq")
+)]
+pub struct PartialAuthorization<C>
+where
+    C: BaseConfiguration<Bool = bool> + ?Sized,
+{
+    /// Proof Authorization Key Share
+    proof_authorization_key_share: C::Group,
+
+    /// Randomized Proof Authorization Key Share
+    randomized_proof_authorization_key_share: C::Group,
+}
+
+impl<C> PartialAuthorization<C>
+where
+    C: BaseConfiguration<Bool = bool> + ?Sized,
+{
+    /// Builds a new [`PartialAuthorization`] from `proof_authorization_key_share` and
+    /// `randomized_proof_authorization_key_share`.
+    #[inline]
+    pub fn new(
+        proof_authorization_key_share: C::Group,
+        randomized_proof_authorization_key_share: C::Group,
+    ) -> Self {
+        Self {
+            proof_authorization_key_share,
+            randomized_proof_authorization_key_share,
+        }
+    }
+
+    /// Returns the proof authorization key share.
+    #[inline]
+    pub fn proof_authorization_key_share(&self) -> &C::Group {
+        &self.proof_authorization_key_share
+    }
+
+    /// Returns the randomized proof authorization key share.
+    #[inline]
+    pub fn randomized_proof_authorization_key_share(&self) -> &C::Group {
+        &self.randomized_proof_authorization_key_share
+    }
+
+    /// Returns `true` if the randomized proof authorization key share is the proof authorization
+    /// key share randomized by `randomness`.
+    #[inline]
+    pub fn is_randomized_by(&self, randomness: &C::Scalar) -> bool
+    where
+        C::Group: ConstantTimeEq,
+    {
+        self.randomized_proof_authorization_key_share.ct_eq(
+            &self
+                .proof_authorization_key_share
+                .scalar_mul(randomness, &mut ()),
+            &mut (),
+        )
+    }
+}
+
 /// Identifier
 #[cfg_attr(
     feature = "serde",
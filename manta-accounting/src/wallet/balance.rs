@@ -21,13 +21,19 @@
 //! abstractions.
 
 use crate::asset::{Asset, AssetList};
-use alloc::collections::btree_map::{BTreeMap, Entry as BTreeMapEntry};
+use alloc::{
+    collections::btree_map::{BTreeMap, Entry as BTreeMapEntry},
+    vec::Vec,
+};
 use core::ops::AddAssign;
 use manta_util::{
-    iter::{ConvertItemRef, ExactSizeIterable, RefItem},
+    iter::{ConvertItemRef, ExactSizeIterable, Iterable, RefItem},
     num::CheckedSub,
 };
 
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
 #[cfg(feature = "std")]
 use std::{
     collections::hash_map::{Entry as HashMapEntry, HashMap, RandomState},
@@ -75,6 +81,63 @@ pub trait BalanceState<I, V>:
 
     /// Clears the entire balance state.
     fn clear(&mut self);
+
+    /// Returns the assets with a nonzero balance in `self`, in the iteration order of `self`.
+    #[inline]
+    fn nonzero_assets(&self) -> Vec<Asset<I, V>>
+    where
+        I: Clone,
+        V: Clone + Default + PartialEq,
+    {
+        let zero = V::default();
+        self.convert_iter::<(&I, &V)>()
+            .filter(|(_, value)| **value != zero)
+            .map(|(id, value)| Asset::new(id.clone(), value.clone()))
+            .collect()
+    }
+
+    /// Returns the assets with a nonzero balance in `self` ordered by decreasing value, breaking
+    /// ties by increasing id.
+    #[inline]
+    fn assets_by_value(&self) -> Vec<Asset<I, V>>
+    where
+        I: Clone + Ord,
+        V: Clone + Default + Ord,
+    {
+        let mut assets = self.nonzero_assets();
+        assets.sort_by(|lhs, rhs| rhs.value.cmp(&lhs.value).then_with(|| lhs.id.cmp(&rhs.id)));
+        assets
+    }
+
+    /// Returns the total balance of every asset class in `self`, where `class` returns the class
+    /// of an asset id, or `None` to leave the asset out of the totals.
+    #[inline]
+    fn totals_by_class<K, F>(&self, mut class: F) -> BTreeMap<K, V>
+    where
+        K: Ord,
+        V: AddAssign + Clone + Default,
+        F: FnMut(&I) -> Option<K>,
+    {
+        let mut totals = BTreeMap::<K, V>::new();
+        for (id, value) in self.convert_iter::<(&I, &V)>() {
+            if let Some(class) = class(id) {
+                *totals.entry(class).or_default() += value.clone();
+            }
+        }
+        totals
+    }
+
+    /// Returns a [`BalanceSnapshot`] of the nonzero balances in `self`.
+    #[inline]
+    fn snapshot(&self) -> BalanceSnapshot<I, V>
+    where
+        I: Clone + Ord,
+        V: Clone + Default + PartialEq,
+    {
+        let mut assets = self.nonzero_assets();
+        assets.sort_by(|lhs, rhs| lhs.id.cmp(&rhs.id));
+        BalanceSnapshot { assets }
+    }
 }
 
 impl<I, V> BalanceState<I, V> for AssetList<I, V>
@@ -207,6 +270,194 @@ where
     impl_balance_state_map_body! { I, V, HashMapEntry }
 }
 
+/// Balance Snapshot
+///
+/// Lists the nonzero balances of a [`BalanceState`] ordered by asset id, in a form which does not
+/// depend on the balance state type and can be sent to user interfaces.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BalanceSnapshot<I, V> {
+    /// Assets with a Nonzero Balance Sorted by Id
+    pub assets: Vec<Asset<I, V>>,
+}
+
+impl<I, V> BalanceSnapshot<I, V> {
+    /// Returns the number of assets in `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.assets.len()
+    }
+
+    /// Returns `true` if `self` has no assets.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+
+    /// Returns the balance of the asset with the given `id` in `self`.
+    #[inline]
+    pub fn balance(&self, id: &I) -> V
+    where
+        I: Ord,
+        V: Clone + Default,
+    {
+        self.assets
+            .binary_search_by(|asset| asset.id.cmp(id))
+            .map(|index| self.assets[index].value.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns an iterator over the assets in `self` sorted by id.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Asset<I, V>> {
+        self.assets.iter()
+    }
+}
+
+/// Balance History
+///
+/// Records a snapshot of the balances of a [`BalanceState`] at every checkpoint `K` it is
+/// synchronized to, so that balances can be queried as of any past checkpoint. Consecutive
+/// snapshots with equal balances are only stored once.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "K: Deserialize<'de>, I: Deserialize<'de> + Ord, V: Deserialize<'de>",
+            serialize = "K: Serialize, I: Serialize, V: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "K: Clone, I: Clone, V: Clone"),
+    Debug(bound = "K: core::fmt::Debug, I: core::fmt::Debug, V: core::fmt::Debug"),
+    Default(bound = ""),
+    Eq(bound = "K: Eq, I: Eq, V: Eq"),
+    Hash(bound = "K: core::hash::Hash, I: core::hash::Hash, V: core::hash::Hash"),
+    PartialEq(bound = "K: PartialEq, I: PartialEq, V: PartialEq")
+)]
+pub struct BalanceHistory<K, I, V> {
+    /// Snapshots Ordered by Checkpoint
+    snapshots: Vec<(K, BTreeMap<I, V>)>,
+}
+
+impl<K, I, V> BalanceHistory<K, I, V>
+where
+    K: PartialOrd,
+    I: Ord,
+{
+    /// Builds a new empty [`BalanceHistory`].
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            snapshots: Vec::new(),
+        }
+    }
+
+    /// Returns the number of snapshots stored in `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Returns `true` if `self` has no snapshots.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Returns an iterator over the snapshots in `self` in increasing checkpoint order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &BTreeMap<I, V>)> {
+        self.snapshots
+            .iter()
+            .map(|(checkpoint, balances)| (checkpoint, balances))
+    }
+
+    /// Records the balances in `state` as the balances at `checkpoint`.
+    ///
+    /// Snapshots at `checkpoint` or later are replaced, since recording an earlier checkpoint
+    /// means that the wallet was rolled back to it.
+    #[inline]
+    pub fn record<B>(&mut self, checkpoint: K, state: &B)
+    where
+        I: Clone,
+        V: Clone + PartialEq,
+        B: BalanceState<I, V>,
+    {
+        while matches!(self.snapshots.last(), Some((last, _)) if *last >= checkpoint) {
+            self.snapshots.pop();
+        }
+        let balances = state
+            .convert_iter::<(&I, &V)>()
+            .map(|(id, value)| (id.clone(), value.clone()))
+            .collect::<BTreeMap<_, _>>();
+        if !matches!(self.snapshots.last(), Some((_, last)) if *last == balances) {
+            self.snapshots.push((checkpoint, balances));
+        }
+    }
+
+    /// Drops the snapshots at checkpoints later than `checkpoint`.
+    #[inline]
+    pub fn rollback(&mut self, checkpoint: &K) {
+        while matches!(self.snapshots.last(), Some((last, _)) if last > checkpoint) {
+            self.snapshots.pop();
+        }
+    }
+
+    /// Drops the snapshots which are not needed to answer queries at `checkpoint` or later.
+    #[inline]
+    pub fn prune_before(&mut self, checkpoint: &K) {
+        let index = self.partition_point(checkpoint);
+        self.snapshots.drain(..index.saturating_sub(1));
+    }
+
+    /// Returns the number of snapshots at checkpoints less than or equal to `checkpoint`.
+    #[inline]
+    fn partition_point(&self, checkpoint: &K) -> usize {
+        self.snapshots.partition_point(|(key, _)| key <= checkpoint)
+    }
+
+    /// Returns the balances as of `checkpoint`, which are the ones in the latest snapshot
+    /// recorded at or before `checkpoint`, or `None` if there is no such snapshot.
+    #[inline]
+    pub fn snapshot_at(&self, checkpoint: &K) -> Option<&BTreeMap<I, V>> {
+        match self.partition_point(checkpoint) {
+            0 => None,
+            index => Some(&self.snapshots[index - 1].1),
+        }
+    }
+
+    /// Returns the balance of the asset with the given `id` as of `checkpoint`, or `None` if
+    /// there is no snapshot at or before `checkpoint`.
+    #[inline]
+    pub fn balance_at(&self, checkpoint: &K, id: &I) -> Option<V>
+    where
+        V: Clone + Default,
+    {
+        Some(
+            self.snapshot_at(checkpoint)?
+                .get(id)
+                .cloned()
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Clears all the snapshots in `self`.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+}
+
 /// Testing Framework
 #[cfg(any(feature = "test", test))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "test")))]
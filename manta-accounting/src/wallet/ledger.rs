@@ -15,8 +15,19 @@
 // along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Ledger Connection
+//!
+//! The wallet talks to the ledger only through the [`Read`] and [`Write`] `trait`s in this module,
+//! which are bundled together in the [`Ledger`] `trait`. Any chain which can serve checkpointed
+//! UTXO and nullifier data and accept [`TransferPost`]s, whether it is a Substrate runtime or not,
+//! can be targeted by the same wallet and signer by implementing them. The [`PullRequest`] and
+//! [`PullResponse`] types are the messages to exchange with a remote ledger over the wire.
 
-use core::{fmt::Debug, hash::Hash};
+use crate::{
+    transfer::{Configuration, TransferPost},
+    wallet::signer::SyncData,
+};
+use alloc::vec::Vec;
+use core::{fmt::Debug, hash::Hash, time::Duration};
 use manta_util::future::LocalBoxFutureResult;
 
 #[cfg(feature = "serde")]
@@ -39,6 +50,120 @@ pub trait Connection {
     type Error;
 }
 
+/// Ledger Connection Configuration
+///
+/// Ledger connections which pull data in pages from a remote node implement this `trait` to let
+/// the integrator tune the [`SyncConfig`] for slow RPC nodes or rate-limited public endpoints.
+pub trait Configure: Connection {
+    /// Returns the [`SyncConfig`] currently used by `self`.
+    fn sync_config(&self) -> &SyncConfig;
+
+    /// Replaces the [`SyncConfig`] used by `self` with `config`.
+    fn set_sync_config(&mut self, config: SyncConfig);
+}
+
+/// Retry Policy
+///
+/// Failed ledger requests are retried at most [`max_retries`](Self::max_retries) times, waiting
+/// for an exponentially growing backoff between attempts.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum Number of Retries after the First Attempt
+    pub max_retries: usize,
+
+    /// Backoff before the First Retry in Milliseconds
+    pub initial_backoff_ms: u64,
+
+    /// Upper Bound on the Backoff in Milliseconds
+    pub max_backoff_ms: u64,
+}
+
+impl RetryPolicy {
+    /// Retry Policy which never retries a failed request
+    pub const NEVER: Self = Self {
+        max_retries: 0,
+        initial_backoff_ms: 0,
+        max_backoff_ms: 0,
+    };
+
+    /// Returns the time to wait before retrying a request which has already failed `failures`
+    /// times, or `None` if the request should not be retried anymore.
+    #[inline]
+    pub fn backoff(&self, failures: usize) -> Option<Duration> {
+        if failures == 0 || failures > self.max_retries {
+            return None;
+        }
+        let factor = 1u64
+            .checked_shl((failures - 1).min(u32::MAX as usize) as u32)
+            .unwrap_or(u64::MAX);
+        Some(Duration::from_millis(
+            self.initial_backoff_ms
+                .saturating_mul(factor)
+                .min(self.max_backoff_ms),
+        ))
+    }
+}
+
+impl Default for RetryPolicy {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 8_000,
+        }
+    }
+}
+
+/// Ledger Synchronization Configuration
+///
+/// See [`Configure`] for the ledger connections which support it.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SyncConfig {
+    /// Maximum Number of UTXOs and of Nullifiers returned by a Single [`read`](Read::read)
+    ///
+    /// When the ledger has more data past the requested checkpoint, the
+    /// [`ReadResponse::should_continue`] flag is set so that the wallet pulls the next page.
+    pub batch_size: usize,
+
+    /// Maximum Number of Requests in Flight at the same Time
+    ///
+    /// This bound is shared between all the clones of the same ledger connection.
+    pub max_in_flight: usize,
+
+    /// Retry Policy for Failed Requests
+    pub retry: RetryPolicy,
+}
+
+impl SyncConfig {
+    /// Default Batch Size
+    pub const DEFAULT_BATCH_SIZE: usize = 4096;
+
+    /// Default Maximum Number of Requests in Flight
+    pub const DEFAULT_MAX_IN_FLIGHT: usize = 4;
+}
+
+impl Default for SyncConfig {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            max_in_flight: Self::DEFAULT_MAX_IN_FLIGHT,
+            retry: Default::default(),
+        }
+    }
+}
+
 /// Ledger Checkpoint
 ///
 /// The checkpoint type is responsible for keeping the ledger, signer, and wallet in sync with each
@@ -106,6 +231,13 @@ pub struct ReadResponse<D> {
     /// This is the data payload that was returned by the ledger corresponding to the
     /// [`read`](Read::read) request.
     pub data: D,
+
+    /// Block Height
+    ///
+    /// This is the height of the ledger at the end of the [`data`](Self::data), or `None` if the
+    /// ledger does not report block heights.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub height: Option<u64>,
 }
 
 /// Ledger Connection Writing
@@ -122,3 +254,65 @@ pub trait Write<R>: Connection {
     /// Sends the `request` to the ledger, returning its [`Response`](Self::Response).
     fn write(&mut self, request: R) -> LocalBoxFutureResult<Self::Response, Self::Error>;
 }
+
+/// Ledger Connection Nullifier Subscription
+///
+/// Ledgers which broadcast the nullifiers of the transactions they observe implement this `trait`
+/// so that the signer can detect spent notes as soon as the ledger sees them, without waiting for
+/// the next [`read`](Read::read). The nullifiers are delivered again by [`read`](Read::read)
+/// once they are part of the ledger data.
+pub trait SubscribeNullifiers<N>: Connection {
+    /// Subscribes `self` to the nullifiers observed by the ledger from now on. Subscribing again
+    /// drops the nullifiers which were not yet returned by [`next_nullifiers`].
+    ///
+    /// [`next_nullifiers`]: Self::next_nullifiers
+    fn subscribe_nullifiers(&mut self) -> LocalBoxFutureResult<'_, (), Self::Error>;
+
+    /// Returns the nullifiers observed by the ledger since the last call to this method, or since
+    /// the subscription for the first call. The returned vector is empty if no nullifier was
+    /// observed or if `self` is not subscribed.
+    fn next_nullifiers(&mut self) -> LocalBoxFutureResult<'_, Vec<N>, Self::Error>;
+}
+
+/// Ledger
+///
+/// This `trait` is an alias for the [`Read`] and [`Write`] requirements of a ledger connection
+/// which pulls [`SyncData`] and accepts [`TransferPost`]s.
+pub trait Ledger<C>: Read<SyncData<C>> + Write<PushRequest<C>>
+where
+    C: Configuration + ?Sized,
+{
+}
+
+impl<C, L> Ledger<C> for L
+where
+    C: Configuration + ?Sized,
+    L: Read<SyncData<C>> + Write<PushRequest<C>>,
+{
+}
+
+/// Ledger Pull Request
+///
+/// This is the message sent to a remote ledger to [`read`](Read::read) the data after
+/// [`checkpoint`](Self::checkpoint).
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PullRequest<T> {
+    /// Checkpoint to Pull the Data from
+    pub checkpoint: T,
+
+    /// Maximum Number of UTXOs and of Nullifiers to Return
+    ///
+    /// See [`SyncConfig::batch_size`] for more.
+    pub batch_size: usize,
+}
+
+/// Ledger Pull Response
+pub type PullResponse<C> = ReadResponse<SyncData<C>>;
+
+/// Ledger Push Request
+pub type PushRequest<C> = Vec<TransferPost<C>>;
@@ -34,8 +34,9 @@ use crate::{
         Address, Asset, Configuration, IdentifiedAsset, TransferPost, UtxoAccumulatorModel,
     },
     wallet::{
-        balance::{BTreeMapBalanceState, BalanceState},
+        balance::{BTreeMapBalanceState, BalanceHistory, BalanceState},
         ledger::ReadResponse,
+        report::AccountingReport,
         signer::{
             BalanceUpdate, Checkpoint, ConsolidationPrerequest, IdentityRequest, IdentityResponse,
             InitialSyncData, InitialSyncRequest, SignError, SignRequest, SignResponse,
@@ -46,13 +47,14 @@ use crate::{
 };
 use alloc::vec::Vec;
 use core::{fmt::Debug, hash::Hash, marker::PhantomData, ops::AddAssign};
-use manta_util::ops::ControlFlow;
+use manta_util::{num::CheckedSub, ops::ControlFlow};
 
 #[cfg(feature = "serde")]
 use manta_util::serde::{Deserialize, Serialize};
 
 pub mod balance;
 pub mod ledger;
+pub mod report;
 pub mod signer;
 
 #[cfg(feature = "test")]
@@ -65,8 +67,8 @@ pub mod test;
     derive(Deserialize, Serialize),
     serde(
         bound(
-            deserialize = "L: Deserialize<'de>, S::Checkpoint: Deserialize<'de>, S: Deserialize<'de>, B: Deserialize<'de>",
-            serialize = "L: Serialize, S::Checkpoint: Serialize, S: Serialize, B: Serialize",
+            deserialize = "L: Deserialize<'de>, S::Checkpoint: Deserialize<'de>, S: Deserialize<'de>, B: Deserialize<'de>, C::AssetId: Deserialize<'de>, C::AssetValue: Deserialize<'de>",
+            serialize = "L: Serialize, S::Checkpoint: Serialize, S: Serialize, B: Serialize, C::AssetId: Serialize, C::AssetValue: Serialize",
         ),
         crate = "manta_util::serde",
         deny_unknown_fields
@@ -75,11 +77,14 @@ pub mod test;
 #[derive(derivative::Derivative)]
 #[derivative(
     Clone(bound = "L: Clone, S::Checkpoint: Clone, S: Clone, B: Clone"),
-    Copy(bound = "L: Copy, S::Checkpoint: Copy, S: Copy, B: Copy"),
-    Debug(bound = "L: Debug, S::Checkpoint: Debug, S: Debug, B: Debug"),
+    Debug(
+        bound = "L: Debug, S::Checkpoint: Debug, S: Debug, B: Debug, C::AssetId: Debug, C::AssetValue: Debug"
+    ),
     Default(bound = "L: Default, S::Checkpoint: Default, S: Default, B: Default"),
-    Eq(bound = "L: Eq, S::Checkpoint: Eq, S: Eq, B: Eq"),
-    Hash(bound = "L: Hash, S::Checkpoint: Hash, S: Hash, B: Hash"),
+    Eq(bound = "L: Eq, S::Checkpoint: Eq, S: Eq, B: Eq, C::AssetValue: Eq"),
+    Hash(
+        bound = "L: Hash, S::Checkpoint: Hash, S: Hash, B: Hash, C::AssetId: Hash, C::AssetValue: Hash"
+    ),
     PartialEq(bound = "L: PartialEq, S::Checkpoint: PartialEq, S: PartialEq, B: PartialEq")
 )]
 pub struct Wallet<
@@ -105,6 +110,13 @@ pub struct Wallet<
     /// Balance State
     assets: B,
 
+    /// Balance History
+    ///
+    /// Snapshots of the balance state at every synchronized checkpoint, which are only recorded
+    /// after calling [`enable_balance_history`](Self::enable_balance_history).
+    #[cfg_attr(feature = "serde", serde(default))]
+    balance_history: Option<BalanceHistory<S::Checkpoint, C::AssetId, C::AssetValue>>,
+
     /// Type Parameter Marker
     __: PhantomData<C>,
 }
@@ -125,6 +137,7 @@ where
             checkpoint,
             signer,
             assets,
+            balance_history: None,
             __: PhantomData,
         }
     }
@@ -178,6 +191,9 @@ where
     pub fn reset_state(&mut self) {
         self.checkpoint = Default::default();
         self.assets = Default::default();
+        if let Some(history) = &mut self.balance_history {
+            history.clear();
+        }
     }
 
     /// Returns the current balance associated with this `id`.
@@ -212,12 +228,96 @@ where
         &self.assets
     }
 
+    /// Starts recording a snapshot of the balance state at every checkpoint `self` synchronizes
+    /// to, beginning with the current one. Snapshots can be queried with
+    /// [`balance_as_of`](Self::balance_as_of).
+    #[inline]
+    pub fn enable_balance_history(&mut self) {
+        if self.balance_history.is_none() {
+            let mut history = BalanceHistory::new();
+            history.record(self.checkpoint.clone(), &self.assets);
+            self.balance_history = Some(history);
+        }
+    }
+
+    /// Stops recording balance snapshots and drops the ones which were already recorded.
+    #[inline]
+    pub fn disable_balance_history(&mut self) {
+        self.balance_history = None;
+    }
+
+    /// Returns the [`BalanceHistory`] of `self` if it has been enabled.
+    #[inline]
+    pub fn balance_history(
+        &self,
+    ) -> Option<&BalanceHistory<S::Checkpoint, C::AssetId, C::AssetValue>> {
+        self.balance_history.as_ref()
+    }
+
+    /// Returns a mutable reference to the [`BalanceHistory`] of `self` if it has been enabled, for
+    /// example to prune old snapshots.
+    #[inline]
+    pub fn balance_history_mut(
+        &mut self,
+    ) -> Option<&mut BalanceHistory<S::Checkpoint, C::AssetId, C::AssetValue>> {
+        self.balance_history.as_mut()
+    }
+
+    /// Returns the balance associated with this `id` as of `checkpoint`, or `None` if the balance
+    /// history is disabled or does not go back as far as `checkpoint`.
+    ///
+    /// Ledgers which index their data by block height can translate a height into the
+    /// [`Checkpoint`](ledger::Checkpoint) of the data up to that height to answer "balance as
+    /// of height" queries.
+    #[inline]
+    pub fn balance_as_of(
+        &self,
+        checkpoint: &S::Checkpoint,
+        id: &C::AssetId,
+    ) -> Option<C::AssetValue> {
+        self.balance_history.as_ref()?.balance_at(checkpoint, id)
+    }
+
+    /// Returns the [`AccountingReport`] of the balance changes recorded in the balance history of
+    /// `self`, or `None` if the balance history is disabled.
+    #[inline]
+    pub fn accounting_report(
+        &self,
+    ) -> Option<AccountingReport<S::Checkpoint, C::AssetId, C::AssetValue>>
+    where
+        for<'v> &'v C::AssetValue: CheckedSub<Output = C::AssetValue>,
+    {
+        Some(AccountingReport::from_history(
+            self.balance_history.as_ref()?,
+        ))
+    }
+
     /// Returns a shared reference to the ledger connection associated to `self`.
     #[inline]
     pub fn ledger(&self) -> &L {
         &self.ledger
     }
 
+    /// Returns the [`SyncConfig`](ledger::SyncConfig) of the ledger connection associated to
+    /// `self`.
+    #[inline]
+    pub fn sync_config(&self) -> &ledger::SyncConfig
+    where
+        L: ledger::Configure,
+    {
+        self.ledger.sync_config()
+    }
+
+    /// Replaces the [`SyncConfig`](ledger::SyncConfig) of the ledger connection associated to
+    /// `self` with `config`, which is used starting from the next synchronization.
+    #[inline]
+    pub fn set_sync_config(&mut self, config: ledger::SyncConfig)
+    where
+        L: ledger::Configure,
+    {
+        self.ledger.set_sync_config(config)
+    }
+
     /// Returns the [`Checkpoint`](ledger::Checkpoint) representing the current state of this
     /// wallet.
     #[inline]
@@ -339,6 +439,7 @@ where
             let ReadResponse {
                 should_continue,
                 data,
+                ..
             } = self.read_from_ledger(&checkpoint).await?;
             let more = InitialSyncRequest::from_initial_sync_data(&parameters, data);
             is_continue = should_continue;
@@ -412,10 +513,12 @@ where
         let ReadResponse {
             should_continue,
             data,
+            height,
         } = self.read_from_ledger(&self.checkpoint.clone()).await?;
         self.signer_sbt_sync(SyncRequest {
             origin_checkpoint: self.checkpoint.clone(),
             data,
+            height,
         })
         .await?;
         Ok(ControlFlow::should_continue(should_continue))
@@ -430,10 +533,12 @@ where
         let ReadResponse {
             should_continue,
             data,
+            height,
         } = self.read_from_ledger(&self.checkpoint.clone()).await?;
         self.signer_sync(SyncRequest {
             origin_checkpoint: self.checkpoint.clone(),
             data,
+            height,
         })
         .await?;
         Ok(ControlFlow::should_continue(should_continue))
@@ -462,11 +567,17 @@ where
                         self.assets.deposit_all(assets);
                     }
                 }
+                if let Some(history) = &mut self.balance_history {
+                    history.record(checkpoint.clone(), &self.assets);
+                }
                 self.checkpoint = checkpoint;
                 Ok(())
             }
             Err(SyncError::InconsistentSynchronization { checkpoint }) => {
                 if checkpoint < self.checkpoint {
+                    if let Some(history) = &mut self.balance_history {
+                        history.rollback(&checkpoint);
+                    }
                     self.checkpoint = checkpoint;
                 }
                 Err(Error::Inconsistency(
@@ -558,6 +669,7 @@ where
             .sign(SignRequest {
                 transaction,
                 metadata,
+                recipient_name: None,
             })
             .await
             .map_err(Error::SignerConnectionError)?
@@ -704,6 +816,7 @@ where
             .sign_with_transaction_data(SignRequest {
                 transaction,
                 metadata,
+                recipient_name: None,
             })
             .await
             .map_err(Error::SignerConnectionError)?
@@ -0,0 +1,321 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Accounting Reports
+//!
+//! Bookkeeping reports built from the [`BalanceHistory`] of a wallet, listing every balance change
+//! of every asset with its direction and the running balance after it. Reports can be written as
+//! CSV with [`AccountingReport::write_csv`] or as JSON through their `serde` implementation.
+
+use crate::wallet::balance::BalanceHistory;
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display, Write};
+use manta_util::num::CheckedSub;
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Direction of a Balance Change
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    /// Balance Increase
+    Incoming,
+
+    /// Balance Decrease
+    Outgoing,
+}
+
+impl Direction {
+    /// Returns the name of `self` as written in reports.
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Incoming => "incoming",
+            Self::Outgoing => "outgoing",
+        }
+    }
+}
+
+impl Display for Direction {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Report Entry
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ReportEntry<K, I, V> {
+    /// Checkpoint at which the Balance Changed
+    pub checkpoint: K,
+
+    /// Asset Id
+    pub asset_id: I,
+
+    /// Direction of the Change
+    pub direction: Direction,
+
+    /// Amount of the Change
+    pub amount: V,
+
+    /// Balance after the Change
+    pub balance: V,
+
+    /// Counterparty, if Disclosed
+    pub counterparty: Option<String>,
+}
+
+/// Accounting Report
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct AccountingReport<K, I, V> {
+    /// Opening Checkpoint and Balances
+    ///
+    /// The balances in the first snapshot of the history, which are not reported as changes.
+    opening: Option<(K, Vec<(I, V)>)>,
+
+    /// Balance Changes Ordered by Checkpoint and Asset Id
+    entries: Vec<ReportEntry<K, I, V>>,
+}
+
+impl<K, I, V> AccountingReport<K, I, V> {
+    /// Builds an [`AccountingReport`] from `history`, with one entry for every asset whose balance
+    /// changed between two consecutive snapshots.
+    #[inline]
+    pub fn from_history(history: &BalanceHistory<K, I, V>) -> Self
+    where
+        K: Clone + PartialOrd,
+        I: Clone + Ord,
+        V: Clone + Default + PartialOrd,
+        for<'v> &'v V: CheckedSub<Output = V>,
+    {
+        let mut snapshots = history.iter();
+        let (opening_checkpoint, mut previous) = match snapshots.next() {
+            Some((checkpoint, balances)) => (checkpoint, balances),
+            _ => {
+                return Self {
+                    opening: None,
+                    entries: Vec::new(),
+                }
+            }
+        };
+        let opening = (
+            opening_checkpoint.clone(),
+            previous
+                .iter()
+                .map(|(id, value)| (id.clone(), value.clone()))
+                .collect(),
+        );
+        let mut entries = Vec::new();
+        for (checkpoint, balances) in snapshots {
+            let mut ids = previous.keys().chain(balances.keys()).collect::<Vec<_>>();
+            ids.sort();
+            ids.dedup();
+            for id in ids {
+                let zero = V::default();
+                let before = previous.get(id).unwrap_or(&zero);
+                let after = balances.get(id).unwrap_or(&zero);
+                let (direction, amount) = if after > before {
+                    (Direction::Incoming, after.checked_sub(before))
+                } else if after < before {
+                    (Direction::Outgoing, before.checked_sub(after))
+                } else {
+                    continue;
+                };
+                entries.push(ReportEntry {
+                    checkpoint: checkpoint.clone(),
+                    asset_id: id.clone(),
+                    direction,
+                    amount: amount.expect("The smaller balance is subtracted from the larger one."),
+                    balance: after.clone(),
+                    counterparty: None,
+                });
+            }
+            previous = balances;
+        }
+        Self {
+            opening: Some(opening),
+            entries,
+        }
+    }
+
+    /// Returns the opening checkpoint and balances of `self`, or `None` if the history it was
+    /// built from was empty.
+    #[inline]
+    pub fn opening(&self) -> Option<(&K, &[(I, V)])> {
+        self.opening
+            .as_ref()
+            .map(|(checkpoint, balances)| (checkpoint, balances.as_slice()))
+    }
+
+    /// Returns the entries of `self` ordered by checkpoint and asset id.
+    #[inline]
+    pub fn entries(&self) -> &[ReportEntry<K, I, V>] {
+        &self.entries
+    }
+
+    /// Returns an iterator over the entries of `self` for the asset with `asset_id`.
+    #[inline]
+    pub fn entries_for<'s>(
+        &'s self,
+        asset_id: &'s I,
+    ) -> impl Iterator<Item = &'s ReportEntry<K, I, V>> + 's
+    where
+        I: PartialEq,
+    {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.asset_id == *asset_id)
+    }
+
+    /// Records `counterparty` on the entry of the asset with `asset_id` at `checkpoint`, returning
+    /// `false` if there is no such entry.
+    #[inline]
+    pub fn disclose(&mut self, checkpoint: &K, asset_id: &I, counterparty: String) -> bool
+    where
+        K: PartialEq,
+        I: PartialEq,
+    {
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.checkpoint == *checkpoint && entry.asset_id == *asset_id)
+        {
+            Some(entry) => {
+                entry.counterparty = Some(counterparty);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Maps the checkpoints, asset ids and values of `self` with `checkpoint`, `asset_id` and
+    /// `value`, for instance to replace checkpoints with dates and asset ids with symbols before
+    /// writing the report.
+    #[inline]
+    pub fn map<L, J, W, FK, FI, FV>(
+        self,
+        mut checkpoint: FK,
+        mut asset_id: FI,
+        mut value: FV,
+    ) -> AccountingReport<L, J, W>
+    where
+        FK: FnMut(K) -> L,
+        FI: FnMut(I) -> J,
+        FV: FnMut(V) -> W,
+    {
+        AccountingReport {
+            opening: self.opening.map(|(k, balances)| {
+                (
+                    checkpoint(k),
+                    balances
+                        .into_iter()
+                        .map(|(id, v)| (asset_id(id), value(v)))
+                        .collect(),
+                )
+            }),
+            entries: self
+                .entries
+                .into_iter()
+                .map(|entry| ReportEntry {
+                    checkpoint: checkpoint(entry.checkpoint),
+                    asset_id: asset_id(entry.asset_id),
+                    direction: entry.direction,
+                    amount: value(entry.amount),
+                    balance: value(entry.balance),
+                    counterparty: entry.counterparty,
+                })
+                .collect(),
+        }
+    }
+
+    /// Writes the entries of `self` to `writer` as CSV, with a header row.
+    #[inline]
+    pub fn write_csv<W>(&self, writer: &mut W) -> fmt::Result
+    where
+        K: Display,
+        I: Display,
+        V: Display,
+        W: Write,
+    {
+        writeln!(
+            writer,
+            "checkpoint,asset_id,direction,amount,balance,counterparty"
+        )?;
+        for entry in &self.entries {
+            write_csv_field(writer, &entry.checkpoint)?;
+            writer.write_char(',')?;
+            write_csv_field(writer, &entry.asset_id)?;
+            write!(writer, ",{},", entry.direction)?;
+            write_csv_field(writer, &entry.amount)?;
+            writer.write_char(',')?;
+            write_csv_field(writer, &entry.balance)?;
+            writer.write_char(',')?;
+            if let Some(counterparty) = &entry.counterparty {
+                write_csv_field(writer, counterparty)?;
+            }
+            writer.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    /// Returns the entries of `self` as CSV, with a header row. See [`write_csv`](Self::write_csv)
+    /// for more.
+    #[inline]
+    pub fn to_csv(&self) -> String
+    where
+        K: Display,
+        I: Display,
+        V: Display,
+    {
+        let mut csv = String::new();
+        self.write_csv(&mut csv)
+            .expect("Writing to a string is not allowed to fail.");
+        csv
+    }
+}
+
+/// Writes `field` to `writer`, quoting it if it contains a comma, a quote or a line break.
+#[inline]
+fn write_csv_field<W, T>(writer: &mut W, field: &T) -> fmt::Result
+where
+    W: Write,
+    T: Display + ?Sized,
+{
+    let mut buffer = String::new();
+    write!(buffer, "{field}")?;
+    if buffer.contains([',', '"', '\n', '\r']) {
+        writer.write_char('"')?;
+        writer.write_str(&buffer.replace('"', "\"\""))?;
+        writer.write_char('"')
+    } else {
+        writer.write_str(&buffer)
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Air-Gapped Signing
+//!
+//! An online device holding only the [`AuthorizationContext`](transfer::AuthorizationContext)
+//! builds and proves transfers as [`UnsignedTransferPost`]s and sends them to an offline device
+//! holding the spending key in an [`AirGapRequest`]. The offline device returns the authorization
+//! signatures in an [`AirGapResponse`], which the online device attaches to finish the
+//! [`TransferPost`]s. The serialized messages are carried across the air gap as a sequence of
+//! bounded [`Frame`]s, for instance one per QR code, built with [`split`] and joined back with a
+//! [`Reassembler`] in any order.
+
+use crate::transfer::{
+    self, Authorization, AuthorizationSignature, Parameters, SpendingKey, TransferPost,
+    UnsignedTransferPost,
+};
+use alloc::vec::Vec;
+use core::{fmt::Debug, hash::Hash};
+use manta_crypto::rand::{CryptoRng, RngCore};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Air-Gap Request
+///
+/// Transfers proven by the online device which are waiting for their authorization signatures.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "UnsignedTransferPost<C>: Deserialize<'de>",
+            serialize = "UnsignedTransferPost<C>: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields,
+        transparent
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "UnsignedTransferPost<C>: Clone"),
+    Debug(bound = "UnsignedTransferPost<C>: Debug"),
+    Default(bound = ""),
+    Eq(bound = "UnsignedTransferPost<C>: Eq"),
+    Hash(bound = "UnsignedTransferPost<C>: Hash"),
+    PartialEq(bound = "UnsignedTransferPost<C>: PartialEq")
+)]
+pub struct AirGapRequest<C>(pub Vec<UnsignedTransferPost<C>>)
+where
+    C: transfer::Configuration;
+
+impl<C> AirGapRequest<C>
+where
+    C: transfer::Configuration,
+{
+    /// Signs every post in `self` with `spending_key`. Posts whose authorization was not derived
+    /// from `spending_key` are left unsigned.
+    #[inline]
+    pub fn sign<R>(
+        &self,
+        parameters: &Parameters<C>,
+        spending_key: &SpendingKey<C>,
+        rng: &mut R,
+    ) -> AirGapResponse<C>
+    where
+        Authorization<C>: Clone,
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        AirGapResponse(
+            self.0
+                .iter()
+                .map(|post| post.sign(parameters, spending_key, rng))
+                .collect(),
+        )
+    }
+
+    /// Attaches the signatures in `response` to the posts in `self`, returning `None` if
+    /// `response` does not cover every post in `self` or if any signature is invalid.
+    #[inline]
+    pub fn finish(
+        self,
+        parameters: &Parameters<C>,
+        response: AirGapResponse<C>,
+    ) -> Option<Vec<TransferPost<C>>> {
+        if self.0.len() != response.0.len() {
+            return None;
+        }
+        self.0
+            .into_iter()
+            .zip(response.0)
+            .map(|(post, signature)| post.into_post(parameters, signature?))
+            .collect()
+    }
+}
+
+/// Air-Gap Response
+///
+/// Authorization signatures returned by the offline device, in the order of the posts in the
+/// [`AirGapRequest`]. Posts which could not be signed are marked with `None`.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "AuthorizationSignature<C>: Deserialize<'de>",
+            serialize = "AuthorizationSignature<C>: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields,
+        transparent
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "AuthorizationSignature<C>: Clone"),
+    Debug(bound = "AuthorizationSignature<C>: Debug"),
+    Default(bound = ""),
+    Eq(bound = "AuthorizationSignature<C>: Eq"),
+    Hash(bound = "AuthorizationSignature<C>: Hash"),
+    PartialEq(bound = "AuthorizationSignature<C>: PartialEq")
+)]
+pub struct AirGapResponse<C>(pub Vec<Option<AuthorizationSignature<C>>>)
+where
+    C: transfer::Configuration;
+
+/// Size of the Frame Header in Bytes
+///
+/// The header holds the message checksum, the frame index and the frame count.
+pub const FRAME_HEADER_SIZE: usize = 8;
+
+/// Size of the Frame Checksum in Bytes
+pub const FRAME_CHECKSUM_SIZE: usize = 4;
+
+/// Frame Error
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FrameError {
+    /// Frame Size Too Small
+    ///
+    /// The maximum frame size leaves no room for the payload.
+    FrameSizeTooSmall,
+
+    /// Message Too Large
+    ///
+    /// The message does not fit in [`u16::MAX`] frames of the requested size.
+    MessageTooLarge,
+
+    /// Truncated Frame
+    Truncated,
+
+    /// Invalid Frame Checksum
+    InvalidFrameChecksum,
+
+    /// Invalid Frame Index
+    InvalidIndex,
+
+    /// Frame Belongs to a Different Message
+    MessageMismatch,
+
+    /// Missing Frames
+    Incomplete,
+
+    /// Invalid Message Checksum
+    InvalidMessageChecksum,
+}
+
+/// Frame
+///
+/// Chunk of a message together with the information needed to reassemble the message.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Frame {
+    /// Checksum of the Whole Message
+    pub message_checksum: u32,
+
+    /// Frame Index
+    pub index: u16,
+
+    /// Frame Count
+    pub count: u16,
+
+    /// Payload
+    pub payload: Vec<u8>,
+}
+
+impl Frame {
+    /// Encodes `self` as bytes, appending a checksum over the header and the payload.
+    #[inline]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(FRAME_HEADER_SIZE + self.payload.len() + FRAME_CHECKSUM_SIZE);
+        bytes.extend_from_slice(&self.message_checksum.to_be_bytes());
+        bytes.extend_from_slice(&self.index.to_be_bytes());
+        bytes.extend_from_slice(&self.count.to_be_bytes());
+        bytes.extend_from_slice(&self.payload);
+        bytes.extend_from_slice(&crc32(&bytes).to_be_bytes());
+        bytes
+    }
+
+    /// Decodes a [`Frame`] from `bytes`, checking its checksum and its index.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrameError> {
+        if bytes.len() < FRAME_HEADER_SIZE + FRAME_CHECKSUM_SIZE {
+            return Err(FrameError::Truncated);
+        }
+        let (body, checksum) = bytes.split_at(bytes.len() - FRAME_CHECKSUM_SIZE);
+        if crc32(body).to_be_bytes() != checksum {
+            return Err(FrameError::InvalidFrameChecksum);
+        }
+        let frame = Self {
+            message_checksum: u32::from_be_bytes([body[0], body[1], body[2], body[3]]),
+            index: u16::from_be_bytes([body[4], body[5]]),
+            count: u16::from_be_bytes([body[6], body[7]]),
+            payload: body[FRAME_HEADER_SIZE..].to_vec(),
+        };
+        if frame.index >= frame.count {
+            return Err(FrameError::InvalidIndex);
+        }
+        Ok(frame)
+    }
+}
+
+/// Splits `message` into [`Frame`]s which encode to at most `max_frame_size` bytes each.
+#[inline]
+pub fn split(message: &[u8], max_frame_size: usize) -> Result<Vec<Frame>, FrameError> {
+    let payload_size = max_frame_size
+        .checked_sub(FRAME_HEADER_SIZE + FRAME_CHECKSUM_SIZE)
+        .filter(|size| *size > 0)
+        .ok_or(FrameError::FrameSizeTooSmall)?;
+    let count = u16::try_from(message.len().div_ceil(payload_size).max(1))
+        .map_err(|_| FrameError::MessageTooLarge)?;
+    let message_checksum = crc32(message);
+    if message.is_empty() {
+        return Ok(Vec::from([Frame {
+            message_checksum,
+            index: 0,
+            count,
+            payload: Vec::new(),
+        }]));
+    }
+    Ok(message
+        .chunks(payload_size)
+        .enumerate()
+        .map(|(index, payload)| Frame {
+            message_checksum,
+            index: index as u16,
+            count,
+            payload: payload.to_vec(),
+        })
+        .collect())
+}
+
+/// Frame Reassembler
+///
+/// Collects the [`Frame`]s of a single message in any order, ignoring duplicates.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Reassembler {
+    /// Checksum of the Message being Reassembled
+    message_checksum: Option<u32>,
+
+    /// Received Payloads
+    payloads: Vec<Option<Vec<u8>>>,
+
+    /// Number of Received Frames
+    received: usize,
+}
+
+impl Reassembler {
+    /// Builds a new empty [`Reassembler`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `frame` to `self`, returning `true` once every frame of the message was received.
+    #[inline]
+    pub fn push(&mut self, frame: Frame) -> Result<bool, FrameError> {
+        if frame.index >= frame.count {
+            return Err(FrameError::InvalidIndex);
+        }
+        match self.message_checksum {
+            Some(checksum) => {
+                if checksum != frame.message_checksum
+                    || self.payloads.len() != usize::from(frame.count)
+                {
+                    return Err(FrameError::MessageMismatch);
+                }
+            }
+            None => {
+                self.message_checksum = Some(frame.message_checksum);
+                self.payloads = (0..frame.count).map(|_| None).collect();
+            }
+        }
+        let slot = &mut self.payloads[usize::from(frame.index)];
+        if slot.is_none() {
+            *slot = Some(frame.payload);
+            self.received += 1;
+        }
+        Ok(self.is_complete())
+    }
+
+    /// Decodes a [`Frame`] from `bytes` and adds it to `self`. See [`push`](Self::push) for more.
+    #[inline]
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<bool, FrameError> {
+        self.push(Frame::from_bytes(bytes)?)
+    }
+
+    /// Returns the number of distinct frames received so far.
+    #[inline]
+    pub fn received(&self) -> usize {
+        self.received
+    }
+
+    /// Returns the number of frames in the message, if any frame was received.
+    #[inline]
+    pub fn count(&self) -> Option<usize> {
+        self.message_checksum.map(|_| self.payloads.len())
+    }
+
+    /// Returns `true` if every frame of the message was received.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.message_checksum.is_some() && self.received == self.payloads.len()
+    }
+
+    /// Joins the received frames back into the message, checking it against the message
+    /// checksum.
+    #[inline]
+    pub fn finish(self) -> Result<Vec<u8>, FrameError> {
+        let message_checksum = self.message_checksum.ok_or(FrameError::Incomplete)?;
+        let mut message = Vec::new();
+        for payload in self.payloads {
+            message.extend(payload.ok_or(FrameError::Incomplete)?);
+        }
+        if crc32(&message) != message_checksum {
+            return Err(FrameError::InvalidMessageChecksum);
+        }
+        Ok(message)
+    }
+}
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of `bytes`.
+#[inline]
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
@@ -0,0 +1,220 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Self-Churn Scheduling
+//!
+//! A [`ChurnPolicy`] makes the [`Signer`](super::Signer) issue private transfers to itself at
+//! randomized times, so that the timing of its transactions says less about the real activity of
+//! its owner. The signer never acts on its own: the host polls it periodically with
+//! [`Signer::churn`](super::Signer::churn) and posts the transfers it returns. The number of
+//! self-transfers is limited per day and over the lifetime of the policy.
+
+use crate::{
+    transfer::{self, Asset},
+    wallet::signer::policy::SECONDS_PER_DAY,
+};
+use core::{fmt::Debug, hash::Hash};
+use manta_crypto::rand::RngCore;
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Returns the number of seconds elapsed since the Unix epoch according to the system clock.
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[inline]
+pub fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// Churn Policy
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ChurnPolicy<I, V> {
+    /// Asset Id of the Self-Transfers
+    pub id: I,
+
+    /// Value of each Self-Transfer
+    pub value: V,
+
+    /// Minimum Number of Seconds between two Self-Transfers
+    pub min_interval: u64,
+
+    /// Maximum Number of Seconds between two Self-Transfers
+    pub max_interval: u64,
+
+    /// Maximum Number of Self-Transfers per Day
+    pub per_day: u32,
+
+    /// Maximum Total Number of Self-Transfers
+    ///
+    /// The policy is exhausted once this many self-transfers were signed. Policies without a
+    /// budget never run out.
+    pub budget: Option<u32>,
+}
+
+impl<I, V> ChurnPolicy<I, V> {
+    /// Samples the number of seconds until the next self-transfer, uniformly between
+    /// [`min_interval`](Self::min_interval) and [`max_interval`](Self::max_interval).
+    #[inline]
+    pub fn sample_interval<R>(&self, rng: &mut R) -> u64
+    where
+        R: RngCore + ?Sized,
+    {
+        let max = self.max_interval.max(self.min_interval);
+        match (max - self.min_interval).checked_add(1) {
+            Some(span) => self.min_interval + rng.next_u64() % span,
+            _ => rng.next_u64(),
+        }
+    }
+}
+
+/// Churn Schedule
+///
+/// Stores the [`ChurnPolicy`] of a signer along with the time of its next self-transfer and the
+/// number of self-transfers it already signed, so that the limits survive restarts when persisted
+/// with the signer state.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "C::AssetId: Deserialize<'de>, C::AssetValue: Deserialize<'de>",
+            serialize = "C::AssetId: Serialize, C::AssetValue: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "C::AssetId: Clone, C::AssetValue: Clone"),
+    Debug(bound = "C::AssetId: Debug, C::AssetValue: Debug"),
+    Default(bound = ""),
+    Eq(bound = "C::AssetId: Eq, C::AssetValue: Eq"),
+    Hash(bound = "C::AssetId: Hash, C::AssetValue: Hash"),
+    PartialEq(bound = "C::AssetId: PartialEq, C::AssetValue: PartialEq")
+)]
+pub struct ChurnSchedule<C>
+where
+    C: transfer::Configuration,
+{
+    /// Churn Policy
+    policy: Option<ChurnPolicy<C::AssetId, C::AssetValue>>,
+
+    /// Time of the Next Self-Transfer
+    ///
+    /// Counted in seconds since the Unix epoch, or `None` until the first poll of the policy.
+    next: Option<u64>,
+
+    /// Current Day
+    day: u64,
+
+    /// Number of Self-Transfers Signed on the Current Day
+    today: u32,
+
+    /// Number of Self-Transfers Signed under the Current Policy
+    total: u32,
+}
+
+impl<C> ChurnSchedule<C>
+where
+    C: transfer::Configuration,
+{
+    /// Returns the churn policy of `self`, if any.
+    #[inline]
+    pub fn policy(&self) -> Option<&ChurnPolicy<C::AssetId, C::AssetValue>> {
+        self.policy.as_ref()
+    }
+
+    /// Replaces the churn policy of `self` with `policy`, disabling self-transfers if `policy` is
+    /// `None`. The budget of the new policy starts from zero and its first self-transfer is
+    /// scheduled at the next poll, while the self-transfers signed today still count towards the
+    /// daily limit.
+    #[inline]
+    pub fn set_policy(&mut self, policy: Option<ChurnPolicy<C::AssetId, C::AssetValue>>) {
+        self.policy = policy;
+        self.next = None;
+        self.total = 0;
+    }
+
+    /// Returns the time of the next self-transfer in seconds since the Unix epoch, if one is
+    /// scheduled.
+    #[inline]
+    pub fn next(&self) -> Option<u64> {
+        self.next.filter(|_| !self.is_exhausted())
+    }
+
+    /// Returns the number of self-transfers signed under the current policy.
+    #[inline]
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+
+    /// Returns `true` if there is no policy or if the current policy has used up its budget.
+    #[inline]
+    pub fn is_exhausted(&self) -> bool {
+        match &self.policy {
+            Some(policy) => matches!(policy.budget, Some(budget) if self.total >= budget),
+            _ => true,
+        }
+    }
+
+    /// Returns the asset of the self-transfer which is due at time `now`, if any, scheduling the
+    /// following one with `rng`. The self-transfer should be [`record`](Self::record)ed once it is
+    /// signed.
+    ///
+    /// The first poll of a policy only schedules its first self-transfer. Self-transfers which are
+    /// due while the daily limit is reached are skipped.
+    #[inline]
+    pub fn poll<R>(&mut self, now: u64, rng: &mut R) -> Option<Asset<C>>
+    where
+        R: RngCore + ?Sized,
+    {
+        if self.is_exhausted() {
+            return None;
+        }
+        let policy = self.policy.as_ref()?;
+        let due = matches!(self.next, Some(next) if next <= now);
+        if self.next.is_none() || due {
+            self.next = Some(now.saturating_add(policy.sample_interval(rng)));
+        }
+        if !due {
+            return None;
+        }
+        let day = now / SECONDS_PER_DAY;
+        if day > self.day {
+            self.day = day;
+            self.today = 0;
+        }
+        (self.today < policy.per_day)
+            .then(|| Asset::<C>::new(policy.id.clone(), policy.value.clone()))
+    }
+
+    /// Counts a self-transfer signed after it was returned by [`poll`](Self::poll).
+    #[inline]
+    pub fn record(&mut self) {
+        self.today = self.today.saturating_add(1);
+        self.total = self.total.saturating_add(1);
+    }
+}
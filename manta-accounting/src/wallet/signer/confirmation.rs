@@ -0,0 +1,306 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Confirmation Depth
+//!
+//! A [`ConfirmationPolicy`] makes the [`Signer`](super::Signer) wait before spending the notes it
+//! receives. A note gets one confirmation for every later synchronization which brings new UTXOs
+//! from the ledger, and it is only selected for spending once it has as many confirmations as the
+//! policy requires for its asset. Until then, its value is reported as pending.
+
+use crate::{
+    asset::AssetMap,
+    transfer::{self, Identifier},
+};
+use alloc::vec::Vec;
+use core::{fmt::Debug, hash::Hash};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Confirmation Policy
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "C::AssetId: Deserialize<'de>",
+            serialize = "C::AssetId: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "C::AssetId: Clone"),
+    Debug(bound = "C::AssetId: Debug"),
+    Default(bound = ""),
+    Eq(bound = "C::AssetId: Eq"),
+    Hash(bound = "C::AssetId: Hash"),
+    PartialEq(bound = "C::AssetId: PartialEq")
+)]
+pub struct ConfirmationPolicy<C>
+where
+    C: transfer::Configuration,
+{
+    /// Default Number of Confirmations
+    ///
+    /// Number of confirmations required for the assets without a depth of their own.
+    pub default: u32,
+
+    /// Number of Confirmations per Asset Id
+    pub assets: Vec<(C::AssetId, u32)>,
+}
+
+impl<C> ConfirmationPolicy<C>
+where
+    C: transfer::Configuration,
+{
+    /// Returns the number of confirmations required before spending the asset with the given
+    /// `id`.
+    #[inline]
+    pub fn depth(&self, id: &C::AssetId) -> u32 {
+        self.assets
+            .iter()
+            .find(|(asset_id, _)| asset_id == id)
+            .map(|(_, depth)| *depth)
+            .unwrap_or(self.default)
+    }
+
+    /// Returns the largest number of confirmations required by `self`.
+    #[inline]
+    pub fn max_depth(&self) -> u32 {
+        self.assets
+            .iter()
+            .map(|(_, depth)| *depth)
+            .fold(self.default, u32::max)
+    }
+}
+
+/// Received Note
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "Identifier<C>: Deserialize<'de>, C::AssetId: Deserialize<'de>",
+            serialize = "Identifier<C>: Serialize, C::AssetId: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "Identifier<C>: Clone, C::AssetId: Clone"),
+    Debug(bound = "Identifier<C>: Debug, C::AssetId: Debug"),
+    Eq(bound = "Identifier<C>: Eq, C::AssetId: Eq"),
+    Hash(bound = "Identifier<C>: Hash, C::AssetId: Hash"),
+    PartialEq(bound = "Identifier<C>: PartialEq, C::AssetId: PartialEq")
+)]
+struct ReceivedNote<C>
+where
+    C: transfer::Configuration,
+{
+    /// Note Identifier
+    identifier: Identifier<C>,
+
+    /// Asset Id
+    id: C::AssetId,
+
+    /// Synchronization Round in which the Note was Received
+    round: u64,
+}
+
+/// Spendable Balance
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SpendableBalance<V> {
+    /// Value which can be Spent
+    pub available: V,
+
+    /// Value Waiting for Confirmations
+    pub pending: V,
+}
+
+/// Confirmation State
+///
+/// Stores the [`ConfirmationPolicy`] of a signer along with the notes which may still be waiting
+/// for confirmations, so that both survive restarts when persisted with the signer state.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "Identifier<C>: Deserialize<'de>, C::AssetId: Deserialize<'de>",
+            serialize = "Identifier<C>: Serialize, C::AssetId: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "Identifier<C>: Clone, C::AssetId: Clone"),
+    Debug(bound = "Identifier<C>: Debug, C::AssetId: Debug"),
+    Default(bound = ""),
+    Eq(bound = "Identifier<C>: Eq, C::AssetId: Eq"),
+    Hash(bound = "Identifier<C>: Hash, C::AssetId: Hash"),
+    PartialEq(bound = "Identifier<C>: PartialEq, C::AssetId: PartialEq")
+)]
+pub struct ConfirmationState<C>
+where
+    C: transfer::Configuration,
+{
+    /// Confirmation Policy
+    policy: ConfirmationPolicy<C>,
+
+    /// Number of Synchronizations which brought new UTXOs
+    round: u64,
+
+    /// Notes which may still be Waiting for Confirmations
+    received: Vec<ReceivedNote<C>>,
+}
+
+impl<C> ConfirmationState<C>
+where
+    C: transfer::Configuration,
+{
+    /// Returns the confirmation policy of `self`.
+    #[inline]
+    pub fn policy(&self) -> &ConfirmationPolicy<C> {
+        &self.policy
+    }
+
+    /// Replaces the confirmation policy of `self` with `policy`. The new depths apply to the notes
+    /// which are still tracked by `self`, while the notes which were already spendable remain so.
+    #[inline]
+    pub fn set_policy(&mut self, policy: ConfirmationPolicy<C>) {
+        self.policy = policy;
+        self.prune();
+    }
+
+    /// Returns `true` if the policy of `self` requires any confirmation, in which case received
+    /// notes have to be [`record`](Self::record)ed.
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.policy.max_depth() > 0
+    }
+
+    /// Returns the number of synchronizations which brought new UTXOs since `self` was created.
+    #[inline]
+    pub fn round(&self) -> u64 {
+        self.round
+    }
+
+    /// Returns the number of confirmations of the note with `identifier`, or `None` if it is not
+    /// tracked by `self` because it already had enough confirmations when it was last checked.
+    #[inline]
+    pub fn confirmations(&self, identifier: &Identifier<C>) -> Option<u64>
+    where
+        Identifier<C>: PartialEq,
+    {
+        self.received
+            .iter()
+            .find(|note| &note.identifier == identifier)
+            .map(|note| self.round - note.round)
+    }
+
+    /// Returns `true` if the note with `identifier` holding an asset with the given `id` still
+    /// waits for confirmations.
+    #[inline]
+    pub fn is_pending(&self, identifier: &Identifier<C>, id: &C::AssetId) -> bool
+    where
+        Identifier<C>: PartialEq,
+    {
+        matches!(
+            self.confirmations(identifier),
+            Some(confirmations) if confirmations < u64::from(self.policy.depth(id))
+        )
+    }
+
+    /// Records a synchronization which brought new UTXOs, in which the notes with the
+    /// identifiers and asset ids in `received` were received. The notes received earlier get one
+    /// more confirmation.
+    #[inline]
+    pub fn record<I>(&mut self, received: I)
+    where
+        I: IntoIterator<Item = (Identifier<C>, C::AssetId)>,
+    {
+        self.round += 1;
+        let round = self.round;
+        self.received
+            .extend(received.into_iter().map(|(identifier, id)| ReceivedNote {
+                identifier,
+                id,
+                round,
+            }));
+        self.prune();
+    }
+
+    /// Removes the notes which have enough confirmations from `self`.
+    #[inline]
+    fn prune(&mut self) {
+        let round = self.round;
+        let policy = &self.policy;
+        self.received
+            .retain(|note| round - note.round < u64::from(policy.depth(&note.id)));
+    }
+
+    /// Returns the assets of `assets` which can be spent, building a new asset map in `buffer`
+    /// which leaves out the notes waiting for confirmations if there are any.
+    #[inline]
+    pub fn spendable<'a, M>(&self, assets: &'a M, buffer: &'a mut Option<M>) -> &'a M
+    where
+        M: AssetMap<C::AssetId, C::AssetValue, Key = Identifier<C>>,
+        Identifier<C>: PartialEq,
+    {
+        if self.received.is_empty() {
+            return assets;
+        }
+        let mut spendable = M::default();
+        for (identifier, asset) in assets.asset_vector() {
+            if !self.is_pending(&identifier, &asset.id) {
+                spendable.insert(identifier, asset);
+            }
+        }
+        buffer.insert(spendable)
+    }
+
+    /// Returns the balance of the asset with the given `id` held in `assets`, split between the
+    /// value which can be spent and the value waiting for confirmations.
+    #[inline]
+    pub fn balance<M>(&self, assets: &M, id: &C::AssetId) -> SpendableBalance<C::AssetValue>
+    where
+        M: AssetMap<C::AssetId, C::AssetValue, Key = Identifier<C>>,
+        Identifier<C>: PartialEq,
+    {
+        let mut balance = SpendableBalance::<C::AssetValue>::default();
+        for (identifier, asset) in assets.asset_vector_with_id(id) {
+            if self.is_pending(&identifier, id) {
+                balance.pending += asset.value;
+            } else {
+                balance.available += asset.value;
+            }
+        }
+        balance
+    }
+}
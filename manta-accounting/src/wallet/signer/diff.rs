@@ -0,0 +1,250 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Incremental Accumulator Synchronization
+//!
+//! In steady state almost none of the UTXOs on the ledger belong to the signer, but a
+//! [`SyncData`] message still carries every [`Utxo`] together with its full [`Note`]. An
+//! [`AccumulatorDiff`] carries only the accumulator item of the leaves which the signer did not
+//! detect, using the [`NoteFilter`]s it matched with
+//! [`Signer::detect_notes`](super::Signer::detect_notes), together with the new roots of the
+//! accumulator so that the signer can check that it reached the same state as the ledger.
+//!
+//! [`NoteFilter`]: crate::transfer::NoteFilter
+
+use crate::{
+    transfer::{
+        self, Note, Nullifier, Parameters, Utxo, UtxoAccumulatorItem, UtxoAccumulatorOutput,
+    },
+    wallet::{
+        ledger,
+        signer::{functions, Configuration, SyncData},
+    },
+};
+use alloc::vec::Vec;
+use core::{fmt::Debug, hash::Hash};
+use manta_crypto::accumulator::FromItemsAndWitnesses;
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Accumulator Diff Leaf
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = r"
+                UtxoAccumulatorItem<C>: Deserialize<'de>,
+                Utxo<C>: Deserialize<'de>,
+                Note<C>: Deserialize<'de>
+            ",
+            serialize = r"
+                UtxoAccumulatorItem<C>: Serialize,
+                Utxo<C>: Serialize,
+                Note<C>: Serialize
+            ",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "UtxoAccumulatorItem<C>: Clone, Utxo<C>: Clone, Note<C>: Clone"),
+    Debug(bound = "UtxoAccumulatorItem<C>: Debug, Utxo<C>: Debug, Note<C>: Debug"),
+    Eq(bound = "UtxoAccumulatorItem<C>: Eq, Utxo<C>: Eq, Note<C>: Eq"),
+    Hash(bound = "UtxoAccumulatorItem<C>: Hash, Utxo<C>: Hash, Note<C>: Hash"),
+    PartialEq(bound = "UtxoAccumulatorItem<C>: PartialEq, Utxo<C>: PartialEq, Note<C>: PartialEq")
+)]
+pub enum DiffLeaf<C>
+where
+    C: transfer::Configuration + ?Sized,
+{
+    /// Accumulator Item of a Leaf which was not Detected by the Signer
+    Item(UtxoAccumulatorItem<C>),
+
+    /// UTXO and Note of a Leaf which was Detected by the Signer
+    Note(Utxo<C>, Note<C>),
+}
+
+impl<C> DiffLeaf<C>
+where
+    C: Configuration,
+{
+    /// Returns the accumulator item of `self`, hashing its [`Utxo`] if it carries one.
+    #[inline]
+    pub fn item(&self, parameters: &Parameters<C>) -> UtxoAccumulatorItem<C>
+    where
+        UtxoAccumulatorItem<C>: Clone,
+    {
+        match self {
+            Self::Item(item) => item.clone(),
+            Self::Note(utxo, _) => functions::item_hash::<C>(parameters, utxo),
+        }
+    }
+}
+
+/// Accumulator Diff
+///
+/// This is the incremental counterpart of a [`SyncRequest`](super::SyncRequest). It can only be
+/// applied by a signer whose checkpoint is exactly the [`origin_checkpoint`], since its leaves
+/// cannot be pruned without their [`Utxo`]s.
+///
+/// [`origin_checkpoint`]: Self::origin_checkpoint
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = r"
+                T: Deserialize<'de>,
+                DiffLeaf<C>: Deserialize<'de>,
+                UtxoAccumulatorOutput<C>: Deserialize<'de>,
+                Nullifier<C>: Deserialize<'de>
+            ",
+            serialize = r"
+                T: Serialize,
+                DiffLeaf<C>: Serialize,
+                UtxoAccumulatorOutput<C>: Serialize,
+                Nullifier<C>: Serialize
+            ",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(
+        bound = "T: Clone, DiffLeaf<C>: Clone, UtxoAccumulatorOutput<C>: Clone, Nullifier<C>: Clone"
+    ),
+    Debug(
+        bound = "T: Debug, DiffLeaf<C>: Debug, UtxoAccumulatorOutput<C>: Debug, Nullifier<C>: Debug"
+    ),
+    Default(bound = "T: Default"),
+    Eq(bound = "T: Eq, DiffLeaf<C>: Eq, UtxoAccumulatorOutput<C>: Eq, Nullifier<C>: Eq"),
+    Hash(bound = "T: Hash, DiffLeaf<C>: Hash, UtxoAccumulatorOutput<C>: Hash, Nullifier<C>: Hash"),
+    PartialEq(
+        bound = "T: PartialEq, DiffLeaf<C>: PartialEq, UtxoAccumulatorOutput<C>: PartialEq, Nullifier<C>: PartialEq"
+    )
+)]
+pub struct AccumulatorDiff<C, T>
+where
+    C: transfer::Configuration + ?Sized,
+    T: ledger::Checkpoint,
+{
+    /// Origin Checkpoint
+    pub origin_checkpoint: T,
+
+    /// New Leaves
+    ///
+    /// The leaves are in the order in which they were inserted into the ledger accumulator.
+    pub leaves: Vec<DiffLeaf<C>>,
+
+    /// New Roots
+    ///
+    /// These are the outputs of the subaccumulators which received new [`leaves`](Self::leaves),
+    /// in the order of the subaccumulators, as computed by [`new_roots`]. Ledgers which serve a
+    /// diff in several pages only know the roots after the last page, so they can leave this
+    /// empty on the other pages to skip the check.
+    pub roots: Vec<UtxoAccumulatorOutput<C>>,
+
+    /// Removed Checkpoints
+    ///
+    /// These are the checkpoints which the ledger invalidated since the
+    /// [`origin_checkpoint`](Self::origin_checkpoint), for instance because of a chain
+    /// reorganization. A signer whose checkpoint is one of them cannot apply the diff and needs
+    /// to be recovered from the ledger.
+    pub removed_checkpoints: Vec<T>,
+
+    /// Nullifier Data
+    pub nullifier_data: Vec<Nullifier<C>>,
+
+    /// Block Height
+    ///
+    /// This is the height of the ledger at the end of the diff, if the ledger reports block
+    /// heights.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub height: Option<u64>,
+}
+
+impl<C, T> AccumulatorDiff<C, T>
+where
+    C: transfer::Configuration,
+    T: ledger::Checkpoint,
+{
+    /// Builds a new [`AccumulatorDiff`] out of `data`, retrieved at `origin_checkpoint`, keeping
+    /// the full notes of the leaves at the `detected` indices only. The `detected` indices must be
+    /// sorted, as they are when returned by [`Signer::detect_notes`](super::Signer::detect_notes).
+    /// The [`height`](Self::height) of the diff is left unknown.
+    #[inline]
+    pub fn from_sync_data(
+        parameters: &Parameters<C>,
+        origin_checkpoint: T,
+        data: SyncData<C>,
+        detected: &[usize],
+        roots: Vec<UtxoAccumulatorOutput<C>>,
+        removed_checkpoints: Vec<T>,
+    ) -> Self
+    where
+        C: Configuration,
+    {
+        Self {
+            origin_checkpoint,
+            leaves: data
+                .utxo_note_data
+                .into_iter()
+                .enumerate()
+                .map(|(index, (utxo, note))| {
+                    if detected.binary_search(&index).is_ok() {
+                        DiffLeaf::Note(utxo, note)
+                    } else {
+                        DiffLeaf::Item(functions::item_hash::<C>(parameters, &utxo))
+                    }
+                })
+                .collect(),
+            roots,
+            removed_checkpoints,
+            nullifier_data: data.nullifier_data,
+            height: None,
+        }
+    }
+
+    /// Returns the number of leaves in `self` which carry a full [`Note`].
+    #[inline]
+    pub fn note_count(&self) -> usize {
+        self.leaves
+            .iter()
+            .filter(|leaf| matches!(leaf, DiffLeaf::Note(..)))
+            .count()
+    }
+}
+
+/// Returns the outputs of the subaccumulators of `accumulator` which contain one of the `items`,
+/// in the order of the subaccumulators, or `None` if `accumulator` cannot compute one of them.
+///
+/// The `items` must already be inserted into `accumulator`.
+#[inline]
+pub fn new_roots<A>(accumulator: &A, items: Vec<A::Item>) -> Option<Vec<A::Output>>
+where
+    A: FromItemsAndWitnesses,
+{
+    A::sort_items(items)
+        .into_iter()
+        .filter_map(|items| items.last().map(|item| accumulator.output_from(item)))
+        .collect()
+}
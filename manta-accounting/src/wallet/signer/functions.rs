@@ -23,29 +23,35 @@ use crate::{
         self,
         batch::Join,
         canonical::{
-            MultiProvingContext, PrivateTransfer, PrivateTransferShape, Selection, ToPrivate,
-            ToPublic, ToPublicShape, Transaction, TransactionData, TransferShape,
+            PrivateTransfer, PrivateTransferShape, Selection, ToPrivate, ToPublic, ToPublicShape,
+            Transaction, TransactionData, TransferShape,
         },
         receiver::ReceiverPost,
         requires_authorization,
         utxo::{
-            auth::DeriveContext, DeriveAddress as _, DeriveDecryptionKey, DeriveSpend,
+            auth::DeriveContext, DeriveAddress as _, DeriveDecryptionKey, DeriveSpend, NoteDetect,
             NullifierOpen, Spend, UtxoReconstruct,
         },
-        Address, Asset, AssociatedData, Authorization, AuthorizationContext, FullParametersRef,
-        IdentifiedAsset, Identifier, IdentityProof, Note, Nullifier, Parameters, PreSender,
-        ProvingContext, Receiver, Sender, Shape, SpendingKey, Transfer, TransferPost, Utxo,
-        UtxoAccumulatorItem, UtxoAccumulatorModel, UtxoAccumulatorWitness,
+        Address, Asset, AssociatedData, Authorization, AuthorizationContext, DetectionKey,
+        FullParametersRef, IdentifiedAsset, Identifier, IdentityProof, Note, NoteFilter, Nullifier,
+        Parameters, PreSender, ProvingContext, Receiver, Sender, Shape, SpendingKey, Transfer,
+        TransferPost, Utxo, UtxoAccumulatorItem, UtxoAccumulatorModel, UtxoAccumulatorOutput,
+        UtxoAccumulatorWitness,
     },
     wallet::signer::{
-        nullifier_map::NullifierMap, AccountTable, BalanceUpdate, Checkpoint, Configuration,
-        ConsolidationPrerequest, ConsolidationRequest, InitialSyncRequest, SignError, SignResponse,
+        diff::{new_roots, AccumulatorDiff, DiffLeaf},
+        hook::SignerHooks,
+        nullifier_map::NullifierMap,
+        progress::{SyncProgress, SyncProgressReporter},
+        prover::{BatchProver, ProofJob},
+        witness, AccountTable, BalanceUpdate, Checkpoint, Configuration, ConsolidationPrerequest,
+        ConsolidationRequest, InitialSyncRequest, SignError, SignResponse,
         SignWithTransactionDataResponse, SignWithTransactionDataResult, SignerParameters, SyncData,
         SyncError, SyncRequest, SyncResponse,
     },
 };
 use alloc::{vec, vec::Vec};
-use core::ops::SubAssign;
+use core::{cmp::Ordering, ops::SubAssign};
 use manta_crypto::{
     accumulator::{
         Accumulator, BatchInsertion, FromItemsAndWitnesses, ItemHashFunction, OptimizedAccumulator,
@@ -217,11 +223,12 @@ fn sync_with<C, I>(
     inserts: I,
     nullifier_data: Vec<Nullifier<C>>,
     is_partial: bool,
+    progress: &SyncProgressReporter,
     rng: &mut C::Rng,
 ) -> Result<SyncResponse<C, C::Checkpoint>, SyncError<C::Checkpoint>>
 where
     C: Configuration,
-    I: Iterator<Item = (Utxo<C>, Note<C>)>,
+    I: ExactSizeIterator<Item = DiffLeaf<C>>,
     C::AssetValue: CheckedAdd<Output = C::AssetValue> + CheckedSub<Output = C::AssetValue>,
 {
     let nullifier_count = nullifier_data.len();
@@ -234,26 +241,42 @@ where
             .filter(|nullifier| parameters.can_be_opened(nullifier, &decryption_key)),
     );
     let mut nonprovable_inserts = Vec::new();
-    for (utxo, note) in inserts {
-        if let Some((identifier, asset)) = parameters.open_with_check(&decryption_key, &utxo, note)
-        {
-            if !nonprovable_inserts.is_empty() {
-                utxo_accumulator.batch_insert_nonprovable(&nonprovable_inserts);
-                nonprovable_inserts.clear();
+    let mut sync_progress = SyncProgress {
+        total: inserts.len(),
+        ..Default::default()
+    };
+    if sync_progress.total == 0 {
+        progress.report(sync_progress);
+    }
+    for leaf in inserts {
+        sync_progress.processed_utxo_index += 1;
+        match leaf {
+            DiffLeaf::Note(utxo, note) => {
+                if let Some((identifier, asset)) =
+                    parameters.open_with_check(&decryption_key, &utxo, note)
+                {
+                    sync_progress.new_notes_found += 1;
+                    if !nonprovable_inserts.is_empty() {
+                        utxo_accumulator.batch_insert_nonprovable(&nonprovable_inserts);
+                        nonprovable_inserts.clear();
+                    }
+                    insert_next_item::<C>(
+                        authorization_context,
+                        utxo_accumulator,
+                        assets,
+                        parameters,
+                        transfer::utxo::IdentifiedAsset::new(identifier, asset),
+                        nullifiers,
+                        &mut deposit,
+                        rng,
+                    );
+                } else {
+                    nonprovable_inserts.push(item_hash::<C>(parameters, &utxo));
+                }
             }
-            insert_next_item::<C>(
-                authorization_context,
-                utxo_accumulator,
-                assets,
-                parameters,
-                transfer::utxo::IdentifiedAsset::new(identifier, asset),
-                nullifiers,
-                &mut deposit,
-                rng,
-            );
-        } else {
-            nonprovable_inserts.push(item_hash::<C>(parameters, &utxo));
+            DiffLeaf::Item(item) => nonprovable_inserts.push(item),
         }
+        progress.report(sync_progress);
     }
     if !nonprovable_inserts.is_empty() {
         utxo_accumulator.batch_insert_nonprovable(&nonprovable_inserts);
@@ -456,6 +479,15 @@ where
 }
 
 /// Builds the [`Receiver`] associated with the default address and `asset`.
+///
+/// # Note
+///
+/// Change is always sent back to the default address instead of a freshly derived internal one.
+/// An address is derived from the viewing key of a single spending key, and every sender of a
+/// transfer is checked against the one [`Authorization`] of that transfer, so notes owned by
+/// different internal addresses could never be spent together. Change notes remain unlinkable on
+/// the ledger since their UTXO commitments are randomized and their incoming notes are encrypted
+/// under fresh ephemeral keys, leaving only the coarse address partition in common.
 #[inline]
 fn default_receiver<C>(
     accounts: &AccountTable<C>,
@@ -491,6 +523,7 @@ fn select<C>(
     accounts: &AccountTable<C>,
     assets: &C::AssetMap,
     parameters: &Parameters<C>,
+    hooks: &SignerHooks<C>,
     asset: &Asset<C>,
     rng: &mut C::Rng,
 ) -> Result<Selection<C>, SignError<C>>
@@ -501,6 +534,9 @@ where
     if !asset.is_zero() && selection.is_empty() {
         return Err(SignError::InsufficientBalance(asset.clone()));
     }
+    hooks
+        .check_selection(asset, &selection.values)
+        .map_err(SignError::HookRejection)?;
     Selection::new(selection, move |k, v| {
         Ok(build_pre_sender::<C>(
             accounts,
@@ -522,6 +558,7 @@ fn custom_select<C>(
     accounts: &AccountTable<C>,
     assets: &C::AssetMap,
     parameters: &Parameters<C>,
+    hooks: &SignerHooks<C>,
     request: ConsolidationRequest<C>,
     rng: &mut C::Rng,
 ) -> Result<Selection<C>, SignError<C>>
@@ -532,8 +569,12 @@ where
     if !request.check_consolidation_request(assets) {
         return Err(SignError::InvalidConsolidationRequest);
     }
-    let id = request.id().clone();
+    let asset = request.asset();
     let selection = request.select::<C::AssetMap>();
+    hooks
+        .check_selection(&asset, &selection.values)
+        .map_err(SignError::HookRejection)?;
+    let id = asset.id;
     Selection::new(selection, move |k, v| {
         Ok(build_pre_sender::<C>(
             accounts,
@@ -614,6 +655,67 @@ where
     )
 }
 
+/// Proof Job for a [`PrivateTransfer`]
+type PrivateTransferJob<C> = ProofJob<
+    C,
+    { PrivateTransferShape::SOURCES },
+    { PrivateTransferShape::SENDERS },
+    { PrivateTransferShape::RECEIVERS },
+    { PrivateTransferShape::SINKS },
+>;
+
+/// Proof Job for a [`ToPublic`] transfer
+type ToPublicJob<C> = ProofJob<
+    C,
+    { ToPublicShape::SOURCES },
+    { ToPublicShape::SENDERS },
+    { ToPublicShape::RECEIVERS },
+    { ToPublicShape::SINKS },
+>;
+
+/// Builds the [`TransferPost`]s for the transfers in `batch` against `proving_context` with the
+/// prover of `parameters`, keeping the order of `batch`.
+#[inline]
+fn build_posts<
+    C,
+    const SOURCES: usize,
+    const SENDERS: usize,
+    const RECEIVERS: usize,
+    const SINKS: usize,
+>(
+    accounts: &AccountTable<C>,
+    utxo_accumulator_model: &UtxoAccumulatorModel<C>,
+    parameters: &SignerParameters<C>,
+    proving_context: &ProvingContext<C>,
+    mut batch: Vec<ProofJob<C, SOURCES, SENDERS, RECEIVERS, SINKS>>,
+    rng: &mut C::Rng,
+) -> Result<Vec<TransferPost<C>>, SignError<C>>
+where
+    C: Configuration,
+{
+    if let Some(refresher) = &parameters.witness_refresher {
+        for job in &mut batch {
+            witness::refresh_senders(
+                refresher.as_ref(),
+                &parameters.parameters,
+                job.transfer.senders_mut(),
+            );
+        }
+    }
+    let spending_key = requires_authorization(SENDERS)
+        .then(|| default_spending_key::<C>(accounts, &parameters.parameters));
+    parameters
+        .prover
+        .prove_batch(
+            FullParametersRef::<C>::new(&parameters.parameters, utxo_accumulator_model),
+            proving_context,
+            spending_key.as_ref(),
+            batch,
+            rng,
+        )
+        .map_err(SignError::ProofSystemError)
+}
+
 /// Computes the next [`Join`](Join) element for an asset rebalancing round.
 #[allow(clippy::type_complexity)] // NOTE: Clippy is too harsh here.
 #[inline]
@@ -742,7 +844,8 @@ where
     Ok(into_array_unchecked(senders))
 }
 
-/// Computes the batched transactions for rebalancing before a final transfer.
+/// Computes the batched transactions for rebalancing before a final transfer, pushing the joins
+/// to be proven onto `jobs`.
 #[allow(clippy::too_many_arguments)]
 #[inline]
 fn compute_batched_transactions<C>(
@@ -750,10 +853,9 @@ fn compute_batched_transactions<C>(
     assets: &C::AssetMap,
     utxo_accumulator: &mut C::UtxoAccumulator,
     parameters: &Parameters<C>,
-    proving_context: &MultiProvingContext<C>,
     asset_id: &C::AssetId,
     mut pre_senders: Vec<PreSender<C>>,
-    posts: &mut Vec<TransferPost<C>>,
+    jobs: &mut Vec<PrivateTransferJob<C>>,
     rng: &mut C::Rng,
 ) -> Result<[Sender<C>; PrivateTransferShape::SENDERS], SignError<C>>
 where
@@ -779,15 +881,10 @@ where
             )?;
             let authorization =
                 authorization_for_default_spending_key::<C>(accounts, parameters, rng);
-            posts.push(build_post(
-                Some(accounts),
-                utxo_accumulator.model(),
-                parameters,
-                &proving_context.private_transfer,
+            jobs.push(ProofJob::new(
                 PrivateTransfer::build(authorization, senders, receivers),
                 Vec::new(),
-                rng,
-            )?);
+            ));
             join.insert_utxos(parameters, utxo_accumulator);
             joins.push(join.pre_sender);
             new_zeroes.append(&mut join.zeroes);
@@ -809,20 +906,20 @@ where
 }
 
 /// Performs a ToPublic transaction spending the assets in `selection`,
-/// returning [`TransferPost`]s.
+/// returning the [`ToPublic`] transfers to be proven, all tagged with `tag` if it is given.
 #[allow(clippy::too_many_arguments)]
 #[inline]
 fn compute_to_public_transaction<C>(
     accounts: &AccountTable<C>,
     assets: &C::AssetMap,
     parameters: &Parameters<C>,
-    proving_context: &MultiProvingContext<C>,
     asset_id: &C::AssetId,
     sink_accounts: Vec<C::AccountId>,
+    tag: Option<&C::WithdrawalTag>,
     selection: Selection<C>,
     utxo_accumulator: &mut C::UtxoAccumulator,
     rng: &mut C::Rng,
-) -> Result<SignResponse<C>, SignError<C>>
+) -> Result<Vec<ToPublicJob<C>>, SignError<C>>
 where
     C: Configuration,
     C::AssetValue: SubAssign,
@@ -831,7 +928,7 @@ where
         mut change,
         mut pre_senders,
     } = selection;
-    let mut posts = Vec::new();
+    let mut jobs = Vec::new();
     let mut iter = pre_senders
         .into_iter()
         .chunk_by::<{ ToPublicShape::SENDERS }>();
@@ -843,13 +940,12 @@ where
         process_to_public_senders(
             accounts,
             parameters,
-            proving_context,
             asset_id,
             senders,
             sink_accounts.clone(),
-            utxo_accumulator,
+            tag,
             &mut change,
-            &mut posts,
+            &mut jobs,
             rng,
         )?;
     }
@@ -868,33 +964,31 @@ where
         process_to_public_senders(
             accounts,
             parameters,
-            proving_context,
             asset_id,
             final_senders,
             sink_accounts,
-            utxo_accumulator,
+            tag,
             &mut change,
-            &mut posts,
+            &mut jobs,
             rng,
         )?;
     }
-    Ok(SignResponse::new(posts))
+    Ok(jobs)
 }
 
-/// Creates a to public [`TransferPost`] spending the assets held by `senders` and
-/// attaches it to `post`.
+/// Creates a [`ToPublic`] transfer spending the assets held by `senders`, tagged with `tag` if it
+/// is given, and attaches it to `jobs`.
 #[allow(clippy::too_many_arguments)]
 #[inline]
 fn process_to_public_senders<C>(
     accounts: &AccountTable<C>,
     parameters: &Parameters<C>,
-    proving_context: &MultiProvingContext<C>,
     asset_id: &C::AssetId,
     senders: [Sender<C>; ToPublicShape::SENDERS],
     sink_accounts: Vec<C::AccountId>,
-    utxo_accumulator: &mut C::UtxoAccumulator,
+    tag: Option<&C::WithdrawalTag>,
     change: &mut C::AssetValue,
-    posts: &mut Vec<TransferPost<C>>,
+    jobs: &mut Vec<ToPublicJob<C>>,
     rng: &mut C::Rng,
 ) -> Result<(), SignError<C>>
 where
@@ -922,20 +1016,19 @@ where
         Asset::<C>::new(asset_id.clone(), received_value),
         rng,
     );
-    posts.push(build_post(
-        Some(accounts),
-        utxo_accumulator.model(),
-        parameters,
-        &proving_context.to_public,
-        ToPublic::build(
-            authorization,
-            senders,
-            [receiver],
-            Asset::<C>::new(asset_id.clone(), reclaimed_value),
-        ),
+    let transfer = ToPublic::build(
+        authorization,
+        senders,
+        [receiver],
+        Asset::<C>::new(asset_id.clone(), reclaimed_value),
+    );
+    jobs.push(ProofJob::new(
+        match tag {
+            Some(tag) => transfer.with_tag(tag.clone()),
+            _ => transfer,
+        },
         sink_accounts,
-        rng,
-    )?);
+    ));
     Ok(())
 }
 
@@ -951,6 +1044,46 @@ where
     address_from_authorization_context::<C>(authorization_context, &parameters.parameters)
 }
 
+/// Returns the [`DetectionKey`] corresponding to `authorization_context`.
+#[inline]
+pub fn detection_key<C>(
+    parameters: &SignerParameters<C>,
+    authorization_context: &mut AuthorizationContext<C>,
+) -> DetectionKey<C>
+where
+    C: Configuration,
+{
+    let decryption_key = parameters
+        .parameters
+        .derive_decryption_key(authorization_context);
+    parameters.parameters.derive_detection_key(&decryption_key)
+}
+
+/// Returns the indices of the filters in `note_filters` which are matched by `detection_key`.
+///
+/// Light clients download the note filters published by the ledger and only fetch the notes at
+/// the returned indices for trial decryption.
+#[inline]
+pub fn detect_notes<C>(
+    parameters: &SignerParameters<C>,
+    detection_key: &DetectionKey<C>,
+    note_filters: &[NoteFilter<C>],
+) -> Vec<usize>
+where
+    C: Configuration,
+{
+    note_filters
+        .iter()
+        .enumerate()
+        .filter_map(|(index, note_filter)| {
+            parameters
+                .parameters
+                .detect(detection_key, note_filter)
+                .then_some(index)
+        })
+        .collect()
+}
+
 /// Checks that the origin checkpoint in `request` is less or equal than `checkpoint`.
 /// If it is strictly less, it prunes the data in `request` accordingly.
 #[inline]
@@ -1012,8 +1145,9 @@ where
     ))
 }
 
-/// Updates `assets`, `checkpoint` and `utxo_accumulator`, returning the new asset distribution.
-#[allow(clippy::too_many_arguments)] // This function must take 8 arguments
+/// Updates `assets`, `checkpoint` and `utxo_accumulator`, returning the new asset distribution
+/// and reporting the processed UTXOs to `progress`.
+#[allow(clippy::too_many_arguments)] // This function must take 9 arguments
 #[inline]
 pub fn sync<C>(
     parameters: &SignerParameters<C>,
@@ -1023,6 +1157,7 @@ pub fn sync<C>(
     checkpoint: &mut C::Checkpoint,
     utxo_accumulator: &mut C::UtxoAccumulator,
     request: SyncRequest<C, C::Checkpoint>,
+    progress: &SyncProgressReporter,
     rng: &mut C::Rng,
 ) -> Result<SyncResponse<C, C::Checkpoint>, SyncError<C::Checkpoint>>
 where
@@ -1043,33 +1178,212 @@ where
         checkpoint,
         utxo_accumulator,
         &parameters.parameters,
-        utxo_note_data.into_iter(),
+        utxo_note_data
+            .into_iter()
+            .map(|(utxo, note)| DiffLeaf::Note(utxo, note)),
         nullifier_data,
         !has_pruned,
+        progress,
         rng,
     );
     utxo_accumulator.commit();
     response
 }
 
-/// Signs a withdraw transaction for `asset` sent to `address`.
+/// Updates `assets`, `checkpoint` and `utxo_accumulator` by applying `diff`, returning the new
+/// asset distribution and reporting the processed leaves to `progress`.
+///
+/// The `diff` is rejected with [`SyncError::InconsistentSynchronization`], without changing any
+/// state, if its origin is not `checkpoint`, if it removes `checkpoint`, or if the roots of
+/// `utxo_accumulator` after inserting its leaves do not match the ones it carries.
+#[allow(clippy::too_many_arguments)] // This function must take 9 arguments
+#[inline]
+pub fn sync_diff<C>(
+    parameters: &SignerParameters<C>,
+    authorization_context: &mut AuthorizationContext<C>,
+    assets: &mut C::AssetMap,
+    nullifiers: &mut C::NullifierMap,
+    checkpoint: &mut C::Checkpoint,
+    utxo_accumulator: &mut C::UtxoAccumulator,
+    diff: AccumulatorDiff<C, C::Checkpoint>,
+    progress: &SyncProgressReporter,
+    rng: &mut C::Rng,
+) -> Result<SyncResponse<C, C::Checkpoint>, SyncError<C::Checkpoint>>
+where
+    C: Configuration,
+    C::AssetValue: CheckedAdd<Output = C::AssetValue> + CheckedSub<Output = C::AssetValue>,
+    UtxoAccumulatorItem<C>: Clone,
+    UtxoAccumulatorOutput<C>: PartialEq,
+{
+    let inconsistent = || SyncError::InconsistentSynchronization {
+        checkpoint: checkpoint.clone(),
+    };
+    if diff.origin_checkpoint != *checkpoint || diff.removed_checkpoints.contains(checkpoint) {
+        return Err(inconsistent());
+    }
+    if !diff.roots.is_empty() {
+        let items = diff
+            .leaves
+            .iter()
+            .map(|leaf| leaf.item(&parameters.parameters))
+            .collect::<Vec<_>>();
+        utxo_accumulator.batch_insert_nonprovable(&items);
+        let roots = new_roots(utxo_accumulator, items);
+        utxo_accumulator.rollback();
+        if roots.as_ref() != Some(&diff.roots) {
+            return Err(inconsistent());
+        }
+    }
+    let response = sync_with::<C, _>(
+        authorization_context,
+        assets,
+        nullifiers,
+        checkpoint,
+        utxo_accumulator,
+        &parameters.parameters,
+        diff.leaves.into_iter(),
+        diff.nullifier_data,
+        true,
+        progress,
+        rng,
+    );
+    utxo_accumulator.commit();
+    response
+}
+
+/// Returns the assets of `assets` spent by the `nullifier_data` observed by the ledger. The
+/// assets are left in `assets` until the nullifiers arrive with the synchronization data, so that
+/// their withdrawal is reported in the balance update of that synchronization.
+#[inline]
+pub fn observe_nullifiers<C>(
+    parameters: &Parameters<C>,
+    authorization_context: &mut AuthorizationContext<C>,
+    assets: &C::AssetMap,
+    nullifier_data: Vec<Nullifier<C>>,
+    rng: &mut C::Rng,
+) -> Vec<IdentifiedAsset<C>>
+where
+    C: Configuration,
+{
+    let decryption_key = parameters.derive_decryption_key(authorization_context);
+    let mut observed = C::NullifierMap::default();
+    observed.extend(
+        nullifier_data
+            .into_iter()
+            .filter(|nullifier| parameters.can_be_opened(nullifier, &decryption_key)),
+    );
+    if observed.is_empty() {
+        return Vec::new();
+    }
+    assets
+        .asset_vector()
+        .into_iter()
+        .filter_map(|(identifier, asset)| {
+            let (_, _, nullifier) = parameters.derive_spend(
+                authorization_context,
+                identifier.clone(),
+                asset.clone(),
+                rng,
+            );
+            observed
+                .contains_item(&nullifier)
+                .then(|| IdentifiedAsset::<C>::new(identifier, asset))
+        })
+        .collect()
+}
+
+/// Merges the state of another signer with the same seed, given by `other_assets`,
+/// `other_nullifiers`, `other_checkpoint` and `other_utxo_accumulator`, into the current state,
+/// returning the full balance of the merged state.
+///
+/// Both signers discover the same assets from the ledger, so the state which is strictly further
+/// ahead already accounts for every asset of the other one, and its checkpoint, UTXO accumulator
+/// and assets are kept. When neither checkpoint is ahead of the other, the assets of both states
+/// are combined. In both cases the nullifiers are combined and the assets they spend are dropped,
+/// so merging never brings back spent assets, and merging a state with itself leaves it unchanged.
+#[allow(clippy::too_many_arguments)]
+#[inline]
+pub fn merge<C>(
+    parameters: &SignerParameters<C>,
+    authorization_context: &mut AuthorizationContext<C>,
+    assets: &mut C::AssetMap,
+    nullifiers: &mut C::NullifierMap,
+    checkpoint: &mut C::Checkpoint,
+    utxo_accumulator: &mut C::UtxoAccumulator,
+    other_assets: C::AssetMap,
+    other_nullifiers: C::NullifierMap,
+    other_checkpoint: C::Checkpoint,
+    other_utxo_accumulator: C::UtxoAccumulator,
+    rng: &mut C::Rng,
+) -> SyncResponse<C, C::Checkpoint>
+where
+    C: Configuration,
+    Identifier<C>: PartialEq,
+{
+    nullifiers.merge(other_nullifiers);
+    let entries = match other_checkpoint.partial_cmp(checkpoint) {
+        Some(Ordering::Greater) => {
+            *checkpoint = other_checkpoint;
+            *utxo_accumulator = other_utxo_accumulator;
+            other_assets.asset_vector()
+        }
+        Some(Ordering::Less) => assets.asset_vector(),
+        _ => {
+            let mut entries = assets.asset_vector();
+            for entry in other_assets.asset_vector() {
+                if !entries.contains(&entry) {
+                    entries.push(entry);
+                }
+            }
+            entries
+        }
+    };
+    let mut merged_assets = C::AssetMap::default();
+    for (identifier, asset) in entries {
+        let (_, utxo, nullifier) = parameters.parameters.derive_spend(
+            authorization_context,
+            identifier.clone(),
+            asset.clone(),
+            rng,
+        );
+        let item = item_hash::<C>(&parameters.parameters, &utxo);
+        if nullifiers.remove(&nullifier) {
+            utxo_accumulator.remove_proof(&item);
+        } else {
+            merged_assets.insert(identifier, asset);
+        }
+    }
+    utxo_accumulator.commit();
+    *assets = merged_assets;
+    SyncResponse {
+        checkpoint: checkpoint.clone(),
+        balance_update: BalanceUpdate::Full {
+            assets: assets.assets().into(),
+        },
+    }
+}
+
+/// Signs a withdraw transaction for `asset` sent to `address`, or to `sink_accounts` with the
+/// withdrawal `tag` if `address` is `None`.
 #[allow(clippy::too_many_arguments)]
 #[inline]
 fn sign_withdraw<C>(
     parameters: &SignerParameters<C>,
     accounts: &AccountTable<C>,
     assets: &C::AssetMap,
+    hooks: &SignerHooks<C>,
     utxo_accumulator: &mut C::UtxoAccumulator,
     asset: Asset<C>,
     address: Option<Address<C>>,
     sink_accounts: Vec<C::AccountId>,
+    tag: Option<C::WithdrawalTag>,
     rng: &mut C::Rng,
 ) -> Result<SignResponse<C>, SignError<C>>
 where
     C: Configuration,
     C::AssetValue: SubAssign,
 {
-    let selection = select(accounts, assets, &parameters.parameters, &asset, rng)?;
+    let selection = select(accounts, assets, &parameters.parameters, hooks, &asset, rng)?;
     sign_after_selection(
         parameters,
         accounts,
@@ -1078,6 +1392,7 @@ where
         asset,
         address,
         sink_accounts,
+        tag,
         selection,
         rng,
     )
@@ -1089,6 +1404,7 @@ fn consolidate_internal<C>(
     parameters: &SignerParameters<C>,
     accounts: &AccountTable<C>,
     assets: &C::AssetMap,
+    hooks: &SignerHooks<C>,
     utxo_accumulator: &mut C::UtxoAccumulator,
     request: ConsolidationRequest<C>,
     rng: &mut C::Rng,
@@ -1099,7 +1415,14 @@ where
     C::Identifier: PartialEq,
 {
     let asset = request.asset();
-    let selection = custom_select(accounts, assets, &parameters.parameters, request, rng)?;
+    let selection = custom_select(
+        accounts,
+        assets,
+        &parameters.parameters,
+        hooks,
+        request,
+        rng,
+    )?;
     sign_after_selection(
         parameters,
         accounts,
@@ -1108,6 +1431,7 @@ where
         asset,
         Some(default_address::<C>(accounts, &parameters.parameters)),
         Vec::new(),
+        None,
         selection,
         rng,
     )
@@ -1129,16 +1453,15 @@ fn sign_after_selection_private_transfer<C>(
 where
     C: Configuration,
 {
-    let mut posts = Vec::new();
+    let mut jobs = Vec::new();
     let senders = compute_batched_transactions(
         accounts,
         assets,
         utxo_accumulator,
         &parameters.parameters,
-        &parameters.proving_context,
         &asset.id,
         selection.pre_senders,
-        &mut posts,
+        &mut jobs,
         rng,
     )?;
     let change = default_receiver::<C>(
@@ -1156,21 +1479,24 @@ where
         Default::default(),
         rng,
     );
-    let final_post = build_post(
-        Some(accounts),
-        utxo_accumulator.model(),
-        &parameters.parameters,
-        &parameters.proving_context.private_transfer,
+    jobs.push(ProofJob::new(
         PrivateTransfer::build(authorization, senders, [change, receiver]),
         Vec::new(),
+    ));
+    Ok(SignResponse::new(build_posts(
+        accounts,
+        utxo_accumulator.model(),
+        parameters,
+        parameters
+            .proving_context
+            .get(TransferShape::PrivateTransfer)?,
+        jobs,
         rng,
-    )?;
-    posts.push(final_post);
-    Ok(SignResponse::new(posts))
+    )?))
 }
 
-/// Signs a withdraw transaction for `asset` sent to `address`, where `selection`
-/// owns at least `asset`.
+/// Signs a withdraw transaction for `asset` sent to `address`, or to `sink_accounts` with the
+/// withdrawal `tag` if `address` is `None`, where `selection` owns at least `asset`.
 #[allow(clippy::too_many_arguments)]
 #[inline]
 fn sign_after_selection<C>(
@@ -1181,6 +1507,7 @@ fn sign_after_selection<C>(
     asset: Asset<C>,
     address: Option<Address<C>>,
     sink_accounts: Vec<C::AccountId>,
+    tag: Option<C::WithdrawalTag>,
     selection: Selection<C>,
     rng: &mut C::Rng,
 ) -> Result<SignResponse<C>, SignError<C>>
@@ -1199,27 +1526,46 @@ where
             selection,
             rng,
         ),
-        _ => compute_to_public_transaction(
-            accounts,
-            assets,
-            &parameters.parameters,
-            &parameters.proving_context,
-            &asset.id,
-            sink_accounts,
-            selection,
-            utxo_accumulator,
-            rng,
-        ),
+        _ => {
+            let proving_context = match tag {
+                Some(_) => parameters
+                    .tagged_to_public
+                    .as_ref()
+                    .ok_or(SignError::MissingProvingContext(TransferShape::ToPublic))?,
+                _ => parameters.proving_context.get(TransferShape::ToPublic)?,
+            };
+            let jobs = compute_to_public_transaction(
+                accounts,
+                assets,
+                &parameters.parameters,
+                &asset.id,
+                sink_accounts,
+                tag.as_ref(),
+                selection,
+                utxo_accumulator,
+                rng,
+            )?;
+            Ok(SignResponse::new(build_posts(
+                accounts,
+                utxo_accumulator.model(),
+                parameters,
+                proving_context,
+                jobs,
+                rng,
+            )?))
+        }
     }
 }
 
 /// Signs the `transaction`, generating transfer posts without releasing resources.
+#[allow(clippy::too_many_arguments)]
 #[inline]
 fn sign_internal<C>(
     parameters: &SignerParameters<C>,
     accounts: Option<&AccountTable<C>>,
     authorization_context: Option<&mut AuthorizationContext<C>>,
     assets: &C::AssetMap,
+    hooks: &SignerHooks<C>,
     utxo_accumulator: &mut C::UtxoAccumulator,
     transaction: Transaction<C>,
     rng: &mut C::Rng,
@@ -1240,7 +1586,7 @@ where
                 None,
                 utxo_accumulator.model(),
                 &parameters.parameters,
-                &parameters.proving_context.to_private,
+                parameters.proving_context.get(TransferShape::ToPrivate)?,
                 ToPrivate::build(asset, receiver),
                 Vec::new(),
                 rng,
@@ -1250,32 +1596,41 @@ where
             parameters,
             accounts.ok_or(SignError::MissingSpendingKey)?,
             assets,
+            hooks,
             utxo_accumulator,
             asset,
             Some(address),
             Vec::new(),
+            None,
             rng,
         ),
         Transaction::ToPublic(asset, public_account) => sign_withdraw(
             parameters,
             accounts.ok_or(SignError::MissingSpendingKey)?,
             assets,
+            hooks,
             utxo_accumulator,
             asset,
             None,
             Vec::from([public_account]),
+            None,
             rng,
         ),
     }
 }
 
 /// Signs the `transaction`, generating transfer posts.
+///
+/// The coin selection and the generated posts are checked against the `hooks` registered for the
+/// asset id of `transaction`.
+#[allow(clippy::too_many_arguments)]
 #[inline]
 pub fn sign<C>(
     parameters: &SignerParameters<C>,
     accounts: Option<&AccountTable<C>>,
     authorization_context: Option<&mut AuthorizationContext<C>>,
     assets: &C::AssetMap,
+    hooks: &SignerHooks<C>,
     utxo_accumulator: &mut C::UtxoAccumulator,
     transaction: Transaction<C>,
     rng: &mut C::Rng,
@@ -1284,16 +1639,64 @@ where
     C: Configuration,
     C::AssetValue: SubAssign,
 {
+    let asset_id = transaction.asset().id.clone();
     let result = sign_internal(
         parameters,
         accounts,
         authorization_context,
         assets,
+        hooks,
         utxo_accumulator,
         transaction,
         rng,
     )?;
     utxo_accumulator.rollback();
+    hooks
+        .check_posts(&asset_id, &result.posts)
+        .map_err(SignError::HookRejection)?;
+    Ok(result)
+}
+
+/// Signs a [`ToPublic`] withdrawal of `asset` to `public_account` tagged with the public withdrawal
+/// `tag`, generating transfer posts.
+///
+/// Every generated [`ToPublic`] post carries `tag`, which is proven against the tagged proving
+/// context of `parameters`. The coin selection and the generated posts are checked against the
+/// `hooks` registered for the asset id of `asset`.
+#[allow(clippy::too_many_arguments)]
+#[inline]
+pub fn sign_tagged_to_public<C>(
+    parameters: &SignerParameters<C>,
+    accounts: Option<&AccountTable<C>>,
+    assets: &C::AssetMap,
+    hooks: &SignerHooks<C>,
+    utxo_accumulator: &mut C::UtxoAccumulator,
+    asset: Asset<C>,
+    public_account: C::AccountId,
+    tag: C::WithdrawalTag,
+    rng: &mut C::Rng,
+) -> Result<SignResponse<C>, SignError<C>>
+where
+    C: Configuration,
+    C::AssetValue: SubAssign,
+{
+    let asset_id = asset.id.clone();
+    let result = sign_withdraw(
+        parameters,
+        accounts.ok_or(SignError::MissingSpendingKey)?,
+        assets,
+        hooks,
+        utxo_accumulator,
+        asset,
+        None,
+        Vec::from([public_account]),
+        Some(tag),
+        rng,
+    )?;
+    utxo_accumulator.rollback();
+    hooks
+        .check_posts(&asset_id, &result.posts)
+        .map_err(SignError::HookRejection)?;
     Ok(result)
 }
 
@@ -1304,6 +1707,7 @@ pub fn consolidate<C>(
     parameters: &SignerParameters<C>,
     accounts: Option<&AccountTable<C>>,
     assets: &C::AssetMap,
+    hooks: &SignerHooks<C>,
     utxo_accumulator: &mut C::UtxoAccumulator,
     request: ConsolidationPrerequest<C>,
     rng: &mut C::Rng,
@@ -1313,15 +1717,21 @@ where
     C::AssetValue: SubAssign,
     C::Identifier: PartialEq,
 {
+    let request = ConsolidationRequest::<C>::try_from(request)?;
+    let asset_id = request.id().clone();
     let result = consolidate_internal(
         parameters,
         accounts.ok_or(SignError::MissingSpendingKey)?,
         assets,
+        hooks,
         utxo_accumulator,
-        request.try_into()?,
+        request,
         rng,
     )?;
     utxo_accumulator.rollback();
+    hooks
+        .check_posts(&asset_id, &result.posts)
+        .map_err(SignError::HookRejection)?;
     Ok(result)
 }
 
@@ -1367,7 +1777,10 @@ where
         Some(accounts),
         utxo_accumulator_model,
         &parameters.parameters,
-        &parameters.proving_context.to_public,
+        parameters
+            .proving_context
+            .get(TransferShape::ToPublic)
+            .ok()?,
         ToPublic::build(authorization, senders, [change], identified_asset.asset),
         Vec::from([public_account]),
         rng,
@@ -1424,12 +1837,14 @@ where
 
 /// Signs the `transaction`, generating transfer posts
 /// and returning their [`TransactionData`].
+#[allow(clippy::too_many_arguments)]
 #[inline]
 pub fn sign_with_transaction_data<C>(
     parameters: &SignerParameters<C>,
     accounts: Option<&AccountTable<C>>,
     authorization_context: &mut AuthorizationContext<C>,
     assets: &C::AssetMap,
+    hooks: &SignerHooks<C>,
     utxo_accumulator: &mut C::UtxoAccumulator,
     transaction: Transaction<C>,
     rng: &mut C::Rng,
@@ -1445,6 +1860,7 @@ where
             accounts,
             Some(authorization_context),
             assets,
+            hooks,
             utxo_accumulator,
             transaction,
             rng,
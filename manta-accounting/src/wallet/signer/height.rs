@@ -0,0 +1,142 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Block Heights
+//!
+//! Ledgers which know the block height of the data they serve report it in their
+//! [`ReadResponse`](crate::wallet::ledger::ReadResponse)s, and the wallet forwards it to the
+//! [`Signer`](super::Signer) with every [`SyncRequest`](super::SyncRequest). The signer keeps the
+//! height it is synchronized to along with the height at which each of its notes was received,
+//! which is the height at the end of the batch of data the note arrived in.
+
+use crate::transfer::{self, Identifier};
+use alloc::vec::Vec;
+use core::{fmt::Debug, hash::Hash};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Synchronized Height
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "Identifier<C>: Deserialize<'de>",
+            serialize = "Identifier<C>: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "Identifier<C>: Clone"),
+    Debug(bound = "Identifier<C>: Debug"),
+    Default(bound = ""),
+    Eq(bound = "Identifier<C>: Eq"),
+    Hash(bound = "Identifier<C>: Hash"),
+    PartialEq(bound = "Identifier<C>: PartialEq")
+)]
+pub struct SyncHeight<C>
+where
+    C: transfer::Configuration,
+{
+    /// Block Height of the Last Synchronization
+    height: Option<u64>,
+
+    /// Block Heights at which the Notes were Received
+    notes: Vec<(Identifier<C>, u64)>,
+}
+
+impl<C> SyncHeight<C>
+where
+    C: transfer::Configuration,
+{
+    /// Returns the block height `self` is synchronized to, or `None` if the ledger never reported
+    /// one.
+    #[inline]
+    pub fn height(&self) -> Option<u64> {
+        self.height
+    }
+
+    /// Returns the block height at which the note with `identifier` was received, if it is known.
+    #[inline]
+    pub fn note_height(&self, identifier: &Identifier<C>) -> Option<u64>
+    where
+        Identifier<C>: PartialEq,
+    {
+        self.notes
+            .iter()
+            .find(|(note, _)| note == identifier)
+            .map(|(_, height)| *height)
+    }
+
+    /// Returns an iterator over the notes of `self` and the block heights at which they were
+    /// received.
+    #[inline]
+    pub fn notes(&self) -> impl Iterator<Item = (&Identifier<C>, u64)> {
+        self.notes.iter().map(|(note, height)| (note, *height))
+    }
+
+    /// Returns an iterator over the notes of `self` which were received at least `age` blocks
+    /// before the current height, for instance to find the notes whose time to live has expired.
+    #[inline]
+    pub fn notes_older_than(&self, age: u64) -> impl Iterator<Item = &Identifier<C>> {
+        let height = self.height.unwrap_or_default();
+        self.notes
+            .iter()
+            .filter(move |(_, received)| height.saturating_sub(*received) >= age)
+            .map(|(note, _)| note)
+    }
+
+    /// Records a synchronization up to `height` in which the notes with the identifiers in
+    /// `received` were received. Heights never go backwards.
+    #[inline]
+    pub fn record<I>(&mut self, height: u64, received: I)
+    where
+        I: IntoIterator<Item = Identifier<C>>,
+    {
+        self.height = Some(self.height.map_or(height, |current| current.max(height)));
+        self.notes
+            .extend(received.into_iter().map(|note| (note, height)));
+    }
+
+    /// Retains only the notes for which `f` returns `true`, dropping the notes which were spent.
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Identifier<C>) -> bool,
+    {
+        self.notes.retain(|(note, _)| f(note))
+    }
+
+    /// Merges `other` into `self`, keeping the highest height and the lowest height at which every
+    /// note was received.
+    #[inline]
+    pub fn merge(&mut self, other: Self)
+    where
+        Identifier<C>: PartialEq,
+    {
+        self.height = self.height.max(other.height);
+        for (note, height) in other.notes {
+            match self.notes.iter_mut().find(|(known, _)| *known == note) {
+                Some((_, known)) => *known = (*known).min(height),
+                _ => self.notes.push((note, height)),
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Signer Hooks
+//!
+//! Integrator-defined rules which the [`Signer`](super::Signer) runs while signing, so that
+//! asset-specific restrictions can be enforced without modifying the signer itself. Hooks are
+//! registered for a single asset id or for every asset, and can reject a transaction after its
+//! coin selection and after its posts are built.
+
+use crate::transfer::{self, Asset, Identifier, TransferPost};
+use alloc::{sync::Arc, vec::Vec};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Hook Rejection
+///
+/// Integrator-defined code identifying the rule which rejected the transaction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HookRejection(pub u32);
+
+/// Signer Hook
+pub trait SignerHook<C>
+where
+    C: transfer::Configuration,
+{
+    /// Checks the UTXOs selected to pay for `asset`, given by their identifiers and values,
+    /// before any post spending them is built.
+    #[inline]
+    fn check_selection(
+        &self,
+        asset: &Asset<C>,
+        selection: &[(Identifier<C>, C::AssetValue)],
+    ) -> Result<(), HookRejection> {
+        let _ = (asset, selection);
+        Ok(())
+    }
+
+    /// Checks the `posts` built for a transaction of `asset_id`, before they are returned by the
+    /// signer.
+    #[inline]
+    fn check_posts(
+        &self,
+        asset_id: &C::AssetId,
+        posts: &[TransferPost<C>],
+    ) -> Result<(), HookRejection> {
+        let _ = (asset_id, posts);
+        Ok(())
+    }
+}
+
+/// Shared Signer Hook
+pub type SharedSignerHook<C> = Arc<dyn SignerHook<C> + Send + Sync>;
+
+/// Signer Hook Registry
+#[derive(derivative::Derivative)]
+#[derivative(Clone(bound = ""), Default(bound = ""))]
+pub struct SignerHooks<C>
+where
+    C: transfer::Configuration,
+{
+    /// Registered Hooks with the Asset Id they apply to
+    ///
+    /// Hooks registered without an asset id apply to every asset.
+    hooks: Vec<(Option<C::AssetId>, SharedSignerHook<C>)>,
+}
+
+impl<C> SignerHooks<C>
+where
+    C: transfer::Configuration,
+{
+    /// Builds a new empty [`SignerHooks`] registry.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `hook` for the assets with `asset_id`, or for every asset if `asset_id` is
+    /// `None`.
+    #[inline]
+    pub fn register(&mut self, asset_id: Option<C::AssetId>, hook: SharedSignerHook<C>) {
+        self.hooks.push((asset_id, hook));
+    }
+
+    /// Removes every hook from `self`.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.hooks.clear();
+    }
+
+    /// Returns `true` if no hook is registered in `self`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Returns an iterator over the hooks which apply to `asset_id`.
+    #[inline]
+    fn applying_to<'s>(
+        &'s self,
+        asset_id: &'s C::AssetId,
+    ) -> impl Iterator<Item = &'s SharedSignerHook<C>> + 's {
+        self.hooks
+            .iter()
+            .filter(move |(id, _)| id.as_ref().is_none_or(|id| id == asset_id))
+            .map(|(_, hook)| hook)
+    }
+
+    /// Runs [`SignerHook::check_selection`] for every hook which applies to `asset`.
+    #[inline]
+    pub fn check_selection(
+        &self,
+        asset: &Asset<C>,
+        selection: &[(Identifier<C>, C::AssetValue)],
+    ) -> Result<(), HookRejection> {
+        self.applying_to(&asset.id)
+            .try_for_each(|hook| hook.check_selection(asset, selection))
+    }
+
+    /// Runs [`SignerHook::check_posts`] for every hook which applies to `asset_id`.
+    #[inline]
+    pub fn check_posts(
+        &self,
+        asset_id: &C::AssetId,
+        posts: &[TransferPost<C>],
+    ) -> Result<(), HookRejection> {
+        self.applying_to(asset_id)
+            .try_for_each(|hook| hook.check_posts(asset_id, posts))
+    }
+}
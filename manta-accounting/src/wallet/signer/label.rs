@@ -0,0 +1,235 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Signer Labels
+//!
+//! User-defined labels attached to the notes and transactions of a [`Signer`](super::Signer) and
+//! persisted with its state, so that wallets can group shielded funds, for instance into budgeting
+//! envelopes.
+
+use crate::transfer::{self, canonical::TransactionData, Identifier};
+use alloc::{string::String, vec::Vec};
+use core::{fmt::Debug, hash::Hash};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Label
+pub type Label = String;
+
+/// Labeled Entries
+type Entries<K> = Vec<(K, Vec<Label>)>;
+
+/// Returns the labels of `key` in `entries`.
+#[inline]
+fn labels_of<'e, K>(entries: &'e Entries<K>, key: &K) -> &'e [Label]
+where
+    K: PartialEq,
+{
+    entries
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, labels)| labels.as_slice())
+        .unwrap_or_default()
+}
+
+/// Adds `label` to `key` in `entries`, returning `false` if `key` already had `label`.
+#[inline]
+fn insert_label<K>(entries: &mut Entries<K>, key: K, label: Label) -> bool
+where
+    K: PartialEq,
+{
+    match entries.iter_mut().find(|(k, _)| *k == key) {
+        Some((_, labels)) if labels.contains(&label) => false,
+        Some((_, labels)) => {
+            labels.push(label);
+            true
+        }
+        _ => {
+            entries.push((key, Vec::from([label])));
+            true
+        }
+    }
+}
+
+/// Removes `label` from `key` in `entries`, returning `false` if `key` did not have `label`.
+#[inline]
+fn remove_label<K>(entries: &mut Entries<K>, key: &K, label: &str) -> bool
+where
+    K: PartialEq,
+{
+    let index = match entries.iter().position(|(k, _)| k == key) {
+        Some(index) => index,
+        _ => return false,
+    };
+    let labels = &mut entries[index].1;
+    match labels.iter().position(|l| l == label) {
+        Some(position) => {
+            labels.remove(position);
+            if labels.is_empty() {
+                entries.remove(index);
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Returns the key of `transaction_data`, the [`Identifier`] of the first note it opens.
+#[inline]
+fn transaction_key<C>(transaction_data: &TransactionData<C>) -> Option<&Identifier<C>>
+where
+    C: transfer::Configuration,
+{
+    match transaction_data {
+        TransactionData::ToPrivate(identifier, _) | TransactionData::ToPublic(identifier, _) => {
+            Some(identifier)
+        }
+        TransactionData::PrivateTransfer(identified_assets) => {
+            identified_assets.first().map(|(identifier, _)| identifier)
+        }
+    }
+}
+
+/// Label Book
+///
+/// Stores the labels of notes, keyed by their [`Identifier`], and of transactions, keyed by the
+/// [`Identifier`] of the first note their [`TransactionData`] opens.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "Identifier<C>: Deserialize<'de>",
+            serialize = "Identifier<C>: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "Identifier<C>: Clone"),
+    Debug(bound = "Identifier<C>: Debug"),
+    Default(bound = ""),
+    Eq(bound = "Identifier<C>: Eq"),
+    Hash(bound = "Identifier<C>: Hash"),
+    PartialEq(bound = "Identifier<C>: PartialEq")
+)]
+pub struct LabelBook<C>
+where
+    C: transfer::Configuration,
+{
+    /// Note Labels
+    notes: Entries<Identifier<C>>,
+
+    /// Transaction Labels
+    transactions: Entries<Identifier<C>>,
+}
+
+impl<C> LabelBook<C>
+where
+    C: transfer::Configuration,
+    Identifier<C>: PartialEq,
+{
+    /// Returns the labels of the note with `identifier`.
+    #[inline]
+    pub fn note_labels(&self, identifier: &Identifier<C>) -> &[Label] {
+        labels_of(&self.notes, identifier)
+    }
+
+    /// Adds `label` to the note with `identifier`, returning `false` if it was already there.
+    #[inline]
+    pub fn add_note_label(&mut self, identifier: Identifier<C>, label: Label) -> bool {
+        insert_label(&mut self.notes, identifier, label)
+    }
+
+    /// Removes `label` from the note with `identifier`, returning `false` if it was not there.
+    #[inline]
+    pub fn remove_note_label(&mut self, identifier: &Identifier<C>, label: &str) -> bool {
+        remove_label(&mut self.notes, identifier, label)
+    }
+
+    /// Returns an iterator over the identifiers of the notes labeled with `label`.
+    #[inline]
+    pub fn notes_with_label<'s>(
+        &'s self,
+        label: &'s str,
+    ) -> impl Iterator<Item = &'s Identifier<C>> + 's {
+        self.notes
+            .iter()
+            .filter(move |(_, labels)| labels.iter().any(|l| l == label))
+            .map(|(identifier, _)| identifier)
+    }
+
+    /// Returns the labels of the transaction with `transaction_data`.
+    #[inline]
+    pub fn transaction_labels(&self, transaction_data: &TransactionData<C>) -> &[Label] {
+        match transaction_key(transaction_data) {
+            Some(key) => labels_of(&self.transactions, key),
+            _ => &[],
+        }
+    }
+
+    /// Adds `label` to the transaction with `transaction_data`, returning `false` if it was
+    /// already there.
+    #[inline]
+    pub fn add_transaction_label(
+        &mut self,
+        transaction_data: &TransactionData<C>,
+        label: Label,
+    ) -> bool
+    where
+        Identifier<C>: Clone,
+    {
+        match transaction_key(transaction_data) {
+            Some(key) => insert_label(&mut self.transactions, key.clone(), label),
+            _ => false,
+        }
+    }
+
+    /// Removes `label` from the transaction with `transaction_data`, returning `false` if it was
+    /// not there.
+    #[inline]
+    pub fn remove_transaction_label(
+        &mut self,
+        transaction_data: &TransactionData<C>,
+        label: &str,
+    ) -> bool {
+        match transaction_key(transaction_data) {
+            Some(key) => remove_label(&mut self.transactions, key, label),
+            _ => false,
+        }
+    }
+
+    /// Adds every label of `other` to `self`.
+    #[inline]
+    pub fn merge(&mut self, other: Self)
+    where
+        Identifier<C>: Clone,
+    {
+        for (identifier, labels) in other.notes {
+            for label in labels {
+                insert_label(&mut self.notes, identifier.clone(), label);
+            }
+        }
+        for (identifier, labels) in other.transactions {
+            for label in labels {
+                insert_label(&mut self.transactions, identifier.clone(), label);
+            }
+        }
+    }
+}
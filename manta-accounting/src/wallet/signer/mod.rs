@@ -30,15 +30,36 @@ use crate::{
     key::{self, Account, AccountCollection, DeriveAddresses},
     transfer::{
         self,
-        canonical::{MultiProvingContext, Transaction, TransactionData},
-        Address, Asset, AuthorizationContext, IdentifiedAsset, Identifier, IdentityProof, Note,
-        Nullifier, Parameters, ProofSystemError, SpendingKey, TransferPost, Utxo,
-        UtxoAccumulatorItem, UtxoAccumulatorModel, UtxoAccumulatorWitness, UtxoMembershipProof,
+        canonical::{MultiProvingContext, Transaction, TransactionData, TransferShape},
+        Address, Asset, Authorization, AuthorizationContext, DetectionKey, IdentifiedAsset,
+        Identifier, IdentityProof, Note, NoteFilter, Nullifier, Parameters, ProofSystemError,
+        ProvingContext, SpendingKey, TransferPost, Utxo, UtxoAccumulatorItem, UtxoAccumulatorModel,
+        UtxoAccumulatorOutput, UtxoAccumulatorWitness, UtxoMembershipProof,
     },
-    wallet::ledger::{self, Data},
+    wallet::{
+        ledger::{self, Data},
+        signer::{
+            airgap::{AirGapRequest, AirGapResponse},
+            churn::{ChurnPolicy, ChurnSchedule},
+            confirmation::{ConfirmationPolicy, ConfirmationState, SpendableBalance},
+            diff::AccumulatorDiff,
+            height::SyncHeight,
+            hook::{HookRejection, SharedSignerHook, SignerHooks},
+            label::{Label, LabelBook},
+            observed::ObservedSpends,
+            policy::{PolicyViolation, SpendingPolicy, SpendingPolicyState},
+            progress::{SyncProgressCallback, SyncProgressReporter},
+            prover::BatchProver,
+            resolver::{NameResolutionError, SharedNameResolver},
+            validation::RequestProblem,
+            witness::SharedWitnessRefresher,
+        },
+    },
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    cmp::max, convert::Infallible, fmt::Debug, hash::Hash, num::NonZeroUsize, ops::SubAssign,
 };
-use alloc::{boxed::Box, vec::Vec};
-use core::{cmp::max, convert::Infallible, fmt::Debug, hash::Hash, ops::SubAssign};
 use manta_crypto::{
     accumulator::{
         Accumulator, BatchInsertion, ExactSizeAccumulator, FromItemsAndWitnesses, ItemHashFunction,
@@ -55,8 +76,27 @@ use manta_util::{
 #[cfg(feature = "serde")]
 use manta_util::serde::{Deserialize, Serialize};
 
+pub mod airgap;
+pub mod churn;
+pub mod confirmation;
+pub mod diff;
 pub mod functions;
+pub mod height;
+pub mod hook;
+pub mod label;
 pub mod nullifier_map;
+pub mod observed;
+pub mod policy;
+pub mod progress;
+pub mod prover;
+pub mod proving_context;
+pub mod resolver;
+pub mod validation;
+pub mod witness;
+
+pub use proving_context::{
+    EvictionPolicy, ProvingContextCache, ProvingContextLoader, SharedProvingContextLoader,
+};
 
 /// Signer Connection
 pub trait Connection<C>
@@ -370,6 +410,21 @@ where
     pub nullifier_data: Vec<Nullifier<C>>,
 }
 
+impl<C> SyncData<C>
+where
+    C: transfer::Configuration + ?Sized,
+{
+    /// Returns the [`NoteFilter`]s of the notes in `self`, in order, which is what the ledger
+    /// publishes to light clients in place of the full notes.
+    #[inline]
+    pub fn note_filters(&self, parameters: &Parameters<C>) -> Vec<NoteFilter<C>> {
+        self.utxo_note_data
+            .iter()
+            .map(|(_, note)| transfer::utxo::NoteDetect::note_filter(parameters, note))
+            .collect()
+    }
+}
+
 impl<C> Data<C::Checkpoint> for SyncData<C>
 where
     C: Configuration + ?Sized,
@@ -422,6 +477,13 @@ where
 
     /// Ledger Synchronization Data
     pub data: SyncData<C>,
+
+    /// Block Height
+    ///
+    /// This is the height of the ledger at the end of the [`data`](Self::data), if the ledger
+    /// reports block heights.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub height: Option<u64>,
 }
 
 impl<C, T> SyncRequest<C, T>
@@ -661,6 +723,16 @@ where
     InconsistentBalance,
 }
 
+impl<T> From<SyncError<T>> for manta_util::error::Error
+where
+    T: ledger::Checkpoint + Debug,
+{
+    #[inline]
+    fn from(err: SyncError<T>) -> Self {
+        Self::new(format_args!("Signer synchronization failed: {err:?}"))
+    }
+}
+
 /// Synchronization Result
 pub type SyncResult<C, T> = Result<SyncResponse<C, T>, SyncError<T>>;
 
@@ -697,6 +769,14 @@ where
 
     /// Asset Metadata
     pub metadata: Option<A>,
+
+    /// Recipient Name
+    ///
+    /// If set, the [`PrivateTransfer`](Transaction::PrivateTransfer) in `transaction` is sent to
+    /// the address which the [`NameResolver`](resolver::NameResolver) of the signer resolves
+    /// this name to, instead of the address it carries.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recipient_name: Option<String>,
 }
 
 /// Signer Signing Response
@@ -849,6 +929,38 @@ where
 
     /// Invalid Consolidation Request
     InvalidConsolidationRequest,
+
+    /// Missing Proving Context
+    ///
+    /// The proving context for this shape is not loaded and could not be loaded on demand.
+    MissingProvingContext(TransferShape),
+
+    /// Spending Policy Violation
+    ///
+    /// The transaction is not allowed by the [`SpendingPolicy`] of the signer.
+    PolicyViolation(PolicyViolation<C>),
+
+    /// Hook Rejection
+    ///
+    /// The transaction was rejected by one of the [`SignerHooks`] of the signer.
+    HookRejection(HookRejection),
+
+    /// Name Resolution Error
+    ///
+    /// The recipient name of the [`SignRequest`] could not be resolved to an address.
+    NameResolution(NameResolutionError),
+}
+
+impl<C> From<SignError<C>> for manta_util::error::Error
+where
+    C: transfer::Configuration,
+    Asset<C>: Debug,
+    ProofSystemError<C>: Debug,
+{
+    #[inline]
+    fn from(err: SignError<C>) -> Self {
+        Self::new(format_args!("Signing failed: {err:?}"))
+    }
 }
 
 /// Asset List Response
@@ -878,6 +990,35 @@ pub struct AssetListResponse<C>(pub Vec<IdentifiedAsset<C>>)
 where
     C: transfer::Configuration + ?Sized;
 
+/// Labeled Asset List Response
+///
+/// Every [`IdentifiedAsset`] owned by the signer together with the labels of its note.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = r"Asset<C>: Deserialize<'de>, 
+                Identifier<C>: Deserialize<'de>",
+            serialize = r"Asset<C>: Serialize, 
+                Identifier<C>: Serialize"
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "Asset<C>: Clone, Identifier<C>: Clone"),
+    Debug(bound = "Asset<C>: Debug, Identifier<C>: Debug"),
+    Eq(bound = "Asset<C>: Eq, Identifier<C>: Eq"),
+    Hash(bound = "Asset<C>: Hash, Identifier<C>: Hash"),
+    PartialEq(bound = "Asset<C>: PartialEq, Identifier<C>: PartialEq")
+)]
+pub struct LabeledAssetListResponse<C>(pub Vec<(IdentifiedAsset<C>, Vec<Label>)>)
+where
+    C: transfer::Configuration + ?Sized;
+
 /// Consolidation Prerequest
 #[cfg_attr(
     feature = "serde",
@@ -1120,6 +1261,7 @@ pub trait Configuration: transfer::Configuration {
             Item = UtxoAccumulatorItem<Self>,
             Model = UtxoAccumulatorModel<Self>,
             Witness = UtxoAccumulatorWitness<Self>,
+            Output = UtxoAccumulatorOutput<Self>,
         > + BatchInsertion
         + ExactSizeAccumulator
         + FromItemsAndWitnesses
@@ -1137,6 +1279,9 @@ pub trait Configuration: transfer::Configuration {
 
     /// Random Number Generator Type
     type Rng: CryptoRng + FromEntropy + RngCore;
+
+    /// Batch Prover Type
+    type Prover: BatchProver<Self> + Clone + Default;
 }
 
 /// Account Table Type
@@ -1148,8 +1293,16 @@ pub type AccountTable<C> = key::AccountTable<<C as Configuration>::Account>;
     derive(Deserialize, Serialize),
     serde(
         bound(
-            deserialize = "Parameters<C>: Deserialize<'de>, MultiProvingContext<C>: Deserialize<'de>",
-            serialize = "Parameters<C>: Serialize, MultiProvingContext<C>: Serialize",
+            deserialize = r"
+                Parameters<C>: Deserialize<'de>,
+                ProvingContextCache<C>: Deserialize<'de>,
+                ProvingContext<C>: Deserialize<'de>,
+            ",
+            serialize = r"
+                Parameters<C>: Serialize,
+                ProvingContextCache<C>: Serialize,
+                ProvingContext<C>: Serialize,
+            ",
         ),
         crate = "manta_util::serde",
         deny_unknown_fields
@@ -1157,11 +1310,13 @@ pub type AccountTable<C> = key::AccountTable<<C as Configuration>::Account>;
 )]
 #[derive(derivative::Derivative)]
 #[derivative(
-    Clone(bound = "Parameters<C>: Clone, MultiProvingContext<C>: Clone"),
-    Debug(bound = "Parameters<C>: Debug, MultiProvingContext<C>: Debug"),
-    Eq(bound = "Parameters<C>: Eq, MultiProvingContext<C>: Eq"),
-    Hash(bound = "Parameters<C>: Hash, MultiProvingContext<C>: Hash"),
-    PartialEq(bound = "Parameters<C>: PartialEq, MultiProvingContext<C>: PartialEq")
+    Clone(bound = "Parameters<C>: Clone, ProvingContextCache<C>: Clone, ProvingContext<C>: Clone"),
+    Debug(bound = "Parameters<C>: Debug, ProvingContextCache<C>: Debug, ProvingContext<C>: Debug"),
+    Eq(bound = "Parameters<C>: Eq, ProvingContextCache<C>: Eq, ProvingContext<C>: Eq"),
+    Hash(bound = "Parameters<C>: Hash, ProvingContextCache<C>: Hash, ProvingContext<C>: Hash"),
+    PartialEq(
+        bound = "Parameters<C>: PartialEq, ProvingContextCache<C>: PartialEq, ProvingContext<C>: PartialEq"
+    )
 )]
 pub struct SignerParameters<C>
 where
@@ -1171,7 +1326,26 @@ where
     pub parameters: Parameters<C>,
 
     /// Proving Context
-    pub proving_context: MultiProvingContext<C>,
+    pub proving_context: ProvingContextCache<C>,
+
+    /// Tagged [`ToPublic`](transfer::canonical::ToPublic) Proving Context
+    ///
+    /// Proving context of the circuit for [`ToPublic`](transfer::canonical::ToPublic) transfers
+    /// with a withdrawal tag, which is only needed to sign tagged withdrawals.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tagged_to_public: Option<ProvingContext<C>>,
+
+    /// Batch Prover
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derivative(Debug = "ignore", Hash = "ignore", PartialEq = "ignore")]
+    pub prover: C::Prover,
+
+    /// Witness Refresher
+    ///
+    /// Refreshes the membership proofs of the senders of a transaction right before proving.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derivative(Debug = "ignore", Hash = "ignore", PartialEq = "ignore")]
+    pub witness_refresher: Option<SharedWitnessRefresher<C>>,
 }
 
 impl<C> SignerParameters<C>
@@ -1183,9 +1357,53 @@ where
     pub fn new(parameters: Parameters<C>, proving_context: MultiProvingContext<C>) -> Self {
         Self {
             parameters,
-            proving_context,
+            proving_context: proving_context.into(),
+            tagged_to_public: None,
+            prover: Default::default(),
+            witness_refresher: None,
+        }
+    }
+
+    /// Builds a new [`SignerParameters`] from `parameters` which loads proving contexts on demand
+    /// from `loader`, releasing them according to `policy`.
+    #[inline]
+    pub fn from_loader(
+        parameters: Parameters<C>,
+        loader: SharedProvingContextLoader<C>,
+        policy: EvictionPolicy,
+    ) -> Self {
+        Self {
+            parameters,
+            proving_context: ProvingContextCache::from_loader(loader, policy),
+            tagged_to_public: None,
+            prover: Default::default(),
+            witness_refresher: None,
         }
     }
+
+    /// Sets the proving context of the tagged [`ToPublic`](transfer::canonical::ToPublic)
+    /// circuit to `proving_context`, enabling tagged withdrawals.
+    #[inline]
+    pub fn with_tagged_to_public(mut self, proving_context: ProvingContext<C>) -> Self {
+        self.tagged_to_public = Some(proving_context);
+        self
+    }
+
+    /// Replaces the batch prover used to generate the proofs of multi-post transactions with
+    /// `prover`.
+    #[inline]
+    pub fn with_prover(mut self, prover: C::Prover) -> Self {
+        self.prover = prover;
+        self
+    }
+
+    /// Sets the [`WitnessRefresher`](witness::WitnessRefresher) used to refresh the membership
+    /// proofs of the senders of a transaction right before proving to `refresher`.
+    #[inline]
+    pub fn with_witness_refresher(mut self, refresher: SharedWitnessRefresher<C>) -> Self {
+        self.witness_refresher = Some(refresher);
+        self
+    }
 }
 
 /// Signer State
@@ -1202,6 +1420,12 @@ where
                 C::NullifierMap: Deserialize<'de>,
                 C::Checkpoint: Deserialize<'de>,
                 C::AccountId: Deserialize<'de>,
+                SpendingPolicyState<C>: Deserialize<'de>,
+                LabelBook<C>: Deserialize<'de>,
+                ChurnSchedule<C>: Deserialize<'de>,
+                ConfirmationState<C>: Deserialize<'de>,
+                SyncHeight<C>: Deserialize<'de>,
+                ObservedSpends<C>: Deserialize<'de>,
             ",
             serialize = r"
                 AccountTable<C>: Serialize,
@@ -1211,6 +1435,12 @@ where
                 C::NullifierMap: Serialize,
                 C::Checkpoint: Serialize,
                 C::AccountId: Serialize,
+                SpendingPolicyState<C>: Serialize,
+                LabelBook<C>: Serialize,
+                ChurnSchedule<C>: Serialize,
+                ConfirmationState<C>: Serialize,
+                SyncHeight<C>: Serialize,
+                ObservedSpends<C>: Serialize,
             ",
         ),
         crate = "manta_util::serde",
@@ -1226,7 +1456,13 @@ where
         C::AssetMap: Debug,
         C::NullifierMap: Debug,
         C::Checkpoint: Debug,
-        C::Rng: Debug
+        C::Rng: Debug,
+        SpendingPolicyState<C>: Debug,
+        LabelBook<C>: Debug,
+        ChurnSchedule<C>: Debug,
+        ConfirmationState<C>: Debug,
+        SyncHeight<C>: Debug,
+        ObservedSpends<C>: Debug
     "),
     Default(bound = r"
         AccountTable<C>: Default,
@@ -1235,7 +1471,13 @@ where
         C::AssetMap: Default,
         C::NullifierMap: Default,
         C::Checkpoint: Default,
-        C::Rng: Default
+        C::Rng: Default,
+        SpendingPolicyState<C>: Default,
+        LabelBook<C>: Default,
+        ChurnSchedule<C>: Default,
+        ConfirmationState<C>: Default,
+        SyncHeight<C>: Default,
+        ObservedSpends<C>: Default
     "),
     Eq(bound = r"
         AccountTable<C>: Eq,
@@ -1244,7 +1486,13 @@ where
         C::AssetMap: Eq,
         C::NullifierMap: Eq,
         C::Checkpoint: Eq,
-        C::Rng: Eq
+        C::Rng: Eq,
+        SpendingPolicyState<C>: Eq,
+        LabelBook<C>: Eq,
+        ChurnSchedule<C>: Eq,
+        ConfirmationState<C>: Eq,
+        SyncHeight<C>: Eq,
+        ObservedSpends<C>: Eq
     "),
     Hash(bound = r"
         AccountTable<C>: Hash,
@@ -1253,7 +1501,13 @@ where
         C::AssetMap: Hash,
         C::NullifierMap: Hash,
         C::Checkpoint: Hash,
-        C::Rng: Hash
+        C::Rng: Hash,
+        SpendingPolicyState<C>: Hash,
+        LabelBook<C>: Hash,
+        ChurnSchedule<C>: Hash,
+        ConfirmationState<C>: Hash,
+        SyncHeight<C>: Hash,
+        ObservedSpends<C>: Hash
     "),
     PartialEq(bound = r"
         AccountTable<C>: PartialEq,
@@ -1262,7 +1516,13 @@ where
         C::AssetMap: PartialEq,
         C::NullifierMap: PartialEq,
         C::Checkpoint: PartialEq,
-        C::Rng: PartialEq
+        C::Rng: PartialEq,
+        SpendingPolicyState<C>: PartialEq,
+        LabelBook<C>: PartialEq,
+        ChurnSchedule<C>: PartialEq,
+        ConfirmationState<C>: PartialEq,
+        SyncHeight<C>: PartialEq,
+        ObservedSpends<C>: PartialEq
     ")
 )]
 pub struct SignerState<C>
@@ -1293,6 +1553,30 @@ where
     /// Current Checkpoint
     checkpoint: C::Checkpoint,
 
+    /// Spending Policy State
+    #[cfg_attr(feature = "serde", serde(default))]
+    policy: SpendingPolicyState<C>,
+
+    /// Note and Transaction Labels
+    #[cfg_attr(feature = "serde", serde(default))]
+    labels: LabelBook<C>,
+
+    /// Self-Churn Schedule
+    #[cfg_attr(feature = "serde", serde(default))]
+    churn: ChurnSchedule<C>,
+
+    /// Confirmation State
+    #[cfg_attr(feature = "serde", serde(default))]
+    confirmations: ConfirmationState<C>,
+
+    /// Synchronized Height
+    #[cfg_attr(feature = "serde", serde(default))]
+    height: SyncHeight<C>,
+
+    /// Observed Spends
+    #[cfg_attr(feature = "serde", serde(default))]
+    spends: ObservedSpends<C>,
+
     /// Random Number Generator
     ///
     /// We use this entropy source to add randomness to various cryptographic constructions. The
@@ -1321,6 +1605,12 @@ where
             utxo_accumulator,
             assets,
             nullifiers,
+            policy: Default::default(),
+            labels: Default::default(),
+            churn: Default::default(),
+            confirmations: Default::default(),
+            height: Default::default(),
+            spends: Default::default(),
             rng,
         }
     }
@@ -1403,6 +1693,50 @@ where
                 .collect(),
         )
     }
+
+    /// Returns a vector with all the [`Asset`]s owned by `self` and the labels of their notes.
+    #[inline]
+    pub fn labeled_asset_list(&self) -> LabeledAssetListResponse<C>
+    where
+        Identifier<C>: PartialEq,
+    {
+        LabeledAssetListResponse(
+            self.asset_list()
+                .0
+                .into_iter()
+                .map(|asset| {
+                    let labels = self.labels.note_labels(&asset.identifier).to_vec();
+                    (asset, labels)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the total value owned by `self` in the notes labeled with `label`, for each asset
+    /// id.
+    #[inline]
+    pub fn label_balance(&self, label: &str) -> Vec<Asset<C>>
+    where
+        Identifier<C>: PartialEq,
+    {
+        let mut balance = Vec::<Asset<C>>::new();
+        for (identifier, asset) in self.assets.asset_vector() {
+            if asset.is_zero()
+                || !self
+                    .labels
+                    .note_labels(&identifier)
+                    .iter()
+                    .any(|l| l == label)
+            {
+                continue;
+            }
+            match balance.iter_mut().find(|entry| entry.id == asset.id) {
+                Some(entry) => entry.value += asset.value,
+                _ => balance.push(asset),
+            }
+        }
+        balance
+    }
 }
 
 impl<C> Clone for SignerState<C>
@@ -1413,6 +1747,12 @@ where
     C::UtxoAccumulator: Clone,
     C::AssetMap: Clone,
     C::NullifierMap: Clone,
+    SpendingPolicyState<C>: Clone,
+    LabelBook<C>: Clone,
+    ChurnSchedule<C>: Clone,
+    ConfirmationState<C>: Clone,
+    SyncHeight<C>: Clone,
+    ObservedSpends<C>: Clone,
 {
     #[inline]
     fn clone(&self) -> Self {
@@ -1422,6 +1762,12 @@ where
             self.nullifiers.clone(),
             FromEntropy::from_entropy(),
         );
+        signer_state.policy = self.policy.clone();
+        signer_state.labels = self.labels.clone();
+        signer_state.churn = self.churn.clone();
+        signer_state.confirmations = self.confirmations.clone();
+        signer_state.height = self.height.clone();
+        signer_state.spends = self.spends.clone();
         if self.accounts.is_some() {
             signer_state.load_accounts(self.accounts.as_ref().unwrap().clone());
         }
@@ -1463,6 +1809,23 @@ where
 
     /// Signer State
     state: SignerState<C>,
+
+    /// Signer Hooks
+    ///
+    /// Hooks are runtime extensions of the signer and are not serialized with it.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derivative(Debug = "ignore", Hash = "ignore", PartialEq = "ignore")]
+    hooks: SignerHooks<C>,
+
+    /// Name Resolver
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derivative(Debug = "ignore", Hash = "ignore", PartialEq = "ignore")]
+    resolver: Option<SharedNameResolver<C>>,
+
+    /// Synchronization Progress Reporter
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derivative(Debug = "ignore", Hash = "ignore", PartialEq = "ignore")]
+    progress: SyncProgressReporter,
 }
 
 impl<C> Signer<C>
@@ -1472,14 +1835,20 @@ where
     /// Builds a new [`Signer`] from `parameters` and `state`.
     #[inline]
     pub fn from_parts(parameters: SignerParameters<C>, state: SignerState<C>) -> Self {
-        Self { parameters, state }
+        Self {
+            parameters,
+            state,
+            hooks: Default::default(),
+            resolver: None,
+            progress: Default::default(),
+        }
     }
 
     /// Builds a new [`Signer`].
     #[inline]
     fn new_inner(
         parameters: Parameters<C>,
-        proving_context: MultiProvingContext<C>,
+        proving_context: ProvingContextCache<C>,
         utxo_accumulator: C::UtxoAccumulator,
         assets: C::AssetMap,
         nullifiers: C::NullifierMap,
@@ -1489,6 +1858,9 @@ where
             SignerParameters {
                 parameters,
                 proving_context,
+                tagged_to_public: None,
+                prover: Default::default(),
+                witness_refresher: None,
             },
             SignerState::build(utxo_accumulator, assets, nullifiers, rng),
         )
@@ -1504,7 +1876,27 @@ where
     ) -> Self {
         Self::new_inner(
             parameters,
-            proving_context,
+            proving_context.into(),
+            utxo_accumulator,
+            Default::default(),
+            Default::default(),
+            rng,
+        )
+    }
+
+    /// Builds a new [`Signer`] which loads its proving contexts on demand from `loader`,
+    /// releasing them according to `policy`.
+    #[inline]
+    pub fn from_loader(
+        parameters: Parameters<C>,
+        loader: SharedProvingContextLoader<C>,
+        policy: EvictionPolicy,
+        utxo_accumulator: C::UtxoAccumulator,
+        rng: C::Rng,
+    ) -> Self {
+        Self::new_inner(
+            parameters,
+            ProvingContextCache::from_loader(loader, policy),
             utxo_accumulator,
             Default::default(),
             Default::default(),
@@ -1512,6 +1904,13 @@ where
         )
     }
 
+    /// Loads the proving contexts for `shapes` ahead of time, for hosts which prefer to pay the
+    /// loading cost eagerly instead of on the first transaction of each shape.
+    #[inline]
+    pub fn preload(&mut self, shapes: &[TransferShape]) -> Result<(), SignError<C>> {
+        self.parameters.proving_context.preload(shapes)
+    }
+
     /// Returns a shared reference to the signer parameters.
     #[inline]
     pub fn parameters(&self) -> &SignerParameters<C> {
@@ -1583,8 +1982,121 @@ where
     ) -> Result<SyncResponse<C, C::Checkpoint>, SyncError<C::Checkpoint>>
     where
         C::AssetValue: CheckedAdd<Output = C::AssetValue> + CheckedSub<Output = C::AssetValue>,
+        Identifier<C>: Clone + PartialEq,
+    {
+        let has_utxos = !request.data.utxo_note_data.is_empty();
+        let height = request.height;
+        self.sync_and_record(has_utxos, height, move |signer| {
+            functions::sync(
+                &signer.parameters,
+                signer
+                    .state
+                    .authorization_context
+                    .as_mut()
+                    .ok_or(SyncError::MissingProofAuthorizationKey)?,
+                &mut signer.state.assets,
+                &mut signer.state.nullifiers,
+                &mut signer.state.checkpoint,
+                &mut signer.state.utxo_accumulator,
+                request,
+                &signer.progress,
+                &mut signer.state.rng,
+            )
+        })
+    }
+
+    /// Updates the internal ledger state by applying `diff`, returning the new asset distribution.
+    /// See [`functions::sync_diff`] for the conditions under which `diff` is rejected.
+    #[inline]
+    pub fn sync_diff(
+        &mut self,
+        diff: AccumulatorDiff<C, C::Checkpoint>,
+    ) -> Result<SyncResponse<C, C::Checkpoint>, SyncError<C::Checkpoint>>
+    where
+        C::AssetValue: CheckedAdd<Output = C::AssetValue> + CheckedSub<Output = C::AssetValue>,
+        UtxoAccumulatorItem<C>: Clone,
+        UtxoAccumulatorOutput<C>: PartialEq,
+        Identifier<C>: Clone + PartialEq,
+    {
+        let has_utxos = !diff.leaves.is_empty();
+        let height = diff.height;
+        self.sync_and_record(has_utxos, height, move |signer| {
+            functions::sync_diff(
+                &signer.parameters,
+                signer
+                    .state
+                    .authorization_context
+                    .as_mut()
+                    .ok_or(SyncError::MissingProofAuthorizationKey)?,
+                &mut signer.state.assets,
+                &mut signer.state.nullifiers,
+                &mut signer.state.checkpoint,
+                &mut signer.state.utxo_accumulator,
+                diff,
+                &signer.progress,
+                &mut signer.state.rng,
+            )
+        })
+    }
+
+    /// Runs `sync` on `self`, recording the notes it brings in the [`ConfirmationState`] of `self`
+    /// if the synchronization `has_utxos`, and in its [`SyncHeight`] if the ledger reported the
+    /// `height` of the data. The [`ObservedSpends`] whose nullifiers were synchronized are dropped.
+    #[inline]
+    fn sync_and_record<F>(
+        &mut self,
+        has_utxos: bool,
+        height: Option<u64>,
+        sync: F,
+    ) -> Result<SyncResponse<C, C::Checkpoint>, SyncError<C::Checkpoint>>
+    where
+        F: FnOnce(&mut Self) -> Result<SyncResponse<C, C::Checkpoint>, SyncError<C::Checkpoint>>,
+        Identifier<C>: Clone + PartialEq,
+    {
+        let confirmations = has_utxos && self.state.confirmations.is_enabled();
+        if !confirmations && height.is_none() && self.state.spends.is_empty() {
+            return sync(self);
+        }
+        let known = self.state.assets.asset_vector();
+        let response = sync(self)?;
+        let assets = self.state.assets.asset_vector();
+        let received = assets
+            .iter()
+            .filter(|(identifier, _)| !known.iter().any(|(known, _)| known == identifier))
+            .map(|(identifier, asset)| (identifier.clone(), asset.id.clone()))
+            .collect::<Vec<_>>();
+        if let Some(height) = height {
+            self.state.height.record(
+                height,
+                received.iter().map(|(identifier, _)| identifier.clone()),
+            );
+            self.state
+                .height
+                .retain(|note| assets.iter().any(|(identifier, _)| identifier == note));
+        }
+        if confirmations {
+            self.state.confirmations.record(received);
+        }
+        self.state
+            .spends
+            .retain(|note| assets.iter().any(|(identifier, _)| identifier == note));
+        Ok(response)
+    }
+
+    /// Merges `other`, the [`StorageState`] exported from another device which uses the same seed,
+    /// into `self`, returning the full balance of the merged state. See [`functions::merge`] for
+    /// how conflicts between the two states are resolved.
+    #[inline]
+    pub fn merge(
+        &mut self,
+        other: StorageState<C>,
+    ) -> Result<SyncResponse<C, C::Checkpoint>, SyncError<C::Checkpoint>>
+    where
+        Identifier<C>: Clone + PartialEq,
     {
-        functions::sync(
+        self.state.labels.merge(other.labels);
+        self.state.height.merge(other.height);
+        Ok(functions::merge(
             &self.parameters,
             self.state
                 .authorization_context
@@ -1594,9 +2106,12 @@ where
             &mut self.state.nullifiers,
             &mut self.state.checkpoint,
             &mut self.state.utxo_accumulator,
-            request,
+            other.assets,
+            other.nullifiers,
+            other.checkpoint,
+            other.utxo_accumulator,
             &mut self.state.rng,
-        )
+        ))
     }
 
     /// Performs the initial synchronization of a new signer with the ledger data.
@@ -1628,6 +2143,7 @@ where
         identified_asset: IdentifiedAsset<C>,
         public_account: C::AccountId,
     ) -> Option<IdentityProof<C>> {
+        self.preload(&[TransferShape::ToPublic]).ok()?;
         functions::identity_proof(
             &self.parameters,
             self.state.accounts.as_ref()?,
@@ -1638,21 +2154,556 @@ where
         )
     }
 
+    /// Signs the posts in `request` with the default spending key, acting as the offline device
+    /// of an air-gapped setup. See the [`airgap`] module for more.
+    #[inline]
+    pub fn sign_air_gap_request(
+        &mut self,
+        request: &AirGapRequest<C>,
+    ) -> Result<AirGapResponse<C>, SignError<C>>
+    where
+        Authorization<C>: Clone,
+    {
+        let accounts = self
+            .state
+            .accounts
+            .as_ref()
+            .ok_or(SignError::MissingSpendingKey)?;
+        Ok(request.sign(
+            &self.parameters.parameters,
+            &functions::default_account::<C>(accounts).spending_key(),
+            &mut self.state.rng,
+        ))
+    }
+
+    /// Returns the [`SpendingPolicy`] enforced by `self`.
+    #[inline]
+    pub fn spending_policy(&self) -> &SpendingPolicy<C> {
+        self.state.policy.policy()
+    }
+
+    /// Replaces the [`SpendingPolicy`] enforced by `self` with `policy`.
+    #[inline]
+    pub fn set_spending_policy(&mut self, policy: SpendingPolicy<C>) {
+        self.state.policy.set_policy(policy)
+    }
+
+    /// Moves the daily spending totals of `self` to `day`, counted in days since the Unix epoch.
+    ///
+    /// # Note
+    ///
+    /// With the `std` feature enabled, the day is read from the system clock before every
+    /// transaction is signed. Otherwise, hosts must call this method to reset the daily totals.
+    #[inline]
+    pub fn advance_policy_day(&mut self, day: u64) {
+        self.state.policy.advance_day(day)
+    }
+
+    /// Records the notes of `self` spent by `nullifiers`, as delivered by a
+    /// [`SubscribeNullifiers`](ledger::SubscribeNullifiers) ledger connection, returning the notes
+    /// which were not already known to be spent. These notes are no longer selected for new
+    /// transactions, and they are withdrawn by the synchronization which brings `nullifiers`.
+    #[inline]
+    pub fn observe_nullifiers(
+        &mut self,
+        nullifiers: Vec<Nullifier<C>>,
+    ) -> Result<Vec<IdentifiedAsset<C>>, SyncError<C::Checkpoint>>
+    where
+        Identifier<C>: Clone + PartialEq,
+    {
+        let spent = functions::observe_nullifiers::<C>(
+            &self.parameters.parameters,
+            self.state
+                .authorization_context
+                .as_mut()
+                .ok_or(SyncError::MissingProofAuthorizationKey)?,
+            &self.state.assets,
+            nullifiers,
+            &mut self.state.rng,
+        )
+        .into_iter()
+        .filter(|asset| !self.state.spends.contains(&asset.identifier))
+        .collect::<Vec<_>>();
+        self.state
+            .spends
+            .record(spent.iter().map(|asset| asset.identifier.clone()));
+        Ok(spent)
+    }
+
+    /// Returns the [`ObservedSpends`] of `self`, with the notes spent on the ledger but not yet
+    /// synchronized.
+    #[inline]
+    pub fn observed_spends(&self) -> &ObservedSpends<C> {
+        &self.state.spends
+    }
+
+    /// Returns the [`SyncHeight`] of `self`, with the block height it is synchronized to and the
+    /// heights at which its notes were received.
+    #[inline]
+    pub fn sync_height(&self) -> &SyncHeight<C> {
+        &self.state.height
+    }
+
+    /// Returns the [`ConfirmationPolicy`] enforced by `self`.
+    #[inline]
+    pub fn confirmation_policy(&self) -> &ConfirmationPolicy<C> {
+        self.state.confirmations.policy()
+    }
+
+    /// Replaces the [`ConfirmationPolicy`] enforced by `self` with `policy`. See
+    /// [`ConfirmationState::set_policy`] for more.
+    #[inline]
+    pub fn set_confirmation_policy(&mut self, policy: ConfirmationPolicy<C>) {
+        self.state.confirmations.set_policy(policy)
+    }
+
+    /// Returns the balance of the asset with the given `id` held by `self`, split between the
+    /// value which can be spent and the value waiting for confirmations. The notes observed as
+    /// spent are left out of both.
+    #[inline]
+    pub fn spendable_balance(&self, id: &C::AssetId) -> SpendableBalance<C::AssetValue>
+    where
+        Identifier<C>: PartialEq,
+    {
+        if self.state.spends.is_empty() {
+            return self.state.confirmations.balance(&self.state.assets, id);
+        }
+        let mut unspent = C::AssetMap::default();
+        for (identifier, asset) in self.state.assets.asset_vector_with_id(id) {
+            if !self.state.spends.contains(&identifier) {
+                unspent.insert(identifier, asset);
+            }
+        }
+        self.state.confirmations.balance(&unspent, id)
+    }
+
+    /// Returns the [`ChurnSchedule`] of `self`.
+    #[inline]
+    pub fn churn_schedule(&self) -> &ChurnSchedule<C> {
+        &self.state.churn
+    }
+
+    /// Replaces the [`ChurnPolicy`] of `self` with `policy`, disabling self-transfers if `policy`
+    /// is `None`. See [`ChurnSchedule::set_policy`] for more.
+    #[inline]
+    pub fn set_churn_policy(&mut self, policy: Option<ChurnPolicy<C::AssetId, C::AssetValue>>) {
+        self.state.churn.set_policy(policy)
+    }
+
+    /// Signs the self-transfer of the [`ChurnPolicy`] of `self` which is due at time `now`, counted
+    /// in seconds since the Unix epoch, returning `None` if no self-transfer is due.
+    ///
+    /// # Note
+    ///
+    /// Hosts running a churn policy should call this method periodically, for instance with
+    /// [`churn::now`] under the `std` feature, and post the returned transfers like any other.
+    /// Self-transfers don't leave the wallet, so they are not checked against the spending policy
+    /// of `self`, but the signer hooks apply to them.
+    #[inline]
+    pub fn churn(&mut self, now: u64) -> Result<Option<SignResponse<C>>, SignError<C>>
+    where
+        C::AssetValue: SubAssign,
+        Identifier<C>: PartialEq,
+    {
+        let asset = match self.state.churn.poll(now, &mut self.state.rng) {
+            Some(asset) => asset,
+            _ => return Ok(None),
+        };
+        let address = self
+            .address()
+            .ok_or(SignError::MissingProofAuthorizationKey)?;
+        self.preload(&[TransferShape::PrivateTransfer])?;
+        let mut assets = None;
+        let assets = spendable_assets(
+            &self.state.assets,
+            &self.state.confirmations,
+            &self.state.spends,
+            &mut assets,
+        );
+        let response = functions::sign(
+            &self.parameters,
+            self.state.accounts.as_ref(),
+            self.state.authorization_context.as_mut(),
+            assets,
+            &self.hooks,
+            &mut self.state.utxo_accumulator,
+            Transaction::PrivateTransfer(asset, address),
+            &mut self.state.rng,
+        )?;
+        self.state.churn.record();
+        Ok(Some(response))
+    }
+
+    /// Returns the [`LabelBook`] of `self`.
+    #[inline]
+    pub fn labels(&self) -> &LabelBook<C> {
+        &self.state.labels
+    }
+
+    /// Adds `label` to the note with `identifier`, returning `false` if it was already there.
+    #[inline]
+    pub fn add_note_label(&mut self, identifier: Identifier<C>, label: Label) -> bool
+    where
+        Identifier<C>: PartialEq,
+    {
+        self.state.labels.add_note_label(identifier, label)
+    }
+
+    /// Removes `label` from the note with `identifier`, returning `false` if it was not there.
+    #[inline]
+    pub fn remove_note_label(&mut self, identifier: &Identifier<C>, label: &str) -> bool
+    where
+        Identifier<C>: PartialEq,
+    {
+        self.state.labels.remove_note_label(identifier, label)
+    }
+
+    /// Adds `label` to the transaction with `transaction_data`, returning `false` if it was
+    /// already there.
+    #[inline]
+    pub fn add_transaction_label(
+        &mut self,
+        transaction_data: &TransactionData<C>,
+        label: Label,
+    ) -> bool
+    where
+        Identifier<C>: Clone + PartialEq,
+    {
+        self.state
+            .labels
+            .add_transaction_label(transaction_data, label)
+    }
+
+    /// Removes `label` from the transaction with `transaction_data`, returning `false` if it was
+    /// not there.
+    #[inline]
+    pub fn remove_transaction_label(
+        &mut self,
+        transaction_data: &TransactionData<C>,
+        label: &str,
+    ) -> bool
+    where
+        Identifier<C>: PartialEq,
+    {
+        self.state
+            .labels
+            .remove_transaction_label(transaction_data, label)
+    }
+
+    /// Returns a vector with all the [`Asset`]s owned by `self` and the labels of their notes.
+    #[inline]
+    pub fn labeled_asset_list(&self) -> LabeledAssetListResponse<C>
+    where
+        Identifier<C>: PartialEq,
+    {
+        self.state.labeled_asset_list()
+    }
+
+    /// Returns the total value owned by `self` in the notes labeled with `label`, for each asset
+    /// id.
+    #[inline]
+    pub fn label_balance(&self, label: &str) -> Vec<Asset<C>>
+    where
+        Identifier<C>: PartialEq,
+    {
+        self.state.label_balance(label)
+    }
+
+    /// Returns the [`TransactionData`] of `post` together with the labels of the transaction. See
+    /// [`transaction_data`](Self::transaction_data) for more.
+    #[inline]
+    pub fn labeled_transaction_data(
+        &mut self,
+        post: TransferPost<C>,
+    ) -> Option<(TransactionData<C>, Vec<Label>)>
+    where
+        Identifier<C>: PartialEq,
+    {
+        let transaction_data = self.transaction_data(post)?;
+        let labels = self
+            .state
+            .labels
+            .transaction_labels(&transaction_data)
+            .to_vec();
+        Some((transaction_data, labels))
+    }
+
+    /// Registers `hook` with `self` for the assets with `asset_id`, or for every asset if
+    /// `asset_id` is `None`. See [`SignerHook`](hook::SignerHook) for more.
+    #[inline]
+    pub fn register_hook(&mut self, asset_id: Option<C::AssetId>, hook: SharedSignerHook<C>) {
+        self.hooks.register(asset_id, hook)
+    }
+
+    /// Calls `callback` with the progress of every [`sync`](Self::sync) of `self`, every
+    /// `interval` processed UTXOs and once more at the end of each request. See [`SyncProgress`]
+    /// for the reported values.
+    ///
+    /// [`SyncProgress`]: progress::SyncProgress
+    #[inline]
+    pub fn set_sync_progress(&mut self, interval: NonZeroUsize, callback: SyncProgressCallback) {
+        self.progress = SyncProgressReporter::new(interval, callback);
+    }
+
+    /// Stops reporting the synchronization progress of `self`.
+    #[inline]
+    pub fn clear_sync_progress(&mut self) {
+        self.progress = Default::default();
+    }
+
+    /// Replaces the batch prover used by `self` to generate the proofs of multi-post
+    /// transactions with `prover`.
+    #[inline]
+    pub fn set_prover(&mut self, prover: C::Prover) {
+        self.parameters.prover = prover;
+    }
+
+    /// Sets the [`WitnessRefresher`](witness::WitnessRefresher) which `self` uses to refresh the
+    /// membership proofs of the senders of a transaction right before proving to `refresher`.
+    #[inline]
+    pub fn set_witness_refresher(&mut self, refresher: SharedWitnessRefresher<C>) {
+        self.parameters.witness_refresher = Some(refresher);
+    }
+
+    /// Removes the [`WitnessRefresher`](witness::WitnessRefresher) of `self`, so that the senders
+    /// of a transaction are proven against the UTXO accumulator of `self`.
+    #[inline]
+    pub fn clear_witness_refresher(&mut self) {
+        self.parameters.witness_refresher = None;
+    }
+
+    /// Returns the [`SignerHooks`] registered with `self`.
+    #[inline]
+    pub fn hooks(&self) -> &SignerHooks<C> {
+        &self.hooks
+    }
+
+    /// Sets the [`NameResolver`](resolver::NameResolver) which `self` uses to resolve the
+    /// recipient names of [`SignRequest`]s to `resolver`.
+    #[inline]
+    pub fn set_name_resolver(&mut self, resolver: SharedNameResolver<C>) {
+        self.resolver = Some(resolver);
+    }
+
+    /// Removes the [`NameResolver`](resolver::NameResolver) of `self`.
+    #[inline]
+    pub fn clear_name_resolver(&mut self) {
+        self.resolver = None;
+    }
+
+    /// Resolves `name` to an [`Address`] with the [`NameResolver`](resolver::NameResolver) of
+    /// `self`.
+    #[inline]
+    pub fn resolve_name(&self, name: &str) -> Result<Address<C>, NameResolutionError> {
+        self.resolver
+            .as_ref()
+            .ok_or(NameResolutionError::MissingResolver)?
+            .resolve(name)
+            .ok_or(NameResolutionError::UnknownName)
+    }
+
+    /// Returns the transaction of `request`, sending it to the address its recipient name
+    /// resolves to if it has one.
+    #[inline]
+    pub fn resolve_request<A>(
+        &self,
+        request: SignRequest<A, C>,
+    ) -> Result<Transaction<C>, SignError<C>> {
+        match (request.recipient_name, request.transaction) {
+            (None, transaction) => Ok(transaction),
+            (Some(name), Transaction::PrivateTransfer(asset, _)) => {
+                Ok(Transaction::PrivateTransfer(
+                    asset,
+                    self.resolve_name(&name)
+                        .map_err(SignError::NameResolution)?,
+                ))
+            }
+            _ => Err(SignError::NameResolution(
+                NameResolutionError::UnsupportedTransaction,
+            )),
+        }
+    }
+
+    /// Checks `transaction` against the [`SpendingPolicy`] of `self`, returning the asset it
+    /// spends, if any.
+    #[inline]
+    fn check_policy(
+        &mut self,
+        transaction: &Transaction<C>,
+    ) -> Result<Option<Asset<C>>, SignError<C>>
+    where
+        Address<C>: PartialEq,
+        C::AccountId: PartialEq,
+    {
+        #[cfg(feature = "std")]
+        self.advance_policy_day(policy::today());
+        self.state
+            .policy
+            .check(transaction)
+            .map_err(SignError::PolicyViolation)
+    }
+
+    /// Records `spent` in the daily spending totals of `self`.
+    #[inline]
+    fn record_policy(&mut self, spent: Option<Asset<C>>) {
+        if let Some(asset) = spent {
+            self.state.policy.record(asset);
+        }
+    }
+
+    /// Checks `transaction` before signing it, returning every problem which would make signing
+    /// fail, or an empty vector if none was found.
+    ///
+    /// The problems are reported in the order they would be met while signing: missing keys,
+    /// missing proving contexts, invalid recipient addresses, spending policy violations,
+    /// insufficient balances, and hook rejections of the coin selection. The proving context for
+    /// the shape of `transaction` is loaded if it is not already, and nothing else in `self` is
+    /// modified. Posts can still be rejected by [`SignerHook::check_posts`](hook::SignerHook)
+    /// once they are built, which cannot be checked without proving.
+    #[inline]
+    pub fn validate_request(&mut self, transaction: &Transaction<C>) -> Vec<RequestProblem<C>>
+    where
+        Identifier<C>: PartialEq,
+        Address<C>: Default + PartialEq,
+        C::AccountId: PartialEq,
+    {
+        let mut problems = Vec::new();
+        match transaction {
+            Transaction::ToPrivate(_) if self.state.authorization_context.is_none() => {
+                problems.push(RequestProblem::MissingProofAuthorizationKey)
+            }
+            Transaction::PrivateTransfer(_, _) | Transaction::ToPublic(_, _)
+                if self.state.accounts.is_none() =>
+            {
+                problems.push(RequestProblem::MissingSpendingKey)
+            }
+            _ => {}
+        }
+        if let Err(SignError::MissingProvingContext(shape)) = self.preload(&[transaction.shape()]) {
+            problems.push(RequestProblem::MissingProvingContext(shape));
+        }
+        if let Transaction::PrivateTransfer(_, address) = transaction {
+            if *address == Default::default() {
+                problems.push(RequestProblem::InvalidAddress);
+            }
+        }
+        #[cfg(feature = "std")]
+        self.advance_policy_day(policy::today());
+        if let Err(violation) = self.state.policy.check(transaction) {
+            problems.push(RequestProblem::PolicyViolation(violation));
+        }
+        let asset = match transaction {
+            Transaction::ToPrivate(_) => return problems,
+            Transaction::PrivateTransfer(asset, _) | Transaction::ToPublic(asset, _) => asset,
+        };
+        let balance = self.spendable_balance(&asset.id);
+        if balance.available < asset.value {
+            problems.push(RequestProblem::InsufficientBalance {
+                requested: asset.clone(),
+                balance,
+            });
+            return problems;
+        }
+        let mut assets = None;
+        let assets = spendable_assets(
+            &self.state.assets,
+            &self.state.confirmations,
+            &self.state.spends,
+            &mut assets,
+        );
+        if let Err(rejection) = self
+            .hooks
+            .check_selection(asset, &assets.select(asset).values)
+        {
+            problems.push(RequestProblem::HookRejection(rejection));
+        }
+        problems
+    }
+
     /// Signs the `transaction`, generating transfer posts.
     #[inline]
     pub fn sign(&mut self, transaction: Transaction<C>) -> Result<SignResponse<C>, SignError<C>>
     where
         C::AssetValue: SubAssign,
+        Identifier<C>: PartialEq,
+        Address<C>: PartialEq,
+        C::AccountId: PartialEq,
     {
-        functions::sign(
+        let spent = self.check_policy(&transaction)?;
+        self.preload(&[transaction.shape()])?;
+        let mut assets = None;
+        let assets = spendable_assets(
+            &self.state.assets,
+            &self.state.confirmations,
+            &self.state.spends,
+            &mut assets,
+        );
+        let response = functions::sign(
             &self.parameters,
             self.state.accounts.as_ref(),
             self.state.authorization_context.as_mut(),
-            &self.state.assets,
+            assets,
+            &self.hooks,
             &mut self.state.utxo_accumulator,
             transaction,
             &mut self.state.rng,
-        )
+        )?;
+        self.record_policy(spent);
+        Ok(response)
+    }
+
+    /// Signs a withdrawal of `asset` to `public_account` tagged with the public withdrawal `tag`,
+    /// generating transfer posts.
+    ///
+    /// The withdrawal is checked against the spending policy of `self` like the equivalent
+    /// [`Transaction::ToPublic`]. Signing fails with [`SignError::MissingProvingContext`] unless
+    /// the tagged proving context was set with [`set_tagged_to_public`](Self::set_tagged_to_public).
+    #[inline]
+    pub fn sign_tagged_to_public(
+        &mut self,
+        asset: Asset<C>,
+        public_account: C::AccountId,
+        tag: C::WithdrawalTag,
+    ) -> Result<SignResponse<C>, SignError<C>>
+    where
+        C::AssetValue: SubAssign,
+        Identifier<C>: PartialEq,
+        Address<C>: PartialEq,
+        C::AccountId: PartialEq,
+    {
+        let spent = self.check_policy(&Transaction::ToPublic(
+            asset.clone(),
+            public_account.clone(),
+        ))?;
+        self.preload(&[TransferShape::PrivateTransfer])?;
+        let mut assets = None;
+        let assets = spendable_assets(
+            &self.state.assets,
+            &self.state.confirmations,
+            &self.state.spends,
+            &mut assets,
+        );
+        let response = functions::sign_tagged_to_public(
+            &self.parameters,
+            self.state.accounts.as_ref(),
+            assets,
+            &self.hooks,
+            &mut self.state.utxo_accumulator,
+            asset,
+            public_account,
+            tag,
+            &mut self.state.rng,
+        )?;
+        self.record_policy(spent);
+        Ok(response)
+    }
+
+    /// Sets the proving context of the tagged [`ToPublic`](transfer::canonical::ToPublic)
+    /// circuit used by [`sign_tagged_to_public`](Self::sign_tagged_to_public) to
+    /// `proving_context`.
+    #[inline]
+    pub fn set_tagged_to_public(&mut self, proving_context: ProvingContext<C>) {
+        self.parameters.tagged_to_public = Some(proving_context);
     }
 
     /// Signs a [`ConsolidationPrerequest`] and returns the transfer posts if successful.
@@ -1670,10 +2721,19 @@ where
         C::AssetValue: SubAssign,
         C::Identifier: PartialEq,
     {
+        self.preload(&[TransferShape::PrivateTransfer])?;
+        let mut assets = None;
+        let assets = spendable_assets(
+            &self.state.assets,
+            &self.state.confirmations,
+            &self.state.spends,
+            &mut assets,
+        );
         functions::consolidate(
             &self.parameters,
             self.state.accounts.as_ref(),
-            &self.state.assets,
+            assets,
+            &self.hooks,
             &mut self.state.utxo_accumulator,
             request,
             &mut self.state.rng,
@@ -1705,6 +2765,28 @@ where
         ))
     }
 
+    /// Returns the [`DetectionKey`] corresponding to `self`, which can be handed to a light client
+    /// to filter notes before they are downloaded.
+    #[inline]
+    pub fn detection_key(&mut self) -> Option<DetectionKey<C>> {
+        Some(functions::detection_key(
+            &self.parameters,
+            self.state.authorization_context.as_mut()?,
+        ))
+    }
+
+    /// Returns the indices of the filters in `note_filters` which may belong to `self`. See
+    /// [`NoteDetect`](transfer::utxo::NoteDetect) for more.
+    #[inline]
+    pub fn detect_notes(&mut self, note_filters: &[NoteFilter<C>]) -> Vec<usize> {
+        match self.detection_key() {
+            Some(detection_key) => {
+                functions::detect_notes(&self.parameters, &detection_key, note_filters)
+            }
+            _ => Vec::new(),
+        }
+    }
+
     /// Returns the [`AuthorizationContext`] corresponding to `self`.
     #[inline]
     pub fn authorization_context(&self) -> Option<&AuthorizationContext<C>> {
@@ -1746,20 +2828,35 @@ where
     ) -> Result<SignWithTransactionDataResponse<C>, SignError<C>>
     where
         C::AssetValue: SubAssign,
+        Identifier<C>: PartialEq,
         TransferPost<C>: Clone,
+        Address<C>: PartialEq,
+        C::AccountId: PartialEq,
     {
-        functions::sign_with_transaction_data(
+        let spent = self.check_policy(&transaction)?;
+        self.preload(&[transaction.shape()])?;
+        let mut assets = None;
+        let assets = spendable_assets(
+            &self.state.assets,
+            &self.state.confirmations,
+            &self.state.spends,
+            &mut assets,
+        );
+        let response = functions::sign_with_transaction_data(
             &self.parameters,
             self.state.accounts.as_ref(),
             self.state
                 .authorization_context
                 .as_mut()
                 .ok_or(SignError::MissingProofAuthorizationKey)?,
-            &self.state.assets,
+            assets,
+            &self.hooks,
             &mut self.state.utxo_accumulator,
             transaction,
             &mut self.state.rng,
-        )
+        )?;
+        self.record_policy(spent);
+        Ok(response)
     }
 
     /// Builds a new [`StorageStateOption`] from `self`.
@@ -1769,6 +2866,12 @@ where
         C::UtxoAccumulator: Clone,
         C::AssetMap: Clone,
         C::NullifierMap: Clone,
+        SpendingPolicyState<C>: Clone,
+        LabelBook<C>: Clone,
+        ChurnSchedule<C>: Clone,
+        ConfirmationState<C>: Clone,
+        SyncHeight<C>: Clone,
+        ObservedSpends<C>: Clone,
     {
         Some(StorageState::from_signer(self))
     }
@@ -1780,6 +2883,12 @@ where
         C::UtxoAccumulator: Clone,
         C::AssetMap: Clone,
         C::NullifierMap: Clone,
+        SpendingPolicyState<C>: Clone,
+        LabelBook<C>: Clone,
+        ChurnSchedule<C>: Clone,
+        ConfirmationState<C>: Clone,
+        SyncHeight<C>: Clone,
+        ObservedSpends<C>: Clone,
     {
         if let Some(storage_state) = storage_state {
             storage_state.update_signer(self);
@@ -1846,12 +2955,40 @@ where
     }
 }
 
+/// Returns the assets of `assets` which can be selected for new transactions, leaving out the
+/// notes waiting for confirmations and the notes observed as spent. A new asset map is built in
+/// `buffer` if any note has to be left out.
+#[inline]
+fn spendable_assets<'a, C>(
+    assets: &'a C::AssetMap,
+    confirmations: &ConfirmationState<C>,
+    spends: &ObservedSpends<C>,
+    buffer: &'a mut Option<C::AssetMap>,
+) -> &'a C::AssetMap
+where
+    C: Configuration,
+    Identifier<C>: PartialEq,
+{
+    if spends.is_empty() {
+        return confirmations.spendable(assets, buffer);
+    }
+    let mut spendable = C::AssetMap::default();
+    for (identifier, asset) in assets.asset_vector() {
+        if !spends.contains(&identifier) && !confirmations.is_pending(&identifier, &asset.id) {
+            spendable.insert(identifier, asset);
+        }
+    }
+    buffer.insert(spendable)
+}
+
 impl<C> Connection<C> for Signer<C>
 where
     C: Configuration,
     C::AssetValue:
         CheckedAdd<Output = C::AssetValue> + CheckedSub<Output = C::AssetValue> + SubAssign,
-    C::Identifier: PartialEq,
+    C::Identifier: Clone + PartialEq,
+    Address<C>: PartialEq,
+    C::AccountId: PartialEq,
 {
     type AssetMetadata = C::AssetMetadata;
     type Checkpoint = C::Checkpoint;
@@ -1886,7 +3023,11 @@ where
         &mut self,
         request: SignRequest<Self::AssetMetadata, C>,
     ) -> LocalBoxFutureResult<SignResult<C>, Self::Error> {
-        Box::pin(async move { Ok(self.sign(request.transaction)) })
+        Box::pin(async move {
+            Ok(self
+                .resolve_request(request)
+                .and_then(|transaction| self.sign(transaction)))
+        })
     }
 
     #[inline]
@@ -1918,7 +3059,11 @@ where
     where
         TransferPost<C>: Clone,
     {
-        Box::pin(async move { Ok(self.sign_with_transaction_data(request.transaction)) })
+        Box::pin(async move {
+            Ok(self
+                .resolve_request(request)
+                .and_then(|transaction| self.sign_with_transaction_data(transaction)))
+        })
     }
 
     #[inline]
@@ -1950,12 +3095,24 @@ where
                 C::AssetMap: Deserialize<'de>,
                 C::NullifierMap: Deserialize<'de>,
                 C::Checkpoint: Deserialize<'de>,
+                SpendingPolicyState<C>: Deserialize<'de>,
+                LabelBook<C>: Deserialize<'de>,
+                ChurnSchedule<C>: Deserialize<'de>,
+                ConfirmationState<C>: Deserialize<'de>,
+                SyncHeight<C>: Deserialize<'de>,
+                ObservedSpends<C>: Deserialize<'de>,
             ",
             serialize = r"
                 C::UtxoAccumulator: Serialize,
                 C::AssetMap: Serialize,
                 C::NullifierMap: Serialize,
                 C::Checkpoint: Serialize,
+                SpendingPolicyState<C>: Serialize,
+                LabelBook<C>: Serialize,
+                ChurnSchedule<C>: Serialize,
+                ConfirmationState<C>: Serialize,
+                SyncHeight<C>: Serialize,
+                ObservedSpends<C>: Serialize,
             ",
         ),
         crate = "manta_util::serde",
@@ -1969,36 +3126,72 @@ where
         C::AssetMap: Clone,
         C::NullifierMap: Clone,
         C::Checkpoint: Clone,
+        SpendingPolicyState<C>: Clone,
+        LabelBook<C>: Clone,
+        ChurnSchedule<C>: Clone,
+        ConfirmationState<C>: Clone,
+        SyncHeight<C>: Clone,
+        ObservedSpends<C>: Clone,
     "),
     Debug(bound = r"
         C::UtxoAccumulator: Debug,
         C::AssetMap: Debug,
         C::NullifierMap: Debug,
         C::Checkpoint: Debug,
+        SpendingPolicyState<C>: Debug,
+        LabelBook<C>: Debug,
+        ChurnSchedule<C>: Debug,
+        ConfirmationState<C>: Debug,
+        SyncHeight<C>: Debug,
+        ObservedSpends<C>: Debug,
     "),
     Default(bound = r"
         C::UtxoAccumulator: Default,
         C::AssetMap: Default,
         C::NullifierMap: Default,
         C::Checkpoint: Default,
+        SpendingPolicyState<C>: Default,
+        LabelBook<C>: Default,
+        ChurnSchedule<C>: Default,
+        ConfirmationState<C>: Default,
+        SyncHeight<C>: Default,
+        ObservedSpends<C>: Default,
     "),
     Eq(bound = r"
         C::UtxoAccumulator: Eq,
         C::AssetMap: Eq,
         C::NullifierMap: Eq,
         C::Checkpoint: Eq,
+        SpendingPolicyState<C>: Eq,
+        LabelBook<C>: Eq,
+        ChurnSchedule<C>: Eq,
+        ConfirmationState<C>: Eq,
+        SyncHeight<C>: Eq,
+        ObservedSpends<C>: Eq,
     "),
     Hash(bound = r"
         C::UtxoAccumulator: Hash,
         C::AssetMap: Hash,
         C::NullifierMap: Hash,
         C::Checkpoint: Hash,
+        SpendingPolicyState<C>: Hash,
+        LabelBook<C>: Hash,
+        ChurnSchedule<C>: Hash,
+        ConfirmationState<C>: Hash,
+        SyncHeight<C>: Hash,
+        ObservedSpends<C>: Hash,
     "),
     PartialEq(bound = r"
         C::UtxoAccumulator: PartialEq,
         C::AssetMap: PartialEq,
         C::NullifierMap: PartialEq,
         C::Checkpoint: PartialEq,
+        SpendingPolicyState<C>: PartialEq,
+        LabelBook<C>: PartialEq,
+        ChurnSchedule<C>: PartialEq,
+        ConfirmationState<C>: PartialEq,
+        SyncHeight<C>: PartialEq,
+        ObservedSpends<C>: PartialEq,
     ")
 )]
 pub struct StorageState<C>
@@ -2016,6 +3209,30 @@ where
 
     /// Nullifiers
     nullifiers: C::NullifierMap,
+
+    /// Spending Policy State
+    #[cfg_attr(feature = "serde", serde(default))]
+    policy: SpendingPolicyState<C>,
+
+    /// Note and Transaction Labels
+    #[cfg_attr(feature = "serde", serde(default))]
+    labels: LabelBook<C>,
+
+    /// Self-Churn Schedule
+    #[cfg_attr(feature = "serde", serde(default))]
+    churn: ChurnSchedule<C>,
+
+    /// Confirmation State
+    #[cfg_attr(feature = "serde", serde(default))]
+    confirmations: ConfirmationState<C>,
+
+    /// Synchronized Height
+    #[cfg_attr(feature = "serde", serde(default))]
+    height: SyncHeight<C>,
+
+    /// Observed Spends
+    #[cfg_attr(feature = "serde", serde(default))]
+    spends: ObservedSpends<C>,
 }
 
 impl<C> StorageState<C>
@@ -2031,6 +3248,12 @@ where
             utxo_accumulator,
             assets: Default::default(),
             nullifiers: Default::default(),
+            policy: Default::default(),
+            labels: Default::default(),
+            churn: Default::default(),
+            confirmations: Default::default(),
+            height: Default::default(),
+            spends: Default::default(),
         }
     }
 
@@ -2041,11 +3264,23 @@ where
         C::UtxoAccumulator: Clone,
         C::AssetMap: Clone,
         C::NullifierMap: Clone,
+        SpendingPolicyState<C>: Clone,
+        LabelBook<C>: Clone,
+        ChurnSchedule<C>: Clone,
+        ConfirmationState<C>: Clone,
+        SyncHeight<C>: Clone,
+        ObservedSpends<C>: Clone,
     {
         self.checkpoint = signer.state.checkpoint.clone();
         self.utxo_accumulator = signer.state.utxo_accumulator.clone();
         self.assets = signer.state.assets.clone();
         self.nullifiers = signer.state.nullifiers.clone();
+        self.policy = signer.state.policy.clone();
+        self.labels = signer.state.labels.clone();
+        self.churn = signer.state.churn.clone();
+        self.confirmations = signer.state.confirmations.clone();
+        self.height = signer.state.height.clone();
+        self.spends = signer.state.spends.clone();
     }
 
     /// Builds a new [`StorageState`] from `signer`.
@@ -2055,12 +3290,24 @@ where
         C::UtxoAccumulator: Clone,
         C::AssetMap: Clone,
         C::NullifierMap: Clone,
+        SpendingPolicyState<C>: Clone,
+        LabelBook<C>: Clone,
+        ChurnSchedule<C>: Clone,
+        ConfirmationState<C>: Clone,
+        SyncHeight<C>: Clone,
+        ObservedSpends<C>: Clone,
     {
         Self {
             checkpoint: signer.state.checkpoint.clone(),
             utxo_accumulator: signer.state.utxo_accumulator.clone(),
             assets: signer.state.assets.clone(),
             nullifiers: signer.state.nullifiers.clone(),
+            policy: signer.state.policy.clone(),
+            labels: signer.state.labels.clone(),
+            churn: signer.state.churn.clone(),
+            confirmations: signer.state.confirmations.clone(),
+            height: signer.state.height.clone(),
+            spends: signer.state.spends.clone(),
         }
     }
 
@@ -2071,11 +3318,23 @@ where
         C::UtxoAccumulator: Clone,
         C::AssetMap: Clone,
         C::NullifierMap: Clone,
+        SpendingPolicyState<C>: Clone,
+        LabelBook<C>: Clone,
+        ChurnSchedule<C>: Clone,
+        ConfirmationState<C>: Clone,
+        SyncHeight<C>: Clone,
+        ObservedSpends<C>: Clone,
     {
         signer.state.checkpoint = self.checkpoint.clone();
         signer.state.utxo_accumulator = self.utxo_accumulator.clone();
         signer.state.assets = self.assets.clone();
         signer.state.nullifiers = self.nullifiers.clone();
+        signer.state.policy = self.policy.clone();
+        signer.state.labels = self.labels.clone();
+        signer.state.churn = self.churn.clone();
+        signer.state.confirmations = self.confirmations.clone();
+        signer.state.height = self.height.clone();
+        signer.state.spends = self.spends.clone();
     }
 
     /// Initializes a [`Signer`] from `self`, `accounts`, `parameters` and `proving_context`.
@@ -2089,6 +3348,12 @@ where
         C::UtxoAccumulator: Clone,
         C::AssetMap: Clone,
         C::NullifierMap: Clone,
+        SpendingPolicyState<C>: Clone,
+        LabelBook<C>: Clone,
+        ChurnSchedule<C>: Clone,
+        ConfirmationState<C>: Clone,
+        SyncHeight<C>: Clone,
+        ObservedSpends<C>: Clone,
     {
         let mut signer = Signer::new(
             parameters,
@@ -48,6 +48,9 @@ pub trait NullifierMap<T>: Default {
 
     /// Checks if `self` contains `item`.
     fn contains_item(&self, item: &T) -> bool;
+
+    /// Inserts every item of `other` which is not already in `self`.
+    fn merge(&mut self, other: Self);
 }
 
 impl<T> NullifierMap<T> for Vec<T>
@@ -92,6 +95,15 @@ where
     fn contains_item(&self, item: &T) -> bool {
         self.contains(item)
     }
+
+    #[inline]
+    fn merge(&mut self, other: Self) {
+        for item in other {
+            if !self.contains(&item) {
+                self.push(item);
+            }
+        }
+    }
 }
 
 impl<T> NullifierMap<T> for BTreeSet<T>
@@ -130,6 +142,11 @@ where
     fn contains_item(&self, item: &T) -> bool {
         self.contains(item)
     }
+
+    #[inline]
+    fn merge(&mut self, other: Self) {
+        Extend::extend(self, other)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -169,4 +186,9 @@ where
     fn contains_item(&self, item: &T) -> bool {
         self.contains(item)
     }
+
+    #[inline]
+    fn merge(&mut self, other: Self) {
+        Extend::extend(self, other)
+    }
 }
@@ -0,0 +1,115 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Observed Spends
+//!
+//! Ledger connections which implement [`SubscribeNullifiers`] deliver the nullifiers of the
+//! transactions they observe before these are part of the synchronization data. The
+//! [`Signer`](super::Signer) uses them to confirm its outgoing payments and to detect the notes
+//! spent by other devices with the same seed. Such notes are never selected again, and they are
+//! removed from the signer state by the synchronization which brings their nullifiers.
+//!
+//! [`SubscribeNullifiers`]: crate::wallet::ledger::SubscribeNullifiers
+
+use crate::transfer::{self, Identifier};
+use alloc::vec::Vec;
+use core::{fmt::Debug, hash::Hash};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Observed Spends
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "Identifier<C>: Deserialize<'de>",
+            serialize = "Identifier<C>: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "Identifier<C>: Clone"),
+    Debug(bound = "Identifier<C>: Debug"),
+    Default(bound = ""),
+    Eq(bound = "Identifier<C>: Eq"),
+    Hash(bound = "Identifier<C>: Hash"),
+    PartialEq(bound = "Identifier<C>: PartialEq")
+)]
+pub struct ObservedSpends<C>
+where
+    C: transfer::Configuration,
+{
+    /// Notes Spent on the Ledger but not yet Synchronized
+    notes: Vec<Identifier<C>>,
+}
+
+impl<C> ObservedSpends<C>
+where
+    C: transfer::Configuration,
+{
+    /// Returns `true` if no spend is waiting for a synchronization.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    /// Returns the notes which were observed as spent but not yet synchronized.
+    #[inline]
+    pub fn notes(&self) -> &[Identifier<C>] {
+        &self.notes
+    }
+
+    /// Returns `true` if the note with `identifier` was observed as spent.
+    #[inline]
+    pub fn contains(&self, identifier: &Identifier<C>) -> bool
+    where
+        Identifier<C>: PartialEq,
+    {
+        self.notes.contains(identifier)
+    }
+
+    /// Records the notes with the identifiers in `spent` as spent, returning the number of notes
+    /// which were not already recorded.
+    #[inline]
+    pub fn record<I>(&mut self, spent: I) -> usize
+    where
+        I: IntoIterator<Item = Identifier<C>>,
+        Identifier<C>: PartialEq,
+    {
+        let count = self.notes.len();
+        for identifier in spent {
+            if !self.notes.contains(&identifier) {
+                self.notes.push(identifier);
+            }
+        }
+        self.notes.len() - count
+    }
+
+    /// Retains only the notes for which `f` returns `true`, dropping the notes whose spend was
+    /// synchronized.
+    #[inline]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&Identifier<C>) -> bool,
+    {
+        self.notes.retain(f)
+    }
+}
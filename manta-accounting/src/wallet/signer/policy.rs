@@ -0,0 +1,353 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Signer Spending Policy
+//!
+//! Limits on the transactions a [`Signer`](super::Signer) agrees to sign, checked before any
+//! authorization signature or proof is produced. Deposits are never limited since they do not
+//! spend private assets.
+
+use crate::transfer::{self, canonical::Transaction, Address, Asset};
+use alloc::vec::Vec;
+use core::{fmt::Debug, hash::Hash};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Number of Seconds in a Day
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Returns the number of days elapsed since the Unix epoch according to the system clock.
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[inline]
+pub fn today() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs() / SECONDS_PER_DAY)
+        .unwrap_or_default()
+}
+
+/// Asset Limit
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AssetLimit<I, V> {
+    /// Asset Id
+    pub id: I,
+
+    /// Maximum Value per Transaction
+    pub per_transaction: Option<V>,
+
+    /// Maximum Total Value per Day
+    pub per_day: Option<V>,
+}
+
+/// Recipient Rule
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "T: Clone"),
+    Debug(bound = "T: Debug"),
+    Default(bound = ""),
+    Eq(bound = "T: Eq"),
+    Hash(bound = "T: Hash"),
+    PartialEq(bound = "T: PartialEq")
+)]
+pub enum RecipientRule<T> {
+    /// Allows every recipient
+    #[derivative(Default)]
+    AllowAll,
+
+    /// Allows only the listed recipients
+    Allow(Vec<T>),
+
+    /// Allows every recipient except the listed ones
+    Deny(Vec<T>),
+}
+
+impl<T> RecipientRule<T> {
+    /// Returns `true` if `self` allows sending to `recipient`.
+    #[inline]
+    pub fn permits(&self, recipient: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self {
+            Self::AllowAll => true,
+            Self::Allow(list) => list.contains(recipient),
+            Self::Deny(list) => !list.contains(recipient),
+        }
+    }
+}
+
+/// Spending Policy
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = r"
+                C::AssetId: Deserialize<'de>,
+                C::AssetValue: Deserialize<'de>,
+                Address<C>: Deserialize<'de>,
+                C::AccountId: Deserialize<'de>
+            ",
+            serialize = r"
+                C::AssetId: Serialize,
+                C::AssetValue: Serialize,
+                Address<C>: Serialize,
+                C::AccountId: Serialize
+            ",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(
+        bound = "C::AssetId: Clone, C::AssetValue: Clone, Address<C>: Clone, C::AccountId: Clone"
+    ),
+    Debug(
+        bound = "C::AssetId: Debug, C::AssetValue: Debug, Address<C>: Debug, C::AccountId: Debug"
+    ),
+    Default(bound = ""),
+    Eq(bound = "C::AssetId: Eq, C::AssetValue: Eq, Address<C>: Eq, C::AccountId: Eq"),
+    Hash(bound = "C::AssetId: Hash, C::AssetValue: Hash, Address<C>: Hash, C::AccountId: Hash"),
+    PartialEq(bound = r"
+        C::AssetId: PartialEq,
+        C::AssetValue: PartialEq,
+        Address<C>: PartialEq,
+        C::AccountId: PartialEq
+    ")
+)]
+pub struct SpendingPolicy<C>
+where
+    C: transfer::Configuration,
+{
+    /// Asset Limits
+    ///
+    /// Assets without a limit can be spent without restriction.
+    pub limits: Vec<AssetLimit<C::AssetId, C::AssetValue>>,
+
+    /// Private Transfer Recipients
+    pub addresses: RecipientRule<Address<C>>,
+
+    /// To-Public Recipients
+    pub accounts: RecipientRule<C::AccountId>,
+}
+
+impl<C> SpendingPolicy<C>
+where
+    C: transfer::Configuration,
+{
+    /// Returns the limit of the asset with the given `id`, if any.
+    #[inline]
+    pub fn limit(&self, id: &C::AssetId) -> Option<&AssetLimit<C::AssetId, C::AssetValue>> {
+        self.limits.iter().find(|limit| &limit.id == id)
+    }
+}
+
+/// Policy Violation
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "Asset<C>: Deserialize<'de>",
+            serialize = "Asset<C>: Serialize"
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "Asset<C>: Clone"),
+    Copy(bound = "Asset<C>: Copy"),
+    Debug(bound = "Asset<C>: Debug"),
+    Eq(bound = "Asset<C>: Eq"),
+    Hash(bound = "Asset<C>: Hash"),
+    PartialEq(bound = "Asset<C>: PartialEq")
+)]
+pub enum PolicyViolation<C>
+where
+    C: transfer::Configuration,
+{
+    /// Transaction Limit Exceeded
+    ///
+    /// The asset exceeds the maximum value allowed in a single transaction.
+    TransactionLimit(Asset<C>),
+
+    /// Daily Limit Exceeded
+    ///
+    /// The asset would bring the total spent today above the maximum value allowed per day.
+    DailyLimit(Asset<C>),
+
+    /// Recipient Not Allowed
+    RecipientNotAllowed,
+}
+
+/// Spending Policy State
+///
+/// Stores the [`SpendingPolicy`] of a signer along with the assets it has spent on the current
+/// day, so that both survive restarts when persisted with the signer state.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "SpendingPolicy<C>: Deserialize<'de>, Asset<C>: Deserialize<'de>",
+            serialize = "SpendingPolicy<C>: Serialize, Asset<C>: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "SpendingPolicy<C>: Clone, Asset<C>: Clone"),
+    Debug(bound = "SpendingPolicy<C>: Debug, Asset<C>: Debug"),
+    Default(bound = ""),
+    Eq(bound = "SpendingPolicy<C>: Eq, Asset<C>: Eq"),
+    Hash(bound = "SpendingPolicy<C>: Hash, Asset<C>: Hash"),
+    PartialEq(bound = "SpendingPolicy<C>: PartialEq, Asset<C>: PartialEq")
+)]
+pub struct SpendingPolicyState<C>
+where
+    C: transfer::Configuration,
+{
+    /// Spending Policy
+    policy: SpendingPolicy<C>,
+
+    /// Current Day
+    day: u64,
+
+    /// Assets Spent on the Current Day
+    spent: Vec<Asset<C>>,
+}
+
+impl<C> SpendingPolicyState<C>
+where
+    C: transfer::Configuration,
+{
+    /// Builds a new [`SpendingPolicyState`] enforcing `policy`.
+    #[inline]
+    pub fn new(policy: SpendingPolicy<C>) -> Self {
+        Self {
+            policy,
+            day: 0,
+            spent: Vec::new(),
+        }
+    }
+
+    /// Returns the spending policy of `self`.
+    #[inline]
+    pub fn policy(&self) -> &SpendingPolicy<C> {
+        &self.policy
+    }
+
+    /// Replaces the spending policy of `self` with `policy`, keeping the totals spent today.
+    #[inline]
+    pub fn set_policy(&mut self, policy: SpendingPolicy<C>) {
+        self.policy = policy;
+    }
+
+    /// Returns the current day of `self`.
+    #[inline]
+    pub fn day(&self) -> u64 {
+        self.day
+    }
+
+    /// Moves `self` to `day`, resetting the daily totals if `day` is later than the current day.
+    /// Earlier days are ignored so that the daily totals cannot be reset by a clock going
+    /// backwards.
+    #[inline]
+    pub fn advance_day(&mut self, day: u64) {
+        if day > self.day {
+            self.day = day;
+            self.spent.clear();
+        }
+    }
+
+    /// Returns the total value of the asset with the given `id` spent on the current day.
+    #[inline]
+    pub fn spent_today(&self, id: &C::AssetId) -> C::AssetValue {
+        self.spent
+            .iter()
+            .find(|asset| &asset.id == id)
+            .map(|asset| asset.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// Checks that `transaction` is allowed by the policy, returning the asset it spends, if
+    /// any, which should be [`record`](Self::record)ed once the transaction is signed.
+    #[inline]
+    pub fn check(
+        &self,
+        transaction: &Transaction<C>,
+    ) -> Result<Option<Asset<C>>, PolicyViolation<C>>
+    where
+        Address<C>: PartialEq,
+        C::AccountId: PartialEq,
+    {
+        let asset = match transaction {
+            Transaction::ToPrivate(_) => return Ok(None),
+            Transaction::PrivateTransfer(asset, address) => {
+                if !self.policy.addresses.permits(address) {
+                    return Err(PolicyViolation::RecipientNotAllowed);
+                }
+                asset
+            }
+            Transaction::ToPublic(asset, account) => {
+                if !self.policy.accounts.permits(account) {
+                    return Err(PolicyViolation::RecipientNotAllowed);
+                }
+                asset
+            }
+        };
+        if let Some(limit) = self.policy.limit(&asset.id) {
+            if matches!(&limit.per_transaction, Some(max) if &asset.value > max) {
+                return Err(PolicyViolation::TransactionLimit(asset.clone()));
+            }
+            if let Some(max) = &limit.per_day {
+                let mut total = self.spent_today(&asset.id);
+                total += asset.value.clone();
+                if &total > max {
+                    return Err(PolicyViolation::DailyLimit(asset.clone()));
+                }
+            }
+        }
+        Ok(Some(asset.clone()))
+    }
+
+    /// Adds `asset` to the totals spent on the current day.
+    #[inline]
+    pub fn record(&mut self, asset: Asset<C>) {
+        match self.spent.iter_mut().find(|spent| spent.id == asset.id) {
+            Some(spent) => spent.value += asset.value,
+            _ => self.spent.push(asset),
+        }
+    }
+}
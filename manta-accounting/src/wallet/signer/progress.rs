@@ -0,0 +1,101 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Signer Synchronization Progress
+//!
+//! Progress events emitted by a [`Signer`](super::Signer) while it processes the UTXOs of a
+//! [`SyncRequest`](super::SyncRequest), so that user interfaces can report accurate progress
+//! during long restores.
+
+use alloc::sync::Arc;
+use core::num::NonZeroUsize;
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Synchronization Progress
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SyncProgress {
+    /// Number of UTXOs of the request processed so far
+    pub processed_utxo_index: usize,
+
+    /// Number of UTXOs in the request
+    pub total: usize,
+
+    /// Number of new notes found so far in the request
+    pub new_notes_found: usize,
+}
+
+impl SyncProgress {
+    /// Returns `true` if every UTXO of the request has been processed.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.processed_utxo_index == self.total
+    }
+}
+
+/// Synchronization Progress Callback
+pub type SyncProgressCallback = Arc<dyn Fn(SyncProgress) + Send + Sync>;
+
+/// Synchronization Progress Reporter
+///
+/// Calls its callback every `interval` processed UTXOs and once more when the request has been
+/// fully processed.
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Default)]
+pub struct SyncProgressReporter {
+    /// Reporting Interval
+    interval: Option<NonZeroUsize>,
+
+    /// Progress Callback
+    callback: Option<SyncProgressCallback>,
+}
+
+impl SyncProgressReporter {
+    /// Builds a new [`SyncProgressReporter`] which calls `callback` every `interval` processed
+    /// UTXOs.
+    #[inline]
+    pub fn new(interval: NonZeroUsize, callback: SyncProgressCallback) -> Self {
+        Self {
+            interval: Some(interval),
+            callback: Some(callback),
+        }
+    }
+
+    /// Returns `true` if `self` has a callback to report to.
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.callback.is_some()
+    }
+
+    /// Reports `progress` to the callback of `self` if it falls on the reporting interval or
+    /// completes the request.
+    #[inline]
+    pub fn report(&self, progress: SyncProgress) {
+        if let (Some(interval), Some(callback)) = (self.interval, &self.callback) {
+            if progress.is_complete()
+                || progress.processed_utxo_index.is_multiple_of(interval.get())
+            {
+                callback(progress);
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Signer Batch Proving
+//!
+//! When a transaction is split into several [`TransferPost`]s, for instance by batch joins, the
+//! [`Signer`](super::Signer) first builds every [`Transfer`] and only then generates their proofs
+//! as a single batch. Since the transfers of a batch are independent, a [`BatchProver`] is free to
+//! prove them concurrently, as long as it returns the posts in the order of the batch.
+
+use crate::{
+    transfer::{
+        FullParametersRef, ProofSystemError, ProvingContext, SpendingKey, Transfer, TransferPost,
+    },
+    wallet::signer::Configuration,
+};
+use alloc::vec::Vec;
+use manta_crypto::rand::{CryptoRng, RngCore};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Proof Job
+///
+/// A [`Transfer`] waiting for its validity proof, together with its sink accounts.
+pub struct ProofJob<
+    C,
+    const SOURCES: usize,
+    const SENDERS: usize,
+    const RECEIVERS: usize,
+    const SINKS: usize,
+> where
+    C: Configuration,
+{
+    /// Transfer
+    pub transfer: Transfer<C, SOURCES, SENDERS, RECEIVERS, SINKS>,
+
+    /// Sink Accounts
+    pub sink_accounts: Vec<C::AccountId>,
+}
+
+impl<C, const SOURCES: usize, const SENDERS: usize, const RECEIVERS: usize, const SINKS: usize>
+    ProofJob<C, SOURCES, SENDERS, RECEIVERS, SINKS>
+where
+    C: Configuration,
+{
+    /// Builds a new [`ProofJob`] from `transfer` and `sink_accounts`.
+    #[inline]
+    pub fn new(
+        transfer: Transfer<C, SOURCES, SENDERS, RECEIVERS, SINKS>,
+        sink_accounts: Vec<C::AccountId>,
+    ) -> Self {
+        Self {
+            transfer,
+            sink_accounts,
+        }
+    }
+
+    /// Proves `self`, converting it into a [`TransferPost`] signed with `spending_key` if the
+    /// transfer requires an authorization.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the authorization of the transfer is missing or does not match
+    /// `spending_key`, which never happens for the transfers built by the signer.
+    #[inline]
+    pub fn prove<R>(
+        self,
+        parameters: FullParametersRef<C>,
+        proving_context: &ProvingContext<C>,
+        spending_key: Option<&SpendingKey<C>>,
+        rng: &mut R,
+    ) -> Result<TransferPost<C>, ProofSystemError<C>>
+    where
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        self.transfer
+            .into_post(
+                parameters,
+                proving_context,
+                spending_key,
+                self.sink_accounts,
+                rng,
+            )
+            .map(|p| p.expect("Internally, all transfer posts are constructed correctly."))
+    }
+}
+
+/// Batch Prover
+pub trait BatchProver<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Proves every job in `batch` against `proving_context`, returning their posts in the same
+    /// order as `batch`. Jobs which require an authorization are signed with `spending_key`.
+    fn prove_batch<
+        const SOURCES: usize,
+        const SENDERS: usize,
+        const RECEIVERS: usize,
+        const SINKS: usize,
+    >(
+        &self,
+        parameters: FullParametersRef<C>,
+        proving_context: &ProvingContext<C>,
+        spending_key: Option<&SpendingKey<C>>,
+        batch: Vec<ProofJob<C, SOURCES, SENDERS, RECEIVERS, SINKS>>,
+        rng: &mut C::Rng,
+    ) -> Result<Vec<TransferPost<C>>, ProofSystemError<C>>
+    where
+        C: Sized;
+}
+
+/// Sequential Prover
+///
+/// Proves the jobs of a batch one after the other on the current thread.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SequentialProver;
+
+impl<C> BatchProver<C> for SequentialProver
+where
+    C: Configuration,
+{
+    #[inline]
+    fn prove_batch<
+        const SOURCES: usize,
+        const SENDERS: usize,
+        const RECEIVERS: usize,
+        const SINKS: usize,
+    >(
+        &self,
+        parameters: FullParametersRef<C>,
+        proving_context: &ProvingContext<C>,
+        spending_key: Option<&SpendingKey<C>>,
+        batch: Vec<ProofJob<C, SOURCES, SENDERS, RECEIVERS, SINKS>>,
+        rng: &mut C::Rng,
+    ) -> Result<Vec<TransferPost<C>>, ProofSystemError<C>> {
+        batch
+            .into_iter()
+            .map(|job| job.prove(parameters, proving_context, spending_key, rng))
+            .collect()
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Lazy Proving Context Loading
+//!
+//! Proving keys are large, and a signer which only ever performs [`ToPrivate`] transactions does
+//! not need the [`PrivateTransfer`] or [`ToPublic`] keys in memory. The [`ProvingContextCache`]
+//! loads the [`ProvingContext`] for each [`TransferShape`] on first use through a
+//! [`ProvingContextLoader`] and releases them according to an [`EvictionPolicy`].
+//!
+//! [`ToPrivate`]: crate::transfer::canonical::ToPrivate
+//! [`PrivateTransfer`]: crate::transfer::canonical::PrivateTransfer
+//! [`ToPublic`]: crate::transfer::canonical::ToPublic
+
+use crate::{
+    transfer::{
+        canonical::{MultiProvingContext, TransferShape},
+        Configuration, ProvingContext,
+    },
+    wallet::signer::SignError,
+};
+use alloc::{sync::Arc, vec::Vec};
+use core::{fmt::Debug, hash::Hash};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Proving Context Loader
+pub trait ProvingContextLoader<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Loads the [`ProvingContext`] for `shape`, returning `None` if it is not available.
+    fn load(&self, shape: TransferShape) -> Option<ProvingContext<C>>;
+}
+
+impl<C, F> ProvingContextLoader<C> for F
+where
+    C: Configuration + ?Sized,
+    F: Fn(TransferShape) -> Option<ProvingContext<C>>,
+{
+    #[inline]
+    fn load(&self, shape: TransferShape) -> Option<ProvingContext<C>> {
+        self(shape)
+    }
+}
+
+/// Shared Proving Context Loader
+pub type SharedProvingContextLoader<C> = Arc<dyn ProvingContextLoader<C> + Send + Sync>;
+
+/// Eviction Policy
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EvictionPolicy {
+    /// Keep every proving context in memory once it has been loaded.
+    KeepAll,
+
+    /// Keep at most the given number of proving contexts in memory, evicting the least recently
+    /// used ones first.
+    ///
+    /// # Note
+    ///
+    /// Proving contexts needed by the operation in progress are never evicted, so the bound can be
+    /// exceeded temporarily if it is smaller than the number of shapes a single operation needs.
+    LeastRecentlyUsed(usize),
+}
+
+impl Default for EvictionPolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::KeepAll
+    }
+}
+
+/// Proving Context Cache
+///
+/// Only the loaded proving contexts take part in serialization and comparison. The loader and
+/// eviction policy must be attached again with [`set_loader`](Self::set_loader) after
+/// deserializing.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "ProvingContext<C>: Deserialize<'de>",
+            serialize = "ProvingContext<C>: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "ProvingContext<C>: Clone"),
+    Debug(bound = "ProvingContext<C>: Debug"),
+    Eq(bound = "ProvingContext<C>: Eq"),
+    Hash(bound = "ProvingContext<C>: Hash"),
+    PartialEq(bound = "ProvingContext<C>: PartialEq")
+)]
+pub struct ProvingContextCache<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// [`ToPrivate`](crate::transfer::canonical::ToPrivate) Proving Context
+    to_private: Option<ProvingContext<C>>,
+
+    /// [`PrivateTransfer`](crate::transfer::canonical::PrivateTransfer) Proving Context
+    private_transfer: Option<ProvingContext<C>>,
+
+    /// [`ToPublic`](crate::transfer::canonical::ToPublic) Proving Context
+    to_public: Option<ProvingContext<C>>,
+
+    /// Proving Context Loader
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derivative(Debug = "ignore", Hash = "ignore", PartialEq = "ignore")]
+    loader: Option<SharedProvingContextLoader<C>>,
+
+    /// Eviction Policy
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derivative(Hash = "ignore", PartialEq = "ignore")]
+    policy: EvictionPolicy,
+
+    /// Loaded Shapes, from Least to Most Recently Used
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derivative(Hash = "ignore", PartialEq = "ignore")]
+    recency: Vec<TransferShape>,
+}
+
+impl<C> ProvingContextCache<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Builds a new empty [`ProvingContextCache`] which loads proving contexts on demand from
+    /// `loader` and releases them according to `policy`.
+    #[inline]
+    pub fn from_loader(loader: SharedProvingContextLoader<C>, policy: EvictionPolicy) -> Self {
+        Self {
+            to_private: None,
+            private_transfer: None,
+            to_public: None,
+            loader: Some(loader),
+            policy,
+            recency: Vec::new(),
+        }
+    }
+
+    /// Sets the loader and eviction policy of `self`, keeping the proving contexts which are
+    /// already loaded.
+    #[inline]
+    pub fn set_loader(&mut self, loader: SharedProvingContextLoader<C>, policy: EvictionPolicy) {
+        self.loader = Some(loader);
+        self.policy = policy;
+    }
+
+    /// Returns the eviction policy of `self`.
+    #[inline]
+    pub fn policy(&self) -> EvictionPolicy {
+        self.policy
+    }
+
+    /// Returns a shared reference to the slot for `shape`.
+    #[inline]
+    fn slot(&self, shape: TransferShape) -> &Option<ProvingContext<C>> {
+        match shape {
+            TransferShape::ToPrivate => &self.to_private,
+            TransferShape::PrivateTransfer => &self.private_transfer,
+            TransferShape::ToPublic => &self.to_public,
+        }
+    }
+
+    /// Returns a mutable reference to the slot for `shape`.
+    #[inline]
+    fn slot_mut(&mut self, shape: TransferShape) -> &mut Option<ProvingContext<C>> {
+        match shape {
+            TransferShape::ToPrivate => &mut self.to_private,
+            TransferShape::PrivateTransfer => &mut self.private_transfer,
+            TransferShape::ToPublic => &mut self.to_public,
+        }
+    }
+
+    /// Returns `true` if the proving context for `shape` is currently loaded.
+    #[inline]
+    pub fn is_loaded(&self, shape: TransferShape) -> bool {
+        self.slot(shape).is_some()
+    }
+
+    /// Returns the number of proving contexts currently loaded.
+    #[inline]
+    pub fn loaded_count(&self) -> usize {
+        [
+            TransferShape::ToPrivate,
+            TransferShape::PrivateTransfer,
+            TransferShape::ToPublic,
+        ]
+        .into_iter()
+        .filter(|shape| self.is_loaded(*shape))
+        .count()
+    }
+
+    /// Returns the proving context for `shape` if it is loaded.
+    ///
+    /// # Note
+    ///
+    /// This method never loads anything. Use [`preload`](Self::preload) first to make sure the
+    /// proving contexts needed by an operation are in memory.
+    #[inline]
+    pub fn get(&self, shape: TransferShape) -> Result<&ProvingContext<C>, SignError<C>>
+    where
+        C: Sized,
+    {
+        self.slot(shape)
+            .as_ref()
+            .ok_or(SignError::MissingProvingContext(shape))
+    }
+
+    /// Marks `shape` as the most recently used shape.
+    #[inline]
+    fn touch(&mut self, shape: TransferShape) {
+        self.recency.retain(|s| *s != shape);
+        self.recency.push(shape);
+    }
+
+    /// Loads the proving contexts for every shape in `shapes` which is not already loaded, and
+    /// then applies the eviction policy, never evicting any of `shapes`.
+    #[inline]
+    pub fn preload(&mut self, shapes: &[TransferShape]) -> Result<(), SignError<C>>
+    where
+        C: Sized,
+    {
+        for shape in shapes {
+            if !self.is_loaded(*shape) {
+                let context = self
+                    .loader
+                    .as_ref()
+                    .and_then(|loader| loader.load(*shape))
+                    .ok_or(SignError::MissingProvingContext(*shape))?;
+                *self.slot_mut(*shape) = Some(context);
+            }
+            self.touch(*shape);
+        }
+        if let EvictionPolicy::LeastRecentlyUsed(capacity) = self.policy {
+            let candidates = self
+                .recency
+                .iter()
+                .copied()
+                .filter(|shape| !shapes.contains(shape))
+                .collect::<Vec<_>>();
+            for shape in candidates {
+                if self.loaded_count() <= capacity {
+                    break;
+                }
+                self.evict(shape);
+            }
+        }
+        Ok(())
+    }
+
+    /// Releases the proving context for `shape`, returning `true` if it was loaded.
+    ///
+    /// # Note
+    ///
+    /// Proving contexts are only released when `self` has a loader, since they could not be
+    /// reloaded otherwise.
+    #[inline]
+    pub fn evict(&mut self, shape: TransferShape) -> bool {
+        if self.loader.is_none() {
+            return false;
+        }
+        self.recency.retain(|s| *s != shape);
+        self.slot_mut(shape).take().is_some()
+    }
+}
+
+impl<C> From<MultiProvingContext<C>> for ProvingContextCache<C>
+where
+    C: Configuration + ?Sized,
+{
+    #[inline]
+    fn from(context: MultiProvingContext<C>) -> Self {
+        Self {
+            to_private: Some(context.to_private),
+            private_transfer: Some(context.private_transfer),
+            to_public: Some(context.to_public),
+            loader: None,
+            policy: EvictionPolicy::KeepAll,
+            recency: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Name Resolution
+//!
+//! Pluggable backends which map human-readable names to [`Address`]es, so that a
+//! [`SignRequest`](super::SignRequest) can name its recipient instead of spelling out its address.
+//! The [`Signer`](super::Signer) resolves the name with its registered [`NameResolver`] before
+//! signing the transaction.
+
+use crate::transfer::{self, Address};
+use alloc::{collections::BTreeMap, string::String, sync::Arc};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Name Resolver
+pub trait NameResolver<C>
+where
+    C: transfer::Configuration,
+{
+    /// Returns the [`Address`] registered for `name`, if any.
+    fn resolve(&self, name: &str) -> Option<Address<C>>;
+}
+
+/// Shared Name Resolver
+pub type SharedNameResolver<C> = Arc<dyn NameResolver<C> + Send + Sync>;
+
+/// Static Name Resolver
+///
+/// Resolves names from a fixed map, for instance the address book of a wallet.
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "Address<C>: Clone"),
+    Debug(bound = "Address<C>: core::fmt::Debug"),
+    Default(bound = ""),
+    Eq(bound = "Address<C>: Eq"),
+    PartialEq(bound = "Address<C>: PartialEq")
+)]
+pub struct StaticNameResolver<C>
+where
+    C: transfer::Configuration,
+{
+    /// Registered Names
+    names: BTreeMap<String, Address<C>>,
+}
+
+impl<C> StaticNameResolver<C>
+where
+    C: transfer::Configuration,
+{
+    /// Builds a new empty [`StaticNameResolver`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `address` for `name`, returning the address previously registered for `name`.
+    #[inline]
+    pub fn insert(&mut self, name: String, address: Address<C>) -> Option<Address<C>> {
+        self.names.insert(name, address)
+    }
+
+    /// Removes `name` from `self`, returning the address registered for it.
+    #[inline]
+    pub fn remove(&mut self, name: &str) -> Option<Address<C>> {
+        self.names.remove(name)
+    }
+
+    /// Returns the number of names registered in `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` if no name is registered in `self`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+impl<C> FromIterator<(String, Address<C>)> for StaticNameResolver<C>
+where
+    C: transfer::Configuration,
+{
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (String, Address<C>)>,
+    {
+        Self {
+            names: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<C> NameResolver<C> for StaticNameResolver<C>
+where
+    C: transfer::Configuration,
+{
+    #[inline]
+    fn resolve(&self, name: &str) -> Option<Address<C>> {
+        self.names.get(name).cloned()
+    }
+}
+
+/// Name Resolution Error
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NameResolutionError {
+    /// Missing Resolver
+    ///
+    /// No [`NameResolver`] is registered with the signer.
+    MissingResolver,
+
+    /// Unknown Name
+    ///
+    /// The registered [`NameResolver`] has no address for the name.
+    UnknownName,
+
+    /// Unsupported Transaction
+    ///
+    /// Only [`PrivateTransfer`](transfer::canonical::Transaction::PrivateTransfer)s can name
+    /// their recipient.
+    UnsupportedTransaction,
+}
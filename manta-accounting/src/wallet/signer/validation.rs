@@ -0,0 +1,104 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Request Validation
+//!
+//! Signing a transaction only fails once its proofs are being built, and it stops at the first
+//! error it finds. The [`Signer`](super::Signer) can instead check a transaction up front with
+//! [`validate_request`](super::Signer::validate_request), which collects every
+//! [`RequestProblem`] it can detect without proving so that user interfaces can report the exact
+//! reasons a transaction cannot be signed.
+
+use crate::{
+    transfer::{self, canonical::TransferShape, Asset},
+    wallet::signer::{
+        confirmation::SpendableBalance, hook::HookRejection, policy::PolicyViolation,
+    },
+};
+use core::{fmt::Debug, hash::Hash};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Request Problem
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "Asset<C>: Deserialize<'de>, C::AssetValue: Deserialize<'de>",
+            serialize = "Asset<C>: Serialize, C::AssetValue: Serialize"
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "Asset<C>: Clone, C::AssetValue: Clone"),
+    Copy(bound = "Asset<C>: Copy, C::AssetValue: Copy"),
+    Debug(bound = "Asset<C>: Debug, C::AssetValue: Debug"),
+    Eq(bound = "Asset<C>: Eq, C::AssetValue: Eq"),
+    Hash(bound = "Asset<C>: Hash, C::AssetValue: Hash"),
+    PartialEq(bound = "Asset<C>: PartialEq, C::AssetValue: PartialEq")
+)]
+pub enum RequestProblem<C>
+where
+    C: transfer::Configuration,
+{
+    /// Missing Spending Key
+    ///
+    /// The signer has no accounts to spend the assets of a private transfer or withdrawal.
+    MissingSpendingKey,
+
+    /// Missing Proof Authorization Key
+    ///
+    /// The signer has no proof authorization key to receive the assets of a deposit.
+    MissingProofAuthorizationKey,
+
+    /// Missing Proving Context
+    ///
+    /// The proving context for the transfer shape of the transaction is not loaded and cannot be
+    /// loaded, so none of its posts can be proven.
+    MissingProvingContext(TransferShape),
+
+    /// Invalid Address
+    ///
+    /// The receiving key of the recipient address is the default group element, which no one can
+    /// decrypt notes for.
+    InvalidAddress,
+
+    /// Policy Violation
+    PolicyViolation(PolicyViolation<C>),
+
+    /// Insufficient Balance
+    ///
+    /// The value which can be spent is lower than the value of the `requested` asset. The
+    /// `balance` includes the value still waiting for confirmations, in which case the
+    /// transaction can be signed later on.
+    InsufficientBalance {
+        /// Requested Asset
+        requested: Asset<C>,
+
+        /// Balance of the Requested Asset Id
+        balance: SpendableBalance<C::AssetValue>,
+    },
+
+    /// Hook Rejection
+    ///
+    /// One of the signer hooks rejected the coin selection for the transaction.
+    HookRejection(HookRejection),
+}
@@ -0,0 +1,118 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Witness Refreshing
+//!
+//! The membership proofs of the [`Sender`]s of a transaction are computed against the UTXO
+//! accumulator of the [`Signer`](super::Signer), whose root can fall behind the roots accepted by
+//! the ledger when the signer is not synchronized often. Right before proving, the signer asks its
+//! registered [`WitnessRefresher`] for up-to-date membership proofs, which can come from a fresher
+//! local accumulator or from a remote provider.
+
+use crate::transfer::{
+    self, Parameters, Sender, UtxoAccumulatorItem, UtxoAccumulatorModel, UtxoMembershipProof,
+};
+use alloc::sync::Arc;
+use manta_crypto::accumulator::Accumulator;
+
+/// Witness Refresher
+pub trait WitnessRefresher<C>
+where
+    C: transfer::Configuration,
+{
+    /// Returns a membership proof of `item` against a more recent accumulator root than the one of
+    /// `proof`, or `None` if `proof` is already up to date or `item` is unknown to `self`.
+    ///
+    /// The returned proof is used as is, so implementations must only return valid proofs.
+    fn refresh(
+        &self,
+        item: &UtxoAccumulatorItem<C>,
+        proof: &UtxoMembershipProof<C>,
+    ) -> Option<UtxoMembershipProof<C>>;
+}
+
+/// Shared Witness Refresher
+pub type SharedWitnessRefresher<C> = Arc<dyn WitnessRefresher<C> + Send + Sync>;
+
+/// Accumulator Witness Refresher
+///
+/// Refreshes membership proofs from a local accumulator, for instance one kept synchronized with
+/// the ledger independently of the signer.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct AccumulatorWitnessRefresher<A> {
+    /// Accumulator
+    accumulator: A,
+}
+
+impl<A> AccumulatorWitnessRefresher<A> {
+    /// Builds a new [`AccumulatorWitnessRefresher`] which proves membership against
+    /// `accumulator`.
+    #[inline]
+    pub fn new(accumulator: A) -> Self {
+        Self { accumulator }
+    }
+
+    /// Returns a mutable reference to the underlying accumulator, to keep it up to date.
+    #[inline]
+    pub fn accumulator_mut(&mut self) -> &mut A {
+        &mut self.accumulator
+    }
+
+    /// Returns the underlying accumulator.
+    #[inline]
+    pub fn into_inner(self) -> A {
+        self.accumulator
+    }
+}
+
+impl<C, A> WitnessRefresher<C> for AccumulatorWitnessRefresher<A>
+where
+    C: transfer::Configuration,
+    A: Accumulator<Item = UtxoAccumulatorItem<C>, Model = UtxoAccumulatorModel<C>>,
+    UtxoMembershipProof<C>: PartialEq,
+{
+    #[inline]
+    fn refresh(
+        &self,
+        item: &UtxoAccumulatorItem<C>,
+        proof: &UtxoMembershipProof<C>,
+    ) -> Option<UtxoMembershipProof<C>> {
+        self.accumulator
+            .prove(item)
+            .filter(|refreshed| refreshed != proof)
+    }
+}
+
+/// Refreshes the membership proofs of `senders` with `refresher`, returning the number of proofs
+/// which were replaced.
+#[inline]
+pub fn refresh_senders<C>(
+    refresher: &(dyn WitnessRefresher<C> + Send + Sync),
+    parameters: &Parameters<C>,
+    senders: &mut [Sender<C>],
+) -> usize
+where
+    C: transfer::Configuration,
+{
+    senders
+        .iter_mut()
+        .map(|sender| {
+            sender
+                .refresh_membership_proof(parameters, |item, proof| refresher.refresh(item, proof))
+        })
+        .filter(|refreshed| *refreshed)
+        .count()
+}
@@ -0,0 +1,333 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! In-Memory Ledger
+
+use crate::{
+    transfer::{Note, Nullifier, Parameters, Utxo},
+    wallet::{
+        ledger::{
+            self, Configure, PullResponse, PushRequest, ReadResponse, SubscribeNullifiers,
+            SyncConfig,
+        },
+        signer::{Checkpoint, Configuration, SyncData, SyncRequest},
+    },
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::{cmp::Ordering, convert::Infallible, fmt::Debug};
+use manta_util::future::LocalBoxFutureResult;
+
+/// In-Memory Ledger
+///
+/// This ledger stores its data as a sequence of blocks, one for every call to [`push`] or
+/// [`push_data`], and can be used to test wallets and signers without connecting to a chain. It
+/// does not check the validity proofs of the [`TransferPost`](crate::transfer::TransferPost)s it
+/// receives, it only rejects the ones which reuse a [`Utxo`] or a [`Nullifier`].
+///
+/// # Checkpoints
+///
+/// The data is pulled in whole blocks, so [`pull`] only returns the data after `checkpoint` if
+/// `checkpoint` is the checkpoint at the start of one of the blocks, as is the case for the
+/// checkpoints of the wallets which synchronize with this ledger.
+///
+/// [`push`]: Self::push
+/// [`push_data`]: Self::push_data
+/// [`pull`]: Self::pull
+pub struct InMemoryLedger<C>
+where
+    C: Configuration,
+{
+    /// Transfer Parameters
+    parameters: Parameters<C>,
+
+    /// Blocks with the Checkpoints at their Start
+    blocks: Vec<(C::Checkpoint, SyncData<C>)>,
+
+    /// Current Checkpoint
+    checkpoint: C::Checkpoint,
+
+    /// Synchronization Configuration
+    sync_config: SyncConfig,
+
+    /// Index of the First Block whose Nullifiers were not yet Broadcast to the Subscriber
+    nullifier_subscription: Option<usize>,
+}
+
+impl<C> InMemoryLedger<C>
+where
+    C: Configuration,
+{
+    /// Builds a new empty [`InMemoryLedger`] using `parameters` to sort the incoming [`Utxo`]s.
+    #[inline]
+    pub fn new(parameters: Parameters<C>) -> Self {
+        Self {
+            parameters,
+            blocks: Vec::new(),
+            checkpoint: Default::default(),
+            sync_config: Default::default(),
+            nullifier_subscription: None,
+        }
+    }
+
+    /// Returns the current checkpoint of `self`.
+    #[inline]
+    pub fn checkpoint(&self) -> &C::Checkpoint {
+        &self.checkpoint
+    }
+
+    /// Returns the number of blocks in `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns `true` if `self` has no blocks.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Returns `true` if `utxo` is stored in `self`.
+    #[inline]
+    pub fn contains_utxo(&self, utxo: &Utxo<C>) -> bool
+    where
+        Utxo<C>: PartialEq,
+    {
+        self.blocks
+            .iter()
+            .any(|(_, data)| data.utxo_note_data.iter().any(|(u, _)| u == utxo))
+    }
+
+    /// Returns `true` if `nullifier` is stored in `self`.
+    #[inline]
+    pub fn contains_nullifier(&self, nullifier: &Nullifier<C>) -> bool
+    where
+        Nullifier<C>: PartialEq,
+    {
+        self.blocks
+            .iter()
+            .any(|(_, data)| data.nullifier_data.contains(nullifier))
+    }
+
+    /// Appends `data` to `self` as a new block without checking it against the existing data.
+    #[inline]
+    pub fn push_data(&mut self, data: SyncData<C>) {
+        let request = SyncRequest {
+            origin_checkpoint: self.checkpoint.clone(),
+            data,
+            height: None,
+        };
+        let utxo_count = request.utxo_count(&self.parameters);
+        let mut checkpoint = request.origin_checkpoint;
+        checkpoint.update_from_utxo_count(utxo_count);
+        checkpoint.update_from_nullifiers(request.data.nullifier_data.len());
+        self.blocks.push((
+            core::mem::replace(&mut self.checkpoint, checkpoint),
+            request.data,
+        ));
+    }
+
+    /// Appends the [`Utxo`]s, notes and [`Nullifier`]s of `posts` to `self` as a new block,
+    /// returning `false` without changing `self` if any of them is already stored in `self`.
+    #[inline]
+    pub fn push(&mut self, posts: PushRequest<C>) -> bool
+    where
+        Utxo<C>: PartialEq,
+        Nullifier<C>: PartialEq,
+    {
+        let mut data = SyncData::<C>::default();
+        for post in posts {
+            for sender in post.body.sender_posts {
+                if self.contains_nullifier(&sender.nullifier)
+                    || data.nullifier_data.contains(&sender.nullifier)
+                {
+                    return false;
+                }
+                data.nullifier_data.push(sender.nullifier);
+            }
+            for receiver in post.body.receiver_posts {
+                if self.contains_utxo(&receiver.utxo)
+                    || data.utxo_note_data.iter().any(|(u, _)| *u == receiver.utxo)
+                {
+                    return false;
+                }
+                data.utxo_note_data.push((receiver.utxo, receiver.note));
+            }
+        }
+        self.push_data(data);
+        true
+    }
+
+    /// Pulls the blocks after `checkpoint`, stopping after the first block which reaches
+    /// `batch_size` [`Utxo`]s or [`Nullifier`]s in total. The
+    /// [`should_continue`](ReadResponse::should_continue) flag is set if there are blocks left.
+    /// Blocks are counted from one, so the [`height`](ReadResponse::height) of the response is
+    /// the number of blocks up to the last one which was pulled.
+    #[inline]
+    pub fn pull(&self, checkpoint: &C::Checkpoint, batch_size: usize) -> PullResponse<C>
+    where
+        Utxo<C>: Clone,
+        Note<C>: Clone,
+        Nullifier<C>: Clone,
+    {
+        let mut blocks = self
+            .blocks
+            .iter()
+            .enumerate()
+            .skip_while(|(_, (start, _))| {
+                !matches!(
+                    checkpoint.partial_cmp(start),
+                    Some(Ordering::Less | Ordering::Equal)
+                )
+            })
+            .peekable();
+        let mut height = None;
+        let mut data = SyncData::<C>::default();
+        for (index, (_, block)) in blocks.by_ref() {
+            height = Some(index as u64 + 1);
+            data.utxo_note_data
+                .extend(block.utxo_note_data.iter().cloned());
+            data.nullifier_data
+                .extend(block.nullifier_data.iter().cloned());
+            if data.utxo_note_data.len() >= batch_size || data.nullifier_data.len() >= batch_size {
+                break;
+            }
+        }
+        ReadResponse {
+            should_continue: blocks.peek().is_some(),
+            data,
+            height,
+        }
+    }
+
+    /// Subscribes to the [`Nullifier`]s of the blocks appended to `self` from now on. See
+    /// [`SubscribeNullifiers`] for more.
+    #[inline]
+    pub fn subscribe_nullifiers(&mut self) {
+        self.nullifier_subscription = Some(self.blocks.len());
+    }
+
+    /// Returns the [`Nullifier`]s of the blocks appended to `self` since the last call to this
+    /// method, or since the subscription for the first call.
+    #[inline]
+    pub fn next_nullifiers(&mut self) -> Vec<Nullifier<C>>
+    where
+        Nullifier<C>: Clone,
+    {
+        match &mut self.nullifier_subscription {
+            Some(start) => {
+                let nullifiers = self.blocks[*start..]
+                    .iter()
+                    .flat_map(|(_, data)| data.nullifier_data.iter().cloned())
+                    .collect();
+                *start = self.blocks.len();
+                nullifiers
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl<C> Debug for InMemoryLedger<C>
+where
+    C: Configuration,
+    C::Checkpoint: Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InMemoryLedger")
+            .field("blocks", &self.blocks.len())
+            .field("checkpoint", &self.checkpoint)
+            .field("sync_config", &self.sync_config)
+            .finish()
+    }
+}
+
+impl<C> ledger::Connection for InMemoryLedger<C>
+where
+    C: Configuration,
+{
+    type Error = Infallible;
+}
+
+impl<C> Configure for InMemoryLedger<C>
+where
+    C: Configuration,
+{
+    #[inline]
+    fn sync_config(&self) -> &SyncConfig {
+        &self.sync_config
+    }
+
+    #[inline]
+    fn set_sync_config(&mut self, config: SyncConfig) {
+        self.sync_config = config;
+    }
+}
+
+impl<C> ledger::Read<SyncData<C>> for InMemoryLedger<C>
+where
+    C: Configuration,
+    Utxo<C>: Clone,
+    Note<C>: Clone,
+    Nullifier<C>: Clone,
+{
+    type Checkpoint = C::Checkpoint;
+
+    #[inline]
+    fn read<'s>(
+        &'s mut self,
+        checkpoint: &'s Self::Checkpoint,
+    ) -> LocalBoxFutureResult<'s, PullResponse<C>, Self::Error> {
+        Box::pin(async move { Ok(self.pull(checkpoint, self.sync_config.batch_size)) })
+    }
+}
+
+impl<C> ledger::Write<PushRequest<C>> for InMemoryLedger<C>
+where
+    C: Configuration,
+    Utxo<C>: PartialEq,
+    Nullifier<C>: PartialEq,
+{
+    type Response = bool;
+
+    #[inline]
+    fn write(
+        &mut self,
+        posts: PushRequest<C>,
+    ) -> LocalBoxFutureResult<'_, Self::Response, Self::Error> {
+        Box::pin(async move { Ok(self.push(posts)) })
+    }
+}
+
+impl<C> SubscribeNullifiers<Nullifier<C>> for InMemoryLedger<C>
+where
+    C: Configuration,
+    Nullifier<C>: Clone,
+{
+    #[inline]
+    fn subscribe_nullifiers(&mut self) -> LocalBoxFutureResult<'_, (), Self::Error> {
+        Box::pin(async move {
+            InMemoryLedger::subscribe_nullifiers(self);
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn next_nullifiers(&mut self) -> LocalBoxFutureResult<'_, Vec<Nullifier<C>>, Self::Error> {
+        Box::pin(async move { Ok(InMemoryLedger::next_nullifiers(self)) })
+    }
+}
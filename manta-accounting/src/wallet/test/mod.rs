@@ -40,6 +40,7 @@ use statrs::{distribution::Categorical, StatsError};
 #[cfg(feature = "serde")]
 use manta_util::serde::{Deserialize, Serialize};
 
+pub mod memory;
 pub mod sim;
 
 /// Simulation Action Space
@@ -384,10 +385,9 @@ where
 
 /// Ledger Alias Trait
 ///
-/// This `trait` is used as an alias for the [`Read`](ledger::Read) and [`Write`](ledger::Write)
-/// requirements for the simulation ledger.
-pub trait Ledger<C>:
-    ledger::Read<SyncData<C>> + ledger::Write<Vec<TransferPost<C>>, Response = bool>
+/// This `trait` is used as an alias for the [`Ledger`](ledger::Ledger) requirements for the
+/// simulation ledger, which must respond to every write with whether it was accepted.
+pub trait Ledger<C>: ledger::Ledger<C, Response = bool>
 where
     C: Configuration,
 {
@@ -396,7 +396,7 @@ where
 impl<C, L> Ledger<C> for L
 where
     C: Configuration,
-    L: ledger::Read<SyncData<C>> + ledger::Write<Vec<TransferPost<C>>, Response = bool>,
+    L: ledger::Ledger<C, Response = bool>,
 {
 }
 
@@ -0,0 +1,87 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Trusted Setup Ceremony Benchmarks
+
+use ark_groth16::{ProvingKey, VerifyingKey};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use manta_crypto::{
+    arkworks::{
+        bn254::{G1Affine, G2Affine},
+        ec::AffineCurve,
+    },
+    rand::OsRng,
+};
+use manta_trusted_setup::groth16::{
+    ceremony::config::ppot::{Challenge, Config},
+    mpc::{contribute, Configuration, State},
+};
+
+/// Numbers of elements in each of the `h` and `l` queries of the contributed state.
+const QUERY_SIZES: [usize; 3] = [1 << 8, 1 << 10, 1 << 12];
+
+/// Builds a state whose `h` and `l` queries have `size` elements. Contributing only rescales the
+/// queries and the `delta` elements, so the time does not depend on the circuit the state was
+/// derived from.
+#[inline]
+fn state(size: usize) -> State<Config> {
+    let g1 = G1Affine::prime_subgroup_generator();
+    let g2 = G2Affine::prime_subgroup_generator();
+    State(ProvingKey {
+        vk: VerifyingKey {
+            alpha_g1: g1,
+            beta_g2: g2,
+            gamma_g2: g2,
+            delta_g2: g2,
+            gamma_abc_g1: Vec::new(),
+        },
+        beta_g1: g1,
+        delta_g1: g1,
+        a_query: Vec::new(),
+        b_g1_query: Vec::new(),
+        b_g2_query: Vec::new(),
+        h_query: vec![g1; size],
+        l_query: vec![g1; size],
+    })
+}
+
+#[inline]
+fn contribution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench");
+    group.sample_size(10);
+    let mut rng = OsRng;
+    let hasher = <Config as Configuration>::Hasher::default();
+    let challenge = Challenge::from([0; 64]);
+    for size in QUERY_SIZES {
+        group.bench_with_input(
+            BenchmarkId::new("Ceremony Contribution", size),
+            &size,
+            |b, size| {
+                b.iter_batched(
+                    || state(*size),
+                    |mut state| {
+                        black_box(contribute(&hasher, &challenge, &mut state, &mut rng));
+                        state
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+}
+
+criterion_group!(ceremony, contribution);
+criterion_main!(ceremony);
@@ -20,14 +20,14 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use manta_accounting::{asset, transfer::utxo::protocol};
 use manta_crypto::{
     arkworks::{constraint::fp::Fp, ff::field_new},
-    encryption::{Decrypt, EmptyHeader, Encrypt},
+    encryption::{Decrypt, Encrypt},
     hash::ArrayHashFunction,
     rand::{OsRng, Rand, Sample},
 };
 use manta_pay::{
     config::{
         poseidon::Spec2 as Poseidon2,
-        utxo::{Config, IncomingBaseAES, InnerHashDomainTag},
+        utxo::{Config, IncomingBaseAES, InnerHashDomainTag, NoteHeader},
         ConstraintField,
     },
     crypto::poseidon::hash::Hasher,
@@ -57,7 +57,7 @@ fn aes_decryption(c: &mut Criterion) {
     let mut group = c.benchmark_group("bench");
     let mut rng = OsRng;
     let base_aes = black_box(IncomingBaseAES::default());
-    let header = black_box(EmptyHeader::default());
+    let header = black_box(NoteHeader::default());
     let key = black_box(rng.gen());
     let plaintext = black_box(protocol::IncomingPlaintext::<Config>::new(
         rng.gen(),
@@ -0,0 +1,80 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Merkle Tree Benchmarks
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+use manta_crypto::{
+    accumulator::{Accumulator, OptimizedAccumulator},
+    rand::{OsRng, Rand},
+};
+use manta_pay::{
+    config::utxo::UtxoAccumulatorItem, parameters::load_utxo_accumulator_model,
+    test::payment::UtxoAccumulator,
+};
+
+/// Numbers of items inserted into a fresh accumulator per iteration.
+const BATCH_SIZES: [usize; 3] = [1, 16, 256];
+
+#[inline]
+fn insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench");
+    let mut rng = OsRng;
+    let model = load_utxo_accumulator_model();
+    for batch_size in BATCH_SIZES {
+        let items = (0..batch_size)
+            .map(|_| rng.gen())
+            .collect::<Vec<UtxoAccumulatorItem>>();
+        group.throughput(Throughput::Elements(batch_size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("Merkle Forest Insert", batch_size),
+            &items,
+            |b, items| {
+                b.iter_batched(
+                    || UtxoAccumulator::new(model.clone()),
+                    |mut accumulator| {
+                        for item in items {
+                            black_box(accumulator.insert(item));
+                        }
+                        accumulator
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("Merkle Forest Insert Nonprovable", batch_size),
+            &items,
+            |b, items| {
+                b.iter_batched(
+                    || UtxoAccumulator::new(model.clone()),
+                    |mut accumulator| {
+                        for item in items {
+                            black_box(accumulator.insert_nonprovable(item));
+                        }
+                        accumulator
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+}
+
+criterion_group!(merkle_tree, insert);
+criterion_main!(merkle_tree);
@@ -0,0 +1,141 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Synchronization Benchmarks
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+use manta_accounting::{
+    key::AccountTable,
+    transfer::utxo::{auth::DeriveContext, DeriveDecryptionKey, DeriveMint, UtxoReconstruct},
+    wallet::signer::{EvictionPolicy, SyncData},
+};
+use manta_crypto::{
+    accumulator::Accumulator,
+    rand::{ChaCha20Rng, FromEntropy, OsRng, Rand, SeedableRng},
+};
+use manta_pay::{
+    config::{Address, Config, Note, Parameters, Utxo},
+    key::KeySecret,
+    parameters::{load_transfer_parameters, load_utxo_accumulator_model},
+    signer::{
+        base::{Signer, UtxoAccumulator},
+        SyncRequest,
+    },
+};
+use std::sync::Arc;
+
+/// Numbers of notes in the synchronization data.
+const NOTE_COUNTS: [usize; 3] = [16, 128, 1024];
+
+/// Mints `count` notes of random assets to `address`.
+#[inline]
+fn mint(parameters: &Parameters, address: Address, count: usize) -> Vec<(Utxo, Note)> {
+    let mut rng = OsRng;
+    (0..count)
+        .map(|_| {
+            let (_, utxo, note) =
+                parameters.derive_mint(address, rng.gen(), Default::default(), &mut rng);
+            (utxo, note)
+        })
+        .collect()
+}
+
+/// Builds a signer with the account derived from `seed` and an empty UTXO accumulator, which never
+/// loads proving contexts.
+#[inline]
+fn offline_signer(seed: [u8; 32]) -> Signer {
+    let mut signer = Signer::from_loader(
+        load_transfer_parameters(),
+        Arc::new(|_| None),
+        EvictionPolicy::default(),
+        UtxoAccumulator::empty(&load_utxo_accumulator_model()),
+        FromEntropy::from_entropy(),
+    );
+    signer.load_accounts(AccountTable::new(KeySecret::sample(
+        &mut ChaCha20Rng::from_seed(seed),
+    )));
+    signer
+}
+
+#[inline]
+fn note_decryption(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench");
+    let mut rng = OsRng;
+    let parameters = load_transfer_parameters();
+    let spending_key = rng.gen();
+    let mut authorization_context = parameters.derive_context(&spending_key);
+    let decryption_key = parameters.derive_decryption_key(&mut authorization_context);
+    let address = parameters.address_from_spending_key(&spending_key);
+    for count in NOTE_COUNTS {
+        let notes = mint(&parameters, address, count);
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(
+            BenchmarkId::new("Note Decryption", count),
+            &notes,
+            |b, notes| {
+                b.iter(|| {
+                    for (utxo, note) in notes {
+                        let _ = black_box(parameters.open_with_check(
+                            &decryption_key,
+                            utxo,
+                            note.clone(),
+                        ));
+                    }
+                })
+            },
+        );
+    }
+}
+
+#[inline]
+fn signer_sync(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench");
+    group.sample_size(10);
+    let mut rng = OsRng;
+    let seed = rng.gen();
+    let mut signer = offline_signer(seed);
+    let parameters = signer.parameters().parameters.clone();
+    let address = signer.address().expect("The signer has accounts.");
+    let other_address = parameters.address_from_spending_key(&rng.gen());
+    for count in NOTE_COUNTS {
+        let mut utxo_note_data = mint(&parameters, address, count / 2);
+        utxo_note_data.extend(mint(&parameters, other_address, count - count / 2));
+        let data = SyncData::<Config> {
+            utxo_note_data,
+            nullifier_data: Vec::new(),
+        };
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::new("Signer Sync", count), &data, |b, data| {
+            b.iter_batched(
+                || (offline_signer(seed), data.clone()),
+                |(mut signer, data)| {
+                    let _ = black_box(signer.sync(SyncRequest {
+                        origin_checkpoint: Default::default(),
+                        data,
+                        height: None,
+                    }));
+                    signer
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+}
+
+criterion_group!(sync, note_decryption, signer_sync);
+criterion_main!(sync);
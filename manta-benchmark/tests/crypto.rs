@@ -19,14 +19,14 @@
 use manta_accounting::{asset, transfer::utxo::protocol};
 use manta_crypto::{
     arkworks::{constraint::fp::Fp, ff::field_new},
-    encryption::{Decrypt, EmptyHeader, Encrypt},
+    encryption::{Decrypt, Encrypt},
     hash::ArrayHashFunction,
     rand::{OsRng, Rand, Sample},
 };
 use manta_pay::{
     config::{
         poseidon::Spec2 as Poseidon2,
-        utxo::{Config, IncomingBaseAES, InnerHashDomainTag},
+        utxo::{Config, IncomingBaseAES, InnerHashDomainTag, NoteHeader},
         ConstraintField,
     },
     crypto::poseidon::hash::Hasher,
@@ -64,7 +64,7 @@ fn bench_poseidon_hash() {
 fn bench_aes_decryption() {
     let mut rng = OsRng;
     let base_aes = IncomingBaseAES::default();
-    let header = EmptyHeader::default();
+    let header = NoteHeader::default();
     let key = rng.gen();
     let plaintext = protocol::IncomingPlaintext::<Config>::new(
         rng.gen(),
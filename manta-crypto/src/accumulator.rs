@@ -17,7 +17,7 @@
 //! Dynamic Cryptographic Accumulators
 
 use crate::eclair::alloc::{mode::Derived, Allocate, Allocator, Constant, Variable};
-use alloc::vec::Vec;
+use alloc::{collections::VecDeque, vec::Vec};
 use core::{fmt::Debug, hash::Hash};
 
 #[cfg(feature = "serde")]
@@ -216,6 +216,27 @@ pub trait OptimizedAccumulator: Accumulator {
     fn prune(&mut self) {}
 }
 
+/// Accumulator Checkpoints
+///
+/// Accumulators which can be rewound to an earlier state, for example when the ledger they follow
+/// is reorganized and the items inserted since some block have to be discarded.
+pub trait CheckpointAccumulator: Accumulator {
+    /// Checkpoint Type
+    type Checkpoint;
+
+    /// Returns a checkpoint of the committed state of `self`.
+    fn checkpoint(&self) -> Self::Checkpoint;
+
+    /// Rewinds `self` to the state it was in when `checkpoint` was taken, discarding every item
+    /// inserted since then.
+    ///
+    /// # Implementation Note
+    ///
+    /// Rolling back to a checkpoint must not invalidate it, so that rolling back to the same
+    /// checkpoint several times has the same effect as doing it once.
+    fn rollback_to(&mut self, checkpoint: &Self::Checkpoint);
+}
+
 /// Batch Insertion
 pub trait BatchInsertion: OptimizedAccumulator {
     /// Inserts `items` in `self` provably, see [`insert`] for more details.
@@ -284,6 +305,215 @@ pub trait FromItemsAndWitnesses: Accumulator {
     fn sort_items(items: Vec<Self::Item>) -> Vec<Vec<Self::Item>>;
 }
 
+/// Root History
+///
+/// Ledgers accept membership proofs against any root in a bounded window of the most recent
+/// outputs of their accumulator, so that transactions which were built concurrently against
+/// slightly different states all remain valid. This `trait` is implemented by the types which keep
+/// such a window.
+pub trait RootHistory<O> {
+    /// Returns `true` if `root` is in the window of recent roots of `self`.
+    fn is_recent_root(&self, root: &O) -> bool;
+}
+
+/// Returns a membership proof for `item` from `accumulator` if the output it targets is accepted
+/// by `history`.
+///
+/// Accumulators always prove membership against their latest output, which is the freshest root
+/// they know of, so this function returns `None` whenever that root was either evicted from
+/// `history` or not yet added to it. In both cases `accumulator` has to be synchronized with the
+/// ledger before it can build a valid proof.
+#[inline]
+pub fn prove_against_recent_root<A, H>(
+    accumulator: &A,
+    history: &H,
+    item: &A::Item,
+) -> Option<MembershipProof<A::Model>>
+where
+    A: Accumulator,
+    H: RootHistory<A::Output> + ?Sized,
+{
+    accumulator
+        .prove(item)
+        .filter(|proof| history.is_recent_root(proof.output()))
+}
+
+/// Root Eviction Policy
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RootEvictionPolicy {
+    /// Keeps at most this number of roots, evicting the oldest ones first
+    MaxRoots(usize),
+
+    /// Keeps the roots added at most this number of heights before the latest one
+    MaxAge(u64),
+}
+
+impl RootEvictionPolicy {
+    /// Default Maximum Number of Roots
+    pub const DEFAULT_MAX_ROOTS: usize = 64;
+}
+
+impl Default for RootEvictionPolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::MaxRoots(Self::DEFAULT_MAX_ROOTS)
+    }
+}
+
+/// Root Window
+///
+/// Bounded window of the most recent roots of an accumulator, together with the height at which
+/// each of them was added, from the oldest to the most recent one. Roots are evicted according to
+/// the [`RootEvictionPolicy`] of the window every time a new root is added.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(deserialize = "O: Deserialize<'de>", serialize = "O: Serialize"),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "O: Clone"),
+    Debug(bound = "O: Debug"),
+    Default(bound = ""),
+    Eq(bound = "O: Eq"),
+    Hash(bound = "O: Hash"),
+    PartialEq(bound = "O: PartialEq")
+)]
+pub struct RootWindow<O> {
+    /// Eviction Policy
+    policy: RootEvictionPolicy,
+
+    /// Recent Roots and their Heights
+    roots: VecDeque<(u64, O)>,
+}
+
+impl<O> RootWindow<O> {
+    /// Builds a new empty [`RootWindow`] which evicts roots according to `policy`.
+    #[inline]
+    pub fn new(policy: RootEvictionPolicy) -> Self {
+        Self {
+            policy,
+            roots: Default::default(),
+        }
+    }
+
+    /// Returns the eviction policy of `self`.
+    #[inline]
+    pub fn policy(&self) -> RootEvictionPolicy {
+        self.policy
+    }
+
+    /// Replaces the eviction policy of `self` with `policy`, returning the roots which are
+    /// evicted by the new policy.
+    #[inline]
+    pub fn set_policy(&mut self, policy: RootEvictionPolicy) -> Vec<O> {
+        self.policy = policy;
+        self.evict()
+    }
+
+    /// Returns the number of roots in `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Returns `true` if `self` has no roots.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// Returns an iterator over the roots of `self`, from the oldest to the most recent one.
+    #[inline]
+    pub fn roots(&self) -> impl DoubleEndedIterator<Item = &O> + ExactSizeIterator {
+        self.roots.iter().map(|(_, root)| root)
+    }
+
+    /// Returns the most recent root of `self`.
+    #[inline]
+    pub fn latest(&self) -> Option<&O> {
+        self.roots.back().map(|(_, root)| root)
+    }
+
+    /// Returns the most recent of the `candidates` which is still in `self`. This is the root
+    /// that new membership proofs should target when several of them are available.
+    #[inline]
+    pub fn freshest<'o, I>(&self, candidates: I) -> Option<&'o O>
+    where
+        O: PartialEq,
+        I: IntoIterator<Item = &'o O>,
+    {
+        candidates
+            .into_iter()
+            .filter_map(|candidate| Some((self.position(candidate)?, candidate)))
+            .max_by_key(|(position, _)| *position)
+            .map(|(_, candidate)| candidate)
+    }
+
+    /// Returns the position of the most recent occurrence of `root` in `self`.
+    #[inline]
+    fn position(&self, root: &O) -> Option<usize>
+    where
+        O: PartialEq,
+    {
+        self.roots.iter().rposition(|(_, recent)| recent == root)
+    }
+
+    /// Adds `root` at `height` to `self`, returning the roots which were evicted to make room for
+    /// it.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `height` is smaller than the height of the latest root in `self`.
+    #[inline]
+    pub fn push(&mut self, height: u64, root: O) -> Vec<O> {
+        if let Some((latest, _)) = self.roots.back() {
+            assert!(
+                *latest <= height,
+                "Roots must be added in order of non-decreasing height."
+            );
+        }
+        self.roots.push_back((height, root));
+        self.evict()
+    }
+
+    /// Evicts the roots of `self` which are not allowed by its eviction policy.
+    #[inline]
+    fn evict(&mut self) -> Vec<O> {
+        let count = match self.policy {
+            RootEvictionPolicy::MaxRoots(max_roots) => self.roots.len().saturating_sub(max_roots),
+            RootEvictionPolicy::MaxAge(max_age) => match self.roots.back() {
+                Some((latest, _)) => self
+                    .roots
+                    .iter()
+                    .take_while(|(height, _)| latest - height > max_age)
+                    .count(),
+                _ => 0,
+            },
+        };
+        self.roots.drain(..count).map(|(_, root)| root).collect()
+    }
+}
+
+impl<O> RootHistory<O> for RootWindow<O>
+where
+    O: PartialEq,
+{
+    #[inline]
+    fn is_recent_root(&self, root: &O) -> bool {
+        self.position(root).is_some()
+    }
+}
+
 /// Accumulator Membership Proof
 #[cfg_attr(
     feature = "serde",
@@ -340,6 +570,12 @@ where
         &self.output
     }
 
+    /// Returns a reference to the secret witness part of `self`.
+    #[inline]
+    pub fn witness(&self) -> &M::Witness {
+        &self.witness
+    }
+
     /// Verifies that `item` is stored in a known accumulator using `model`.
     #[inline]
     pub fn verify<COM>(&self, model: &M, item: &M::Item, compiler: &mut COM) -> M::Verification
@@ -399,13 +635,16 @@ where
 }
 
 /// Testing Framework
-#[cfg(feature = "test")]
-#[cfg_attr(doc_cfg, doc(cfg(feature = "test")))]
+#[cfg(any(feature = "test", test))]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "test", test))))]
 pub mod test {
     use super::*;
     use alloc::vec::Vec;
     use core::fmt::Debug;
 
+    #[cfg(test)]
+    use alloc::vec;
+
     /// Asserts that `accumulator` can prove the membership of `item` after it is inserted.
     #[inline]
     pub fn assert_provable_membership<A>(accumulator: &mut A, item: &A::Item) -> Output<A>
@@ -453,4 +692,23 @@ pub mod test {
             }
         }
     }
+
+    /// Checks that root windows evict their oldest roots according to their policy.
+    #[cfg(test)]
+    #[test]
+    fn root_window_eviction() {
+        let mut window = RootWindow::new(RootEvictionPolicy::MaxRoots(3));
+        for root in 0..3 {
+            assert!(window.push(root, root).is_empty());
+        }
+        assert_eq!(window.push(3, 3), vec![0]);
+        assert!(!window.is_recent_root(&0));
+        assert!(window.is_recent_root(&1));
+        assert_eq!(window.latest(), Some(&3));
+        assert_eq!(window.freshest(&[0, 2, 1]), Some(&2));
+        assert_eq!(window.freshest(&[0]), None);
+        assert_eq!(window.set_policy(RootEvictionPolicy::MaxAge(1)), vec![1]);
+        assert_eq!(window.push(5, 5), vec![2, 3]);
+        assert_eq!(window.roots().copied().collect::<Vec<_>>(), vec![5]);
+    }
 }
@@ -25,7 +25,8 @@ use crate::{
         r1cs_std::{eq::EqGadget, fields::fp::FpVar, groups::CurveVar, ToBitsGadget},
         relations::ns,
         serialize::{
-            ArkReader, ArkWriter, CanonicalDeserialize, CanonicalSerialize, SerializationError,
+            ArkReader, ArkWriter, CanonicalDeserialize, CanonicalSerialize, Read,
+            SerializationError, Write,
         },
     },
     constraint::{Input, ProofSystem},
@@ -37,6 +38,7 @@ use crate::{
         bool::{Bool, ConditionalSelect},
         cmp,
         num::Zero,
+        ops::ConstantTimeEq,
     },
     rand::{RngCore, Sample},
 };
@@ -44,6 +46,9 @@ use alloc::vec::Vec;
 use core::{borrow::Borrow, marker::PhantomData};
 use manta_util::{codec, AsBytes};
 
+#[cfg(feature = "constant-time")]
+use crate::arkworks::ladder::{self, LadderCurve};
+
 #[cfg(feature = "serde")]
 use manta_util::serde::{Deserialize, Serialize, Serializer};
 
@@ -322,6 +327,74 @@ where
     }
 }
 
+impl<C> CanonicalSerialize for Group<C>
+where
+    C: ProjectiveCurve,
+{
+    #[inline]
+    fn serialize<W>(&self, writer: W) -> Result<(), SerializationError>
+    where
+        W: Write,
+    {
+        self.0.serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.0.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W>(&self, writer: W) -> Result<(), SerializationError>
+    where
+        W: Write,
+    {
+        self.0.serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W>(&self, writer: W) -> Result<(), SerializationError>
+    where
+        W: Write,
+    {
+        self.0.serialize_unchecked(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.0.uncompressed_size()
+    }
+}
+
+impl<C> CanonicalDeserialize for Group<C>
+where
+    C: ProjectiveCurve,
+{
+    #[inline]
+    fn deserialize<R>(reader: R) -> Result<Self, SerializationError>
+    where
+        R: Read,
+    {
+        C::Affine::deserialize(reader).map(Self)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R>(reader: R) -> Result<Self, SerializationError>
+    where
+        R: Read,
+    {
+        C::Affine::deserialize_uncompressed(reader).map(Self)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R>(reader: R) -> Result<Self, SerializationError>
+    where
+        R: Read,
+    {
+        C::Affine::deserialize_unchecked(reader).map(Self)
+    }
+}
+
 impl<C> cmp::PartialEq<Self> for Group<C>
 where
     C: ProjectiveCurve,
@@ -332,6 +405,16 @@ where
     }
 }
 
+impl<C> ConstantTimeEq for Group<C>
+where
+    C: ProjectiveCurve,
+{
+    #[inline]
+    fn ct_eq(&self, rhs: &Self, compiler: &mut ()) -> bool {
+        self.as_bytes().ct_eq(&rhs.as_bytes(), compiler)
+    }
+}
+
 impl<C> AsBytes for Group<C>
 where
     C: ProjectiveCurve,
@@ -364,6 +447,34 @@ where
     }
 }
 
+/// Constant-Time Group Element
+///
+/// Wrapper around a [`Group`] element whose native scalar multiplication uses the Montgomery
+/// ladder from the [`ladder`](crate::arkworks::ladder) module instead of the default
+/// variable-time algorithm. Native key operations which multiply by secret keys opt into the
+/// ladder by wrapping their base point.
+#[cfg(feature = "constant-time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "constant-time")))]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ConstantTime<G>(
+    /// Group Element
+    pub G,
+);
+
+#[cfg(feature = "constant-time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "constant-time")))]
+impl<C> algebra::ScalarMul<Scalar<C>> for ConstantTime<Group<C>>
+where
+    C: LadderCurve,
+{
+    type Output = Group<C>;
+
+    #[inline]
+    fn scalar_mul(&self, scalar: &Scalar<C>, _: &mut ()) -> Self::Output {
+        Group(ladder::scalar_mul::<C>(&self.0 .0, &scalar.0))
+    }
+}
+
 /// Discrete Logarithm Hardness
 ///
 /// We assume that the DL problem is hard for all `arkworks` implementations of elliptic curves.
@@ -691,14 +802,74 @@ mod test {
     use crate::{
         algebra::{test::window_correctness, PrecomputedBaseTable, ScalarMul},
         arkworks::{
-            algebra::scalar_bits, ed_on_bn254::EdwardsProjective as Bn254_Edwards,
+            algebra::scalar_bits,
+            ed_on_bn254::EdwardsProjective as Bn254_Edwards,
             r1cs_std::groups::curves::twisted_edwards::AffineVar,
+            serialize::{Compressed, SerializationMode, Unchecked, Uncompressed},
         },
         constraint::measure::Measure,
-        eclair::bool::AssertEq,
+        eclair::{bool::AssertEq, ops::ConstantTimeSelect},
         rand::OsRng,
     };
 
+    /// Checks that group elements round-trip through every [`SerializationMode`] and that the
+    /// compressed encoding is the smallest one.
+    #[test]
+    fn serialization_modes_round_trip() {
+        let point = Group::<Bn254_Edwards>::gen(&mut OsRng);
+        let compressed = Compressed::to_bytes(&point).expect("Serialization is infallible.");
+        let uncompressed = Uncompressed::to_bytes(&point).expect("Serialization is infallible.");
+        let unchecked = Unchecked::to_bytes(&point).expect("Serialization is infallible.");
+        assert!(compressed.len() < uncompressed.len());
+        assert_eq!(uncompressed.len(), unchecked.len());
+        assert_eq!(
+            Compressed::deserialize::<Group<Bn254_Edwards>, _>(compressed.as_slice()).ok(),
+            Some(point)
+        );
+        assert_eq!(
+            Uncompressed::deserialize::<Group<Bn254_Edwards>, _>(uncompressed.as_slice()).ok(),
+            Some(point)
+        );
+        assert_eq!(
+            Unchecked::deserialize::<Group<Bn254_Edwards>, _>(unchecked.as_slice()).ok(),
+            Some(point)
+        );
+    }
+
+    /// Checks that the constant-time comparisons and selections agree with their variable-time
+    /// counterparts.
+    #[test]
+    fn constant_time_operations_match() {
+        let mut rng = OsRng;
+        let point = Group::<Bn254_Edwards>::gen(&mut rng);
+        let other_point = Group::<Bn254_Edwards>::gen(&mut rng);
+        assert!(point.ct_eq(&point, &mut ()));
+        assert!(!point.ct_eq(&other_point, &mut ()));
+        let scalar = Scalar::<Bn254_Edwards>::gen(&mut rng);
+        let other_scalar = Scalar::<Bn254_Edwards>::gen(&mut rng);
+        assert!(scalar.ct_eq(&scalar, &mut ()));
+        assert!(!scalar.ct_eq(&other_scalar, &mut ()));
+        for bit in [false, true] {
+            assert_eq!(
+                Scalar::<Bn254_Edwards>::ct_select(&bit, &scalar, &other_scalar, &mut ()),
+                Scalar::<Bn254_Edwards>::select(&bit, &scalar, &other_scalar, &mut ()),
+            );
+        }
+    }
+
+    /// Checks that the constant-time scalar multiplication agrees with the default one.
+    #[cfg(feature = "constant-time")]
+    #[test]
+    fn constant_time_scalar_mul_matches() {
+        let mut rng = OsRng;
+        let point = Group::<Bn254_Edwards>::gen(&mut rng);
+        let scalar = Scalar::<Bn254_Edwards>::gen(&mut rng);
+        assert_eq!(
+            ConstantTime(point).scalar_mul(&scalar, &mut ()),
+            point.scalar_mul(&scalar, &mut ()),
+        );
+    }
+
     /// Checks if the fixed base multiplcation is correct.
     #[test]
     fn fixed_base_mul_is_correct() {
@@ -0,0 +1,826 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Bandersnatch Embedded Curve
+//!
+//! Bandersnatch is a twisted Edwards curve defined over the scalar field of BLS12-381, like
+//! JubJub, but equipped with an efficiently computable endomorphism `ψ` of degree two which acts
+//! as multiplication by `λ` on the prime-order subgroup, where `λ² = -2`. This lets us split every
+//! scalar `k` into two halves `k₁ + λ·k₂` of roughly 128 bits each and compute `k·P` as
+//! `k₁·P + k₂·ψ(P)` with a single shared doubling chain (the GLV method), halving the number of
+//! doublings and additions in variable-base scalar multiplication both natively and in-circuit.
+//!
+//! See [`glv_mul`] for the native algorithm, [`glv_scalar_mul`] for the R1CS gadget and
+//! [`GlvGroupVar`] for the group variable which plugs the gadget into the generic
+//! [`algebra`](crate::algebra) protocols in place of the default
+//! [`GroupVar`](crate::arkworks::algebra::GroupVar).
+//!
+//! # Note
+//!
+//! The twisted Edwards addition law on Bandersnatch is only complete on the prime-order subgroup,
+//! so every point given to the functions in this module is expected to be in that subgroup.
+//!
+//! The curve parameters are taken from <https://eprint.iacr.org/2021/1152>.
+
+use crate::{
+    algebra,
+    arkworks::{
+        algebra::{Group, GroupVar, ScalarVar},
+        constraint::{Boolean, R1CS},
+        ec::{
+            models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
+            twisted_edwards_extended::{GroupAffine, GroupProjective},
+            ProjectiveCurve,
+        },
+        ed_on_bls12_381::{constraints::FqVar, Fq},
+        ff::{
+            field_new, BigInteger, BigInteger256, FftParameters, Field, Fp256, Fp256Parameters,
+            FpParameters, One, PrimeField, Zero,
+        },
+        r1cs_std::{
+            alloc::AllocVar,
+            eq::EqGadget,
+            fields::{fp::FpVar, FieldVar},
+            groups::{curves::twisted_edwards::AffineVar, CurveVar},
+            select::CondSelectGadget,
+            R1CSVar, ToBitsGadget,
+        },
+        relations::{
+            ns,
+            r1cs::{ConstraintSystemRef, SynthesisError},
+        },
+    },
+    eclair::{
+        self,
+        alloc::{
+            mode::{Public, Secret},
+            Allocate, Allocator, Constant, Variable,
+        },
+        bool::Bool,
+    },
+};
+use alloc::{vec, vec::Vec};
+
+/// Bandersnatch Scalar Field Element
+pub type Fr = Fp256<FrParameters>;
+
+/// Bandersnatch Scalar Field Parameters
+///
+/// The scalar field has modulus
+/// `r = 13108968793781547619861935127046491459309155893440570251786403306729687672801`.
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInt = BigInteger256;
+
+    const TWO_ADICITY: u32 = 5;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger256 = BigInteger256([
+        0x4b263b9a8d79c573,
+        0xeadb3d0a007af1fd,
+        0xa54c8a4668832589,
+        0x0610860c4254fb9d,
+    ]);
+}
+
+impl FpParameters for FrParameters {
+    #[rustfmt::skip]
+    const MODULUS: BigInteger256 = BigInteger256([
+        0x74fd06b52876e7e1,
+        0xff8f870074190471,
+        0x0cce760202687600,
+        0x1cfb69d4ca675f52,
+    ]);
+
+    const MODULUS_BITS: u32 = 253;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 3;
+
+    #[rustfmt::skip]
+    const R: BigInteger256 = BigInteger256([
+        0x5817ca56bc48c0f8,
+        0x0383c7fc5f37dc74,
+        0x998c4fefecbc4ff8,
+        0x1824b159acc5056f,
+    ]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger256 = BigInteger256([
+        0xdbb4f5d658db47cb,
+        0x40fa7ca27fecb938,
+        0xaa9e6daec0055cea,
+        0x0ae793ddb14aec7d,
+    ]);
+
+    const INV: u64 = 0xf19f22295cc063df;
+
+    /// GENERATOR = 7
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger256 = BigInteger256([
+        0x1fb566d55baabf63,
+        0x1accd4e45609f0f7,
+        0xf2cde1856d1be1c3,
+        0x1817c84bc55e4972,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger256 = BigInteger256([
+        13438322763177358320,
+        9207542918679396920,
+        461402362329971456,
+        1044189607433056169,
+    ]);
+
+    const T: BigInteger256 = BigInteger256([
+        10063267209553360703,
+        575471432417462307,
+        10405131189107246000,
+        65261850464566010,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger256 = BigInteger256([
+        14255005641631456159,
+        287735716208731153,
+        5202565594553623000,
+        32630925232283005,
+    ]);
+}
+
+/// Bandersnatch Affine Point
+pub type EdwardsAffine = GroupAffine<BandersnatchParameters>;
+
+/// Bandersnatch Projective Point
+pub type EdwardsProjective = GroupProjective<BandersnatchParameters>;
+
+/// Bandersnatch Affine Point Variable
+pub type EdwardsVar = AffineVar<BandersnatchParameters, FqVar>;
+
+/// Bandersnatch Curve Parameters
+///
+/// Bandersnatch is the twisted Edwards curve `-5x² + y² = 1 + dx²y²` over the scalar field of
+/// BLS12-381 where
+/// `d = 45022363124591815672509500913686876175488063829319466900776701791074614335719`.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct BandersnatchParameters;
+
+impl ModelParameters for BandersnatchParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl TEModelParameters for BandersnatchParameters {
+    /// COEFF_A = -5
+    const COEFF_A: Fq = field_new!(Fq, "-5");
+
+    #[rustfmt::skip]
+    const COEFF_D: Fq = field_new!(Fq, "45022363124591815672509500913686876175488063829319466900776701791074614335719");
+
+    /// COFACTOR = 4
+    const COFACTOR: &'static [u64] = &[4];
+
+    /// COFACTOR_INV = 4^(-1) mod r
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, "9831726595336160714896451345284868594481866920080427688839802480047265754601");
+
+    const AFFINE_GENERATOR_COEFFS: (Fq, Fq) = (GENERATOR_X, GENERATOR_Y);
+
+    type MontgomeryModelParameters = BandersnatchParameters;
+
+    #[inline(always)]
+    fn mul_by_a(elem: &Fq) -> Fq {
+        let t = elem.double().double();
+        -(t + elem)
+    }
+}
+
+impl MontgomeryModelParameters for BandersnatchParameters {
+    /// COEFF_A = 2(a + d)/(a - d)
+    #[rustfmt::skip]
+    const COEFF_A: Fq = field_new!(Fq, "29978822694968839326280996386011761570173833766074948509196803838190355340952");
+
+    /// COEFF_B = 4/(a - d)
+    #[rustfmt::skip]
+    const COEFF_B: Fq = field_new!(Fq, "25465760566081946422412445027709227188579564747101592991722834452325077642517");
+
+    type TEModelParameters = BandersnatchParameters;
+}
+
+#[rustfmt::skip]
+const GENERATOR_X: Fq = field_new!(Fq, "18886178867200960497001835917649091219057080094937609519140440539760939937304");
+
+#[rustfmt::skip]
+const GENERATOR_Y: Fq = field_new!(Fq, "19188667384257783945677642223292697773471335439753913231509108946878080696678");
+
+/// Endomorphism Eigenvalue
+///
+/// The endomorphism [`endomorphism`] acts as multiplication by `LAMBDA` on the prime-order
+/// subgroup.
+#[rustfmt::skip]
+pub const LAMBDA: Fr = field_new!(Fr, "8913659658109529928382530854484400854125314752504019737736543920008458395397");
+
+/// Endomorphism Coefficient `b`
+#[rustfmt::skip]
+const ENDOMORPHISM_B: Fq = field_new!(Fq, "37446463827641770816307242315180085052603635617490163568005256780843403514036");
+
+/// Endomorphism Coefficient `c`
+#[rustfmt::skip]
+const ENDOMORPHISM_C: Fq = field_new!(Fq, "49199877423542878313146170939139662862850515542392585932876811575731455068989");
+
+/// Little-endian 64-bit limbs of [`LAMBDA`]
+const LAMBDA_LIMBS: [u64; 4] = [
+    15077243688541478661,
+    14971264481070744942,
+    17147536215654319915,
+    1420027909992195219,
+];
+
+/// Short Lattice Basis Vector `(A1, B1)` with `A1 + λ·B1 = 0 mod r`
+const A1: u128 = 113482231691339203864511368254957623327;
+
+/// Short Lattice Basis Vector `(A1, B1)` with `A1 + λ·B1 = 0 mod r`
+const B1: u128 = 10741319382058138887739339959866629956;
+
+/// Short Lattice Basis Vector `(A2, -B2)` with `A2 - λ·B2 = 0 mod r`
+const A2: u128 = 21482638764116277775478679919733259912;
+
+/// Short Lattice Basis Vector `(A2, -B2)` with `A2 - λ·B2 = 0 mod r`
+const B2: u128 = 113482231691339203864511368254957623327;
+
+/// Rounded value of `2^254 · B2 / r`
+const G1: u128 = 0xbc877d6c15073d8cb7aeb1de8fd1d1f0;
+
+/// Rounded value of `2^254 · B1 / r`
+const G2: u128 = 0x11d83c49f61d9ef7a64edd79d51dda2b;
+
+/// Number of bits in each half of a decomposed scalar
+pub const HALF_SCALAR_BITS: usize = 128;
+
+/// Applies the endomorphism `ψ` to `point`.
+///
+/// For `point` in the prime-order subgroup this is the same as multiplying it by [`LAMBDA`].
+#[inline]
+pub fn endomorphism(point: &EdwardsAffine) -> EdwardsAffine {
+    let xy = point.x * point.y;
+    if xy.is_zero() {
+        return EdwardsAffine::zero();
+    }
+    let y2 = point.y.square();
+    let x = ENDOMORPHISM_C * (Fq::one() - y2) * xy.inverse().expect("xy is nonzero.");
+    let y = ENDOMORPHISM_B
+        * (y2 + ENDOMORPHISM_B)
+        * (y2 - ENDOMORPHISM_B)
+            .inverse()
+            .expect("Points in the prime-order subgroup have y² ≠ b.");
+    EdwardsAffine::new(x, y)
+}
+
+/// Signed Half Scalar
+///
+/// Stores a signed integer of at most [`HALF_SCALAR_BITS`] bits as a sign and a magnitude.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SignedHalf {
+    /// Sign Bit, `true` if the value is negative
+    pub is_negative: bool,
+
+    /// Magnitude
+    pub magnitude: u128,
+}
+
+impl SignedHalf {
+    /// Converts the small element `value` of `F` into a [`SignedHalf`], returning `None` if neither
+    /// `value` nor `-value` fits in [`HALF_SCALAR_BITS`] bits.
+    #[inline]
+    fn from_field<F>(value: F) -> Option<Self>
+    where
+        F: PrimeField,
+    {
+        let into_u128 = |value: F| {
+            let repr = value.into_repr();
+            if repr.num_bits() as usize > HALF_SCALAR_BITS {
+                return None;
+            }
+            let limbs = repr.as_ref();
+            Some(limbs[0] as u128 | (limbs[1] as u128) << 64)
+        };
+        match into_u128(value) {
+            Some(magnitude) => Some(Self {
+                is_negative: false,
+                magnitude,
+            }),
+            _ => into_u128(-value).map(|magnitude| Self {
+                is_negative: true,
+                magnitude,
+            }),
+        }
+    }
+
+    /// Converts `self` into an element of `F`.
+    #[inline]
+    fn into_field<F>(self) -> F
+    where
+        F: PrimeField,
+    {
+        let value = F::from(self.magnitude);
+        if self.is_negative {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
+/// GLV Scalar Decomposition
+///
+/// Stores two signed halves `k₁` and `k₂` with `k = k₁ + λ·k₂ mod r`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Decomposition {
+    /// First Half
+    pub k1: SignedHalf,
+
+    /// Second Half
+    pub k2: SignedHalf,
+}
+
+impl Decomposition {
+    /// Recombines `self` into the scalar `k₁ + λ·k₂`.
+    #[inline]
+    pub fn scalar(&self) -> Fr {
+        self.k1.into_field::<Fr>() + LAMBDA * self.k2.into_field::<Fr>()
+    }
+}
+
+/// Returns the top bits `(k · g) >> 254` of the product of `k < 2^256` and `g < 2^128`.
+#[inline]
+fn mul_shift(k: &BigInteger256, g: u128) -> u128 {
+    let g = [g as u64, (g >> 64) as u64];
+    let mut product = [0u64; 6];
+    for (i, k) in k.0.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, g) in g.iter().enumerate() {
+            let sum = (*k as u128) * (*g as u128) + product[i + j] as u128 + carry;
+            product[i + j] = sum as u64;
+            carry = sum >> 64;
+        }
+        product[i + 2] = carry as u64;
+    }
+    ((product[3] >> 62) as u128) | (product[4] as u128) << 2 | (product[5] as u128) << 66
+}
+
+/// Decomposes `k` into two signed halves `k₁ + λ·k₂` of at most [`HALF_SCALAR_BITS`] bits each.
+#[inline]
+pub fn decompose(k: &Fr) -> Decomposition {
+    let repr = k.into_repr();
+    let beta1 = Fr::from(mul_shift(&repr, G1));
+    let beta2 = Fr::from(mul_shift(&repr, G2));
+    let k1 = *k - beta1 * Fr::from(A1) - beta2 * Fr::from(A2);
+    let k2 = beta2 * Fr::from(B2) - beta1 * Fr::from(B1);
+    Decomposition {
+        k1: SignedHalf::from_field(k1).expect("The lattice basis is short enough."),
+        k2: SignedHalf::from_field(k2).expect("The lattice basis is short enough."),
+    }
+}
+
+/// Multiplies `point` by `k` using the GLV method.
+#[inline]
+pub fn glv_mul(point: &EdwardsAffine, k: &Fr) -> EdwardsProjective {
+    let decomposition = decompose(k);
+    let signed = |point: EdwardsAffine, is_negative| if is_negative { -point } else { point };
+    let p1 = signed(*point, decomposition.k1.is_negative);
+    let p2 = signed(endomorphism(point), decomposition.k2.is_negative);
+    let sum = p1 + p2;
+    let mut result = EdwardsProjective::zero();
+    for i in (0..HALF_SCALAR_BITS).rev() {
+        result.double_in_place();
+        match (
+            decomposition.k1.magnitude >> i & 1 == 1,
+            decomposition.k2.magnitude >> i & 1 == 1,
+        ) {
+            (true, true) => result.add_assign_mixed(&sum),
+            (true, false) => result.add_assign_mixed(&p1),
+            (false, true) => result.add_assign_mixed(&p2),
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Allocates the little-endian bits of `value` as `bits` Boolean witnesses and returns them,
+/// constraining them to recompose to `value` when it is given as a variable.
+#[inline]
+fn witness_bits(
+    cs: &ConstraintSystemRef<Fq>,
+    value: Option<Fq>,
+    bits: usize,
+) -> Result<Vec<Boolean<Fq>>, SynthesisError> {
+    let repr = value.map(|value| value.into_repr());
+    (0..bits)
+        .map(|i| {
+            Boolean::new_witness(cs.clone(), || {
+                repr.map(|repr| repr.get_bit(i))
+                    .ok_or(SynthesisError::AssignmentMissing)
+            })
+        })
+        .collect()
+}
+
+/// Allocates `half` as a sign bit and little-endian magnitude bits.
+#[inline]
+fn witness_signed_half(
+    cs: &ConstraintSystemRef<Fq>,
+    half: Option<SignedHalf>,
+) -> Result<(Boolean<Fq>, Vec<Boolean<Fq>>), SynthesisError> {
+    let sign = Boolean::new_witness(cs.clone(), || {
+        half.map(|half| half.is_negative)
+            .ok_or(SynthesisError::AssignmentMissing)
+    })?;
+    let magnitude = witness_bits(
+        cs,
+        half.map(|half| Fq::from(half.magnitude)),
+        HALF_SCALAR_BITS,
+    )?;
+    Ok((sign, magnitude))
+}
+
+/// Returns the 64-bit limbs of the integer whose little-endian bits are `bits`.
+#[inline]
+fn limbs(bits: &[Boolean<Fq>]) -> Result<Vec<FpVar<Fq>>, SynthesisError> {
+    bits.chunks(64).map(Boolean::le_bits_to_fp_var).collect()
+}
+
+/// Returns the signed 64-bit limbs of the signed integer with `sign` and magnitude `bits`.
+#[inline]
+fn signed_limbs(
+    sign: &Boolean<Fq>,
+    bits: &[Boolean<Fq>],
+) -> Result<Vec<FpVar<Fq>>, SynthesisError> {
+    let sign = FpVar::from(sign.clone());
+    limbs(bits)?
+        .into_iter()
+        .map(|limb| Ok(&limb - (&sign * &limb).double()?))
+        .collect()
+}
+
+/// Enforces that the signed integer with little-endian 64-bit `limbs` is zero.
+///
+/// Every limb must be smaller than `2^132` in absolute value so that the carries stay below
+/// `2^70` and no intermediate value wraps around the modulus of [`Fq`].
+#[inline]
+fn enforce_zero_integer(
+    cs: &ConstraintSystemRef<Fq>,
+    limbs: &[FpVar<Fq>],
+) -> Result<(), SynthesisError> {
+    const CARRY_BITS: usize = 70;
+    let shift = Fq::from(2u8).pow([64]);
+    let shift_inverse = shift.inverse().expect("2^64 is invertible.");
+    let offset = Fq::from(2u8).pow([CARRY_BITS as u64]);
+    let mut carry = FpVar::<Fq>::zero();
+    for (i, limb) in limbs.iter().enumerate() {
+        let sum = limb + &carry;
+        if i + 1 == limbs.len() {
+            return sum.enforce_equal(&FpVar::zero());
+        }
+        let value = sum.value().ok().map(|sum| sum * shift_inverse);
+        let next_carry = FpVar::new_witness(cs.clone(), || {
+            value.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let offset_carry = &next_carry + offset;
+        let bits = witness_bits(cs, offset_carry.value().ok(), CARRY_BITS + 1)?;
+        Boolean::le_bits_to_fp_var(&bits)?.enforce_equal(&offset_carry)?;
+        sum.enforce_equal(&(&next_carry * shift))?;
+        carry = next_carry;
+    }
+    Ok(())
+}
+
+/// Applies the endomorphism `ψ` to `point` in-circuit.
+///
+/// This costs seven constraints and additionally enforces that the result lies on the curve.
+#[inline]
+pub fn endomorphism_var(point: &EdwardsVar) -> Result<EdwardsVar, SynthesisError> {
+    let cs = point.cs();
+    let value = point
+        .value()
+        .ok()
+        .map(|point| endomorphism(&point.into_affine()));
+    let x = FqVar::new_witness(ns!(cs, "endomorphism x"), || {
+        value.map(|p| p.x).ok_or(SynthesisError::AssignmentMissing)
+    })?;
+    let y = FqVar::new_witness(ns!(cs, "endomorphism y"), || {
+        value.map(|p| p.y).ok_or(SynthesisError::AssignmentMissing)
+    })?;
+    let xy = &point.x * &point.y;
+    let y2 = point.y.square()?;
+    x.mul_equals(&xy, &((FqVar::one() - &y2) * ENDOMORPHISM_C))?;
+    y.mul_equals(
+        &(&y2 - ENDOMORPHISM_B),
+        &((&y2 + ENDOMORPHISM_B) * ENDOMORPHISM_B),
+    )?;
+    let x2 = x.square()?;
+    let y2 = y.square()?;
+    x2.mul_equals(
+        &y2,
+        &((&y2 - FqVar::one() - x2.clone().double()?.double()? - &x2)
+            * BandersnatchParameters::COEFF_D
+                .inverse()
+                .expect("d is nonzero.")),
+    )?;
+    Ok(EdwardsVar::new(x, y))
+}
+
+/// Multiplies `point` by the scalar `k` using the GLV method in-circuit.
+///
+/// The scalar `k` is given as an element of the constraint field and is interpreted modulo the
+/// Bandersnatch scalar field. The gadget witnesses the decomposition `k₁ + λ·k₂` with `k₁` and
+/// `k₂` of at most [`HALF_SCALAR_BITS`] bits each, proves `k₁ + λ·k₂ = k + t·r` over the integers
+/// for some witnessed `t` using 64-bit limbs, and computes `k₁·P + k₂·ψ(P)` with a joint
+/// double-and-add over the half-length scalars.
+#[inline]
+pub fn glv_scalar_mul(point: &EdwardsVar, k: &FqVar) -> Result<EdwardsVar, SynthesisError> {
+    let cs = point.cs().or(k.cs());
+    if cs.is_none() {
+        let point = point.value()?.into_affine();
+        let k = Fr::from_le_bytes_mod_order(&k.value()?.into_repr().to_bytes_le());
+        return Ok(EdwardsVar::constant(glv_mul(&point, &k)));
+    }
+    let k_value = k.value().ok();
+    let decomposition =
+        k_value.map(|k| decompose(&Fr::from_le_bytes_mod_order(&k.into_repr().to_bytes_le())));
+    let quotient = decomposition.zip(k_value).map(|(decomposition, k)| {
+        let lambda = Fq::from_repr(BigInteger256(LAMBDA_LIMBS)).expect("λ < r < q.");
+        let modulus = Fq::from_repr(FrParameters::MODULUS).expect("r < q.");
+        SignedHalf::from_field(
+            (decomposition.k1.into_field::<Fq>() + lambda * decomposition.k2.into_field::<Fq>()
+                - k)
+                * modulus.inverse().expect("r is nonzero."),
+        )
+        .expect("The quotient is smaller than 2^128.")
+    });
+    let (k1_sign, k1_bits) = witness_signed_half(&cs, decomposition.map(|d| d.k1))?;
+    let (k2_sign, k2_bits) = witness_signed_half(&cs, decomposition.map(|d| d.k2))?;
+    let (t_sign, t_bits) = witness_signed_half(&cs, quotient)?;
+    let k1 = signed_limbs(&k1_sign, &k1_bits)?;
+    let k2 = signed_limbs(&k2_sign, &k2_bits)?;
+    let t = signed_limbs(&t_sign, &t_bits)?;
+    let k = limbs(&k.to_bits_le()?)?;
+    let mut difference = vec![FpVar::<Fq>::zero(); 5];
+    for (i, limb) in k1.iter().enumerate() {
+        difference[i] += limb;
+    }
+    for (i, limb) in k.iter().enumerate() {
+        difference[i] -= limb;
+    }
+    for (i, lambda) in LAMBDA_LIMBS.iter().enumerate() {
+        for (j, limb) in k2.iter().enumerate() {
+            difference[i + j] += limb * Fq::from(*lambda);
+        }
+    }
+    for (i, modulus) in FrParameters::MODULUS.0.iter().enumerate() {
+        for (j, limb) in t.iter().enumerate() {
+            difference[i + j] -= limb * Fq::from(*modulus);
+        }
+    }
+    enforce_zero_integer(&cs, &difference)?;
+    let p1 = EdwardsVar::conditionally_select(&k1_sign, &point.negate()?, point)?;
+    let endomorphism = endomorphism_var(point)?;
+    let p2 = EdwardsVar::conditionally_select(&k2_sign, &endomorphism.negate()?, &endomorphism)?;
+    let table = [EdwardsVar::zero(), p1.clone(), p2.clone(), p1 + &p2];
+    let mut result = EdwardsVar::zero();
+    for (k1_bit, k2_bit) in k1_bits.into_iter().zip(k2_bits).rev() {
+        result.double_in_place()?;
+        result += EdwardsVar::conditionally_select_power_of_two_vector(&[k2_bit, k1_bit], &table)?;
+    }
+    Ok(result)
+}
+
+/// Compiler Type
+type Compiler = R1CS<Fq>;
+
+/// Bandersnatch Group Variable with GLV Scalar Multiplication
+///
+/// This group variable uses [`glv_scalar_mul`] for variable-base scalar multiplication and
+/// otherwise behaves like [`GroupVar`].
+#[derive(Clone)]
+pub struct GlvGroupVar(pub EdwardsVar);
+
+impl algebra::Group<Compiler> for GlvGroupVar {
+    #[inline]
+    fn add(&self, rhs: &Self, compiler: &mut Compiler) -> Self {
+        let _ = compiler;
+        Self(self.0.clone() + &rhs.0)
+    }
+
+    #[inline]
+    fn double_assign(&mut self, compiler: &mut Compiler) -> &mut Self {
+        let _ = compiler;
+        self.0
+            .double_in_place()
+            .expect("Doubling is not allowed to fail.");
+        self
+    }
+}
+
+impl algebra::ScalarMul<ScalarVar<EdwardsProjective, EdwardsVar>, Compiler> for GlvGroupVar {
+    type Output = Self;
+
+    #[inline]
+    fn scalar_mul(
+        &self,
+        scalar: &ScalarVar<EdwardsProjective, EdwardsVar>,
+        compiler: &mut Compiler,
+    ) -> Self {
+        let _ = compiler;
+        Self(
+            glv_scalar_mul(&self.0, scalar.as_ref())
+                .expect("Scalar multiplication is not allowed to fail."),
+        )
+    }
+}
+
+impl algebra::security::DiscreteLogarithmHardness for GlvGroupVar {}
+
+impl algebra::security::ComputationalDiffieHellmanHardness for GlvGroupVar {}
+
+impl eclair::cmp::PartialEq<Self, Compiler> for GlvGroupVar {
+    #[inline]
+    fn eq(&self, rhs: &Self, compiler: &mut Compiler) -> Bool<Compiler> {
+        let _ = compiler;
+        self.0
+            .is_eq(&rhs.0)
+            .expect("Equality checking is not allowed to fail.")
+    }
+}
+
+impl Constant<Compiler> for GlvGroupVar {
+    type Type = Group<EdwardsProjective>;
+
+    #[inline]
+    fn new_constant(this: &Self::Type, compiler: &mut Compiler) -> Self {
+        Self(this.as_constant::<GroupVar<_, EdwardsVar>>(compiler).0)
+    }
+}
+
+impl Variable<Public, Compiler> for GlvGroupVar {
+    type Type = Group<EdwardsProjective>;
+
+    #[inline]
+    fn new_known(this: &Self::Type, compiler: &mut Compiler) -> Self {
+        Self(this.as_known::<Public, GroupVar<_, EdwardsVar>>(compiler).0)
+    }
+
+    #[inline]
+    fn new_unknown(compiler: &mut Compiler) -> Self {
+        Self(
+            compiler
+                .allocate_unknown::<Public, GroupVar<_, EdwardsVar>>()
+                .0,
+        )
+    }
+}
+
+impl Variable<Secret, Compiler> for GlvGroupVar {
+    type Type = Group<EdwardsProjective>;
+
+    #[inline]
+    fn new_known(this: &Self::Type, compiler: &mut Compiler) -> Self {
+        Self(this.as_known::<Secret, GroupVar<_, EdwardsVar>>(compiler).0)
+    }
+
+    #[inline]
+    fn new_unknown(compiler: &mut Compiler) -> Self {
+        Self(
+            compiler
+                .allocate_unknown::<Secret, GroupVar<_, EdwardsVar>>()
+                .0,
+        )
+    }
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        algebra::ScalarMul,
+        arkworks::{algebra::Scalar, ec::AffineCurve},
+        constraint::measure::Measure,
+        eclair::bool::AssertEq,
+        rand::{OsRng, Sample},
+    };
+
+    /// Samples a random point in the prime-order subgroup.
+    #[inline]
+    fn sample_point() -> EdwardsAffine {
+        EdwardsAffine::prime_subgroup_generator()
+            .mul(Scalar::<EdwardsProjective>::gen(&mut OsRng).0)
+            .into_affine()
+    }
+
+    /// Checks that the curve parameters describe a curve whose generator has prime order `r`.
+    #[test]
+    fn generator_is_in_prime_order_subgroup() {
+        let generator = EdwardsAffine::prime_subgroup_generator();
+        assert!(generator.is_on_curve());
+        assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+        assert!(!generator.is_zero());
+    }
+
+    /// Checks that the endomorphism acts as multiplication by [`LAMBDA`].
+    #[test]
+    fn endomorphism_is_multiplication_by_lambda() {
+        assert_eq!(LAMBDA.square(), -Fr::from(2u8));
+        assert!(endomorphism(&EdwardsAffine::zero()).is_zero());
+        for _ in 0..16 {
+            let point = sample_point();
+            assert_eq!(endomorphism(&point), point.mul(LAMBDA).into_affine());
+        }
+    }
+
+    /// Checks that the GLV decomposition recombines to the original scalar and that GLV
+    /// multiplication agrees with the default scalar multiplication.
+    #[test]
+    fn glv_mul_is_correct() {
+        for k in [Fr::zero(), Fr::one(), -Fr::one(), LAMBDA, -LAMBDA] {
+            assert_eq!(decompose(&k).scalar(), k);
+        }
+        for _ in 0..16 {
+            let point = sample_point();
+            let k = Scalar::<EdwardsProjective>::gen(&mut OsRng).0;
+            assert_eq!(decompose(&k).scalar(), k);
+            assert_eq!(glv_mul(&point, &k), point.mul(k));
+        }
+    }
+
+    /// Checks that the GLV scalar multiplication gadget agrees with the default scalar
+    /// multiplication gadget and uses fewer constraints.
+    #[test]
+    fn glv_scalar_mul_gadget_is_correct() {
+        let mut cs = Compiler::for_proofs();
+        let point = Group::<EdwardsProjective>(sample_point());
+        let scalar = Scalar::<EdwardsProjective>::gen(&mut OsRng);
+        let point_var = point.as_known::<Secret, GroupVar<_, EdwardsVar>>(&mut cs);
+        let glv_point_var = point.as_known::<Secret, GlvGroupVar>(&mut cs);
+        let scalar_var =
+            scalar.as_known::<Secret, ScalarVar<EdwardsProjective, EdwardsVar>>(&mut cs);
+        let start = cs.constraint_count();
+        let expected = point_var.scalar_mul(&scalar_var, &mut cs);
+        let middle = cs.constraint_count();
+        let actual = glv_point_var.scalar_mul(&scalar_var, &mut cs);
+        let end = cs.constraint_count();
+        cs.assert_eq(&GlvGroupVar(expected.0), &actual);
+        assert!(cs.is_satisfied());
+        assert_eq!(
+            actual.0.value().expect("Value is known."),
+            glv_mul(&point.0, &scalar.0)
+        );
+        println!("default scalar mul constraints: {:?}", middle - start);
+        println!("GLV scalar mul constraints: {:?}", end - middle);
+        assert!(end - middle < middle - start);
+    }
+
+    /// Checks that the in-circuit endomorphism maps the identity to the identity.
+    #[test]
+    fn endomorphism_gadget_preserves_identity() {
+        let mut cs = Compiler::for_proofs();
+        let identity = Group::<EdwardsProjective>(EdwardsAffine::zero())
+            .as_known::<Secret, GlvGroupVar>(&mut cs);
+        let image = endomorphism_var(&identity.0).expect("Endomorphism is not allowed to fail.");
+        assert!(image.value().expect("Value is known.").is_zero());
+        assert!(cs.is_satisfied());
+    }
+
+    /// Checks that the GLV scalar multiplication gadget allocates the same number of constraints
+    /// with and without known values, so that it can be used for proving key generation.
+    #[test]
+    fn glv_scalar_mul_gadget_has_fixed_shape() {
+        let mut contexts = Compiler::for_contexts();
+        let point = contexts.allocate_unknown::<Secret, GlvGroupVar>();
+        let scalar =
+            contexts.allocate_unknown::<Secret, ScalarVar<EdwardsProjective, EdwardsVar>>();
+        let _ = point.scalar_mul(&scalar, &mut contexts);
+        let mut proofs = Compiler::for_proofs();
+        let point =
+            Group::<EdwardsProjective>(sample_point()).as_known::<Secret, GlvGroupVar>(&mut proofs);
+        let scalar = Scalar::<EdwardsProjective>::gen(&mut OsRng)
+            .as_known::<Secret, ScalarVar<EdwardsProjective, EdwardsVar>>(&mut proofs);
+        let _ = point.scalar_mul(&scalar, &mut proofs);
+        assert_eq!(contexts.constraint_count(), proofs.constraint_count());
+        assert!(proofs.is_satisfied());
+    }
+}
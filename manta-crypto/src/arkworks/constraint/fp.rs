@@ -26,8 +26,10 @@ use crate::{
     eclair::{
         self,
         bool::{Bool, ConditionalSelect},
+        ops::{ConstantTimeEq, ConstantTimeSelect},
     },
     rand::{RngCore, Sample},
+    transcript::{Blake2sTranscript, Challenge},
 };
 use alloc::vec::Vec;
 use core::iter;
@@ -143,6 +145,34 @@ where
     }
 }
 
+impl<F> ConstantTimeEq for Fp<F>
+where
+    F: PrimeField,
+{
+    #[inline]
+    fn ct_eq(&self, rhs: &Self, compiler: &mut ()) -> bool {
+        self.0
+            .into_repr()
+            .as_ref()
+            .ct_eq(rhs.0.into_repr().as_ref(), compiler)
+    }
+}
+
+impl<F> ConstantTimeSelect for Fp<F>
+where
+    F: PrimeField,
+{
+    #[inline]
+    fn ct_select(bit: &Bool, true_value: &Self, false_value: &Self, compiler: &mut ()) -> Self {
+        let true_repr = true_value.0.into_repr();
+        let mut repr = false_value.0.into_repr();
+        for (limb, true_limb) in repr.as_mut().iter_mut().zip(true_repr.as_ref()) {
+            *limb = u64::ct_select(bit, true_limb, limb, compiler);
+        }
+        Self(F::from_repr(repr).expect("Both inputs are canonical field elements."))
+    }
+}
+
 impl<F> eclair::num::Zero for Fp<F>
 where
     F: Field,
@@ -204,6 +234,22 @@ where
     }
 }
 
+impl<F> Challenge<Fp<F>> for Blake2sTranscript
+where
+    F: PrimeField,
+{
+    /// Squeezes 64 bytes out of the transcript and reduces them into a field element so that the
+    /// challenge is close to uniform.
+    #[inline]
+    fn challenge(&mut self, label: &Self::Label, compiler: &mut ()) -> Fp<F> {
+        let _ = compiler;
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&self.challenge_bytes(label));
+        bytes[32..].copy_from_slice(&self.challenge_bytes(label));
+        Fp(F::from_le_bytes_mod_order(&bytes))
+    }
+}
+
 impl<F> algebra::Group for Fp<F>
 where
     F: Field,
@@ -33,7 +33,10 @@ use crate::{
             },
         },
     },
-    constraint::measure::{Count, Measure},
+    constraint::{
+        measure::{Count, Measure},
+        Satisfied,
+    },
     eclair::{
         self,
         alloc::{
@@ -41,8 +44,9 @@ use crate::{
             Constant, Variable,
         },
         bool::{Assert, ConditionalSelect, ConditionalSwap},
-        num::{AssertWithinBitRange, Zero},
-        ops::{Add, BitAnd, BitOr, Rem},
+        lookup::{Lookup, Table},
+        num::{AssertWithinBitRange, One, Zero},
+        ops::{Add, BitAnd, BitOr, ConstantTimeEq, Mul, Rem},
         Has, NonNative,
     },
 };
@@ -146,6 +150,16 @@ where
 
 impl<F> NonNative for R1CS<F> where F: PrimeField {}
 
+impl<F> Satisfied for R1CS<F>
+where
+    F: PrimeField,
+{
+    #[inline]
+    fn is_satisfied(&self) -> bool {
+        Self::is_satisfied(self)
+    }
+}
+
 impl<F> Has<bool> for R1CS<F>
 where
     F: PrimeField,
@@ -325,6 +339,25 @@ where
     }
 }
 
+/// Rank-one constraint systems have no lookup argument, so membership is enforced by asserting
+/// that `value` is a root of the vanishing polynomial of `table`, which costs one multiplication
+/// per entry.
+impl<F> Lookup<FpVar<F>, Fp<F>> for R1CS<F>
+where
+    F: PrimeField,
+{
+    #[inline]
+    fn assert_in_table(&mut self, table: &Table<Fp<F>>, value: &FpVar<F>) {
+        let mut product: FpVar<F> = FieldVar::one();
+        for entry in table.entries() {
+            product *= value - entry.0;
+        }
+        product
+            .enforce_equal(&FieldVar::zero())
+            .expect("Enforcing equality is not allowed to fail.");
+    }
+}
+
 impl<F> Constant<R1CS<F>> for FpVar<F>
 where
     F: PrimeField,
@@ -401,6 +434,18 @@ where
     }
 }
 
+impl<F> ConstantTimeEq<Self, R1CS<F>> for FpVar<F>
+where
+    F: PrimeField,
+{
+    /// Returns the equality check between `self` and `rhs`, which is constant-time because the
+    /// constraints it allocates do not depend on the values of the variables.
+    #[inline]
+    fn ct_eq(&self, rhs: &Self, compiler: &mut R1CS<F>) -> Boolean<F> {
+        eclair::cmp::PartialEq::eq(self, rhs, compiler)
+    }
+}
+
 impl<F> ConditionalSelect<R1CS<F>> for FpVar<F>
 where
     F: PrimeField,
@@ -444,6 +489,19 @@ where
     }
 }
 
+impl<F> Mul<Self, R1CS<F>> for FpVar<F>
+where
+    F: PrimeField,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self, compiler: &mut R1CS<F>) -> Self {
+        let _ = compiler;
+        self * rhs
+    }
+}
+
 impl<F> Zero<R1CS<F>> for FpVar<F>
 where
     F: PrimeField,
@@ -463,6 +521,25 @@ where
     }
 }
 
+impl<F> One<R1CS<F>> for FpVar<F>
+where
+    F: PrimeField,
+{
+    type Verification = Boolean<F>;
+
+    #[inline]
+    fn one(compiler: &mut R1CS<F>) -> Self {
+        let _ = compiler;
+        FieldVar::one()
+    }
+
+    #[inline]
+    fn is_one(&self, compiler: &mut R1CS<F>) -> Self::Verification {
+        let _ = compiler;
+        FieldVar::is_one(self).expect("Comparison with one is not allowed to fail.")
+    }
+}
+
 /// Prime Modulus
 #[derive(derivative::Derivative)]
 #[derivative(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
@@ -556,7 +633,10 @@ mod tests {
     use super::*;
     use crate::{
         arkworks::{bn254::Fr, ff::BigInteger},
-        eclair::alloc::Allocate,
+        eclair::{
+            alloc::Allocate,
+            lookup::{assert_decomposition, assert_in_table_by_equality},
+        },
         rand::{OsRng, Rand, RngCore},
     };
     use alloc::vec::Vec;
@@ -634,4 +714,42 @@ mod tests {
         test_assert_within_range::<_, Fr, 64, 32>(&mut rng);
         test_assert_within_range::<_, Fr, 128, 32>(&mut rng);
     }
+
+    /// Checks that lookups into the byte table are satisfied exactly for bytes, both with the
+    /// vanishing-polynomial encoding and with the equality fallback.
+    #[test]
+    fn lookup_is_correct() {
+        let table = Table::<Fp<Fr>>::bytes();
+        for (value, should_pass) in [(0, true), (255, true), (256, false), (u128::MAX, false)] {
+            let mut cs = R1CS::<Fr>::for_proofs();
+            let variable = Fp::from(value).as_known::<Secret, FpVar<_>>(&mut cs);
+            cs.assert_in_table(&table, &variable);
+            assert_eq!(should_pass, cs.is_satisfied(), "on value {value}");
+            let mut cs = R1CS::<Fr>::for_proofs();
+            let variable = Fp::from(value).as_known::<Secret, FpVar<_>>(&mut cs);
+            assert_in_table_by_equality(&table, &variable, &mut cs);
+            assert_eq!(should_pass, cs.is_satisfied(), "on value {value}");
+        }
+    }
+
+    /// Checks that byte decompositions are satisfied only by the little-endian bytes of the value.
+    #[test]
+    fn byte_decomposition_is_correct() {
+        let table = Table::<Fp<Fr>>::bytes();
+        let value = 0xdead_beefu128;
+        for (limbs, should_pass) in [
+            ([0xef, 0xbe, 0xad, 0xde], true),
+            ([0xde, 0xad, 0xbe, 0xef], false),
+            ([0xbeef, 0, 0xdead, 0], false),
+        ] {
+            let mut cs = R1CS::<Fr>::for_proofs();
+            let base = Fp::from(256).as_constant::<FpVar<_>>(&mut cs);
+            let limbs = limbs
+                .map(|limb| Fp::from(limb).as_known::<Secret, FpVar<_>>(&mut cs))
+                .to_vec();
+            let variable = Fp::from(value).as_known::<Secret, FpVar<_>>(&mut cs);
+            assert_decomposition(&table, &base, &limbs, &variable, &mut cs);
+            assert_eq!(should_pass, cs.is_satisfied(), "on limbs {limbs:?}");
+        }
+    }
 }
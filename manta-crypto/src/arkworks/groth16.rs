@@ -15,6 +15,9 @@
 // along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Groth16 Proof System
+//!
+//! Proof verification only depends on `alloc`, so [`Groth16::verify`] and the
+//! [`PreparedVerifyingKeyCache`] are available in `no_std` environments like on-chain runtimes.
 
 use crate::{
     arkworks::{
@@ -26,11 +29,11 @@ use crate::{
             HasSerialization, Read, SerializationError, Write,
         },
     },
-    constraint::{Input, ProofSystem},
+    constraint::{dynamic::Encoding, Input, ProofSystem},
     rand::{CryptoRng, RngCore, SizedRng},
 };
 use alloc::vec::Vec;
-use ark_groth16::{Groth16 as ArkGroth16, PreparedVerifyingKey, ProvingKey, VerifyingKey};
+use ark_groth16::Groth16 as ArkGroth16;
 use ark_snark::SNARK;
 use core::{
     hash::{Hash, Hasher},
@@ -41,6 +44,9 @@ use manta_util::codec::{self, DecodeError};
 #[cfg(feature = "scale")]
 use crate::crypto::ecc::arkworks::Group;
 
+#[doc(inline)]
+pub use ark_groth16::{PreparedVerifyingKey, ProvingKey, VerifyingKey};
+
 #[cfg(feature = "serde")]
 use {
     crate::arkworks::serialize::{canonical_deserialize, canonical_serialize},
@@ -237,6 +243,18 @@ where
         Self { proving_key }
     }
 
+    /// Returns the [`ProvingKey`] of `self`.
+    #[inline]
+    pub fn proving_key(&self) -> &ProvingKey<E> {
+        &self.proving_key
+    }
+
+    /// Returns the [`VerifyingKey`] embedded in `self`.
+    #[inline]
+    pub fn verifying_key(&self) -> &VerifyingKey<E> {
+        &self.proving_key.vk
+    }
+
     /// Returns the [`VerifyingContext`] for `self`.
     #[inline]
     pub fn get_verifying_context(&self) -> Result<VerifyingContext<E>, SynthesisError> {
@@ -332,6 +350,19 @@ where
     ) -> Result<Self, SynthesisError> {
         Self::new(&proving_context.proving_key.vk)
     }
+
+    /// Returns the [`VerifyingKey`] that `self` was prepared from.
+    #[inline]
+    pub fn verifying_key(&self) -> &VerifyingKey<E> {
+        &self.0.vk
+    }
+
+    /// Returns the [`CompressedVerifyingKey`] for `self`, dropping the prepared pairing
+    /// elements.
+    #[inline]
+    pub fn to_compressed(&self) -> CompressedVerifyingKey<E> {
+        CompressedVerifyingKey(self.0.vk.clone())
+    }
 }
 
 impl<E> CanonicalSerialize for VerifyingContext<E>
@@ -538,6 +569,355 @@ where
     }
 }
 
+/// Compressed Verifying Key
+///
+/// The [`VerifyingContext`] encoding contains the prepared pairing elements, which are several
+/// times larger than the verifying key itself. This type only stores the [`VerifyingKey`] using
+/// compressed curve points in the fixed layout
+///
+/// ```text
+/// alpha_g1 || beta_g2 || gamma_g2 || delta_g2 || len(gamma_abc_g1) as u32 || gamma_abc_g1
+/// ```
+///
+/// where the length is little-endian, which makes it suitable for runtime storage. The
+/// [`VerifyingContext`] is prepared again with [`into_verifying_context`] after decoding.
+///
+/// [`into_verifying_context`]: Self::into_verifying_context
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(deserialize = "", serialize = ""),
+        crate = "manta_util::serde",
+        deny_unknown_fields,
+        try_from = "Vec<u8>"
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Debug, Default(bound = ""), PartialEq)]
+pub struct CompressedVerifyingKey<E>(
+    /// Verifying Key
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_compressed_verifying_key::<E, _>")
+    )]
+    pub VerifyingKey<E>,
+)
+where
+    E: PairingEngine;
+
+impl<E> CompressedVerifyingKey<E>
+where
+    E: PairingEngine,
+{
+    /// Converts `self` into its canonical byte-representation.
+    #[inline]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(self.serialized_size());
+        CanonicalSerialize::serialize(self, &mut buffer)
+            .expect("Serialization is not allowed to fail.");
+        buffer
+    }
+
+    /// Parses a [`CompressedVerifyingKey`] from `bytes`, rejecting any trailing bytes.
+    #[inline]
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, SerializationError> {
+        let key = <Self as CanonicalDeserialize>::deserialize(&mut bytes)?;
+        if bytes.is_empty() {
+            Ok(key)
+        } else {
+            Err(SerializationError::InvalidData)
+        }
+    }
+
+    /// Prepares the [`VerifyingContext`] for `self`.
+    #[inline]
+    pub fn into_verifying_context(self) -> Result<VerifyingContext<E>, SynthesisError> {
+        VerifyingContext::new(&self.0)
+    }
+}
+
+impl<E> CanonicalSerialize for CompressedVerifyingKey<E>
+where
+    E: PairingEngine,
+{
+    #[inline]
+    fn serialize<W>(&self, mut writer: W) -> Result<(), SerializationError>
+    where
+        W: Write,
+    {
+        let VerifyingKey {
+            alpha_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g2,
+            gamma_abc_g1,
+        } = &self.0;
+        alpha_g1.serialize(&mut writer)?;
+        beta_g2.serialize(&mut writer)?;
+        gamma_g2.serialize(&mut writer)?;
+        delta_g2.serialize(&mut writer)?;
+        let len =
+            u32::try_from(gamma_abc_g1.len()).map_err(|_| SerializationError::NotEnoughSpace)?;
+        CanonicalSerialize::serialize(&len, &mut writer)?;
+        for point in gamma_abc_g1 {
+            point.serialize(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        let VerifyingKey {
+            alpha_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g2,
+            gamma_abc_g1,
+        } = &self.0;
+        alpha_g1.serialized_size()
+            + beta_g2.serialized_size()
+            + gamma_g2.serialized_size()
+            + delta_g2.serialized_size()
+            + CanonicalSerialize::serialized_size(&0u32)
+            + gamma_abc_g1
+                .iter()
+                .map(CanonicalSerialize::serialized_size)
+                .sum::<usize>()
+    }
+}
+
+impl<E> CanonicalDeserialize for CompressedVerifyingKey<E>
+where
+    E: PairingEngine,
+{
+    #[inline]
+    fn deserialize<R>(mut reader: R) -> Result<Self, SerializationError>
+    where
+        R: Read,
+    {
+        let alpha_g1 = CanonicalDeserialize::deserialize(&mut reader)?;
+        let beta_g2 = CanonicalDeserialize::deserialize(&mut reader)?;
+        let gamma_g2 = CanonicalDeserialize::deserialize(&mut reader)?;
+        let delta_g2 = CanonicalDeserialize::deserialize(&mut reader)?;
+        let len = <u32 as CanonicalDeserialize>::deserialize(&mut reader)?;
+        let gamma_abc_g1 = (0..len)
+            .map(|_| CanonicalDeserialize::deserialize(&mut reader))
+            .collect::<Result<_, _>>()?;
+        Ok(Self(VerifyingKey {
+            alpha_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g2,
+            gamma_abc_g1,
+        }))
+    }
+}
+
+impl<E> codec::Decode for CompressedVerifyingKey<E>
+where
+    E: PairingEngine,
+{
+    type Error = SerializationError;
+
+    #[inline]
+    fn decode<R>(reader: R) -> Result<Self, DecodeError<R::Error, Self::Error>>
+    where
+        R: codec::Read,
+    {
+        let mut reader = ArkReader::new(reader);
+        match CanonicalDeserialize::deserialize(&mut reader) {
+            Ok(value) => reader
+                .finish()
+                .map(move |_| value)
+                .map_err(DecodeError::Read),
+            Err(err) => Err(DecodeError::Decode(err)),
+        }
+    }
+}
+
+impl<E> codec::Encode for CompressedVerifyingKey<E>
+where
+    E: PairingEngine,
+{
+    #[inline]
+    fn encode<W>(&self, writer: W) -> Result<(), W::Error>
+    where
+        W: codec::Write,
+    {
+        let mut writer = ArkWriter::new(writer);
+        let _ = CanonicalSerialize::serialize(self, &mut writer);
+        writer.finish().map(move |_| ())
+    }
+}
+
+impl<E> From<&VerifyingContext<E>> for CompressedVerifyingKey<E>
+where
+    E: PairingEngine,
+{
+    #[inline]
+    fn from(context: &VerifyingContext<E>) -> Self {
+        context.to_compressed()
+    }
+}
+
+impl<E> TryFrom<Vec<u8>> for CompressedVerifyingKey<E>
+where
+    E: PairingEngine,
+{
+    type Error = SerializationError;
+
+    #[inline]
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// Uses `serializer` to serialize `verifying_key` in the [`CompressedVerifyingKey`] layout.
+#[cfg(feature = "serde")]
+#[inline]
+fn serialize_compressed_verifying_key<E, S>(
+    verifying_key: &VerifyingKey<E>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    E: PairingEngine,
+    S: Serializer,
+{
+    serializer.serialize_bytes(&CompressedVerifyingKey(verifying_key.clone()).to_bytes())
+}
+
+/// Prepared Verifying Key Cache
+///
+/// Preparing a [`VerifyingKey`] computes the pairing of its `alpha` and `beta` elements and the
+/// line coefficients of its `gamma` and `delta` elements, which is more expensive than the
+/// verification itself. Environments which only store plain verifying keys, like on-chain
+/// runtimes, can keep this cache around so that every key is only prepared once. The cache keeps
+/// the [`VerifyingContext`]s of the `capacity` most recently used keys.
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Debug)]
+pub struct PreparedVerifyingKeyCache<E>
+where
+    E: PairingEngine,
+{
+    /// Maximum Number of Cached Contexts
+    capacity: usize,
+
+    /// Cached Contexts, from Least to Most Recently Used
+    contexts: Vec<VerifyingContext<E>>,
+}
+
+impl<E> PreparedVerifyingKeyCache<E>
+where
+    E: PairingEngine,
+{
+    /// Builds a new empty [`PreparedVerifyingKeyCache`] which holds at most `capacity` prepared
+    /// verifying keys.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `capacity` is zero.
+    #[inline]
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "The cache capacity must be positive.");
+        Self {
+            capacity,
+            contexts: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the maximum number of prepared verifying keys that `self` can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of prepared verifying keys in `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.contexts.len()
+    }
+
+    /// Returns `true` if `self` has no prepared verifying keys.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.contexts.is_empty()
+    }
+
+    /// Returns the position of the prepared `verifying_key` in `self`.
+    #[inline]
+    fn position(&self, verifying_key: &VerifyingKey<E>) -> Option<usize> {
+        self.contexts
+            .iter()
+            .position(|context| context.verifying_key() == verifying_key)
+    }
+
+    /// Returns the cached [`VerifyingContext`] for `verifying_key` without preparing it.
+    #[inline]
+    pub fn get(&self, verifying_key: &VerifyingKey<E>) -> Option<&VerifyingContext<E>> {
+        self.position(verifying_key)
+            .map(|index| &self.contexts[index])
+    }
+
+    /// Inserts the already prepared `context` into `self`, evicting the least recently used
+    /// context if `self` is full.
+    #[inline]
+    pub fn insert(&mut self, context: VerifyingContext<E>) -> &VerifyingContext<E> {
+        match self.position(context.verifying_key()) {
+            Some(index) => {
+                self.contexts.remove(index);
+            }
+            _ if self.contexts.len() == self.capacity => {
+                self.contexts.remove(0);
+            }
+            _ => {}
+        }
+        self.contexts.push(context);
+        self.contexts
+            .last()
+            .expect("The context was just inserted.")
+    }
+
+    /// Returns the [`VerifyingContext`] for `verifying_key`, preparing and caching it if it is
+    /// not already in `self`.
+    #[inline]
+    pub fn prepare(
+        &mut self,
+        verifying_key: &VerifyingKey<E>,
+    ) -> Result<&VerifyingContext<E>, SynthesisError> {
+        match self.position(verifying_key) {
+            Some(index) => {
+                let context = self.contexts.remove(index);
+                self.contexts.push(context);
+                Ok(self
+                    .contexts
+                    .last()
+                    .expect("The context was just reinserted."))
+            }
+            _ => Ok(self.insert(VerifyingContext::new(verifying_key)?)),
+        }
+    }
+
+    /// Verifies `proof` against `input` under `verifying_key`, preparing it if it is not already
+    /// in `self`.
+    #[inline]
+    pub fn verify(
+        &mut self,
+        verifying_key: &VerifyingKey<E>,
+        input: &[E::Fr],
+        proof: &Proof<E>,
+    ) -> Result<bool, Error> {
+        let context = self.prepare(verifying_key).map_err(|_| Error)?;
+        ArkGroth16::verify_with_processed_vk(&context.0, input, &proof.0).map_err(|_| Error)
+    }
+
+    /// Removes all the prepared verifying keys from `self`.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.contexts.clear();
+    }
+}
+
 /// Arkworks Groth16 Proof System
 #[derive(derivative::Derivative)]
 #[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -609,6 +989,37 @@ where
     }
 }
 
+impl<E> Encoding for Groth16<E>
+where
+    E: PairingEngine,
+{
+    #[inline]
+    fn encode_input(input: &Self::Input) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        input
+            .serialize(&mut buffer)
+            .expect("Serialization is not allowed to fail.");
+        buffer
+    }
+
+    #[inline]
+    fn decode_input(mut bytes: &[u8]) -> Option<Self::Input> {
+        let input = CanonicalDeserialize::deserialize(&mut bytes).ok()?;
+        bytes.is_empty().then_some(input)
+    }
+
+    #[inline]
+    fn encode_proof(proof: &Self::Proof) -> Vec<u8> {
+        proof_as_bytes(&proof.0)
+    }
+
+    #[inline]
+    fn decode_proof(mut bytes: &[u8]) -> Option<Self::Proof> {
+        let proof = CanonicalDeserialize::deserialize(&mut bytes).ok()?;
+        bytes.is_empty().then_some(Proof(proof))
+    }
+}
+
 /// Implements [`Input`] over [`Groth16`] for `$type` that can convert to a field element.
 macro_rules! public_input_impl {
     ($($type:tt),* $(,)?) => {
@@ -627,3 +1038,85 @@ macro_rules! public_input_impl {
 }
 
 public_input_impl!(bool, u8, u16, u32, u64, u128);
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        arkworks::{
+            bn254::{Bn254, Fr},
+            constraint::fp::Fp,
+            r1cs_std::{eq::EqGadget, fields::fp::FpVar},
+        },
+        constraint::dynamic::{DynProofSystem, Erased, Error as DynError},
+        eclair::alloc::{
+            mode::{Public, Secret},
+            Allocate, Allocator,
+        },
+        rand::OsRng,
+    };
+    use alloc::boxed::Box;
+
+    /// Allocates the circuit `a * b == c` where `c` is public, using `values` as the witness if
+    /// they are known.
+    #[inline]
+    fn product_circuit(values: Option<(u64, u64)>) -> R1CS<Fr> {
+        match values {
+            Some((a, b)) => {
+                let mut compiler = R1CS::for_proofs();
+                let lhs = Fp(Fr::from(a)).as_known::<Secret, FpVar<_>>(&mut compiler);
+                let rhs = Fp(Fr::from(b)).as_known::<Secret, FpVar<_>>(&mut compiler);
+                let product = Fp(Fr::from(a * b)).as_known::<Public, FpVar<_>>(&mut compiler);
+                (lhs * rhs)
+                    .enforce_equal(&product)
+                    .expect("Enforcing equality is not allowed to fail.");
+                compiler
+            }
+            _ => {
+                let mut compiler = R1CS::for_contexts();
+                let lhs = compiler.allocate_unknown::<Secret, FpVar<_>>();
+                let rhs = compiler.allocate_unknown::<Secret, FpVar<_>>();
+                let product = compiler.allocate_unknown::<Public, FpVar<_>>();
+                (lhs * rhs)
+                    .enforce_equal(&product)
+                    .expect("Enforcing equality is not allowed to fail.");
+                compiler
+            }
+        }
+    }
+
+    /// Checks that erased Groth16 proof systems prove and verify through byte encodings and can
+    /// be stored side by side.
+    #[test]
+    fn erased_proof_system_round_trip() {
+        let (proving_context, verifying_context) =
+            Groth16::<Bn254>::compile(&(), product_circuit(None), &mut OsRng)
+                .expect("Unable to compile circuit.");
+        let systems: Vec<Box<dyn DynProofSystem>> = vec![
+            Erased::<Groth16<Bn254>>::new(proving_context, verifying_context.clone()).boxed(),
+            Erased::<Groth16<Bn254>>::verifier(verifying_context).boxed(),
+        ];
+        let proof = systems[0]
+            .prove(Box::new(product_circuit(Some((3, 7)))), &mut OsRng)
+            .expect("Unable to generate proof.");
+        let input = Groth16::<Bn254>::encode_input(&vec![Fr::from(21u64)]);
+        let wrong_input = Groth16::<Bn254>::encode_input(&vec![Fr::from(22u64)]);
+        for system in &systems {
+            assert_eq!(system.verify(&input, &proof), Ok(true));
+            assert_eq!(system.verify(&wrong_input, &proof), Ok(false));
+            assert_eq!(
+                system.verify(&input, &proof[1..]),
+                Err(DynError::InvalidProof)
+            );
+        }
+        assert_eq!(
+            systems[1].prove(Box::new(product_circuit(Some((3, 7)))), &mut OsRng),
+            Err(DynError::MissingProvingContext)
+        );
+        assert_eq!(
+            systems[0].prove(Box::new(()), &mut OsRng),
+            Err(DynError::UnexpectedCompiler)
+        );
+    }
+}
@@ -0,0 +1,1046 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Inner-Product Argument Proof System
+//!
+//! This is an experimental transparent proof system for [`R1CS`] constraint systems which needs
+//! neither a universal nor a circuit-specific trusted setup. The constraints are translated into
+//! the arithmetic circuits of [Bulletproofs] and proven with its zero-knowledge argument, which
+//! reduces to an inner-product argument over Pedersen vector commitments in a prime-order group
+//! `G` whose scalar field is the constraint field. All the commitment generators are hashed to
+//! the curve, so compiling a circuit only depends on its constraints.
+//!
+//! Proofs are logarithmic in the size of the circuit, but proving and verifying are both linear,
+//! which makes this backend much slower to verify than [`Groth16`](super::groth16::Groth16).
+//!
+//! # Circuit Translation
+//!
+//! For `m` constraints `(A z) * (B z) = (C z)` over the assignment `z = (1, x, w)` with public
+//! input `x` and witness `w`, the first `m` multiplication gates hold the rows of `A z`, `B z` and
+//! `C z` as their left inputs, right inputs and outputs, and the following gates hold two witness
+//! variables each as their inputs. Every row of the three matrices becomes one linear constraint
+//! which binds the gate to the witness gates, while the public input is moved into the constant
+//! terms, so that the verifier can compute it.
+//!
+//! [Bulletproofs]: https://eprint.iacr.org/2017/1066
+
+use crate::{
+    arkworks::{
+        constraint::R1CS,
+        ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve},
+        ff::{batch_inversion, Field, One, PrimeField, UniformRand, Zero},
+        relations::r1cs::Matrix,
+        serialize::{CanonicalDeserialize, CanonicalSerialize, Read, SerializationError, Write},
+    },
+    constraint::{dynamic::Encoding, Input, ProofSystem},
+    rand::{CryptoRng, RngCore},
+    transcript::{Absorb, Blake2sTranscript},
+};
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+
+/// Proof System Error
+///
+/// This is the error state of the [`Ipa`] proof system methods. This type is intentionally opaque
+/// so that error details are not revealed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Error;
+
+/// Returns the multi-scalar multiplication of `bases` by `scalars`.
+#[inline]
+pub(crate) fn msm<G>(bases: &[G], scalars: &[G::ScalarField]) -> G::Projective
+where
+    G: AffineCurve,
+{
+    let scalars = scalars
+        .iter()
+        .map(PrimeField::into_repr)
+        .collect::<Vec<_>>();
+    VariableBaseMSM::multi_scalar_mul(bases, &scalars)
+}
+
+/// Returns the inner product of `lhs` and `rhs`.
+#[inline]
+fn inner_product<F>(lhs: &[F], rhs: &[F]) -> F
+where
+    F: Field,
+{
+    lhs.iter().zip(rhs).map(|(l, r)| *l * r).sum()
+}
+
+/// Returns the first `n` powers of `x`, starting from one.
+#[inline]
+fn powers<F>(x: F, n: usize) -> Vec<F>
+where
+    F: Field,
+{
+    let mut powers = Vec::with_capacity(n);
+    let mut power = F::one();
+    for _ in 0..n {
+        powers.push(power);
+        power *= x;
+    }
+    powers
+}
+
+/// Returns the canonical serialization of `value`.
+#[inline]
+pub(crate) fn to_bytes<T>(value: &T) -> Vec<u8>
+where
+    T: CanonicalSerialize,
+{
+    let mut buffer = Vec::new();
+    value
+        .serialize(&mut buffer)
+        .expect("Serialization is not allowed to fail.");
+    buffer
+}
+
+/// Hashes `label` and `index` to a point in the prime-order subgroup of the curve `G` under
+/// `domain`, by trying successive outputs of a transcript until one of them is the encoding of a
+/// point.
+#[inline]
+pub(crate) fn hash_to_curve<G>(domain: &[u8], label: &[u8], index: usize) -> G
+where
+    G: AffineCurve,
+{
+    let mut transcript = Blake2sTranscript::new(domain);
+    transcript.absorb(b"label", label, &mut ());
+    transcript.absorb(b"index", &(index as u64).to_le_bytes(), &mut ());
+    loop {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&transcript.challenge_bytes(b"point"));
+        bytes[32..].copy_from_slice(&transcript.challenge_bytes(b"point"));
+        if let Some(point) = G::from_random_bytes(&bytes) {
+            let point = point.mul_by_cofactor();
+            if !point.is_zero() {
+                return point;
+            }
+        }
+    }
+}
+
+/// Returns the instance and witness assignments of `compiler`, or `None` if `compiler` was not
+/// built for proofs.
+#[inline]
+pub(crate) fn assignments<F>(compiler: &R1CS<F>) -> Option<(Vec<F>, Vec<F>)>
+where
+    F: PrimeField,
+{
+    let constraint_system = compiler.as_ref().borrow()?;
+    Some((
+        constraint_system.instance_assignment.clone(),
+        constraint_system.witness_assignment.clone(),
+    ))
+}
+
+/// Commitment Generators
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Debug, Eq, PartialEq)]
+pub struct Generators<G>
+where
+    G: AffineCurve,
+{
+    /// Left Input Generators
+    left: Vec<G>,
+
+    /// Right Input Generators
+    right: Vec<G>,
+
+    /// Value Generator
+    value: G,
+
+    /// Blinding Generator
+    blinding: G,
+
+    /// Inner Product Generator
+    inner_product: G,
+}
+
+impl<G> Generators<G>
+where
+    G: AffineCurve,
+{
+    /// Generator Derivation Domain
+    pub const DOMAIN: &'static [u8] = b"manta-crypto/ipa/generators";
+
+    /// Derives the generators for vectors of length `n` by hashing to the curve.
+    #[inline]
+    pub fn new(n: usize) -> Self {
+        let generator = |label: &[u8], index| hash_to_curve(Self::DOMAIN, label, index);
+        Self {
+            left: (0..n).map(|i| generator(b"left", i)).collect(),
+            right: (0..n).map(|i| generator(b"right", i)).collect(),
+            value: generator(b"value", 0),
+            blinding: generator(b"blinding", 0),
+            inner_product: generator(b"inner_product", 0),
+        }
+    }
+
+    /// Returns the length of the vectors committed to by `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    /// Returns `true` if `self` has no vector generators.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+}
+
+/// Linear Constraint Weights
+///
+/// Weights of the left inputs, right inputs and outputs of the multiplication gates in the linear
+/// constraints of a [`Circuit`], combined by the powers of a verifier challenge.
+struct Weights<F> {
+    /// Gate Weights for the Left Inputs, Right Inputs and Outputs
+    gates: [Vec<F>; 3],
+
+    /// Combined Constant Terms
+    constant: F,
+}
+
+/// Compiled Circuit
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Debug, Eq, PartialEq)]
+pub struct Circuit<F>
+where
+    F: PrimeField,
+{
+    /// Number of Instance Variables, including the Constant One
+    instance_count: usize,
+
+    /// Number of Witness Variables
+    witness_count: usize,
+
+    /// Constraint Matrices
+    matrices: [Matrix<F>; 3],
+
+    /// Circuit Digest
+    digest: [u8; 32],
+}
+
+impl<F> Circuit<F>
+where
+    F: PrimeField,
+{
+    /// Circuit Digest Domain
+    pub const DOMAIN: &'static [u8] = b"manta-crypto/ipa/circuit";
+
+    /// Compiles the constraints of `compiler` into a [`Circuit`], returning `None` if `compiler`
+    /// does not record its constraint matrices.
+    #[inline]
+    pub fn new(compiler: &R1CS<F>) -> Option<Self> {
+        let constraint_system = compiler.as_ref();
+        constraint_system.finalize();
+        let matrices = constraint_system.to_matrices()?;
+        let mut circuit = Self {
+            instance_count: matrices.num_instance_variables,
+            witness_count: matrices.num_witness_variables,
+            matrices: [matrices.a, matrices.b, matrices.c],
+            digest: [0; 32],
+        };
+        circuit.digest = circuit.compute_digest();
+        Some(circuit)
+    }
+
+    /// Computes the digest of the variable counts and the constraint matrices of `self`.
+    #[inline]
+    fn compute_digest(&self) -> [u8; 32] {
+        let mut transcript = Blake2sTranscript::new(Self::DOMAIN);
+        transcript.absorb(
+            b"instance_count",
+            &(self.instance_count as u64).to_le_bytes(),
+            &mut (),
+        );
+        transcript.absorb(
+            b"witness_count",
+            &(self.witness_count as u64).to_le_bytes(),
+            &mut (),
+        );
+        for matrix in &self.matrices {
+            for row in matrix {
+                transcript.absorb(b"row", &(row.len() as u64).to_le_bytes(), &mut ());
+                for (coefficient, index) in row {
+                    transcript.absorb(b"coefficient", to_bytes(coefficient).as_slice(), &mut ());
+                    transcript.absorb(b"index", &(*index as u64).to_le_bytes(), &mut ());
+                }
+            }
+        }
+        transcript.challenge_bytes(b"digest")
+    }
+
+    /// Returns the number of instance variables of `self`, including the constant one.
+    #[inline]
+    pub fn instance_count(&self) -> usize {
+        self.instance_count
+    }
+
+    /// Returns the number of witness variables of `self`.
+    #[inline]
+    pub fn witness_count(&self) -> usize {
+        self.witness_count
+    }
+
+    /// Returns the `A`, `B` and `C` constraint matrices of `self`.
+    #[inline]
+    pub fn matrices(&self) -> &[Matrix<F>; 3] {
+        &self.matrices
+    }
+
+    /// Returns the digest of the variable counts and the constraint matrices of `self`.
+    #[inline]
+    pub fn digest(&self) -> &[u8; 32] {
+        &self.digest
+    }
+
+    /// Returns the number of constraints of `self`.
+    #[inline]
+    pub fn constraint_count(&self) -> usize {
+        self.matrices[0].len()
+    }
+
+    /// Returns the number of multiplication gates of `self`, padded to a power of two.
+    #[inline]
+    pub fn gate_count(&self) -> usize {
+        (self.constraint_count() + self.witness_count.div_ceil(2)).next_power_of_two()
+    }
+
+    /// Returns the side and the index of the multiplication gate which holds the witness variable
+    /// with the given `index`.
+    #[inline]
+    fn witness_gate(&self, index: usize) -> (usize, usize) {
+        (index % 2, self.constraint_count() + index / 2)
+    }
+
+    /// Assigns the multiplication gates of `self` from the `instance` and `witness` assignments,
+    /// returning `None` if the assignments do not satisfy the constraints.
+    #[inline]
+    fn assign(&self, instance: &[F], witness: &[F]) -> Option<[Vec<F>; 3]> {
+        if instance.len() != self.instance_count || witness.len() != self.witness_count {
+            return None;
+        }
+        let value = |index: usize| {
+            if index < self.instance_count {
+                instance[index]
+            } else {
+                witness[index - self.instance_count]
+            }
+        };
+        let n = self.gate_count();
+        let mut gates = [vec![F::zero(); n], vec![F::zero(); n], vec![F::zero(); n]];
+        for (matrix, gates) in self.matrices.iter().zip(gates.iter_mut()) {
+            for (row, gate) in matrix.iter().zip(gates.iter_mut()) {
+                *gate = row
+                    .iter()
+                    .map(|(coefficient, index)| *coefficient * value(*index))
+                    .sum();
+            }
+        }
+        for (index, value) in witness.iter().enumerate() {
+            let (side, gate) = self.witness_gate(index);
+            gates[side][gate] = *value;
+        }
+        let [left, right, output] = &mut gates;
+        for (gate, ((l, r), o)) in left.iter().zip(right.iter()).zip(output).enumerate() {
+            if gate < self.constraint_count() {
+                if *l * r != *o {
+                    return None;
+                }
+            } else {
+                *o = *l * r;
+            }
+        }
+        Some(gates)
+    }
+
+    /// Returns the weights of the linear constraints of `self` combined by the powers of
+    /// `challenge`, moving the public `input` into the constant terms. Returns `None` if `input`
+    /// has the wrong length.
+    #[inline]
+    fn weights(&self, challenge: F, input: &[F]) -> Option<Weights<F>> {
+        if input.len() + 1 != self.instance_count {
+            return None;
+        }
+        let n = self.gate_count();
+        let mut gates = [vec![F::zero(); n], vec![F::zero(); n], vec![F::zero(); n]];
+        let mut constant = F::zero();
+        let mut power = F::one();
+        for row in 0..self.constraint_count() {
+            for (side, matrix) in self.matrices.iter().enumerate() {
+                power *= challenge;
+                gates[side][row] += power;
+                for (coefficient, index) in &matrix[row] {
+                    if *index == 0 {
+                        constant += power * coefficient;
+                    } else if *index < self.instance_count {
+                        constant += power * coefficient * input[index - 1];
+                    } else {
+                        let (side, gate) = self.witness_gate(index - self.instance_count);
+                        gates[side][gate] -= power * coefficient;
+                    }
+                }
+            }
+        }
+        Some(Weights { gates, constant })
+    }
+}
+
+/// Proving and Verifying Context
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Debug, Eq, PartialEq)]
+pub struct Context<G>
+where
+    G: AffineCurve,
+{
+    /// Circuit
+    circuit: Circuit<G::ScalarField>,
+
+    /// Generators
+    generators: Generators<G>,
+}
+
+impl<G> Context<G>
+where
+    G: AffineCurve,
+{
+    /// Returns the compiled [`Circuit`] of `self`.
+    #[inline]
+    pub fn circuit(&self) -> &Circuit<G::ScalarField> {
+        &self.circuit
+    }
+
+    /// Returns the commitment [`Generators`] of `self`.
+    #[inline]
+    pub fn generators(&self) -> &Generators<G> {
+        &self.generators
+    }
+}
+
+/// Inner-Product Argument
+#[derive(derivative::Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(Clone, Debug, Eq, PartialEq)]
+pub struct InnerProductProof<G>
+where
+    G: AffineCurve,
+{
+    /// Left Commitments of each Round
+    left: Vec<G>,
+
+    /// Right Commitments of each Round
+    right: Vec<G>,
+
+    /// Folded Left Vector
+    a: G::ScalarField,
+
+    /// Folded Right Vector
+    b: G::ScalarField,
+}
+
+impl<G> InnerProductProof<G>
+where
+    G: AffineCurve,
+{
+    /// Proves that the commitment to `a` and `b` under `left` and `right` opens to their inner
+    /// product under `inner_product`, deriving the challenges from `transcript`.
+    #[inline]
+    fn prove(
+        transcript: &mut ProofTranscript,
+        mut left: Vec<G>,
+        mut right: Vec<G>,
+        inner_product: G,
+        mut a: Vec<G::ScalarField>,
+        mut b: Vec<G::ScalarField>,
+    ) -> Result<Self, Error> {
+        let mut left_commitments = Vec::new();
+        let mut right_commitments = Vec::new();
+        while a.len() > 1 {
+            let k = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(k);
+            let (b_lo, b_hi) = b.split_at(k);
+            let (g_lo, g_hi) = left.split_at(k);
+            let (h_lo, h_hi) = right.split_at(k);
+            let l = (msm(g_hi, a_lo)
+                + msm(h_lo, b_hi)
+                + inner_product.mul(inner_product_of(a_lo, b_hi)))
+            .into_affine();
+            let r = (msm(g_lo, a_hi)
+                + msm(h_hi, b_lo)
+                + inner_product.mul(inner_product_of(a_hi, b_lo)))
+            .into_affine();
+            transcript.point(b"L", &l);
+            transcript.point(b"R", &r);
+            let x = transcript.challenge::<G::ScalarField>(b"x");
+            let x_inverse = x.inverse().ok_or(Error)?;
+            a = fold(a_lo, a_hi, x, x_inverse);
+            b = fold(b_lo, b_hi, x_inverse, x);
+            left = fold_points(g_lo, g_hi, x_inverse, x);
+            right = fold_points(h_lo, h_hi, x, x_inverse);
+            left_commitments.push(l);
+            right_commitments.push(r);
+        }
+        Ok(Self {
+            left: left_commitments,
+            right: right_commitments,
+            a: a[0],
+            b: b[0],
+        })
+    }
+
+    /// Absorbs the round commitments of `self` into `transcript`, returning the round challenges
+    /// and their inverses if `self` has the right number of rounds for vectors of length `n`.
+    #[inline]
+    fn challenges(
+        &self,
+        transcript: &mut ProofTranscript,
+        n: usize,
+    ) -> Option<Vec<(G::ScalarField, G::ScalarField)>> {
+        if self.left.len() != self.right.len() || n.trailing_zeros() as usize != self.left.len() {
+            return None;
+        }
+        self.left
+            .iter()
+            .zip(&self.right)
+            .map(|(l, r)| {
+                transcript.point(b"L", l);
+                transcript.point(b"R", r);
+                let x = transcript.challenge::<G::ScalarField>(b"x");
+                Some((x, x.inverse()?))
+            })
+            .collect()
+    }
+}
+
+/// Returns the inner product of `lhs` and `rhs` as a scalar multiplier.
+#[inline]
+fn inner_product_of<F>(lhs: &[F], rhs: &[F]) -> F::BigInt
+where
+    F: PrimeField,
+{
+    inner_product(lhs, rhs).into_repr()
+}
+
+/// Folds `lo` and `hi` into `lo * x + hi * y`.
+#[inline]
+fn fold<F>(lo: &[F], hi: &[F], x: F, y: F) -> Vec<F>
+where
+    F: Field,
+{
+    lo.iter().zip(hi).map(|(l, h)| *l * x + *h * y).collect()
+}
+
+/// Folds the points `lo` and `hi` into `lo * x + hi * y`.
+#[inline]
+fn fold_points<G>(lo: &[G], hi: &[G], x: G::ScalarField, y: G::ScalarField) -> Vec<G>
+where
+    G: AffineCurve,
+{
+    let (x, y) = (x.into_repr(), y.into_repr());
+    G::Projective::batch_normalization_into_affine(
+        &lo.iter()
+            .zip(hi)
+            .map(|(l, h)| l.mul(x) + h.mul(y))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Inner-Product Argument Proof
+#[derive(derivative::Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(Clone, Debug, Eq, PartialEq)]
+pub struct Proof<G>
+where
+    G: AffineCurve,
+{
+    /// Commitment to the Gate Inputs
+    input_commitment: G,
+
+    /// Commitment to the Gate Outputs
+    output_commitment: G,
+
+    /// Commitment to the Blinding Vectors
+    blinding_commitment: G,
+
+    /// Commitments to the Coefficients of the Inner-Product Polynomial, except for the Second
+    polynomial_commitments: Vec<G>,
+
+    /// Blinding Factor of the Inner-Product Polynomial Evaluation
+    tau: G::ScalarField,
+
+    /// Blinding Factor of the Vector Commitments
+    mu: G::ScalarField,
+
+    /// Inner-Product Polynomial Evaluation
+    t: G::ScalarField,
+
+    /// Inner-Product Argument
+    inner_product_proof: InnerProductProof<G>,
+}
+
+/// Returns the canonical byte-representation of `proof`.
+#[inline]
+pub fn proof_as_bytes<G>(proof: &Proof<G>) -> Vec<u8>
+where
+    G: AffineCurve,
+{
+    to_bytes(proof)
+}
+
+/// Proof Transcript
+struct ProofTranscript(Blake2sTranscript);
+
+impl ProofTranscript {
+    /// Proof Transcript Domain
+    const DOMAIN: &'static [u8] = b"manta-crypto/ipa/proof";
+
+    /// Builds a new [`ProofTranscript`] for proofs of the circuit with the given `digest` and
+    /// public `input`.
+    #[inline]
+    fn new<F>(digest: &[u8; 32], input: &[F]) -> Self
+    where
+        F: PrimeField,
+    {
+        let mut transcript = Blake2sTranscript::new(Self::DOMAIN);
+        transcript.absorb(b"circuit", digest, &mut ());
+        transcript.absorb(b"input", to_bytes(&input.to_vec()).as_slice(), &mut ());
+        Self(transcript)
+    }
+
+    /// Absorbs `point` under `label`.
+    #[inline]
+    fn point<G>(&mut self, label: &[u8], point: &G)
+    where
+        G: AffineCurve,
+    {
+        self.0.absorb(label, to_bytes(point).as_slice(), &mut ());
+    }
+
+    /// Absorbs `scalar` under `label`.
+    #[inline]
+    fn scalar<F>(&mut self, label: &[u8], scalar: &F)
+    where
+        F: PrimeField,
+    {
+        self.0.absorb(label, to_bytes(scalar).as_slice(), &mut ());
+    }
+
+    /// Squeezes a scalar challenge under `label`.
+    #[inline]
+    fn challenge<F>(&mut self, label: &[u8]) -> F
+    where
+        F: PrimeField,
+    {
+        F::from_le_bytes_mod_order(&self.0.challenge_bytes(label))
+    }
+}
+
+/// Inner-Product Argument Proof System over the Curve `G`
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ipa<G>(PhantomData<G>)
+where
+    G: AffineCurve;
+
+impl<G> ProofSystem for Ipa<G>
+where
+    G: AffineCurve,
+{
+    type Compiler = R1CS<G::ScalarField>;
+    type PublicParameters = ();
+    type ProvingContext = Context<G>;
+    type VerifyingContext = Context<G>;
+    type Input = Vec<G::ScalarField>;
+    type Proof = Proof<G>;
+    type Error = Error;
+
+    #[inline]
+    fn context_compiler() -> Self::Compiler {
+        Self::Compiler::for_contexts()
+    }
+
+    #[inline]
+    fn proof_compiler() -> Self::Compiler {
+        Self::Compiler::for_proofs()
+    }
+
+    #[inline]
+    fn compile<R>(
+        public_parameters: &Self::PublicParameters,
+        compiler: Self::Compiler,
+        rng: &mut R,
+    ) -> Result<(Self::ProvingContext, Self::VerifyingContext), Self::Error>
+    where
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        let _ = (public_parameters, rng);
+        let circuit = Circuit::new(&compiler).ok_or(Error)?;
+        let context = Context {
+            generators: Generators::new(circuit.gate_count()),
+            circuit,
+        };
+        Ok((context.clone(), context))
+    }
+
+    #[inline]
+    fn prove<R>(
+        context: &Self::ProvingContext,
+        compiler: Self::Compiler,
+        rng: &mut R,
+    ) -> Result<Self::Proof, Self::Error>
+    where
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        let Context {
+            circuit,
+            generators,
+        } = context;
+        let (instance, witness) = assignments(&compiler).ok_or(Error)?;
+        let [a_l, a_r, a_o] = circuit.assign(&instance, &witness).ok_or(Error)?;
+        let n = generators.len();
+        let mut transcript = ProofTranscript::new(&circuit.digest, &instance[1..]);
+        let mut sample = || G::ScalarField::rand(&mut *rng);
+        let (alpha, beta, rho) = (sample(), sample(), sample());
+        let s_l = (0..n).map(|_| sample()).collect::<Vec<_>>();
+        let s_r = (0..n).map(|_| sample()).collect::<Vec<_>>();
+        let input_commitment = (msm(&generators.left, &a_l)
+            + msm(&generators.right, &a_r)
+            + generators.blinding.mul(alpha.into_repr()))
+        .into_affine();
+        let output_commitment =
+            (msm(&generators.left, &a_o) + generators.blinding.mul(beta.into_repr())).into_affine();
+        let blinding_commitment = (msm(&generators.left, &s_l)
+            + msm(&generators.right, &s_r)
+            + generators.blinding.mul(rho.into_repr()))
+        .into_affine();
+        transcript.point(b"A_I", &input_commitment);
+        transcript.point(b"A_O", &output_commitment);
+        transcript.point(b"S", &blinding_commitment);
+        let y = transcript.challenge::<G::ScalarField>(b"y");
+        let z = transcript.challenge(b"z");
+        let y_inverse = y.inverse().ok_or(Error)?;
+        let Weights {
+            gates: [w_l, w_r, w_o],
+            ..
+        } = circuit.weights(z, &instance[1..]).ok_or(Error)?;
+        let y_powers = powers(y, n);
+        let y_inverse_powers = powers(y_inverse, n);
+        let l_1 = (0..n)
+            .map(|i| a_l[i] + y_inverse_powers[i] * w_r[i])
+            .collect::<Vec<_>>();
+        let l_2 = a_o;
+        let l_3 = s_l;
+        let r_0 = (0..n).map(|i| w_o[i] - y_powers[i]).collect::<Vec<_>>();
+        let r_1 = (0..n)
+            .map(|i| y_powers[i] * a_r[i] + w_l[i])
+            .collect::<Vec<_>>();
+        let r_3 = (0..n).map(|i| y_powers[i] * s_r[i]).collect::<Vec<_>>();
+        let coefficients = [
+            (1, inner_product(&l_1, &r_0)),
+            (3, inner_product(&l_2, &r_1) + inner_product(&l_3, &r_0)),
+            (4, inner_product(&l_1, &r_3) + inner_product(&l_3, &r_1)),
+            (5, inner_product(&l_2, &r_3)),
+            (6, inner_product(&l_3, &r_3)),
+        ];
+        let mut polynomial_commitments = Vec::with_capacity(coefficients.len());
+        let mut taus = Vec::with_capacity(coefficients.len());
+        for (_, coefficient) in coefficients {
+            let tau = sample();
+            let commitment = (generators.value.mul(coefficient.into_repr())
+                + generators.blinding.mul(tau.into_repr()))
+            .into_affine();
+            transcript.point(b"T", &commitment);
+            polynomial_commitments.push(commitment);
+            taus.push(tau);
+        }
+        let x = transcript.challenge::<G::ScalarField>(b"x");
+        let x_powers = powers(x, 7);
+        let l = (0..n)
+            .map(|i| l_1[i] * x + l_2[i] * x_powers[2] + l_3[i] * x_powers[3])
+            .collect::<Vec<_>>();
+        let r = (0..n)
+            .map(|i| r_0[i] + r_1[i] * x + r_3[i] * x_powers[3])
+            .collect::<Vec<_>>();
+        let t = inner_product(&l, &r);
+        let tau = coefficients
+            .iter()
+            .zip(&taus)
+            .map(|((power, _), tau)| *tau * x_powers[*power])
+            .sum::<G::ScalarField>();
+        let mu = alpha * x + beta * x_powers[2] + rho * x_powers[3];
+        transcript.scalar(b"t", &t);
+        transcript.scalar(b"tau", &tau);
+        transcript.scalar(b"mu", &mu);
+        let w = transcript.challenge::<G::ScalarField>(b"w");
+        let right = G::Projective::batch_normalization_into_affine(
+            &generators
+                .right
+                .iter()
+                .zip(&y_inverse_powers)
+                .map(|(h, y)| h.mul(y.into_repr()))
+                .collect::<Vec<_>>(),
+        );
+        let inner_product_proof = InnerProductProof::prove(
+            &mut transcript,
+            generators.left.clone(),
+            right,
+            generators.inner_product.mul(w.into_repr()).into_affine(),
+            l,
+            r,
+        )?;
+        Ok(Proof {
+            input_commitment,
+            output_commitment,
+            blinding_commitment,
+            polynomial_commitments,
+            tau,
+            mu,
+            t,
+            inner_product_proof,
+        })
+    }
+
+    #[inline]
+    fn verify(
+        context: &Self::VerifyingContext,
+        input: &Self::Input,
+        proof: &Self::Proof,
+    ) -> Result<bool, Self::Error> {
+        let Context {
+            circuit,
+            generators,
+        } = context;
+        if proof.polynomial_commitments.len() != 5 {
+            return Ok(false);
+        }
+        let n = generators.len();
+        let mut transcript = ProofTranscript::new(&circuit.digest, input);
+        transcript.point(b"A_I", &proof.input_commitment);
+        transcript.point(b"A_O", &proof.output_commitment);
+        transcript.point(b"S", &proof.blinding_commitment);
+        let y = transcript.challenge::<G::ScalarField>(b"y");
+        let z = transcript.challenge(b"z");
+        let y_inverse = y.inverse().ok_or(Error)?;
+        let Weights {
+            gates: [w_l, w_r, w_o],
+            constant,
+        } = match circuit.weights(z, input) {
+            Some(weights) => weights,
+            _ => return Ok(false),
+        };
+        for commitment in &proof.polynomial_commitments {
+            transcript.point(b"T", commitment);
+        }
+        let x = transcript.challenge::<G::ScalarField>(b"x");
+        transcript.scalar(b"t", &proof.t);
+        transcript.scalar(b"tau", &proof.tau);
+        transcript.scalar(b"mu", &proof.mu);
+        let w = transcript.challenge::<G::ScalarField>(b"w");
+        let challenges = match proof.inner_product_proof.challenges(&mut transcript, n) {
+            Some(challenges) => challenges,
+            _ => return Ok(false),
+        };
+        let x_powers = powers(x, 7);
+        let y_inverse_powers = powers(y_inverse, n);
+        let delta = (0..n)
+            .map(|i| y_inverse_powers[i] * w_r[i] * w_l[i])
+            .sum::<G::ScalarField>();
+        let mut bases = vec![generators.value, generators.blinding];
+        let mut scalars = vec![proof.t - x_powers[2] * (delta + constant), proof.tau];
+        for (commitment, power) in proof.polynomial_commitments.iter().zip([1, 3, 4, 5, 6]) {
+            bases.push(*commitment);
+            scalars.push(-x_powers[power]);
+        }
+        if !msm(&bases, &scalars).is_zero() {
+            return Ok(false);
+        }
+        let mut s = vec![G::ScalarField::one()];
+        for (x, x_inverse) in &challenges {
+            s = s.iter().flat_map(|s| [*s * x_inverse, *s * x]).collect();
+        }
+        let mut s_inverse = s.clone();
+        batch_inversion(&mut s_inverse);
+        let InnerProductProof { left, right, a, b } = &proof.inner_product_proof;
+        let mut bases = Vec::with_capacity(2 * n + 2 * challenges.len() + 5);
+        let mut scalars = Vec::with_capacity(bases.capacity());
+        bases.extend_from_slice(&generators.left);
+        scalars.extend((0..n).map(|i| x * y_inverse_powers[i] * w_r[i] - *a * s[i]));
+        bases.extend_from_slice(&generators.right);
+        scalars.extend((0..n).map(|i| {
+            y_inverse_powers[i] * (x * w_l[i] + w_o[i] - *b * s_inverse[i]) - G::ScalarField::one()
+        }));
+        bases.extend([
+            proof.input_commitment,
+            proof.output_commitment,
+            proof.blinding_commitment,
+            generators.blinding,
+            generators.inner_product,
+        ]);
+        scalars.extend([
+            x,
+            x_powers[2],
+            x_powers[3],
+            -proof.mu,
+            w * (proof.t - *a * b),
+        ]);
+        for ((l, r), (x, x_inverse)) in left.iter().zip(right).zip(&challenges) {
+            bases.push(*l);
+            scalars.push(x.square());
+            bases.push(*r);
+            scalars.push(x_inverse.square());
+        }
+        Ok(msm(&bases, &scalars).is_zero())
+    }
+}
+
+impl<G> Encoding for Ipa<G>
+where
+    G: AffineCurve,
+{
+    #[inline]
+    fn encode_input(input: &Self::Input) -> Vec<u8> {
+        to_bytes(input)
+    }
+
+    #[inline]
+    fn decode_input(mut bytes: &[u8]) -> Option<Self::Input> {
+        let input = CanonicalDeserialize::deserialize(&mut bytes).ok()?;
+        bytes.is_empty().then_some(input)
+    }
+
+    #[inline]
+    fn encode_proof(proof: &Self::Proof) -> Vec<u8> {
+        proof_as_bytes(proof)
+    }
+
+    #[inline]
+    fn decode_proof(mut bytes: &[u8]) -> Option<Self::Proof> {
+        let proof = CanonicalDeserialize::deserialize(&mut bytes).ok()?;
+        bytes.is_empty().then_some(proof)
+    }
+}
+
+/// Implements [`Input`] over [`Ipa`] for `$type` that can convert to a field element.
+macro_rules! public_input_impl {
+    ($($type:tt),* $(,)?) => {
+        $(
+            impl<G> Input<Ipa<G>> for $type
+            where
+                G: AffineCurve,
+            {
+                #[inline]
+                fn extend(&self, input: &mut Vec<G::ScalarField>) {
+                    input.push((*self).into());
+                }
+            }
+        )*
+    };
+}
+
+public_input_impl!(bool, u8, u16, u32, u64, u128);
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        arkworks::{
+            bn254::{Fr, G1Affine},
+            constraint::fp::Fp,
+            r1cs_std::{eq::EqGadget, fields::fp::FpVar},
+        },
+        eclair::alloc::{
+            mode::{Public, Secret},
+            Allocate, Allocator,
+        },
+        rand::OsRng,
+    };
+
+    /// Allocates the circuit `a * b * c == d` where `d` is public, using `values` as the witness
+    /// if they are known.
+    #[inline]
+    fn product_circuit(values: Option<(u64, u64, u64)>) -> R1CS<Fr> {
+        match values {
+            Some((a, b, c)) => {
+                let mut compiler = R1CS::for_proofs();
+                let a_var = Fp(Fr::from(a)).as_known::<Secret, FpVar<_>>(&mut compiler);
+                let b_var = Fp(Fr::from(b)).as_known::<Secret, FpVar<_>>(&mut compiler);
+                let c_var = Fp(Fr::from(c)).as_known::<Secret, FpVar<_>>(&mut compiler);
+                let product = Fp(Fr::from(a * b * c)).as_known::<Public, FpVar<_>>(&mut compiler);
+                (a_var * b_var * c_var)
+                    .enforce_equal(&product)
+                    .expect("Enforcing equality is not allowed to fail.");
+                compiler
+            }
+            _ => {
+                let mut compiler = R1CS::for_contexts();
+                let a_var = compiler.allocate_unknown::<Secret, FpVar<_>>();
+                let b_var = compiler.allocate_unknown::<Secret, FpVar<_>>();
+                let c_var = compiler.allocate_unknown::<Secret, FpVar<_>>();
+                let product = compiler.allocate_unknown::<Public, FpVar<_>>();
+                (a_var * b_var * c_var)
+                    .enforce_equal(&product)
+                    .expect("Enforcing equality is not allowed to fail.");
+                compiler
+            }
+        }
+    }
+
+    /// Checks that valid proofs verify, through their byte encodings, and that they do not verify
+    /// for the wrong public input.
+    #[test]
+    fn prove_and_verify() {
+        let (proving_context, verifying_context) =
+            Ipa::<G1Affine>::compile(&(), product_circuit(None), &mut OsRng)
+                .expect("Unable to compile circuit.");
+        let proof = Ipa::prove(
+            &proving_context,
+            product_circuit(Some((3, 5, 7))),
+            &mut OsRng,
+        )
+        .expect("Unable to generate proof.");
+        let proof = Ipa::<G1Affine>::decode_proof(&Ipa::encode_proof(&proof))
+            .expect("Unable to decode proof.");
+        assert_eq!(
+            Ipa::verify(&verifying_context, &vec![Fr::from(105u64)], &proof),
+            Ok(true)
+        );
+        assert_eq!(
+            Ipa::verify(&verifying_context, &vec![Fr::from(106u64)], &proof),
+            Ok(false)
+        );
+        assert_eq!(Ipa::verify(&verifying_context, &vec![], &proof), Ok(false));
+    }
+
+    /// Checks that assignments which do not satisfy the constraints cannot be proven.
+    #[test]
+    fn unsatisfied_constraints_are_not_proven() {
+        let (proving_context, _) = Ipa::<G1Affine>::compile(&(), product_circuit(None), &mut OsRng)
+            .expect("Unable to compile circuit.");
+        let mut compiler = R1CS::for_proofs();
+        let a_var = Fp(Fr::from(3u64)).as_known::<Secret, FpVar<_>>(&mut compiler);
+        let b_var = Fp(Fr::from(5u64)).as_known::<Secret, FpVar<_>>(&mut compiler);
+        let c_var = Fp(Fr::from(7u64)).as_known::<Secret, FpVar<_>>(&mut compiler);
+        let product = Fp(Fr::from(104u64)).as_known::<Public, FpVar<_>>(&mut compiler);
+        (a_var * b_var * c_var)
+            .enforce_equal(&product)
+            .expect("Enforcing equality is not allowed to fail.");
+        assert_eq!(
+            Ipa::prove(&proving_context, compiler, &mut OsRng),
+            Err(Error)
+        );
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Constant-Time Scalar Multiplication
+//!
+//! The default arkworks scalar multiplication is a double-and-add loop which branches on the bits
+//! of the scalar and skips its leading zeros, leaking information about the scalar through timing.
+//! This module implements a Montgomery ladder which runs for a fixed number of iterations and
+//! swaps its accumulators with field arithmetic instead of branches. On twisted Edwards curves the
+//! group law is complete, so the whole ladder is free of secret-dependent branches. The short
+//! Weierstrass group law used by arkworks still special-cases the point at infinity, so the
+//! ladder is only a best-effort hardening on those curves.
+
+use crate::arkworks::{
+    ec::{
+        short_weierstrass_jacobian, twisted_edwards_extended, AffineCurve, ProjectiveCurve,
+        SWModelParameters, TEModelParameters,
+    },
+    ff::{BigInteger, Field, FpParameters, PrimeField, Zero},
+};
+
+/// Ladder Curve
+///
+/// Projective curve representation which supports the operations needed by [`scalar_mul`].
+pub trait LadderCurve: ProjectiveCurve {
+    /// Swaps `lhs` and `rhs` if `bit` is `true`, without branching on `bit`.
+    fn conditional_swap(lhs: &mut Self, rhs: &mut Self, bit: bool);
+
+    /// Converts `point` into its affine representation using a constant-time inversion.
+    fn into_affine_constant_time(point: Self) -> Self::Affine;
+}
+
+/// Swaps `lhs` and `rhs` if `bit` is `true` by adding and subtracting `bit * (rhs - lhs)`.
+#[inline]
+fn conditional_swap_field<F>(lhs: &mut F, rhs: &mut F, bit: &F)
+where
+    F: PrimeField,
+{
+    let delta = *bit * (*rhs - *lhs);
+    *lhs += delta;
+    *rhs -= delta;
+}
+
+/// Inverts `x` by raising it to the power `p - 2`, returning zero if `x` is zero.
+///
+/// Unlike [`Field::inverse`](crate::arkworks::ff::Field::inverse), which runs the binary extended
+/// Euclidean algorithm, the running time only depends on the public modulus.
+#[inline]
+fn inverse<F>(x: &F) -> F
+where
+    F: PrimeField,
+{
+    let mut exponent = F::Params::MODULUS;
+    exponent.sub_noborrow(&F::BigInt::from(2));
+    x.pow(exponent)
+}
+
+impl<P> LadderCurve for twisted_edwards_extended::GroupProjective<P>
+where
+    P: TEModelParameters,
+    P::BaseField: PrimeField,
+{
+    #[inline]
+    fn conditional_swap(lhs: &mut Self, rhs: &mut Self, bit: bool) {
+        let bit = P::BaseField::from(bit);
+        conditional_swap_field(&mut lhs.x, &mut rhs.x, &bit);
+        conditional_swap_field(&mut lhs.y, &mut rhs.y, &bit);
+        conditional_swap_field(&mut lhs.t, &mut rhs.t, &bit);
+        conditional_swap_field(&mut lhs.z, &mut rhs.z, &bit);
+    }
+
+    #[inline]
+    fn into_affine_constant_time(point: Self) -> Self::Affine {
+        let z_inverse = inverse(&point.z);
+        twisted_edwards_extended::GroupAffine::new(point.x * z_inverse, point.y * z_inverse)
+    }
+}
+
+impl<P> LadderCurve for short_weierstrass_jacobian::GroupProjective<P>
+where
+    P: SWModelParameters,
+    P::BaseField: PrimeField,
+{
+    #[inline]
+    fn conditional_swap(lhs: &mut Self, rhs: &mut Self, bit: bool) {
+        let bit = P::BaseField::from(bit);
+        conditional_swap_field(&mut lhs.x, &mut rhs.x, &bit);
+        conditional_swap_field(&mut lhs.y, &mut rhs.y, &bit);
+        conditional_swap_field(&mut lhs.z, &mut rhs.z, &bit);
+    }
+
+    #[inline]
+    fn into_affine_constant_time(point: Self) -> Self::Affine {
+        if point.z.is_zero() {
+            return Zero::zero();
+        }
+        let z_inverse = inverse(&point.z);
+        let z_inverse_squared = z_inverse.square();
+        short_weierstrass_jacobian::GroupAffine::new(
+            point.x * z_inverse_squared,
+            point.y * z_inverse_squared * z_inverse,
+            false,
+        )
+    }
+}
+
+/// Multiplies `point` by `scalar` with a Montgomery ladder over every bit of the scalar field.
+#[inline]
+pub fn scalar_mul<C>(point: &C::Affine, scalar: &C::ScalarField) -> C::Affine
+where
+    C: LadderCurve,
+{
+    let bits = scalar.into_repr();
+    let mut lhs = C::zero();
+    let mut rhs = point.into_projective();
+    for i in (0..C::ScalarField::size_in_bits()).rev() {
+        let bit = bits.get_bit(i);
+        C::conditional_swap(&mut lhs, &mut rhs, bit);
+        rhs += &lhs;
+        lhs.double_in_place();
+        C::conditional_swap(&mut lhs, &mut rhs, bit);
+    }
+    C::into_affine_constant_time(lhs)
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        arkworks::{
+            bn254::G1Projective, ec::AffineCurve, ed_on_bn254::EdwardsProjective, ff::UniformRand,
+        },
+        rand::OsRng,
+    };
+
+    /// Checks that [`scalar_mul`] agrees with the arkworks scalar multiplication on `C`.
+    #[inline]
+    fn check_scalar_mul<C>()
+    where
+        C: LadderCurve,
+    {
+        let mut rng = OsRng;
+        let point = C::rand(&mut rng).into_affine();
+        for scalar in [
+            C::ScalarField::zero(),
+            C::ScalarField::from(1u8),
+            -C::ScalarField::from(1u8),
+            C::ScalarField::rand(&mut rng),
+        ] {
+            assert_eq!(
+                scalar_mul::<C>(&point, &scalar),
+                point.mul(scalar.into_repr()).into_affine()
+            );
+        }
+    }
+
+    /// Checks the ladder on a twisted Edwards curve.
+    #[test]
+    fn ladder_matches_twisted_edwards_scalar_mul() {
+        check_scalar_mul::<EdwardsProjective>();
+    }
+
+    /// Checks the ladder on a short Weierstrass curve.
+    #[test]
+    fn ladder_matches_short_weierstrass_scalar_mul() {
+        check_scalar_mul::<G1Projective>();
+    }
+}
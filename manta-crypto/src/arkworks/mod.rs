@@ -35,8 +35,14 @@ pub use ark_ed_on_bls12_381 as ed_on_bls12_381;
 pub use ark_ed_on_bn254 as ed_on_bn254;
 
 pub mod algebra;
+
+#[cfg(feature = "ark-ed-on-bls12-381")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "ark-ed-on-bls12-381")))]
+pub mod bandersnatch;
+
 pub mod constraint;
 pub mod ff;
+pub mod ladder;
 pub mod pairing;
 pub mod rand;
 pub mod ratio;
@@ -45,3 +51,11 @@ pub mod serialize;
 #[cfg(feature = "ark-groth16")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "ark-groth16")))]
 pub mod groth16;
+
+#[cfg(feature = "ipa")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "ipa")))]
+pub mod ipa;
+
+#[cfg(feature = "nova")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nova")))]
+pub mod nova;
@@ -0,0 +1,480 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Nova Folding Scheme
+//!
+//! This is an experimental implementation of the non-interactive folding scheme of [Nova] for
+//! [`R1CS`] circuits, which lets a prover like a rollup operator accumulate many instances of the
+//! same circuit, for example one transfer circuit per transaction in a block, into a single
+//! relaxed instance. Each [`fold`](Accumulator::fold) costs two commitments instead of a full
+//! proof, and the verifier [`folds`](Parameters::fold_instance) the public instances alongside,
+//! using only the public inputs and the [`FoldingProof`] of every step.
+//!
+//! A relaxed instance `(E, u, W, x)` is satisfied if `(A z) * (B z) = u * (C z) + E` holds for
+//! `z = (u, x, W)`, and the commitments to the error vector `E` and the witness `W` open to them.
+//! Accumulation starts from the trivially satisfied zero instance, and a single
+//! [`decision`](Parameters::decide) over the final accumulated instance checks every folded
+//! instance at once.
+//!
+//! This module only implements the folding scheme without compression: the decider takes the
+//! accumulated witness in the clear, so it is run by the operator itself and no final succinct
+//! proof of the accumulated instance is produced. Compressing the decision into a SNARK over the
+//! relaxed instance is left to a later change.
+//!
+//! [Nova]: https://eprint.iacr.org/2021/370
+
+use crate::{
+    arkworks::{
+        constraint::R1CS,
+        ec::{AffineCurve, ProjectiveCurve},
+        ff::{Field, PrimeField, Zero},
+        ipa::{assignments, hash_to_curve, msm, to_bytes, Circuit},
+        relations::r1cs::Matrix,
+        serialize::{CanonicalDeserialize, CanonicalSerialize, Read, SerializationError, Write},
+    },
+    transcript::{Absorb, Blake2sTranscript},
+};
+use alloc::{vec, vec::Vec};
+use core::iter;
+
+/// Folding Error
+///
+/// This is the error state of the [`Accumulator`] methods. This type is intentionally opaque so
+/// that error details are not revealed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Error;
+
+/// Returns the product of `matrix` with the assignment `z`.
+#[inline]
+fn multiply<F>(matrix: &Matrix<F>, z: &[F]) -> Vec<F>
+where
+    F: Field,
+{
+    matrix
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|(coefficient, index)| *coefficient * z[*index])
+                .sum::<F>()
+        })
+        .collect()
+}
+
+/// Returns the assignment `z = (scale, input, witness)`.
+#[inline]
+fn assignment<F>(scale: F, input: &[F], witness: &[F]) -> Vec<F>
+where
+    F: Field,
+{
+    iter::once(scale)
+        .chain(input.iter().copied())
+        .chain(witness.iter().copied())
+        .collect()
+}
+
+/// Relaxed R1CS Instance
+#[derive(derivative::Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(Clone, Debug, Eq, PartialEq)]
+pub struct Instance<G>
+where
+    G: AffineCurve,
+{
+    /// Commitment to the Error Vector
+    pub error_commitment: G,
+
+    /// Scalar of the Constant Terms
+    pub scale: G::ScalarField,
+
+    /// Commitment to the Witness
+    pub witness_commitment: G,
+
+    /// Public Input
+    pub input: Vec<G::ScalarField>,
+}
+
+/// Relaxed R1CS Witness
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Debug, Eq, PartialEq)]
+pub struct Witness<F>
+where
+    F: PrimeField,
+{
+    /// Error Vector
+    pub error: Vec<F>,
+
+    /// Witness
+    pub witness: Vec<F>,
+}
+
+/// Folding Proof
+///
+/// The prover sends one [`FoldingProof`] for every instance it folds into its [`Accumulator`].
+#[derive(derivative::Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FoldingProof<G>
+where
+    G: AffineCurve,
+{
+    /// Commitment to the Witness of the Folded Instance
+    pub witness_commitment: G,
+
+    /// Commitment to the Cross Term
+    pub cross_term_commitment: G,
+}
+
+/// Folding Parameters
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Debug, Eq, PartialEq)]
+pub struct Parameters<G>
+where
+    G: AffineCurve,
+{
+    /// Circuit
+    circuit: Circuit<G::ScalarField>,
+
+    /// Witness Commitment Generators
+    witness_generators: Vec<G>,
+
+    /// Error Commitment Generators
+    error_generators: Vec<G>,
+}
+
+impl<G> Parameters<G>
+where
+    G: AffineCurve,
+{
+    /// Generator Derivation Domain
+    pub const DOMAIN: &'static [u8] = b"manta-crypto/nova/generators";
+
+    /// Folding Transcript Domain
+    pub const TRANSCRIPT_DOMAIN: &'static [u8] = b"manta-crypto/nova/fold";
+
+    /// Builds the [`Parameters`] for the circuit in `compiler`, which must have been built for
+    /// contexts, deriving the commitment generators by hashing to the curve.
+    #[inline]
+    pub fn new(compiler: &R1CS<G::ScalarField>) -> Option<Self> {
+        let circuit = Circuit::new(compiler)?;
+        Some(Self {
+            witness_generators: (0..circuit.witness_count())
+                .map(|i| hash_to_curve(Self::DOMAIN, b"witness", i))
+                .collect(),
+            error_generators: (0..circuit.constraint_count())
+                .map(|i| hash_to_curve(Self::DOMAIN, b"error", i))
+                .collect(),
+            circuit,
+        })
+    }
+
+    /// Returns the compiled [`Circuit`] of `self`.
+    #[inline]
+    pub fn circuit(&self) -> &Circuit<G::ScalarField> {
+        &self.circuit
+    }
+
+    /// Commits to `values` with the first `values.len()` of `generators`.
+    #[inline]
+    fn commit(generators: &[G], values: &[G::ScalarField]) -> G {
+        msm(&generators[..values.len()], values).into_affine()
+    }
+
+    /// Returns the products of the `A`, `B` and `C` matrices with the assignment `z`.
+    #[inline]
+    fn products(&self, z: &[G::ScalarField]) -> [Vec<G::ScalarField>; 3] {
+        let [a, b, c] = self.circuit.matrices();
+        [multiply(a, z), multiply(b, z), multiply(c, z)]
+    }
+
+    /// Returns the trivially satisfied zero instance which starts every accumulation.
+    #[inline]
+    pub fn zero_instance(&self) -> Instance<G> {
+        Instance {
+            error_commitment: G::zero(),
+            scale: Zero::zero(),
+            witness_commitment: G::zero(),
+            input: vec![Zero::zero(); self.circuit.instance_count() - 1],
+        }
+    }
+
+    /// Derives the folding challenge for folding the instance with public `input` into `running`
+    /// with `proof`.
+    #[inline]
+    fn challenge(
+        &self,
+        running: &Instance<G>,
+        input: &[G::ScalarField],
+        proof: &FoldingProof<G>,
+    ) -> G::ScalarField {
+        let mut transcript = Blake2sTranscript::new(Self::TRANSCRIPT_DOMAIN);
+        transcript.absorb(b"circuit", self.circuit.digest(), &mut ());
+        transcript.absorb(b"running", to_bytes(running).as_slice(), &mut ());
+        transcript.absorb(b"input", to_bytes(&input.to_vec()).as_slice(), &mut ());
+        transcript.absorb(b"proof", to_bytes(proof).as_slice(), &mut ());
+        G::ScalarField::from_le_bytes_mod_order(&transcript.challenge_bytes(b"r"))
+    }
+
+    /// Folds the instance with public `input` and `proof` into `running`, returning `None` if
+    /// `input` has the wrong length. This is the verifier side of [`Accumulator::fold`].
+    #[inline]
+    pub fn fold_instance(
+        &self,
+        running: &Instance<G>,
+        input: &[G::ScalarField],
+        proof: &FoldingProof<G>,
+    ) -> Option<Instance<G>> {
+        if input.len() + 1 != self.circuit.instance_count() || running.input.len() != input.len() {
+            return None;
+        }
+        Some(self.fold_with(running, input, proof, self.challenge(running, input, proof)))
+    }
+
+    /// Folds the instance with public `input` and `proof` into `running` with the challenge `r`.
+    #[inline]
+    fn fold_with(
+        &self,
+        running: &Instance<G>,
+        input: &[G::ScalarField],
+        proof: &FoldingProof<G>,
+        r: G::ScalarField,
+    ) -> Instance<G> {
+        Instance {
+            error_commitment: (running.error_commitment.into_projective()
+                + proof.cross_term_commitment.mul(r.into_repr()))
+            .into_affine(),
+            scale: running.scale + r,
+            witness_commitment: (running.witness_commitment.into_projective()
+                + proof.witness_commitment.mul(r.into_repr()))
+            .into_affine(),
+            input: running
+                .input
+                .iter()
+                .zip(input)
+                .map(|(running, input)| *running + r * input)
+                .collect(),
+        }
+    }
+
+    /// Decides if `witness` satisfies the relaxed `instance`, which checks every instance that
+    /// was folded into it.
+    ///
+    /// This check is not succinct: it reads the full accumulated witness and recomputes its
+    /// commitments, so it is not a substitute for a final proof.
+    #[inline]
+    pub fn decide(&self, instance: &Instance<G>, witness: &Witness<G::ScalarField>) -> bool {
+        if instance.input.len() + 1 != self.circuit.instance_count()
+            || witness.witness.len() != self.circuit.witness_count()
+            || witness.error.len() != self.circuit.constraint_count()
+        {
+            return false;
+        }
+        let [a, b, c] = self.products(&assignment(
+            instance.scale,
+            &instance.input,
+            &witness.witness,
+        ));
+        a.iter()
+            .zip(&b)
+            .zip(&c)
+            .zip(&witness.error)
+            .all(|(((a, b), c), e)| *a * b == instance.scale * c + e)
+            && Self::commit(&self.error_generators, &witness.error) == instance.error_commitment
+            && Self::commit(&self.witness_generators, &witness.witness)
+                == instance.witness_commitment
+    }
+}
+
+/// Folding Accumulator
+///
+/// The prover side of the folding scheme, which holds the running relaxed instance together with
+/// its witness.
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Debug, Eq, PartialEq)]
+pub struct Accumulator<G>
+where
+    G: AffineCurve,
+{
+    /// Running Instance
+    instance: Instance<G>,
+
+    /// Running Witness
+    witness: Witness<G::ScalarField>,
+}
+
+impl<G> Accumulator<G>
+where
+    G: AffineCurve,
+{
+    /// Builds a new [`Accumulator`] which starts from the zero instance of `parameters`.
+    #[inline]
+    pub fn new(parameters: &Parameters<G>) -> Self {
+        Self {
+            instance: parameters.zero_instance(),
+            witness: Witness {
+                error: vec![Zero::zero(); parameters.circuit.constraint_count()],
+                witness: vec![Zero::zero(); parameters.circuit.witness_count()],
+            },
+        }
+    }
+
+    /// Returns the running instance of `self`.
+    #[inline]
+    pub fn instance(&self) -> &Instance<G> {
+        &self.instance
+    }
+
+    /// Returns the running witness of `self`.
+    #[inline]
+    pub fn witness(&self) -> &Witness<G::ScalarField> {
+        &self.witness
+    }
+
+    /// Returns the running instance and witness of `self`.
+    #[inline]
+    pub fn into_inner(self) -> (Instance<G>, Witness<G::ScalarField>) {
+        (self.instance, self.witness)
+    }
+
+    /// Folds the circuit in `compiler`, which must have been built for proofs, into `self` and
+    /// returns the proof that the verifier needs to fold its public instance. Returns an error if
+    /// the assignment of `compiler` does not satisfy the circuit of `parameters`.
+    #[inline]
+    pub fn fold(
+        &mut self,
+        parameters: &Parameters<G>,
+        compiler: R1CS<G::ScalarField>,
+    ) -> Result<FoldingProof<G>, Error> {
+        let circuit = &parameters.circuit;
+        let (instance, witness) = assignments(&compiler).ok_or(Error)?;
+        if instance.len() != circuit.instance_count() || witness.len() != circuit.witness_count() {
+            return Err(Error);
+        }
+        let input = &instance[1..];
+        let [a_1, b_1, c_1] = parameters.products(&assignment(
+            self.instance.scale,
+            &self.instance.input,
+            &self.witness.witness,
+        ));
+        let [a_2, b_2, c_2] = parameters.products(&assignment(instance[0], input, &witness));
+        if a_2
+            .iter()
+            .zip(&b_2)
+            .zip(&c_2)
+            .any(|((a, b), c)| *a * b != *c)
+        {
+            return Err(Error);
+        }
+        let cross_term = (0..circuit.constraint_count())
+            .map(|i| a_1[i] * b_2[i] + a_2[i] * b_1[i] - self.instance.scale * c_2[i] - c_1[i])
+            .collect::<Vec<_>>();
+        let proof = FoldingProof {
+            witness_commitment: Parameters::commit(&parameters.witness_generators, &witness),
+            cross_term_commitment: Parameters::commit(&parameters.error_generators, &cross_term),
+        };
+        let r = parameters.challenge(&self.instance, input, &proof);
+        self.instance = parameters.fold_with(&self.instance, input, &proof, r);
+        for (error, cross_term) in self.witness.error.iter_mut().zip(&cross_term) {
+            *error += r * cross_term;
+        }
+        for (running, witness) in self.witness.witness.iter_mut().zip(&witness) {
+            *running += r * witness;
+        }
+        Ok(proof)
+    }
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        arkworks::{
+            bn254::{Fr, G1Affine},
+            constraint::fp::Fp,
+            r1cs_std::{eq::EqGadget, fields::fp::FpVar},
+        },
+        eclair::alloc::{
+            mode::{Public, Secret},
+            Allocate, Allocator,
+        },
+    };
+
+    /// Allocates the circuit `a * b == c` where `c` is public, using `values` as the witness and
+    /// the public input if they are known.
+    #[inline]
+    fn product_circuit(values: Option<(u64, u64, u64)>) -> R1CS<Fr> {
+        match values {
+            Some((a, b, c)) => {
+                let mut compiler = R1CS::for_proofs();
+                let lhs = Fp(Fr::from(a)).as_known::<Secret, FpVar<_>>(&mut compiler);
+                let rhs = Fp(Fr::from(b)).as_known::<Secret, FpVar<_>>(&mut compiler);
+                let product = Fp(Fr::from(c)).as_known::<Public, FpVar<_>>(&mut compiler);
+                (lhs * rhs)
+                    .enforce_equal(&product)
+                    .expect("Enforcing equality is not allowed to fail.");
+                compiler
+            }
+            _ => {
+                let mut compiler = R1CS::for_contexts();
+                let lhs = compiler.allocate_unknown::<Secret, FpVar<_>>();
+                let rhs = compiler.allocate_unknown::<Secret, FpVar<_>>();
+                let product = compiler.allocate_unknown::<Public, FpVar<_>>();
+                (lhs * rhs)
+                    .enforce_equal(&product)
+                    .expect("Enforcing equality is not allowed to fail.");
+                compiler
+            }
+        }
+    }
+
+    /// Checks that the prover and the verifier fold the same instance, which the decider accepts,
+    /// and that a tampered folding proof is caught by the decider.
+    #[test]
+    fn folded_instances_are_decided() {
+        let parameters = Parameters::<G1Affine>::new(&product_circuit(None))
+            .expect("Unable to compile circuit.");
+        let mut accumulator = Accumulator::new(&parameters);
+        let mut instance = parameters.zero_instance();
+        for (a, b) in [(3, 5), (7, 11), (13, 17)] {
+            let proof = accumulator
+                .fold(&parameters, product_circuit(Some((a, b, a * b))))
+                .expect("Unable to fold instance.");
+            instance = parameters
+                .fold_instance(&instance, &[Fr::from(a * b)], &proof)
+                .expect("The input has the right length.");
+        }
+        assert_eq!(&instance, accumulator.instance());
+        assert!(parameters.decide(&instance, accumulator.witness()));
+        let proof = accumulator
+            .fold(&parameters, product_circuit(Some((2, 2, 4))))
+            .expect("Unable to fold instance.");
+        let tampered = parameters
+            .fold_instance(&instance, &[Fr::from(5u64)], &proof)
+            .expect("The input has the right length.");
+        assert!(!parameters.decide(&tampered, accumulator.witness()));
+    }
+
+    /// Checks that assignments which do not satisfy the circuit cannot be folded.
+    #[test]
+    fn unsatisfied_instances_are_not_folded() {
+        let parameters = Parameters::<G1Affine>::new(&product_circuit(None))
+            .expect("Unable to compile circuit.");
+        let mut accumulator = Accumulator::new(&parameters);
+        assert_eq!(
+            accumulator.fold(&parameters, product_circuit(Some((3, 5, 16)))),
+            Err(Error)
+        );
+        assert!(parameters.decide(accumulator.instance(), accumulator.witness()));
+    }
+}
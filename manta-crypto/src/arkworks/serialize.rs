@@ -15,14 +15,32 @@
 // along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Arkworks Canonical Serialize and Deserialize Backend
+//!
+//! # Serialization Modes
+//!
+//! Group elements can be encoded in three ways, which trade bandwidth for deserialization time:
+//!
+//! - [`Compressed`]: only one coordinate and a sign bit are stored, which is about half the size
+//!   of the other encodings, but decoding requires a square root and the curve and subgroup
+//!   checks.
+//! - [`Uncompressed`]: both coordinates are stored, so decoding skips the square root but still
+//!   checks that the point is in the prime order subgroup.
+//! - [`Unchecked`]: both coordinates are stored and decoding performs no checks at all. This is
+//!   only safe for data that comes from a trusted source, like locally cached parameters.
+//!
+//! The default serde implementations in this crate use the [`Compressed`] mode. The
+//! [`canonical_serialize_with`] and [`canonical_deserialize_with`] functions and the
+//! [`Canonical`] wrapper select the mode explicitly.
 
+use alloc::vec::Vec;
 use ark_std::io::{self, Error, ErrorKind};
+use core::marker::PhantomData;
 use manta_util::codec::{self, ReadExactError};
 
 #[cfg(feature = "serde")]
-use {
-    alloc::vec::Vec,
-    manta_util::serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer},
+use manta_util::{
+    serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer},
+    BorrowedBytes,
 };
 
 #[doc(inline)]
@@ -175,6 +193,224 @@ where
     }
 }
 
+/// Serialization Mode
+///
+/// See the [module-level documentation](self) for the trade-offs between the modes.
+pub trait SerializationMode {
+    /// Serializes `data` into `writer` using this mode.
+    fn serialize<T, W>(data: &T, writer: W) -> Result<(), SerializationError>
+    where
+        T: CanonicalSerialize + ?Sized,
+        W: Write;
+
+    /// Returns the size in bytes of `data` when serialized using this mode.
+    fn serialized_size<T>(data: &T) -> usize
+    where
+        T: CanonicalSerialize + ?Sized;
+
+    /// Deserializes a value of type `T` from `reader` using this mode.
+    fn deserialize<T, R>(reader: R) -> Result<T, SerializationError>
+    where
+        T: CanonicalDeserialize,
+        R: Read;
+
+    /// Serializes `data` into a new byte vector using this mode.
+    #[inline]
+    fn to_bytes<T>(data: &T) -> Result<Vec<u8>, SerializationError>
+    where
+        T: CanonicalSerialize + ?Sized,
+    {
+        let mut bytes = Vec::with_capacity(Self::serialized_size(data));
+        Self::serialize(data, &mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+/// Compressed Serialization Mode
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Compressed;
+
+impl SerializationMode for Compressed {
+    #[inline]
+    fn serialize<T, W>(data: &T, writer: W) -> Result<(), SerializationError>
+    where
+        T: CanonicalSerialize + ?Sized,
+        W: Write,
+    {
+        data.serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size<T>(data: &T) -> usize
+    where
+        T: CanonicalSerialize + ?Sized,
+    {
+        data.serialized_size()
+    }
+
+    #[inline]
+    fn deserialize<T, R>(reader: R) -> Result<T, SerializationError>
+    where
+        T: CanonicalDeserialize,
+        R: Read,
+    {
+        T::deserialize(reader)
+    }
+}
+
+/// Uncompressed Serialization Mode
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Uncompressed;
+
+impl SerializationMode for Uncompressed {
+    #[inline]
+    fn serialize<T, W>(data: &T, writer: W) -> Result<(), SerializationError>
+    where
+        T: CanonicalSerialize + ?Sized,
+        W: Write,
+    {
+        data.serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn serialized_size<T>(data: &T) -> usize
+    where
+        T: CanonicalSerialize + ?Sized,
+    {
+        data.uncompressed_size()
+    }
+
+    #[inline]
+    fn deserialize<T, R>(reader: R) -> Result<T, SerializationError>
+    where
+        T: CanonicalDeserialize,
+        R: Read,
+    {
+        T::deserialize_uncompressed(reader)
+    }
+}
+
+/// Unchecked Serialization Mode
+///
+/// # Safety
+///
+/// Deserialization in this mode does not check that points are on the curve or in the prime order
+/// subgroup, so it must only be used for trusted data.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Unchecked;
+
+impl SerializationMode for Unchecked {
+    #[inline]
+    fn serialize<T, W>(data: &T, writer: W) -> Result<(), SerializationError>
+    where
+        T: CanonicalSerialize + ?Sized,
+        W: Write,
+    {
+        data.serialize_unchecked(writer)
+    }
+
+    #[inline]
+    fn serialized_size<T>(data: &T) -> usize
+    where
+        T: CanonicalSerialize + ?Sized,
+    {
+        data.uncompressed_size()
+    }
+
+    #[inline]
+    fn deserialize<T, R>(reader: R) -> Result<T, SerializationError>
+    where
+        T: CanonicalDeserialize,
+        R: Read,
+    {
+        T::deserialize_unchecked(reader)
+    }
+}
+
+/// Canonical Serde Wrapper
+///
+/// Implements [`Serialize`] and [`Deserialize`] for `T` by encoding it as bytes with the
+/// [`SerializationMode`] `M`.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "T: CanonicalDeserialize, M: SerializationMode",
+            serialize = "T: CanonicalSerialize, M: SerializationMode"
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "T: Clone"),
+    Copy(bound = "T: Copy"),
+    Debug(bound = "T: core::fmt::Debug"),
+    Default(bound = "T: Default"),
+    Eq(bound = "T: Eq"),
+    Hash(bound = "T: core::hash::Hash"),
+    PartialEq(bound = "T: PartialEq")
+)]
+pub struct Canonical<T, M = Compressed>(
+    /// Wrapped Value
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "canonical_serialize_with::<M, T, _>",
+            deserialize_with = "canonical_deserialize_with::<'de, M, _, T>"
+        )
+    )]
+    pub T,
+    /// Type Parameter Marker
+    #[cfg_attr(feature = "serde", serde(skip))]
+    PhantomData<M>,
+);
+
+impl<T, M> Canonical<T, M> {
+    /// Wraps `value` so that it is serialized with the mode `M`.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Serializes `data` using the [`CanonicalSerialize`] format in the [`SerializationMode`] `M`
+/// with `S` as the [`Serializer`].
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+#[inline]
+pub fn canonical_serialize_with<M, T, S>(data: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    M: SerializationMode,
+    T: CanonicalSerialize,
+    S: Serializer,
+{
+    Serialize::serialize(&M::to_bytes(data).map_err(ser::Error::custom)?, serializer)
+}
+
+/// Deserializes data of type `T` using the [`CanonicalDeserialize`] format in the
+/// [`SerializationMode`] `M` with `D` as the [`Deserializer`].
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+#[inline]
+pub fn canonical_deserialize_with<'de, M, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    M: SerializationMode,
+    D: Deserializer<'de>,
+    T: CanonicalDeserialize,
+{
+    let bytes = BorrowedBytes::deserialize(deserializer)?;
+    M::deserialize(bytes.as_ref()).map_err(de::Error::custom)
+}
+
 /// Serializes `data` using the [`CanonicalSerialize`] format with `S` as the [`Serializer`].
 #[cfg(feature = "serde")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
@@ -214,8 +450,8 @@ where
     D: Deserializer<'de>,
     T: CanonicalDeserialize,
 {
-    let bytes: Vec<u8> = Deserialize::deserialize(deserializer)?;
-    CanonicalDeserialize::deserialize(bytes.as_slice()).map_err(de::Error::custom)
+    let bytes = BorrowedBytes::deserialize(deserializer)?;
+    CanonicalDeserialize::deserialize(bytes.as_ref()).map_err(de::Error::custom)
 }
 
 /// Deserializes data of type `T` using the [`CanonicalDeserialize`] format with `D` as the
@@ -228,6 +464,32 @@ where
     D: Deserializer<'de>,
     T: CanonicalDeserialize,
 {
-    let bytes: Vec<u8> = Deserialize::deserialize(deserializer)?;
-    CanonicalDeserialize::deserialize_unchecked(bytes.as_slice()).map_err(de::Error::custom)
+    let bytes = BorrowedBytes::deserialize(deserializer)?;
+    CanonicalDeserialize::deserialize_unchecked(bytes.as_ref()).map_err(de::Error::custom)
+}
+
+/// Deserializes data of type `T` using the [`CanonicalDeserialize`] format with `D` as the
+/// [`Deserializer`] in uncompressed form.
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+#[inline]
+pub fn canonical_deserialize_uncompressed<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: CanonicalDeserialize,
+{
+    canonical_deserialize_with::<Uncompressed, _, _>(deserializer)
+}
+
+/// Serializes `data` using the [`CanonicalSerialize`] format with `S` as the [`Serializer`] in
+/// the form expected by [`canonical_deserialize_unchecked`].
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+#[inline]
+pub fn canonical_serialize_unchecked<T, S>(data: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: CanonicalSerialize,
+    S: Serializer,
+{
+    canonical_serialize_with::<Unchecked, _, _>(data, serializer)
 }
@@ -0,0 +1,442 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! KZG Polynomial Commitments
+//!
+//! The [KZG] scheme commits to a polynomial `p` of bounded degree with the single group element
+//! `p(τ) G`, where the powers `τ^i G` and `τ H` of a secret `τ` come from a universal setup over
+//! the pairing curve `E`. An opening of `p` at a point `z` is the value `v = p(z)` together with
+//! the commitment to the quotient `(p(X) - v) / (X - z)`, which the verifier checks with a single
+//! pairing equation. Several polynomials are opened at the same point with one group element by
+//! combining them with the powers of a challenge derived from a transcript.
+//!
+//! Polynomials are represented by their coefficients, starting with the constant term.
+//!
+//! [KZG]: https://www.iacr.org/archive/asiacrypt2010/6477178/6477178.pdf
+
+use crate::{
+    arkworks::{
+        ec::{msm::VariableBaseMSM, AffineCurve, PairingEngine, ProjectiveCurve},
+        ff::{One, PrimeField, UniformRand, Zero},
+        serialize::{CanonicalDeserialize, CanonicalSerialize, Read, SerializationError, Write},
+    },
+    rand::{CryptoRng, RngCore},
+    transcript::{Absorb, Blake2sTranscript},
+};
+use alloc::{vec, vec::Vec};
+
+/// Returns the canonical serialization of `value`.
+#[inline]
+fn to_bytes<T>(value: &T) -> Vec<u8>
+where
+    T: CanonicalSerialize,
+{
+    let mut buffer = Vec::new();
+    value
+        .serialize(&mut buffer)
+        .expect("Serialization is not allowed to fail.");
+    buffer
+}
+
+/// Evaluates `polynomial` at `point` and returns the value together with the coefficients of the
+/// quotient of `polynomial` by `X - point`.
+#[inline]
+fn divide<F>(polynomial: &[F], point: F) -> (F, Vec<F>)
+where
+    F: PrimeField,
+{
+    let mut quotient = vec![F::zero(); polynomial.len().saturating_sub(1)];
+    let mut remainder = F::zero();
+    for (i, coefficient) in polynomial.iter().enumerate().rev() {
+        remainder = remainder * point + coefficient;
+        if i > 0 {
+            quotient[i - 1] = remainder;
+        }
+    }
+    (remainder, quotient)
+}
+
+/// Returns the linear combination of `polynomials` with the powers of `challenge`.
+#[inline]
+fn combine<F>(polynomials: &[Vec<F>], challenge: F) -> Vec<F>
+where
+    F: PrimeField,
+{
+    let mut combined = Vec::<F>::new();
+    let mut power = F::one();
+    for polynomial in polynomials {
+        if combined.len() < polynomial.len() {
+            combined.resize(polynomial.len(), F::zero());
+        }
+        for (combined, coefficient) in combined.iter_mut().zip(polynomial) {
+            *combined += power * coefficient;
+        }
+        power *= challenge;
+    }
+    combined
+}
+
+/// Polynomial Commitment
+#[derive(derivative::Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(Clone, Copy, Debug, Eq, Hash(bound = ""), PartialEq)]
+pub struct Commitment<E>(
+    /// Committed Point
+    pub E::G1Affine,
+)
+where
+    E: PairingEngine;
+
+/// Polynomial Opening
+#[derive(derivative::Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Opening<E>
+where
+    E: PairingEngine,
+{
+    /// Value of the Polynomial at the Opening Point
+    pub value: E::Fr,
+
+    /// Commitment to the Quotient Polynomial
+    pub witness: E::G1Affine,
+}
+
+/// Batch Opening of Several Polynomials at the same Point
+#[derive(derivative::Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(Clone, Debug, Eq, PartialEq)]
+pub struct BatchOpening<E>
+where
+    E: PairingEngine,
+{
+    /// Values of the Polynomials at the Opening Point
+    pub values: Vec<E::Fr>,
+
+    /// Commitment to the Quotient of the Combined Polynomial
+    pub witness: E::G1Affine,
+}
+
+/// Commitment Parameters
+#[derive(derivative::Derivative, CanonicalSerialize)]
+#[derivative(Clone, Debug, Eq, PartialEq)]
+pub struct Parameters<E>
+where
+    E: PairingEngine,
+{
+    /// Powers of the Secret in the First Group
+    powers: Vec<E::G1Affine>,
+
+    /// Generator of the Second Group
+    g2: E::G2Affine,
+
+    /// Secret times the Generator of the Second Group
+    tau_g2: E::G2Affine,
+}
+
+impl<E> Parameters<E>
+where
+    E: PairingEngine,
+{
+    /// Batch Opening Transcript Domain
+    pub const DOMAIN: &'static [u8] = b"manta-crypto/kzg/batch";
+
+    /// Builds new [`Parameters`] from the `powers` of the secret in the first group, starting with
+    /// the generator, and the generator `g2` of the second group with its multiple `tau_g2`, like
+    /// the outputs of a powers-of-tau ceremony. Returns `None` if there are no `powers`.
+    #[inline]
+    pub fn new(powers: Vec<E::G1Affine>, g2: E::G2Affine, tau_g2: E::G2Affine) -> Option<Self> {
+        (!powers.is_empty()).then_some(Self { powers, g2, tau_g2 })
+    }
+
+    /// Samples [`Parameters`] for polynomials up to `max_degree` from a fresh secret.
+    ///
+    /// # Security
+    ///
+    /// Anyone who knows the secret can open commitments to arbitrary values. The secret is dropped
+    /// when this function returns, so these parameters are only suitable for testing, and
+    /// production parameters should come from a ceremony through [`new`](Self::new).
+    #[inline]
+    pub fn sample<R>(max_degree: usize, rng: &mut R) -> Self
+    where
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        let tau = E::Fr::rand(rng);
+        let g1 = E::G1Affine::prime_subgroup_generator();
+        let g2 = E::G2Affine::prime_subgroup_generator();
+        let mut power = E::Fr::one();
+        let mut powers = Vec::with_capacity(max_degree + 1);
+        for _ in 0..=max_degree {
+            powers.push(g1.mul(power.into_repr()));
+            power *= tau;
+        }
+        Self {
+            powers: E::G1Projective::batch_normalization_into_affine(&powers),
+            g2,
+            tau_g2: g2.mul(tau.into_repr()).into_affine(),
+        }
+    }
+
+    /// Returns the maximum degree of the polynomials which can be committed to with `self`.
+    #[inline]
+    pub fn max_degree(&self) -> usize {
+        self.powers.len() - 1
+    }
+
+    /// Commits to `polynomial`, returning `None` if its degree is larger than
+    /// [`max_degree`](Self::max_degree).
+    #[inline]
+    pub fn commit(&self, polynomial: &[E::Fr]) -> Option<Commitment<E>> {
+        if polynomial.len() > self.powers.len() {
+            return None;
+        }
+        let scalars = polynomial
+            .iter()
+            .map(PrimeField::into_repr)
+            .collect::<Vec<_>>();
+        Some(Commitment(
+            VariableBaseMSM::multi_scalar_mul(&self.powers[..polynomial.len()], &scalars)
+                .into_affine(),
+        ))
+    }
+
+    /// Opens `polynomial` at `point`, returning `None` if its degree is larger than
+    /// [`max_degree`](Self::max_degree).
+    #[inline]
+    pub fn open(&self, polynomial: &[E::Fr], point: E::Fr) -> Option<Opening<E>> {
+        if polynomial.len() > self.powers.len() {
+            return None;
+        }
+        let (value, quotient) = divide(polynomial, point);
+        Some(Opening {
+            value,
+            witness: self.commit(&quotient)?.0,
+        })
+    }
+
+    /// Verifies that `opening` is a valid opening of `commitment` at `point`.
+    #[inline]
+    pub fn verify(&self, commitment: &Commitment<E>, point: E::Fr, opening: &Opening<E>) -> bool {
+        // NOTE: The quotient `q` satisfies `p(τ) - v = q(τ) (τ - z)`, so we check that
+        //       `e(C - v G + z W, H) = e(W, τ H)`.
+        let lhs = commitment.0.into_projective() - self.powers[0].mul(opening.value.into_repr())
+            + opening.witness.mul(point.into_repr());
+        E::product_of_pairings(&[
+            (lhs.into_affine().into(), self.g2.into()),
+            ((-opening.witness).into(), self.tau_g2.into()),
+        ])
+        .is_one()
+    }
+
+    /// Derives the challenge which combines the polynomials of `commitments` with `values` at
+    /// `point`.
+    #[inline]
+    fn batch_challenge(commitments: &[Commitment<E>], point: E::Fr, values: &[E::Fr]) -> E::Fr {
+        let mut transcript = Blake2sTranscript::new(Self::DOMAIN);
+        transcript.absorb(b"point", to_bytes(&point).as_slice(), &mut ());
+        for (commitment, value) in commitments.iter().zip(values) {
+            transcript.absorb(b"commitment", to_bytes(commitment).as_slice(), &mut ());
+            transcript.absorb(b"value", to_bytes(value).as_slice(), &mut ());
+        }
+        E::Fr::from_le_bytes_mod_order(&transcript.challenge_bytes(b"challenge"))
+    }
+
+    /// Opens all the `polynomials` with the given `commitments` at `point` with a single witness,
+    /// returning `None` if the lengths do not match or if any degree is larger than
+    /// [`max_degree`](Self::max_degree).
+    #[inline]
+    pub fn batch_open(
+        &self,
+        polynomials: &[Vec<E::Fr>],
+        commitments: &[Commitment<E>],
+        point: E::Fr,
+    ) -> Option<BatchOpening<E>> {
+        if polynomials.len() != commitments.len()
+            || polynomials
+                .iter()
+                .any(|polynomial| polynomial.len() > self.powers.len())
+        {
+            return None;
+        }
+        let values = polynomials
+            .iter()
+            .map(|polynomial| divide(polynomial, point).0)
+            .collect::<Vec<_>>();
+        let challenge = Self::batch_challenge(commitments, point, &values);
+        let opening = self.open(&combine(polynomials, challenge), point)?;
+        Some(BatchOpening {
+            values,
+            witness: opening.witness,
+        })
+    }
+
+    /// Verifies that `opening` is a valid batch opening of `commitments` at `point`.
+    #[inline]
+    pub fn batch_verify(
+        &self,
+        commitments: &[Commitment<E>],
+        point: E::Fr,
+        opening: &BatchOpening<E>,
+    ) -> bool {
+        if commitments.len() != opening.values.len() {
+            return false;
+        }
+        let challenge = Self::batch_challenge(commitments, point, &opening.values);
+        let mut power = E::Fr::one();
+        let mut commitment = E::G1Projective::zero();
+        let mut value = E::Fr::zero();
+        for (c, v) in commitments.iter().zip(&opening.values) {
+            commitment += c.0.mul(power.into_repr());
+            value += power * v;
+            power *= challenge;
+        }
+        self.verify(
+            &Commitment(commitment.into_affine()),
+            point,
+            &Opening {
+                value,
+                witness: opening.witness,
+            },
+        )
+    }
+}
+
+impl<E> CanonicalDeserialize for Parameters<E>
+where
+    E: PairingEngine,
+{
+    #[inline]
+    fn deserialize<R>(mut reader: R) -> Result<Self, SerializationError>
+    where
+        R: Read,
+    {
+        Self::new(
+            CanonicalDeserialize::deserialize(&mut reader)?,
+            CanonicalDeserialize::deserialize(&mut reader)?,
+            CanonicalDeserialize::deserialize(&mut reader)?,
+        )
+        .ok_or(SerializationError::InvalidData)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R>(mut reader: R) -> Result<Self, SerializationError>
+    where
+        R: Read,
+    {
+        Self::new(
+            CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+            CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+            CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+        )
+        .ok_or(SerializationError::InvalidData)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R>(mut reader: R) -> Result<Self, SerializationError>
+    where
+        R: Read,
+    {
+        Self::new(
+            CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+            CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+            CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+        )
+        .ok_or(SerializationError::InvalidData)
+    }
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        arkworks::bn254::{Bn254, Fr},
+        rand::OsRng,
+    };
+
+    /// Samples a random polynomial with `length` coefficients.
+    #[inline]
+    fn sample_polynomial(length: usize) -> Vec<Fr> {
+        (0..length).map(|_| Fr::rand(&mut OsRng)).collect()
+    }
+
+    /// Checks that openings verify for the committed value only.
+    #[test]
+    fn open_and_verify() {
+        let parameters = Parameters::<Bn254>::sample(15, &mut OsRng);
+        let polynomial = sample_polynomial(16);
+        let commitment = parameters
+            .commit(&polynomial)
+            .expect("The degree is supported.");
+        let point = Fr::rand(&mut OsRng);
+        let mut opening = parameters
+            .open(&polynomial, point)
+            .expect("The degree is supported.");
+        assert!(parameters.verify(&commitment, point, &opening));
+        assert!(!parameters.verify(&commitment, point + Fr::one(), &opening));
+        opening.value += Fr::one();
+        assert!(!parameters.verify(&commitment, point, &opening));
+        assert_eq!(parameters.commit(&sample_polynomial(17)), None);
+    }
+
+    /// Checks that batch openings verify for the committed values only.
+    #[test]
+    fn batch_open_and_verify() {
+        let parameters = Parameters::<Bn254>::sample(15, &mut OsRng);
+        let polynomials = [3, 16, 9].map(sample_polynomial).to_vec();
+        let commitments = polynomials
+            .iter()
+            .map(|polynomial| {
+                parameters
+                    .commit(polynomial)
+                    .expect("The degree is supported.")
+            })
+            .collect::<Vec<_>>();
+        let point = Fr::rand(&mut OsRng);
+        let mut opening = parameters
+            .batch_open(&polynomials, &commitments, point)
+            .expect("The degrees are supported.");
+        assert!(parameters.batch_verify(&commitments, point, &opening));
+        assert!(!parameters.batch_verify(&commitments[1..], point, &opening));
+        opening.values[2] += Fr::one();
+        assert!(!parameters.batch_verify(&commitments, point, &opening));
+    }
+
+    /// Checks that parameters round-trip through serialization and that parameters without any
+    /// powers are rejected.
+    #[test]
+    fn deserialize_parameters() {
+        let parameters = Parameters::<Bn254>::sample(3, &mut OsRng);
+        assert_eq!(
+            Parameters::deserialize(to_bytes(&parameters).as_slice())
+                .expect("The parameters are valid."),
+            parameters
+        );
+        let empty = Parameters {
+            powers: Vec::new(),
+            ..parameters
+        };
+        assert!(matches!(
+            Parameters::<Bn254>::deserialize(to_bytes(&empty).as_slice()),
+            Err(SerializationError::InvalidData)
+        ));
+        let mut bytes = Vec::new();
+        empty
+            .serialize_unchecked(&mut bytes)
+            .expect("Serialization is not allowed to fail.");
+        assert!(matches!(
+            Parameters::<Bn254>::deserialize_unchecked(bytes.as_slice()),
+            Err(SerializationError::InvalidData)
+        ));
+    }
+}
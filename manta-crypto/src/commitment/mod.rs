@@ -16,6 +16,10 @@
 
 //! Commitment Schemes
 
+#[cfg(feature = "arkworks")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "arkworks")))]
+pub mod kzg;
+
 /// Commitment Scheme
 pub trait CommitmentScheme<COM = ()> {
     /// Randomness Type
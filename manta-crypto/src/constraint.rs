@@ -384,6 +384,193 @@ pub mod measure {
     }
 }
 
+/// Dynamic Proof Systems
+///
+/// The [`ProofSystem`] trait is not object-safe, so applications which need to hold verifiers for
+/// several circuit versions or several proof systems at once would otherwise have to thread all of
+/// them through their generics. The [`DynProofSystem`] trait erases the concrete proof system by
+/// moving inputs and proofs across its boundary as bytes.
+pub mod dynamic {
+    use super::*;
+    use alloc::{boxed::Box, format, string::String, vec::Vec};
+    use core::{any::Any, fmt::Debug};
+    use rand_core::CryptoRngCore;
+
+    /// Proof System Byte Encoding
+    pub trait Encoding: ProofSystem {
+        /// Encodes `input` as a byte vector.
+        fn encode_input(input: &Self::Input) -> Vec<u8>;
+
+        /// Decodes `bytes` into an input, returning `None` if `bytes` is not a valid encoding.
+        fn decode_input(bytes: &[u8]) -> Option<Self::Input>;
+
+        /// Encodes `proof` as a byte vector.
+        fn encode_proof(proof: &Self::Proof) -> Vec<u8>;
+
+        /// Decodes `bytes` into a proof, returning `None` if `bytes` is not a valid encoding.
+        fn decode_proof(bytes: &[u8]) -> Option<Self::Proof>;
+    }
+
+    /// Dynamic Proof System Error
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    pub enum Error {
+        /// Missing Proving Context
+        ///
+        /// The proof system was constructed for verification only.
+        MissingProvingContext,
+
+        /// Unexpected Compiler
+        ///
+        /// The compiler passed to [`DynProofSystem::prove`] is not the compiler of the underlying
+        /// proof system.
+        UnexpectedCompiler,
+
+        /// Invalid Input Encoding
+        InvalidInput,
+
+        /// Invalid Proof Encoding
+        InvalidProof,
+
+        /// Proof System Error
+        ///
+        /// The underlying proof system returned an error, which is recorded here by its [`Debug`]
+        /// representation.
+        ProofSystem(String),
+    }
+
+    /// Object-Safe Proof System
+    pub trait DynProofSystem {
+        /// Generates a proof for the circuit stored in `compiler`, returning its byte encoding.
+        ///
+        /// The `compiler` must be the [`ProofSystem::Compiler`] of the underlying proof system,
+        /// otherwise [`Error::UnexpectedCompiler`] is returned.
+        fn prove(
+            &self,
+            compiler: Box<dyn Any>,
+            rng: &mut dyn CryptoRngCore,
+        ) -> Result<Vec<u8>, Error>;
+
+        /// Verifies that the byte-encoded `proof` is valid for the byte-encoded `input`.
+        fn verify(&self, input: &[u8], proof: &[u8]) -> Result<bool, Error>;
+    }
+
+    impl<T> DynProofSystem for Box<T>
+    where
+        T: DynProofSystem + ?Sized,
+    {
+        #[inline]
+        fn prove(
+            &self,
+            compiler: Box<dyn Any>,
+            rng: &mut dyn CryptoRngCore,
+        ) -> Result<Vec<u8>, Error> {
+            (**self).prove(compiler, rng)
+        }
+
+        #[inline]
+        fn verify(&self, input: &[u8], proof: &[u8]) -> Result<bool, Error> {
+            (**self).verify(input, proof)
+        }
+    }
+
+    /// Type-Erased Proof System
+    ///
+    /// Wraps the contexts of the proof system `P` so that they can be used through the
+    /// [`DynProofSystem`] trait.
+    pub struct Erased<P>
+    where
+        P: ProofSystem,
+    {
+        /// Proving Context
+        proving_context: Option<P::ProvingContext>,
+
+        /// Verifying Context
+        verifying_context: P::VerifyingContext,
+    }
+
+    impl<P> Erased<P>
+    where
+        P: ProofSystem,
+    {
+        /// Builds a new [`Erased`] proof system which can both prove and verify.
+        #[inline]
+        pub fn new(
+            proving_context: P::ProvingContext,
+            verifying_context: P::VerifyingContext,
+        ) -> Self {
+            Self {
+                proving_context: Some(proving_context),
+                verifying_context,
+            }
+        }
+
+        /// Builds a new [`Erased`] proof system which can only verify.
+        #[inline]
+        pub fn verifier(verifying_context: P::VerifyingContext) -> Self {
+            Self {
+                proving_context: None,
+                verifying_context,
+            }
+        }
+
+        /// Returns the proving context of `self` if it has one.
+        #[inline]
+        pub fn proving_context(&self) -> Option<&P::ProvingContext> {
+            self.proving_context.as_ref()
+        }
+
+        /// Returns the verifying context of `self`.
+        #[inline]
+        pub fn verifying_context(&self) -> &P::VerifyingContext {
+            &self.verifying_context
+        }
+
+        /// Moves `self` into a boxed [`DynProofSystem`] trait object.
+        #[inline]
+        pub fn boxed(self) -> Box<dyn DynProofSystem>
+        where
+            P: Encoding + 'static,
+            P::Compiler: 'static,
+            P::Error: Debug,
+        {
+            Box::new(self)
+        }
+    }
+
+    impl<P> DynProofSystem for Erased<P>
+    where
+        P: Encoding,
+        P::Compiler: 'static,
+        P::Error: Debug,
+    {
+        #[inline]
+        fn prove(
+            &self,
+            compiler: Box<dyn Any>,
+            rng: &mut dyn CryptoRngCore,
+        ) -> Result<Vec<u8>, Error> {
+            let context = self
+                .proving_context
+                .as_ref()
+                .ok_or(Error::MissingProvingContext)?;
+            let compiler = compiler
+                .downcast::<P::Compiler>()
+                .map_err(|_| Error::UnexpectedCompiler)?;
+            P::prove(context, *compiler, rng)
+                .map(|proof| P::encode_proof(&proof))
+                .map_err(|err| Error::ProofSystem(format!("{err:?}")))
+        }
+
+        #[inline]
+        fn verify(&self, input: &[u8], proof: &[u8]) -> Result<bool, Error> {
+            let input = P::decode_input(input).ok_or(Error::InvalidInput)?;
+            let proof = P::decode_proof(proof).ok_or(Error::InvalidProof)?;
+            P::verify(&self.verifying_context, &input, &proof)
+                .map_err(|err| Error::ProofSystem(format!("{err:?}")))
+        }
+    }
+}
+
 /// Testing Framework
 #[cfg(feature = "test")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "test")))]
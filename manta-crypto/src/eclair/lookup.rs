@@ -0,0 +1,222 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Lookup Tables
+//!
+//! A lookup asserts that a value is one of the entries of a fixed [`Table`]. Proof systems with a
+//! dedicated lookup argument can check this at a cost which does not depend on the size of the
+//! table, which makes range checks and byte decompositions much cheaper than bit-by-bit
+//! constraints. Compilers expose this through the [`Lookup`] `trait` and advertise whether they
+//! have such an argument with [`Lookup::HAS_LOOKUP_ARGUMENT`], so that circuits can choose the
+//! cheapest strategy for each backend.
+
+use crate::eclair::{
+    alloc::{Allocate, Constant},
+    bool::{Assert, AssertEq, Bool},
+    cmp::PartialEq,
+    num::Zero,
+    ops::{Add, BitOr, Mul},
+};
+use alloc::vec::Vec;
+use core::cmp;
+
+/// Lookup Table
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Table<V> {
+    /// Table Entries
+    entries: Vec<V>,
+}
+
+impl<V> Table<V> {
+    /// Builds a new [`Table`] over `entries`.
+    #[inline]
+    pub fn new(entries: Vec<V>) -> Self {
+        Self { entries }
+    }
+
+    /// Builds the [`Table`] of all the values smaller than `2^bits`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bits` is larger than `16`, since the table would be too large to
+    /// be practical.
+    #[inline]
+    pub fn range(bits: u32) -> Self
+    where
+        V: From<u128>,
+    {
+        assert!(bits <= 16, "Range tables support at most 16 bits.");
+        Self::new((0..(1u128 << bits)).map(V::from).collect())
+    }
+
+    /// Builds the [`Table`] of all the byte values.
+    #[inline]
+    pub fn bytes() -> Self
+    where
+        V: From<u128>,
+    {
+        Self::range(8)
+    }
+
+    /// Returns the entries of `self`.
+    #[inline]
+    pub fn entries(&self) -> &[V] {
+        &self.entries
+    }
+
+    /// Returns the number of entries in `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if `self` has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if `value` is an entry of `self`.
+    #[inline]
+    pub fn contains(&self, value: &V) -> bool
+    where
+        V: cmp::PartialEq,
+    {
+        self.entries.contains(value)
+    }
+}
+
+impl<V> FromIterator<V> for Table<V> {
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+    {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Table Lookup
+///
+/// This `trait` is implemented by compilers which can assert that a value of type `T` is an entry
+/// of a [`Table`] of constants of type `V`. Backends without a dedicated lookup argument can
+/// implement it with [`assert_in_table_by_equality`] or with a cheaper ad-hoc encoding.
+pub trait Lookup<T, V> {
+    /// Lookup Argument Flag
+    ///
+    /// This is `true` whenever lookups cost a constant amount regardless of the size of the table.
+    /// When it is `false`, circuits should prefer bit decompositions for range checks.
+    const HAS_LOOKUP_ARGUMENT: bool = false;
+
+    /// Asserts that `value` is an entry of `table`.
+    fn assert_in_table(&mut self, table: &Table<V>, value: &T);
+}
+
+/// Native lookups are free, so the native compiler reports having a lookup argument to exercise
+/// the same code paths as the backends which do.
+impl<V> Lookup<V, V> for ()
+where
+    V: cmp::PartialEq,
+{
+    const HAS_LOOKUP_ARGUMENT: bool = true;
+
+    #[inline]
+    fn assert_in_table(&mut self, table: &Table<V>, value: &V) {
+        assert!(table.contains(value), "Value is not an entry of the table.")
+    }
+}
+
+/// Asserts that `value` is an entry of `table` by comparing it against every entry.
+///
+/// This is the generic fallback for compilers without a lookup argument, and its cost grows
+/// linearly with the size of `table`.
+#[inline]
+pub fn assert_in_table_by_equality<T, V, COM>(table: &Table<V>, value: &T, compiler: &mut COM)
+where
+    COM: Assert,
+    Bool<COM>: Constant<COM, Type = bool> + BitOr<Bool<COM>, COM, Output = Bool<COM>>,
+    T: PartialEq<T, COM> + Constant<COM, Type = V>,
+{
+    let mut is_entry = false.as_constant::<Bool<COM>>(compiler);
+    for entry in table.entries() {
+        let entry = entry.as_constant::<T>(compiler);
+        let is_equal = value.eq(&entry, compiler);
+        is_entry = is_entry.bitor(is_equal, compiler);
+    }
+    compiler.assert(&is_entry);
+}
+
+/// Asserts that `value` decomposes into `limbs` in little-endian order with respect to `base`,
+/// looking up every limb in `table`.
+///
+/// With `table` equal to [`Table::bytes`] and `base` equal to `256`, this asserts that `value`
+/// fits in `8 * limbs.len()` bits, as long as that is smaller than the modulus of the underlying
+/// field.
+#[inline]
+pub fn assert_decomposition<T, V, COM>(
+    table: &Table<V>,
+    base: &T,
+    limbs: &[T],
+    value: &T,
+    compiler: &mut COM,
+) where
+    COM: Assert + Lookup<T, V>,
+    T: Add<T, COM, Output = T> + Clone + Mul<T, COM, Output = T> + PartialEq<T, COM> + Zero<COM>,
+{
+    let mut composed = T::zero(compiler);
+    for limb in limbs.iter().rev() {
+        compiler.assert_in_table(table, limb);
+        composed = composed
+            .mul(base.clone(), compiler)
+            .add(limb.clone(), compiler);
+    }
+    compiler.assert_eq(&composed, value);
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Checks that native lookups accept exactly the entries of the table.
+    #[test]
+    fn native_lookup() {
+        let table = Table::<u128>::bytes();
+        assert_eq!(table.len(), 256);
+        for value in [0, 17, 255] {
+            ().assert_in_table(&table, &value);
+        }
+        assert!(!table.contains(&256));
+    }
+
+    /// Checks that native byte decompositions recompose to the original value.
+    #[test]
+    fn native_decomposition() {
+        let value = 0x1234_5678u128;
+        let limbs = value.to_le_bytes()[..4]
+            .iter()
+            .map(|byte| *byte as u128)
+            .collect::<Vec<_>>();
+        assert_decomposition(&Table::bytes(), &256, &limbs, &value, &mut ());
+    }
+
+    /// Checks that limbs outside of the table are rejected.
+    #[test]
+    #[should_panic]
+    fn native_decomposition_rejects_large_limbs() {
+        assert_decomposition(&Table::bytes(), &256, &[0x1234, 0], &0x1234, &mut ());
+    }
+}
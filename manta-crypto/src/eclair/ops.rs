@@ -16,7 +16,10 @@
 
 //! Overloadable Operations
 
+use crate::eclair::{bool::Bool, Has};
+use alloc::vec::Vec;
 use core::ops;
+use subtle::{Choice, ConditionallySelectable};
 
 /// Defines a unary operation for compilers based on those defined in [`core::ops`].
 macro_rules! unary_op {
@@ -144,3 +147,144 @@ binary_op_assign!(RemAssign, rem_assign, "Remainder", "%=");
 binary_op_assign!(ShlAssign, shl_assign, "Left Shift", "<<=");
 binary_op_assign!(ShrAssign, shr_assign, "Right Shift", ">>=");
 binary_op_assign!(SubAssign, sub_assign, "Subtraction", "-=");
+
+/// Constant-Time Equality
+///
+/// Unlike [`PartialEq`](crate::eclair::cmp::PartialEq), implementations of this `trait` must take
+/// the same amount of time for every pair of values of the same shape, so that secret values can
+/// be compared without leaking information through timing side channels. Circuit compilers get
+/// this for free, but native implementations must avoid early returns and data-dependent branches.
+pub trait ConstantTimeEq<Rhs = Self, COM = ()>
+where
+    Rhs: ?Sized,
+    COM: Has<bool> + ?Sized,
+{
+    /// Returns `true` if `self` and `rhs` are equal, in constant time.
+    fn ct_eq(&self, rhs: &Rhs, compiler: &mut COM) -> Bool<COM>;
+
+    /// Returns `true` if `self` and `rhs` are not equal, in constant time.
+    #[inline]
+    fn ct_ne(&self, rhs: &Rhs, compiler: &mut COM) -> Bool<COM>
+    where
+        Bool<COM>: Not<COM, Output = Bool<COM>>,
+    {
+        self.ct_eq(rhs, compiler).not(compiler)
+    }
+}
+
+/// Constant-Time Selection
+///
+/// Unlike [`ConditionalSelect`](crate::eclair::bool::ConditionalSelect), native implementations
+/// of this `trait` must not branch on the value of the selection bit.
+pub trait ConstantTimeSelect<COM = ()>: Sized
+where
+    COM: Has<bool> + ?Sized,
+{
+    /// Selects `true_value` when `bit == true` and `false_value` when `bit == false`, in constant
+    /// time.
+    fn ct_select(
+        bit: &Bool<COM>,
+        true_value: &Self,
+        false_value: &Self,
+        compiler: &mut COM,
+    ) -> Self;
+}
+
+/// Implements [`ConstantTimeEq`] and [`ConstantTimeSelect`] for the given `$type` using the
+/// [`subtle`] implementations.
+macro_rules! impl_constant_time {
+    ($($type:tt),* $(,)?) => {
+        $(
+            impl ConstantTimeEq for $type {
+                #[inline]
+                fn ct_eq(&self, rhs: &Self, _: &mut ()) -> bool {
+                    subtle::ConstantTimeEq::ct_eq(self, rhs).into()
+                }
+            }
+
+            impl ConstantTimeSelect for $type {
+                #[inline]
+                fn ct_select(bit: &bool, true_value: &Self, false_value: &Self, _: &mut ()) -> Self {
+                    ConditionallySelectable::conditional_select(
+                        false_value,
+                        true_value,
+                        Choice::from(*bit as u8),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_constant_time!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl ConstantTimeEq for bool {
+    #[inline]
+    fn ct_eq(&self, rhs: &Self, compiler: &mut ()) -> bool {
+        (*self as u8).ct_eq(&(*rhs as u8), compiler)
+    }
+}
+
+impl ConstantTimeSelect for bool {
+    #[inline]
+    fn ct_select(bit: &bool, true_value: &Self, false_value: &Self, compiler: &mut ()) -> Self {
+        u8::ct_select(bit, &(*true_value as u8), &(*false_value as u8), compiler) == 1
+    }
+}
+
+/// Compares `lhs` and `rhs` element-wise in constant time. The lengths of the slices are treated
+/// as public information.
+#[inline]
+fn ct_eq_slice<T, Rhs>(lhs: &[T], rhs: &[Rhs]) -> bool
+where
+    T: ConstantTimeEq<Rhs>,
+{
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let mut are_equal = Choice::from(1);
+    for (lhs, rhs) in lhs.iter().zip(rhs) {
+        are_equal &= Choice::from(lhs.ct_eq(rhs, &mut ()) as u8);
+    }
+    are_equal.into()
+}
+
+impl<T, Rhs> ConstantTimeEq<[Rhs]> for [T]
+where
+    T: ConstantTimeEq<Rhs>,
+{
+    #[inline]
+    fn ct_eq(&self, rhs: &[Rhs], _: &mut ()) -> bool {
+        ct_eq_slice(self, rhs)
+    }
+}
+
+impl<T, Rhs, const N: usize> ConstantTimeEq<[Rhs; N]> for [T; N]
+where
+    T: ConstantTimeEq<Rhs>,
+{
+    #[inline]
+    fn ct_eq(&self, rhs: &[Rhs; N], _: &mut ()) -> bool {
+        ct_eq_slice(self, rhs)
+    }
+}
+
+impl<T, const N: usize> ConstantTimeSelect for [T; N]
+where
+    T: ConstantTimeSelect,
+{
+    #[inline]
+    fn ct_select(bit: &bool, true_value: &Self, false_value: &Self, compiler: &mut ()) -> Self {
+        core::array::from_fn(|i| T::ct_select(bit, &true_value[i], &false_value[i], compiler))
+    }
+}
+
+impl<T, Rhs> ConstantTimeEq<Vec<Rhs>> for Vec<T>
+where
+    T: ConstantTimeEq<Rhs>,
+{
+    #[inline]
+    fn ct_eq(&self, rhs: &Vec<Rhs>, _: &mut ()) -> bool {
+        ct_eq_slice(self, rhs)
+    }
+}
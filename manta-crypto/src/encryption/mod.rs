@@ -153,6 +153,152 @@ where
     }
 }
 
+/// Supported Header
+///
+/// Headers which can report whether ciphertexts built under them use a format that is known to
+/// the local decryption logic.
+pub trait SupportedHeader {
+    /// Returns `true` if ciphertexts encrypted under `self` can be decrypted.
+    fn is_supported(&self) -> bool;
+}
+
+impl<COM> SupportedHeader for EmptyHeader<COM> {
+    #[inline]
+    fn is_supported(&self) -> bool {
+        true
+    }
+}
+
+/// Version Header
+///
+/// Header carrying the version of the ciphertext format, where `LATEST` is the newest format
+/// known to this build. New ciphertexts are always built at `LATEST`, which is the [`Default`]
+/// version, while any version up to and including `LATEST` is considered
+/// [`supported`](SupportedHeader::is_supported) so that historical ciphertexts can still be
+/// decrypted. The version is not part of the public input and is allocated as a constant
+/// in-circuit.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(deserialize = "", serialize = ""),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VersionHeader<const LATEST: u8, COM = ()> {
+    /// Ciphertext Format Version
+    version: u8,
+
+    /// Type Parameter Marker
+    #[cfg_attr(feature = "serde", serde(skip))]
+    __: PhantomData<COM>,
+}
+
+impl<const LATEST: u8, COM> VersionHeader<LATEST, COM> {
+    /// Latest Ciphertext Format Version
+    pub const LATEST: u8 = LATEST;
+
+    /// Builds a new [`VersionHeader`] for the ciphertext format `version`.
+    ///
+    /// # Note
+    ///
+    /// The `version` is not checked against `LATEST` since headers of unknown versions can still
+    /// be received from other parties. Use [`is_supported`](SupportedHeader::is_supported) before
+    /// decrypting.
+    #[inline]
+    pub fn new(version: u8) -> Self {
+        Self {
+            version,
+            __: PhantomData,
+        }
+    }
+
+    /// Returns the ciphertext format version of `self`.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl<const LATEST: u8, COM> Default for VersionHeader<LATEST, COM> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(LATEST)
+    }
+}
+
+impl<const LATEST: u8, COM> SupportedHeader for VersionHeader<LATEST, COM> {
+    #[inline]
+    fn is_supported(&self) -> bool {
+        self.version <= LATEST
+    }
+}
+
+impl<const LATEST: u8, COM> Constant<COM> for VersionHeader<LATEST, COM> {
+    type Type = VersionHeader<LATEST>;
+
+    #[inline]
+    fn new_constant(this: &Self::Type, compiler: &mut COM) -> Self {
+        let _ = compiler;
+        Self::new(this.version)
+    }
+}
+
+impl<const LATEST: u8, M, COM> Variable<M, COM> for VersionHeader<LATEST, COM> {
+    type Type = VersionHeader<LATEST>;
+
+    #[inline]
+    fn new_unknown(compiler: &mut COM) -> Self {
+        let _ = compiler;
+        Self::default()
+    }
+
+    #[inline]
+    fn new_known(this: &Self::Type, compiler: &mut COM) -> Self {
+        let _ = compiler;
+        Self::new(this.version)
+    }
+}
+
+impl<const LATEST: u8, COM> eclair::cmp::PartialEq<Self, COM> for VersionHeader<LATEST, COM>
+where
+    COM: Has<bool>,
+    Bool<COM>: Constant<COM, Type = bool>,
+{
+    #[inline]
+    fn eq(&self, rhs: &Self, compiler: &mut COM) -> Bool<COM> {
+        Bool::<COM>::new_constant(&(self.version == rhs.version), compiler)
+    }
+
+    #[inline]
+    fn ne(&self, rhs: &Self, compiler: &mut COM) -> Bool<COM> {
+        Bool::<COM>::new_constant(&(self.version != rhs.version), compiler)
+    }
+}
+
+impl<const LATEST: u8> Encode for VersionHeader<LATEST> {
+    #[inline]
+    fn encode<W>(&self, writer: W) -> Result<(), W::Error>
+    where
+        W: Write,
+    {
+        self.version.encode(writer)
+    }
+}
+
+impl<const LATEST: u8, P> Input<P> for VersionHeader<LATEST>
+where
+    P: ProofSystem + ?Sized,
+{
+    #[inline]
+    fn extend(&self, input: &mut P::Input) {
+        let _ = input;
+    }
+}
+
 /// Unsafe Empty Output
 ///
 /// For protocols that need some unconstrained behavior in-circuit, we can use this
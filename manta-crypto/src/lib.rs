@@ -36,6 +36,7 @@ pub mod password;
 pub mod permutation;
 pub mod rand;
 pub mod signature;
+pub mod transcript;
 
 #[cfg(feature = "arkworks")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "arkworks")))]
@@ -44,3 +45,7 @@ pub mod arkworks;
 #[cfg(feature = "dalek")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "dalek")))]
 pub mod dalek;
+
+#[cfg(feature = "secp256k1")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "secp256k1")))]
+pub mod secp256k1;
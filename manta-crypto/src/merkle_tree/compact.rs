@@ -0,0 +1,532 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Compact Merkle Path Encodings
+//!
+//! The [`Path`] type stores every sibling digest on the way from a leaf to the root, even though
+//! many of them are default digests in sparsely filled trees, and a collection of paths in the
+//! same tree repeats the siblings close to the root. The compact encodings in this module replace
+//! the default siblings by a bit mask and, for multiple paths, elide the siblings which are shared
+//! with the previous path. The directions of each path are the bits of its leaf index, so they are
+//! never stored separately.
+
+use crate::{
+    accumulator::{MembershipProof, Types},
+    merkle_tree::{path_length, Configuration, InnerDigest, LeafDigest, Node, Path, Root},
+};
+use alloc::vec::Vec;
+use core::{fmt::Debug, hash::Hash};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Compact Path Error
+///
+/// This `enum` is the error state of decoding a [`CompactPath`], [`CompactMultiPath`], or one of
+/// their membership proof counterparts.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CompactPathError {
+    /// Leaf index does not fit in a tree of the configured height
+    IndexTooLarge,
+
+    /// Default mask marks siblings above the top of the tree
+    InvalidMask,
+
+    /// Encoding ran out of sibling digests
+    MissingDigest,
+
+    /// Encoding contained more sibling digests than the path requires
+    ExtraDigest,
+
+    /// Leaf indices of a multi-path are not strictly increasing
+    UnsortedLeaves,
+
+    /// Multi-path contained no paths
+    Empty,
+}
+
+/// Returns the number of inner siblings of a path, asserting that a default mask can hold them.
+#[inline]
+fn inner_path_length<C>() -> usize
+where
+    C: Configuration + ?Sized,
+{
+    let length = path_length::<C, _>();
+    assert!(
+        length <= u64::BITS as usize,
+        "Compact paths only support trees with at most 64 inner levels."
+    );
+    length
+}
+
+/// Returns the number of inner siblings shared between the paths of `previous` and `next`.
+///
+/// The siblings of two leaves agree at every level above the one where their ancestors meet.
+#[inline]
+fn shared_levels<C>(previous: Node, next: Node) -> usize
+where
+    C: Configuration + ?Sized,
+{
+    let split = (usize::BITS - 1 - (previous.0 ^ next.0).leading_zeros()) as usize;
+    inner_path_length::<C>().saturating_sub(split)
+}
+
+/// Compact Merkle Tree Path
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "LeafDigest<C>: Deserialize<'de>, InnerDigest<C>: Deserialize<'de>",
+            serialize = "LeafDigest<C>: Serialize, InnerDigest<C>: Serialize"
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "LeafDigest<C>: Clone, InnerDigest<C>: Clone"),
+    Debug(bound = "LeafDigest<C>: Debug, InnerDigest<C>: Debug"),
+    Eq(bound = "LeafDigest<C>: Eq, InnerDigest<C>: Eq"),
+    Hash(bound = "LeafDigest<C>: Hash, InnerDigest<C>: Hash"),
+    PartialEq(bound = "LeafDigest<C>: PartialEq, InnerDigest<C>: PartialEq")
+)]
+pub struct CompactPath<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Leaf Index
+    leaf_index: Node,
+
+    /// Sibling Digest
+    ///
+    /// The sibling digest is omitted when it is the default digest.
+    sibling_digest: Option<LeafDigest<C>>,
+
+    /// Default Mask
+    ///
+    /// The `i`-th bit is set whenever the `i`-th inner sibling is the default digest, in which
+    /// case it is omitted from `digests`.
+    default_mask: u64,
+
+    /// Non-Default Inner Digests
+    ///
+    /// Inner digests are stored from leaf to root, skipping the default digests.
+    digests: Vec<InnerDigest<C>>,
+}
+
+impl<C> CompactPath<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Builds a [`CompactPath`] from the first `levels`-many inner siblings of `path`.
+    #[inline]
+    fn encode(path: &Path<C>, levels: usize) -> Self
+    where
+        LeafDigest<C>: Clone + Default + PartialEq,
+        InnerDigest<C>: Clone + Default + PartialEq,
+    {
+        let default_leaf = LeafDigest::<C>::default();
+        let default_inner = InnerDigest::<C>::default();
+        let mut default_mask = 0;
+        let mut digests = Vec::new();
+        for (i, digest) in path.inner_path.path.iter().take(levels).enumerate() {
+            if *digest == default_inner {
+                default_mask |= 1 << i;
+            } else {
+                digests.push(digest.clone());
+            }
+        }
+        Self {
+            leaf_index: path.leaf_index(),
+            sibling_digest: (path.sibling_digest != default_leaf)
+                .then(|| path.sibling_digest.clone()),
+            default_mask,
+            digests,
+        }
+    }
+
+    /// Decodes the first `levels`-many inner siblings of `self`, placing the remaining ones from
+    /// `upper` on top of them.
+    #[inline]
+    fn decode(self, levels: usize, upper: &[InnerDigest<C>]) -> Result<Path<C>, CompactPathError>
+    where
+        LeafDigest<C>: Default,
+        InnerDigest<C>: Clone + Default,
+    {
+        let length = inner_path_length::<C>();
+        if self
+            .leaf_index
+            .0
+            .checked_shr(length as u32 + 1)
+            .unwrap_or(0)
+            != 0
+        {
+            return Err(CompactPathError::IndexTooLarge);
+        }
+        if self.default_mask.checked_shr(levels as u32).unwrap_or(0) != 0 {
+            return Err(CompactPathError::InvalidMask);
+        }
+        let mut digests = self.digests.into_iter();
+        let mut path = Vec::with_capacity(length);
+        for i in 0..levels {
+            if self.default_mask & (1 << i) != 0 {
+                path.push(Default::default());
+            } else {
+                path.push(digests.next().ok_or(CompactPathError::MissingDigest)?);
+            }
+        }
+        if digests.next().is_some() {
+            return Err(CompactPathError::ExtraDigest);
+        }
+        path.extend_from_slice(upper);
+        Ok(Path::new(
+            self.sibling_digest.unwrap_or_default(),
+            self.leaf_index,
+            path,
+        ))
+    }
+
+    /// Builds a [`CompactPath`] from `path`.
+    #[inline]
+    pub fn from_path(path: &Path<C>) -> Self
+    where
+        LeafDigest<C>: Clone + Default + PartialEq,
+        InnerDigest<C>: Clone + Default + PartialEq,
+    {
+        Self::encode(path, inner_path_length::<C>())
+    }
+
+    /// Decodes `self` back into a [`Path`].
+    #[inline]
+    pub fn into_path(self) -> Result<Path<C>, CompactPathError>
+    where
+        LeafDigest<C>: Default,
+        InnerDigest<C>: Clone + Default,
+    {
+        self.decode(inner_path_length::<C>(), &[])
+    }
+
+    /// Returns the leaf index of `self`.
+    #[inline]
+    pub fn leaf_index(&self) -> Node {
+        self.leaf_index
+    }
+
+    /// Returns the number of inner digests stored in `self`.
+    #[inline]
+    pub fn digest_count(&self) -> usize {
+        self.digests.len()
+    }
+}
+
+impl<C> From<&Path<C>> for CompactPath<C>
+where
+    C: Configuration + ?Sized,
+    LeafDigest<C>: Clone + Default + PartialEq,
+    InnerDigest<C>: Clone + Default + PartialEq,
+{
+    #[inline]
+    fn from(path: &Path<C>) -> Self {
+        Self::from_path(path)
+    }
+}
+
+impl<C> TryFrom<CompactPath<C>> for Path<C>
+where
+    C: Configuration + ?Sized,
+    LeafDigest<C>: Default,
+    InnerDigest<C>: Clone + Default,
+{
+    type Error = CompactPathError;
+
+    #[inline]
+    fn try_from(path: CompactPath<C>) -> Result<Self, Self::Error> {
+        path.into_path()
+    }
+}
+
+/// Compact Merkle Tree Multi-Path
+///
+/// Collection of paths in the same tree, sorted by leaf index, where each path only stores the
+/// inner siblings which are not shared with the previous path.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "LeafDigest<C>: Deserialize<'de>, InnerDigest<C>: Deserialize<'de>",
+            serialize = "LeafDigest<C>: Serialize, InnerDigest<C>: Serialize"
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "LeafDigest<C>: Clone, InnerDigest<C>: Clone"),
+    Debug(bound = "LeafDigest<C>: Debug, InnerDigest<C>: Debug"),
+    Eq(bound = "LeafDigest<C>: Eq, InnerDigest<C>: Eq"),
+    Hash(bound = "LeafDigest<C>: Hash, InnerDigest<C>: Hash"),
+    PartialEq(bound = "LeafDigest<C>: PartialEq, InnerDigest<C>: PartialEq")
+)]
+pub struct CompactMultiPath<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Compact Paths
+    paths: Vec<CompactPath<C>>,
+}
+
+impl<C> CompactMultiPath<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Builds a [`CompactMultiPath`] from `paths`, which must all belong to the same tree.
+    ///
+    /// The paths are sorted by leaf index and paths for the same leaf are only stored once, so
+    /// [`into_paths`](Self::into_paths) returns them in that order.
+    #[inline]
+    pub fn from_paths<'p, I>(paths: I) -> Self
+    where
+        C: 'p,
+        I: IntoIterator<Item = &'p Path<C>>,
+        LeafDigest<C>: Clone + Default + PartialEq,
+        InnerDigest<C>: Clone + Default + PartialEq,
+    {
+        let mut paths = paths.into_iter().collect::<Vec<_>>();
+        paths.sort_by_key(|path| path.leaf_index().0);
+        paths.dedup_by_key(|path| path.leaf_index());
+        let length = inner_path_length::<C>();
+        let mut previous = None;
+        Self {
+            paths: paths
+                .into_iter()
+                .map(|path| {
+                    let leaf_index = path.leaf_index();
+                    let shared = previous.map_or(0, |p| shared_levels::<C>(p, leaf_index));
+                    previous = Some(leaf_index);
+                    CompactPath::encode(path, length - shared)
+                })
+                .collect(),
+        }
+    }
+
+    /// Decodes `self` back into the [`Path`]s it was built from, sorted by leaf index.
+    #[inline]
+    pub fn into_paths(self) -> Result<Vec<Path<C>>, CompactPathError>
+    where
+        LeafDigest<C>: Default,
+        InnerDigest<C>: Clone + Default,
+    {
+        let length = inner_path_length::<C>();
+        let mut result = Vec::<Path<C>>::with_capacity(self.paths.len());
+        for path in self.paths {
+            let leaf_index = path.leaf_index;
+            let decoded = match result.last() {
+                Some(previous) => {
+                    if previous.leaf_index().0 >= leaf_index.0 {
+                        return Err(CompactPathError::UnsortedLeaves);
+                    }
+                    let levels = length - shared_levels::<C>(previous.leaf_index(), leaf_index);
+                    let upper = previous.inner_path.path[levels..].to_vec();
+                    path.decode(levels, &upper)?
+                }
+                _ => path.decode(length, &[])?,
+            };
+            result.push(decoded);
+        }
+        Ok(result)
+    }
+
+    /// Returns the number of paths stored in `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Returns `true` if `self` stores no paths.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Returns the number of inner digests stored in `self`.
+    #[inline]
+    pub fn digest_count(&self) -> usize {
+        self.paths.iter().map(CompactPath::digest_count).sum()
+    }
+}
+
+/// Compact Merkle Tree Membership Proof
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "LeafDigest<C>: Deserialize<'de>, InnerDigest<C>: Deserialize<'de>",
+            serialize = "LeafDigest<C>: Serialize, InnerDigest<C>: Serialize"
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "LeafDigest<C>: Clone, InnerDigest<C>: Clone"),
+    Debug(bound = "LeafDigest<C>: Debug, InnerDigest<C>: Debug"),
+    Eq(bound = "LeafDigest<C>: Eq, InnerDigest<C>: Eq"),
+    Hash(bound = "LeafDigest<C>: Hash, InnerDigest<C>: Hash"),
+    PartialEq(bound = "LeafDigest<C>: PartialEq, InnerDigest<C>: PartialEq")
+)]
+pub struct CompactMembershipProof<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Compact Path
+    path: CompactPath<C>,
+
+    /// Root
+    output: Root<C>,
+}
+
+impl<C> CompactMembershipProof<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Builds a [`CompactMembershipProof`] from a merkle tree or forest membership `proof`.
+    #[inline]
+    pub fn from_proof<M>(proof: &MembershipProof<M>) -> Self
+    where
+        M: Types<Witness = Path<C>, Output = Root<C>> + ?Sized,
+        LeafDigest<C>: Clone + Default + PartialEq,
+        InnerDigest<C>: Clone + Default + PartialEq,
+    {
+        Self {
+            path: CompactPath::from_path(proof.witness()),
+            output: proof.output().clone(),
+        }
+    }
+
+    /// Decodes `self` back into a [`MembershipProof`].
+    #[inline]
+    pub fn into_proof<M>(self) -> Result<MembershipProof<M>, CompactPathError>
+    where
+        M: Types<Witness = Path<C>, Output = Root<C>> + ?Sized,
+        LeafDigest<C>: Default,
+        InnerDigest<C>: Clone + Default,
+    {
+        Ok(MembershipProof::new(self.path.into_path()?, self.output))
+    }
+}
+
+/// Compact Merkle Tree Multi-Proof
+///
+/// Collection of membership proofs against the same root, stored as a [`CompactMultiPath`].
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "LeafDigest<C>: Deserialize<'de>, InnerDigest<C>: Deserialize<'de>",
+            serialize = "LeafDigest<C>: Serialize, InnerDigest<C>: Serialize"
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "LeafDigest<C>: Clone, InnerDigest<C>: Clone"),
+    Debug(bound = "LeafDigest<C>: Debug, InnerDigest<C>: Debug"),
+    Eq(bound = "LeafDigest<C>: Eq, InnerDigest<C>: Eq"),
+    Hash(bound = "LeafDigest<C>: Hash, InnerDigest<C>: Hash"),
+    PartialEq(bound = "LeafDigest<C>: PartialEq, InnerDigest<C>: PartialEq")
+)]
+pub struct CompactMultiProof<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Compact Paths
+    paths: CompactMultiPath<C>,
+
+    /// Root
+    output: Root<C>,
+}
+
+impl<C> CompactMultiProof<C>
+where
+    C: Configuration + ?Sized,
+{
+    /// Builds a [`CompactMultiProof`] from membership `proofs`, returning `None` if there are no
+    /// proofs or if they do not all have the same root.
+    #[inline]
+    pub fn from_proofs<'p, M, I>(proofs: I) -> Option<Self>
+    where
+        M: 'p + Types<Witness = Path<C>, Output = Root<C>> + ?Sized,
+        I: IntoIterator<Item = &'p MembershipProof<M>>,
+        LeafDigest<C>: Clone + Default + PartialEq,
+        InnerDigest<C>: Clone + Default + PartialEq,
+    {
+        let proofs = proofs.into_iter().collect::<Vec<_>>();
+        let output = proofs.first()?.output();
+        if proofs.iter().any(|proof| proof.output() != output) {
+            return None;
+        }
+        Some(Self {
+            paths: CompactMultiPath::from_paths(proofs.iter().map(|proof| proof.witness())),
+            output: output.clone(),
+        })
+    }
+
+    /// Decodes `self` back into [`MembershipProof`]s, sorted by leaf index.
+    #[inline]
+    pub fn into_proofs<M>(self) -> Result<Vec<MembershipProof<M>>, CompactPathError>
+    where
+        M: Types<Witness = Path<C>, Output = Root<C>> + ?Sized,
+        LeafDigest<C>: Default,
+        InnerDigest<C>: Clone + Default,
+    {
+        if self.paths.is_empty() {
+            return Err(CompactPathError::Empty);
+        }
+        let output = self.output;
+        Ok(self
+            .paths
+            .into_paths()?
+            .into_iter()
+            .map(|path| MembershipProof::new(path, output.clone()))
+            .collect())
+    }
+
+    /// Returns the root which all the proofs in `self` share.
+    #[inline]
+    pub fn output(&self) -> &Root<C> {
+        &self.output
+    }
+
+    /// Returns the compact paths of `self`.
+    #[inline]
+    pub fn paths(&self) -> &CompactMultiPath<C> {
+        &self.paths
+    }
+}
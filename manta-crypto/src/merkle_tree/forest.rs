@@ -24,8 +24,8 @@
 
 use crate::{
     accumulator::{
-        self, Accumulator, BatchInsertion, ConstantCapacityAccumulator, ExactSizeAccumulator,
-        FromItemsAndWitnesses, MembershipProof, OptimizedAccumulator,
+        self, Accumulator, BatchInsertion, CheckpointAccumulator, ConstantCapacityAccumulator,
+        ExactSizeAccumulator, FromItemsAndWitnesses, MembershipProof, OptimizedAccumulator,
     },
     merkle_tree::{
         fork::ForkedTree,
@@ -77,9 +77,17 @@ pub trait FixedIndex<const N: usize>: Into<usize> {
     fn from_index(index: usize) -> Self;
 }
 
-impl FixedIndex<256> for u8 {
+/// Any forest width up to `256` can be indexed by a [`u8`], as long as the configuration only
+/// produces indices in `0..N`. Using a larger width fails to compile.
+impl<const N: usize> FixedIndex<N> for u8 {
     #[inline]
     fn from_index(index: usize) -> Self {
+        const {
+            assert!(
+                N <= 256,
+                "A `u8` can only index forests of width up to 256."
+            );
+        }
         index as Self
     }
 }
@@ -811,3 +819,40 @@ where
         }
     }
 }
+
+impl<C, T, M, L, const N: usize> CheckpointAccumulator
+    for MerkleForest<C, TreeArray<C, ForkedTree<C, T, M, L>, N>>
+where
+    C: Configuration + ?Sized,
+    C::Index: FixedIndex<N>,
+    T: Clone + Tree<C> + WithProofs<C>,
+    M: Default + InnerMap<C>,
+    L: Default + LeafMap<C>,
+    LeafDigest<C>: Clone + Default + PartialEq,
+    InnerDigest<C>: Clone + Default + PartialEq,
+    Parameters<C>: Clone,
+{
+    /// The base trees of the forest, which do not include the leaves which were not committed.
+    type Checkpoint = Vec<T>;
+
+    #[inline]
+    fn checkpoint(&self) -> Self::Checkpoint {
+        self.forest
+            .as_ref()
+            .iter()
+            .map(|tree| tree.base().clone())
+            .collect()
+    }
+
+    #[inline]
+    fn rollback_to(&mut self, checkpoint: &Self::Checkpoint) {
+        assert_eq!(
+            checkpoint.len(),
+            N,
+            "The checkpoint must have been taken from a forest of the same width."
+        );
+        for (tree, base) in self.forest.as_mut().iter_mut().zip(checkpoint) {
+            tree.reset_base(&self.parameters, base.clone());
+        }
+    }
+}
@@ -1007,6 +1007,24 @@ where
             .batch_maybe_push_digest(parameters, leaf_digests)
     }
 
+    /// Returns a shared reference to the base tree, which does not include the leaves of the
+    /// fork.
+    #[inline]
+    pub fn base(&self) -> &T {
+        &self.base
+    }
+
+    /// Replaces the base tree with `base` and resets the fork on top of it.
+    #[inline]
+    pub fn reset_base(&mut self, parameters: &Parameters<C>, base: T)
+    where
+        LeafDigest<C>: Clone + Default,
+        InnerDigest<C>: Default,
+    {
+        self.base = base;
+        self.reset_fork(parameters);
+    }
+
     /// Resets the fork of the base tree back to the trunk.
     #[inline]
     pub fn reset_fork(&mut self, parameters: &Parameters<C>)
@@ -0,0 +1,238 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Frontier Merkle Tree Storage
+//!
+//! A ledger only needs to append leaves and to check that membership proofs were built against a
+//! recent root, so it does not need to keep the whole tree in its state. The [`Frontier`] stores
+//! the rightmost path of the tree, from which the next root can be computed, and a bounded history
+//! of the most recent roots.
+
+use crate::{
+    accumulator::RootHistory,
+    merkle_tree::{
+        single_path::SinglePath, Configuration, CurrentPath, InnerDigest, LeafDigest, MerkleTree,
+        Parameters, Root, Tree,
+    },
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use core::{fmt::Debug, hash::Hash};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Default Number of Roots kept in the History of a [`Frontier`]
+pub const DEFAULT_ROOT_HISTORY: usize = 64;
+
+/// Frontier Merkle Tree Type
+pub type FrontierMerkleTree<C, const HISTORY: usize = DEFAULT_ROOT_HISTORY> =
+    MerkleTree<C, Frontier<C, HISTORY>>;
+
+/// Frontier Merkle Tree Backing Structure
+///
+/// Keeps the rightmost path of the tree and the last `HISTORY` roots, oldest first. A root is
+/// recorded after every call to [`maybe_push_digest`](Tree::maybe_push_digest) and after every
+/// call to [`batch_maybe_push_digest`](Tree::batch_maybe_push_digest) which inserts at least one
+/// leaf, so a batch of leaves only records the root of the tree after the whole batch.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "LeafDigest<C>: Deserialize<'de>, InnerDigest<C>: Deserialize<'de>",
+            serialize = "LeafDigest<C>: Serialize, InnerDigest<C>: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "LeafDigest<C>: Clone, InnerDigest<C>: Clone"),
+    Debug(bound = "LeafDigest<C>: Debug, InnerDigest<C>: Debug"),
+    Default(bound = "LeafDigest<C>: Default, InnerDigest<C>: Default"),
+    Eq(bound = "LeafDigest<C>: Eq, InnerDigest<C>: Eq"),
+    Hash(bound = "LeafDigest<C>: Hash, InnerDigest<C>: Hash"),
+    PartialEq(bound = "LeafDigest<C>: PartialEq, InnerDigest<C>: PartialEq")
+)]
+pub struct Frontier<C, const HISTORY: usize>
+where
+    C: Configuration + ?Sized,
+{
+    /// Rightmost Path
+    path: SinglePath<C>,
+
+    /// Root History
+    roots: VecDeque<Root<C>>,
+}
+
+impl<C, const HISTORY: usize> Frontier<C, HISTORY>
+where
+    C: Configuration + ?Sized,
+{
+    /// Returns the current merkle tree root.
+    #[inline]
+    pub fn root(&self) -> &Root<C> {
+        self.path.root()
+    }
+
+    /// Returns the current merkle tree path for the current leaf.
+    #[inline]
+    pub fn current_path(&self) -> &CurrentPath<C> {
+        self.path.current_path()
+    }
+
+    /// Returns the currently stored leaf digest, returning `None` if the tree is empty.
+    #[inline]
+    pub fn leaf_digest(&self) -> Option<&LeafDigest<C>> {
+        self.path.leaf_digest()
+    }
+
+    /// Returns an iterator over the recorded roots, from the oldest to the most recent one.
+    #[inline]
+    pub fn roots(&self) -> impl DoubleEndedIterator<Item = &Root<C>> + ExactSizeIterator {
+        self.roots.iter()
+    }
+
+    /// Returns the `count` most recent roots, from the oldest to the most recent one.
+    #[inline]
+    pub fn recent_roots(&self, count: usize) -> Vec<Root<C>>
+    where
+        Root<C>: Clone,
+    {
+        self.roots
+            .iter()
+            .skip(self.roots.len().saturating_sub(count))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns `true` if `root` is one of the recorded roots.
+    #[inline]
+    pub fn contains_root(&self, root: &Root<C>) -> bool
+    where
+        Root<C>: PartialEq,
+    {
+        self.roots.iter().any(|recorded| recorded == root)
+    }
+
+    /// Records the current root, evicting the oldest root if the history is full.
+    #[inline]
+    fn record_root(&mut self)
+    where
+        Root<C>: Clone,
+    {
+        if HISTORY == 0 {
+            return;
+        }
+        if self.roots.len() == HISTORY {
+            self.roots.pop_front();
+        }
+        self.roots.push_back(self.path.root().clone());
+    }
+}
+
+impl<C, const HISTORY: usize> Tree<C> for Frontier<C, HISTORY>
+where
+    C: Configuration + ?Sized,
+    LeafDigest<C>: Clone + Default,
+    InnerDigest<C>: Clone + Default,
+{
+    #[inline]
+    fn new(parameters: &Parameters<C>) -> Self {
+        Self {
+            path: SinglePath::new(parameters),
+            roots: VecDeque::with_capacity(HISTORY),
+        }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        Tree::len(&self.path)
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        Tree::is_empty(&self.path)
+    }
+
+    #[inline]
+    fn current_leaf(&self) -> Option<&LeafDigest<C>> {
+        Tree::current_leaf(&self.path)
+    }
+
+    #[inline]
+    fn root(&self) -> &Root<C> {
+        self.root()
+    }
+
+    #[inline]
+    fn current_path(&self, parameters: &Parameters<C>) -> CurrentPath<C> {
+        Tree::current_path(&self.path, parameters)
+    }
+
+    #[inline]
+    fn maybe_push_digest<F>(&mut self, parameters: &Parameters<C>, leaf_digest: F) -> Option<bool>
+    where
+        F: FnOnce() -> Option<LeafDigest<C>>,
+    {
+        let result = self.path.maybe_push_digest(parameters, leaf_digest);
+        if result == Some(true) {
+            self.record_root();
+        }
+        result
+    }
+
+    #[inline]
+    fn batch_maybe_push_digest<F>(
+        &mut self,
+        parameters: &Parameters<C>,
+        leaf_digests: F,
+    ) -> Option<bool>
+    where
+        F: FnOnce() -> Vec<LeafDigest<C>>,
+    {
+        let len = Tree::len(&self.path);
+        let result = self.path.batch_maybe_push_digest(parameters, leaf_digests);
+        if Tree::len(&self.path) != len {
+            self.record_root();
+        }
+        result
+    }
+}
+
+impl<C, const HISTORY: usize> RootHistory<Root<C>> for Frontier<C, HISTORY>
+where
+    C: Configuration + ?Sized,
+    Root<C>: PartialEq,
+{
+    #[inline]
+    fn is_recent_root(&self, root: &Root<C>) -> bool {
+        self.contains_root(root)
+    }
+}
+
+impl<C, const HISTORY: usize> RootHistory<Root<C>> for FrontierMerkleTree<C, HISTORY>
+where
+    C: Configuration + ?Sized,
+    LeafDigest<C>: Clone + Default,
+    InnerDigest<C>: Clone + Default + PartialEq,
+{
+    #[inline]
+    fn is_recent_root(&self, root: &Root<C>) -> bool {
+        self.tree.contains_root(root)
+    }
+}
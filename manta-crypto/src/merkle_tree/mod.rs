@@ -26,14 +26,17 @@
 mod node;
 mod tree;
 
+pub mod compact;
 pub mod forest;
 pub mod fork;
+pub mod frontier;
 pub mod full;
 pub mod inner_tree;
 pub mod leaf_map;
 pub mod partial;
 pub mod path;
 pub mod single_path;
+pub mod storage;
 
 #[cfg(feature = "test")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "test")))]
@@ -0,0 +1,571 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage-Backed Merkle Forests
+//!
+//! The [`StoredTreeArray`] forest keeps each of its trees in a [`TreeStore`] and only holds a
+//! bounded number of them in memory at any given time, evicting the least recently used trees back
+//! to the store whenever the forest is mutated. This allows wallets which track very large ledgers
+//! to use a merkle forest without keeping the entire forest resident in memory.
+
+use crate::{
+    accumulator::{BatchInsertion, FromItemsAndWitnesses},
+    merkle_tree::{
+        forest::{capacity, Configuration, ConstantWidthForest, FixedIndex, Forest, MerkleForest},
+        fork::ForkedTree,
+        inner_tree::InnerMap,
+        leaf_map::LeafMap,
+        partial::Partial,
+        tree::{Leaf, Parameters, Tree},
+        InnerDigest, LeafDigest, WithProofs,
+    },
+};
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    vec,
+    vec::Vec,
+};
+use core::{
+    cell::{OnceCell, RefCell},
+    convert::Infallible,
+    fmt::Debug,
+    marker::PhantomData,
+};
+use manta_util::persistence::Rollback;
+
+/// Tree Store
+///
+/// Key-value store for the trees of a [`StoredTreeArray`], keyed by their index in the forest.
+pub trait TreeStore<T> {
+    /// Error Type
+    type Error;
+
+    /// Loads the tree stored at `index`, returning `None` if no tree has been stored there yet.
+    fn load(&self, index: usize) -> Result<Option<T>, Self::Error>;
+
+    /// Stores `tree` at `index`, replacing any tree which was previously stored there.
+    fn store(&mut self, index: usize, tree: &T) -> Result<(), Self::Error>;
+}
+
+impl<S, T> TreeStore<T> for &mut S
+where
+    S: TreeStore<T>,
+{
+    type Error = S::Error;
+
+    #[inline]
+    fn load(&self, index: usize) -> Result<Option<T>, Self::Error> {
+        (**self).load(index)
+    }
+
+    #[inline]
+    fn store(&mut self, index: usize, tree: &T) -> Result<(), Self::Error> {
+        (**self).store(index, tree)
+    }
+}
+
+/// In-Memory [`TreeStore`]
+///
+/// This store keeps every tree in memory and is mostly useful for testing. Persistent stores
+/// should encode the trees into an on-disk key-value database instead.
+impl<T> TreeStore<T> for BTreeMap<usize, T>
+where
+    T: Clone,
+{
+    type Error = Infallible;
+
+    #[inline]
+    fn load(&self, index: usize) -> Result<Option<T>, Self::Error> {
+        Ok(self.get(&index).cloned())
+    }
+
+    #[inline]
+    fn store(&mut self, index: usize, tree: &T) -> Result<(), Self::Error> {
+        self.insert(index, tree.clone());
+        Ok(())
+    }
+}
+
+/// Default Number of Resident Trees in a [`StoredTreeArray`]
+pub const DEFAULT_CACHE_CAPACITY: usize = 16;
+
+/// Stored Tree Array Merkle Forest Alias
+pub type StoredTreeArrayMerkleForest<C, T, S, const N: usize> =
+    MerkleForest<C, StoredTreeArray<C, T, S, N>>;
+
+/// Stored Tree Array
+///
+/// Merkle forest of `N` trees which are kept in a [`TreeStore`] and loaded into memory on demand.
+///
+/// # Caching
+///
+/// At most `cache_capacity` trees are kept in memory after every mutation of the forest, evicting
+/// the least recently used ones back to the store. Since [`Forest::get`] only has shared access to
+/// the forest, trees loaded through it stay resident until the next mutation.
+///
+/// # Panics
+///
+/// The [`Forest`] interface is infallible, so the [`Forest`] methods of this type panic if the
+/// underlying store fails to load or store a tree. Use [`flush`](Self::flush) to write the
+/// resident trees back to the store with error handling.
+#[derive(derivative::Derivative)]
+#[derivative(Debug(bound = "T: Debug, S: Debug"))]
+pub struct StoredTreeArray<C, T, S, const N: usize>
+where
+    C: Configuration + ?Sized,
+    C::Index: FixedIndex<N>,
+    T: Tree<C>,
+    S: TreeStore<T>,
+{
+    /// Tree Store
+    store: S,
+
+    /// Empty Tree
+    ///
+    /// Trees which have never been written to the store start out as copies of this tree.
+    empty: T,
+
+    /// Resident Trees
+    resident: Vec<OnceCell<T>>,
+
+    /// Stored Tree Lengths
+    ///
+    /// Number of leaves in each tree as of the last time it was written to the store.
+    lengths: Vec<usize>,
+
+    /// Dirty Flags
+    ///
+    /// Marks the resident trees which have been modified since they were last written to the
+    /// store.
+    dirty: Vec<bool>,
+
+    /// Modified Flags
+    ///
+    /// Marks the trees which have been modified since the last [`Rollback`] operation.
+    modified: Vec<bool>,
+
+    /// Resident Trees in Least-Recently-Used Order
+    recent: RefCell<VecDeque<usize>>,
+
+    /// Maximum Number of Resident Trees
+    cache_capacity: usize,
+
+    /// Type Parameter Marker
+    __: PhantomData<C>,
+}
+
+impl<C, T, S, const N: usize> StoredTreeArray<C, T, S, N>
+where
+    C: Configuration + ?Sized,
+    C::Index: FixedIndex<N>,
+    T: Clone + Tree<C>,
+    S: TreeStore<T>,
+    S::Error: Debug,
+{
+    /// Builds a new [`StoredTreeArray`] over an empty `store` which keeps at most
+    /// `cache_capacity`-many trees in memory.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `cache_capacity` is zero.
+    #[inline]
+    pub fn new(parameters: &Parameters<C>, store: S, cache_capacity: usize) -> Self {
+        Self::with_lengths(parameters, store, cache_capacity, vec![0; N])
+    }
+
+    /// Opens a [`StoredTreeArray`] over a `store` which may already contain trees, keeping at most
+    /// `cache_capacity`-many trees in memory.
+    ///
+    /// # Implementation Note
+    ///
+    /// Every tree in the store is loaded once, one at a time, to recover the length of the forest.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `cache_capacity` is zero.
+    #[inline]
+    pub fn open(
+        parameters: &Parameters<C>,
+        store: S,
+        cache_capacity: usize,
+    ) -> Result<Self, S::Error> {
+        let mut lengths = Vec::with_capacity(N);
+        for index in 0..N {
+            lengths.push(store.load(index)?.as_ref().map_or(0, T::len));
+        }
+        Ok(Self::with_lengths(
+            parameters,
+            store,
+            cache_capacity,
+            lengths,
+        ))
+    }
+
+    /// Builds a new [`StoredTreeArray`] from its `store` and the `lengths` of the stored trees.
+    #[inline]
+    fn with_lengths(
+        parameters: &Parameters<C>,
+        store: S,
+        cache_capacity: usize,
+        lengths: Vec<usize>,
+    ) -> Self {
+        assert!(
+            cache_capacity > 0,
+            "The forest must be able to keep at least one tree in memory."
+        );
+        Self {
+            store,
+            empty: T::new(parameters),
+            resident: (0..N).map(|_| OnceCell::new()).collect(),
+            lengths,
+            dirty: vec![false; N],
+            modified: vec![false; N],
+            recent: Default::default(),
+            cache_capacity,
+            __: PhantomData,
+        }
+    }
+
+    /// Returns a shared reference to the underlying tree store.
+    ///
+    /// Trees which are resident and dirty are not reflected in the store until they are evicted
+    /// or [`flush`](Self::flush) is called.
+    #[inline]
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Returns the maximum number of trees kept in memory after each mutation.
+    #[inline]
+    pub fn cache_capacity(&self) -> usize {
+        self.cache_capacity
+    }
+
+    /// Sets the maximum number of trees kept in memory, evicting trees if there are now too many
+    /// of them resident.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `cache_capacity` is zero.
+    #[inline]
+    pub fn set_cache_capacity(&mut self, cache_capacity: usize) {
+        assert!(
+            cache_capacity > 0,
+            "The forest must be able to keep at least one tree in memory."
+        );
+        self.cache_capacity = cache_capacity;
+        self.evict(None);
+    }
+
+    /// Returns the number of trees which are currently held in memory.
+    #[inline]
+    pub fn resident_count(&self) -> usize {
+        self.recent.borrow().len()
+    }
+
+    /// Returns `true` if the tree at `index` is currently held in memory.
+    #[inline]
+    pub fn is_resident(&self, index: usize) -> bool {
+        self.resident[index].get().is_some()
+    }
+
+    /// Returns the number of leaves in each tree of the forest.
+    #[inline]
+    pub fn tree_lengths(&self) -> Vec<usize> {
+        (0..N).map(|index| self.tree_len(index)).collect()
+    }
+
+    /// Returns the number of leaves in the tree at `index`.
+    #[inline]
+    fn tree_len(&self, index: usize) -> usize {
+        self.resident[index]
+            .get()
+            .map_or(self.lengths[index], T::len)
+    }
+
+    /// Writes every dirty resident tree back to the store without evicting it.
+    #[inline]
+    pub fn flush(&mut self) -> Result<(), S::Error> {
+        for index in 0..N {
+            if self.dirty[index] {
+                if let Some(tree) = self.resident[index].get() {
+                    self.store.store(index, tree)?;
+                    self.lengths[index] = tree.len();
+                }
+                self.dirty[index] = false;
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves `index` to the most-recently-used end of the eviction queue.
+    #[inline]
+    fn touch(&self, index: usize) {
+        let mut recent = self.recent.borrow_mut();
+        if let Some(position) = recent.iter().position(|i| *i == index) {
+            recent.remove(position);
+        }
+        recent.push_back(index);
+    }
+
+    /// Returns a shared reference to the tree at `index`, loading it from the store if it is not
+    /// already resident.
+    #[inline]
+    fn load(&self, index: usize) -> &T {
+        let cell = &self.resident[index];
+        if cell.get().is_none() {
+            let tree = self
+                .store
+                .load(index)
+                .expect("Unable to load tree from the store.")
+                .unwrap_or_else(|| self.empty.clone());
+            let _ = cell.set(tree);
+        }
+        self.touch(index);
+        cell.get().expect("The tree was loaded above.")
+    }
+
+    /// Evicts the least recently used trees until at most `cache_capacity`-many trees are
+    /// resident, never evicting `keep`.
+    #[inline]
+    fn evict(&mut self, keep: Option<usize>) {
+        let mut recent = self.recent.take();
+        let mut kept = VecDeque::new();
+        while recent.len() + kept.len() > self.cache_capacity {
+            let index = match recent.pop_front() {
+                Some(index) => index,
+                _ => break,
+            };
+            if Some(index) == keep {
+                kept.push_back(index);
+                continue;
+            }
+            if let Some(tree) = self.resident[index].take() {
+                if self.dirty[index] {
+                    self.store
+                        .store(index, &tree)
+                        .expect("Unable to write tree back to the store.");
+                    self.dirty[index] = false;
+                }
+                self.lengths[index] = tree.len();
+            }
+        }
+        kept.extend(recent);
+        *self.recent.get_mut() = kept;
+    }
+
+    /// Returns a mutable reference to the tree at `index`, loading it from the store and evicting
+    /// other trees if necessary.
+    #[inline]
+    fn load_mut(&mut self, index: usize) -> &mut T {
+        self.load(index);
+        self.evict(Some(index));
+        self.dirty[index] = true;
+        self.modified[index] = true;
+        self.resident[index]
+            .get_mut()
+            .expect("The tree was loaded above and cannot have been evicted.")
+    }
+
+    /// Calls `f` on every tree which was modified since the last call to this method.
+    #[inline]
+    fn for_each_modified<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for index in 0..N {
+            if self.modified[index] {
+                f(self.load_mut(index));
+                self.modified[index] = false;
+            }
+        }
+    }
+}
+
+impl<C, T, S, const N: usize> Forest<C> for StoredTreeArray<C, T, S, N>
+where
+    C: Configuration + ?Sized,
+    C::Index: FixedIndex<N>,
+    T: Clone + Tree<C>,
+    S: Default + TreeStore<T>,
+    S::Error: Debug,
+{
+    type Tree = T;
+
+    #[inline]
+    fn new(parameters: &Parameters<C>) -> Self {
+        Self::new(parameters, Default::default(), DEFAULT_CACHE_CAPACITY)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        (0..N).map(|index| self.tree_len(index)).sum()
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        capacity::<C, Self>()
+    }
+
+    #[inline]
+    fn get(&self, index: C::Index) -> &Self::Tree {
+        self.load(index.into())
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: C::Index) -> &mut Self::Tree {
+        self.load_mut(index.into())
+    }
+
+    #[inline]
+    fn prune(&mut self) {
+        for index in 0..N {
+            self.load_mut(index).prune();
+        }
+    }
+}
+
+impl<C, T, S, const N: usize> ConstantWidthForest<C> for StoredTreeArray<C, T, S, N>
+where
+    C: Configuration + ?Sized,
+    C::Index: FixedIndex<N>,
+    T: Clone + Tree<C>,
+    S: Default + TreeStore<T>,
+    S::Error: Debug,
+{
+    const WIDTH: usize = N;
+}
+
+impl<C, M, L, S, const N: usize> Rollback
+    for StoredTreeArrayMerkleForest<C, ForkedTree<C, Partial<C, M, L>, M, L>, S, N>
+where
+    C: Configuration + ?Sized,
+    C::Index: FixedIndex<N>,
+    M: Clone + Default + InnerMap<C>,
+    L: Clone + Default + LeafMap<C>,
+    S: Default + TreeStore<ForkedTree<C, Partial<C, M, L>, M, L>>,
+    S::Error: Debug,
+    LeafDigest<C>: Clone + Default + PartialEq,
+    InnerDigest<C>: Clone + Default + PartialEq,
+{
+    #[inline]
+    fn rollback(&mut self) {
+        let parameters = &self.parameters;
+        self.forest
+            .for_each_modified(|tree| tree.reset_fork(parameters));
+    }
+
+    #[inline]
+    fn commit(&mut self) {
+        let parameters = &self.parameters;
+        self.forest
+            .for_each_modified(|tree| tree.merge_fork_partial(parameters));
+    }
+}
+
+impl<C, S, const N: usize> FromItemsAndWitnesses
+    for StoredTreeArrayMerkleForest<C, ForkedTree<C, Partial<C>>, S, N>
+where
+    C: Configuration + ?Sized,
+    C::Index: FixedIndex<N>,
+    S: Default + TreeStore<ForkedTree<C, Partial<C>>>,
+    S::Error: Debug,
+    Parameters<C>: Clone,
+    LeafDigest<C>: Clone + Default + PartialEq,
+    InnerDigest<C>: Clone + Default + PartialEq,
+{
+    const NUMBER_OF_SUBACCUMULATORS: usize = N;
+
+    #[inline]
+    fn from_items_and_witnesses(
+        model: &Self::Model,
+        items: Vec<Vec<Self::Item>>,
+        witnesses: Vec<Self::Witness>,
+    ) -> Self {
+        assert_eq!(witnesses.len(), N);
+        let mut forest = StoredTreeArray::new(model, S::default(), DEFAULT_CACHE_CAPACITY);
+        for (index, (leaves, path)) in items.into_iter().zip(witnesses).enumerate() {
+            *forest.load_mut(index) = ForkedTree::from_leaves_and_path_unchecked(
+                model,
+                leaves.iter().map(|leaf| model.digest(leaf)).collect(),
+                path,
+            );
+        }
+        Self::from_forest(forest, model.clone())
+    }
+
+    #[inline]
+    fn sort_items(items: Vec<Self::Item>) -> Vec<Vec<Self::Item>> {
+        let mut result = Vec::<Vec<Self::Item>>::default();
+        result.resize_with(N, Default::default);
+        for item in items {
+            let tree_index = C::tree_index(&item).into();
+            result[tree_index].push(item);
+        }
+        result
+    }
+}
+
+impl<C, S, const N: usize> BatchInsertion
+    for StoredTreeArrayMerkleForest<C, ForkedTree<C, Partial<C>>, S, N>
+where
+    C: Configuration + ?Sized,
+    C::Index: FixedIndex<N>,
+    S: Default + TreeStore<ForkedTree<C, Partial<C>>>,
+    S::Error: Debug,
+    Parameters<C>: Clone,
+    Leaf<C>: Clone,
+    LeafDigest<C>: Clone + Default + PartialEq,
+    InnerDigest<C>: Clone + Default + PartialEq,
+{
+    #[inline]
+    fn batch_insert<'a, I>(&mut self, items: I) -> bool
+    where
+        Self::Item: 'a,
+        I: IntoIterator<Item = &'a Self::Item>,
+    {
+        let mut result = true;
+        for (index, group) in Self::sort_items(items.into_iter().cloned().collect())
+            .into_iter()
+            .enumerate()
+        {
+            if !group.is_empty() {
+                let tree = self.forest.load_mut(index);
+                result &= tree.batch_push_provable(&self.parameters, &group);
+            }
+        }
+        result
+    }
+
+    #[inline]
+    fn batch_insert_nonprovable<'a, I>(&mut self, items: I) -> bool
+    where
+        Self::Item: 'a,
+        I: IntoIterator<Item = &'a Self::Item>,
+    {
+        let mut result = true;
+        for (index, group) in Self::sort_items(items.into_iter().cloned().collect())
+            .into_iter()
+            .enumerate()
+        {
+            if !group.is_empty() {
+                let tree = self.forest.load_mut(index);
+                result &= tree.batch_push(&self.parameters, &group);
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Accumulator Checkpoint Tests
+
+use crate::{
+    accumulator::{Accumulator, CheckpointAccumulator},
+    merkle_tree::{forest, fork, partial, test::Test, tree::Parameters},
+    rand::{OsRng, Rand, Sample},
+};
+use manta_util::persistence::Rollback;
+
+/// Merkle Tree Height
+const HEIGHT: usize = 9;
+
+/// Merkle Tree Configuration
+type Config = Test<u64, HEIGHT>;
+
+/// Forked Merkle Tree
+type ForkedTree = fork::ForkedTree<Config, partial::Partial<Config>>;
+
+/// Merkle Forest Type
+type Forest = forest::TreeArrayMerkleForest<Config, ForkedTree, 2>;
+
+/// Tests that rolling a forest back to a checkpoint discards both the committed and uncommitted
+/// insertions made after it, and that the checkpoint can be reused.
+#[test]
+fn forest_rollback_to_checkpoint() {
+    let mut rng = OsRng;
+    let parameters = Parameters::<Config>::sample(Default::default(), &mut rng);
+    let mut forest = Forest::new(parameters);
+    let leaves = (0..1 << (HEIGHT - 2))
+        .map(|_| rng.gen())
+        .collect::<Vec<_>>();
+    let (before, after) = leaves.split_at(leaves.len() / 2);
+    for leaf in before {
+        assert!(forest.insert(leaf));
+    }
+    forest.commit();
+    let checkpoint = forest.checkpoint();
+    let snapshot = forest.clone();
+    for leaf in after {
+        assert!(forest.insert(leaf));
+    }
+    forest.commit();
+    assert!(forest.insert(&rng.gen()));
+    forest.rollback_to(&checkpoint);
+    assert_eq!(
+        forest, snapshot,
+        "Rolling back must restore the checkpoint."
+    );
+    for leaf in before {
+        assert!(forest.prove(leaf).is_some());
+    }
+    for leaf in after {
+        assert!(forest.prove(leaf).is_none());
+    }
+    for leaf in after {
+        assert!(forest.insert(leaf));
+    }
+    forest.commit();
+    forest.rollback_to(&checkpoint);
+    assert_eq!(forest, snapshot, "Checkpoints must be reusable.");
+}
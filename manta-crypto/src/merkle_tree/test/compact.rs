@@ -0,0 +1,125 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Compact Path Encoding Tests
+
+use crate::{
+    accumulator::{Accumulator, MembershipProof},
+    merkle_tree::{
+        compact::{
+            CompactMembershipProof, CompactMultiPath, CompactMultiProof, CompactPath,
+            CompactPathError,
+        },
+        full::FullMerkleTree,
+        test::Test,
+        tree::Parameters,
+        Path, WithProofs,
+    },
+    rand::{OsRng, Rand, Sample},
+};
+
+/// Merkle Tree Height
+const HEIGHT: usize = 9;
+
+/// Merkle Tree Configuration
+type Config = Test<u64, HEIGHT>;
+
+/// Tests that compact paths and proofs decode to the paths and proofs they were built from, and
+/// that multi-paths store fewer digests than the paths they contain.
+#[test]
+fn compact_paths_round_trip() {
+    let mut rng = OsRng;
+    let parameters = Parameters::<Config>::sample(Default::default(), &mut rng);
+    let mut tree = FullMerkleTree::<Config>::new(parameters);
+    let number_of_insertions = rng.gen_range(8..(1 << (HEIGHT - 2)));
+    let leaves = (0..number_of_insertions)
+        .map(|_| rng.gen())
+        .collect::<Vec<u64>>();
+    for leaf in &leaves {
+        assert!(tree.insert(leaf));
+    }
+    let paths = (0..number_of_insertions)
+        .map(|index| {
+            tree.tree
+                .path(&parameters, index)
+                .expect("Every inserted leaf must have a path.")
+        })
+        .collect::<Vec<_>>();
+    for path in &paths {
+        let compact = CompactPath::from_path(path);
+        assert!(compact.digest_count() <= path.inner_path.path.len());
+        assert_eq!(&compact.into_path().expect("Decoding must succeed."), path);
+    }
+    let subset = paths
+        .iter()
+        .rev()
+        .step_by(3)
+        .cloned()
+        .collect::<Vec<Path<Config>>>();
+    let multi_path = CompactMultiPath::from_paths(&subset);
+    assert!(
+        multi_path.digest_count() < subset.iter().map(|p| p.inner_path.path.len()).sum(),
+        "Multi-paths must elide the shared siblings."
+    );
+    let mut sorted = subset.clone();
+    sorted.sort_by_key(|path| path.leaf_index().0);
+    assert_eq!(
+        multi_path.into_paths().expect("Decoding must succeed."),
+        sorted
+    );
+    let proofs = leaves
+        .iter()
+        .map(|leaf| {
+            tree.prove(leaf)
+                .expect("Every inserted leaf must be provable.")
+        })
+        .collect::<Vec<_>>();
+    for (leaf, proof) in leaves.iter().zip(&proofs) {
+        let decoded: MembershipProof<_> = CompactMembershipProof::from_proof(proof)
+            .into_proof()
+            .expect("Decoding must succeed.");
+        assert!(decoded.verify(&parameters, leaf, &mut ()));
+    }
+    let multi_proof =
+        CompactMultiProof::from_proofs(&proofs).expect("All proofs share the current root.");
+    let decoded: Vec<MembershipProof<Parameters<Config>>> =
+        multi_proof.into_proofs().expect("Decoding must succeed.");
+    for (leaf, proof) in leaves.iter().zip(&decoded) {
+        assert!(proof.verify(&parameters, leaf, &mut ()));
+    }
+}
+
+/// Tests that compact paths for leaves outside of the tree are rejected.
+#[test]
+fn compact_paths_reject_out_of_range_leaves() {
+    let mut rng = OsRng;
+    let parameters = Parameters::<Config>::sample(Default::default(), &mut rng);
+    let mut tree = FullMerkleTree::<Config>::new(parameters);
+    assert!(tree.insert(&rng.gen()));
+    let mut path = tree
+        .tree
+        .path(&parameters, 0)
+        .expect("Every inserted leaf must have a path.");
+    path.inner_path.leaf_index.0 = 1 << (HEIGHT - 1);
+    assert_eq!(
+        CompactPath::from_path(&path).into_path(),
+        Err(CompactPathError::IndexTooLarge)
+    );
+    assert_eq!(
+        CompactMultiPath::from_paths([&path]).into_paths(),
+        Err(CompactPathError::IndexTooLarge)
+    );
+}
@@ -0,0 +1,84 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Frontier Merkle Tree Tests
+
+use crate::{
+    merkle_tree::{
+        frontier::FrontierMerkleTree,
+        full::FullMerkleTree,
+        test::Test,
+        tree::{Parameters, Tree},
+    },
+    rand::{OsRng, Rand, Sample},
+};
+
+/// Merkle Tree Height
+const HEIGHT: usize = 7;
+
+/// Root History Length
+const HISTORY: usize = 8;
+
+/// Merkle Tree Configuration
+type Config = Test<u64, HEIGHT>;
+
+/// Tests that the [`Frontier`](crate::merkle_tree::frontier::Frontier) computes the same roots as
+/// a full tree and only keeps the most recent ones.
+#[test]
+fn frontier_matches_full_tree() {
+    let mut rng = OsRng;
+    let parameters = Parameters::<Config>::sample(Default::default(), &mut rng);
+    let mut full = FullMerkleTree::<Config>::new(parameters);
+    let mut frontier = FrontierMerkleTree::<Config, HISTORY>::new(parameters);
+    let mut roots = Vec::new();
+    for _ in 0..rng.gen_range(HISTORY + 1..(1 << (HEIGHT - 1))) {
+        let leaf = rng.gen();
+        assert!(full.push(&leaf));
+        assert!(frontier.push(&leaf));
+        assert_eq!(full.root(), frontier.root(), "Roots must be equal.");
+        roots.push(*full.root());
+    }
+    let recent = &roots[roots.len() - HISTORY..];
+    assert!(frontier.tree.roots().eq(recent.iter()));
+    assert_eq!(frontier.tree.recent_roots(2), recent[HISTORY - 2..]);
+    assert!(recent.iter().all(|root| frontier.tree.contains_root(root)));
+    assert!(!frontier
+        .tree
+        .contains_root(&roots[roots.len() - HISTORY - 1]));
+}
+
+/// Tests that pushing a batch of leaves into a
+/// [`Frontier`](crate::merkle_tree::frontier::Frontier) only records the root after the batch and
+/// that a full frontier rejects new leaves.
+#[test]
+fn frontier_batch_and_capacity() {
+    let mut rng = OsRng;
+    let parameters = Parameters::<Config>::sample(Default::default(), &mut rng);
+    let mut full = FullMerkleTree::<Config>::new(parameters);
+    let mut frontier = FrontierMerkleTree::<Config, HISTORY>::new(parameters);
+    let leaves = (0..1 << (HEIGHT - 1))
+        .map(|_| rng.gen())
+        .collect::<Vec<_>>();
+    let (first, second) = leaves.split_at(leaves.len() / 2);
+    for batch in [first, second] {
+        assert!(full.batch_push(batch));
+        assert!(frontier.tree.batch_push(&parameters, batch));
+        assert_eq!(full.root(), frontier.root(), "Roots must be equal.");
+    }
+    assert_eq!(frontier.tree.roots().len(), 2);
+    assert!(!frontier.push(&rng.gen()), "The tree must be full.");
+    assert_eq!(frontier.tree.roots().len(), 2);
+}
@@ -30,12 +30,24 @@ use core::{fmt::Debug, hash::Hash, marker::PhantomData};
 #[cfg(test)]
 pub mod batch_insertion;
 
+#[cfg(test)]
+pub mod checkpoint;
+
+#[cfg(test)]
+pub mod compact;
+
+#[cfg(test)]
+pub mod frontier;
+
 #[cfg(test)]
 pub mod partial;
 
 #[cfg(test)]
 pub mod pruning;
 
+#[cfg(test)]
+pub mod storage;
+
 /// Hash Parameter Sampling
 pub trait HashParameterSampling: HashConfiguration {
     /// Leaf Hash Parameter Distribution
@@ -0,0 +1,124 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage-Backed Forest Tests
+
+use crate::{
+    accumulator::{Accumulator, BatchInsertion, OptimizedAccumulator},
+    merkle_tree::{
+        forest::{self, FixedIndex, Forest},
+        fork, partial,
+        storage::{StoredTreeArray, StoredTreeArrayMerkleForest},
+        test::{BinaryIndex, Test},
+        tree::Parameters,
+    },
+    rand::{OsRng, Rand, Sample},
+};
+use alloc::collections::BTreeMap;
+use manta_util::persistence::Rollback;
+
+/// Merkle Tree Height
+const HEIGHT: usize = 7;
+
+/// Merkle Tree Configuration
+type Config = Test<u64, HEIGHT>;
+
+/// Forked Merkle Tree
+type ForkedTree = fork::ForkedTree<Config, partial::Partial<Config>>;
+
+/// Tree Store
+type Store = BTreeMap<usize, ForkedTree>;
+
+/// Resident Merkle Forest Type
+type ResidentForest = forest::TreeArrayMerkleForest<Config, ForkedTree, 2>;
+
+/// Stored Merkle Forest Type
+type StoredForest = StoredTreeArrayMerkleForest<Config, ForkedTree, Store, 2>;
+
+/// Asserts that every tree in `stored` has the same root and length as in `resident`.
+#[inline]
+fn assert_same_trees(stored: &StoredForest, resident: &ResidentForest) {
+    for index in 0..2 {
+        let index = BinaryIndex::from_index(index);
+        assert_eq!(
+            stored.forest.get(index).root(),
+            resident.forest.get(index).root(),
+            "The stored and resident trees must have the same root."
+        );
+        assert_eq!(
+            stored.forest.get(index).len(),
+            resident.forest.get(index).len()
+        );
+    }
+}
+
+/// Tests that a stored forest which can only keep one tree in memory behaves like a fully
+/// resident forest, and that it can be reopened from its store.
+#[test]
+fn stored_forest_matches_resident_forest() {
+    let mut rng = OsRng;
+    let parameters = Parameters::<Config>::sample(Default::default(), &mut rng);
+    let mut resident = ResidentForest::new(parameters);
+    let mut stored = StoredForest::from_forest(
+        StoredTreeArray::new(&parameters, Store::new(), 1),
+        parameters,
+    );
+    let leaves = (0..1 << (HEIGHT - 2))
+        .map(|_| rng.gen())
+        .collect::<Vec<u64>>();
+    let (provable, nonprovable) = leaves.split_at(leaves.len() / 2);
+    for leaf in provable {
+        assert!(resident.insert(leaf));
+        assert!(stored.insert(leaf));
+        assert!(stored.forest.resident_count() <= 1);
+    }
+    assert!(resident.batch_insert_nonprovable(nonprovable));
+    assert!(stored.batch_insert_nonprovable(nonprovable));
+    resident.commit();
+    stored.commit();
+    assert_eq!(stored.len(), resident.len());
+    assert_same_trees(&stored, &resident);
+    for leaf in provable {
+        assert_eq!(
+            stored
+                .prove(leaf)
+                .expect("Provable leaves must have a proof."),
+            resident
+                .prove(leaf)
+                .expect("Provable leaves must have a proof."),
+        );
+    }
+    assert!(stored.insert(&rng.gen()));
+    stored.rollback();
+    assert_same_trees(&stored, &resident);
+    resident.prune();
+    stored.prune();
+    assert_same_trees(&stored, &resident);
+    stored
+        .forest
+        .flush()
+        .expect("The in-memory store cannot fail.");
+    let reopened = StoredForest::from_forest(
+        StoredTreeArray::open(&parameters, stored.forest.store().clone(), 1)
+            .expect("The in-memory store cannot fail."),
+        parameters,
+    );
+    assert_eq!(reopened.forest.tree_lengths(), stored.forest.tree_lengths());
+    assert_same_trees(&reopened, &resident);
+    for leaf in provable {
+        assert!(reopened.prove(leaf).is_some());
+    }
+}
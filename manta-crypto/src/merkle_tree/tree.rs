@@ -26,8 +26,8 @@
 
 use crate::{
     accumulator::{
-        self, Accumulator, BatchInsertion, ConstantCapacityAccumulator, ExactSizeAccumulator,
-        MembershipProof, OptimizedAccumulator,
+        self, Accumulator, BatchInsertion, CheckpointAccumulator, ConstantCapacityAccumulator,
+        ExactSizeAccumulator, MembershipProof, OptimizedAccumulator,
     },
     eclair::{
         self,
@@ -1363,3 +1363,27 @@ where
         self.tree.merge_fork(&self.parameters);
     }
 }
+
+impl<C, T, M, L> CheckpointAccumulator for MerkleTree<C, ForkedTree<C, T, M, L>>
+where
+    C: Configuration + ?Sized,
+    T: Clone + Tree<C> + WithProofs<C>,
+    M: Default + InnerMap<C>,
+    L: LeafMap<C> + Default,
+    LeafDigest<C>: Clone + Default + PartialEq,
+    InnerDigest<C>: Clone + Default + PartialEq,
+    Parameters<C>: Clone,
+{
+    /// The base tree, which does not include the leaves which were not committed.
+    type Checkpoint = T;
+
+    #[inline]
+    fn checkpoint(&self) -> Self::Checkpoint {
+        self.tree.base().clone()
+    }
+
+    #[inline]
+    fn rollback_to(&mut self, checkpoint: &Self::Checkpoint) {
+        self.tree.reset_base(&self.parameters, checkpoint.clone());
+    }
+}
@@ -300,6 +300,82 @@ where
             Sponge::new(&self.permutation, &mut state).absorb_all(ciphertext.iter(), compiler);
         (C::Tag::read(&state, compiler), plaintext)
     }
+
+    /// Computes the authentication tag of `message` under `key` and `header`.
+    ///
+    /// This is the tag that [`Encrypt::encrypt`] attaches to the [`Ciphertext`] whose message is
+    /// `message`.
+    #[inline]
+    pub fn tag(
+        &self,
+        key: &C::Key,
+        header: &C::Header,
+        message: &C::Ciphertext,
+        compiler: &mut COM,
+    ) -> C::Tag
+    where
+        C: Setup<P, COM>,
+    {
+        self.duplex_decryption(key, header, message, compiler).0
+    }
+
+    /// Verifies the authentication tag of `ciphertext` under `key` and `header`.
+    #[inline]
+    pub fn verify_tag(
+        &self,
+        key: &C::Key,
+        header: &C::Header,
+        ciphertext: &Ciphertext<C::Tag, C::Ciphertext>,
+        compiler: &mut COM,
+    ) -> C::Verification
+    where
+        C: Setup<P, COM> + Verify<P, COM>,
+    {
+        let tag = self.tag(key, header, &ciphertext.message, compiler);
+        self.configuration.verify(&ciphertext.tag, &tag, compiler)
+    }
+
+    /// Decrypts `ciphertext` under `key` and `header`, returning the plaintext only if the
+    /// authentication tag of `ciphertext` is valid.
+    ///
+    /// Unlike [`Decrypt::decrypt`], a ciphertext with an invalid tag never exposes its plaintext
+    /// blocks, so they cannot be passed on to the decoding of the plaintext.
+    #[inline]
+    pub fn decrypt_authenticated(
+        &self,
+        key: &C::Key,
+        header: &C::Header,
+        ciphertext: &Ciphertext<C::Tag, C::Ciphertext>,
+        compiler: &mut COM,
+    ) -> Option<C::Plaintext>
+    where
+        C: Setup<P, COM> + Verify<P, COM, Verification = bool>,
+    {
+        let (tag, plaintext) = self.duplex_decryption(key, header, &ciphertext.message, compiler);
+        self.configuration
+            .verify(&ciphertext.tag, &tag, compiler)
+            .then_some(plaintext)
+    }
+
+    /// Decrypts `ciphertext` under `key` and `header` inside of `compiler`, asserting that the
+    /// authentication tag of `ciphertext` is valid.
+    #[inline]
+    pub fn decrypt_asserted(
+        &self,
+        key: &C::Key,
+        header: &C::Header,
+        ciphertext: &Ciphertext<C::Tag, C::Ciphertext>,
+        compiler: &mut COM,
+    ) -> C::Plaintext
+    where
+        COM: Assert,
+        C: Setup<P, COM> + Verify<P, COM, Verification = Bool<COM>>,
+    {
+        let (tag, plaintext) = self.duplex_decryption(key, header, &ciphertext.message, compiler);
+        let verification = self.configuration.verify(&ciphertext.tag, &tag, compiler);
+        compiler.assert(&verification);
+        plaintext
+    }
 }
 
 impl<P, C, COM> Constant<COM> for Duplexer<P, C, COM>
@@ -210,6 +210,126 @@ where
     }
 }
 
+/// Test Random Number Generator
+///
+/// Explicitly seeded cryptographic RNG for reproducible tests and simulations. The seed is a
+/// [`u64`] so that it can be printed when a test fails and passed back in to replay it.
+#[cfg(feature = "test")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "test")))]
+#[derive(Clone, Debug)]
+pub struct TestRng {
+    /// Seed
+    seed: u64,
+
+    /// Underlying RNG
+    rng: rand_chacha::ChaCha20Rng,
+}
+
+#[cfg(feature = "test")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "test")))]
+impl TestRng {
+    /// Seed Environment Variable
+    ///
+    /// Name of the environment variable read by [`from_env`](Self::from_env).
+    pub const SEED_VARIABLE: &'static str = "MANTA_TEST_SEED";
+
+    /// Builds a new [`TestRng`] from `seed`.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: rand_chacha::ChaCha20Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Builds a new [`TestRng`] from the seed stored in the [`SEED_VARIABLE`] environment
+    /// variable, falling back to a random seed if it is not set. The seed is printed to the
+    /// standard error so that the run can be reproduced.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the [`SEED_VARIABLE`] environment variable is set but is not a valid
+    /// [`u64`].
+    ///
+    /// [`SEED_VARIABLE`]: Self::SEED_VARIABLE
+    #[cfg(all(feature = "getrandom", feature = "std"))]
+    #[cfg_attr(doc_cfg, doc(cfg(all(feature = "getrandom", feature = "std"))))]
+    #[inline]
+    pub fn from_env() -> Self {
+        let seed = match std::env::var(Self::SEED_VARIABLE) {
+            Ok(seed) => seed.parse().unwrap_or_else(|_| {
+                panic!(
+                    "Invalid `{}` value: expected a `u64` but found {seed:?}.",
+                    Self::SEED_VARIABLE
+                )
+            }),
+            _ => OsRng.next_u64(),
+        };
+        std::eprintln!(
+            "TestRng seed: {seed} (set {}={seed} to reproduce)",
+            Self::SEED_VARIABLE
+        );
+        Self::new(seed)
+    }
+
+    /// Returns the seed of `self`.
+    #[inline]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Derives a new [`TestRng`] from `self` whose seed is drawn from `self`, so that every
+    /// derived generator is reproducible from the seed of `self`.
+    #[inline]
+    pub fn fork(&mut self) -> Self {
+        Self::new(self.rng.next_u64())
+    }
+}
+
+#[cfg(feature = "test")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "test")))]
+impl CryptoRng for TestRng {}
+
+#[cfg(feature = "test")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "test")))]
+impl RngCore for TestRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(feature = "test")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "test")))]
+impl SeedableRng for TestRng {
+    type Seed = [u8; 8];
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    #[inline]
+    fn seed_from_u64(state: u64) -> Self {
+        Self::new(state)
+    }
+}
+
 /// Sampling Trait
 pub trait Sample<D = ()>: Sized {
     /// Returns a random value of type `Self`, sampled according to the given `distribution`,
@@ -659,3 +779,24 @@ pub mod fuzz {
         }
     }
 }
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Checks that a [`TestRng`] and every generator forked from it are reproducible from its
+    /// seed.
+    #[test]
+    fn test_rng_is_reproducible() {
+        let mut rng = TestRng::new(OsRng.next_u64());
+        let mut replay = TestRng::new(rng.seed());
+        let mut fork = rng.fork();
+        let mut fork_replay = replay.fork();
+        assert_eq!(fork.seed(), fork_replay.seed(), "Forked seeds must match.");
+        let (bytes, replayed_bytes): ([u8; 32], [u8; 32]) = (rng.gen(), replay.gen());
+        assert_eq!(bytes, replayed_bytes, "Replayed outputs must match.");
+        let (value, replayed_value): (u128, u128) = (fork.gen(), fork_replay.gen());
+        assert_eq!(value, replayed_value, "Forked outputs must match.");
+    }
+}
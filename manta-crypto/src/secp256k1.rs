@@ -0,0 +1,293 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! [`k256`] Backend for the `secp256k1` Elliptic Curve
+//!
+//! This module instantiates the [`ecdsa`](crate::signature::ecdsa) signature scheme natively over
+//! `secp256k1`, with conversions from and to the `(r, s, v)` encoding used by Ethereum so that
+//! spend authorizations can be bound to keys held in Ethereum wallets. Messages are 32-byte
+//! digests, which for Ethereum are the Keccak-256 hashes computed by the wallet.
+
+use crate::{
+    algebra::{Group, Ring, ScalarMul},
+    eclair::{cmp::PartialEq, num::Zero},
+    rand::{RngCore, Sample},
+    signature::ecdsa::{self, Specification},
+};
+use k256::{
+    elliptic_curve::{
+        ops::Reduce,
+        sec1::{Coordinates, ToEncodedPoint},
+        subtle::Choice,
+        AffineXCoordinate, DecompressPoint, Field, PrimeField,
+    },
+    AffinePoint, PublicKey, U256,
+};
+
+pub use k256::{ProjectivePoint, Scalar};
+
+impl Group for Scalar {
+    #[inline]
+    fn add(&self, rhs: &Self, _: &mut ()) -> Self {
+        self + rhs
+    }
+}
+
+impl Ring for Scalar {
+    #[inline]
+    fn mul(&self, rhs: &Self, _: &mut ()) -> Self {
+        self * rhs
+    }
+}
+
+impl Zero for Scalar {
+    type Verification = bool;
+
+    #[inline]
+    fn zero(_: &mut ()) -> Self {
+        Scalar::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self, _: &mut ()) -> Self::Verification {
+        bool::from(Field::is_zero(self))
+    }
+}
+
+impl Sample for Scalar {
+    #[inline]
+    fn sample<R>(_: (), rng: &mut R) -> Self
+    where
+        R: RngCore + ?Sized,
+    {
+        <Scalar as Field>::random(rng)
+    }
+}
+
+impl Group for ProjectivePoint {
+    #[inline]
+    fn add(&self, rhs: &Self, _: &mut ()) -> Self {
+        self + rhs
+    }
+}
+
+impl Zero for ProjectivePoint {
+    type Verification = bool;
+
+    #[inline]
+    fn zero(_: &mut ()) -> Self {
+        ProjectivePoint::IDENTITY
+    }
+
+    #[inline]
+    fn is_zero(&self, _: &mut ()) -> Self::Verification {
+        *self == ProjectivePoint::IDENTITY
+    }
+}
+
+impl ScalarMul<Scalar> for ProjectivePoint {
+    type Output = Self;
+
+    #[inline]
+    fn scalar_mul(&self, scalar: &Scalar, _: &mut ()) -> Self::Output {
+        self * scalar
+    }
+}
+
+impl PartialEq<Self> for ProjectivePoint {
+    #[inline]
+    fn eq(&self, rhs: &Self, _: &mut ()) -> bool {
+        core::cmp::PartialEq::eq(self, rhs)
+    }
+}
+
+/// Reduces the big-endian `bytes` into a [`Scalar`].
+#[inline]
+fn reduce(bytes: [u8; 32]) -> Scalar {
+    <Scalar as Reduce<U256>>::from_be_bytes_reduced(bytes.into())
+}
+
+/// Secp256k1 ECDSA Specification
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Secp256k1;
+
+impl Specification for Secp256k1 {
+    type Scalar = Scalar;
+    type Group = ProjectivePoint;
+    type Message = [u8; 32];
+
+    #[inline]
+    fn hash(&self, message: &Self::Message, _: &mut ()) -> Self::Scalar {
+        reduce(*message)
+    }
+
+    #[inline]
+    fn x_coordinate(&self, point: &Self::Group, _: &mut ()) -> Self::Scalar {
+        reduce(point.to_affine().x().into())
+    }
+
+    #[inline]
+    fn inverse(&self, scalar: &Self::Scalar, _: &mut ()) -> Self::Scalar {
+        scalar.invert().unwrap_or(Scalar::ZERO)
+    }
+}
+
+/// Secp256k1 ECDSA Signature Scheme
+pub type Ecdsa = ecdsa::Ecdsa<Secp256k1>;
+
+/// Secp256k1 ECDSA Signature
+pub type Signature = ecdsa::Signature<Scalar, ProjectivePoint>;
+
+/// Builds the [`Ecdsa`] signature scheme over the standard generator of `secp256k1`.
+#[inline]
+pub fn ecdsa() -> Ecdsa {
+    Ecdsa::new(Secp256k1, ProjectivePoint::GENERATOR)
+}
+
+/// Parses a verifying key from its SEC1 encoding, either compressed or uncompressed.
+#[inline]
+pub fn verifying_key_from_sec1_bytes(bytes: &[u8]) -> Option<ProjectivePoint> {
+    PublicKey::from_sec1_bytes(bytes)
+        .ok()
+        .map(|key| key.to_projective())
+}
+
+/// Parses a [`Signature`] from its `(r, s, v)` encoding, where `v` is the recovery identifier
+/// either in `{0, 1}` or in the Ethereum form `{27, 28}`.
+///
+/// This function returns `None` if any component is out of range or zero, or if `v` requests the
+/// x-coordinate of the nonce point to be `r` plus the group order, which never happens in
+/// practice and is rejected by Ethereum.
+#[inline]
+pub fn signature_from_rsv(r: [u8; 32], s: [u8; 32], v: u8) -> Option<Signature> {
+    let y_is_odd = match v {
+        0 | 27 => 0,
+        1 | 28 => 1,
+        _ => return None,
+    };
+    let r_scalar = Option::<Scalar>::from(Scalar::from_repr(r.into()))?;
+    let scalar = Option::<Scalar>::from(Scalar::from_repr(s.into()))?;
+    if bool::from(r_scalar.is_zero()) || bool::from(scalar.is_zero()) {
+        return None;
+    }
+    let nonce_point =
+        Option::<AffinePoint>::from(AffinePoint::decompress(&r.into(), Choice::from(y_is_odd)))?;
+    Some(Signature {
+        scalar,
+        nonce_point: nonce_point.into(),
+    })
+}
+
+/// Returns the `(r, s, v)` encoding of `signature` with `v` in `{0, 1}`, or `None` if the
+/// x-coordinate of its nonce point is not a canonical scalar, in which case it has no such
+/// encoding.
+#[inline]
+pub fn signature_to_rsv(signature: &Signature) -> Option<([u8; 32], [u8; 32], u8)> {
+    let nonce_point = signature.nonce_point.to_affine().to_encoded_point(false);
+    match nonce_point.coordinates() {
+        Coordinates::Uncompressed { x, y } => {
+            Option::<Scalar>::from(Scalar::from_repr(*x))?;
+            Some(((*x).into(), signature.scalar.to_bytes().into(), y[31] & 1))
+        }
+        _ => None,
+    }
+}
+
+/// Recovers the verifying key which produced `signature` on `digest`, like the `ecrecover`
+/// precompile of Ethereum.
+#[inline]
+pub fn recover(digest: &[u8; 32], signature: &Signature) -> Option<ProjectivePoint> {
+    let r = Secp256k1.x_coordinate(&signature.nonce_point, &mut ());
+    let r_inverse = Option::<Scalar>::from(r.invert())?;
+    let z = Secp256k1.hash(digest, &mut ());
+    let verifying_key =
+        (signature.nonce_point * signature.scalar - ProjectivePoint::GENERATOR * z) * r_inverse;
+    (verifying_key != ProjectivePoint::IDENTITY).then_some(verifying_key)
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        rand::{OsRng, Rand},
+        signature::{test::correctness, Derive, Sign, Verify},
+    };
+
+    /// Checks that signatures verify, survive the `(r, s, v)` encoding, and recover the signer.
+    #[test]
+    fn sign_verify_and_recover() {
+        let scheme = ecdsa();
+        let mut rng = OsRng;
+        let signing_key = rng.gen::<_, Scalar>();
+        let verifying_key = scheme.derive(&signing_key, &mut ());
+        let digest = rng.gen::<_, [u8; 32]>();
+        assert!(correctness(
+            &scheme,
+            &signing_key,
+            &rng.gen(),
+            &digest,
+            &mut ()
+        ));
+        let signature = scheme.sign(&signing_key, &rng.gen(), &digest, &mut ());
+        let (r, s, v) = signature_to_rsv(&signature).expect("The nonce point is canonical.");
+        let decoded = signature_from_rsv(r, s, v + 27).expect("The encoding is valid.");
+        assert_eq!(signature, decoded);
+        assert!(scheme.verify(&verifying_key, &digest, &decoded, &mut ()));
+        assert_eq!(recover(&digest, &decoded), Some(verifying_key));
+        let flipped = signature_from_rsv(r, s, v ^ 1).expect("The encoding is valid.");
+        assert!(!scheme.verify(&verifying_key, &digest, &flipped, &mut ()));
+        let mut tampered = digest;
+        tampered[0] ^= 1;
+        assert!(!scheme.verify(&verifying_key, &tampered, &decoded, &mut ()));
+    }
+
+    /// Checks that degenerate signatures are rejected, including the one with a unit scalar and
+    /// the identity as nonce point, which satisfies the verification equation on every digest
+    /// reducing to zero under every verifying key.
+    #[test]
+    fn degenerate_signatures_are_rejected() {
+        let scheme = ecdsa();
+        let mut rng = OsRng;
+        let verifying_key = scheme.derive(&rng.gen(), &mut ());
+        let forged = Signature {
+            scalar: Scalar::ONE,
+            nonce_point: ProjectivePoint::IDENTITY,
+        };
+        assert!(!scheme.verify(&verifying_key, &[0; 32], &forged, &mut ()));
+        let signing_key = rng.gen::<_, Scalar>();
+        let verifying_key = scheme.derive(&signing_key, &mut ());
+        let digest = rng.gen::<_, [u8; 32]>();
+        let signature = scheme.sign(&signing_key, &rng.gen(), &digest, &mut ());
+        let zero_scalar = Signature {
+            scalar: Scalar::ZERO,
+            ..signature
+        };
+        assert!(!scheme.verify(&verifying_key, &digest, &zero_scalar, &mut ()));
+    }
+
+    /// Checks that the signer's key can be parsed from its SEC1 encoding.
+    #[test]
+    fn sec1_verifying_key() {
+        let verifying_key = ecdsa().derive(&OsRng.gen(), &mut ());
+        let bytes = verifying_key.to_affine().to_encoded_point(true);
+        assert_eq!(
+            verifying_key_from_sec1_bytes(bytes.as_bytes()),
+            Some(verifying_key)
+        );
+        assert_eq!(verifying_key_from_sec1_bytes(&[0; 33]), None);
+    }
+}
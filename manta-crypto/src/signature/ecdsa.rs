@@ -0,0 +1,277 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Elliptic Curve Digital Signature Algorithm
+//!
+//! An ECDSA signature `(r, s)` on a message with hash `z` under the verifying key `Q = d G`
+//! satisfies `s = k^{-1} (z + r d)` where `R = k G` is the nonce point and `r` is the
+//! x-coordinate of `R` reduced into the scalar field. Instead of inverting `s` during verification
+//! we carry the nonce point `R` in the [`Signature`] and check that
+//!
+//! ```text
+//! s R = z G + r Q
+//! ```
+//!
+//! which only uses group operations. Like standard ECDSA, verification also rejects signatures
+//! whose `r` or `s` component is zero or whose nonce point is the identity, since these satisfy
+//! the equation above for some messages under every verifying key. The nonce point can be
+//! recovered from the usual `(r, s, v)` encoding, where `v` records the parity of the
+//! y-coordinate of `R`.
+//!
+//! The scheme is generic over the compiler, but it is only instantiated natively, over
+//! [`secp256k1`](crate::secp256k1). No constraint-system [`Specification`] is provided yet.
+
+use super::*;
+use crate::{
+    algebra::{Group as _, HasGenerator, Ring, ScalarMul, ScalarMulGroup},
+    eclair::{
+        bool::Bool,
+        cmp::PartialEq,
+        num::Zero,
+        ops::{BitAnd, BitOr, Not},
+        Has,
+    },
+};
+use core::{cmp, marker::PhantomData};
+
+/// ECDSA Specification
+pub trait Specification<COM = ()> {
+    /// Scalar Type
+    type Scalar: Ring<COM>;
+
+    /// Group Type
+    type Group: ScalarMulGroup<Self::Scalar, COM, Output = Self::Group>;
+
+    /// Message Type
+    type Message;
+
+    /// Hashes `message` into a scalar of type [`Scalar`](Self::Scalar).
+    fn hash(&self, message: &Self::Message, compiler: &mut COM) -> Self::Scalar;
+
+    /// Returns the x-coordinate of `point` reduced into a scalar of type [`Scalar`](Self::Scalar).
+    fn x_coordinate(&self, point: &Self::Group, compiler: &mut COM) -> Self::Scalar;
+
+    /// Returns the multiplicative inverse of `scalar`, or zero if `scalar` is zero.
+    fn inverse(&self, scalar: &Self::Scalar, compiler: &mut COM) -> Self::Scalar;
+}
+
+/// ECDSA Signature
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "S: Deserialize<'de>, G: Deserialize<'de>",
+            serialize = "S: Serialize, G: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "S: Clone, G: Clone"),
+    Copy(bound = "S: Copy, G: Copy"),
+    Debug(bound = "S: Debug, G: Debug"),
+    Eq(bound = "S: Eq, G: Eq"),
+    Hash(bound = "S: Hash, G: Hash"),
+    PartialEq(bound = "S: cmp::PartialEq, G: cmp::PartialEq")
+)]
+pub struct Signature<S, G> {
+    /// Scalar
+    ///
+    /// This scalar is the `s` component of the signature, the hash of the message plus the secret
+    /// key times the x-coordinate of the nonce point, divided by the secret nonce.
+    pub scalar: S,
+
+    /// Nonce Point
+    ///
+    /// This point is the generator of the group multiplied by the secret nonce, whose reduced
+    /// x-coordinate is the `r` component of the signature.
+    pub nonce_point: G,
+}
+
+/// ECDSA Signature Scheme
+#[derive(derivative::Derivative)]
+#[derivative(
+    Clone(bound = "S: Clone, S::Group: Clone"),
+    Copy(bound = "S: Copy, S::Group: Copy"),
+    Debug(bound = "S: Debug, S::Group: Debug"),
+    Eq(bound = "S: Eq, S::Group: Eq"),
+    Hash(bound = "S: Hash, S::Group: Hash"),
+    PartialEq(bound = "S: cmp::PartialEq, S::Group: cmp::PartialEq")
+)]
+pub struct Ecdsa<S, COM = ()>
+where
+    S: Specification<COM>,
+{
+    /// ECDSA Specification
+    pub specification: S,
+
+    /// ECDSA Group Generator
+    pub generator: S::Group,
+
+    /// Type Parameter Marker
+    __: PhantomData<COM>,
+}
+
+impl<S, COM> Ecdsa<S, COM>
+where
+    S: Specification<COM>,
+{
+    /// Builds a new [`Ecdsa`] signature scheme over `specification` and `generator`.
+    #[inline]
+    pub fn new(specification: S, generator: S::Group) -> Self {
+        Self {
+            specification,
+            generator,
+            __: PhantomData,
+        }
+    }
+}
+
+impl<S, COM> HasGenerator<S::Group, COM> for Ecdsa<S, COM>
+where
+    S: Specification<COM>,
+{
+    type Generator = S::Group;
+
+    #[inline]
+    fn generator(&self) -> &Self::Generator {
+        &self.generator
+    }
+}
+
+impl<S, COM> SigningKeyType for Ecdsa<S, COM>
+where
+    S: Specification<COM>,
+{
+    type SigningKey = S::Scalar;
+}
+
+impl<S, COM> VerifyingKeyType for Ecdsa<S, COM>
+where
+    S: Specification<COM>,
+{
+    type VerifyingKey = S::Group;
+}
+
+impl<S, COM> MessageType for Ecdsa<S, COM>
+where
+    S: Specification<COM>,
+{
+    type Message = S::Message;
+}
+
+impl<S, COM> SignatureType for Ecdsa<S, COM>
+where
+    S: Specification<COM>,
+{
+    type Signature = Signature<S::Scalar, S::Group>;
+}
+
+impl<S, COM> RandomnessType for Ecdsa<S, COM>
+where
+    S: Specification<COM>,
+{
+    type Randomness = S::Scalar;
+}
+
+impl<S, COM> Derive<COM> for Ecdsa<S, COM>
+where
+    S: Specification<COM>,
+{
+    #[inline]
+    fn derive(&self, signing_key: &Self::SigningKey, compiler: &mut COM) -> Self::VerifyingKey {
+        self.generator.scalar_mul(signing_key, compiler)
+    }
+}
+
+impl<S, COM> Sign<COM> for Ecdsa<S, COM>
+where
+    S: Specification<COM>,
+{
+    /// Signs `message` with `signing_key` using `randomness` as the secret nonce.
+    ///
+    /// # Security
+    ///
+    /// The `randomness` must be sampled uniformly for every signature, since reusing or biasing
+    /// it reveals the `signing_key`. With negligible probability the resulting signature has a
+    /// zero component, which verifiers following the standard reject.
+    #[inline]
+    fn sign(
+        &self,
+        signing_key: &Self::SigningKey,
+        randomness: &Self::Randomness,
+        message: &Self::Message,
+        compiler: &mut COM,
+    ) -> Self::Signature {
+        let nonce_point = self.generator.scalar_mul(randomness, compiler);
+        let r = self.specification.x_coordinate(&nonce_point, compiler);
+        let z = self.specification.hash(message, compiler);
+        let numerator = z.add(&r.mul(signing_key, compiler), compiler);
+        Signature {
+            scalar: self
+                .specification
+                .inverse(randomness, compiler)
+                .mul(&numerator, compiler),
+            nonce_point,
+        }
+    }
+}
+
+impl<S, COM> Verify<COM> for Ecdsa<S, COM>
+where
+    COM: Has<bool>,
+    Bool<COM>: BitAnd<Bool<COM>, COM, Output = Bool<COM>>
+        + BitOr<Bool<COM>, COM, Output = Bool<COM>>
+        + Not<COM, Output = Bool<COM>>,
+    S: Specification<COM>,
+    S::Scalar: Zero<COM, Verification = Bool<COM>>,
+    S::Group: PartialEq<S::Group, COM> + Zero<COM, Verification = Bool<COM>>,
+{
+    type Verification = Bool<COM>;
+
+    #[inline]
+    fn verify(
+        &self,
+        verifying_key: &Self::VerifyingKey,
+        message: &Self::Message,
+        signature: &Self::Signature,
+        compiler: &mut COM,
+    ) -> Self::Verification {
+        let Signature {
+            scalar,
+            nonce_point,
+        } = signature;
+        let r = self.specification.x_coordinate(nonce_point, compiler);
+        let z = self.specification.hash(message, compiler);
+        let is_degenerate = nonce_point
+            .is_zero(compiler)
+            .bitor(r.is_zero(compiler), compiler)
+            .bitor(scalar.is_zero(compiler), compiler);
+        nonce_point
+            .scalar_mul(scalar, compiler)
+            .eq(
+                &self
+                    .generator
+                    .scalar_mul(&z, compiler)
+                    .add(&verifying_key.scalar_mul(&r, compiler), compiler),
+                compiler,
+            )
+            .bitand(is_degenerate.not(compiler), compiler)
+    }
+}
@@ -40,6 +40,7 @@ use core::{fmt::Debug, hash::Hash};
 use manta_util::serde::{Deserialize, Serialize};
 
 pub mod convert;
+pub mod ecdsa;
 
 /// Signing Key
 pub trait SigningKeyType {
@@ -89,6 +90,22 @@ where
 /// Message Type
 pub type Message<T> = <T as MessageType>::Message;
 
+/// Message Digest
+pub trait DigestType {
+    /// Message Digest Type
+    type Digest;
+}
+
+impl<T> DigestType for &T
+where
+    T: DigestType,
+{
+    type Digest = T::Digest;
+}
+
+/// Message Digest Type
+pub type Digest<T> = <T as DigestType>::Digest;
+
 /// Signature
 pub trait SignatureType {
     /// Signature Type
@@ -266,6 +283,80 @@ where
     }
 }
 
+/// Prehashed Signature Creation
+///
+/// Signing over a message digest computed by the caller, so that large messages are hashed once
+/// instead of being passed in full to the signature scheme.
+pub trait SignPrehashed<COM = ()>:
+    DigestType + RandomnessType + SignatureType + SigningKeyType
+{
+    /// Signs the message `digest` with the `signing_key` using `randomness` to hide the
+    /// signature.
+    fn sign_prehashed(
+        &self,
+        signing_key: &Self::SigningKey,
+        randomness: &Self::Randomness,
+        digest: &Self::Digest,
+        compiler: &mut COM,
+    ) -> Self::Signature;
+}
+
+impl<S, COM> SignPrehashed<COM> for &S
+where
+    S: SignPrehashed<COM>,
+{
+    #[inline]
+    fn sign_prehashed(
+        &self,
+        signing_key: &Self::SigningKey,
+        randomness: &Self::Randomness,
+        digest: &Self::Digest,
+        compiler: &mut COM,
+    ) -> Self::Signature {
+        (*self).sign_prehashed(signing_key, randomness, digest, compiler)
+    }
+}
+
+/// Prehashed Signature Verification
+pub trait VerifyPrehashed<COM = ()>: DigestType + SignatureType + VerifyingKeyType {
+    /// Verification Result Type
+    ///
+    /// This type is typically either [`bool`], a [`Result`] type, or a compiler variable
+    /// representing either of those concrete types.
+    type Verification;
+
+    /// Verifies that the `signature` of the message `digest` was signed with the signing key
+    /// deriving `verifying_key`.
+    ///
+    /// Signatures produced by [`SignPrehashed::sign_prehashed`] are only valid under this method
+    /// and not under [`Verify::verify`], and vice versa.
+    fn verify_prehashed(
+        &self,
+        verifying_key: &Self::VerifyingKey,
+        digest: &Self::Digest,
+        signature: &Self::Signature,
+        compiler: &mut COM,
+    ) -> Self::Verification;
+}
+
+impl<V, COM> VerifyPrehashed<COM> for &V
+where
+    V: VerifyPrehashed<COM>,
+{
+    type Verification = V::Verification;
+
+    #[inline]
+    fn verify_prehashed(
+        &self,
+        verifying_key: &Self::VerifyingKey,
+        digest: &Self::Digest,
+        signature: &Self::Signature,
+        compiler: &mut COM,
+    ) -> Self::Verification {
+        (*self).verify_prehashed(verifying_key, digest, signature, compiler)
+    }
+}
+
 /// Schnorr Signatures
 pub mod schnorr {
     use super::*;
@@ -308,6 +399,27 @@ pub mod schnorr {
         ) -> Self::Scalar;
     }
 
+    /// Schnorr Signature Prehashed Hash Function
+    pub trait PrehashedHashFunction<COM = ()>: HashFunction<COM> {
+        /// Message Digest Type
+        type Digest;
+
+        /// Hashes the message `digest` along with `verifying_key` and `nonce_point` into a scalar
+        /// of type [`Scalar`](HashFunction::Scalar).
+        ///
+        /// # Implementation Note
+        ///
+        /// This hash must be domain separated from [`HashFunction::hash`] so that a signature over
+        /// a digest can never be reinterpreted as a signature over a message.
+        fn hash_prehashed(
+            &self,
+            verifying_key: &Self::Group,
+            nonce_point: &Self::Group,
+            digest: &Self::Digest,
+            compiler: &mut COM,
+        ) -> Self::Scalar;
+    }
+
     /// Scalar Type
     pub type Scalar<H, COM = ()> = <H as HashFunction<COM>>::Scalar;
 
@@ -389,6 +501,54 @@ pub mod schnorr {
                 __: PhantomData,
             }
         }
+
+        /// Signs with `signing_key` and `randomness`, computing the challenge scalar with
+        /// `challenge` from the verifying key and the nonce point.
+        #[inline]
+        fn sign_with<F>(
+            &self,
+            signing_key: &H::Scalar,
+            randomness: &H::Scalar,
+            challenge: F,
+            compiler: &mut COM,
+        ) -> Signature<H::Scalar, H::Group>
+        where
+            F: FnOnce(&H::Group, &H::Group, &mut COM) -> H::Scalar,
+        {
+            let nonce_point = self.generator.scalar_mul(randomness, compiler);
+            let verifying_key = self.generator.scalar_mul(signing_key, compiler);
+            let challenge = challenge(&verifying_key, &nonce_point, compiler);
+            Signature {
+                scalar: randomness.add(&signing_key.mul(&challenge, compiler), compiler),
+                nonce_point,
+            }
+        }
+
+        /// Verifies `signature` against `verifying_key`, computing the challenge scalar with
+        /// `challenge` from the verifying key and the nonce point.
+        #[inline]
+        fn verify_with<F>(
+            &self,
+            verifying_key: &H::Group,
+            signature: &Signature<H::Scalar, H::Group>,
+            challenge: F,
+            compiler: &mut COM,
+        ) -> Bool<COM>
+        where
+            COM: Has<bool>,
+            H::Group: PartialEq<H::Group, COM>,
+            F: FnOnce(&H::Group, &H::Group, &mut COM) -> H::Scalar,
+        {
+            let Signature {
+                scalar,
+                nonce_point,
+            } = signature;
+            let challenge = challenge(verifying_key, nonce_point, compiler);
+            self.generator.scalar_mul(scalar, compiler).eq(
+                &nonce_point.add(&verifying_key.scalar_mul(&challenge, compiler), compiler),
+                compiler,
+            )
+        }
     }
 
     impl<H, COM> HasGenerator<H::Group, COM> for Schnorr<H, COM>
@@ -462,6 +622,13 @@ pub mod schnorr {
         }
     }
 
+    impl<H, COM> DigestType for Schnorr<H, COM>
+    where
+        H: PrehashedHashFunction<COM>,
+    {
+        type Digest = H::Digest;
+    }
+
     impl<H, COM> Sign<COM> for Schnorr<H, COM>
     where
         H: HashFunction<COM>,
@@ -474,22 +641,39 @@ pub mod schnorr {
             message: &Self::Message,
             compiler: &mut COM,
         ) -> Self::Signature {
-            let nonce_point = self.generator.scalar_mul(randomness, compiler);
-            Signature {
-                scalar: randomness.add(
-                    &signing_key.mul(
-                        &self.hash_function.hash(
-                            &self.generator.scalar_mul(signing_key, compiler),
-                            &nonce_point,
-                            message,
-                            compiler,
-                        ),
-                        compiler,
-                    ),
-                    compiler,
-                ),
-                nonce_point,
-            }
+            self.sign_with(
+                signing_key,
+                randomness,
+                |verifying_key, nonce_point, compiler| {
+                    self.hash_function
+                        .hash(verifying_key, nonce_point, message, compiler)
+                },
+                compiler,
+            )
+        }
+    }
+
+    impl<H, COM> SignPrehashed<COM> for Schnorr<H, COM>
+    where
+        H: PrehashedHashFunction<COM>,
+    {
+        #[inline]
+        fn sign_prehashed(
+            &self,
+            signing_key: &Self::SigningKey,
+            randomness: &Self::Randomness,
+            digest: &Self::Digest,
+            compiler: &mut COM,
+        ) -> Self::Signature {
+            self.sign_with(
+                signing_key,
+                randomness,
+                |verifying_key, nonce_point, compiler| {
+                    self.hash_function
+                        .hash_prehashed(verifying_key, nonce_point, digest, compiler)
+                },
+                compiler,
+            )
         }
     }
 
@@ -509,20 +693,41 @@ pub mod schnorr {
             signature: &Self::Signature,
             compiler: &mut COM,
         ) -> Self::Verification {
-            let Signature {
-                scalar,
-                nonce_point,
-            } = signature;
-            self.generator.scalar_mul(scalar, compiler).eq(
-                &nonce_point.add(
-                    &verifying_key.scalar_mul(
-                        &self
-                            .hash_function
-                            .hash(verifying_key, nonce_point, message, compiler),
-                        compiler,
-                    ),
-                    compiler,
-                ),
+            self.verify_with(
+                verifying_key,
+                signature,
+                |verifying_key, nonce_point, compiler| {
+                    self.hash_function
+                        .hash(verifying_key, nonce_point, message, compiler)
+                },
+                compiler,
+            )
+        }
+    }
+
+    impl<H, COM> VerifyPrehashed<COM> for Schnorr<H, COM>
+    where
+        COM: Has<bool>,
+        H: PrehashedHashFunction<COM>,
+        H::Group: PartialEq<H::Group, COM>,
+    {
+        type Verification = Bool<COM>;
+
+        #[inline]
+        fn verify_prehashed(
+            &self,
+            verifying_key: &Self::VerifyingKey,
+            digest: &Self::Digest,
+            signature: &Self::Signature,
+            compiler: &mut COM,
+        ) -> Self::Verification {
+            self.verify_with(
+                verifying_key,
+                signature,
+                |verifying_key, nonce_point, compiler| {
+                    self.hash_function
+                        .hash_prehashed(verifying_key, nonce_point, digest, compiler)
+                },
                 compiler,
             )
         }
@@ -573,4 +778,25 @@ pub mod test {
             compiler,
         )
     }
+
+    /// Verifies that `scheme` produces self-consistent results on the given `signing_key`,
+    /// `randomness`, and message `digest` when signing prehashed messages.
+    #[inline]
+    pub fn prehashed_correctness<S, COM>(
+        scheme: &S,
+        signing_key: &S::SigningKey,
+        randomness: &S::Randomness,
+        digest: &S::Digest,
+        compiler: &mut COM,
+    ) -> S::Verification
+    where
+        S: Derive<COM> + SignPrehashed<COM> + VerifyPrehashed<COM>,
+    {
+        scheme.verify_prehashed(
+            &scheme.derive(signing_key, compiler),
+            digest,
+            &scheme.sign_prehashed(signing_key, randomness, digest, compiler),
+            compiler,
+        )
+    }
 }
@@ -0,0 +1,260 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Fiat-Shamir Transcripts
+//!
+//! A transcript records every public message of an interactive protocol and derives the
+//! verifier challenges from them, turning the protocol into a non-interactive one. Every message
+//! and challenge is bound to a label, and every transcript to a protocol domain, so that values
+//! from different protocols or different positions in the same protocol can never be confused.
+//!
+//! Two transcripts are provided:
+//!
+//! - [`Blake2sTranscript`]: a byte-oriented transcript over BLAKE2s for native protocols
+//! - [`SpongeTranscript`]: a transcript over a [`PseudorandomPermutation`] like Poseidon, which
+//!   can also be used inside of a circuit
+
+use crate::permutation::{
+    sponge::{Read, Sponge, Write},
+    PseudorandomPermutation,
+};
+use blake2::{Blake2s256, Digest};
+use core::marker::PhantomData;
+
+/// Transcript
+pub trait Transcript {
+    /// Label Type
+    ///
+    /// Labels name the messages and challenges of a protocol and are absorbed before each of
+    /// them.
+    type Label: ?Sized;
+}
+
+/// Transcript Absorption
+pub trait Absorb<T, COM = ()>: Transcript
+where
+    T: ?Sized,
+{
+    /// Absorbs `value` into `self` under `label`.
+    fn absorb(&mut self, label: &Self::Label, value: &T, compiler: &mut COM);
+}
+
+/// Transcript Challenge
+pub trait Challenge<T, COM = ()>: Transcript {
+    /// Squeezes a challenge of type `T` out of `self` under `label`.
+    ///
+    /// The challenge is bound to every value absorbed before it, and every challenge squeezed
+    /// after it is bound to it.
+    fn challenge(&mut self, label: &Self::Label, compiler: &mut COM) -> T;
+}
+
+/// BLAKE2s Transcript
+///
+/// Every label, message and challenge is framed with its length before being hashed so that the
+/// encoding of the transcript is injective.
+#[derive(Clone, Debug)]
+pub struct Blake2sTranscript {
+    /// Hasher State
+    hasher: Blake2s256,
+}
+
+impl Blake2sTranscript {
+    /// Transcript Protocol Tag
+    pub const PROTOCOL: &'static [u8] = b"manta-crypto/transcript/blake2s";
+
+    /// Challenge Tag
+    const CHALLENGE: &'static [u8] = b"challenge";
+
+    /// Builds a new [`Blake2sTranscript`] for the protocol identified by `domain`.
+    #[inline]
+    pub fn new(domain: &[u8]) -> Self {
+        let mut transcript = Self {
+            hasher: Blake2s256::new(),
+        };
+        transcript.append(Self::PROTOCOL, domain);
+        transcript
+    }
+
+    /// Appends a length-prefixed `bytes` to the hasher.
+    #[inline]
+    fn frame(&mut self, bytes: &[u8]) {
+        self.hasher.update((bytes.len() as u64).to_le_bytes());
+        self.hasher.update(bytes);
+    }
+
+    /// Appends `message` under `label` to the hasher.
+    #[inline]
+    fn append(&mut self, label: &[u8], message: &[u8]) {
+        self.frame(label);
+        self.frame(message);
+    }
+
+    /// Squeezes 32 bytes out of `self` under `label`.
+    #[inline]
+    pub fn challenge_bytes(&mut self, label: &[u8]) -> [u8; 32] {
+        self.append(Self::CHALLENGE, label);
+        let challenge: [u8; 32] = self.hasher.clone().finalize().into();
+        self.frame(&challenge);
+        challenge
+    }
+}
+
+impl Transcript for Blake2sTranscript {
+    type Label = [u8];
+}
+
+impl Absorb<[u8]> for Blake2sTranscript {
+    #[inline]
+    fn absorb(&mut self, label: &Self::Label, value: &[u8], compiler: &mut ()) {
+        let _ = compiler;
+        self.append(label, value);
+    }
+}
+
+impl<const N: usize> Absorb<[u8; N]> for Blake2sTranscript {
+    #[inline]
+    fn absorb(&mut self, label: &Self::Label, value: &[u8; N], compiler: &mut ()) {
+        let _ = compiler;
+        self.append(label, value);
+    }
+}
+
+impl Challenge<[u8; 32]> for Blake2sTranscript {
+    #[inline]
+    fn challenge(&mut self, label: &Self::Label, compiler: &mut ()) -> [u8; 32] {
+        let _ = compiler;
+        self.challenge_bytes(label)
+    }
+}
+
+/// Sponge Transcript
+///
+/// Absorbs labels and messages into the state of the permutation `P` with [`Write`] and squeezes
+/// challenges with [`Read`], so that the same transcript can be evaluated natively and inside of a
+/// circuit. Labels of type `L` are usually constant domain tags.
+pub struct SpongeTranscript<P, L, COM = ()>
+where
+    P: PseudorandomPermutation<COM>,
+{
+    /// Permutation
+    permutation: P,
+
+    /// Permutation State
+    state: P::Domain,
+
+    /// Type Parameter Marker
+    __: PhantomData<L>,
+}
+
+impl<P, L, COM> SpongeTranscript<P, L, COM>
+where
+    P: PseudorandomPermutation<COM>,
+    L: Write<P, COM>,
+{
+    /// Builds a new [`SpongeTranscript`] over `permutation`, starting from `state` and absorbing
+    /// the protocol `domain`.
+    #[inline]
+    pub fn new(permutation: P, state: P::Domain, domain: &L, compiler: &mut COM) -> Self {
+        let mut transcript = Self {
+            permutation,
+            state,
+            __: PhantomData,
+        };
+        transcript.sponge().absorb(domain, compiler);
+        transcript
+    }
+
+    /// Returns the underlying sponge of `self`.
+    #[inline]
+    fn sponge(&mut self) -> Sponge<'_, P, COM> {
+        Sponge::new(&self.permutation, &mut self.state)
+    }
+
+    /// Returns the current state of `self`.
+    #[inline]
+    pub fn state(&self) -> &P::Domain {
+        &self.state
+    }
+}
+
+impl<P, L, COM> Transcript for SpongeTranscript<P, L, COM>
+where
+    P: PseudorandomPermutation<COM>,
+{
+    type Label = L;
+}
+
+impl<P, L, T, COM> Absorb<T, COM> for SpongeTranscript<P, L, COM>
+where
+    P: PseudorandomPermutation<COM>,
+    L: Write<P, COM>,
+    T: Write<P, COM>,
+{
+    #[inline]
+    fn absorb(&mut self, label: &Self::Label, value: &T, compiler: &mut COM) {
+        let mut sponge = self.sponge();
+        sponge.absorb(label, compiler);
+        sponge.absorb(value, compiler);
+    }
+}
+
+impl<P, L, T, COM> Challenge<T, COM> for SpongeTranscript<P, L, COM>
+where
+    P: PseudorandomPermutation<COM>,
+    L: Write<P, COM>,
+    T: Read<P, COM>,
+{
+    #[inline]
+    fn challenge(&mut self, label: &Self::Label, compiler: &mut COM) -> T {
+        let mut sponge = self.sponge();
+        sponge.absorb(label, compiler);
+        sponge.squeeze(compiler)
+    }
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Checks that challenges depend on the domain, the labels and the framing of the messages.
+    #[test]
+    fn blake2s_transcript_is_domain_separated() {
+        let challenge = |domain: &[u8], label: &[u8], message: &[u8]| {
+            let mut transcript = Blake2sTranscript::new(domain);
+            transcript.absorb(label, message, &mut ());
+            Challenge::<[u8; 32]>::challenge(&mut transcript, b"challenge", &mut ())
+        };
+        let base = challenge(b"protocol", b"message", b"value");
+        assert_eq!(base, challenge(b"protocol", b"message", b"value"));
+        assert_ne!(base, challenge(b"other-protocol", b"message", b"value"));
+        assert_ne!(base, challenge(b"protocol", b"other-message", b"value"));
+        assert_ne!(base, challenge(b"protocol", b"message", b"other-value"));
+        assert_ne!(
+            challenge(b"protocol", b"ab", b"c"),
+            challenge(b"protocol", b"a", b"bc")
+        );
+    }
+
+    /// Checks that consecutive challenges under the same label are distinct.
+    #[test]
+    fn blake2s_transcript_challenges_are_chained() {
+        let mut transcript = Blake2sTranscript::new(b"protocol");
+        let first = transcript.challenge_bytes(b"challenge");
+        let second = transcript.challenge_bytes(b"challenge");
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Miniature Trusted Setup Ceremony
+
+use alloc::vec::Vec;
+use blake2::Digest;
+use manta_crypto::{
+    arkworks::{
+        bn254::{Bn254, Fr, G1Affine, G2Affine},
+        constraint::{fp::Fp, FpVar},
+        ec::{AffineCurve, PairingEngine},
+        groth16::ProvingKey,
+        pairing::Pairing,
+        r1cs_std::eq::EqGadget,
+        serialize::CanonicalSerialize,
+    },
+    eclair::alloc::{
+        mode::{Public, Secret},
+        Allocate,
+    },
+    rand::{CryptoRng, RngCore, Sample},
+};
+use manta_pay::config::{Compiler, ProvingContext};
+use manta_trusted_setup::{
+    groth16::{
+        kzg::{self, Accumulator, Contribution, Size},
+        mpc::{self, contribute, initialize, verify_transform, verify_transform_all, Proof, State},
+    },
+    mpc::{ChallengeType, ContributionType, ProofType, StateType},
+    util::{BlakeHasher, KZGBlakeHasher},
+};
+use manta_util::into_array_unchecked;
+
+/// Challenge Type
+pub type Challenge = [u8; 64];
+
+/// Miniature Ceremony Configuration
+///
+/// This configuration follows the ceremony configuration over BN254 but only produces enough
+/// powers of tau for circuits with a handful of constraints.
+#[derive(Clone, Debug, Default)]
+pub struct Mini;
+
+impl Size for Mini {
+    const G1_POWERS: usize = (Self::G2_POWERS << 1) - 1;
+    const G2_POWERS: usize = 1 << 3;
+}
+
+impl Pairing for Mini {
+    type Scalar = Fr;
+    type G1 = G1Affine;
+    type G1Prepared = <Self::Pairing as PairingEngine>::G1Prepared;
+    type G2 = G2Affine;
+    type G2Prepared = <Self::Pairing as PairingEngine>::G2Prepared;
+    type Pairing = Bn254;
+
+    #[inline]
+    fn g1_prime_subgroup_generator() -> Self::G1 {
+        G1Affine::prime_subgroup_generator()
+    }
+
+    #[inline]
+    fn g2_prime_subgroup_generator() -> Self::G2 {
+        G2Affine::prime_subgroup_generator()
+    }
+}
+
+impl kzg::Configuration for Mini {
+    type DomainTag = u8;
+    type Challenge = Challenge;
+    type Response = [u8; 64];
+    type HashToGroup = KZGBlakeHasher<Self>;
+
+    const TAU_DOMAIN_TAG: Self::DomainTag = 0;
+    const ALPHA_DOMAIN_TAG: Self::DomainTag = 1;
+    const BETA_DOMAIN_TAG: Self::DomainTag = 2;
+
+    #[inline]
+    fn hasher(domain_tag: Self::DomainTag) -> Self::HashToGroup {
+        Self::HashToGroup { domain_tag }
+    }
+
+    #[inline]
+    fn response(
+        state: &Accumulator<Self>,
+        challenge: &Self::Challenge,
+        proof: &kzg::Proof<Self>,
+    ) -> Self::Response {
+        let mut hasher = BlakeHasher::default();
+        for item in &state.tau_powers_g1 {
+            item.serialize_uncompressed(&mut hasher).unwrap();
+        }
+        for item in &state.tau_powers_g2 {
+            item.serialize_uncompressed(&mut hasher).unwrap();
+        }
+        for item in &state.alpha_tau_powers_g1 {
+            item.serialize_uncompressed(&mut hasher).unwrap();
+        }
+        for item in &state.beta_tau_powers_g1 {
+            item.serialize_uncompressed(&mut hasher).unwrap();
+        }
+        state.beta_g2.serialize_uncompressed(&mut hasher).unwrap();
+        hasher.0.update(challenge);
+        proof
+            .tau
+            .serialize(&mut hasher)
+            .expect("Consuming ratio proof of tau failed.");
+        proof
+            .alpha
+            .serialize(&mut hasher)
+            .expect("Consuming ratio proof of alpha failed.");
+        proof
+            .beta
+            .serialize(&mut hasher)
+            .expect("Consuming ratio proof of beta failed.");
+        into_array_unchecked(hasher.0.finalize())
+    }
+}
+
+impl mpc::Configuration for Mini {
+    type Hasher = BlakeHasher;
+
+    #[inline]
+    fn challenge(
+        challenge: &Self::Challenge,
+        prev: &State<Self>,
+        next: &State<Self>,
+        proof: &Proof<Self>,
+    ) -> Self::Challenge {
+        let mut hasher = Self::Hasher::default();
+        hasher.0.update(challenge);
+        prev.0
+            .serialize(&mut hasher)
+            .expect("Consuming the previous state failed.");
+        next.0
+            .serialize(&mut hasher)
+            .expect("Consuming the current state failed.");
+        proof
+            .0
+            .serialize(&mut hasher)
+            .expect("Consuming proof failed");
+        into_array_unchecked(hasher.0.finalize())
+    }
+}
+
+impl mpc::ProvingKeyHasher<Self> for Mini {
+    type Output = Challenge;
+
+    #[inline]
+    fn hash(proving_key: &ProvingKey<Bn254>) -> Self::Output {
+        let mut hasher = BlakeHasher::default();
+        proving_key
+            .serialize(&mut hasher)
+            .expect("Hasher is not allowed to fail");
+        into_array_unchecked(hasher.0.finalize())
+    }
+}
+
+impl ChallengeType for Mini {
+    type Challenge = Challenge;
+}
+
+impl ContributionType for Mini {
+    type Contribution = Contribution<Self>;
+}
+
+impl ProofType for Mini {
+    type Proof = Proof<Self>;
+}
+
+impl StateType for Mini {
+    type State = State<Self>;
+}
+
+/// Runs a phase one ceremony with a single contribution, returning the verified accumulator.
+#[inline]
+pub fn phase_one<R>(rng: &mut R) -> Result<Accumulator<Mini>, kzg::VerificationError>
+where
+    R: CryptoRng + RngCore + ?Sized,
+{
+    let accumulator = Accumulator::default();
+    let challenge = [0; 64];
+    let contribution = Contribution::gen(rng);
+    let proof = contribution
+        .proof(&challenge, rng)
+        .expect("The contribution proof should have been generated correctly.");
+    let mut next_accumulator = accumulator.clone();
+    next_accumulator.update(&contribution);
+    Accumulator::verify_transform(accumulator, next_accumulator, challenge, proof)
+}
+
+/// Allocates a circuit into `compiler` which proves knowledge of two secret factors `lhs` and
+/// `rhs` of the public `product`.
+#[inline]
+pub fn product_circuit(compiler: &mut Compiler, lhs: Fr, rhs: Fr, product: Fr) {
+    let lhs = Fp(lhs).as_known::<Secret, FpVar<_>>(compiler);
+    let rhs = Fp(rhs).as_known::<Secret, FpVar<_>>(compiler);
+    let product = Fp(product).as_known::<Public, FpVar<_>>(compiler);
+    (&lhs * &rhs)
+        .enforce_equal(&product)
+        .expect("enforce_equal is not allowed to fail");
+}
+
+/// Phase Two Round
+///
+/// The round plays the role of the coordinator: it keeps the latest state and challenge, checks
+/// every submitted contribution against them, and records the accepted contributions in its
+/// transcript.
+pub struct Round {
+    /// Initial Challenge
+    initial_challenge: Challenge,
+
+    /// Initial State
+    initial_state: State<Mini>,
+
+    /// Current Challenge
+    challenge: Challenge,
+
+    /// Current State
+    state: State<Mini>,
+
+    /// Accepted Contributions
+    rounds: Vec<(State<Mini>, Proof<Mini>)>,
+}
+
+impl Round {
+    /// Starts a new round for the circuit in `compiler` from the `phase_one` accumulator.
+    #[inline]
+    pub fn new(phase_one: &Accumulator<Mini>, compiler: Compiler) -> Result<Self, mpc::Error> {
+        let state = initialize(phase_one, compiler)?;
+        let challenge = <Mini as mpc::ProvingKeyHasher<Mini>>::hash(&state.0);
+        Ok(Self {
+            initial_challenge: challenge,
+            initial_state: state.clone(),
+            challenge,
+            state,
+            rounds: Vec::new(),
+        })
+    }
+
+    /// Returns the number of contributions accepted in this round.
+    #[inline]
+    pub fn contributions(&self) -> usize {
+        self.rounds.len()
+    }
+
+    /// Computes a contribution on top of the current state without submitting it, returning the
+    /// next state and the proof of its correctness.
+    #[inline]
+    pub fn contribution<R>(&self, rng: &mut R) -> (State<Mini>, Proof<Mini>)
+    where
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        let mut state = self.state.clone();
+        let proof = contribute(&Default::default(), &self.challenge, &mut state, rng)
+            .expect("The contribution proof should have been generated correctly.");
+        (state, proof)
+    }
+
+    /// Checks that `state` was obtained from the current state as witnessed by `proof`, and
+    /// accepts it as the current state if so.
+    #[inline]
+    pub fn submit(&mut self, state: State<Mini>, proof: Proof<Mini>) -> Result<(), mpc::Error> {
+        let (challenge, state) =
+            verify_transform(&self.challenge, &self.state, state, proof.clone())?;
+        self.challenge = challenge;
+        self.state = state.clone();
+        self.rounds.push((state, proof));
+        Ok(())
+    }
+
+    /// Computes a contribution on top of the current state and submits it.
+    #[inline]
+    pub fn contribute<R>(&mut self, rng: &mut R) -> Result<(), mpc::Error>
+    where
+        R: CryptoRng + RngCore + ?Sized,
+    {
+        let (state, proof) = self.contribution(rng);
+        self.submit(state, proof)
+    }
+
+    /// Verifies the whole transcript of the round and returns the proving context of the final
+    /// state.
+    #[inline]
+    pub fn finish(self) -> Result<ProvingContext, mpc::Error> {
+        verify_transform_all(self.initial_challenge, self.initial_state, self.rounds)?;
+        Ok(ProvingContext::new(self.state.0))
+    }
+}
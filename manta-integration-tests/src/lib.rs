@@ -0,0 +1,34 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Integration Testing Suite
+//!
+//! The harnesses in this crate wire the signer, the ledger and the trusted setup together in a
+//! single process, so that the test suites in `tests/` can exercise them end-to-end.
+
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+#![forbid(rustdoc::broken_intra_doc_links)]
+#![forbid(missing_docs)]
+
+extern crate alloc;
+
+#[cfg(feature = "integration-tests")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "integration-tests")))]
+pub mod ceremony;
+
+#[cfg(feature = "integration-tests")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "integration-tests")))]
+pub mod network;
@@ -0,0 +1,137 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! In-Process Payment Network
+
+use alloc::sync::Arc;
+use manta_accounting::{transfer::canonical::generate_context, wallet};
+use manta_crypto::rand::{CryptoRng, Rand, RngCore};
+use manta_pay::{
+    config::{
+        AccountId, Asset, AssetId, AssetValue, Config, FullParametersRef, MultiProvingContext,
+        Parameters, UtxoAccumulatorModel,
+    },
+    signer::base::Signer,
+    simulation::{
+        ledger::{Ledger, LedgerConnection, SharedLedger},
+        sample_signer_from_seed,
+    },
+};
+use tokio::sync::RwLock;
+
+/// Wallet Type
+pub type Wallet = wallet::Wallet<Config, LedgerConnection, Signer>;
+
+/// Wallet Error Type
+pub type WalletError = wallet::Error<Config, LedgerConnection, Signer>;
+
+/// In-Process Payment Network
+///
+/// The network owns a verifying [`Ledger`] and the proving contexts matching its verifying
+/// contexts, and hands out [`Wallet`]s which each run their own [`Signer`] against the shared
+/// ledger.
+pub struct Network {
+    /// UTXO Configuration Parameters
+    parameters: Parameters,
+
+    /// UTXO Accumulator Model
+    utxo_accumulator_model: UtxoAccumulatorModel,
+
+    /// Proving Context
+    proving_context: MultiProvingContext,
+
+    /// Shared Ledger
+    ledger: SharedLedger,
+}
+
+impl Network {
+    /// Samples new parameters, compiles the transfer circuits against them, and builds an empty
+    /// ledger which verifies posts with the resulting verifying contexts.
+    #[inline]
+    pub fn sample<R>(rng: &mut R) -> Self
+    where
+        R: CryptoRng + RngCore,
+    {
+        let parameters = rng.gen();
+        let utxo_accumulator_model = rng.gen();
+        let (proving_context, verifying_context) = generate_context(
+            &(),
+            FullParametersRef::new(&parameters, &utxo_accumulator_model),
+            rng,
+        )
+        .expect("Unable to create proving and verifying contexts.");
+        let ledger = Ledger::new(
+            utxo_accumulator_model.clone(),
+            verifying_context,
+            parameters.clone(),
+        );
+        Self {
+            parameters,
+            utxo_accumulator_model,
+            proving_context,
+            ledger: Arc::new(RwLock::new(ledger)),
+        }
+    }
+
+    /// Returns the UTXO configuration parameters of the network.
+    #[inline]
+    pub fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    /// Returns the shared ledger of the network.
+    #[inline]
+    pub fn ledger(&self) -> &SharedLedger {
+        &self.ledger
+    }
+
+    /// Opens the public `account` on the ledger holding `asset`.
+    #[inline]
+    pub async fn open_account(&self, account: AccountId, asset: Asset) {
+        self.ledger
+            .write()
+            .await
+            .set_public_balance(account, asset.id, asset.value);
+    }
+
+    /// Returns the public balance of `account` in assets with the given `id`, or zero if the
+    /// account does not exist.
+    #[inline]
+    pub async fn public_balance(&self, account: AccountId, id: &AssetId) -> AssetValue {
+        self.ledger
+            .read()
+            .await
+            .public_balances(account)
+            .map(|balances| balances.value(id))
+            .unwrap_or_default()
+    }
+
+    /// Builds the signer for the accounts derived from `seed` and starts a wallet for it which
+    /// posts to the ledger from the public `account`.
+    #[inline]
+    pub async fn wallet(&self, account: AccountId, seed: [u8; 32]) -> Result<Wallet, WalletError> {
+        Wallet::start(
+            LedgerConnection::new(account, self.ledger.clone()),
+            sample_signer_from_seed(
+                &self.proving_context,
+                &self.parameters,
+                &self.utxo_accumulator_model,
+                seed,
+            ),
+        )
+        .await
+    }
+}
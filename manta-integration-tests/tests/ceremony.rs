@@ -0,0 +1,95 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Trusted Setup Integration Tests
+
+use manta_crypto::{
+    arkworks::{bn254::Fr, ff::field_new},
+    constraint::ProofSystem as _,
+    rand::OsRng,
+};
+use manta_integration_tests::ceremony::{phase_one, product_circuit, Round};
+use manta_pay::config::ProofSystem;
+
+/// Starts a phase two round for the product circuit on top of a fresh phase one accumulator.
+#[inline]
+fn start_round() -> Round {
+    let phase_one = phase_one(&mut OsRng).expect("Phase one contributions must verify.");
+    let mut compiler = ProofSystem::context_compiler();
+    product_circuit(
+        &mut compiler,
+        field_new!(Fr, "2"),
+        field_new!(Fr, "3"),
+        field_new!(Fr, "6"),
+    );
+    Round::new(&phase_one, compiler).expect("Initializing the round must succeed.")
+}
+
+/// Tests that the keys produced by a round with two contributions prove and verify the circuit
+/// with the proof system used by the payment circuits.
+#[test]
+fn phase_two_round_produces_working_keys() {
+    let mut rng = OsRng;
+    let mut round = start_round();
+    for _ in 0..2 {
+        round
+            .contribute(&mut rng)
+            .expect("Honest contributions must be accepted.");
+    }
+    assert_eq!(round.contributions(), 2);
+    let proving_context = round.finish().expect("The round transcript must verify.");
+    let verifying_context = proving_context
+        .get_verifying_context()
+        .expect("Unable to prepare the verifying context.");
+    let mut compiler = ProofSystem::proof_compiler();
+    product_circuit(
+        &mut compiler,
+        field_new!(Fr, "2"),
+        field_new!(Fr, "3"),
+        field_new!(Fr, "6"),
+    );
+    let proof =
+        ProofSystem::prove(&proving_context, compiler, &mut rng).expect("Unable to build proof.");
+    assert!(
+        ProofSystem::verify(&verifying_context, &vec![field_new!(Fr, "6")], &proof)
+            .expect("Unable to verify proof."),
+        "The proof must verify against its public input."
+    );
+    assert!(
+        !ProofSystem::verify(&verifying_context, &vec![field_new!(Fr, "7")], &proof)
+            .expect("Unable to verify proof."),
+        "The proof must not verify against a different public input."
+    );
+}
+
+/// Tests that a contribution whose state does not match its proof is rejected without affecting
+/// the round.
+#[test]
+fn mismatched_contributions_are_rejected() {
+    let mut rng = OsRng;
+    let mut round = start_round();
+    let (state, _) = round.contribution(&mut rng);
+    let (_, proof) = round.contribution(&mut rng);
+    assert!(
+        round.submit(state, proof).is_err(),
+        "A state with a proof for another contribution must be rejected."
+    );
+    assert_eq!(round.contributions(), 0);
+    round
+        .contribute(&mut rng)
+        .expect("Honest contributions must be accepted.");
+    round.finish().expect("The round transcript must verify.");
+}
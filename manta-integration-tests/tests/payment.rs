@@ -0,0 +1,108 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Payment Integration Tests
+
+use manta_crypto::rand::{OsRng, Rand};
+use manta_integration_tests::network::Network;
+use manta_pay::config::{Asset, AssetId, Transaction};
+
+/// Tests that a deposit, a private transfer between two signers, and a withdrawal are accepted by
+/// the ledger and leave every wallet and public account with the expected balance.
+#[tokio::test]
+async fn deposit_private_transfer_and_withdraw() {
+    let mut rng = OsRng;
+    let network = Network::sample(&mut rng);
+    let asset_id: AssetId = 8u128.into();
+    let (alice_account, bob_account) = (rng.gen(), rng.gen());
+    let bob_seed = rng.gen();
+    network
+        .open_account(alice_account, Asset::new(asset_id, 1000))
+        .await;
+    network
+        .open_account(bob_account, Asset::new(asset_id, 0))
+        .await;
+    let mut alice = network
+        .wallet(alice_account, rng.gen())
+        .await
+        .expect("Starting a wallet on an empty ledger is not allowed to fail.");
+    let mut bob = network
+        .wallet(bob_account, bob_seed)
+        .await
+        .expect("Starting a wallet on an empty ledger is not allowed to fail.");
+    // 1) Alice deposits part of her public balance.
+    assert!(
+        alice
+            .post(Transaction::ToPrivate(Asset::new(asset_id, 600)), None)
+            .await
+            .expect("Error posting ToPrivate"),
+        "The ledger must accept the deposit."
+    );
+    alice.sync().await.expect("Sync error");
+    assert_eq!(alice.balance(&asset_id), 600);
+    assert_eq!(network.public_balance(alice_account, &asset_id).await, 400);
+    // 2) Alice sends some of her private assets to Bob.
+    let bob_address = bob
+        .address()
+        .await
+        .expect("Querying the local signer cannot fail.")
+        .expect("Bob's signer has accounts.");
+    assert!(
+        alice
+            .post(
+                Transaction::PrivateTransfer(Asset::new(asset_id, 250), bob_address),
+                None
+            )
+            .await
+            .expect("Error posting PrivateTransfer"),
+        "The ledger must accept the private transfer."
+    );
+    alice.sync().await.expect("Sync error");
+    bob.sync().await.expect("Sync error");
+    assert_eq!(alice.balance(&asset_id), 350);
+    assert_eq!(bob.balance(&asset_id), 250);
+    // 3) Bob withdraws to his public account and the same posts cannot be replayed.
+    let posts = bob
+        .sign(
+            Transaction::ToPublic(Asset::new(asset_id, 100), bob_account),
+            None,
+        )
+        .await
+        .expect("Error signing ToPublic")
+        .posts;
+    assert!(
+        network
+            .ledger()
+            .write()
+            .await
+            .push(bob_account, posts.clone()),
+        "The ledger must accept the withdrawal."
+    );
+    assert!(
+        !network.ledger().write().await.push(bob_account, posts),
+        "The ledger must reject a replayed withdrawal."
+    );
+    bob.sync().await.expect("Sync error");
+    assert_eq!(bob.balance(&asset_id), 150);
+    assert_eq!(network.public_balance(bob_account, &asset_id).await, 100);
+    // 4) A fresh wallet for Bob's seed recovers the same balance from the ledger.
+    let recovered = network
+        .wallet(bob_account, bob_seed)
+        .await
+        .expect("Recovering a wallet from the ledger is not allowed to fail.");
+    assert_eq!(recovered.balance(&asset_id), 150);
+    assert_eq!(recovered.checkpoint(), bob.checkpoint());
+}
@@ -170,6 +170,516 @@ pub mod github {
     }
 }
 
+/// Mirrored Data File Downloading
+///
+/// Downloads are tried against a list of [`Mirror`]s in order. Data is first written to a partial
+/// file next to the target path, so an interrupted download resumes where it stopped the next time
+/// it is attempted, and it only replaces the target once its checksum has been verified.
+#[cfg(feature = "download")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "download")))]
+pub mod mirror {
+    use super::*;
+    use anyhow::{anyhow, bail, ensure};
+    use attohttpc::{header::RANGE, StatusCode};
+    use core::fmt;
+    use std::{
+        borrow::ToOwned,
+        env,
+        ffi::OsString,
+        fs::{File, OpenOptions},
+        path::PathBuf,
+        string::String,
+        vec::Vec,
+    };
+
+    /// Environment Variable Overriding the [`cache_directory`]
+    pub const CACHE_DIRECTORY_VARIABLE: &str = "MANTA_PARAMETERS_CACHE";
+
+    /// Extension of Partially Downloaded Files
+    pub const PARTIAL_EXTENSION: &str = "part";
+
+    /// Download Mirror
+    ///
+    /// A mirror serves the data of this crate under a base URL, such that the data at `data_path`
+    /// is found at the base URL followed by `data_path`.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    pub struct Mirror {
+        /// Base URL
+        pub base_url: String,
+    }
+
+    impl Mirror {
+        /// Builds a new [`Mirror`] from `base_url`.
+        #[inline]
+        pub fn new<T>(base_url: T) -> Self
+        where
+            T: Into<String>,
+        {
+            Self {
+                base_url: base_url.into(),
+            }
+        }
+
+        /// Builds the GitHub Git-LFS [`Mirror`] for the given `branch`.
+        #[inline]
+        pub fn github_lfs(branch: &str) -> Self {
+            Self::new(github::lfs_url(branch, ""))
+        }
+
+        /// Builds the GitHub raw file storage [`Mirror`] for the given `branch`.
+        #[inline]
+        pub fn github_raw(branch: &str) -> Self {
+            Self::new(github::raw_url(branch, ""))
+        }
+
+        /// Returns the URL of the data at `data_path` on `self`.
+        #[inline]
+        pub fn url(&self, data_path: &str) -> String {
+            std::format!("{}{}", self.base_url, data_path)
+        }
+    }
+
+    impl fmt::Display for Mirror {
+        #[inline]
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", self.base_url)
+        }
+    }
+
+    /// Returns the default list of mirrors, namely the GitHub Git-LFS and raw file storage for
+    /// [`DEFAULT_BRANCH`](github::DEFAULT_BRANCH).
+    #[inline]
+    pub fn default_mirrors() -> Vec<Mirror> {
+        std::vec![
+            Mirror::github_lfs(github::DEFAULT_BRANCH),
+            Mirror::github_raw(github::DEFAULT_BRANCH),
+        ]
+    }
+
+    /// Returns the platform-appropriate directory to cache downloaded data in.
+    ///
+    /// The [`CACHE_DIRECTORY_VARIABLE`] takes precedence if it is set. Otherwise, this is the
+    /// `manta-parameters` directory inside `%LOCALAPPDATA%` on Windows, `~/Library/Caches` on
+    /// macOS and `$XDG_CACHE_HOME` or `~/.cache` elsewhere.
+    #[inline]
+    pub fn cache_directory() -> Option<PathBuf> {
+        let var = |key| env::var_os(key).filter(|value: &OsString| !value.is_empty());
+        if let Some(directory) = var(CACHE_DIRECTORY_VARIABLE) {
+            return Some(directory.into());
+        }
+        let base = if cfg!(target_os = "windows") {
+            PathBuf::from(var("LOCALAPPDATA")?)
+        } else if cfg!(target_os = "macos") {
+            PathBuf::from(var("HOME")?).join("Library").join("Caches")
+        } else {
+            match var("XDG_CACHE_HOME") {
+                Some(directory) => directory.into(),
+                _ => PathBuf::from(var("HOME")?).join(".cache"),
+            }
+        };
+        Some(base.join(github::CRATE))
+    }
+
+    /// Returns the path of the partial file used while downloading to `path`.
+    #[inline]
+    pub fn partial_path(path: &Path) -> PathBuf {
+        let mut name = path.file_name().map(ToOwned::to_owned).unwrap_or_default();
+        name.push(".");
+        name.push(PARTIAL_EXTENSION);
+        path.with_file_name(name)
+    }
+
+    /// Fetches `url` into the file at `partial`, resuming from its current length.
+    #[inline]
+    fn fetch(url: String, partial: &Path) -> Result<()> {
+        let offset = fs::metadata(partial).map(|m| m.len()).unwrap_or(0);
+        let mut request = attohttpc::get(url);
+        if offset > 0 {
+            request = request.header(RANGE, std::format!("bytes={offset}-"));
+        }
+        let response = request.send()?;
+        let mut file = match response.status() {
+            StatusCode::PARTIAL_CONTENT => OpenOptions::new().append(true).open(partial)?,
+            StatusCode::RANGE_NOT_SATISFIABLE if offset > 0 => return Ok(()),
+            status if status.is_success() => File::create(partial)?,
+            status => bail!("Unexpected response status: {status}"),
+        };
+        let written = response.write_to(&mut file)?;
+        ensure!(offset + written > 0, "Empty response");
+        Ok(())
+    }
+
+    /// Downloads data from `data_path` to a file at `path`, trying each of the `mirrors` in order
+    /// and verifying that the data matches the `checksum`.
+    ///
+    /// If a previous download was interrupted, it is resumed from the partial file. Partial data
+    /// which does not match the `checksum` once complete is discarded before trying the next
+    /// mirror, and a mirror whose data cannot be fetched or verified is skipped.
+    #[inline]
+    pub fn download<P>(
+        mirrors: &[Mirror],
+        data_path: &str,
+        path: P,
+        checksum: &[u8; 32],
+    ) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let partial = partial_path(path);
+        let mut errors = Vec::new();
+        for mirror in mirrors {
+            match fetch(mirror.url(data_path), &partial)
+                .and_then(|_| Ok(verify_file(&partial, checksum)?))
+            {
+                Ok(true) => {
+                    fs::rename(&partial, path)?;
+                    return Ok(());
+                }
+                Ok(false) => {
+                    fs::remove_file(&partial)?;
+                    errors.push(std::format!("{mirror}: Checksum did not match."));
+                }
+                Err(err) => errors.push(std::format!("{mirror}: {err}")),
+            }
+        }
+        Err(anyhow!(
+            "Unable to download {data_path:?} from any mirror: {errors:?}"
+        ))
+    }
+}
+
+/// Compressed Data Containers
+///
+/// Proving keys are distributed as a container of named sections, where every section is stored
+/// as a single zstd frame together with the length and checksum of its uncompressed data. The
+/// layout of a container is
+///
+/// ```text
+/// MAGIC || VERSION || section* || 0u16
+/// section = len(name) as u16 || name || uncompressed_len as u64 || compressed_len as u64
+///           || checksum || zstd_frame
+/// ```
+///
+/// where all integers are little-endian, `name` is non-empty UTF-8 and `checksum` is the
+/// [`checksum`](super::checksum) of the uncompressed data. Sections can be read one at a time
+/// without buffering the whole container, and their checksums are verified as soon as they have
+/// been read completely.
+#[cfg(feature = "compression")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "compression")))]
+pub mod container {
+    use std::{
+        io::{self, Read, Write},
+        string::String,
+        vec,
+    };
+
+    /// Container Magic Bytes
+    pub const MAGIC: [u8; 4] = *b"MPCZ";
+
+    /// Container Format Version
+    pub const VERSION: u8 = 1;
+
+    /// Default zstd Compression Level
+    pub const DEFAULT_LEVEL: i32 = zstd::DEFAULT_COMPRESSION_LEVEL;
+
+    /// Returns an [`InvalidData`](io::ErrorKind::InvalidData) error with the given `message`.
+    #[inline]
+    fn invalid_data(message: &str) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, message)
+    }
+
+    /// Section Header
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    pub struct SectionHeader {
+        /// Section Name
+        pub name: String,
+
+        /// Length of the Uncompressed Data
+        pub uncompressed_len: u64,
+
+        /// Length of the zstd Frame
+        pub compressed_len: u64,
+
+        /// Checksum of the Uncompressed Data
+        pub checksum: [u8; 32],
+    }
+
+    /// Container Writer
+    pub struct ContainerWriter<W>
+    where
+        W: Write,
+    {
+        /// Underlying Writer
+        writer: W,
+
+        /// Compression Level
+        level: i32,
+    }
+
+    impl<W> ContainerWriter<W>
+    where
+        W: Write,
+    {
+        /// Starts a new container in `writer`, compressing sections with the [`DEFAULT_LEVEL`].
+        #[inline]
+        pub fn new(writer: W) -> io::Result<Self> {
+            Self::with_level(writer, DEFAULT_LEVEL)
+        }
+
+        /// Starts a new container in `writer`, compressing sections with the zstd `level`.
+        #[inline]
+        pub fn with_level(mut writer: W, level: i32) -> io::Result<Self> {
+            writer.write_all(&MAGIC)?;
+            writer.write_all(&[VERSION])?;
+            Ok(Self { writer, level })
+        }
+
+        /// Compresses `data` and appends it to the container as the section called `name`.
+        #[inline]
+        pub fn write_section(&mut self, name: &str, data: &[u8]) -> io::Result<()> {
+            let name_len = u16::try_from(name.len())
+                .ok()
+                .filter(|len| *len > 0)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "Section names must have between 1 and 65535 bytes.",
+                    )
+                })?;
+            let frame = zstd::stream::encode_all(data, self.level)?;
+            self.writer.write_all(&name_len.to_le_bytes())?;
+            self.writer.write_all(name.as_bytes())?;
+            self.writer.write_all(&(data.len() as u64).to_le_bytes())?;
+            self.writer.write_all(&(frame.len() as u64).to_le_bytes())?;
+            self.writer.write_all(&super::checksum(data))?;
+            self.writer.write_all(&frame)
+        }
+
+        /// Writes the end marker of the container and returns the underlying writer.
+        #[inline]
+        pub fn finish(mut self) -> io::Result<W> {
+            self.writer.write_all(&0u16.to_le_bytes())?;
+            self.writer.flush()?;
+            Ok(self.writer)
+        }
+    }
+
+    /// Container Reader
+    pub struct ContainerReader<R>
+    where
+        R: Read,
+    {
+        /// Underlying Reader
+        reader: R,
+
+        /// Number of Bytes left in the Current zstd Frame
+        pending: u64,
+
+        /// End Marker Flag
+        finished: bool,
+    }
+
+    impl<R> ContainerReader<R>
+    where
+        R: Read,
+    {
+        /// Opens the container in `reader`, checking its magic bytes and version.
+        #[inline]
+        pub fn new(mut reader: R) -> io::Result<Self> {
+            let mut prefix = [0; 5];
+            reader.read_exact(&mut prefix)?;
+            if prefix[..4] != MAGIC {
+                return Err(invalid_data("Missing container magic bytes."));
+            }
+            if prefix[4] != VERSION {
+                return Err(invalid_data("Unsupported container version."));
+            }
+            Ok(Self {
+                reader,
+                pending: 0,
+                finished: false,
+            })
+        }
+
+        /// Reads an unsigned little-endian integer of `N` bytes.
+        #[inline]
+        fn read_bytes<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+            let mut bytes = [0; N];
+            self.reader.read_exact(&mut bytes)?;
+            Ok(bytes)
+        }
+
+        /// Returns the next section of the container, or `None` once the end marker has been
+        /// reached. The unread part of the previous section is skipped without being verified.
+        #[inline]
+        pub fn next_section(&mut self) -> io::Result<Option<Section<'_, R>>> {
+            if self.finished {
+                return Ok(None);
+            }
+            if self.pending > 0 {
+                io::copy(&mut (&mut self.reader).take(self.pending), &mut io::sink())?;
+                self.pending = 0;
+            }
+            let name_len = u16::from_le_bytes(self.read_bytes()?);
+            if name_len == 0 {
+                self.finished = true;
+                return Ok(None);
+            }
+            let mut name = vec![0; name_len.into()];
+            self.reader.read_exact(&mut name)?;
+            let name = String::from_utf8(name)
+                .map_err(|_| invalid_data("Section names must be valid UTF-8."))?;
+            let header = SectionHeader {
+                name,
+                uncompressed_len: u64::from_le_bytes(self.read_bytes()?),
+                compressed_len: u64::from_le_bytes(self.read_bytes()?),
+                checksum: self.read_bytes()?,
+            };
+            self.pending = header.compressed_len;
+            Ok(Some(Section {
+                decoder: zstd::stream::read::Decoder::new(Frame { container: self })?,
+                hasher: blake3::Hasher::new(),
+                read: 0,
+                verified: false,
+                header,
+            }))
+        }
+
+        /// Returns the next section of the container, failing if it is missing or if it is not
+        /// called `name`.
+        #[inline]
+        pub fn expect_section(&mut self, name: &str) -> io::Result<Section<'_, R>> {
+            match self.next_section()? {
+                Some(section) if section.header().name == name => Ok(section),
+                Some(_) => Err(invalid_data("Unexpected container section.")),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "Missing container section.",
+                )),
+            }
+        }
+
+        /// Returns the underlying reader.
+        #[inline]
+        pub fn into_inner(self) -> R {
+            self.reader
+        }
+    }
+
+    /// Compressed Frame Reader
+    struct Frame<'c, R>
+    where
+        R: Read,
+    {
+        /// Container Reader
+        container: &'c mut ContainerReader<R>,
+    }
+
+    impl<'c, R> Read for Frame<'c, R>
+    where
+        R: Read,
+    {
+        #[inline]
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let limit = buf
+                .len()
+                .min(usize::try_from(self.container.pending).unwrap_or(usize::MAX));
+            if limit == 0 {
+                return Ok(0);
+            }
+            let read = self.container.reader.read(&mut buf[..limit])?;
+            if read == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "Truncated container section.",
+                ));
+            }
+            self.container.pending -= read as u64;
+            Ok(read)
+        }
+    }
+
+    /// Container Section
+    ///
+    /// Reading from a section yields its uncompressed data. Once the end of the data has been
+    /// reached, its length and checksum are checked against the [`SectionHeader`], and a mismatch
+    /// is reported as an [`InvalidData`](io::ErrorKind::InvalidData) error.
+    pub struct Section<'c, R>
+    where
+        R: Read,
+    {
+        /// Section Header
+        header: SectionHeader,
+
+        /// Frame Decoder
+        decoder: zstd::stream::read::Decoder<'static, io::BufReader<Frame<'c, R>>>,
+
+        /// Checksum of the Data Read so Far
+        hasher: blake3::Hasher,
+
+        /// Number of Bytes Read so Far
+        read: u64,
+
+        /// Verification Flag
+        verified: bool,
+    }
+
+    impl<'c, R> Section<'c, R>
+    where
+        R: Read,
+    {
+        /// Returns the header of `self`.
+        #[inline]
+        pub fn header(&self) -> &SectionHeader {
+            &self.header
+        }
+
+        /// Checks that all the data in `self` has been read and that its checksum matches.
+        #[inline]
+        pub fn finish(mut self) -> io::Result<()> {
+            if self.read(&mut [0])? != 0 {
+                return Err(invalid_data("Unread data left in the container section."));
+            }
+            Ok(())
+        }
+    }
+
+    impl<'c, R> Read for Section<'c, R>
+    where
+        R: Read,
+    {
+        #[inline]
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if buf.is_empty() {
+                return Ok(0);
+            }
+            let read = self.decoder.read(buf)?;
+            self.read += read as u64;
+            if self.read > self.header.uncompressed_len {
+                return Err(invalid_data("Container section is longer than declared."));
+            }
+            if read == 0 && !self.verified {
+                if self.read != self.header.uncompressed_len {
+                    return Err(invalid_data("Container section is shorter than declared."));
+                }
+                if <[u8; 32]>::from(self.hasher.finalize()) != self.header.checksum {
+                    return Err(invalid_data("Container section checksum mismatch."));
+                }
+                self.verified = true;
+            }
+            self.hasher.update(&buf[..read]);
+            Ok(read)
+        }
+    }
+}
+
+/// Computes the checksum of `data` in the same format as the checksums in this crate.
+#[inline]
+pub fn checksum(data: &[u8]) -> [u8; 32] {
+    blake3::hash(data).into()
+}
+
 /// Verifies the `data` against the `checksum`.
 #[inline]
 pub fn verify(data: &[u8], checksum: &[u8; 32]) -> bool {
@@ -257,6 +767,9 @@ macro_rules! define_dat {
 #[cfg(feature = "download")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "download")))]
 pub trait Download: HasChecksum {
+    /// Path of the Data relative to the Root of this Crate
+    const DATA_PATH: &'static str;
+
     /// Downloads the data for this type from GitHub. This method automatically verifies the
     /// checksum while downloading. See [`github::download`] for more.
     fn download<P>(path: P) -> Result<()>
@@ -277,6 +790,48 @@ pub trait Download: HasChecksum {
             _ => Self::download(path),
         }
     }
+
+    /// Downloads the data for this type from the first of the `mirrors` which serves it, resuming
+    /// any partial download. See [`mirror::download`] for more.
+    #[inline]
+    fn download_from_mirrors<P>(mirrors: &[mirror::Mirror], path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        mirror::download(mirrors, Self::DATA_PATH, path, Self::CHECKSUM)
+    }
+
+    /// Checks if the data for this type at the given `path` matches the [`CHECKSUM`] and if not,
+    /// then it downloads it from the `mirrors`. See [`mirror::download`] for more.
+    ///
+    /// [`CHECKSUM`]: HasChecksum::CHECKSUM
+    #[inline]
+    fn download_from_mirrors_if_invalid<P>(mirrors: &[mirror::Mirror], path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        match verify_file(&path, Self::CHECKSUM) {
+            Ok(true) => Ok(()),
+            _ => Self::download_from_mirrors(mirrors, path),
+        }
+    }
+
+    /// Returns the path of the data for this type inside the [`cache_directory`], downloading it
+    /// from the `mirrors` first if it is missing or does not match the [`CHECKSUM`].
+    ///
+    /// [`cache_directory`]: mirror::cache_directory
+    /// [`CHECKSUM`]: HasChecksum::CHECKSUM
+    #[inline]
+    fn cached(mirrors: &[mirror::Mirror]) -> Result<std::path::PathBuf> {
+        let path = mirror::cache_directory()
+            .ok_or_else(|| anyhow::anyhow!("Unable to determine the cache directory."))?
+            .join(Self::DATA_PATH.trim_start_matches('/'));
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        Self::download_from_mirrors_if_invalid(mirrors, &path)?;
+        Ok(path)
+    }
 }
 
 /// Defines a data marker type for download-required data from GitHub LFS and checksum from disk.
@@ -294,6 +849,8 @@ macro_rules! define_lfs {
         #[cfg(feature = "download")]
         #[cfg_attr(doc_cfg, doc(cfg(feature = "download")))]
         impl $crate::Download for $name {
+            const DATA_PATH: &'static str = concat!("/data/", $path, ".lfs");
+
             #[inline]
             fn download<P>(path: P) -> $crate::Result<()>
             where
@@ -301,7 +858,7 @@ macro_rules! define_lfs {
             {
                 $crate::github::download(
                     $crate::github::DEFAULT_BRANCH,
-                    concat!("/data/", $path, ".lfs"),
+                    <Self as $crate::Download>::DATA_PATH,
                     path,
                     <Self as $crate::HasChecksum>::CHECKSUM,
                 )
@@ -415,15 +972,68 @@ pub mod pay {
 mod test {
     use super::*;
     use anyhow::{anyhow, bail};
+    use container::{ContainerReader, ContainerWriter};
     use hex::FromHex;
     use std::{
+        borrow::ToOwned,
         collections::HashMap,
         fs::{File, OpenOptions},
         io::{BufRead, BufReader, Read},
         path::PathBuf,
         println,
+        string::String,
+        vec,
+        vec::Vec,
     };
 
+    /// Checks that sections round-trip through a container, that they can be skipped, and that
+    /// corrupted sections are rejected.
+    #[test]
+    fn container_round_trip() -> Result<()> {
+        let sections = [
+            ("zeros", vec![0u8; 1 << 16]),
+            ("empty", Vec::new()),
+            ("data", (0..1000u32).flat_map(u32::to_le_bytes).collect()),
+        ];
+        let mut writer = ContainerWriter::new(Vec::new())?;
+        for (name, data) in &sections {
+            writer.write_section(name, data)?;
+        }
+        let container = writer.finish()?;
+        assert!(container.len() < sections.iter().map(|(_, data)| data.len()).sum());
+        let mut reader = ContainerReader::new(container.as_slice())?;
+        for (name, data) in &sections {
+            let mut section = reader.expect_section(name)?;
+            let mut decoded = Vec::new();
+            section.read_to_end(&mut decoded)?;
+            assert_eq!(&decoded, data);
+            section.finish()?;
+        }
+        assert!(reader.next_section()?.is_none());
+        let mut reader = ContainerReader::new(container.as_slice())?;
+        reader.next_section()?;
+        reader.next_section()?;
+        let mut section = reader.expect_section("data")?;
+        let mut decoded = Vec::new();
+        section.read_to_end(&mut decoded)?;
+        assert_eq!(decoded, sections[2].1);
+        let mut corrupted = container.clone();
+        let checksum_offset = container::MAGIC.len() + 1 + 2 + "zeros".len() + 16;
+        corrupted[checksum_offset] ^= 1;
+        let mut reader = ContainerReader::new(corrupted.as_slice())?;
+        let mut section = reader.expect_section("zeros")?;
+        assert!(section.read_to_end(&mut Vec::new()).is_err());
+        let truncated = &container[..container.len() / 2];
+        let mut reader = ContainerReader::new(truncated)?;
+        let mut section = reader.expect_section("zeros")?;
+        assert!(section.read_to_end(&mut Vec::new()).is_ok());
+        section.finish()?;
+        reader.expect_section("empty")?.finish()?;
+        let mut section = reader.expect_section("data")?;
+        assert!(section.read_to_end(&mut Vec::new()).is_err());
+        Ok(())
+    }
+
     /// Checks if two files `lhs` and `rhs` have equal content.
     #[inline]
     fn equal_files(lhs: &mut File, rhs: &mut File) -> Result<bool> {
@@ -481,6 +1091,77 @@ mod test {
             .ok_or_else(|| anyhow!("Unable to get checksum for path: {path:?}"))
     }
 
+    /// Serves `data` over HTTP on a local port for `connections` requests, honoring `Range` headers,
+    /// and returns the base URL and the list of requested ranges.
+    #[inline]
+    fn serve(
+        data: Vec<u8>,
+        connections: usize,
+    ) -> (String, std::thread::JoinHandle<Vec<Option<String>>>) {
+        use std::{io::Write, net::TcpListener};
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = std::format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut ranges = Vec::new();
+            for stream in listener.incoming().take(connections) {
+                let mut stream = stream.unwrap();
+                let mut range = None;
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some(value) = line.to_lowercase().strip_prefix("range: bytes=") {
+                        range = Some(value.trim_end_matches('-').to_owned());
+                    }
+                }
+                let offset = range.as_ref().map_or(0, |r| r.parse::<usize>().unwrap());
+                let status = if range.is_some() {
+                    "206 Partial Content"
+                } else {
+                    "200 OK"
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    data.len() - offset
+                )
+                .unwrap();
+                stream.write_all(&data[offset..]).unwrap();
+                ranges.push(range);
+            }
+            ranges
+        });
+        (url, handle)
+    }
+
+    /// Checks that mirrored downloads skip failing mirrors, resume partial files and verify the
+    /// checksum.
+    #[test]
+    fn mirrored_download_resumes() -> Result<()> {
+        let data = (0..4096u32).map(|i| i as u8).collect::<Vec<_>>();
+        let expected = checksum(&data);
+        let (url, server) = serve(data.clone(), 1);
+        let directory = tempfile::tempdir()?;
+        let path = directory.path().join("data.lfs");
+        fs::write(mirror::partial_path(&path), &data[..1000])?;
+        let mirrors = [
+            mirror::Mirror::new("http://127.0.0.1:1"),
+            mirror::Mirror::new(url),
+        ];
+        mirror::download(&mirrors, "/data.lfs", &path, &expected)?;
+        assert_eq!(fs::read(&path)?, data);
+        assert!(!mirror::partial_path(&path).exists());
+        assert_eq!(server.join().unwrap(), [Some("1000".into())]);
+        let (url, server) = serve(data, 1);
+        assert!(
+            mirror::download(&[mirror::Mirror::new(url)], "/data.lfs", &path, &[0; 32]).is_err()
+        );
+        assert!(!mirror::partial_path(&path).exists());
+        assert_eq!(server.join().unwrap(), [None]);
+        Ok(())
+    }
+
     /// Downloads all data from GitHub and checks if they are the same as the data known locally to
     /// this Rust crate.
     #[ignore] // NOTE: We use this so that CI doesn't run this test while still allowing developers to test.
@@ -0,0 +1,419 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Manta Pay C Foreign Function Interface
+//!
+//! This crate exposes the Manta Pay [`Signer`] through `extern "C"` functions so that wallets
+//! written in Swift, Kotlin, C or C++ can embed it by linking against the static or dynamic
+//! library built from this crate, with the header generated by `cbindgen`.
+//!
+//! # Protocol
+//!
+//! Requests and responses cross the boundary as NUL-terminated UTF-8 JSON strings which use the
+//! same `serde` representation as the HTTP and WebSocket signer clients. Every function that
+//! returns a string returns a JSON envelope which is either `{"Ok": <value>}` or
+//! `{"Err": "<message>"}`, where `Err` is reserved for errors of the interface itself, like a null
+//! pointer or malformed JSON. Errors reported by the signer are part of `<value>`, for example
+//! [`SyncResult`] and [`SignResult`] are themselves serialized as `{"Ok": ...}` or
+//! `{"Err": ...}`.
+//!
+//! Strings returned by this library are owned by the caller and must be released with
+//! [`manta_string_free`]. Signers must be released with [`manta_signer_free`].
+//!
+//! # Threading
+//!
+//! A [`MantaSigner`] is not synchronized, so the caller must not use the same signer from more
+//! than one thread at a time.
+//!
+//! [`SyncResult`]: manta_pay::signer::SyncResult
+//! [`SignResult`]: manta_pay::signer::SignResult
+
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+#![forbid(rustdoc::broken_intra_doc_links)]
+#![forbid(missing_docs)]
+
+use manta_accounting::wallet::signer::EvictionPolicy;
+use manta_crypto::{accumulator::Accumulator, rand::FromEntropy};
+use manta_pay::{
+    key::Mnemonic,
+    parameters::{load_transfer_parameters, load_utxo_accumulator_model, proving_context_loader},
+    signer::{
+        base::Signer,
+        functions::{
+            accounts_from_mnemonic, address_from_mnemonic, authorization_context_from_mnemonic,
+            get_storage,
+        },
+        SignRequest, StorageStateOption, SyncRequest,
+    },
+};
+use manta_util::serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    ptr,
+};
+
+/// Signer Creation Request
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct CreateSignerRequest {
+    /// Mnemonic Phrase of the Signer Accounts
+    pub mnemonic: Mnemonic,
+
+    /// Directory where the proving contexts are downloaded to the first time they are needed
+    pub proving_context_directory: PathBuf,
+
+    /// Storage State to restore, as returned by [`manta_signer_storage_state`]
+    #[serde(default)]
+    pub storage_state: StorageStateOption,
+}
+
+/// Signer Handle
+///
+/// This type is opaque to C callers, which only ever see pointers to it.
+pub struct MantaSigner(Signer);
+
+impl MantaSigner {
+    /// Builds a new [`MantaSigner`] from `request`.
+    #[inline]
+    pub fn new(request: CreateSignerRequest) -> Self {
+        let mut signer = Signer::from_loader(
+            load_transfer_parameters(),
+            proving_context_loader(request.proving_context_directory),
+            EvictionPolicy::default(),
+            Accumulator::empty(&load_utxo_accumulator_model()),
+            FromEntropy::from_entropy(),
+        );
+        if let Some(state) = &request.storage_state {
+            state.update_signer(&mut signer);
+        }
+        let authorization_context = authorization_context_from_mnemonic(
+            request.mnemonic.clone(),
+            signer.transfer_parameters(),
+        );
+        signer.load_accounts(accounts_from_mnemonic(request.mnemonic));
+        signer.load_authorization_context(authorization_context);
+        Self(signer)
+    }
+
+    /// Returns a shared reference to the underlying [`Signer`].
+    #[inline]
+    pub fn signer(&self) -> &Signer {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the underlying [`Signer`].
+    #[inline]
+    pub fn signer_mut(&mut self) -> &mut Signer {
+        &mut self.0
+    }
+}
+
+/// Interface Result Type
+type Result<T> = core::result::Result<T, String>;
+
+/// Converts `string` into a raw C string owned by the caller.
+#[inline]
+fn into_raw_string(string: String) -> *mut c_char {
+    CString::new(string)
+        .expect("JSON strings never contain interior NUL bytes.")
+        .into_raw()
+}
+
+/// Runs `f`, catching any panic, and serializes its result into a raw JSON envelope.
+#[inline]
+fn respond<T, F>(f: F) -> *mut c_char
+where
+    T: Serialize,
+    F: FnOnce() -> Result<T>,
+{
+    let response = panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|_| Err("The signer panicked while handling the request.".into()));
+    into_raw_string(serde_json::to_string(&response).unwrap_or_else(|err| {
+        serde_json::to_string(&Result::<()>::Err(format!(
+            "Unable to serialize the response: {err}"
+        )))
+        .expect("Serializing a string is not allowed to fail.")
+    }))
+}
+
+/// Reads the JSON string `request` and deserializes it into a `T`.
+///
+/// # Safety
+///
+/// If `request` is not null it must point to a valid NUL-terminated string.
+#[inline]
+unsafe fn parse<T>(request: *const c_char) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    if request.is_null() {
+        return Err("The request is a null pointer.".into());
+    }
+    let request = CStr::from_ptr(request)
+        .to_str()
+        .map_err(|err| format!("The request is not valid UTF-8: {err}"))?;
+    serde_json::from_str(request).map_err(|err| format!("The request is not valid JSON: {err}"))
+}
+
+/// Returns a mutable reference to the signer behind `signer`.
+///
+/// # Safety
+///
+/// If `signer` is not null it must have been returned by [`manta_signer_new`] and not yet released.
+#[inline]
+unsafe fn signer_mut<'s>(signer: *mut MantaSigner) -> Result<&'s mut MantaSigner> {
+    signer
+        .as_mut()
+        .ok_or_else(|| "The signer is a null pointer.".into())
+}
+
+/// Builds a new signer from the JSON-encoded [`CreateSignerRequest`] in `request`.
+///
+/// Returns a null pointer on failure, in which case, if `error` is not null, `*error` is set to a
+/// string describing the failure which must be released with [`manta_string_free`].
+///
+/// # Safety
+///
+/// `request` must be null or point to a valid NUL-terminated string, and `error` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn manta_signer_new(
+    request: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut MantaSigner {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        parse::<CreateSignerRequest>(request).map(MantaSigner::new)
+    }))
+    .unwrap_or_else(|_| Err("The signer panicked while being created.".into()));
+    match result {
+        Ok(signer) => Box::into_raw(Box::new(signer)),
+        Err(err) => {
+            if !error.is_null() {
+                *error = into_raw_string(err);
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Releases `signer`. Passing a null pointer does nothing.
+///
+/// # Safety
+///
+/// `signer` must be null or have been returned by [`manta_signer_new`] and not yet released.
+#[no_mangle]
+pub unsafe extern "C" fn manta_signer_free(signer: *mut MantaSigner) {
+    if !signer.is_null() {
+        drop(Box::from_raw(signer));
+    }
+}
+
+/// Synchronizes `signer` with the JSON-encoded [`SyncRequest`] in `request`, returning the
+/// JSON-encoded [`SyncResult`](manta_pay::signer::SyncResult) in the response envelope.
+///
+/// # Safety
+///
+/// `signer` must be null or a live pointer returned by [`manta_signer_new`], and `request` must be
+/// null or point to a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn manta_signer_sync(
+    signer: *mut MantaSigner,
+    request: *const c_char,
+) -> *mut c_char {
+    respond(|| {
+        let signer = signer_mut(signer)?;
+        let request = parse::<SyncRequest>(request)?;
+        Ok(signer.signer_mut().sync(request))
+    })
+}
+
+/// Signs the transaction in the JSON-encoded [`SignRequest`] in `request` with `signer`,
+/// returning the JSON-encoded [`SignResult`](manta_pay::signer::SignResult) in the response
+/// envelope.
+///
+/// The proving context for the transaction shape is downloaded into the proving context
+/// directory of the signer the first time it is needed.
+///
+/// # Safety
+///
+/// `signer` must be null or a live pointer returned by [`manta_signer_new`], and `request` must be
+/// null or point to a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn manta_signer_sign(
+    signer: *mut MantaSigner,
+    request: *const c_char,
+) -> *mut c_char {
+    respond(|| {
+        let signer = signer_mut(signer)?;
+        let request = parse::<SignRequest>(request)?;
+        let signer = signer.signer_mut();
+        Ok(signer
+            .resolve_request(request)
+            .and_then(|transaction| signer.sign(transaction)))
+    })
+}
+
+/// Returns the JSON-encoded default address of `signer` in the response envelope.
+///
+/// # Safety
+///
+/// `signer` must be null or a live pointer returned by [`manta_signer_new`].
+#[no_mangle]
+pub unsafe extern "C" fn manta_signer_address(signer: *mut MantaSigner) -> *mut c_char {
+    respond(|| {
+        signer_mut(signer)?
+            .signer_mut()
+            .address()
+            .ok_or_else(|| "The signer has no accounts loaded.".into())
+    })
+}
+
+/// Returns the JSON-encoded storage state of `signer` in the response envelope, which can be
+/// persisted by the host and passed back in [`CreateSignerRequest::storage_state`] to avoid
+/// synchronizing from scratch.
+///
+/// # Safety
+///
+/// `signer` must be null or a live pointer returned by [`manta_signer_new`].
+#[no_mangle]
+pub unsafe extern "C" fn manta_signer_storage_state(signer: *mut MantaSigner) -> *mut c_char {
+    respond(|| Ok(get_storage(signer_mut(signer)?.signer())))
+}
+
+/// Derives the default address of the JSON-encoded mnemonic phrase in `mnemonic` without building
+/// a signer, returning the JSON-encoded address in the response envelope.
+///
+/// # Safety
+///
+/// `mnemonic` must be null or point to a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn manta_address_from_mnemonic(mnemonic: *const c_char) -> *mut c_char {
+    respond(|| {
+        Ok(address_from_mnemonic(
+            parse::<Mnemonic>(mnemonic)?,
+            &load_transfer_parameters(),
+        ))
+    })
+}
+
+/// Releases a string returned by this library. Passing a null pointer does nothing.
+///
+/// # Safety
+///
+/// `string` must be null or have been returned by this library and not yet released.
+#[no_mangle]
+pub unsafe extern "C" fn manta_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use manta_pay::{config::Address, signer::SyncResult};
+
+    /// Test Mnemonic Phrase
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    /// Takes ownership of the response `string` and deserializes its envelope.
+    #[inline]
+    fn take<T>(string: *mut c_char) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        assert!(!string.is_null(), "Responses are never null.");
+        let response = unsafe { CStr::from_ptr(string) }
+            .to_str()
+            .expect("Responses are valid UTF-8.")
+            .to_owned();
+        unsafe { manta_string_free(string) };
+        serde_json::from_str(&response).expect("Responses are valid JSON envelopes.")
+    }
+
+    /// Builds a signer through the C interface from `request`.
+    #[inline]
+    fn new_signer(request: &CreateSignerRequest) -> *mut MantaSigner {
+        let request = CString::new(serde_json::to_string(request).unwrap()).unwrap();
+        let mut error = ptr::null_mut();
+        let signer = unsafe { manta_signer_new(request.as_ptr(), &mut error) };
+        assert!(error.is_null(), "Signer creation failed.");
+        assert!(!signer.is_null());
+        signer
+    }
+
+    /// Checks that a signer can be created, synchronized, persisted and restored through the C
+    /// interface, and that it derives the same address as the mnemonic phrase.
+    #[test]
+    fn signer_round_trip() {
+        let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let mut request = CreateSignerRequest {
+            mnemonic: Mnemonic::new(MNEMONIC).expect("The mnemonic phrase is valid."),
+            proving_context_directory: directory.path().to_owned(),
+            storage_state: None,
+        };
+        let signer = new_signer(&request);
+        let address = take::<Address>(unsafe { manta_signer_address(signer) })
+            .expect("The signer has an address.");
+        let mnemonic = CString::new(format!("{MNEMONIC:?}")).unwrap();
+        assert_eq!(
+            take::<Address>(unsafe { manta_address_from_mnemonic(mnemonic.as_ptr()) }),
+            Ok(address),
+        );
+        let sync_request = CString::new(
+            serde_json::to_string(&SyncRequest {
+                origin_checkpoint: Default::default(),
+                data: Default::default(),
+                height: None,
+            })
+            .unwrap(),
+        )
+        .unwrap();
+        take::<SyncResult>(unsafe { manta_signer_sync(signer, sync_request.as_ptr()) })
+            .expect("The request is well-formed.")
+            .expect("Synchronizing with empty data is not allowed to fail.");
+        request.storage_state = take(unsafe { manta_signer_storage_state(signer) })
+            .expect("The storage state is always available.");
+        unsafe { manta_signer_free(signer) };
+        let restored = new_signer(&request);
+        assert_eq!(
+            take::<Address>(unsafe { manta_signer_address(restored) }),
+            Ok(address),
+        );
+        unsafe { manta_signer_free(restored) };
+    }
+
+    /// Checks that malformed requests and null pointers are reported as errors instead of
+    /// crashing the host.
+    #[test]
+    fn malformed_requests_are_errors() {
+        let invalid = CString::new("{").unwrap();
+        let mut error = ptr::null_mut();
+        assert!(unsafe { manta_signer_new(invalid.as_ptr(), &mut error) }.is_null());
+        assert!(!error.is_null());
+        unsafe { manta_string_free(error) };
+        assert!(unsafe { manta_signer_new(ptr::null(), ptr::null_mut()) }.is_null());
+        assert!(take::<SyncResult>(unsafe {
+            manta_signer_sync(ptr::null_mut(), invalid.as_ptr())
+        })
+        .is_err());
+        assert!(take::<Address>(unsafe { manta_address_from_mnemonic(invalid.as_ptr()) }).is_err());
+    }
+}
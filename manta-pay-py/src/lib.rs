@@ -0,0 +1,325 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Manta Pay Python Bindings
+//!
+//! This crate builds the `manta_pay_py` Python extension module with [`pyo3`], so that research
+//! and operations scripts can load the protocol parameters, derive addresses, build
+//! [`TransferPost`]s and verify their proofs without reimplementing the serialization in Python.
+//!
+//! # Protocol
+//!
+//! Addresses, assets, [`TransferPost`]s and signer requests cross the boundary as JSON strings
+//! which use the same `serde` representation as the HTTP and WebSocket signer clients, so they can
+//! be handled with the `json` module of the Python standard library. Malformed inputs raise a
+//! `ValueError`.
+//!
+//! # Threading
+//!
+//! A [`Signer`] is bound to the Python thread which created it and raises an error if it is used
+//! from any other thread.
+
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+#![forbid(rustdoc::broken_intra_doc_links)]
+#![forbid(missing_docs)]
+
+use core::fmt::Display;
+use manta_accounting::{transfer::canonical::TransferShape, wallet::signer::EvictionPolicy};
+use manta_crypto::{
+    accumulator::Accumulator,
+    rand::{FromEntropy, OsRng},
+};
+use manta_pay::{
+    config::{
+        self, Address, Asset, FullParametersRef, MultiProvingContext, ProvingContext, ToPrivate,
+        TransferPost, UtxoAccumulatorModel,
+    },
+    key::Mnemonic,
+    parameters::{
+        load_transfer_parameters, load_utxo_accumulator_model, proving_context_loader,
+        try_load_proving_context, ParametersVersion, VersionedVerifyingContexts,
+    },
+    signer::{
+        base,
+        functions::{
+            accounts_from_mnemonic, address_from_mnemonic, authorization_context_from_mnemonic,
+            get_storage,
+        },
+        SignRequest, StorageStateOption, SyncRequest,
+    },
+};
+use manta_util::{
+    codec::{Decode, IoReader},
+    serde::{de::DeserializeOwned, Serialize},
+};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::{fs::File, path::PathBuf};
+
+/// Converts `error` into a Python `ValueError`.
+#[inline]
+fn value_error<E>(error: E) -> PyErr
+where
+    E: Display,
+{
+    PyValueError::new_err(error.to_string())
+}
+
+/// Deserializes the JSON string `value` into a `T`.
+#[inline]
+fn parse<T>(value: &str) -> PyResult<T>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_str(value).map_err(value_error)
+}
+
+/// Serializes `value` into a JSON string.
+#[inline]
+fn to_json<T>(value: &T) -> PyResult<String>
+where
+    T: Serialize,
+{
+    serde_json::to_string(value).map_err(value_error)
+}
+
+/// Parses the plain-text `mnemonic` phrase.
+#[inline]
+fn parse_mnemonic(mnemonic: &str) -> PyResult<Mnemonic> {
+    Mnemonic::new(mnemonic).map_err(|err| value_error(format!("{err:?}")))
+}
+
+/// Decodes the proving context stored in the file at `path`.
+#[inline]
+fn decode_proving_context(path: PathBuf) -> PyResult<ProvingContext> {
+    let file = File::open(&path)
+        .map_err(|err| value_error(format!("Unable to open {}: {err}", path.display())))?;
+    ProvingContext::decode(IoReader(file))
+        .map_err(|err| value_error(format!("Unable to decode {}: {err:?}", path.display())))
+}
+
+/// Protocol Parameters
+///
+/// Holds the transfer parameters, the UTXO accumulator model and the verifying contexts of every
+/// known [`ParametersVersion`], all of which are embedded in the library.
+#[pyclass(module = "manta_pay_py")]
+pub struct Parameters {
+    /// Transfer Parameters
+    parameters: config::Parameters,
+
+    /// UTXO Accumulator Model
+    utxo_accumulator_model: UtxoAccumulatorModel,
+
+    /// Verifying Contexts
+    verifying_contexts: VersionedVerifyingContexts,
+}
+
+#[pymethods]
+impl Parameters {
+    /// Loads the latest protocol parameters.
+    #[new]
+    pub fn new() -> Self {
+        Self {
+            parameters: load_transfer_parameters(),
+            utxo_accumulator_model: load_utxo_accumulator_model(),
+            verifying_contexts: VersionedVerifyingContexts::load_all(),
+        }
+    }
+
+    /// Returns the JSON-encoded default address of the plain-text `mnemonic` phrase.
+    pub fn address_from_mnemonic(&self, mnemonic: &str) -> PyResult<String> {
+        to_json(&address_from_mnemonic(
+            parse_mnemonic(mnemonic)?,
+            &self.parameters,
+        ))
+    }
+
+    /// Returns the name of the shape of the JSON-encoded transfer `post`, or `None` if the post
+    /// does not have a canonical shape.
+    pub fn post_shape(&self, post: &str) -> PyResult<Option<String>> {
+        Ok(TransferShape::from_post(&parse::<TransferPost>(post)?)
+            .map(|shape| format!("{shape:?}")))
+    }
+
+    /// Returns the name of the newest parameters version under which the JSON-encoded transfer
+    /// `post` has a valid proof, or `None` if no version accepts it.
+    pub fn detect_version(&self, post: &str) -> PyResult<Option<String>> {
+        Ok(self
+            .verifying_contexts
+            .detect_post(&parse(post)?)
+            .map(|version| format!("{version:?}")))
+    }
+
+    /// Returns `True` if the JSON-encoded transfer `post` has a canonical shape and a valid proof
+    /// under the latest parameters version.
+    pub fn verify_post(&self, post: &str) -> PyResult<bool> {
+        let post = parse::<TransferPost>(post)?;
+        let shape = match TransferShape::from_post(&post) {
+            Some(shape) => shape,
+            _ => return Ok(false),
+        };
+        let verifying_context = self
+            .verifying_contexts
+            .get(ParametersVersion::LATEST)
+            .expect("The latest verifying contexts are always loaded.");
+        post.has_valid_proof(verifying_context.select(shape))
+            .map_err(|err| value_error(format!("{err:?}")))
+    }
+
+    /// Builds the JSON-encoded transfer post of a `ToPrivate` transaction which deposits the
+    /// JSON-encoded `asset` into the JSON-encoded `address`, proving it with `proving_contexts`.
+    pub fn build_to_private(
+        &self,
+        proving_contexts: &ProvingContexts,
+        address: &str,
+        asset: &str,
+    ) -> PyResult<String> {
+        let mut rng = OsRng;
+        let post = ToPrivate::from_address(
+            &self.parameters,
+            parse::<Address>(address)?,
+            parse::<Asset>(asset)?,
+            Default::default(),
+            &mut rng,
+        )
+        .into_post(
+            FullParametersRef::new(&self.parameters, &self.utxo_accumulator_model),
+            &proving_contexts.0.to_private,
+            None,
+            Vec::new(),
+            &mut rng,
+        )
+        .map_err(|err| value_error(format!("{err:?}")))?
+        .expect("ToPrivate transactions do not require authorization.");
+        to_json(&post)
+    }
+}
+
+impl Default for Parameters {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Proving Contexts
+///
+/// Holds the decoded proving contexts of the three transfer shapes, which are required to build
+/// transfer posts.
+#[pyclass(module = "manta_pay_py")]
+pub struct ProvingContexts(MultiProvingContext);
+
+#[pymethods]
+impl ProvingContexts {
+    /// Loads the proving contexts from `directory`, downloading the ones which are missing or
+    /// corrupted.
+    #[staticmethod]
+    pub fn download(directory: PathBuf) -> Self {
+        Self(try_load_proving_context(&directory))
+    }
+
+    /// Decodes the proving contexts from the files at `to_private`, `private_transfer` and
+    /// `to_public`.
+    #[staticmethod]
+    pub fn from_files(
+        to_private: PathBuf,
+        private_transfer: PathBuf,
+        to_public: PathBuf,
+    ) -> PyResult<Self> {
+        Ok(Self(MultiProvingContext {
+            to_private: decode_proving_context(to_private)?,
+            private_transfer: decode_proving_context(private_transfer)?,
+            to_public: decode_proving_context(to_public)?,
+        }))
+    }
+}
+
+/// Signer
+///
+/// Wraps the Manta Pay [`Signer`](base::Signer), which downloads the proving contexts into
+/// `proving_context_directory` the first time a transaction of each shape is signed.
+#[pyclass(module = "manta_pay_py", unsendable)]
+pub struct Signer(base::Signer);
+
+#[pymethods]
+impl Signer {
+    /// Builds a new signer for the plain-text `mnemonic` phrase, restoring the JSON-encoded
+    /// `storage_state` if it is given.
+    #[new]
+    #[pyo3(signature = (mnemonic, proving_context_directory, storage_state = None))]
+    pub fn new(
+        mnemonic: &str,
+        proving_context_directory: PathBuf,
+        storage_state: Option<&str>,
+    ) -> PyResult<Self> {
+        let mnemonic = parse_mnemonic(mnemonic)?;
+        let storage_state = match storage_state {
+            Some(storage_state) => parse::<StorageStateOption>(storage_state)?,
+            _ => None,
+        };
+        let mut signer = base::Signer::from_loader(
+            load_transfer_parameters(),
+            proving_context_loader(proving_context_directory),
+            EvictionPolicy::default(),
+            Accumulator::empty(&load_utxo_accumulator_model()),
+            FromEntropy::from_entropy(),
+        );
+        if let Some(state) = &storage_state {
+            state.update_signer(&mut signer);
+        }
+        let authorization_context =
+            authorization_context_from_mnemonic(mnemonic.clone(), signer.transfer_parameters());
+        signer.load_accounts(accounts_from_mnemonic(mnemonic));
+        signer.load_authorization_context(authorization_context);
+        Ok(Self(signer))
+    }
+
+    /// Returns the JSON-encoded default address of the signer.
+    pub fn address(&mut self) -> PyResult<String> {
+        to_json(&self.0.address())
+    }
+
+    /// Synchronizes the signer with the JSON-encoded sync `request`, returning the JSON-encoded
+    /// sync result.
+    pub fn sync(&mut self, request: &str) -> PyResult<String> {
+        to_json(&self.0.sync(parse::<SyncRequest>(request)?))
+    }
+
+    /// Signs the JSON-encoded sign `request`, returning the JSON-encoded sign result.
+    pub fn sign(&mut self, request: &str) -> PyResult<String> {
+        let request = parse::<SignRequest>(request)?;
+        to_json(
+            &self
+                .0
+                .resolve_request(request)
+                .and_then(|transaction| self.0.sign(transaction)),
+        )
+    }
+
+    /// Returns the JSON-encoded storage state of the signer, which can be persisted and passed
+    /// back to the constructor to avoid synchronizing from scratch.
+    pub fn storage_state(&self) -> PyResult<String> {
+        to_json(&get_storage(&self.0))
+    }
+}
+
+/// Python Module
+#[pymodule]
+fn manta_pay_py(_py: Python<'_>, module: &PyModule) -> PyResult<()> {
+    module.add_class::<Parameters>()?;
+    module.add_class::<ProvingContexts>()?;
+    module.add_class::<Signer>()?;
+    Ok(())
+}
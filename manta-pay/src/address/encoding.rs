@@ -0,0 +1,312 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Bech32m Address Encoding
+//!
+//! Encodes [`Address`]es as [bech32m] strings whose human-readable part names the [`Network`] the
+//! address belongs to, so that typos are caught by the checksum and addresses of one network
+//! cannot be pasted into a wallet of another one by mistake.
+//!
+//! [bech32m]: https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki
+
+use crate::{config::Address, signer::client::network::Network};
+use alloc::{string::String, vec::Vec};
+use bech32::{primitives::decode::CheckedHrpstring, Bech32m, Hrp};
+use core::fmt::{self, Display};
+use manta_util::codec::Encode;
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Length of an Encoded Key of an [`Address`] in Bytes
+pub const KEY_LENGTH: usize = 32;
+
+/// Length of the Encoded Receiving and Detection Keys of an [`Address`] in Bytes
+pub const ADDRESS_LENGTH: usize = 2 * KEY_LENGTH;
+
+/// Human-Readable Part of Manta Addresses
+pub const MANTA_HRP: &str = "manta";
+
+/// Human-Readable Part of Calamari Addresses
+pub const CALAMARI_HRP: &str = "calamari";
+
+/// Human-Readable Part of Dolphin Addresses
+pub const DOLPHIN_HRP: &str = "dolphin";
+
+/// Returns the human-readable part of the addresses of `network`.
+#[inline]
+pub const fn hrp(network: Network) -> &'static str {
+    match network {
+        Network::Dolphin => DOLPHIN_HRP,
+        Network::Calamari => CALAMARI_HRP,
+        Network::Manta => MANTA_HRP,
+    }
+}
+
+/// Returns the [`Network`] whose addresses have the human-readable part `hrp`, ignoring case.
+#[inline]
+pub fn network_from_hrp(hrp: &str) -> Option<Network> {
+    [Network::Dolphin, Network::Calamari, Network::Manta]
+        .into_iter()
+        .find(|network| self::hrp(*network).eq_ignore_ascii_case(hrp))
+}
+
+/// Address Decoding Error
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AddressDecodeError {
+    /// Invalid Format
+    ///
+    /// The string is not a bech32 string, for instance because it has no separator, contains
+    /// characters outside of the bech32 alphabet or mixes upper and lower case.
+    InvalidFormat,
+
+    /// Invalid Checksum
+    ///
+    /// The bech32m checksum of the string does not match, which is usually caused by a typo.
+    /// Strings with a legacy bech32 checksum are rejected with this error as well.
+    InvalidChecksum,
+
+    /// Unknown Network
+    ///
+    /// The human-readable part of the string is not the one of any [`Network`].
+    UnknownNetwork,
+
+    /// Network Mismatch
+    ///
+    /// The address belongs to the `found` network instead of the `expected` one.
+    NetworkMismatch {
+        /// Expected Network
+        expected: Network,
+
+        /// Network of the Decoded Address
+        found: Network,
+    },
+
+    /// Invalid Length
+    ///
+    /// The string encodes the given number of bytes instead of [`ADDRESS_LENGTH`].
+    InvalidLength(usize),
+
+    /// Non-Canonical Encoding
+    ///
+    /// The padding bits of the string are not zero, so it is not the encoding produced by
+    /// [`encode_address`].
+    NonCanonical,
+
+    /// Invalid Receiving Key
+    ///
+    /// The encoded bytes are not a valid receiving key.
+    InvalidReceivingKey,
+
+    /// Invalid Detection Key
+    ///
+    /// The encoded bytes are not a valid detection key.
+    InvalidDetectionKey,
+}
+
+impl Display for AddressDecodeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "The address is not a valid bech32 string."),
+            Self::InvalidChecksum => write!(
+                f,
+                "The address checksum does not match. Check the address for typos."
+            ),
+            Self::UnknownNetwork => write!(
+                f,
+                "The address prefix must be one of `{MANTA_HRP}`, `{CALAMARI_HRP}` or `{DOLPHIN_HRP}`."
+            ),
+            Self::NetworkMismatch { expected, found } => write!(
+                f,
+                "Expected a {expected} address but found a {found} address."
+            ),
+            Self::InvalidLength(length) => write!(
+                f,
+                "The address encodes {length} bytes instead of {ADDRESS_LENGTH}."
+            ),
+            Self::NonCanonical => write!(f, "The address is not canonically encoded."),
+            Self::InvalidReceivingKey => {
+                write!(f, "The address does not encode a valid receiving key.")
+            }
+            Self::InvalidDetectionKey => {
+                write!(f, "The address does not encode a valid detection key.")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for AddressDecodeError {}
+
+/// Encodes `bytes` as a lowercase bech32m string with human-readable part `hrp`.
+#[inline]
+fn encode_bytes(hrp: &'static str, bytes: &[u8]) -> String {
+    bech32::encode::<Bech32m>(Hrp::parse_unchecked(hrp), bytes)
+        .expect("Encoded addresses are shorter than the bech32m length limit.")
+}
+
+/// Encodes `address` as a bech32m string for `network`.
+#[inline]
+pub fn encode_address(address: &Address, network: Network) -> String {
+    let mut bytes = Vec::with_capacity(ADDRESS_LENGTH);
+    address
+        .receiving_key
+        .encode(&mut bytes)
+        .expect("Encoding is not allowed to fail.");
+    address
+        .detection_key
+        .encode(&mut bytes)
+        .expect("Encoding is not allowed to fail.");
+    encode_bytes(hrp(network), &bytes)
+}
+
+/// Decodes the bech32m-encoded `string` into an [`Address`] and the [`Network`] it belongs to.
+///
+/// Both all-lowercase and all-uppercase strings are accepted.
+#[inline]
+pub fn decode_address(string: &str) -> Result<(Network, Address), AddressDecodeError> {
+    let checked = CheckedHrpstring::new::<Bech32m>(string).map_err(|err| match err {
+        bech32::primitives::decode::CheckedHrpstringError::Checksum(_) => {
+            AddressDecodeError::InvalidChecksum
+        }
+        _ => AddressDecodeError::InvalidFormat,
+    })?;
+    let network =
+        network_from_hrp(checked.hrp().as_str()).ok_or(AddressDecodeError::UnknownNetwork)?;
+    let bytes = checked.byte_iter().collect::<Vec<_>>();
+    if bytes.len() != ADDRESS_LENGTH {
+        return Err(AddressDecodeError::InvalidLength(bytes.len()));
+    }
+    if !encode_bytes(hrp(network), &bytes).eq_ignore_ascii_case(string) {
+        return Err(AddressDecodeError::NonCanonical);
+    }
+    let (receiving_key, detection_key) = bytes.split_at(KEY_LENGTH);
+    Ok((
+        network,
+        Address::new(
+            receiving_key
+                .to_vec()
+                .try_into()
+                .map_err(|_| AddressDecodeError::InvalidReceivingKey)?,
+            detection_key
+                .to_vec()
+                .try_into()
+                .map_err(|_| AddressDecodeError::InvalidDetectionKey)?,
+        ),
+    ))
+}
+
+/// Decodes the bech32m-encoded `string` into an [`Address`] of `network`.
+#[inline]
+pub fn decode_address_for(string: &str, network: Network) -> Result<Address, AddressDecodeError> {
+    match decode_address(string)? {
+        (found, address) if found == network => Ok(address),
+        (found, _) => Err(AddressDecodeError::NetworkMismatch {
+            expected: network,
+            found,
+        }),
+    }
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use manta_crypto::rand::{OsRng, Rand};
+
+    /// Tests that decoding an encoded address returns the address and its network.
+    #[test]
+    fn encoding_round_trip() {
+        let address = OsRng.gen::<_, Address>();
+        for network in [Network::Dolphin, Network::Calamari, Network::Manta] {
+            let encoded = encode_address(&address, network);
+            assert!(encoded.starts_with(hrp(network)));
+            assert_eq!(decode_address(&encoded), Ok((network, address)));
+            assert_eq!(
+                decode_address(&encoded.to_uppercase()),
+                Ok((network, address))
+            );
+            assert_eq!(decode_address_for(&encoded, network), Ok(address));
+        }
+    }
+
+    /// Tests that malformed addresses are rejected with the matching error.
+    #[test]
+    fn decoding_errors() {
+        let address = OsRng.gen::<_, Address>();
+        let encoded = encode_address(&address, Network::Manta);
+        assert_eq!(
+            decode_address_for(&encoded, Network::Calamari),
+            Err(AddressDecodeError::NetworkMismatch {
+                expected: Network::Calamari,
+                found: Network::Manta,
+            })
+        );
+        let mut typo = encoded.clone().into_bytes();
+        let last = typo.len() - 1;
+        typo[last] = if typo[last] == b'q' { b'p' } else { b'q' };
+        assert_eq!(
+            decode_address(core::str::from_utf8(&typo).expect("The string is ASCII.")),
+            Err(AddressDecodeError::InvalidChecksum)
+        );
+        assert_eq!(
+            decode_address(&encoded.replacen('1', "b", 1)),
+            Err(AddressDecodeError::InvalidFormat)
+        );
+        let mut mixed_case = encoded.clone();
+        mixed_case.replace_range(..1, "M");
+        assert_eq!(
+            decode_address(&mixed_case),
+            Err(AddressDecodeError::InvalidFormat)
+        );
+        assert_eq!(
+            decode_address(&encode_bytes("polkadot", &[0; ADDRESS_LENGTH])),
+            Err(AddressDecodeError::UnknownNetwork)
+        );
+        assert_eq!(
+            decode_address(&encode_bytes(MANTA_HRP, &[0; ADDRESS_LENGTH + 1])),
+            Err(AddressDecodeError::InvalidLength(ADDRESS_LENGTH + 1))
+        );
+        assert_eq!(
+            decode_address(
+                &bech32::encode::<bech32::Bech32>(Hrp::parse_unchecked(MANTA_HRP), &[0; 32])
+                    .expect("The string is short enough.")
+            ),
+            Err(AddressDecodeError::InvalidChecksum)
+        );
+        assert_eq!(
+            decode_address(&encode_bytes(MANTA_HRP, &[u8::MAX; ADDRESS_LENGTH])),
+            Err(AddressDecodeError::InvalidReceivingKey)
+        );
+        let mut bytes = Vec::with_capacity(ADDRESS_LENGTH);
+        address
+            .receiving_key
+            .encode(&mut bytes)
+            .expect("Encoding is not allowed to fail.");
+        bytes.extend([u8::MAX; KEY_LENGTH]);
+        assert_eq!(
+            decode_address(&encode_bytes(MANTA_HRP, &bytes)),
+            Err(AddressDecodeError::InvalidDetectionKey)
+        );
+    }
+}
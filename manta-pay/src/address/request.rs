@@ -0,0 +1,410 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Payment Requests
+//!
+//! Compact payment requests which a receiving wallet displays as a QR code and a paying wallet
+//! scans. A request is serialized as a URI in the spirit of [BIP-21]:
+//!
+//! ```text
+//! manta:<address>?asset=<asset id>&amount=<amount>&memo=<memo>&expiry=<expiry>
+//! ```
+//!
+//! where `<address>` is the bech32m encoding of the recipient [`Address`] from the
+//! [`encoding`](super::encoding) module, which also identifies the network of the request. Every
+//! parameter is optional, the asset id and amount are decimal integers, the memo is
+//! percent-encoded UTF-8 and the expiry is a UNIX timestamp in seconds. Unknown parameters are
+//! ignored when parsing so that the format can be extended without breaking older wallets.
+//!
+//! [BIP-21]: https://github.com/bitcoin/bips/blob/master/bip-0021.mediawiki
+
+use crate::{
+    address::encoding::{decode_address, encode_address, AddressDecodeError},
+    config::{Address, Asset, AssetId, AssetValue},
+    signer::client::network::Network,
+};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Display, Write};
+use manta_crypto::arkworks::{constraint::fp::Fp, ff::try_into_u128};
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Payment Request URI Scheme
+pub const PAYMENT_REQUEST_SCHEME: &str = "manta";
+
+/// Maximum Length of a Payment Request Memo in Bytes
+///
+/// Longer memos would make the QR code of the request too dense to scan reliably.
+pub const MAX_MEMO_LENGTH: usize = 256;
+
+/// Payment Request
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PaymentRequest {
+    /// Network of the Recipient
+    pub network: Network,
+
+    /// Recipient Address
+    pub address: Address,
+
+    /// Requested Asset Id
+    pub asset_id: Option<AssetId>,
+
+    /// Requested Amount
+    ///
+    /// An amount is only meaningful together with an asset id.
+    pub amount: Option<AssetValue>,
+
+    /// Memo
+    pub memo: Option<String>,
+
+    /// Expiry Time as a UNIX Timestamp in Seconds
+    pub expiry: Option<u64>,
+}
+
+impl PaymentRequest {
+    /// Builds a new [`PaymentRequest`] for a payment of any asset to `address` on `network`.
+    #[inline]
+    pub fn new(network: Network, address: Address) -> Self {
+        Self {
+            network,
+            address,
+            asset_id: None,
+            amount: None,
+            memo: None,
+            expiry: None,
+        }
+    }
+
+    /// Requests a payment of `asset` instead of any asset.
+    #[inline]
+    pub fn with_asset(mut self, asset: Asset) -> Self {
+        self.asset_id = Some(asset.id);
+        self.amount = Some(asset.value);
+        self
+    }
+
+    /// Attaches `memo` to `self`.
+    #[inline]
+    pub fn with_memo(mut self, memo: String) -> Self {
+        self.memo = Some(memo);
+        self
+    }
+
+    /// Sets the expiry time of `self` to the UNIX timestamp `expiry`, in seconds.
+    #[inline]
+    pub fn with_expiry(mut self, expiry: u64) -> Self {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    /// Returns the requested [`Asset`] if `self` has both an asset id and an amount.
+    #[inline]
+    pub fn asset(&self) -> Option<Asset> {
+        Some(Asset::new(self.asset_id?, self.amount?))
+    }
+
+    /// Returns `true` if `self` has expired at the UNIX timestamp `now`, in seconds.
+    #[inline]
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expiry.is_some_and(|expiry| expiry <= now)
+    }
+
+    /// Serializes `self` into a payment request URI.
+    #[inline]
+    pub fn to_uri(&self) -> Result<String, PaymentRequestError> {
+        if self.amount.is_some() && self.asset_id.is_none() {
+            return Err(PaymentRequestError::MissingAssetId);
+        }
+        let mut uri = format!(
+            "{PAYMENT_REQUEST_SCHEME}:{}",
+            encode_address(&self.address, self.network)
+        );
+        let mut separator = '?';
+        let mut push = |key: &str, value: &dyn Display| {
+            write!(uri, "{separator}{key}={value}").expect("Writing to a string cannot fail.");
+            separator = '&';
+        };
+        if let Some(asset_id) = self.asset_id {
+            push(
+                "asset",
+                &try_into_u128(asset_id.0).ok_or(PaymentRequestError::InvalidAssetId)?,
+            );
+        }
+        if let Some(amount) = self.amount {
+            push("amount", &amount);
+        }
+        if let Some(memo) = &self.memo {
+            if memo.len() > MAX_MEMO_LENGTH {
+                return Err(PaymentRequestError::MemoTooLong);
+            }
+            push("memo", &PercentEncoded(memo));
+        }
+        if let Some(expiry) = self.expiry {
+            push("expiry", &expiry);
+        }
+        Ok(uri)
+    }
+
+    /// Parses the payment request URI `uri`.
+    #[inline]
+    pub fn from_uri(uri: &str) -> Result<Self, PaymentRequestError> {
+        let rest = match uri.split_once(':') {
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case(PAYMENT_REQUEST_SCHEME) => rest,
+            _ => return Err(PaymentRequestError::InvalidScheme),
+        };
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let (network, address) = decode_address(address).map_err(PaymentRequestError::Address)?;
+        let mut request = Self::new(network, address);
+        let mut seen = Vec::new();
+        for parameter in query.split('&').filter(|parameter| !parameter.is_empty()) {
+            let (key, value) = parameter
+                .split_once('=')
+                .ok_or(PaymentRequestError::InvalidParameter)?;
+            if seen.contains(&key) {
+                return Err(PaymentRequestError::DuplicateParameter);
+            }
+            seen.push(key);
+            match key {
+                "asset" => {
+                    request.asset_id = Some(Fp::from(
+                        value
+                            .parse::<u128>()
+                            .map_err(|_| PaymentRequestError::InvalidAssetId)?,
+                    ))
+                }
+                "amount" => {
+                    request.amount = Some(
+                        value
+                            .parse()
+                            .map_err(|_| PaymentRequestError::InvalidAmount)?,
+                    )
+                }
+                "memo" => {
+                    let memo = percent_decode(value).ok_or(PaymentRequestError::InvalidMemo)?;
+                    if memo.len() > MAX_MEMO_LENGTH {
+                        return Err(PaymentRequestError::MemoTooLong);
+                    }
+                    request.memo = Some(memo);
+                }
+                "expiry" => {
+                    request.expiry = Some(
+                        value
+                            .parse()
+                            .map_err(|_| PaymentRequestError::InvalidExpiry)?,
+                    )
+                }
+                _ => {}
+            }
+        }
+        if request.amount.is_some() && request.asset_id.is_none() {
+            return Err(PaymentRequestError::MissingAssetId);
+        }
+        Ok(request)
+    }
+}
+
+/// Payment Request Error
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PaymentRequestError {
+    /// Invalid Scheme
+    ///
+    /// The URI does not start with [`PAYMENT_REQUEST_SCHEME`].
+    InvalidScheme,
+
+    /// Address Decoding Error
+    Address(AddressDecodeError),
+
+    /// Invalid Parameter
+    ///
+    /// A query parameter is not of the form `key=value`.
+    InvalidParameter,
+
+    /// Duplicate Parameter
+    ///
+    /// A query parameter appears more than once.
+    DuplicateParameter,
+
+    /// Invalid Asset Id
+    ///
+    /// The asset id is not an integer which fits in 128 bits.
+    InvalidAssetId,
+
+    /// Invalid Amount
+    InvalidAmount,
+
+    /// Missing Asset Id
+    ///
+    /// The request has an amount but no asset id.
+    MissingAssetId,
+
+    /// Invalid Memo
+    ///
+    /// The memo is not correctly percent-encoded UTF-8.
+    InvalidMemo,
+
+    /// Memo Too Long
+    ///
+    /// The memo is longer than [`MAX_MEMO_LENGTH`].
+    MemoTooLong,
+
+    /// Invalid Expiry
+    InvalidExpiry,
+}
+
+impl Display for PaymentRequestError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidScheme => write!(
+                f,
+                "Payment requests must start with `{PAYMENT_REQUEST_SCHEME}:`."
+            ),
+            Self::Address(err) => err.fmt(f),
+            Self::InvalidParameter => write!(f, "Payment request parameters must be `key=value`."),
+            Self::DuplicateParameter => {
+                write!(f, "The payment request repeats one of its parameters.")
+            }
+            Self::InvalidAssetId => write!(f, "The asset id must be a 128-bit integer."),
+            Self::InvalidAmount => write!(f, "The amount must be a 128-bit integer."),
+            Self::MissingAssetId => write!(f, "An amount can only be requested with an asset id."),
+            Self::InvalidMemo => write!(f, "The memo is not correctly encoded."),
+            Self::MemoTooLong => write!(f, "The memo is longer than {MAX_MEMO_LENGTH} bytes."),
+            Self::InvalidExpiry => write!(f, "The expiry must be a UNIX timestamp in seconds."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for PaymentRequestError {}
+
+/// Percent-Encoded String
+///
+/// Formats the wrapped string with every byte outside of the URI unreserved characters
+/// percent-encoded.
+struct PercentEncoded<'s>(&'s str);
+
+impl Display for PercentEncoded<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0.bytes() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                f.write_char(byte as char)?;
+            } else {
+                write!(f, "%{byte:02X}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decodes the percent-encoded `string`, returning `None` if it has an invalid escape or does not
+/// decode to UTF-8.
+#[inline]
+fn percent_decode(string: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(string.len());
+    let mut iter = string.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let high = (iter.next()? as char).to_digit(16)?;
+            let low = (iter.next()? as char).to_digit(16)?;
+            bytes.push((high * 16 + low) as u8);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use manta_crypto::rand::{OsRng, Rand};
+
+    /// Tests that parsing a serialized payment request returns the same request.
+    #[test]
+    fn payment_request_round_trip() {
+        let mut rng = OsRng;
+        let address = rng.gen::<_, Address>();
+        let minimal = PaymentRequest::new(Network::Calamari, address);
+        assert_eq!(
+            PaymentRequest::from_uri(&minimal.to_uri().expect("The request is valid.")),
+            Ok(minimal.clone())
+        );
+        let full = minimal
+            .with_asset(Asset::new(Fp::from(8u128), 1_000_000))
+            .with_memo("Invoice #42: coffee & café".into())
+            .with_expiry(1_700_000_000);
+        let uri = full.to_uri().expect("The request is valid.");
+        assert!(uri.starts_with("manta:calamari1"));
+        assert!(uri.ends_with(
+            "?asset=8&amount=1000000&memo=Invoice%20%2342%3A%20coffee%20%26%20caf%C3%A9&expiry=1700000000"
+        ));
+        assert_eq!(PaymentRequest::from_uri(&uri), Ok(full.clone()));
+        assert_eq!(full.asset(), Some(Asset::new(Fp::from(8u128), 1_000_000)));
+        assert!(!full.is_expired(1_699_999_999));
+        assert!(full.is_expired(1_700_000_000));
+    }
+
+    /// Tests that malformed payment requests are rejected with the matching error.
+    #[test]
+    fn payment_request_errors() {
+        let mut rng = OsRng;
+        let uri = PaymentRequest::new(Network::Manta, rng.gen())
+            .to_uri()
+            .expect("The request is valid.");
+        let with = |query: &str| PaymentRequest::from_uri(&format!("{uri}?{query}"));
+        assert_eq!(
+            PaymentRequest::from_uri(&uri.replacen("manta:", "bitcoin:", 1)),
+            Err(PaymentRequestError::InvalidScheme)
+        );
+        assert!(matches!(
+            PaymentRequest::from_uri(&uri[..uri.len() - 1]),
+            Err(PaymentRequestError::Address(_))
+        ));
+        assert_eq!(with("asset"), Err(PaymentRequestError::InvalidParameter));
+        assert_eq!(
+            with("asset=1&asset=2"),
+            Err(PaymentRequestError::DuplicateParameter)
+        );
+        assert_eq!(with("asset=-1"), Err(PaymentRequestError::InvalidAssetId));
+        assert_eq!(
+            with("asset=1&amount=lots"),
+            Err(PaymentRequestError::InvalidAmount)
+        );
+        assert_eq!(with("amount=5"), Err(PaymentRequestError::MissingAssetId));
+        assert_eq!(with("memo=%E2%28"), Err(PaymentRequestError::InvalidMemo));
+        assert_eq!(with("memo=%G0"), Err(PaymentRequestError::InvalidMemo));
+        assert_eq!(
+            with(&format!("memo={}", "a".repeat(MAX_MEMO_LENGTH + 1))),
+            Err(PaymentRequestError::MemoTooLong)
+        );
+        assert_eq!(with("expiry=soon"), Err(PaymentRequestError::InvalidExpiry));
+        assert!(with("label=shop&asset=1").is_ok());
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Export Key-Derivation Test Vectors
+//!
+//! Usage: `export_key_vectors [SEED]...`, where every `SEED` is a `u64` which samples one mnemonic.
+//! Without seeds, the mnemonics are sampled from the first
+//! [`DEFAULT_SEED_COUNT`](KeyVector::DEFAULT_SEED_COUNT) seeds. The key derivation from each
+//! mnemonic down to its address is printed as JSON for every coin type, using the published
+//! protocol parameters, so that implementations in other languages can check their key derivation
+//! against this crate.
+
+use manta_pay::{parameters::load_transfer_parameters, test::vectors::KeyVector};
+use manta_util::error::{Error, Result};
+use std::env;
+
+/// Prints the key-derivation vectors for the seeds given on the command line.
+#[inline]
+pub fn main() -> Result<()> {
+    let seeds = env::args()
+        .skip(1)
+        .map(|seed| {
+            seed.parse::<u64>()
+                .map_err(|err| Error::new(format_args!("Invalid seed {seed:?}: {err}.")))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let seeds = if seeds.is_empty() {
+        (0..KeyVector::DEFAULT_SEED_COUNT).collect()
+    } else {
+        seeds
+    };
+    let vectors = KeyVector::from_seeds(seeds, &load_transfer_parameters());
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&vectors).map_err(|err| Error::new(format_args!(
+            "Unable to serialize the key vectors: {err}."
+        )))?
+    );
+    Ok(())
+}
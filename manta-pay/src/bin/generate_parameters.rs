@@ -15,206 +15,61 @@
 // along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Generate Parameters
+//!
+//! Usage: `generate_parameters [TARGET_DIRECTORY] [SEED]`, where `SEED` is an optional 64-digit
+//! hexadecimal string which defaults to [`SEED`](manta_pay::parameters::SEED). The artifacts are
+//! a deterministic function of the seed, so two runs with the same seed produce byte-identical
+//! files, and their checksums are written to
+//! [`CHECKSUM_FILE`](manta_pay::parameters::CHECKSUM_FILE) in the target directory.
 
-// TODO: Deduplicate the per-circuit proving context and verifying context serialization code.
 // TODO: Print some statistics about the parameters and circuits and into a stats file as well.
 
-use manta_pay::{
-    config::{utxo::protocol::BaseParameters, Parameters},
-    parameters,
-};
-use manta_util::codec::{Encode, IoWriter};
-use std::{
-    env,
-    fs::{self, OpenOptions},
-    io,
-    path::PathBuf,
-};
+use manta_pay::parameters::{self, CHECKSUM_FILE, SEED};
+use manta_util::error::{Context, Error, Result};
+use std::{env, fs, path::PathBuf};
 
-/// Generates the parameters using the [`SEED`](manta_pay::parameters::SEED) and saves them to the
-/// filesystem.
+/// Parses a 32-byte seed from the hexadecimal string `seed`.
 #[inline]
-pub fn main() -> io::Result<()> {
-    let target_dir = env::args()
-        .nth(1)
-        .map(PathBuf::from)
-        .unwrap_or(env::current_dir()?);
-    assert!(
-        target_dir.is_dir() || !target_dir.exists(),
-        "Specify a directory to place the generated files: {target_dir:?}.",
-    );
-    fs::create_dir_all(&target_dir)?;
-
-    let (proving_context, verifying_context, parameters, utxo_accumulator_model) =
-        parameters::generate().expect("Unable to generate parameters.");
-
-    let Parameters {
-        base:
-            BaseParameters {
-                group_generator,
-                utxo_commitment_scheme,
-                incoming_base_encryption_scheme,
-                light_incoming_base_encryption_scheme,
-                viewing_key_derivation_function,
-                utxo_accumulator_item_hash,
-                nullifier_commitment_scheme,
-                outgoing_base_encryption_scheme,
-            },
-        address_partition_function,
-        schnorr_hash_function,
-    } = &parameters;
-
-    let parameters_dir = target_dir.join("parameters");
-    fs::create_dir_all(&parameters_dir)?;
-
-    group_generator
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(parameters_dir.join("group-generator.dat"))?,
-        ))
-        .unwrap();
-
-    utxo_commitment_scheme
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(parameters_dir.join("utxo-commitment-scheme.dat"))?,
-        ))
-        .unwrap();
-
-    incoming_base_encryption_scheme
-        .encode(IoWriter(OpenOptions::new().create(true).write(true).open(
-            parameters_dir.join("incoming-base-encryption-scheme.dat"),
-        )?))
-        .unwrap();
-    light_incoming_base_encryption_scheme
-        .encode(IoWriter(OpenOptions::new().create(true).write(true).open(
-            parameters_dir.join("light-incoming-base-encryption-scheme.dat"),
-        )?))
-        .unwrap();
-
-    viewing_key_derivation_function
-        .encode(IoWriter(OpenOptions::new().create(true).write(true).open(
-            parameters_dir.join("viewing-key-derivation-function.dat"),
-        )?))
-        .unwrap();
-
-    utxo_accumulator_item_hash
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(parameters_dir.join("utxo-accumulator-item-hash.dat"))?,
-        ))
-        .unwrap();
-
-    nullifier_commitment_scheme
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(parameters_dir.join("nullifier-commitment-scheme.dat"))?,
-        ))
-        .unwrap();
-
-    outgoing_base_encryption_scheme
-        .encode(IoWriter(OpenOptions::new().create(true).write(true).open(
-            parameters_dir.join("outgoing-base-encryption-scheme.dat"),
-        )?))
-        .unwrap();
-
-    address_partition_function
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(parameters_dir.join("address-partition-function.dat"))?,
-        ))
-        .unwrap();
-
-    schnorr_hash_function
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(parameters_dir.join("schnorr-hash-function.dat"))?,
-        ))
-        .unwrap();
-
-    utxo_accumulator_model
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(parameters_dir.join("utxo-accumulator-model.dat"))?,
-        ))
-        .unwrap();
-
-    let proving_context_dir = target_dir.join("proving");
-    fs::create_dir_all(&proving_context_dir)?;
-
-    let verifying_context_dir = target_dir.join("verifying");
-    fs::create_dir_all(&verifying_context_dir)?;
-
-    proving_context
-        .to_private
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(proving_context_dir.join("to-private.lfs"))?,
-        ))
-        .unwrap();
-    verifying_context
-        .to_private
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(verifying_context_dir.join("to-private.dat"))?,
-        ))
-        .unwrap();
-
-    proving_context
-        .private_transfer
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(proving_context_dir.join("private-transfer.lfs"))?,
-        ))
-        .unwrap();
-    verifying_context
-        .private_transfer
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(verifying_context_dir.join("private-transfer.dat"))?,
-        ))
-        .unwrap();
-
-    proving_context
-        .to_public
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(proving_context_dir.join("to-public.lfs"))?,
-        ))
-        .unwrap();
-    verifying_context
-        .to_public
-        .encode(IoWriter(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(verifying_context_dir.join("to-public.dat"))?,
-        ))
-        .unwrap();
+fn parse_seed(seed: &str) -> Option<[u8; 32]> {
+    if seed.len() != 64 || !seed.is_ascii() {
+        return None;
+    }
+    let mut bytes = [0; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&seed[2 * i..2 * i + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
 
+/// Generates the parameters using the [`SEED`](manta_pay::parameters::SEED), or the seed given on
+/// the command line, and saves them to the filesystem.
+#[inline]
+pub fn main() -> Result<()> {
+    let target_dir = match env::args().nth(1) {
+        Some(target_dir) => PathBuf::from(target_dir),
+        _ => env::current_dir().context("Unable to read the current directory")?,
+    };
+    if target_dir.exists() && !target_dir.is_dir() {
+        return Err(Error::new(format_args!(
+            "Specify a directory to place the generated files: {target_dir:?}."
+        )));
+    }
+    let seed = match env::args().nth(2) {
+        Some(seed) => {
+            parse_seed(&seed).context("The seed must be a 64-digit hexadecimal string.")?
+        }
+        _ => SEED,
+    };
+    fs::create_dir_all(&target_dir)
+        .with_context(|| format!("Unable to create the target directory {target_dir:?}"))?;
+    for artifact in parameters::generate_into_directory(seed, &target_dir)
+        .with_context(|| format!("Unable to generate the parameters into {target_dir:?}"))?
+    {
+        println!("Generated {}", artifact.path.display());
+    }
+    println!(
+        "Checksums written to {}",
+        target_dir.join(CHECKSUM_FILE).display()
+    );
     Ok(())
 }
@@ -24,10 +24,10 @@ use manta_pay::{
     parameters::load_parameters,
     simulation::ledger::{safe_fill_ledger, unsafe_fill_ledger, Ledger},
 };
+use manta_util::error::{Context, Error, Result};
 use std::{
     env,
     fs::{self, OpenOptions},
-    io::{self},
     path::PathBuf,
     str::FromStr,
 };
@@ -69,16 +69,18 @@ const DEFAULT_MODE: Mode = Mode::Unsafe;
 /// - cargo run --release --package manta-pay --bin precompute_ledger
 /// --all-features -- `directory` `NUMBER_OF_COINS` `MODE`
 #[inline]
-fn main() -> io::Result<()> {
-    let target_dir = env::args()
-        .nth(1)
-        .map(PathBuf::from)
-        .unwrap_or(env::current_dir()?);
-    assert!(
-        target_dir.is_dir() || !target_dir.exists(),
-        "Specify a directory to place the generated files: {target_dir:?}.",
-    );
-    fs::create_dir_all(&target_dir)?;
+fn main() -> Result<()> {
+    let target_dir = match env::args().nth(1) {
+        Some(target_dir) => PathBuf::from(target_dir),
+        _ => env::current_dir().context("Unable to read the current directory")?,
+    };
+    if target_dir.exists() && !target_dir.is_dir() {
+        return Err(Error::new(format_args!(
+            "Specify a directory to place the generated files: {target_dir:?}."
+        )));
+    }
+    fs::create_dir_all(&target_dir)
+        .with_context(|| format!("Unable to create the target directory {target_dir:?}"))?;
     let number_of_coins = env::args()
         .nth(2)
         .and_then(|s| s.parse().ok())
@@ -87,21 +89,25 @@ fn main() -> io::Result<()> {
         .nth(3)
         .and_then(|s| s.parse().ok())
         .unwrap_or(DEFAULT_MODE);
+    let target_path = target_dir.join("precomputed_ledger");
     let target_file = OpenOptions::new()
         .create_new(true)
         .write(true)
-        .open(target_dir.join("precomputed_ledger"))?;
-    let directory = tempfile::tempdir().expect("Unable to generate temporary test directory.");
+        .open(&target_path)
+        .with_context(|| format!("Unable to create the ledger file {target_path:?}"))?;
+    let directory = tempfile::tempdir().context("Unable to generate temporary test directory.")?;
     let mut rng = ChaCha20Rng::from_seed([0; 32]);
     let (proving_context, verifying_context, parameters, utxo_accumulator_model) =
-        load_parameters(directory.path()).expect("Unable to load parameters.");
+        load_parameters(directory.path())
+            .map_err(|e| Error::new(format_args!("Proof system error: {e:?}")))
+            .context("Unable to load parameters.")?;
     let asset_id = 8.into();
     let ledger = Arc::new(RwLock::new(Ledger::new(
         utxo_accumulator_model.clone(),
         verifying_context,
         parameters.clone(),
     )));
-    let runtime = Runtime::new().expect("Unable to start tokio runtime");
+    let runtime = Runtime::new().context("Unable to start tokio runtime")?;
     match mode {
         Mode::Safe => runtime.block_on(safe_fill_ledger(
             number_of_coins,
@@ -123,5 +129,7 @@ fn main() -> io::Result<()> {
         )),
     };
     runtime.block_on(async { ledger.read().await.serialize_into(target_file) });
-    directory.close()
+    directory
+        .close()
+        .context("Unable to remove the temporary directory")
 }
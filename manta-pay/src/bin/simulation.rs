@@ -17,8 +17,8 @@
 //! Manta Pay Simulation
 
 use clap::{error::ErrorKind, CommandFactory, Parser};
-use manta_crypto::rand::OsRng;
 use manta_pay::{parameters::load_parameters, simulation::Simulation};
+use manta_util::error::{Context, Error, Result};
 
 /// Runs the Manta Pay simulation.
 ///
@@ -26,24 +26,30 @@ use manta_pay::{parameters::load_parameters, simulation::Simulation};
 ///
 /// To run this binary file, use the following command
 /// - cargo run --release --package manta-pay --all-features --bin simulation `number_of_actors`
-/// `number_of_steps` `number_of_asset_ids` `initial_balance`
-pub fn main() {
-    let simulation = Simulation::parse();
-    let mut rng = OsRng;
-    let directory = tempfile::tempdir().expect("Unable to generate temporary test directory.");
+/// `number_of_steps` `number_of_asset_ids` `initial_balance` [--seed `seed`]
+pub fn main() -> Result<()> {
+    let mut simulation = Simulation::parse();
+    let mut rng = simulation.rng();
+    simulation.seed = Some(rng.seed());
+    let directory = tempfile::tempdir().context("Unable to generate temporary test directory.")?;
     let (proving_context, verifying_context, parameters, utxo_accumulator_model) =
-        load_parameters(directory.path()).expect("Unable to load parameters");
+        load_parameters(directory.path())
+            .map_err(|e| Error::new(format_args!("Proof system error: {e:?}")))
+            .context("Unable to load parameters")?;
     match tokio::runtime::Builder::new_multi_thread()
         .worker_threads(6)
         .build()
     {
-        Ok(runtime) => runtime.block_on(simulation.run(
-            &parameters,
-            &utxo_accumulator_model,
-            &proving_context,
-            verifying_context,
-            &mut rng,
-        )),
+        Ok(runtime) => {
+            runtime.block_on(simulation.run(
+                &parameters,
+                &utxo_accumulator_model,
+                &proving_context,
+                verifying_context,
+                &mut rng,
+            ));
+            Ok(())
+        }
         Err(err) => Simulation::command()
             .error(
                 ErrorKind::Io,
@@ -16,12 +16,13 @@
 
 //! Manta-Pay Configuration
 
-use manta_accounting::transfer;
+use alloc::vec::Vec;
+use manta_accounting::transfer::{self, canonical::TransferShape, CircuitVersion};
 use manta_crypto::{
     arkworks::{
         algebra::{self, ScalarVar},
         bn254::{self, Bn254},
-        constraint::{FpVar, R1CS},
+        constraint::{fp::Fp, FpVar, R1CS},
         ed_on_bn254::{
             self, constraints::EdwardsVar as Bn254_EdwardsVar, EdwardsProjective as Bn254_Edwards,
         },
@@ -33,6 +34,9 @@ use manta_crypto::{
 #[cfg(feature = "bs58")]
 use {alloc::string::String, manta_util::codec::Encode};
 
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
 pub mod poseidon;
 pub mod utxo;
 
@@ -49,6 +53,12 @@ pub type EmbeddedScalar = algebra::Scalar<GroupCurve>;
 pub type EmbeddedScalarVar = ScalarVar<GroupCurve, GroupCurveVar>;
 
 /// Embedded Group Curve Type
+///
+/// # Note
+///
+/// This curve has no efficiently computable endomorphism, so scalar multiplication cannot use the
+/// GLV method. See [`bandersnatch`](manta_crypto::arkworks::bandersnatch) for an embedded curve
+/// which supports it, over the BLS12-381 scalar field instead.
 pub type GroupCurve = Bn254_Edwards;
 
 /// Embedded Group Curve Type
@@ -81,9 +91,18 @@ pub type ProofSystem = groth16::Groth16<PairingCurve>;
 /// Proof System Error
 pub type ProofSystemError = groth16::Error;
 
+/// Compressed Verifying Key
+pub type CompressedVerifyingKey = groth16::CompressedVerifyingKey<PairingCurve>;
+
+/// Prepared Verifying Key Cache
+pub type PreparedVerifyingKeyCache = groth16::PreparedVerifyingKeyCache<PairingCurve>;
+
 /// Account Identifier
 pub type AccountId = [u8; 32];
 
+/// Withdrawal Tag
+pub type WithdrawalTag = Fp<ConstraintField>;
+
 /// Transfer Configuration
 #[derive(derivative::Derivative)]
 #[derivative(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
@@ -94,6 +113,7 @@ impl transfer::Configuration for Config {
     type AssetId = utxo::AssetId;
     type AssetValue = utxo::AssetValue;
     type AccountId = AccountId;
+    type WithdrawalTag = WithdrawalTag;
     type AssociatedData = utxo::AssociatedData;
     type Utxo = utxo::Utxo;
     type Nullifier = utxo::Nullifier;
@@ -119,6 +139,7 @@ impl transfer::Configuration for Config {
     type MintSecretVar = utxo::MintSecretVar;
     type SpendSecretVar = utxo::SpendSecretVar;
     type ParametersVar = utxo::ParametersVar;
+    type WithdrawalTagVar = ConstraintFieldVar;
     type ProofSystem = ProofSystem;
 }
 
@@ -224,7 +245,118 @@ pub type IdentityProof = transfer::IdentityProof<Config>;
 /// Transaction Data Type
 pub type TransactionData = transfer::canonical::TransactionData<Config>;
 
+/// Public Input Builder Type
+pub type ProofInputBuilder<'i> = transfer::input::ProofInputBuilder<'i, Config>;
+
+/// Versioned Verifier Error
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VersionedVerifierError {
+    /// Unknown Circuit Version
+    ///
+    /// The [`TransferPost`] is tagged with a version whose verifying contexts are not loaded.
+    UnknownVersion(CircuitVersion),
+
+    /// Invalid Shape
+    ///
+    /// The [`TransferPost`] does not have one of the canonical transfer shapes.
+    InvalidShape,
+
+    /// Proof System Error
+    ProofSystemError(ProofSystemError),
+}
+
+/// Versioned Verifier
+///
+/// Holds the [`MultiVerifyingContext`]s of several [`CircuitVersion`]s and verifies every
+/// [`TransferPost`] against the contexts of the version it is tagged with. During a circuit
+/// upgrade, a ledger can load both the old and the new contexts so that it accepts proofs of either
+/// version, and then [`remove`](Self::remove) the old ones when the migration window closes.
+#[derive(Clone, Debug, Default)]
+pub struct VersionedVerifier {
+    /// Verifying Contexts, sorted by Version
+    contexts: Vec<(CircuitVersion, MultiVerifyingContext)>,
+}
+
+impl VersionedVerifier {
+    /// Builds a new [`VersionedVerifier`] which only accepts proofs for `version`, verified with
+    /// `contexts`.
+    #[inline]
+    pub fn new(version: CircuitVersion, contexts: MultiVerifyingContext) -> Self {
+        let mut verifier = Self::default();
+        verifier.insert(version, contexts);
+        verifier
+    }
+
+    /// Inserts the verifying `contexts` for `version`, returning the previous contexts for
+    /// `version` if they existed.
+    #[inline]
+    pub fn insert(
+        &mut self,
+        version: CircuitVersion,
+        contexts: MultiVerifyingContext,
+    ) -> Option<MultiVerifyingContext> {
+        match self.contexts.binary_search_by_key(&version, |(v, _)| *v) {
+            Ok(index) => Some(core::mem::replace(&mut self.contexts[index].1, contexts)),
+            Err(index) => {
+                self.contexts.insert(index, (version, contexts));
+                None
+            }
+        }
+    }
+
+    /// Removes the verifying contexts for `version`, so that proofs of that version are no longer
+    /// accepted.
+    #[inline]
+    pub fn remove(&mut self, version: CircuitVersion) -> Option<MultiVerifyingContext> {
+        self.contexts
+            .binary_search_by_key(&version, |(v, _)| *v)
+            .ok()
+            .map(|index| self.contexts.remove(index).1)
+    }
+
+    /// Returns the verifying contexts for `version` if they are loaded.
+    #[inline]
+    pub fn get(&self, version: CircuitVersion) -> Option<&MultiVerifyingContext> {
+        self.contexts
+            .binary_search_by_key(&version, |(v, _)| *v)
+            .ok()
+            .map(|index| &self.contexts[index].1)
+    }
+
+    /// Returns an iterator over the versions accepted by `self`, from oldest to newest.
+    #[inline]
+    pub fn versions(&self) -> impl Iterator<Item = CircuitVersion> + '_ {
+        self.contexts.iter().map(|(version, _)| *version)
+    }
+
+    /// Selects the [`VerifyingContext`] for the version and shape of `post`.
+    #[inline]
+    pub fn select(&self, post: &TransferPost) -> Result<&VerifyingContext, VersionedVerifierError> {
+        let contexts = self
+            .get(post.version)
+            .ok_or(VersionedVerifierError::UnknownVersion(post.version))?;
+        let shape = TransferShape::from_post(post).ok_or(VersionedVerifierError::InvalidShape)?;
+        Ok(contexts.select(shape))
+    }
+
+    /// Verifies the proof of `post` against the verifying contexts of the version it is tagged
+    /// with.
+    #[inline]
+    pub fn verify(&self, post: &TransferPost) -> Result<bool, VersionedVerifierError> {
+        post.has_valid_proof(self.select(post)?)
+            .map_err(VersionedVerifierError::ProofSystemError)
+    }
+}
+
 /// Converts an [`Address`] into a base58-encoded string.
+///
+/// Base58 strings carry neither a checksum nor the network of the address, so user-facing
+/// addresses should use the bech32m encoding of the `address::encoding` module instead.
 #[cfg(feature = "bs58")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "bs58")))]
 #[inline]
@@ -234,6 +366,10 @@ pub fn address_to_base58(address: &Address) -> String {
         .receiving_key
         .encode(&mut bytes)
         .expect("Encoding is not allowed to fail.");
+    address
+        .detection_key
+        .encode(&mut bytes)
+        .expect("Encoding is not allowed to fail.");
     bs58::encode(bytes).into_string()
 }
 
@@ -242,11 +378,13 @@ pub fn address_to_base58(address: &Address) -> String {
 #[cfg_attr(doc_cfg, doc(cfg(feature = "bs58")))]
 #[inline]
 pub fn address_from_base58(string: &str) -> Option<Address> {
+    let bytes = bs58::decode(string.as_bytes()).into_vec().ok()?;
+    if bytes.len() != 64 {
+        return None;
+    }
+    let (receiving_key, detection_key) = bytes.split_at(32);
     Some(Address::new(
-        bs58::decode(string.as_bytes())
-            .into_vec()
-            .ok()?
-            .try_into()
-            .ok()?,
+        receiving_key.to_vec().try_into().ok()?,
+        detection_key.to_vec().try_into().ok()?,
     ))
 }
@@ -0,0 +1,273 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Poseidon Configuration
+
+use crate::{config::ConstraintField, crypto::poseidon};
+use manta_crypto::{arkworks::constraint::fp::Fp, eclair::alloc::Constant};
+
+mod tables;
+
+/// Poseidon Specification Configuration
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Spec<const ARITY: usize>;
+
+impl poseidon::Constants for Spec<2> {
+    const WIDTH: usize = 3;
+    const FULL_ROUNDS: usize = 8;
+    const PARTIAL_ROUNDS: usize = 55;
+}
+
+impl poseidon::Constants for Spec<3> {
+    const WIDTH: usize = 4;
+    const FULL_ROUNDS: usize = 8;
+    const PARTIAL_ROUNDS: usize = 55;
+}
+
+impl poseidon::Constants for Spec<4> {
+    const WIDTH: usize = 5;
+    const FULL_ROUNDS: usize = 8;
+    const PARTIAL_ROUNDS: usize = 56;
+}
+
+impl poseidon::Constants for Spec<5> {
+    const WIDTH: usize = 6;
+    const FULL_ROUNDS: usize = 8;
+    const PARTIAL_ROUNDS: usize = 56;
+}
+
+impl<const ARITY: usize> poseidon::arkworks::Specification for Spec<ARITY>
+where
+    Self: poseidon::Constants,
+{
+    type Field = ConstraintField;
+
+    const SBOX_EXPONENT: u64 = 5;
+}
+
+impl<const ARITY: usize, COM> Constant<COM> for Spec<ARITY> {
+    type Type = Self;
+
+    #[inline]
+    fn new_constant(this: &Self::Type, compiler: &mut COM) -> Self {
+        let _ = (this, compiler);
+        Self
+    }
+}
+
+/// Implements [`Precomputed`](poseidon::Precomputed) for `Spec<$arity>` with the tables called
+/// `$round_keys` and `$mds_matrix`.
+macro_rules! impl_precomputed {
+    ($arity:expr, $round_keys:ident, $mds_matrix:ident) => {
+        impl poseidon::Precomputed for Spec<$arity> {
+            #[inline]
+            fn additive_round_keys() -> &'static [Fp<ConstraintField>] {
+                &tables::$round_keys
+            }
+
+            #[inline]
+            fn mds_matrix() -> &'static [Fp<ConstraintField>] {
+                &tables::$mds_matrix
+            }
+        }
+    };
+}
+
+impl_precomputed!(2, SPEC2_ADDITIVE_ROUND_KEYS, SPEC2_MDS_MATRIX);
+impl_precomputed!(3, SPEC3_ADDITIVE_ROUND_KEYS, SPEC3_MDS_MATRIX);
+impl_precomputed!(4, SPEC4_ADDITIVE_ROUND_KEYS, SPEC4_MDS_MATRIX);
+impl_precomputed!(5, SPEC5_ADDITIVE_ROUND_KEYS, SPEC5_MDS_MATRIX);
+
+/// Arity 2 Poseidon Specification
+pub type Spec2 = Spec<2>;
+
+/// Arity 3 Poseidon Specification
+pub type Spec3 = Spec<3>;
+
+/// Arity 4 Poseidon Specification
+pub type Spec4 = Spec<4>;
+
+/// Arity 5 Poseidon Specification
+pub type Spec5 = Spec<5>;
+
+/// Testing Framework
+#[cfg(test)]
+pub mod test {
+    use crate::{
+        config::{poseidon::Spec, Compiler, ConstraintField},
+        crypto::poseidon::{
+            encryption::{
+                BlockArray, CiphertextBlock, FixedCiphertext, FixedDuplexer, FixedPlaintext,
+                PlaintextBlock, Tag,
+            },
+            Constants, Permutation,
+        },
+    };
+    use alloc::boxed::Box;
+    use manta_crypto::{
+        arkworks::constraint::{fp::Fp, FpVar},
+        eclair::alloc::{
+            mode::{Public, Secret},
+            Allocate,
+        },
+        encryption::{Decrypt, Encrypt},
+        permutation::duplex::Ciphertext,
+        rand::{OsRng, Sample},
+    };
+
+    /// Tests Poseidon duplexer encryption works.
+    #[test]
+    fn poseidon_duplexer_test() {
+        const N: usize = 3;
+        let mut rng = OsRng;
+        let duplexer = FixedDuplexer::<1, Spec<N>>::gen(&mut rng);
+        let field_elements = <[Fp<ConstraintField>; Spec::<N>::WIDTH - 1]>::gen(&mut rng);
+        let plaintext_block = PlaintextBlock(Box::new(field_elements));
+        let plaintext = BlockArray::<_, 1>([plaintext_block].into());
+        let mut key = Vec::new();
+        let key_element_1 = Fp::<ConstraintField>::gen(&mut rng);
+        let key_element_2 = Fp::<ConstraintField>::gen(&mut rng);
+        key.push(key_element_1);
+        key.push(key_element_2);
+        let header = vec![];
+        let ciphertext = duplexer.encrypt(&key, &(), &header, &plaintext, &mut ());
+        let (tag_matches, decrypted_plaintext) =
+            duplexer.decrypt(&key, &header, &ciphertext, &mut ());
+        assert!(tag_matches, "Tag doesn't match");
+        assert_eq!(
+            plaintext, decrypted_plaintext,
+            "Decrypted plaintext is not equal to original one."
+        );
+    }
+
+    /// Tests that authenticated Poseidon duplexer decryption rejects tampered ciphertexts natively
+    /// and inside of a circuit.
+    #[test]
+    fn poseidon_duplexer_authentication_test() {
+        const N: usize = 3;
+        let mut rng = OsRng;
+        let duplexer = FixedDuplexer::<1, Spec<N>>::gen(&mut rng);
+        let field_elements = <[Fp<ConstraintField>; Spec::<N>::WIDTH - 1]>::gen(&mut rng);
+        let plaintext = BlockArray::<_, 1>([PlaintextBlock(Box::new(field_elements))].into());
+        let key = vec![Fp::<ConstraintField>::gen(&mut rng)];
+        let header = vec![];
+        let ciphertext = duplexer.encrypt(&key, &(), &header, &plaintext, &mut ());
+        let mut tampered_tag = ciphertext.clone();
+        tampered_tag.tag = Tag(Fp::gen(&mut rng));
+        let mut tampered_message = ciphertext.clone();
+        tampered_message.message.0[0].0[0] = Fp::gen(&mut rng);
+        assert_eq!(
+            duplexer.tag(&key, &header, &ciphertext.message, &mut ()),
+            ciphertext.tag
+        );
+        assert!(duplexer.verify_tag(&key, &header, &ciphertext, &mut ()));
+        assert_eq!(
+            duplexer.decrypt_authenticated(&key, &header, &ciphertext, &mut ()),
+            Some(plaintext.clone())
+        );
+        for tampered in [&tampered_tag, &tampered_message] {
+            assert!(!duplexer.verify_tag(&key, &header, tampered, &mut ()));
+            assert_eq!(
+                duplexer.decrypt_authenticated(&key, &header, tampered, &mut ()),
+                None
+            );
+        }
+        for (ciphertext, is_authentic) in [
+            (&ciphertext, true),
+            (&tampered_tag, false),
+            (&tampered_message, false),
+        ] {
+            let mut compiler = Compiler::for_proofs();
+            let duplexer_var =
+                duplexer.as_constant::<FixedDuplexer<1, Spec<N>, Compiler>>(&mut compiler);
+            let key_var = key
+                .iter()
+                .map(|element| element.as_known::<Secret, FpVar<_>>(&mut compiler))
+                .collect::<Vec<_>>();
+            let ciphertext_var = ciphertext.as_known::<Public, Ciphertext<
+                Tag<Spec<N>, Compiler>,
+                FixedCiphertext<1, Spec<N>, Compiler>,
+            >>(&mut compiler);
+            let _ =
+                duplexer_var.decrypt_asserted(&key_var, &vec![], &ciphertext_var, &mut compiler);
+            assert_eq!(compiler.is_satisfied(), is_authentic);
+        }
+    }
+
+    /// Tests that padded messages spanning several blocks round-trip through the Poseidon
+    /// duplexer and that reordered or truncated ciphertext blocks are rejected.
+    #[test]
+    fn poseidon_multi_block_duplexer_test() {
+        const N: usize = 3;
+        const BLOCKS: usize = 3;
+        type Plaintext = FixedPlaintext<BLOCKS, Spec<N>>;
+        let mut rng = OsRng;
+        let duplexer = FixedDuplexer::<BLOCKS, Spec<N>>::gen(&mut rng);
+        let key = vec![Fp::<ConstraintField>::gen(&mut rng)];
+        let header = vec![Fp::<ConstraintField>::gen(&mut rng)];
+        assert_eq!(Plaintext::capacity(), BLOCKS * (Spec::<N>::WIDTH - 1) - 1);
+        for length in [0, 4, Plaintext::capacity()] {
+            let mut message = (0..length)
+                .map(|_| Fp::<ConstraintField>::gen(&mut rng))
+                .collect::<Vec<_>>();
+            if let Some(last) = message.last_mut() {
+                *last = Fp(0u8.into());
+            }
+            let plaintext = Plaintext::pad(&message, &mut ()).expect("The message fits.");
+            assert!(plaintext.is_well_formed());
+            let ciphertext = duplexer.encrypt(&key, &(), &header, &plaintext, &mut ());
+            assert!(ciphertext.message.is_well_formed());
+            let decrypted = duplexer
+                .decrypt_authenticated(&key, &header, &ciphertext, &mut ())
+                .expect("The ciphertext is authentic.");
+            assert_eq!(decrypted.unpad(), Some(message));
+            let mut reordered = ciphertext.clone();
+            reordered.message.0.swap(0, BLOCKS - 1);
+            assert!(!duplexer.verify_tag(&key, &header, &reordered, &mut ()));
+            let mut truncated = ciphertext.clone();
+            truncated.message.0[0] = CiphertextBlock(Box::new([]));
+            assert!(!truncated.message.is_well_formed());
+        }
+        let message = vec![Fp::<ConstraintField>::gen(&mut rng); Plaintext::capacity() + 1];
+        assert!(Plaintext::pad(&message, &mut ()).is_none());
+        let unpadded = Plaintext::from_iter(
+            (0..BLOCKS).map(|_| PlaintextBlock(Box::new([Fp(0u8.into()); Spec::<N>::WIDTH - 1]))),
+        );
+        assert_eq!(unpadded.unpad(), None);
+    }
+
+    /// Tests that the precomputed parameters are equal to the sampled parameters.
+    #[test]
+    fn precomputed_parameters_match_sampled_parameters() {
+        let mut rng = OsRng;
+        assert_eq!(
+            Permutation::<Spec<2>>::precomputed(),
+            Permutation::gen(&mut rng)
+        );
+        assert_eq!(
+            Permutation::<Spec<3>>::precomputed(),
+            Permutation::gen(&mut rng)
+        );
+        assert_eq!(
+            Permutation::<Spec<4>>::precomputed(),
+            Permutation::gen(&mut rng)
+        );
+        assert_eq!(
+            Permutation::<Spec<5>>::precomputed(),
+            Permutation::gen(&mut rng)
+        );
+    }
+}
@@ -0,0 +1,7453 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precomputed Poseidon Parameters
+//!
+//! These tables hold the additive round keys and MDS matrices generated by the [`Sample`]
+//! implementation of [`Permutation`] for each of the Poseidon specifications, so that they can be
+//! built without running the round constant and MDS matrix generation. Field elements are stored
+//! as their little-endian limbs in Montgomery form, which is the internal representation of
+//! [`ConstraintField`], so that the tables are constant data.
+//!
+//! [`Sample`]: manta_crypto::rand::Sample
+//! [`Permutation`]: crate::crypto::poseidon::Permutation
+
+use crate::config::ConstraintField;
+use manta_crypto::arkworks::{constraint::fp::Fp, ff::BigInteger256};
+
+/// Builds a field element from its little-endian `limbs` in Montgomery form.
+#[inline]
+const fn element(limbs: [u64; 4]) -> Fp<ConstraintField> {
+    Fp(ConstraintField::new(BigInteger256(limbs)))
+}
+
+/// Additive Round Keys for [`Spec2`](super::Spec2)
+pub static SPEC2_ADDITIVE_ROUND_KEYS: [Fp<ConstraintField>; 189] = [
+    element([
+        0xe928749314321f01,
+        0xf78cc45450d65ba6,
+        0x2e887576dbc5f343,
+        0x24d5ad82853cd75a,
+    ]),
+    element([
+        0xfa19158f2aecc8a6,
+        0x1cccf88a9de63ab5,
+        0x6df63a007e60873a,
+        0x296265934c519c15,
+    ]),
+    element([
+        0xb8f706636adfe9c5,
+        0x2bd891a905bc31e6,
+        0xd01a9f339438c036,
+        0x2675eb8cc8534a04,
+    ]),
+    element([
+        0x3a1927e74c7b7e78,
+        0x8cc890f4da9300b9,
+        0x19880a9d79bf9c2e,
+        0x2c14424cc91b1788,
+    ]),
+    element([
+        0x75e2eaba51c2231c,
+        0xd43de28820c7d5e1,
+        0xac353c0dbf3ab98b,
+        0x1b60f107dadfd9a5,
+    ]),
+    element([
+        0xc1d166f6728c14aa,
+        0x99e3b467852e7cfd,
+        0xe9d1e9d58e9b9c03,
+        0x018000a802651fda,
+    ]),
+    element([
+        0xdb7c30459933a60c,
+        0x9429f66bd629db05,
+        0x4369138397fd5f3a,
+        0x1a453ec446c85cf5,
+    ]),
+    element([
+        0xa3f42ed836688a1f,
+        0x61c2976aa1895fb8,
+        0x837eb382db494954,
+        0x1c7f6508a62da061,
+    ]),
+    element([
+        0x6bf1a0cc71737982,
+        0x178cd0e01099cb66,
+        0xca89b8f0d5b98d8a,
+        0x015aad8f56f2d50b,
+    ]),
+    element([
+        0x6c002e41a46c9fc1,
+        0xe6fd7bcdc7b3c879,
+        0x31195e26f851db4c,
+        0x0949eb3a8c84a96b,
+    ]),
+    element([
+        0x4db8285dc38c9fee,
+        0xf679398882b6d768,
+        0x195d0fd53fd0aa4f,
+        0x15701caa0de800c0,
+    ]),
+    element([
+        0x5fdbdc52caa91d37,
+        0xe0ca37530b3c991c,
+        0xb1fce8c04d253862,
+        0x0c1e496fc60f0016,
+    ]),
+    element([
+        0xc277e83b904ac948,
+        0xee90e0337cbdc73d,
+        0x1eb3b1c15506d850,
+        0x2c70870f1c6826f0,
+    ]),
+    element([
+        0x76317da8148aedf4,
+        0xd3ccd6f56f5e41a2,
+        0x14bf6a76e47baab2,
+        0x0cb9a78be2d191ad,
+    ]),
+    element([
+        0x51117b15a0446ff2,
+        0x6891be52fab017eb,
+        0x357c72f0755c7a05,
+        0x2df1545ffbda39b0,
+    ]),
+    element([
+        0x221b9f1519ba0415,
+        0x5f39ed2015fa04c5,
+        0xaa15f9aa2ab0c1bc,
+        0x2ef32fc1d8f2da1e,
+    ]),
+    element([
+        0xe383855f85144e42,
+        0x806c1b59e717573f,
+        0xf72bb5e4d0818066,
+        0x1bfaaa7c85041e41,
+    ]),
+    element([
+        0x4bd611bc3f22513d,
+        0x2a4304e875df6220,
+        0x98934ba5155382f4,
+        0x1ef93126aca7419c,
+    ]),
+    element([
+        0x4107609a5d8a2c81,
+        0x248005ca68148986,
+        0x9c6925e57423c77b,
+        0x020d77b8484386e9,
+    ]),
+    element([
+        0xcfea9b336996ef9d,
+        0x8b761415637746c7,
+        0x9fa6e30dd47a276e,
+        0x0d66918fddba346a,
+    ]),
+    element([
+        0xe1132c6008b4f97f,
+        0x7dd5e2590e91ff16,
+        0x7e3b7d8a095d8424,
+        0x12edfb0ca072afcf,
+    ]),
+    element([
+        0x64cdf3d6d4f4dabb,
+        0x497bcecf0b22e0ca,
+        0x4d8dc9046e03be03,
+        0x00db8d2079621b44,
+    ]),
+    element([
+        0xb12445aacfb79b51,
+        0xa440c455eba3c38a,
+        0x66d430364cfe19a2,
+        0x2f35e79279afaaed,
+    ]),
+    element([
+        0xc359e1a93a71b5af,
+        0x7171d57bff8dcffc,
+        0x3e5360afd1c92cd0,
+        0x08ea45535807f6da,
+    ]),
+    element([
+        0xadfdafa68889553d,
+        0x3cc6b2fe3be09332,
+        0x6ad48dc4890aed9f,
+        0x2fc6fb13bd425dfc,
+    ]),
+    element([
+        0x97f1e0c201079cef,
+        0x7a30a5bd0213b077,
+        0xc7d8255634b8ff3c,
+        0x2b811ab031a5d1c7,
+    ]),
+    element([
+        0x722935ed7d3e278a,
+        0x9a9d4e98fbda5f8c,
+        0xd850711e5c64237b,
+        0x18258c72aa46f88f,
+    ]),
+    element([
+        0x4fdd570d77919161,
+        0xf21fa8e4f5a7351d,
+        0x87019d717c93d05a,
+        0x09178576be66b253,
+    ]),
+    element([
+        0xf923b30e9fc3149f,
+        0x629d2e64477a8074,
+        0xf7e3ff63b2d7df13,
+        0x2a0b07841062facd,
+    ]),
+    element([
+        0xd113869e865927d4,
+        0xcce2d82f66d7e535,
+        0x7f5f77388d271aa3,
+        0x2d5e6577d3442948,
+    ]),
+    element([
+        0x346bb13b68c36727,
+        0xe1345deebce714c0,
+        0x5b16fa3c794ad7d0,
+        0x262eac4b83cba567,
+    ]),
+    element([
+        0x3829f8b02143103a,
+        0x008bc4642ad9f6ec,
+        0x208f6b0269f14b8f,
+        0x078f353e137ec795,
+    ]),
+    element([
+        0xe43b4141c1f044e4,
+        0x35bf3877d9fbd390,
+        0xdde4faf24bf40d27,
+        0x0b8286a87c3c4658,
+    ]),
+    element([
+        0xa8adb6862504e14a,
+        0x6dc6a8ec32d339dd,
+        0x5ef2217d2716ac6d,
+        0x12fc56446cf29eae,
+    ]),
+    element([
+        0x6750be377e9dad4b,
+        0xe42c9bd6fa498a74,
+        0x6ab0d0d74d2c75dd,
+        0x2462b12bc8768ea9,
+    ]),
+    element([
+        0xf78b61dab4453634,
+        0xd1cb54c0aa6c8cba,
+        0xdab60a2772f62028,
+        0x0288e18da0ab5a6c,
+    ]),
+    element([
+        0x2ce64db8e417aedd,
+        0x2367c60381a5013c,
+        0xf0813781590ef179,
+        0x250e264392e096b8,
+    ]),
+    element([
+        0xb79c0b97ae707de1,
+        0x1b2dec025eba8e19,
+        0x4d37510298b54690,
+        0x0b12af77a752ff56,
+    ]),
+    element([
+        0x3ef3cc6a9bb291ac,
+        0x614ffab53e67848b,
+        0x88ecbedbcd1c5461,
+        0x124fd7262deb2cc7,
+    ]),
+    element([
+        0xd886318bb81b11bf,
+        0x5f8345495498bad6,
+        0x3cb7c65175afe851,
+        0x1dce5ef9f1071b2e,
+    ]),
+    element([
+        0x6f6dcf45d78f1d01,
+        0xf334f9c36bbfd6cb,
+        0x4ea76e02f342cf7c,
+        0x2ec7efee1ac5b87c,
+    ]),
+    element([
+        0xd2fea802221cf210,
+        0x5675ce50c3e5577c,
+        0x3305fd56be310a22,
+        0x0e618ea90344d3ac,
+    ]),
+    element([
+        0x45b99c990418e8b4,
+        0xd0411d162fb5be6e,
+        0xdde8b084fe890f46,
+        0x1db4459fe5bfec63,
+    ]),
+    element([
+        0x66632ee091e5dd8b,
+        0x226674bc0d52e536,
+        0xe90a7ec1832173d7,
+        0x245e75064f931938,
+    ]),
+    element([
+        0xddd26d54ab756009,
+        0x1245ae25909b722e,
+        0xa899111d4b34c587,
+        0x243e834eb38146d2,
+    ]),
+    element([
+        0xf519a22511672bb1,
+        0x98eea3219bd597d3,
+        0x122c7dc1069ba6bc,
+        0x044279394753a9ef,
+    ]),
+    element([
+        0x92cc76f8415e5229,
+        0x51af8fe0286264a5,
+        0xa669ee51ddb09abd,
+        0x1cf9abdab32dd961,
+    ]),
+    element([
+        0x01d2d117d50dfe4e,
+        0x14abeaa61068008e,
+        0x2ebe0e43d459608a,
+        0x0cb0aadaf0dffd28,
+    ]),
+    element([
+        0xc6747d0789266842,
+        0x3cbdc66ae6d366d7,
+        0x71d727ff854af2a9,
+        0x04e9282a8f36025f,
+    ]),
+    element([
+        0x9c3c58f34225519c,
+        0x238aa69595149434,
+        0x1f7c6e6104d51496,
+        0x2979154c0c113675,
+    ]),
+    element([
+        0xb6a88a7f8a8f8e6b,
+        0xd4934f8a09133f39,
+        0x41e190dca66260ec,
+        0x16059348ea0116a7,
+    ]),
+    element([
+        0xb9ca988080cd4ae6,
+        0x28110f96451130a7,
+        0x031537f021cb5f67,
+        0x05f5fda038a6a735,
+    ]),
+    element([
+        0x1f34eb49c6fd3eec,
+        0xaad80ea97021be8b,
+        0x0a820648be05320a,
+        0x04bc0dd0abeec752,
+    ]),
+    element([
+        0x45d63234c0d6de75,
+        0xc7fa381d8eb759d0,
+        0x1750a8db7de9c103,
+        0x0497dbd0405d3c7d,
+    ]),
+    element([
+        0x86f64ca25f0c8750,
+        0x197f4bf96ea67926,
+        0xf7279fb06db6d923,
+        0x037fef005c3d3233,
+    ]),
+    element([
+        0x6115c0397a283ab4,
+        0x3ec2d6c2e92f220f,
+        0xd844b22bf009e9da,
+        0x23627bda92158e9a,
+    ]),
+    element([
+        0xcf298dff0cb1513e,
+        0x2fcc7688ca980093,
+        0x69ed35223e6328d3,
+        0x0042e90f7b697ac2,
+    ]),
+    element([
+        0xf0d37511e25ccd97,
+        0x296c51bfc772ec84,
+        0x850f1b889a2b41da,
+        0x28ac6f13149bf499,
+    ]),
+    element([
+        0x0c519f997b66fb3a,
+        0xc439f382be0fef38,
+        0xefa0ee3d0f877fdb,
+        0x12998c8c0ff627f1,
+    ]),
+    element([
+        0x335f6e955074f1d1,
+        0xbde41f069c8bf3d3,
+        0x0fa68a3e39ecc0ef,
+        0x0348010845aeae5d,
+    ]),
+    element([
+        0xd22a73c254bf0f4c,
+        0x9a95bc9fcfaf5d77,
+        0x30b026bb54285282,
+        0x17e4614364c8c54b,
+    ]),
+    element([
+        0x21cb2f5ac7e9fa0f,
+        0xfacf961e0b3ff0a8,
+        0x4ddcb3bfaa9937b8,
+        0x04d6275d92a05042,
+    ]),
+    element([
+        0x89e56eafe160101e,
+        0xb3911d111e07c914,
+        0x10eba0548234e817,
+        0x0d3914d670155782,
+    ]),
+    element([
+        0x92c392eac004a5ba,
+        0x736732233e3c7744,
+        0x5ad0d198ab1dbc20,
+        0x10a84fc74d266584,
+    ]),
+    element([
+        0x23ae64c044d7e61b,
+        0x1bc267200da577db,
+        0xb36d45819891a406,
+        0x2e0e6ee8041833e7,
+    ]),
+    element([
+        0x5a0b90b0fc92e26b,
+        0x648cccf77e47c4ca,
+        0x9cdedcd9300040fa,
+        0x1e6cceaa8fa5b17a,
+    ]),
+    element([
+        0x0553d754a64877bb,
+        0x85246ad03dd2366d,
+        0x07f1ea3ffb90681b,
+        0x1f21d1331379894f,
+    ]),
+    element([
+        0x5f81b905414a33e0,
+        0x08afe6c061235e5e,
+        0x4f64badfba2a1d81,
+        0x101d7373d9dd8e30,
+    ]),
+    element([
+        0xc626321c4db979e9,
+        0x89d348eaabd1c315,
+        0x74bdd50f2837ca9a,
+        0x0e1563210ba4b8cf,
+    ]),
+    element([
+        0xff769cdab17048cf,
+        0x3e739b3dc2c10aa8,
+        0x564bca29697be8d1,
+        0x20a7673589617aab,
+    ]),
+    element([
+        0xae9230432c745ed9,
+        0x3b9c81c7f11f5ecc,
+        0x73db14962bfbbae3,
+        0x128a26f011505e69,
+    ]),
+    element([
+        0x645b6ad042f296e7,
+        0xde8df55009fe6b7a,
+        0x2cafb4f5384b50c7,
+        0x11284fac12110f50,
+    ]),
+    element([
+        0xa4e2ea8ee5f298ab,
+        0xd8ec1d8d821d6db1,
+        0x2b25ecb3da2b8c32,
+        0x037b507451a3d02d,
+    ]),
+    element([
+        0xa24e5f3c1bf313b2,
+        0xba214be13abb17aa,
+        0x53ba6d505d48acf4,
+        0x1caa13ea92802252,
+    ]),
+    element([
+        0xca7cbec7931cbfa4,
+        0xe141532195427307,
+        0x0dcbc15971cb8222,
+        0x13a5a64d3a3834c2,
+    ]),
+    element([
+        0xe780ae8471775755,
+        0x6c9ef3df97b1d426,
+        0x68c1e64caf925b11,
+        0x0d118db963d3f30b,
+    ]),
+    element([
+        0x802a22c3db66cbf8,
+        0xc304f5d10c2f33b0,
+        0x4f9cb3e1af751c8c,
+        0x0b9f8c1e5ed9109a,
+    ]),
+    element([
+        0x2f1e6510fa28e1f7,
+        0x956e4bf306483325,
+        0x86b719e66422e63a,
+        0x300f7a2e14d92767,
+    ]),
+    element([
+        0xf3fb2ed24a72df6a,
+        0xb7ec0a1e690f809d,
+        0xe5553b000f3e85db,
+        0x11ee84832ca91e3b,
+    ]),
+    element([
+        0x06479da0fb7e88f7,
+        0x9e18877d27866d21,
+        0xa60e16171a73c5c2,
+        0x25f0462a1eec0e3a,
+    ]),
+    element([
+        0xb77fa212264d9f05,
+        0x51d5789d5b6e6c25,
+        0xae946660c6c7d7e6,
+        0x08e8cc99f0674c91,
+    ]),
+    element([
+        0x2dda90455c5c367f,
+        0x881f99c25ea3f3e5,
+        0x88d5a692b5a17f07,
+        0x1bc6d3965a1d24ae,
+    ]),
+    element([
+        0xf95badd929178ac9,
+        0xd7750b3a0aac0ab2,
+        0x00f2a480e05ed91c,
+        0x14b48c35ffa7d1cc,
+    ]),
+    element([
+        0xd601f2818ec6ce44,
+        0x44327ef33b500c05,
+        0xeb4e0d777b400fc4,
+        0x279401f551567ab9,
+    ]),
+    element([
+        0x921ae1eccb1949da,
+        0xd4eb368821aa7544,
+        0xa53eb2858604133d,
+        0x2ced3cc9af999a04,
+    ]),
+    element([
+        0xee62cd58d22e12cd,
+        0x9778c6fabaedc9bd,
+        0x56151abb19fbb513,
+        0x25a9978d6443ca93,
+    ]),
+    element([
+        0x2243b3c9921d44dc,
+        0x01c0d6e11be2d97e,
+        0x5a0cba167790dcd7,
+        0x2272610523af4cc5,
+    ]),
+    element([
+        0xfc8d98eb4339fa13,
+        0xb9955d3203038b0b,
+        0xf4245f582f744ac4,
+        0x2a927dd85f0703e5,
+    ]),
+    element([
+        0x71923a9a437c2a96,
+        0xf21ae664eac29ab4,
+        0x06a353e9d0b09ade,
+        0x08345bf0a4b1dbb3,
+    ]),
+    element([
+        0x08f644406eee8abe,
+        0x45ef7aca566c79a4,
+        0xa914e3cba214cf4d,
+        0x018996820a4a4185,
+    ]),
+    element([
+        0x215b7f2d638b677d,
+        0xa774cc70b830947f,
+        0x5198a6faf0da9fe7,
+        0x2ae0f606b0277c40,
+    ]),
+    element([
+        0xbc35b00583576ee8,
+        0x1533b8051962ee8a,
+        0xf2364d31dabc5eae,
+        0x1ac05913a8a508f9,
+    ]),
+    element([
+        0xf71336b63f9e106b,
+        0xdb2efac99cf316de,
+        0xd6d70ac36199cf42,
+        0x0cefa7ae9ee56ef1,
+    ]),
+    element([
+        0xba378a34c03c49a7,
+        0xfe70f8f083bf390a,
+        0x8d6714fdffaecbd0,
+        0x08ab507365d6bc67,
+    ]),
+    element([
+        0xe82294603cbe1ef4,
+        0x76cd13287977b707,
+        0xaa8dfecefd41a393,
+        0x1a9fcfd6a42843bc,
+    ]),
+    element([
+        0x4d78dade89d6b61f,
+        0x306f555d64e0fd80,
+        0x6498e618726895a5,
+        0x1c34ad19cfe1b9cf,
+    ]),
+    element([
+        0x5b89337a566eeeb1,
+        0xd8325fb3a18374be,
+        0x624af99ce08d9198,
+        0x2f3ec87a7185127a,
+    ]),
+    element([
+        0xe617f987b3d17597,
+        0xfb9aed93108b0663,
+        0x5485580eff796bb7,
+        0x2711e89bafeff734,
+    ]),
+    element([
+        0x81cfb141b155353d,
+        0x9268630c69a29235,
+        0x69241c7b9675508e,
+        0x052595c7f43c4c8c,
+    ]),
+    element([
+        0x4d1aab5eb39874a5,
+        0xbaf1b81a76409961,
+        0x2d07282fbb739b7e,
+        0x2129b33d1f4d0100,
+    ]),
+    element([
+        0x09b0878893cb8300,
+        0xdd42fbbf4972cb40,
+        0x8675e4d93693cd5c,
+        0x2648ba3e1a619dcd,
+    ]),
+    element([
+        0x858a41460dd749d1,
+        0x6c87c8533a2ea5fc,
+        0xfc9152b312294fd1,
+        0x1d3d30f9c2b54070,
+    ]),
+    element([
+        0xe13e76a5459549dc,
+        0x787d8b4ee4fa52de,
+        0x1dbcea80fdf97bf7,
+        0x2bbac8bc90d981a7,
+    ]),
+    element([
+        0x0d1bc7b6728a9760,
+        0x32e2c6d46022be8c,
+        0x68cee843b4ef5971,
+        0x0c3859618e04fdb9,
+    ]),
+    element([
+        0x9a211ac034089482,
+        0x1cd2031979c0bf21,
+        0x8562a1fd94bcdbe4,
+        0x05a7657013662223,
+    ]),
+    element([
+        0x0868244142a3e20f,
+        0x6b3a8c1d20b1f811,
+        0x68762037127111a1,
+        0x154342830c05ade3,
+    ]),
+    element([
+        0xe71cee1e479b81e4,
+        0xe09e1e623951b7a4,
+        0x042ecd38f41648f1,
+        0x0ac7c63cf4992e95,
+    ]),
+    element([
+        0x3d283c7c8b031f40,
+        0x84aab6f21c9356ad,
+        0xbb386a21e9ec1548,
+        0x1b27e98f3fec7f1a,
+    ]),
+    element([
+        0x041d7f32678f1b3e,
+        0xee6d0bce9b107497,
+        0x31f1a4c1d43db1f9,
+        0x095cb4119b2aa3e5,
+    ]),
+    element([
+        0xb896a7ab6817d0e6,
+        0x556d92e571503fa4,
+        0x347e9ba0be36d032,
+        0x11828f18a5494758,
+    ]),
+    element([
+        0x6f5aaef6ce05d7fd,
+        0x090f6de869cf8012,
+        0x9859872910c5a00a,
+        0x08e2ad2f348a7ca9,
+    ]),
+    element([
+        0xc7286ab897ae2f6d,
+        0x46178fe28c04a918,
+        0x731dd0e2b4824d0c,
+        0x091120e75cf67730,
+    ]),
+    element([
+        0x0b73841f8b80c9e7,
+        0x25cc7595efabfedf,
+        0x480257c87a51d4d4,
+        0x23101fa94748cde7,
+    ]),
+    element([
+        0x2aa5643d31b3fc8f,
+        0x8e1f3db7e7af0ba5,
+        0x1d93c66d8e59ea22,
+        0x1419211f75a15b0f,
+    ]),
+    element([
+        0x1168cbeb94783153,
+        0x2809c1873e2a6308,
+        0x48ae8a46e55e58e9,
+        0x1ead85ef32cdf92f,
+    ]),
+    element([
+        0xf52a3c27ee544d24,
+        0xa5c25119e5f62687,
+        0x7c4c2aba56f9113b,
+        0x033cfe8e4f49c018,
+    ]),
+    element([
+        0xa9e9c6649a1b3f4d,
+        0xf150c715b83d3178,
+        0x8bc21f30d1aef959,
+        0x27af0b032a38f441,
+    ]),
+    element([
+        0xd46551fa74d4abd0,
+        0x4ccc98c5a04d295f,
+        0x66b53371ea0b142c,
+        0x14c2c98d392179b1,
+    ]),
+    element([
+        0x32205ebb735776c5,
+        0xc699c3005d6acc95,
+        0x5c8986ff24620665,
+        0x14d846741dd1174f,
+    ]),
+    element([
+        0x1a348dd6ea935d4c,
+        0x27fdb92dca8f36ad,
+        0xab4c45fbc09058b0,
+        0x06a25016c2626eb8,
+    ]),
+    element([
+        0x64d041431d9844c6,
+        0x87ded7c4cf184d8a,
+        0x41659bee5d934fa0,
+        0x0864f82402a682a0,
+    ]),
+    element([
+        0x6f41666f6527ab53,
+        0xaa8e1734c4941ebb,
+        0xd25cd44bbeefd49e,
+        0x2622df768f317cb8,
+    ]),
+    element([
+        0x39f4434a7dcc5ff6,
+        0x437f3cf7de70fd70,
+        0x635013df9309f132,
+        0x04e1c261443e0c6f,
+    ]),
+    element([
+        0xe001c70f26a6d727,
+        0xa5a7630a503ad720,
+        0xb100c649f4e3e588,
+        0x11855e34f2fb1dff,
+    ]),
+    element([
+        0x393532f42d3e4856,
+        0x55dd77329377eb3e,
+        0x268c13c75adcc30c,
+        0x0815346ce37fae95,
+    ]),
+    element([
+        0x52c5f5a7c70e441e,
+        0xcf6c062eacea6b17,
+        0x8745f3b77f187a2a,
+        0x221bd301783fc19b,
+    ]),
+    element([
+        0xdb996d23a5172846,
+        0xb444952835ac66ef,
+        0x0e2ce3d7e2b878b9,
+        0x1604f0f973ca4e41,
+    ]),
+    element([
+        0x932b8ec39383c984,
+        0x7b0336ba2918d221,
+        0x6ea4d33c1e46c9b5,
+        0x0decc1bbcf18df12,
+    ]),
+    element([
+        0x04c4730f0caff1c8,
+        0x5bf1de8c2b5710b9,
+        0xe6eb5c3b9890d007,
+        0x17f78279115436bb,
+    ]),
+    element([
+        0x21ed50a62e7c77a9,
+        0xba82423b16f00c7c,
+        0x177f04949eb344b6,
+        0x1aca3701ed025947,
+    ]),
+    element([
+        0x98792de6356dc903,
+        0x7a4db2aaef9a1e2e,
+        0xd71c5ee6d682be87,
+        0x0e9183b2e44001ef,
+    ]),
+    element([
+        0xbf824912435f00f0,
+        0x23d9d924438fd2da,
+        0x6724908901b0ad78,
+        0x0a3adcee9ab03f1b,
+    ]),
+    element([
+        0x6f747cfa8dbc0874,
+        0xc04b0522689a9434,
+        0x6afc99bd7a34b1fc,
+        0x2a4888f124beb1c2,
+    ]),
+    element([
+        0x3d7fb690387062f4,
+        0x37c27f1a663a131f,
+        0xc0b6cfa6581d8810,
+        0x1317c3df5871a17f,
+    ]),
+    element([
+        0xf4721c0943407ff2,
+        0x48eac7bb288e052b,
+        0x86316023848f1670,
+        0x1a8b0925fb09d1cb,
+    ]),
+    element([
+        0x581e7ba6dd452434,
+        0x0c8b11ed83d7119f,
+        0xf1970a26715a882e,
+        0x215c5a537e162d28,
+    ]),
+    element([
+        0xf218d36e9ce51d1b,
+        0xd3312010a6a6622d,
+        0xfd286387c789b378,
+        0x21d7187aee075bbf,
+    ]),
+    element([
+        0xf67c02b233ed6392,
+        0x025834739f852f3f,
+        0xe6c0900b79a368a1,
+        0x239e26277f8e4357,
+    ]),
+    element([
+        0xb92567e1c639031c,
+        0xac143d701fb9a4c6,
+        0x1b4ff0f5eed3eb1e,
+        0x2592342a30147c77,
+    ]),
+    element([
+        0xbeda6d02ceb93a6e,
+        0xa9757c01c86b7981,
+        0xbf69e5511923f155,
+        0x29584a9d860ad76b,
+    ]),
+    element([
+        0x8e259766544ca36f,
+        0x895a79b81db9b69a,
+        0x29cd5a218e26aa9e,
+        0x25e692352512bbcd,
+    ]),
+    element([
+        0x43dbef769fb4fc82,
+        0xf67e49848ff4f780,
+        0x826c2eaf412f4f99,
+        0x277420aaa6a14055,
+    ]),
+    element([
+        0xc7db7b095bb0f70c,
+        0x6d9d8f7119125f4c,
+        0xa79702681529d685,
+        0x10b87f115b101cc5,
+    ]),
+    element([
+        0xf98d842e4298ece8,
+        0x7973b7c40d6170b9,
+        0x77dcb3180d8aeaeb,
+        0x084cd9a18e574e74,
+    ]),
+    element([
+        0x75143a9a9d5d809d,
+        0xed6c92da4d032a18,
+        0x3b1398833ca1dc86,
+        0x028e2d10875fb6ab,
+    ]),
+    element([
+        0x11ddd2c3f9a21c2e,
+        0xf586e46e5a12287f,
+        0x5ea890b97a44f7c8,
+        0x24c276c249ec7dfb,
+    ]),
+    element([
+        0xe3e8f2b1d774c04a,
+        0xb01967b472f88c01,
+        0x1b275b52dc96c683,
+        0x2af414962b4b982f,
+    ]),
+    element([
+        0x3ea038917a434b35,
+        0x26d9f3a8a949e83e,
+        0xc4be9993568873f9,
+        0x17f6428dac66d2eb,
+    ]),
+    element([
+        0x80fe63f09fdaa4a9,
+        0xb0eaec72b40d0896,
+        0x341e532a8fbe2532,
+        0x038ad19b2b0ae684,
+    ]),
+    element([
+        0x8025b2faad55deb8,
+        0xae7f047336b450c5,
+        0x343fece5841ffc3e,
+        0x0e25dc6a2aa59531,
+    ]),
+    element([
+        0xd0f2adbdab79c5fa,
+        0xa869d489aaf367cc,
+        0x8c55a571ad16370b,
+        0x0a38fe912b752544,
+    ]),
+    element([
+        0x8a1397f536830b10,
+        0xf50c0e0f0537e4a6,
+        0x3e7efbaefa1c4b60,
+        0x1697eaca3391c91e,
+    ]),
+    element([
+        0x884ea9d1507c64d3,
+        0xb971782ed3c463cb,
+        0xe2dbf2bf05b6ecf0,
+        0x0e659312a08998b6,
+    ]),
+    element([
+        0x16a3337a7e03c59e,
+        0x939fe907f8aa36de,
+        0x247e04a7802e89b4,
+        0x29364d421f3db750,
+    ]),
+    element([
+        0x47e9537d00856c96,
+        0x0d1d1cd555a9c36a,
+        0x199d23246a6fb01c,
+        0x2717b72a8f21b635,
+    ]),
+    element([
+        0x8706d66ae3219a2d,
+        0xf32851c57815d16e,
+        0x47737eb42ddf0676,
+        0x2cdf7875947e32ed,
+    ]),
+    element([
+        0x68c919a8d22f39a5,
+        0x26856bc5584eff81,
+        0x4a05815434a86fec,
+        0x182c3af21b658c0c,
+    ]),
+    element([
+        0x7b95920b180520cb,
+        0x42c44c8f8187ff0f,
+        0x5f29410e6a6b4b1a,
+        0x18fd26b2e263a55d,
+    ]),
+    element([
+        0x92b3866f46da22c7,
+        0x17fb8d8da7e7582d,
+        0x77d3cedab1123f47,
+        0x3039b61b5a378f9a,
+    ]),
+    element([
+        0x69b800ed30b7e959,
+        0x1b93774d757c958c,
+        0xb1dfba8f2908b032,
+        0x111f882952db3365,
+    ]),
+    element([
+        0xf76f1c73afa4fb7a,
+        0xcddd900f8a78af24,
+        0xa9200760aeb67a0c,
+        0x194cc0ffe1c72d7d,
+    ]),
+    element([
+        0x249d806f9a72bbb2,
+        0x8fb4e1eeb494911d,
+        0xcaf464fd75d523c5,
+        0x1adb11b9c642457c,
+    ]),
+    element([
+        0xe3358fa306fe2fd8,
+        0xd6966be3028251eb,
+        0x7eaedd093a4ab3f6,
+        0x233c217d7f825ea9,
+    ]),
+    element([
+        0xc95f80e18536d076,
+        0x424a306e0995a239,
+        0xf90eac6e7acf0cb8,
+        0x0fa6eb4bff596173,
+    ]),
+    element([
+        0xdbf13ea3be9db77c,
+        0x2dc8791fd92308cd,
+        0x5aaf30a421f0f9b1,
+        0x0af4716a8ef96f85,
+    ]),
+    element([
+        0xf9c338535ed15b31,
+        0x428419cd2378872e,
+        0x40a0802c95962de8,
+        0x005e15003271ba75,
+    ]),
+    element([
+        0x28834a2c45dd45f5,
+        0xe3e1a8e7ae4448a3,
+        0x8e830b45121c4347,
+        0x2a5fe71282eedc99,
+    ]),
+    element([
+        0xa2f1724c66ecb996,
+        0xb9a0d8a0a36151ce,
+        0x488abc6f436d3bab,
+        0x1cdbe0b23b95a890,
+    ]),
+    element([
+        0x70433e910146758e,
+        0x2a261597ce5025ac,
+        0xd137654ce5176259,
+        0x0bd4e41e5c7e7811,
+    ]),
+    element([
+        0xe795bacb54352011,
+        0x0b6965ecde9a22e2,
+        0x53dbfc02c95635b1,
+        0x1f1ff6076fbf4d2a,
+    ]),
+    element([
+        0x699fc300253f79dc,
+        0x3b1db31d6290db31,
+        0x525a086f73f8db6d,
+        0x2eec16408aece0b8,
+    ]),
+    element([
+        0xcd8b2d43360be47a,
+        0xd95d7ff4ca12c866,
+        0x2a4e231faaa8bbe6,
+        0x19e66c7bbe4481fa,
+    ]),
+    element([
+        0xcce495f1424ee3b6,
+        0xa74ce2018af0e095,
+        0xf1c95d4de05c9d21,
+        0x1dd29906c0cf27f0,
+    ]),
+    element([
+        0xc94fb967b81e4529,
+        0x41a92623dda5200e,
+        0x5a99023c70f033a3,
+        0x0f3e2892c3752e68,
+    ]),
+    element([
+        0x295a7f1790180a6a,
+        0x688abe6489d5f230,
+        0x63a49246758f7e8a,
+        0x1ab17ca76efa534c,
+    ]),
+    element([
+        0xcad2aec98154ca29,
+        0xad3ac5d728d855c2,
+        0xdfcaa92f29b79786,
+        0x0119f5a498cc27cd,
+    ]),
+    element([
+        0x066fac2c3b927311,
+        0x2144dd79cb360322,
+        0xcc1370e891f4f1e9,
+        0x145485949cc04af7,
+    ]),
+    element([
+        0x6e43c9579ce056a4,
+        0x36d8ff7a698fc423,
+        0x42f9b09df77b8eef,
+        0x13965c97c317fd0b,
+    ]),
+    element([
+        0xedc8df529e5a55b1,
+        0xcfbff56e8ed1e5a9,
+        0xba2d1bf65d5f9bbc,
+        0x1f2e3f4637fc61e7,
+    ]),
+    element([
+        0x6eb741d8c0ceec4e,
+        0x0e18d62a20a50b91,
+        0x8752c8e2d23fa2a9,
+        0x04dfd4fa71396c2e,
+    ]),
+    element([
+        0x972fbe81601a36de,
+        0x521b7c9cd1a66ed5,
+        0x1a77856e8de9adae,
+        0x2939acd99b9b1461,
+    ]),
+    element([
+        0x48cc7a1986a674c7,
+        0x345879a114a724d9,
+        0x99862cd404c523b9,
+        0x188dd0764817b039,
+    ]),
+    element([
+        0x58c277e513c2154c,
+        0x7b9e165c6ba5f8a8,
+        0x95288448ccbd6095,
+        0x163abc37969965a1,
+    ]),
+    element([
+        0x26309629948a8c3d,
+        0x30a161f417a4f5c4,
+        0xfc4031ffdd2724d8,
+        0x2e8b0fc55b95bbf5,
+    ]),
+    element([
+        0xe924386135ba00ca,
+        0x983ec17078ab377b,
+        0xb47555ae9180db54,
+        0x0b42ba8f8ff35b04,
+    ]),
+    element([
+        0xbc670b0bba1de18c,
+        0xb633a61356400a84,
+        0xd30e69ca7f641b07,
+        0x25cd759fb17d7193,
+    ]),
+    element([
+        0x5a2e285d57fdeeb9,
+        0xce7b8ab1a0db1851,
+        0x38ecd998d27a37b8,
+        0x2eb5740c53098cca,
+    ]),
+    element([
+        0xcfa95d62b61dc5ff,
+        0xf6660334e9eaba9d,
+        0x9fbda8634c9101c9,
+        0x141518dd7cd59c4e,
+    ]),
+    element([
+        0x980e8a558992fd01,
+        0x07ec738e05147045,
+        0x4c780de1057e3279,
+        0x2e1ddb2f34103c3e,
+    ]),
+];
+
+/// MDS Matrix in Row-Major Order for [`Spec2`](super::Spec2)
+pub static SPEC2_MDS_MATRIX: [Fp<ConstraintField>; 9] = [
+    element([
+        0xfad2b89015555554,
+        0x75101f9f5db369e8,
+        0xb4ea4db753538a2e,
+        0x14cf9766d3bdd51d,
+    ]),
+    element([
+        0xbc1e0a6c0fffffff,
+        0xd7cc17b786468f6e,
+        0x47afba497e7ea7a2,
+        0x0f9bb18d1ece5fd6,
+    ]),
+    element([
+        0xd745397409999999,
+        0xb4ada7d483c3efa8,
+        0xc49ca2f8e57f3161,
+        0x162a3754ac156cb3,
+    ]),
+    element([
+        0xbc1e0a6c0fffffff,
+        0xd7cc17b786468f6e,
+        0x47afba497e7ea7a2,
+        0x0f9bb18d1ece5fd6,
+    ]),
+    element([
+        0xd745397409999999,
+        0xb4ada7d483c3efa8,
+        0xc49ca2f8e57f3161,
+        0x162a3754ac156cb3,
+    ]),
+    element([
+        0x7d695c480aaaaaaa,
+        0x3a880fcfaed9b4f4,
+        0xda7526dba9a9c517,
+        0x0a67cbb369deea8e,
+    ]),
+    element([
+        0xd745397409999999,
+        0xb4ada7d483c3efa8,
+        0xc49ca2f8e57f3161,
+        0x162a3754ac156cb3,
+    ]),
+    element([
+        0x7d695c480aaaaaaa,
+        0x3a880fcfaed9b4f4,
+        0xda7526dba9a9c517,
+        0x0a67cbb369deea8e,
+    ]),
+    element([
+        0xf41575289db6db6d,
+        0x07daec5e847b8b05,
+        0xea0fce347eecc0e2,
+        0x02017ed283b7fb4f,
+    ]),
+];
+
+/// Additive Round Keys for [`Spec3`](super::Spec3)
+pub static SPEC3_ADDITIVE_ROUND_KEYS: [Fp<ConstraintField>; 252] = [
+    element([
+        0x928d05cc919b8343,
+        0x54fddb01b9dfa4ae,
+        0x707a5a9dd85f741b,
+        0x0a4cb7f41fd3f693,
+    ]),
+    element([
+        0x052f330e7e72df0f,
+        0x780fd7c0c111ed92,
+        0x9b1157ef644a11a2,
+        0x059f3ea23808dd74,
+    ]),
+    element([
+        0x26fc6306886c49bf,
+        0xe05d93c6aaab91fd,
+        0x0e8bfbd874da53df,
+        0x0d6a49db44c63d4d,
+    ]),
+    element([
+        0x06c4036b3d7c65bb,
+        0x92f9d87fa2128838,
+        0x06884d2427c1ad73,
+        0x2ccd95169fd2d098,
+    ]),
+    element([
+        0xa1ea1d9498b06e84,
+        0x213c03c7afe4a8ac,
+        0x55ec0ffa781f0b64,
+        0x2c2cbefdea41515e,
+    ]),
+    element([
+        0x31e78a3e6c34f5b6,
+        0xeddcb4c61e5e11b9,
+        0xf52c50c530c57c0a,
+        0x1935fd9180aa41a8,
+    ]),
+    element([
+        0x57f03927d4530f4f,
+        0xc6d49d78e3459509,
+        0x5e33d6eb9b58c5b2,
+        0x304ceddb013f43b4,
+    ]),
+    element([
+        0xffd65abe467f2808,
+        0x421cd1f27fb8cfa3,
+        0x64fc7d295342e910,
+        0x1a59fe2a7a1ee86f,
+    ]),
+    element([
+        0x736ad2200225f8a6,
+        0xe9bcf4c45b040492,
+        0x2eb63e7180329096,
+        0x19271080e9f3ff75,
+    ]),
+    element([
+        0x70293fe5031631e2,
+        0x61c2a6b50590bb96,
+        0xb568f152ce0039c4,
+        0x1174f9a8b40d2d44,
+    ]),
+    element([
+        0x3584cde5318611af,
+        0x7c685edc4426c02f,
+        0x032aef8664fd6f07,
+        0x2e6ce96e9551f4e7,
+    ]),
+    element([
+        0xb6108d9fcffa945e,
+        0xdba8d7730f999aad,
+        0x632d2167dffe2ee0,
+        0x1c92e8a884bf4fa5,
+    ]),
+    element([
+        0xd52f85d32bb459e1,
+        0x739bc00ac535f274,
+        0xb37fa354724aacca,
+        0x1ec8f717479435e1,
+    ]),
+    element([
+        0xb29c3a20ba41e866,
+        0xd22aee3c381de79b,
+        0xea7c015c4d949e86,
+        0x10fd6b3fbeccf7d0,
+    ]),
+    element([
+        0x775fc41998420a52,
+        0x30c7684fc8026c0b,
+        0xe37c893871628189,
+        0x1c60dd515cd28e07,
+    ]),
+    element([
+        0x3363da983b5277d9,
+        0xbc1982166b0f91ec,
+        0x3c718a8f33d4fdf9,
+        0x1967c0d00d0905af,
+    ]),
+    element([
+        0xb2c11af48cbdb056,
+        0x188254676227ce29,
+        0x886c2abd6b7c8b0c,
+        0x252653548f8e92c1,
+    ]),
+    element([
+        0xd5aca3aa47709a05,
+        0xf9d45d33fbde38fa,
+        0x000ad2166050705a,
+        0x2fa20080f4619505,
+    ]),
+    element([
+        0xa731aa658374ecbd,
+        0x5d497db7a9703eb8,
+        0x208aee17ebfca251,
+        0x1298ccca82b50f90,
+    ]),
+    element([
+        0x862c4f8f979d391d,
+        0xcc3ae2bac34b9695,
+        0x8ab21a225721774b,
+        0x151c1599b0435e37,
+    ]),
+    element([
+        0x0905ea3fbef34cbc,
+        0xbc145d2c39d7be1b,
+        0x3674de34da02e739,
+        0x160fa49d05ffa89f,
+    ]),
+    element([
+        0x35efb03b6974b7e6,
+        0x6b664112d64158c1,
+        0x622c6020c45c6f3c,
+        0x09acd04e2c036d2b,
+    ]),
+    element([
+        0xcd4be97c8f964fe9,
+        0x3e35a2a7e9f08f39,
+        0x4aea042c81208205,
+        0x017f7f9bae44d742,
+    ]),
+    element([
+        0x22c1414b20aa2f30,
+        0xe04800640dfa772b,
+        0xdd50682464973d6f,
+        0x2c030d51c14f0450,
+    ]),
+    element([
+        0x77060b2fe275b778,
+        0x287442cad56fd8c5,
+        0x64242472b3a7562a,
+        0x11014538e08372b1,
+    ]),
+    element([
+        0x5a42fb2713403a94,
+        0x707b7d81c7baa2f1,
+        0xb19a7be2b13ad3a3,
+        0x2717b881c5967f69,
+    ]),
+    element([
+        0x6c2f62655fb143ad,
+        0xff7c999d2a595e25,
+        0xe5c299c67ef7cd69,
+        0x0002714a96504248,
+    ]),
+    element([
+        0x1ef31c3511e27f59,
+        0x93cc6c609b89c483,
+        0xff71ec89b8279f8e,
+        0x23c3cd9359f0fba6,
+    ]),
+    element([
+        0xdb5c6e041ea03415,
+        0xea00a9d932e51baf,
+        0x96e67caf82de6c0a,
+        0x2e74d1612ee041e9,
+    ]),
+    element([
+        0xeb56220e0d4b55c4,
+        0xdcbb883eb3bab9be,
+        0x78535ee7ce7b4596,
+        0x0e50c277180c8517,
+    ]),
+    element([
+        0x73e885fa964c1ad1,
+        0x58deec9995eaf944,
+        0x036d529c4ddfca44,
+        0x182142bfbf6708a5,
+    ]),
+    element([
+        0xfd012c00a258829b,
+        0x9a261a0d67bc2563,
+        0x325b3df0491cf5a6,
+        0x27455a666a040d71,
+    ]),
+    element([
+        0x5dad690d325687ad,
+        0x6c137f992c590e29,
+        0x5cbc660063896f8d,
+        0x1ef9c454a85e6e7a,
+    ]),
+    element([
+        0x7bd299cb7b37b66d,
+        0xe40ea0e1dac0f12c,
+        0x3b6c401c0ab51044,
+        0x20adf0470a9a4701,
+    ]),
+    element([
+        0x288f79ea10acd34f,
+        0xc0c16417d7d0cdc3,
+        0xdc7592d9d3d586dd,
+        0x27f28c2dfddb5f20,
+    ]),
+    element([
+        0x188190f706a000be,
+        0x991e0407a1d63982,
+        0xef0734dbc5419d68,
+        0x20b75e569638026e,
+    ]),
+    element([
+        0xf7263c401a0dcf99,
+        0xb32e775d4f93c9bc,
+        0x510262c9967c7251,
+        0x105cbc7518c84434,
+    ]),
+    element([
+        0x0dea74e2af1180f7,
+        0x4b5f0fc0ce20fa0d,
+        0xdca90572d15f514f,
+        0x06094a0bb0adb418,
+    ]),
+    element([
+        0x1cd7cb63f5c65407,
+        0x98720948b6af37ce,
+        0xdd035027eacd7570,
+        0x0d26a8f32fef25f5,
+    ]),
+    element([
+        0x8edcec822170322c,
+        0x855cae2d924067fd,
+        0x8f625f8132d264cc,
+        0x25da4d52c50e5cd1,
+    ]),
+    element([
+        0xd9e838e7a1c50779,
+        0xd8184061a530c7ef,
+        0x9fbf05e95a36b47b,
+        0x16b2021b97a75d75,
+    ]),
+    element([
+        0x4b927063b8018377,
+        0x9811b4c5a5487a70,
+        0x2bde2ea82167a0e9,
+        0x173f7fa368f1c149,
+    ]),
+    element([
+        0x1092803dd641ae17,
+        0x0a5f79992c67884c,
+        0x089a3e14e200c943,
+        0x22cf542e6754963a,
+    ]),
+    element([
+        0xcff19830f0590d9a,
+        0x1d2372c489ffdf62,
+        0x18fcd72237bf1f5a,
+        0x299211206ab1ae35,
+    ]),
+    element([
+        0xe322bd3c254dc444,
+        0xb7dcd3dca60a9d28,
+        0xdfc9e3aff0a26a8c,
+        0x0c706de33f65f6ce,
+    ]),
+    element([
+        0xcaaf2aba763cc57b,
+        0xaf258b75378a5a71,
+        0x1a4a48a256792ce5,
+        0x139dc09e0a9944f6,
+    ]),
+    element([
+        0x44ab262cd22ab2ec,
+        0x1b90d54e99fda7be,
+        0x700bee4a672b698d,
+        0x173d20b8050cb098,
+    ]),
+    element([
+        0x0f4a11fc9fe28bef,
+        0x4c09d22b3907797e,
+        0x5890393758bd3107,
+        0x0aa38d078779c83a,
+    ]),
+    element([
+        0x73cb2387ce575f90,
+        0xeeb9ae1d7c4cc5f3,
+        0x5623d40cd73cb952,
+        0x12272e57af9dc948,
+    ]),
+    element([
+        0xfecfd17e8fe3989d,
+        0xfde32249eff3a66a,
+        0xc1bb30175da547b0,
+        0x0e5c58e6d3b1a292,
+    ]),
+    element([
+        0x967ee751d549e5f7,
+        0xf2711c1e8aa461d7,
+        0x1e50e2c527fa5dcd,
+        0x058aa6303e73bd6b,
+    ]),
+    element([
+        0x925db558c4ac9ce5,
+        0x154211fb40c4a53b,
+        0x34b976f82ca1d57e,
+        0x06e8b70e2772b64a,
+    ]),
+    element([
+        0x8cf865faaf7ba8fe,
+        0xa7bc54e84bb51810,
+        0x88d826c98eed555c,
+        0x022eddd9cb88ba92,
+    ]),
+    element([
+        0x3a361f7c6952e908,
+        0xd150550941932029,
+        0x6323baa9eadbacb4,
+        0x0442efc8be24356c,
+    ]),
+    element([
+        0xd663feb9213835f6,
+        0x5fd95f631c21685c,
+        0xc385adf5d12b07aa,
+        0x20bf57a2dbc9d975,
+    ]),
+    element([
+        0xb09d27a815f18768,
+        0xb16be4a771e7cda0,
+        0x445d7ba724511b70,
+        0x23a6432c24b21c86,
+    ]),
+    element([
+        0x2de21f1193a39b5c,
+        0x0017d43922854384,
+        0x3514e2f419e5ca62,
+        0x1984fc328e1c8e5b,
+    ]),
+    element([
+        0xbfdf096ec8014059,
+        0xa8d250d2eef60efd,
+        0xe0da110961baa433,
+        0x2fa58d4d62ece668,
+    ]),
+    element([
+        0x6fc1d9837fc943f3,
+        0x063f5184def7c935,
+        0xd9065605edc1b67f,
+        0x03ac1da1369851bb,
+    ]),
+    element([
+        0x06253733815e559d,
+        0xbcfe79c2847a0544,
+        0x7310de9d37c930da,
+        0x05f4f2e87e16ff41,
+    ]),
+    element([
+        0x409999ced0587fce,
+        0x847aacf1a8cc51e8,
+        0x7e68c65e5f374281,
+        0x0f3ed4b2f4109051,
+    ]),
+    element([
+        0x14e86f51c2838bc5,
+        0x58add03420621e1c,
+        0x905506327c4a8fc1,
+        0x09101633bb226e82,
+    ]),
+    element([
+        0x4ba89d1284a00513,
+        0xc611f9039e1d5de6,
+        0xed3a62e03578e226,
+        0x04e2ce74e83270b4,
+    ]),
+    element([
+        0x07a3c264c8b168f2,
+        0x9a11969efecf0c86,
+        0x4f385f354f40549c,
+        0x2f9cd50993b560a1,
+    ]),
+    element([
+        0xe5f807c0f99430cf,
+        0x8dbfe6818f43bb4a,
+        0x1175ae2d0d7e08b9,
+        0x1b0dd523d20aed16,
+    ]),
+    element([
+        0x0c5f55635d8c2858,
+        0x4b2f3f4bf550889d,
+        0x9af04927de4d9cca,
+        0x081119384a417887,
+    ]),
+    element([
+        0xe4d2a6e683282657,
+        0x7e6629242844a4e3,
+        0x9a8c38d41c3f03a0,
+        0x19911d02857be781,
+    ]),
+    element([
+        0xc6ac803f7e4d87c8,
+        0x7dc50b09a75ffdf0,
+        0x53f17a1db62d2780,
+        0x28f05e58a4f542f0,
+    ]),
+    element([
+        0x49772e08a0b3b27d,
+        0xd1ea7bd644c76e95,
+        0xe4c3490a4f435725,
+        0x0a71b634ee23889e,
+    ]),
+    element([
+        0x5f4d3a25654e8e27,
+        0x3384189b7a27c611,
+        0xc0ec4c92ae4f43bf,
+        0x1de8c6931ac467f4,
+    ]),
+    element([
+        0xfbf5aece1ae221f5,
+        0x3ed5e043d99e39d0,
+        0x62fa99e4a8eeb4d6,
+        0x08574b2a17053690,
+    ]),
+    element([
+        0x790c6ff01513f374,
+        0xd862f3ccd59b484d,
+        0x9fe20fb41c4101c0,
+        0x0f17ab1e7e9971fd,
+    ]),
+    element([
+        0x88f83282832256da,
+        0x5b6da2cf6443b0fc,
+        0x3587231dd3ec9e6a,
+        0x2bc331bf9a545f72,
+    ]),
+    element([
+        0xccaf67a6491aad37,
+        0xde53f532a0cb5554,
+        0x774a66bb5fab5e77,
+        0x23de3a7a304e754d,
+    ]),
+    element([
+        0x46ce19857fda1708,
+        0xe0492a3bc675c5e1,
+        0x9e3adbe1e67a31ce,
+        0x2bb709c32003f2a7,
+    ]),
+    element([
+        0x571b7900dedf1701,
+        0xf0bfc172925bb089,
+        0xbd6b50ddf120becd,
+        0x2c0b99950a3e91dc,
+    ]),
+    element([
+        0x63be092ced2b8c02,
+        0xf1010eb7a347090d,
+        0x344b873b90bf8df2,
+        0x16e1561b5f26b8fa,
+    ]),
+    element([
+        0x977bfbb6aee03e75,
+        0x5d54a1f7a9966ac2,
+        0x88472e29ce71e70d,
+        0x1fddc2ae445fa3fa,
+    ]),
+    element([
+        0xc63fe238a5866187,
+        0x6f4aa79166e75568,
+        0xaec1e86d60efd4e2,
+        0x0aad3e0f4f317106,
+    ]),
+    element([
+        0x5e3155a2e45ee566,
+        0x7284754e0534ed92,
+        0x07e2c73747a5cc1f,
+        0x21687925d865ecd5,
+    ]),
+    element([
+        0x3a620f019d06c420,
+        0x6292460d943961e5,
+        0x9096ee79ff253801,
+        0x111d8b542ab94aca,
+    ]),
+    element([
+        0xa4e0d716707eea1a,
+        0xd41d195e40d8eb63,
+        0x972bf192bc948d38,
+        0x2d66ba61a9898ab4,
+    ]),
+    element([
+        0xeebae71f2fb538b0,
+        0x6a0acebe630f26e6,
+        0xcdb52c9286c62d66,
+        0x1cd95bbdc41d3aeb,
+    ]),
+    element([
+        0x1b5a0d53c80345cb,
+        0xa171d7db5bbec5b5,
+        0x960c071ca0299a80,
+        0x0c598034db8381c6,
+    ]),
+    element([
+        0x349fc0f2884ba4e5,
+        0xd82dc142b0ab4eb8,
+        0x45689c6833972f13,
+        0x011b80d089c559db,
+    ]),
+    element([
+        0xd22215a3cb05edf1,
+        0x973d272d39fe063a,
+        0x1c97414a0576bd90,
+        0x18b8cb40f464da59,
+    ]),
+    element([
+        0x466a5ed2f17c9eb7,
+        0xc8441466497266f4,
+        0x10a06f6f176d42e6,
+        0x23de89c6fda45b41,
+    ]),
+    element([
+        0x0922becce557b705,
+        0x9ef8f62da2a9a152,
+        0x63c776134edac5af,
+        0x0f05190d1248f877,
+    ]),
+    element([
+        0x358cb1bb9e4046cb,
+        0xa7a2d74687aa55a5,
+        0x0328764978633f63,
+        0x1a53ca86b91e91bb,
+    ]),
+    element([
+        0x10f71fdcead78066,
+        0x6c9faa370b782b7f,
+        0xf2b63432befafb30,
+        0x03748ec3de12441a,
+    ]),
+    element([
+        0x5a4a516271a3cec4,
+        0xd84b7623a0bd3ace,
+        0xa9259dd480dc6614,
+        0x2d3231d2c632d89e,
+    ]),
+    element([
+        0xdbd4931fa1579cc6,
+        0x13263ffb80143887,
+        0xbb51cd5c85fae101,
+        0x23202e4597ed6642,
+    ]),
+    element([
+        0xa4592cdedb6c8281,
+        0x3e263afeb2b5b019,
+        0xfbb55a6335f9c029,
+        0x2eea422a98b965b1,
+    ]),
+    element([
+        0x6f916a300607aa56,
+        0x7505759013987c65,
+        0xa7f9d72f930daf1b,
+        0x23ceae8a52e8ede5,
+    ]),
+    element([
+        0xd65ee2da174e6dd7,
+        0xaa42493daf34b8bd,
+        0x5f365104a0732ac6,
+        0x2def796b683bbda8,
+    ]),
+    element([
+        0x7f50f6b90ca4e0d5,
+        0x2241149acbd7ddf9,
+        0xdf380e38e6fa889e,
+        0x0cf1a040ec907fc7,
+    ]),
+    element([
+        0x42ddd205db753d6c,
+        0xe8dc5264cf869b40,
+        0xcb6081278e75ecac,
+        0x0dd47e14a6103941,
+    ]),
+    element([
+        0x435b347328f77b95,
+        0x44546c5908616807,
+        0xb5042310cd1fc9f1,
+        0x2fde1a85e95ffbc5,
+    ]),
+    element([
+        0x4b3aefb3fce6694c,
+        0xc8000c294f9133ea,
+        0x6c6c423a1bdf23c4,
+        0x182dd1c76948d441,
+    ]),
+    element([
+        0x79731b4bf2962847,
+        0x2307f228ed3ed8ea,
+        0x8838d9c38edff472,
+        0x1e383e09f167cd81,
+    ]),
+    element([
+        0xdb7c21013472bf9f,
+        0x1ce017cb7e3a6917,
+        0xf745e045e2f5846d,
+        0x206c557e0b640d86,
+    ]),
+    element([
+        0xc0a98c952fb9d7fb,
+        0x91a14addd0b9432e,
+        0x5fd4b5e48bfb498a,
+        0x2570db768ca7b8bd,
+    ]),
+    element([
+        0xaafdf93f225b9d56,
+        0xbf39f129b8f5c51e,
+        0x916745ee8fb3731b,
+        0x0940040ebce21435,
+    ]),
+    element([
+        0x781887f55bd4b7f7,
+        0xa7eb86bfe2e9bc1d,
+        0x4530a9991466dac9,
+        0x0683b5bbb850895d,
+    ]),
+    element([
+        0xaa5696368f78a0d5,
+        0x75cb9c786ede9b52,
+        0xaf60a55703ad4d60,
+        0x27962b88944293d4,
+    ]),
+    element([
+        0xfe019bbb593eda36,
+        0x040563f5fe177831,
+        0x98e77b0e07cd5d99,
+        0x005a9530e1e3ed97,
+    ]),
+    element([
+        0xb37c9284c54789f0,
+        0xd40e12df8dfa49bc,
+        0xa459e7b7dc137895,
+        0x304febe908d958e9,
+    ]),
+    element([
+        0x0632682d8562244c,
+        0x42138db2cbf89292,
+        0xe6efd1c05d1c5f4e,
+        0x22e9b83327940585,
+    ]),
+    element([
+        0x785d0967eb3944d2,
+        0x13e4657540a372d6,
+        0x964b48e2cded35c8,
+        0x0654fd442b9290c4,
+    ]),
+    element([
+        0xa3d66eb6a1798b8d,
+        0x0d4edb3b0987988d,
+        0xb1f20c46fafc3b58,
+        0x0f278523a40c8739,
+    ]),
+    element([
+        0xf8cd946cd1ebf9e3,
+        0x6231194d382ff95c,
+        0x2c0d4ab6208952a3,
+        0x1a02f1d79fbca06f,
+    ]),
+    element([
+        0xded1a2e11efbaf6d,
+        0x00d3c05e6f343ed7,
+        0x54937da4e007389f,
+        0x09621d60670168db,
+    ]),
+    element([
+        0x55273336d652bd09,
+        0x1263f7e1a2faad07,
+        0x1bee45e16022b553,
+        0x0ea5d2e9dd2c0c42,
+    ]),
+    element([
+        0xd64089310d559cce,
+        0xa012352d9987d633,
+        0x1c73d16ab31434f2,
+        0x09b52bd9a8a2c417,
+    ]),
+    element([
+        0x715bc0bbae8b30a4,
+        0xd2ca3df9415ffb84,
+        0x3af230c1cdefa2f5,
+        0x2ff8059145125b98,
+    ]),
+    element([
+        0x4745b49128a9ea2a,
+        0x6328e1a7474b4ae0,
+        0xcc1ad5cc3b461e02,
+        0x1edc2f7982c57ab8,
+    ]),
+    element([
+        0xe574e364cc96d2d3,
+        0x7627585b65e11307,
+        0xa1a5d91dfcb0982b,
+        0x2d56977119edb439,
+    ]),
+    element([
+        0x05240d6920f172f6,
+        0x7dc0e3d28de0f011,
+        0x1de296cdf6f6ca86,
+        0x2b7bb2243530fae2,
+    ]),
+    element([
+        0xd46032db2b4c9f97,
+        0xb819b2c717bceabb,
+        0xe43df2d48cb903fb,
+        0x244405148b1ce83c,
+    ]),
+    element([
+        0x18c6ba9994b6d914,
+        0x6ba06a48fae48a9a,
+        0xf6a545bc8ea8ba1e,
+        0x084e4a9d1dbcc3f0,
+    ]),
+    element([
+        0x1253f546c9d73ba2,
+        0x6850a17c7cb5008a,
+        0xc51185e8ebe1cd67,
+        0x08e51ebb08b11281,
+    ]),
+    element([
+        0x910941450a12087a,
+        0x0a6041b706e15928,
+        0xeb7c8d6545a2820a,
+        0x170a80efc12221d8,
+    ]),
+    element([
+        0x71b8f1eca28a9080,
+        0xbb17cbccc152f552,
+        0xa7718822229d7299,
+        0x1df5320b131e4a50,
+    ]),
+    element([
+        0x9be35b03987d422f,
+        0x783782fcf2227f20,
+        0x13ad6e5a12e43307,
+        0x23aaa802574645b1,
+    ]),
+    element([
+        0xfd8782259f37c348,
+        0x8e274b99e5b5ecdb,
+        0x433213faa5c007b5,
+        0x293a941975a93b8a,
+    ]),
+    element([
+        0x81f3985d9238e55a,
+        0x8fc81701200a5c17,
+        0xb7f843e7456721c9,
+        0x05d80b66d9ea9a00,
+    ]),
+    element([
+        0x304de4019eb42608,
+        0x2f9f1ca2889c86e2,
+        0x1cab70bdcf3ebf15,
+        0x136fc9d6b9dc0bfa,
+    ]),
+    element([
+        0x58dec00d12f27e2a,
+        0x687903232c70db75,
+        0x181b52a5220f5f36,
+        0x29f71029dd5ec2bb,
+    ]),
+    element([
+        0x004f5e337a833297,
+        0x051d26652d7d6011,
+        0x48ee38a3bd6c38c1,
+        0x18b985daf818c030,
+    ]),
+    element([
+        0x8c261ed32b34cd5e,
+        0x444910eb98f7da1d,
+        0xc7df71c10a417a5c,
+        0x26b4313d3b8ed8a0,
+    ]),
+    element([
+        0x8214eb4749802f50,
+        0x0463635d297358d7,
+        0x551307a06ad975dd,
+        0x00967069f0cbaeeb,
+    ]),
+    element([
+        0x76059b535fe56541,
+        0xe21e33be2697927b,
+        0x8a51caa260881874,
+        0x2d689150b44a2ad7,
+    ]),
+    element([
+        0x02a30334006c6787,
+        0xcd9d485139370d8a,
+        0xc0cae9897c5df985,
+        0x2473df405eb98c0b,
+    ]),
+    element([
+        0x9559882b15605b5a,
+        0xd1035f6ddce7e228,
+        0x84730dc27bdfa859,
+        0x03afe060d50e1512,
+    ]),
+    element([
+        0xfb643f42e0fa5cd9,
+        0x17e949750c9ae9c0,
+        0x5bfbc6d3d7af739c,
+        0x2c2632fa2d7faf4f,
+    ]),
+    element([
+        0x08aa2953162ccbf4,
+        0xd643c9f62406fc9f,
+        0xf87812938eeba661,
+        0x2c60adfc0862d793,
+    ]),
+    element([
+        0x3d59a2207c4142e1,
+        0xbf06908879daa65e,
+        0xa7d0c8d4267549bf,
+        0x009bf61638ee8bba,
+    ]),
+    element([
+        0xfe8a30d7262e5f5b,
+        0x570491ebef23602e,
+        0xca105da3f4f61c61,
+        0x1df7edbc3a8e47f9,
+    ]),
+    element([
+        0xa7d5a4c1e9b21ad1,
+        0x0a0d0d6d0dca0af0,
+        0xd9a712064c734574,
+        0x09ae48f3e462ecab,
+    ]),
+    element([
+        0x5d5a9c46aa294427,
+        0x46c25dc7ed4841ff,
+        0x6eaf33c0fe360936,
+        0x087c66f7d4fc88dd,
+    ]),
+    element([
+        0x1060912544d9b045,
+        0x01a2a4cf51a94373,
+        0x7546a45a909c2391,
+        0x00f6b97ad8bba75e,
+    ]),
+    element([
+        0x1ea97eb2339aa332,
+        0xe415f1ca45a6f9bc,
+        0xe35c456afe8fde47,
+        0x105f010e205fe7b8,
+    ]),
+    element([
+        0xa85fe4532633297d,
+        0x6f84ce58f926fc0f,
+        0xbf7c354776025b8f,
+        0x07287871d16d01b8,
+    ]),
+    element([
+        0xee0127b05d3c024d,
+        0x41c9b5f6057d519e,
+        0x9c3fd7b33270cf92,
+        0x2da9d7495a54fd6a,
+    ]),
+    element([
+        0xff8802a834f5220c,
+        0x8eefca9e80cc21f3,
+        0x56aedf58573da7bc,
+        0x0862c1450ace2e3b,
+    ]),
+    element([
+        0x4bb7c6921c38f909,
+        0xa76432b6485776e7,
+        0xd9e6328ceb5aa8f8,
+        0x121b8aa25a93fc01,
+    ]),
+    element([
+        0xc7a75a629941a09b,
+        0xaa09b3d70311471b,
+        0xf836f56f956f494b,
+        0x1f57bde5bf29337c,
+    ]),
+    element([
+        0x530cd85fabf222c4,
+        0xc2ab62ab6eee2eb0,
+        0x1d912a22b1ce8644,
+        0x0158e7e1de44048a,
+    ]),
+    element([
+        0xa69bc82a9100ca7b,
+        0x6a60a0e9b70e76d0,
+        0xa1e56bbdcdd6efe8,
+        0x063a8be08dd8c001,
+    ]),
+    element([
+        0x0f445d95b0286bbf,
+        0x0fe0326c85b2023e,
+        0xe8c03f261c5ada89,
+        0x266239d9e48e26bf,
+    ]),
+    element([
+        0xdc72edde60e71c81,
+        0xf710a24483af92e5,
+        0xcc89225366edc7bf,
+        0x03a92b54bd9b5fd9,
+    ]),
+    element([
+        0xc6b6f072672404e0,
+        0x005fb6ed42c920ee,
+        0xbccb240c302ab2bd,
+        0x2c88d9094a8a9328,
+    ]),
+    element([
+        0xb4a6898a3db3d95d,
+        0x32fe8be43b6e670d,
+        0x0d5df63d82c176e8,
+        0x2d9b317088f8d546,
+    ]),
+    element([
+        0xacfd4398f81df58f,
+        0x23105cc6c49ee478,
+        0xe5a24718d50c9a29,
+        0x24d3c831c802c01a,
+    ]),
+    element([
+        0x0b8aa97bb00382c5,
+        0x7d9c2e71311ba216,
+        0xd1911b5a79d76d37,
+        0x020f184f8136280b,
+    ]),
+    element([
+        0x087d2ab5636ca6c1,
+        0xf6370867dc1cd621,
+        0x4b0f9eaef2130112,
+        0x244feacc4a86bd2d,
+    ]),
+    element([
+        0xf491c77af5478ce3,
+        0xdf2760667f6d1c3d,
+        0x173bf3843b8acd14,
+        0x3048575c84f84932,
+    ]),
+    element([
+        0xa5884d7545e99618,
+        0xf00bd47ea3340125,
+        0x835eba123ade5810,
+        0x08988a356fa020da,
+    ]),
+    element([
+        0xb2f304009bc7f901,
+        0xa7aa6e5902f6e0c4,
+        0x446d97b8c2e462ed,
+        0x08a924c0749a7915,
+    ]),
+    element([
+        0xb363e0bcef5391ea,
+        0xf9c1992bb3dfd034,
+        0x10493a7314640e68,
+        0x088a5ac365d42a87,
+    ]),
+    element([
+        0x8960dc236ad6e7f8,
+        0x9a8f1dd79d34dc63,
+        0xc7b18e93aeeb4c0b,
+        0x1b38d09e5c02eecc,
+    ]),
+    element([
+        0x79dfacdfe985db3c,
+        0xe6fee5a1f8ab8eaf,
+        0x1d95f8d1e6af71c0,
+        0x03411b227b172e81,
+    ]),
+    element([
+        0xeaecf819f31e4dda,
+        0x8729c6e801f6980b,
+        0x94b01261f623d55f,
+        0x2b94ffe2d5cbc650,
+    ]),
+    element([
+        0xdcea7195f9bd7346,
+        0x7a6a067b9d79b032,
+        0xcda416411f34284c,
+        0x063de09fd49ccc7d,
+    ]),
+    element([
+        0x0a39ea687da897e3,
+        0xc4b8f85ee3d591a8,
+        0x4fbfc9ac4ad463ec,
+        0x11cc62acc6d8d0b8,
+    ]),
+    element([
+        0x2a9628afa028f300,
+        0x2955d5f815bc0b54,
+        0x8d7477bda8e89349,
+        0x14451e765cb3ff5b,
+    ]),
+    element([
+        0xb47649feefc53314,
+        0x282a3796494fa7de,
+        0xb1425c733713e0f4,
+        0x05cef83dab37a19d,
+    ]),
+    element([
+        0x58d7a975d9f21190,
+        0xb9c0e71d3a33ac5f,
+        0xa0eea6c9b31bec44,
+        0x15f62f1818a12c32,
+    ]),
+    element([
+        0x36798b1c634991a9,
+        0xa2f1a7f0a21e2d07,
+        0x14372cde9328d750,
+        0x17f386ee29df8f15,
+    ]),
+    element([
+        0x9125220d33dc4f3d,
+        0xc4dba074dba064ea,
+        0x4d8f6fdbfa8b58e3,
+        0x07c29ed79b1e5576,
+    ]),
+    element([
+        0x129cdf89b81689bc,
+        0xa72c78e70a542a53,
+        0x2e01d670a7fa3f3a,
+        0x1f67e6152ce378fe,
+    ]),
+    element([
+        0x7a977a9fca58c501,
+        0xbdc690d312c9f84e,
+        0x14cae47e65272bad,
+        0x0e417e0db935949d,
+    ]),
+    element([
+        0x982065bc2c54bbb8,
+        0x1b9d802abcbcc100,
+        0xdb602b9744f74aba,
+        0x11d467bd75a7b044,
+    ]),
+    element([
+        0x5a1bfac198285704,
+        0xe3c6686ed9998d91,
+        0x764a3b3f266cea4d,
+        0x014759e8d9d61dd0,
+    ]),
+    element([
+        0x0ea07332f4ad1378,
+        0x375914692f665acf,
+        0x8284d5e2aaf95681,
+        0x044683e51cf7c5dd,
+    ]),
+    element([
+        0xfb1daaeebe112e95,
+        0x88c214dec8a71165,
+        0x903460e78b552877,
+        0x255f1793877ee73e,
+    ]),
+    element([
+        0xcf61a3d587ccf2ea,
+        0x55d85399689e0c1c,
+        0xf63b2b6dafd33f9d,
+        0x013fa37c160e74a4,
+    ]),
+    element([
+        0xc16a9e2c60d9e699,
+        0x999f5e0119d999fa,
+        0x59acdaf01f0e61a3,
+        0x14e09b25425f3635,
+    ]),
+    element([
+        0xadc13e72283b7102,
+        0xedf625a3820e269d,
+        0x3968be1bf7114e25,
+        0x22de7fda3f1ab4b5,
+    ]),
+    element([
+        0xda1b9828c218c05a,
+        0x8953f1207d1ffea0,
+        0x7078a76e0085631d,
+        0x124ed4ff998c77d3,
+    ]),
+    element([
+        0xfbe28737b2e3d885,
+        0x3f15ee4f452c1f28,
+        0x9eb5823344875263,
+        0x2bd80b9555e843bc,
+    ]),
+    element([
+        0x1b5bb71f5cde1830,
+        0x93d2ab8d2dd65cc5,
+        0x06317cb878f85ed3,
+        0x03e86fcbb50449d9,
+    ]),
+    element([
+        0x077648b279c6452c,
+        0x52b7b88342f59297,
+        0xa5b7b1037ad7c64f,
+        0x2e9f18218946014b,
+    ]),
+    element([
+        0x4770df18aec1e5ac,
+        0xfcea6da0bf16c7a6,
+        0x0ad4cacec4f0d737,
+        0x10d97fb8702834bd,
+    ]),
+    element([
+        0x3fb748b8dbcb3137,
+        0xf25bd54302faa6cd,
+        0x36b06aabac885751,
+        0x174db96b185fee2f,
+    ]),
+    element([
+        0x9dd3a144f12a62cc,
+        0x6cd33f3156645f8e,
+        0xec6c5148224d1de0,
+        0x28b0fb58477f683f,
+    ]),
+    element([
+        0x710ab3f8e5d78977,
+        0xf41d8fff1ddf0913,
+        0x59c29e71b112399c,
+        0x22c830375c702eda,
+    ]),
+    element([
+        0xf2f424fc899f5840,
+        0xa786e6bf30a71df2,
+        0x0e55dabc3447a954,
+        0x065bfd2a316cf91d,
+    ]),
+    element([
+        0xfab1a32d2017d242,
+        0x93dd42c713fbd0c5,
+        0x318b21c8f4d2d1fb,
+        0x15f6224b433bbdd8,
+    ]),
+    element([
+        0xb91c5eaea26d132f,
+        0x219015436ca5115f,
+        0x55901d89b8d4ec57,
+        0x24d0cf84241d2ed8,
+    ]),
+    element([
+        0x9d6d9525f277e188,
+        0xd055855aee95379c,
+        0x3f6aa99dd43ad30a,
+        0x2c0387853ff7babb,
+    ]),
+    element([
+        0xaec9168445bf43f9,
+        0xa0d9934b5218cc84,
+        0x125a69a6114f24f1,
+        0x0f35e65cbcec9858,
+    ]),
+    element([
+        0x96f7a27c1dd55f24,
+        0xab3aee87e20516cd,
+        0x692890a3de84ed67,
+        0x16f885639dcb29b2,
+    ]),
+    element([
+        0x74b608e12afe358f,
+        0xf0ccd4be265e806a,
+        0x712b140c59b5c8a7,
+        0x1cc69d8cac4a5505,
+    ]),
+    element([
+        0xd17ec331cf9635d1,
+        0xcddd9b52e43ead01,
+        0x451079c6f5ee8dda,
+        0x0cd8f0459df32d77,
+    ]),
+    element([
+        0x8010de9f6f489d12,
+        0xd0ca26cf7da03a6f,
+        0x2c4a2d9f07d45f08,
+        0x1a9ccfacd5381bdf,
+    ]),
+    element([
+        0xf81fb6eefdf687ac,
+        0xf44a9a7ae3360b64,
+        0xbec69c078dba8518,
+        0x1ad49378ef361721,
+    ]),
+    element([
+        0xb43c875952e496f4,
+        0x324ca38f7ef6bb33,
+        0x50af7b74be8e7dc1,
+        0x1f5380e811fcfb1e,
+    ]),
+    element([
+        0x1ef812e114040eb4,
+        0x822ad79c7237ebd5,
+        0x8fdb5e3444af3dd0,
+        0x11ddaf12bff04d3d,
+    ]),
+    element([
+        0x940831c7195958e9,
+        0xd2029a4f5c56d419,
+        0x98b04055e0239788,
+        0x0df9427148a14882,
+    ]),
+    element([
+        0x159214ccce75166c,
+        0x8fc3d2f9a5f9440e,
+        0xd3ea4a1505dbc242,
+        0x1e7f0e3d561dc229,
+    ]),
+    element([
+        0x84c72e336d63bd9f,
+        0xd555e1a787a976f7,
+        0xececa5775584678a,
+        0x0ec9cce5781b3b5f,
+    ]),
+    element([
+        0xa30e6fe59a7b67ed,
+        0x34bcd4701f4c17dc,
+        0xea1b4c157cefacf2,
+        0x2cb3ecc647831c63,
+    ]),
+    element([
+        0xfe91da3d2e6c9581,
+        0x431c83c977a74fef,
+        0xd184da5e86e14b12,
+        0x1d737269651ff086,
+    ]),
+    element([
+        0x0c52b73c12e34250,
+        0x512475a771b7202f,
+        0x42860f347bea6441,
+        0x1053641ac60b7ce5,
+    ]),
+    element([
+        0xf454c5332c9a7464,
+        0xaae0b57d97bab806,
+        0x319d6c841cc3c47f,
+        0x221d4eb71b991164,
+    ]),
+    element([
+        0x0b174b97f9267151,
+        0x27d4914b1d74971d,
+        0x58aef3bb1288db87,
+        0x11d06797aa1a3a27,
+    ]),
+    element([
+        0x32b65977b5c3b1d3,
+        0x0aa78a294b3aed53,
+        0x1db6596f36164258,
+        0x00e5b29334a9ce84,
+    ]),
+    element([
+        0x4a13a01532e9e184,
+        0x32c38247b869a7fc,
+        0x36098a6e7ddfd648,
+        0x2060fa9b5e4e441b,
+    ]),
+    element([
+        0xada41c4371132dd3,
+        0x1a6754f90698c958,
+        0xb2bb9e5d2bf9add7,
+        0x2e26aa120a3e9d5b,
+    ]),
+    element([
+        0x7e25a435168f1642,
+        0x46fce56f95aace1f,
+        0x05433fcc37a363ce,
+        0x2162cba86f8860ff,
+    ]),
+    element([
+        0xb4a997ee61f0e844,
+        0x454c12355231ecff,
+        0xd2d00b781443b04a,
+        0x152403f9a4bcd945,
+    ]),
+    element([
+        0x8d102b1f47b616c7,
+        0x4565db93d5434e7a,
+        0x053984d2e9097f6c,
+        0x023714bdbfde8f2b,
+    ]),
+    element([
+        0xe4b7fee8642a9879,
+        0xcc97823d4e706521,
+        0x552c379681daefca,
+        0x0a2b9257f5d276b7,
+    ]),
+    element([
+        0x1bd9cb8e1cb59ed7,
+        0x632a271abab225eb,
+        0xf6ccc4178e492cdf,
+        0x24f43c58f96c6c4f,
+    ]),
+    element([
+        0x89737fe668bc2d50,
+        0x51c79ee863d62b9c,
+        0x18e2be64fd919f8a,
+        0x222ffb03da596b86,
+    ]),
+    element([
+        0x5f5823af14c14e18,
+        0x6b80d41cb5035219,
+        0xb4d0ed1e277403f5,
+        0x0dce175d09eb7024,
+    ]),
+    element([
+        0x3402f3342f1cc389,
+        0xae9f396b4e4762c3,
+        0xdf95fe35bc89007a,
+        0x2bb751a423ad22ac,
+    ]),
+    element([
+        0xdafb7784b9d993c7,
+        0x6c9d94641e8fbc33,
+        0x9db27bcde1c6bb97,
+        0x260b583351856910,
+    ]),
+    element([
+        0x3e5279a8dafc17ed,
+        0x0352e8960a517d57,
+        0xbf2079f55814e209,
+        0x2d00a1da6e2899cc,
+    ]),
+    element([
+        0x78c421e2ef09bafc,
+        0x99d597bff207a45c,
+        0x54dfb69b61adb000,
+        0x155931f9600badb4,
+    ]),
+    element([
+        0xd467169051c5fa8e,
+        0x40ee73d4e5b45aeb,
+        0xc990ebc31c6ef7fc,
+        0x1f9ad96529766196,
+    ]),
+    element([
+        0x29ec6a4316e2c11a,
+        0x1a4a12ace17ad1d6,
+        0xa975bfac9a13988f,
+        0x0040a91fba467cbe,
+    ]),
+    element([
+        0x1d9077559474d887,
+        0x6893fa1c74de81a3,
+        0x2226b8da64264c43,
+        0x0f7a109c191cde3f,
+    ]),
+    element([
+        0x62af82c0929fbfd0,
+        0x6a82f64316d31167,
+        0xa3a6e90b84f8a3a5,
+        0x1a2a3b29434dab50,
+    ]),
+    element([
+        0x62d1dafa4a8f53ac,
+        0x859e8913ca97882d,
+        0xc5cd2df994f935bc,
+        0x0db014dc0cc1578d,
+    ]),
+    element([
+        0x60c72b930f3cb6d9,
+        0x470a59e0a557d0b6,
+        0x4ae1de4b17045a9e,
+        0x09ac26ad6d33fd1c,
+    ]),
+    element([
+        0x6f8ca1d73b103d70,
+        0xc4ff53316c74d07d,
+        0xb4a9e058b5d08acd,
+        0x17ca8fbf60a1fdfc,
+    ]),
+    element([
+        0xa115f1ca8aaaa01e,
+        0xa5490530f28a6122,
+        0x2c47bf419e59ca94,
+        0x3028c0d4404e4d30,
+    ]),
+    element([
+        0x2f8a02d0a8f5dd4c,
+        0xe68977838f32b7ba,
+        0xa8d2e2ffa959dc54,
+        0x2d74a85f2d6e454c,
+    ]),
+    element([
+        0xcfa0025e5424e0d9,
+        0x8aac560b27eb7bd0,
+        0x8af376492a724c1b,
+        0x19c7490c088b9592,
+    ]),
+    element([
+        0xfec18d2714dff0a1,
+        0x1a504281fc024d72,
+        0x652742fda6a8f43b,
+        0x2b514fb7abaa7dba,
+    ]),
+    element([
+        0x3f769faf76ac9d40,
+        0x9351d160b97bb3e8,
+        0x3f8255e5d87dbbfd,
+        0x01f41c35f6b1e41d,
+    ]),
+    element([
+        0x2c35cf900b1b74a8,
+        0x4fab80b8ce41d85c,
+        0x3461f5871330f787,
+        0x26e4302c8aafef8c,
+    ]),
+    element([
+        0x9878603d930f53ac,
+        0x98a817dbdcb61f5f,
+        0x1f88f85a0a0086fa,
+        0x23519312407f3ece,
+    ]),
+    element([
+        0x506fbcaef0179eb7,
+        0xfe905ef68318e267,
+        0x73e3b3518c01aac1,
+        0x2b2350f1f7a5c7d8,
+    ]),
+    element([
+        0xfb6d7c8e7574d7b5,
+        0x695b4b3a1288c4a9,
+        0x5c8b2f40f2ab746f,
+        0x12b71a826028390e,
+    ]),
+    element([
+        0xcd6131f7baabdc36,
+        0x7fa860f75471879d,
+        0x1dbfa6258af3ade3,
+        0x2b4563c2b7bedfb0,
+    ]),
+    element([
+        0x040ba06f232c03d4,
+        0x03c043208e8720d6,
+        0xfdf51239d9f24aed,
+        0x0b4f0ded23f29def,
+    ]),
+    element([
+        0x182d95f06fd01a32,
+        0x0ff837f373f5e1c6,
+        0xc092a2981f72b1bb,
+        0x109b3309f81d56f9,
+    ]),
+    element([
+        0x9ed792ad7ec9af26,
+        0xbab0a3eb0eee5150,
+        0xc17bac13326bd5d5,
+        0x0ca8dff9637eb134,
+    ]),
+    element([
+        0xb4f586ad19315857,
+        0x4927dd3d628e7e44,
+        0xaf0fb62c90606101,
+        0x1cc739b616c7127a,
+    ]),
+    element([
+        0x3adc1959066ba92e,
+        0x54bd32c536ea762f,
+        0x6a1312c2982a4f96,
+        0x033cda1406592851,
+    ]),
+    element([
+        0xefea9f3e655a611c,
+        0xc18221e0d3e46396,
+        0x21073fdf5de16e56,
+        0x2e685bd72dcc18d1,
+    ]),
+    element([
+        0xd7fd648d3b243d18,
+        0x7839d60491c36978,
+        0x0f116a9d324253e9,
+        0x0a7556cd7950268d,
+    ]),
+    element([
+        0x4eba87083ff506e7,
+        0x73f9db8ddac5f376,
+        0xe1b7572529b88ed5,
+        0x1a1fee805d9ca845,
+    ]),
+    element([
+        0x739f3bedf744e38c,
+        0x42d95076b167e8ed,
+        0xd46c91d37518030b,
+        0x16122009b072c48d,
+    ]),
+    element([
+        0x278c26db028cb73b,
+        0xc8f1c64b6f0ff7b9,
+        0x08a8fdc5018345fd,
+        0x07f5cf526940fbf9,
+    ]),
+    element([
+        0x750fbf8dc2f1123e,
+        0x46a245f47221af31,
+        0x95fb31b0042cbe8d,
+        0x0ffa3a5a1def0efc,
+    ]),
+    element([
+        0xcc7d5ddbaba85d2e,
+        0x1f383155a225585b,
+        0xd98366dc3b867020,
+        0x1255d44d947b2a94,
+    ]),
+    element([
+        0xb47624604405ed29,
+        0x6691bbd4dd9dc69e,
+        0x62496a58f88cc237,
+        0x1941601225631093,
+    ]),
+    element([
+        0xdffe5fbbeb6dacd7,
+        0x23186f4c6a16864d,
+        0x724aa2bd5da1fe85,
+        0x24a2825f4bf2d4b9,
+    ]),
+];
+
+/// MDS Matrix in Row-Major Order for [`Spec3`](super::Spec3)
+pub static SPEC3_MDS_MATRIX: [Fp<ConstraintField>; 16] = [
+    element([
+        0xbc1e0a6c0fffffff,
+        0xd7cc17b786468f6e,
+        0x47afba497e7ea7a2,
+        0x0f9bb18d1ece5fd6,
+    ]),
+    element([
+        0xd745397409999999,
+        0xb4ada7d483c3efa8,
+        0xc49ca2f8e57f3161,
+        0x162a3754ac156cb3,
+    ]),
+    element([
+        0x7d695c480aaaaaaa,
+        0x3a880fcfaed9b4f4,
+        0xda7526dba9a9c517,
+        0x0a67cbb369deea8e,
+    ]),
+    element([
+        0xf41575289db6db6d,
+        0x07daec5e847b8b05,
+        0xea0fce347eecc0e2,
+        0x02017ed283b7fb4f,
+    ]),
+    element([
+        0xd745397409999999,
+        0xb4ada7d483c3efa8,
+        0xc49ca2f8e57f3161,
+        0x162a3754ac156cb3,
+    ]),
+    element([
+        0x7d695c480aaaaaaa,
+        0x3a880fcfaed9b4f4,
+        0xda7526dba9a9c517,
+        0x0a67cbb369deea8e,
+    ]),
+    element([
+        0xf41575289db6db6d,
+        0x07daec5e847b8b05,
+        0xea0fce347eecc0e2,
+        0x02017ed283b7fb4f,
+    ]),
+    element([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2000000000000000,
+    ]),
+    element([
+        0x7d695c480aaaaaaa,
+        0x3a880fcfaed9b4f4,
+        0xda7526dba9a9c517,
+        0x0a67cbb369deea8e,
+    ]),
+    element([
+        0xf41575289db6db6d,
+        0x07daec5e847b8b05,
+        0xea0fce347eecc0e2,
+        0x02017ed283b7fb4f,
+    ]),
+    element([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2000000000000000,
+    ]),
+    element([
+        0x80dce13d51c71c72,
+        0xec7d50101b0cc3ae,
+        0x0c839db6c77213a2,
+        0x2732bc1987605c7b,
+    ]),
+    element([
+        0xf41575289db6db6d,
+        0x07daec5e847b8b05,
+        0xea0fce347eecc0e2,
+        0x02017ed283b7fb4f,
+    ]),
+    element([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2000000000000000,
+    ]),
+    element([
+        0x80dce13d51c71c72,
+        0xec7d50101b0cc3ae,
+        0x0c839db6c77213a2,
+        0x2732bc1987605c7b,
+    ]),
+    element([
+        0x0d939783fccccccd,
+        0x6e70c80e7ebeb01d,
+        0xbe767457b38044df,
+        0x234742e3c6a3866e,
+    ]),
+];
+
+/// Additive Round Keys for [`Spec4`](super::Spec4)
+pub static SPEC4_ADDITIVE_ROUND_KEYS: [Fp<ConstraintField>; 320] = [
+    element([
+        0xc70ce0316fbbfc01,
+        0xee8889d97452881b,
+        0xbf49a330b1663a03,
+        0x19a6f61a31f80483,
+    ]),
+    element([
+        0x5944b1576d986a55,
+        0xcd7197f5a6745a7a,
+        0xd05fd05c844ae059,
+        0x2bc99cbc691db2dd,
+    ]),
+    element([
+        0x2971c0ca2f887e8e,
+        0xc1abad6f6ce63dbd,
+        0x03e91e5980e548af,
+        0x24a7ee4267bd65e6,
+    ]),
+    element([
+        0x17d0b940634c01fb,
+        0x972ae1df03308619,
+        0xf6d05672da0c847a,
+        0x15dfe7fd206041f9,
+    ]),
+    element([
+        0x9e8b55774a8e2b88,
+        0x663004c6cd0dd238,
+        0x34d7cb5ee6b8f203,
+        0x25327ea86dd3bca1,
+    ]),
+    element([
+        0x04cd7a5a687b6476,
+        0xdc066c870bd3cb7e,
+        0x9f1aadc6fe8acc65,
+        0x28c5a0859b29ad72,
+    ]),
+    element([
+        0xbe250b43e5149c27,
+        0x2b1fdb8147c5f895,
+        0x220a95e5a74fdca6,
+        0x08b7a2b45a142439,
+    ]),
+    element([
+        0xe393a4f414b31903,
+        0xb8da46af17f48e32,
+        0x08c3f8721e70a4d3,
+        0x299e6192bd09baae,
+    ]),
+    element([
+        0x74a823c4f5ae2d14,
+        0xbb2f8b2eef5f7e77,
+        0xe6aae9e2f0227447,
+        0x2bdd7405b957f62e,
+    ]),
+    element([
+        0xfd2b8485bdd2c667,
+        0x5543fbde9349e249,
+        0x0c8f008a414fa4aa,
+        0x1b1f3b101d44401b,
+    ]),
+    element([
+        0x48a54a6f8d2f4a42,
+        0xff0e0f6ca9b29e02,
+        0xc3999d05b3d2e2a4,
+        0x011263bef0e88870,
+    ]),
+    element([
+        0x5fc7a8f2a534698c,
+        0x83ad6fbeebac4e95,
+        0xde53592c141256c3,
+        0x080db833855f5068,
+    ]),
+    element([
+        0x6618210fefe9d08b,
+        0x0025fd61ecf4b5cc,
+        0xab96452f44c13d24,
+        0x0d135346e571813f,
+    ]),
+    element([
+        0xda1ce509d6d13282,
+        0x817b899f819d2b4d,
+        0x0b2279ba04c75595,
+        0x2092b67441edee5b,
+    ]),
+    element([
+        0x726e9d84efc5bc96,
+        0xff5f9701da832144,
+        0x56cf90af88167461,
+        0x18f6eff3ec5e82ca,
+    ]),
+    element([
+        0xedaaf4056fa13567,
+        0x922e44e7cc0936d8,
+        0x4d2cc035b5474643,
+        0x1bd76d4e0e644d40,
+    ]),
+    element([
+        0xb02f07b88b630afe,
+        0xc4a8610562e18edd,
+        0xf020f679301a2fb8,
+        0x2791612c5f9badcb,
+    ]),
+    element([
+        0xf03b619f2bc99e34,
+        0x9e68125b0123704a,
+        0xd061b37cb0292e62,
+        0x1c2938c0be79a4b1,
+    ]),
+    element([
+        0xd1729f5ecca22ef6,
+        0x7d074afc217851b3,
+        0x2fc9ebf019bd225f,
+        0x0e6fafb2310fff58,
+    ]),
+    element([
+        0xc1c8dd690d375a93,
+        0x92bcc95d648b4a18,
+        0xfa36e31392fe7748,
+        0x2de01b1df63d97f8,
+    ]),
+    element([
+        0xccd4020ed343af4f,
+        0x4ea5c4142aeeaec7,
+        0x37974bb151e41cd9,
+        0x12c88b18d789f790,
+    ]),
+    element([
+        0xeac484dac198d128,
+        0xe33827db44059418,
+        0xbea632d3b5ee64fb,
+        0x2ef06126bab3a4b0,
+    ]),
+    element([
+        0xc970c278ff40125e,
+        0xf48497007320661c,
+        0x11cd907662647a80,
+        0x26590e23e7efd39e,
+    ]),
+    element([
+        0x2869d76809a5cc0f,
+        0x15ee292ecc090630,
+        0xf1dc21e174d1fc16,
+        0x1369e7cf35f21206,
+    ]),
+    element([
+        0x2d65e1565fc46ef6,
+        0xbdcf19b6fa5bcf9c,
+        0x81d7cdcece0d35c3,
+        0x23328ab7b67e2a77,
+    ]),
+    element([
+        0x8b4f55a9e40b2273,
+        0x040886b8ff03b27e,
+        0xd2189feaeba7298b,
+        0x2c96a1da796c174f,
+    ]),
+    element([
+        0xbdf9fde798408785,
+        0x61c731036ee2e37f,
+        0x130b15061b60acd0,
+        0x030f76b19f208898,
+    ]),
+    element([
+        0x87b42138b229124f,
+        0x5fbd895eb1ace8eb,
+        0x5e5f8a25c629f7dd,
+        0x1e00d9d9ea54f7da,
+    ]),
+    element([
+        0x42c024d98012fbf7,
+        0x61cc9862922f3a9a,
+        0x7eb5ce9c587d9270,
+        0x1051fbec20ae0e0d,
+    ]),
+    element([
+        0xb48a588cac7fd1fd,
+        0xc50bdc7089cea6cb,
+        0x46bb42249982aeee,
+        0x0eee152dae360dba,
+    ]),
+    element([
+        0xd48648403164277e,
+        0x5fb5842696e3a76e,
+        0x39870b06820c7058,
+        0x04447a69a2dabc49,
+    ]),
+    element([
+        0xf28a4532a0c5a35a,
+        0xd5ddd1d9d8e54c1f,
+        0x6494fd3693504a9f,
+        0x00dd0a846a255067,
+    ]),
+    element([
+        0x0a6e4e1eb95ec745,
+        0xcb503652b7bccad8,
+        0xd82f7e97284123e6,
+        0x0a991a987ce3908f,
+    ]),
+    element([
+        0xc46a55dec3f5838c,
+        0x4a13299282199536,
+        0x22f4ac5d9826f549,
+        0x14d871493fd34b6a,
+    ]),
+    element([
+        0x0ccf1fd9c567821e,
+        0x3f85088d40d151e3,
+        0xe488d6d00d1f6e7c,
+        0x1a5136d5531f596e,
+    ]),
+    element([
+        0x79f5a1242f1d20bf,
+        0xd017f80dadc1ab7b,
+        0xe7d02985bce24278,
+        0x0e4551a61bc91ebd,
+    ]),
+    element([
+        0x324a61abc6b9e93a,
+        0x86d3a9c564460914,
+        0x5b594cf27b80bf27,
+        0x18ff2514e97fec5e,
+    ]),
+    element([
+        0x35e1475279e5834b,
+        0xc7905e4a5626fe4f,
+        0x13f093177f5587b8,
+        0x117bedf6ebd3cf7c,
+    ]),
+    element([
+        0x04cb58c53529ea96,
+        0x3593fa858d797bcb,
+        0x3621a3e87b335432,
+        0x2771ae49994056ea,
+    ]),
+    element([
+        0x7b0eeed59d0fb4bb,
+        0x7f28608b5a90f0e2,
+        0x5cc79b5289bfa577,
+        0x224fba724e172021,
+    ]),
+    element([
+        0x9e7e53d14dd907da,
+        0x46126bfe0abb8c4d,
+        0x39827da2d246dbb5,
+        0x04323923836e67fa,
+    ]),
+    element([
+        0x48fcf1114761d247,
+        0x5b31dc4e1158fa3d,
+        0xd913456e44cdd1dd,
+        0x1372c59edaf833c9,
+    ]),
+    element([
+        0xb713b54db7366600,
+        0x81127924163d72d8,
+        0x4311afee1085c976,
+        0x1bb1979db7ecc4a5,
+    ]),
+    element([
+        0xefa64ba8bc8be521,
+        0x6b846b476be2406a,
+        0xffdbf84c67a266b4,
+        0x29fa74baecb0eb5f,
+    ]),
+    element([
+        0x8873e8b5d625b0c4,
+        0x6ea9507d46c65e20,
+        0x1ced4abb48d7b1b4,
+        0x1a54657b55a94ac6,
+    ]),
+    element([
+        0xbb935bb94b8d9a9e,
+        0xbea1c5042148e449,
+        0x18cde6c3e4042091,
+        0x2eb7fe943553fece,
+    ]),
+    element([
+        0x1663f9174a4004d8,
+        0x2d872235c583e3df,
+        0xd0622fd6bd781b90,
+        0x23be902782c5df2b,
+    ]),
+    element([
+        0xa16ce065376c8c77,
+        0x4790ee9d47cfe784,
+        0xac41dcebaa610499,
+        0x26e2922c2f6bd53e,
+    ]),
+    element([
+        0x9ba60a987c68791d,
+        0x91d265c256130967,
+        0xeccd2e1da101bd2a,
+        0x28d5fc7681bb5f84,
+    ]),
+    element([
+        0xe457cb2297905d22,
+        0x950f844be888fe92,
+        0x57e6b13a53bdf049,
+        0x2de9f91546bc1cb7,
+    ]),
+    element([
+        0xf8a0d1368c01747b,
+        0xc1fb2acf8d6123cd,
+        0xa29d4b362375ee97,
+        0x041ca96bbfdf3d58,
+    ]),
+    element([
+        0xe86fc19b74c9d979,
+        0xfeebcf0079f2876e,
+        0xd5950a90b04bdb9d,
+        0x0dcdb048746495f4,
+    ]),
+    element([
+        0xe6f159bbf111d4aa,
+        0x44f8e9cf242ecfb9,
+        0xc6fe01422a168fc7,
+        0x1cc71ccb6f8a5c83,
+    ]),
+    element([
+        0xd7bdb8b06c14f303,
+        0x0e5095bea686aa46,
+        0xee5714ae717596ff,
+        0x087cc1411ac14191,
+    ]),
+    element([
+        0xf03cfef09c3c481d,
+        0x9b1f15f3cc4c23cd,
+        0x760589b0277fd0c1,
+        0x01a5c00ea92b280d,
+    ]),
+    element([
+        0xcf1fc700430004f6,
+        0x35709e62e9aa3e83,
+        0x4a1a79346cf8a14a,
+        0x2bd0bdb969744f49,
+    ]),
+    element([
+        0x5a287d4df9887321,
+        0x95f4e9de04e63c77,
+        0x6c3851039f350a43,
+        0x0d6caf7cb31379b3,
+    ]),
+    element([
+        0x820a7f3f40282dd6,
+        0xb716c3c3425769b8,
+        0x5e42153721427996,
+        0x1c35baf49e335ed3,
+    ]),
+    element([
+        0xb19779bbf0175985,
+        0x7d57a61699f34772,
+        0xb73622b20d86e5f1,
+        0x27d057d56b68686c,
+    ]),
+    element([
+        0x1df728e0d106b6a0,
+        0xd645a7f8bdae98ef,
+        0x0f40ea0d2546edc5,
+        0x212f9f5edaccb44c,
+    ]),
+    element([
+        0x8b791a19e47b5308,
+        0xc8719f25afcf3c49,
+        0x7cc79764747b10bc,
+        0x18e8adaed3418780,
+    ]),
+    element([
+        0xa98e24605c589489,
+        0x4781b4cd394b0424,
+        0xd182b36b314bc247,
+        0x06a7ae05b96e3ce9,
+    ]),
+    element([
+        0x1e9443420300f9f6,
+        0x373ce75a34403488,
+        0x6f3c512c0e648a32,
+        0x13191dfee8edb3e2,
+    ]),
+    element([
+        0x8a632fb20f7ee347,
+        0xb76b061667309c16,
+        0x6fd27454bc3e23ff,
+        0x190670390eb4ab76,
+    ]),
+    element([
+        0xa41b4c396cc6a12b,
+        0xcc2a5228403505c9,
+        0x633459a65bc91580,
+        0x0155e459922f82f8,
+    ]),
+    element([
+        0x2bcefe2feba52090,
+        0x7e5477b41736ef81,
+        0xe34a825e039937f0,
+        0x0a8898938bd41a80,
+    ]),
+    element([
+        0x04196feafa21d0ca,
+        0xeff04ac2956290e4,
+        0x1bbedb1f8e36de4e,
+        0x257aa3fb30f38ac4,
+    ]),
+    element([
+        0xf732b62339e1248c,
+        0x583466390659b697,
+        0x1371d53798e3cd6b,
+        0x2d879cecc72c1b13,
+    ]),
+    element([
+        0x3cb94739d3b60be5,
+        0x49aca6f0f4257071,
+        0xc97f8380ccc63ca0,
+        0x16d9c66915905ca7,
+    ]),
+    element([
+        0xaf72d397fcdda301,
+        0x2f2c40a54c5401bc,
+        0xfb2c1e93f121f9c9,
+        0x0489ff671b222ffa,
+    ]),
+    element([
+        0x838c97ea8dbc65b6,
+        0x51e1ae5d38c8791d,
+        0x21806e58f17df23c,
+        0x162bb34977d65ee5,
+    ]),
+    element([
+        0xe189a6a23345b6e4,
+        0xd98e4c908317eda3,
+        0x36b4b6b5be7a796a,
+        0x1544b9675ae0f918,
+    ]),
+    element([
+        0xf4c289efdf560c29,
+        0x1f2142e393b50cdf,
+        0xef6ee26aeffd3773,
+        0x263f7f524776bc27,
+    ]),
+    element([
+        0x941c39d1088a7b62,
+        0x8de262e760dc623a,
+        0xe531d6d16fbaa2e4,
+        0x2d32517e5588bd14,
+    ]),
+    element([
+        0xb8de7621f805cf3e,
+        0x4e1a9a85959b7401,
+        0xa54fbeae27bb2d4b,
+        0x13df333dd060b5ef,
+    ]),
+    element([
+        0xbd4102dd1592f50d,
+        0x5fdb31ef9ca21c2a,
+        0x41e11dc2dc84c764,
+        0x2461cec51693ddf3,
+    ]),
+    element([
+        0xc6c10efbfb4c59a4,
+        0x3cda86698f2c3b5f,
+        0xa26fdc86cd61b0a3,
+        0x03cd9d01a1f461ce,
+    ]),
+    element([
+        0x7f1196551eef6459,
+        0x767b69102759f0ff,
+        0xcc4c674fad47ea0d,
+        0x240c5a502decd967,
+    ]),
+    element([
+        0xf250a6b02357cd04,
+        0x4b551da9489cb75a,
+        0x705c9db955cfa577,
+        0x1b512b25adf7ccbf,
+    ]),
+    element([
+        0x5fa0bc3b17b68a50,
+        0x273fc1d0f97458b2,
+        0x42e0a23a52cf979a,
+        0x0418f65baf2c3c48,
+    ]),
+    element([
+        0x47c76ab9dde608d0,
+        0xbcbe588abf343e91,
+        0x557f3f1a86a86d37,
+        0x1dce27f30c8c4b94,
+    ]),
+    element([
+        0xefb0e6ee32d57c7e,
+        0x2cfad5a53ebe5afa,
+        0x160ef46fd3b2f4a3,
+        0x068f4e96336e1586,
+    ]),
+    element([
+        0xc5a259272dceb482,
+        0x4328939db1061f92,
+        0xcff5729232b29523,
+        0x23394de71926da7c,
+    ]),
+    element([
+        0x6ffe8d6cafb95209,
+        0x12c19cc22134b118,
+        0x3657d50170fb8a1f,
+        0x10473ffe8ed921a3,
+    ]),
+    element([
+        0xe735c976da17faf6,
+        0x208e3ddda9a6a2cc,
+        0x40353d39cd58841c,
+        0x1fb3e2be99b82e4d,
+    ]),
+    element([
+        0x33a7b99e423cd90a,
+        0xca24f22d9c69bd63,
+        0x458a3a79176721d6,
+        0x03005997b0583f4d,
+    ]),
+    element([
+        0x2d75b798d1a8eded,
+        0x5572c8b6abbcabfa,
+        0x5fe7c0a0cb547ea1,
+        0x106a5c7b02edd345,
+    ]),
+    element([
+        0x01d0d341b5ceb0f7,
+        0xdfb973a5f05378c1,
+        0x40c2aceaa06d2862,
+        0x059ef90343936e94,
+    ]),
+    element([
+        0x3ffb0d049ad69411,
+        0xe42465cec02746f1,
+        0xc23b4d93358d7168,
+        0x1b2bf7d35e982dc3,
+    ]),
+    element([
+        0x0dc4605dc928cbb8,
+        0x2b44f2ea69fabbda,
+        0x3baf485ca0baf1da,
+        0x262099a35b084877,
+    ]),
+    element([
+        0x80f619a4c90e6c4a,
+        0x07b9e012dca9317e,
+        0x16edbcfbeef5330b,
+        0x0a2ec90a8823a0ce,
+    ]),
+    element([
+        0x9f6bc04c40d4fe1e,
+        0xc786ee5c423fddfb,
+        0x1f1ef46d1cf96e29,
+        0x1f60b62f6dca301c,
+    ]),
+    element([
+        0x2d16c8f788dc74b6,
+        0x7def5a2e3744814d,
+        0xa96409f10352c492,
+        0x09ee584dd92a4d07,
+    ]),
+    element([
+        0x4edf2e6e30732eaa,
+        0xbfa3c1e1f41b27cb,
+        0xe6bd783da29f7873,
+        0x0dba1fecb7332164,
+    ]),
+    element([
+        0x1729e20dd05c0eee,
+        0x0154c98fb39ab78a,
+        0x9517b01e488b630c,
+        0x1b497cff65496833,
+    ]),
+    element([
+        0x420d7bf306e61753,
+        0xe6a1b2d7d4516eab,
+        0x8b3ef8bf76c13bd4,
+        0x120b88358072d600,
+    ]),
+    element([
+        0xca4a8b8703cd95ff,
+        0x8c5d1cd1ffc05db9,
+        0x3f603bcf5d3228bd,
+        0x1b08e1d1df5904f6,
+    ]),
+    element([
+        0xc174d9f9cc665fff,
+        0x47627196b8a38291,
+        0x3936a37f1ffb5d75,
+        0x12ac330534237790,
+    ]),
+    element([
+        0x8f31cac49636a9b2,
+        0x10ae8e1426f2bf31,
+        0x5fff4c585909f6fd,
+        0x2bb26569f7b47e1c,
+    ]),
+    element([
+        0x4e3d415989892fd3,
+        0xcd748e69234bf1c6,
+        0x9f2d6f43ad9bf09b,
+        0x10a3fc128d175a05,
+    ]),
+    element([
+        0x4ac738a277ea8a3f,
+        0xa45275c8b6ceaf05,
+        0x48484db626882f97,
+        0x0952fb567cbf7e3c,
+    ]),
+    element([
+        0xb305ce17516b04d6,
+        0xe1e5a48ddce9273c,
+        0x8e02903d93ae3025,
+        0x2430c931f1836113,
+    ]),
+    element([
+        0xc3c974b16f665339,
+        0x5636d3552bb0e563,
+        0x496d859bd01741a9,
+        0x1ff530c3d4351e7c,
+    ]),
+    element([
+        0x29f1a5ad49bdad52,
+        0x63758de7ea83962a,
+        0xbb94936d57f3eb39,
+        0x11eeaf51d994edaf,
+    ]),
+    element([
+        0xa9e96a36a47e13e9,
+        0xedb0c2c5100eb8d5,
+        0x4b12bbc06e6a8ed3,
+        0x2cfd09aa87d5033b,
+    ]),
+    element([
+        0x2e84952944370cdb,
+        0x2e4e01167e7a1d7e,
+        0x01b27eb14af3f485,
+        0x0ac358652881f62d,
+    ]),
+    element([
+        0x43f0d7ab622ea226,
+        0x97b7440841341819,
+        0xb99589321c0edb3a,
+        0x2fd0519d6b159e67,
+    ]),
+    element([
+        0x206973d3c19a612b,
+        0xeb00bba94f136962,
+        0x91c40d03b79a359a,
+        0x1d77cb551dfd8856,
+    ]),
+    element([
+        0x677f5a0972cd4355,
+        0xd38f3401ed690604,
+        0x6799e0df6184c03a,
+        0x1f135f67b3e5c6b4,
+    ]),
+    element([
+        0xd80fecac5823c444,
+        0x005d246ce8e99201,
+        0xd52b1750dc95adeb,
+        0x1271f393778176e1,
+    ]),
+    element([
+        0x371db51a7ab3993a,
+        0x84b7c7382e74bec2,
+        0x496aa9c2906f7724,
+        0x2532b326b361d9d5,
+    ]),
+    element([
+        0x9fea3fd92027a780,
+        0x8887c6d8610364dd,
+        0xb5b3c9ea2886edef,
+        0x165171fe4c9e8442,
+    ]),
+    element([
+        0x37027460644f4413,
+        0xcb084f1e7567a5c1,
+        0xa89a9acfb7b62e8d,
+        0x29304cbf1344bbb2,
+    ]),
+    element([
+        0x9312fa8db230619d,
+        0x084e38ce2dbc8619,
+        0x7d7051246b7c3ef9,
+        0x305423fa590fab0a,
+    ]),
+    element([
+        0x24b9fdbc4de29e18,
+        0xe5dcb47268c7748a,
+        0x12fd58e5352d58c0,
+        0x18d29782200ebbd8,
+    ]),
+    element([
+        0xf9e632402ecf26c2,
+        0x52cc17282cde5eda,
+        0x2e459272b1d0215a,
+        0x09ecb47af88f3915,
+    ]),
+    element([
+        0x1562a4af9845f11c,
+        0x841eb5a15d5d6dc4,
+        0xdaa6e7aff937f40b,
+        0x25e3f54de7d8ef31,
+    ]),
+    element([
+        0xae9e50ac3a27f0e7,
+        0xa735f4cc065f9cc6,
+        0xf985ab72baeb817d,
+        0x26c3b75a157e6659,
+    ]),
+    element([
+        0xe706a77949034888,
+        0xf693eb5d8d4f4e38,
+        0xe1e1e49aa3628f12,
+        0x172518244717a525,
+    ]),
+    element([
+        0x192360d100560abe,
+        0xfb04fa819a7f3a55,
+        0x2968a11c82ea55ab,
+        0x29acd76fabc73e45,
+    ]),
+    element([
+        0xe68c222d548d559b,
+        0x1eae4617ceda4a93,
+        0x967480965e648c91,
+        0x1c4d8864a59b2d8c,
+    ]),
+    element([
+        0x8310b62fe826f63a,
+        0xe329dbe705e7febb,
+        0xdc9707cb4b6304dc,
+        0x103073c2b7ea2829,
+    ]),
+    element([
+        0x028f275b81f991c1,
+        0x6263e04ac6de180b,
+        0x67ded6f9902b8015,
+        0x30107ac64a1f9469,
+    ]),
+    element([
+        0x85fb0e7b1da731b2,
+        0x8ccaa8577d13a97e,
+        0x183ce2cc09ec6ed8,
+        0x177f0addd5a4c900,
+    ]),
+    element([
+        0xf1ca94f6bd4b79ce,
+        0xf90162666d9fcf8d,
+        0x641faf0cd264319c,
+        0x26a704ab64750ab7,
+    ]),
+    element([
+        0x9fca04ad1320d0b5,
+        0x22403f48cce18b4d,
+        0x54c83c60f9bde0d5,
+        0x1448b18997389467,
+    ]),
+    element([
+        0x1a6775064055402d,
+        0x29138afa7d1da8a0,
+        0x7b9dbaf374bb08d7,
+        0x10b79dc236faa8ce,
+    ]),
+    element([
+        0x93e1d900d4e48045,
+        0xf58789816ed10a94,
+        0x5417f3af40e51319,
+        0x2b46b48e48b19c6c,
+    ]),
+    element([
+        0x704584511e1d41b8,
+        0x0e56ec640816e2e8,
+        0xb66af6f31782d776,
+        0x25d036e677f92ae9,
+    ]),
+    element([
+        0x29ef1779186aeac1,
+        0x9a0d58863fd3267e,
+        0x248cd93969ab035a,
+        0x2d931ad77861a828,
+    ]),
+    element([
+        0xeeac8e8a2690e6fe,
+        0x6b2c82690d6fc98d,
+        0x7bd0f02fb7a3ec7e,
+        0x10f1c085b1deba39,
+    ]),
+    element([
+        0x42de96d84a874c12,
+        0x248be3bad364c1a5,
+        0xdd7e44276a8e014e,
+        0x23e8b6c84321f2a2,
+    ]),
+    element([
+        0x8ec905a5ed01dcbe,
+        0xf1e14de788ef10af,
+        0x663d2b8f8317ec1f,
+        0x2712e58bf57b2952,
+    ]),
+    element([
+        0xebace82a6432a15f,
+        0x6f90047c858cfd14,
+        0x698e901f6e228514,
+        0x1ad757e9d8aa722f,
+    ]),
+    element([
+        0x3f95835be4ecd4fa,
+        0xfb93f000808b4d50,
+        0x3008cb8f608c9e4b,
+        0x28d0e427afb2a980,
+    ]),
+    element([
+        0xf325cc8b67ee0b83,
+        0x36cc074c724e81b8,
+        0x8427f9d4ceb323ee,
+        0x18b2b828a53e8bc1,
+    ]),
+    element([
+        0xcea9046b46cd3cc3,
+        0xd0839651bac81bcd,
+        0xa76770155059a8b2,
+        0x228af81cb8772678,
+    ]),
+    element([
+        0x01ae93b1bd09de6b,
+        0x34803be01330caf5,
+        0x5eeba467f1a68776,
+        0x033d201edfea8398,
+    ]),
+    element([
+        0x0d51685680bc7599,
+        0x406aac20d9c8919c,
+        0x176bfcc2ba3520b1,
+        0x2c0cc57ed67441cc,
+    ]),
+    element([
+        0xf867b3cfe4a551ea,
+        0x7f0bdf4ca95af3dd,
+        0x9dba7cfa21dc8e86,
+        0x01a990526e1777a6,
+    ]),
+    element([
+        0x84923ee2f378c43b,
+        0x4b641cb2815204b5,
+        0xa1679a596010a3b4,
+        0x1d110d959539d023,
+    ]),
+    element([
+        0x0f29661f50d963e4,
+        0x2c382d21c5c98a83,
+        0x899089806336411d,
+        0x27598dded11b5c8c,
+    ]),
+    element([
+        0x342c16486c313f65,
+        0x58b1e93bbd6b3d02,
+        0x9b0ed2282e878a8c,
+        0x26030d986920dbe8,
+    ]),
+    element([
+        0xe3b4c559a6233da7,
+        0x416599afc235a6ac,
+        0x77e5c1e687aa686e,
+        0x17eb0829aafe230d,
+    ]),
+    element([
+        0x12e747a9ae38dd87,
+        0x80c35fe2c26cc9ea,
+        0x8274aef3097e431c,
+        0x25ebbbc67f3bff45,
+    ]),
+    element([
+        0x263131724b0b62ea,
+        0x9bd0d9451c0a7360,
+        0x4ff78b7555021058,
+        0x1bb16fcda9a8dbfa,
+    ]),
+    element([
+        0xce805449ccd01e87,
+        0xb93b35568b218f57,
+        0xebc8b1cd4941f235,
+        0x1a03509fca5f7829,
+    ]),
+    element([
+        0xc0815b585b5ea840,
+        0x0da8c73f2a351781,
+        0x909ce61538aff74c,
+        0x23e44f01d4e2f528,
+    ]),
+    element([
+        0x30bda3546313439f,
+        0x9f582309df739f79,
+        0xaefc500b2e14d7ae,
+        0x2db4a892bbc5714f,
+    ]),
+    element([
+        0x64e3a37989b335b8,
+        0x28e2c1160fb1a9e8,
+        0x88064bf6033be5c9,
+        0x2b983298f13d5c24,
+    ]),
+    element([
+        0x04816937a3282bff,
+        0x5198f71607796c29,
+        0xdc09ca615cf6ab87,
+        0x2f43b786b411ce25,
+    ]),
+    element([
+        0x3a4d3a65386f290d,
+        0x18fdb709255c2392,
+        0xaf4f31f730b57aae,
+        0x2d90eb815293b7e6,
+    ]),
+    element([
+        0x6b3f84e67f25598f,
+        0x46e4758df8fbc625,
+        0x9394220d4da249c3,
+        0x2c6bf5eb1f22c4d6,
+    ]),
+    element([
+        0x5d614bab7994b231,
+        0xb37e2c70329741ac,
+        0xe16c50bee49ff282,
+        0x2ffdd43acbe7356b,
+    ]),
+    element([
+        0xdbb5a89f85c0ef35,
+        0x21599c021e4a45ed,
+        0x4b340d62af1a8f69,
+        0x0e91d44e10376a38,
+    ]),
+    element([
+        0x494573558573d784,
+        0xe8c22ed0916f0213,
+        0x180a3dcb1d717fb3,
+        0x2c0475fd9a6bf5bd,
+    ]),
+    element([
+        0x4e6e5266945fca50,
+        0x8132a221de2f00fd,
+        0xc7b2f5cc8f6766dc,
+        0x243cf4bfe46db729,
+    ]),
+    element([
+        0xddf107db77ea625e,
+        0x785fe3fcba7c2c6c,
+        0x25075fff372305ba,
+        0x273a20d5308a5020,
+    ]),
+    element([
+        0x9fab6790b90edf5b,
+        0xac305991fcc40707,
+        0x597aaaec5193295e,
+        0x02e283029265130d,
+    ]),
+    element([
+        0x215cb9347f7dacb1,
+        0x7fcc48b9cab2a95b,
+        0x6d2e0ffe467d593f,
+        0x0084f95f32d2eb4f,
+    ]),
+    element([
+        0x6c8853f105f392de,
+        0xf737f6536b07c8b4,
+        0x9040f64ed334f2b1,
+        0x015f4ac1a4423ef7,
+    ]),
+    element([
+        0x6cf14d1bea7baf8b,
+        0xc74c1b3eb04ef33c,
+        0xb95cf629fece90bc,
+        0x07fc70ba661380fa,
+    ]),
+    element([
+        0xdd14eae09f9887aa,
+        0x05dbdc3dc3f90d93,
+        0xeb07b228d97744c1,
+        0x29c649b7b0f88995,
+    ]),
+    element([
+        0xf2fc75f3a231ee75,
+        0xe90d25fcfa64831c,
+        0xf803ab8a58eaaac3,
+        0x1cc7ae84e23f8dfc,
+    ]),
+    element([
+        0x89a2657487aa3d83,
+        0x94b6c5007624ba00,
+        0xdacc858e813cfe52,
+        0x2425bc52a2495602,
+    ]),
+    element([
+        0x4796e9f37bbe646d,
+        0xb60f6bf60274de4d,
+        0x20a9fc079d45947c,
+        0x1ad710bcca4940a3,
+    ]),
+    element([
+        0x5d57986b6c57aec8,
+        0x80d75a354befb707,
+        0x77ab123e3f207c06,
+        0x09faf71251ae8cb5,
+    ]),
+    element([
+        0xc03f2e3750029a82,
+        0x77680f702b39b1e7,
+        0x8df6180d4ec7fdd3,
+        0x1e25f0bb2f9c3e72,
+    ]),
+    element([
+        0xfb0047094668698b,
+        0xc1a281356a1b3f3f,
+        0x87f0ec098cbcc7c2,
+        0x0d318e81f239fbd1,
+    ]),
+    element([
+        0x04ee755a46a7251b,
+        0x966d78e081ede0d3,
+        0xa908215c49354796,
+        0x0576fb9855d4dde6,
+    ]),
+    element([
+        0x376accc75ac0fa6a,
+        0xd5e82222ec536d5b,
+        0xdc0a3e7e8bf3c1bb,
+        0x1947f89a04f1795e,
+    ]),
+    element([
+        0x0cfae7234d160734,
+        0x119e9872d275cf9c,
+        0x2411e78e71e3fd8f,
+        0x102806f9c926c87c,
+    ]),
+    element([
+        0xeb0e420c47a32fc2,
+        0xadb65f2377416b15,
+        0x2fcc64737f3e1c2e,
+        0x060193e6a2d19a04,
+    ]),
+    element([
+        0x86325f0d7783f345,
+        0x5b36412d456317ab,
+        0xbf59617e9ea1800b,
+        0x1fb81ce54633e9c0,
+    ]),
+    element([
+        0xffea314aab1a4f32,
+        0x73fd23b6148ef404,
+        0x11ed626edd7b5c73,
+        0x091d1d6cad052020,
+    ]),
+    element([
+        0x2f3baaa5e872622f,
+        0x3fee2707958b565b,
+        0x5fdf34cb0d87a734,
+        0x20b00ced048ffab7,
+    ]),
+    element([
+        0x666673cc841a4fd2,
+        0xd01d3761c193cfb6,
+        0xc46eb1202af4c81a,
+        0x1227ac03d8a5ffd3,
+    ]),
+    element([
+        0xe648e48ffbfcb2d3,
+        0x455307582fdf6aec,
+        0xa40887fd68d121f8,
+        0x04fea0731148e5d4,
+    ]),
+    element([
+        0xb760e9cb86d885ed,
+        0xe32eaeb8c926b060,
+        0x6ed0d156f83e1960,
+        0x2647f75ae660ac7b,
+    ]),
+    element([
+        0xe6828b64ac4095a2,
+        0xd1e5442e0b5f72bc,
+        0x2ff1dc3056124493,
+        0x267e46bd9558e601,
+    ]),
+    element([
+        0x411242a9358e1b5e,
+        0xb6ec6ab5093daa32,
+        0x2fcd459b7f480c20,
+        0x18043ce5dbbee36a,
+    ]),
+    element([
+        0x23d2cc1f7c127d34,
+        0xa6ba5d099d1c25c3,
+        0x9f8d1011bc689261,
+        0x0320a88137d702c4,
+    ]),
+    element([
+        0x84d21626f5f3c212,
+        0x08d6bb670403a8a8,
+        0x7bcc8b42e3bd9ee4,
+        0x045f7f7d930c37f5,
+    ]),
+    element([
+        0xd232c8b30db9c418,
+        0x414d4cde78fed780,
+        0x5f4c76b2d15db541,
+        0x09b8615f3e496a0b,
+    ]),
+    element([
+        0xcffbec3735ff244d,
+        0xc47a26715cf44b86,
+        0x3903612d4c6bb0aa,
+        0x0daf21c77efa6f4a,
+    ]),
+    element([
+        0x843b5e10fc5e29c3,
+        0xfcc9b71073bdbeab,
+        0xd4c7a4f5cacd7ac8,
+        0x211e0d64edaf52ea,
+    ]),
+    element([
+        0xeb017ca50d31cbe7,
+        0xcbb002afe9395ebc,
+        0x9ebb35f29d972fda,
+        0x026dbbc28d7e8cac,
+    ]),
+    element([
+        0xd6a626037ada19ca,
+        0x6cf9b0a5b16e77dd,
+        0x74910cce42bbd76c,
+        0x09d91b4aa8c2d944,
+    ]),
+    element([
+        0x31bf6501b4adc25c,
+        0x5facbb81d20e40f0,
+        0x71452842d035d2b4,
+        0x066143e657f185c6,
+    ]),
+    element([
+        0x22ef688b9175d826,
+        0xd263983097bd7226,
+        0x8b4c88ef93332d1e,
+        0x016cfa7115bb18b9,
+    ]),
+    element([
+        0x483a987fd5b3e69c,
+        0xc288cdc8b7f4bec4,
+        0x1326f177cfef873e,
+        0x123ce0b803f46a86,
+    ]),
+    element([
+        0x24f911edcd5051ab,
+        0x1de826f45cdc4e8b,
+        0x0db07d9bdabdffda,
+        0x0cd62e0473fe4ac8,
+    ]),
+    element([
+        0x474f0cc604e8d377,
+        0xf32ad8239beecdf0,
+        0x46b30e2dc91fbe47,
+        0x2da0cd2865f6c275,
+    ]),
+    element([
+        0xc17ec4c607fa3aab,
+        0xfd620ca8ab043c4c,
+        0x93f3aed4479a30ba,
+        0x1f2a74a95557500c,
+    ]),
+    element([
+        0x84a8b41c910a081e,
+        0x53300d6f495e3002,
+        0xe155d149fe641469,
+        0x155a4fdd53ddc39b,
+    ]),
+    element([
+        0x8a0f97d18153825a,
+        0xae59b478f68b5390,
+        0xa7db25d733aad7b6,
+        0x1b49cfadec3bdd36,
+    ]),
+    element([
+        0x08b601c11dc162d1,
+        0x1ffa79591c5b0f20,
+        0x3f758c72af221837,
+        0x2d07b3a42d34993c,
+    ]),
+    element([
+        0x8c8d77331e29a7e2,
+        0xea67183619486d5f,
+        0x5add5a186f7e95a6,
+        0x281226e62ad01f5d,
+    ]),
+    element([
+        0x6e7a949e915c0d75,
+        0x8de0012bfa205a3e,
+        0xd5155bdfc37161a4,
+        0x121a71ae4b6d3bef,
+    ]),
+    element([
+        0xf766df39a3c7f8d7,
+        0xca44021504538e03,
+        0xe33d04588fe57993,
+        0x2e5b9cc59190306a,
+    ]),
+    element([
+        0x533f36fd1b6673d6,
+        0x3a6f45df58f40902,
+        0xd2c696ab8769b405,
+        0x20c86cbf29ebd3f8,
+    ]),
+    element([
+        0x8695eadf31a2b805,
+        0x9a301c007732d2e3,
+        0x95be16912e1bc991,
+        0x1d185e8562abf784,
+    ]),
+    element([
+        0x919f717710504ab3,
+        0xc6b611dcfc9675d1,
+        0x0369c5108a64babe,
+        0x23272e057d91c1b7,
+    ]),
+    element([
+        0x26b8d1ed7dcf5a6f,
+        0xae690fa219903ae7,
+        0x63e27832ae8c0851,
+        0x2a79048cd75e0c1c,
+    ]),
+    element([
+        0xa92f0c4e8f9ccd0d,
+        0x06b4e0d91d27ec34,
+        0x995b9c9a8b05cc59,
+        0x213ffadb7511452b,
+    ]),
+    element([
+        0xe2302efb9ed77afe,
+        0x7038eb9cf175ebfd,
+        0x7110cd62f6bccdf3,
+        0x25b9d03740b9d186,
+    ]),
+    element([
+        0x78034ee9a8b6fcff,
+        0x482d2f32c65f81e5,
+        0x49ab7b66d2bc7f55,
+        0x1c0d31e7bb1389ac,
+    ]),
+    element([
+        0xf9914e7158dd246c,
+        0x52a134a40de2ff32,
+        0xdee6fb60971e0db2,
+        0x04cea30e64f8fee6,
+    ]),
+    element([
+        0x2873f69eba63125c,
+        0xeff46720ece74763,
+        0x3925924980994892,
+        0x10f4695224fb94e9,
+    ]),
+    element([
+        0x155d229d091117e4,
+        0xc33fad56650b490f,
+        0x8d435880e8d3f33d,
+        0x026ef7b23c69a378,
+    ]),
+    element([
+        0xf013d6e0e9ff4a65,
+        0x468fc7096a1f892e,
+        0xac58f64975b2ac5d,
+        0x2eee61bb07d7c2e5,
+    ]),
+    element([
+        0x7b21733b5993bcaf,
+        0x1b1a03eaac8fe786,
+        0x3596583d76e71637,
+        0x11966c83d41cee37,
+    ]),
+    element([
+        0x3e1fe8ea8f562ba4,
+        0xf11a7ed5ab761e2f,
+        0x6043efee92555977,
+        0x0f61a4cdea3d034b,
+    ]),
+    element([
+        0x26acdef284b06bdd,
+        0xb292096e7713a87b,
+        0xa75faa60c5ac3cd0,
+        0x158dae523301d739,
+    ]),
+    element([
+        0xf96c6a6db3e7303a,
+        0x93250c2521a388a5,
+        0xf60b8b5f6d587680,
+        0x0dd0df07f9094b00,
+    ]),
+    element([
+        0xf92a48339803ca42,
+        0x6698ea4ddb026ac4,
+        0xb5351344f76b7c72,
+        0x2af43b49b757149f,
+    ]),
+    element([
+        0xcf15be53869a35db,
+        0x7c26712527b6a88a,
+        0x3f9d53d76cb5d69f,
+        0x0d136e9184b8dd27,
+    ]),
+    element([
+        0x54aacd0620260363,
+        0x31a90d9c33d1ffab,
+        0x12ab25b8e64a3c88,
+        0x097f074cbb488b7e,
+    ]),
+    element([
+        0xcabbfdffbea3f7c7,
+        0x7afc48eb44ee7124,
+        0xe8f2d0eef926d4af,
+        0x08b9443676cc6f07,
+    ]),
+    element([
+        0x415815df1a29bcac,
+        0xff2248b1e6a2f2c7,
+        0x24c635291887108d,
+        0x0a3ad5f94aba0995,
+    ]),
+    element([
+        0xa6caee2cc10f7f72,
+        0xde86a370cec32c59,
+        0x56f05a85a3d5c5c6,
+        0x140969ddf80458ed,
+    ]),
+    element([
+        0x03b7ca515d906e91,
+        0x1824009f30124da4,
+        0xb5ec166cb6f7d3f3,
+        0x2c032ae8f36d85d1,
+    ]),
+    element([
+        0x8a9ef678ceaf27fd,
+        0xa9f3bae96bc51374,
+        0xf8a9a7f1c4c12f32,
+        0x16e117e6f3c3c052,
+    ]),
+    element([
+        0x7f7f2a8dae827a3a,
+        0x8fdf99ec78653615,
+        0x9e00f78e179db7ba,
+        0x1561e29965e77c60,
+    ]),
+    element([
+        0x6a090e2a12603a9b,
+        0x70213e17a583c9a4,
+        0xab03ec41ac26b927,
+        0x2d130cdceebbffda,
+    ]),
+    element([
+        0xa754b1bf03bd51c5,
+        0xfc3daa92097e350c,
+        0xcf7cd37ab89aeecb,
+        0x0f52dfc1093d1024,
+    ]),
+    element([
+        0x57ca9e09a6638594,
+        0xaf419db7d3465000,
+        0xef7cc1e7fcc6106f,
+        0x2212de1c632019d7,
+    ]),
+    element([
+        0x507ce5b14c15e3b0,
+        0xb59121afd7a8ebde,
+        0x50f101d1caeae147,
+        0x2c9484e0960fa4bf,
+    ]),
+    element([
+        0xa70392608404c865,
+        0xa3236f49c94b082f,
+        0x218922bf89252c11,
+        0x273affed7c94aa5f,
+    ]),
+    element([
+        0x35a1971f07eb6174,
+        0xec64d1866a0a141a,
+        0x9f3c3df082ae9fb7,
+        0x09370deecdd7cb99,
+    ]),
+    element([
+        0x8b830decb706c770,
+        0x23d3c5dc86d2ce6c,
+        0xc1ca5a43fdcfa526,
+        0x10ef62220ff40c5f,
+    ]),
+    element([
+        0x6cb192a643a9832a,
+        0x561ea872e0429af4,
+        0x09333f691c5592c7,
+        0x04c1cf7363781398,
+    ]),
+    element([
+        0x3a464a5ca85a5a38,
+        0x10cf34d5b5b1e290,
+        0xe5a268d7a9c4925a,
+        0x08f8708eafaf964b,
+    ]),
+    element([
+        0xb20ed225dab33bc7,
+        0x4f85300ee3e6cd97,
+        0xc2c4985179acc530,
+        0x3028e0ccfbe847f9,
+    ]),
+    element([
+        0x49ecb6b93c272814,
+        0xb0af01d233cc7e20,
+        0xbe2eef3fdeb1bf4a,
+        0x13c0b8d2ece01f05,
+    ]),
+    element([
+        0x2fe4fd619142aa40,
+        0xdd33bab88d015656,
+        0x0db26c7ee0952d71,
+        0x070d8f751eeae7ed,
+    ]),
+    element([
+        0xe3104be1ce695e12,
+        0xb63175d265698805,
+        0x0d238a112f152a7d,
+        0x238bceca01ba11bf,
+    ]),
+    element([
+        0x637d998c64f6eaa1,
+        0x82041c6af9f41a3c,
+        0x0984a92d56c274b8,
+        0x2e3c65fb3aae2f15,
+    ]),
+    element([
+        0x5508399487803e23,
+        0xe9ac8e1b17f0f3c2,
+        0x23fb4292c1b6e1ea,
+        0x092dafe95d09be0f,
+    ]),
+    element([
+        0x7a36eebc1737b625,
+        0x07f0cf8f8b519119,
+        0x4b0fe7f4778f805c,
+        0x018102a93dc7c6e8,
+    ]),
+    element([
+        0x9be940e523e2412f,
+        0xc4391bbace300e61,
+        0x5f0c501d05f871be,
+        0x2229d88d4b4212e5,
+    ]),
+    element([
+        0xb659c41a64ce7680,
+        0xe9d7438a158978c2,
+        0x68cea34fd3a7de5a,
+        0x11be76b5b9b7e2a9,
+    ]),
+    element([
+        0x88cd00f18e1c4163,
+        0xe40ddf8be147a33d,
+        0x9b5ade953d63d238,
+        0x0170f6d908db2e43,
+    ]),
+    element([
+        0xe1879a32e980781d,
+        0xab1320c4e30e1bb3,
+        0x668b590d0cff2401,
+        0x1cb83965fd5952d4,
+    ]),
+    element([
+        0x7ea1f7e74fbe8ee4,
+        0xfed161f8004ee5f2,
+        0x7d9293a4f18fa0e8,
+        0x2f4dae3ce34a24d4,
+    ]),
+    element([
+        0x817e2933eec9b815,
+        0x119093788f67c9d0,
+        0x117b4fbdc3accbab,
+        0x1c6db7a98f317b32,
+    ]),
+    element([
+        0xb086952bb3c68024,
+        0x08309c0f12e145a0,
+        0x108d3b191e5591a2,
+        0x0924a986f6507c50,
+    ]),
+    element([
+        0xd714b6ebf100eac5,
+        0xd1d1a4bb35e91267,
+        0x77c93bfc382e364e,
+        0x1c2ac37d6d8e8f72,
+    ]),
+    element([
+        0xab1320bed5fa77e1,
+        0x2ae4d3de9d90db27,
+        0x9a4b64dcdc435e63,
+        0x2aa7474ba492b899,
+    ]),
+    element([
+        0x6b454a4c4270e92f,
+        0x6dc8143deaed9802,
+        0x72ad70952f8bd7fb,
+        0x0029a253beb9a40f,
+    ]),
+    element([
+        0x960665fc9edca9a5,
+        0x99a4b98f9026ab01,
+        0x16d617202db898f2,
+        0x2b2c5f8c5ad5cc0a,
+    ]),
+    element([
+        0xb9b9466472033e3e,
+        0xed8c6bd351efbc20,
+        0x7df2b95129e850e1,
+        0x2aca8942e37931bd,
+    ]),
+    element([
+        0xde1e8860ca281c81,
+        0xe167b7609de99e4c,
+        0x13be06432cbf5d55,
+        0x2f9c4759bace9ffc,
+    ]),
+    element([
+        0xe492e9ad7316bded,
+        0x76d9199ace6f7abf,
+        0xa166dcead91e6b9b,
+        0x15d1f53dd166cf2c,
+    ]),
+    element([
+        0x4efcfba272e6e59c,
+        0xb6fcf90abb8621e5,
+        0xc2de60aa11f81b4b,
+        0x1a69ee4f44babdbc,
+    ]),
+    element([
+        0x20689e1acbe0145a,
+        0x2e63f4ec0bd4bbdc,
+        0x69e9a31ee94c1988,
+        0x17305eb072a0010e,
+    ]),
+    element([
+        0x622650607bd60e14,
+        0xcdbcfbde66b83c06,
+        0xad0f0f3a2c0dc14c,
+        0x1bc5064924adf240,
+    ]),
+    element([
+        0xac7ec082bcaacefc,
+        0x95b483fdfc05d246,
+        0x7b84501ebc55e504,
+        0x24d99b9a53a774c5,
+    ]),
+    element([
+        0x1c8c050fe89aec45,
+        0x7ce593258f7ac7e9,
+        0xa36a773e87c2533b,
+        0x111dc66e3f8d21ab,
+    ]),
+    element([
+        0x068e124dcc37d3c1,
+        0xe2c80e8f092ea079,
+        0x88a8d420f6386633,
+        0x1b97a2ab2abbe88e,
+    ]),
+    element([
+        0x1823725d33cc0497,
+        0xfbaac3470a4c3c0c,
+        0x473f50b799ad3e06,
+        0x1173c7d28d6bdbf5,
+    ]),
+    element([
+        0xe8701e037a0657d9,
+        0x83f3bec5e14ee87d,
+        0x98cec1441cb01a49,
+        0x1e147aad510a36fc,
+    ]),
+    element([
+        0x820ba545d39e3dfb,
+        0x31003304a5b70aa7,
+        0x190ca2e4c5b3b2e3,
+        0x13a734e77ee7a1ec,
+    ]),
+    element([
+        0x6f693ac863bbec78,
+        0x4cacb2b22c8135f2,
+        0x5864c9113f1268cc,
+        0x2dadea353c940e0e,
+    ]),
+    element([
+        0x9e103a799421c799,
+        0x8de01403fbf4d241,
+        0x34494ccb6402988e,
+        0x0baccf5b3952fa41,
+    ]),
+    element([
+        0x45902f6ae33a0e45,
+        0x17d35a9f1e42e834,
+        0xe644d769b70e46ec,
+        0x04c66ba6f5fd84e5,
+    ]),
+    element([
+        0x53c6b7e9bae4918d,
+        0x7d68b95bc8988709,
+        0x86c2708f23e4b067,
+        0x2e2dd36341eb962a,
+    ]),
+    element([
+        0xfe7f76ce153c6a26,
+        0xff2a5bedf6c6b515,
+        0xc652a6bf4a3a49e7,
+        0x2dbfcc6696e23c8f,
+    ]),
+    element([
+        0x2f9b8eade750046a,
+        0xb863f4603d07b4f7,
+        0xedd63d26b39aa74e,
+        0x1df2a949f8a0171a,
+    ]),
+    element([
+        0xa4e9d51068e90b6e,
+        0xd1a0d409ff0eb3d7,
+        0x1158e1f1ded5edce,
+        0x0513c82aab325eab,
+    ]),
+    element([
+        0x03376d1ca269d2c3,
+        0x297e69ab03fc38c3,
+        0xd23702e8b3c9e2f5,
+        0x0d472280bae46388,
+    ]),
+    element([
+        0x59fdb5f2029a2705,
+        0x5cd8f8861fedc065,
+        0x1ebed93e337e2f49,
+        0x2578ed4aeadf7df5,
+    ]),
+    element([
+        0xfbcb9653ef310ed6,
+        0xed40e4f92bd0c8f4,
+        0x3598d4315117e9bd,
+        0x0dade88d6e3c4cbc,
+    ]),
+    element([
+        0xe1510efcc2dbc8f5,
+        0x2af41e0923b448b7,
+        0x67cd69abdf49dbee,
+        0x17f38f525f07ac2c,
+    ]),
+    element([
+        0xe9282e644bc6eb5e,
+        0xb21f7c61a5023d4e,
+        0xce7d8adc3b392b73,
+        0x24495597ee7c1526,
+    ]),
+    element([
+        0x42167f86732a375c,
+        0x5c8b8423c71c869f,
+        0xb65ae3f64299a9f0,
+        0x2bb43ce3c8595714,
+    ]),
+    element([
+        0x99ef4c962f066138,
+        0x97082984ff648a6e,
+        0xde5cedbacf028270,
+        0x0d618ee379b4b8b6,
+    ]),
+    element([
+        0xa9922554069bb400,
+        0x2a7928775bf208ba,
+        0x33bd2c4e563a6e3a,
+        0x2a21a3d3dbbb97f0,
+    ]),
+    element([
+        0x6d6c0c09408ec03e,
+        0xd3ca3079a89227cd,
+        0x9aeac3eca971b308,
+        0x2895fed0bed73acf,
+    ]),
+    element([
+        0x117446c5fb58e566,
+        0xd82fb8c89ee38e2e,
+        0xa3ad7b4aeb5cf95b,
+        0x09554c8cdc8fb076,
+    ]),
+    element([
+        0xfefdf39f278fdb98,
+        0xfdc3dbc197b797f0,
+        0x845dc5096e0aaa50,
+        0x255c0e6b60e99344,
+    ]),
+    element([
+        0xc526a3c973c125f3,
+        0xff589375204b398f,
+        0xdceaf7734c0c4b3e,
+        0x2ccfbfcec905cbdc,
+    ]),
+    element([
+        0x8eb30aa9059e57fa,
+        0xd438836709df28aa,
+        0xa9a0a41847c7aaf6,
+        0x23b222fb0a30b709,
+    ]),
+    element([
+        0x3d45646a35f6d973,
+        0x5048f157d51af298,
+        0xc13a1fda884132e6,
+        0x00ce3066629607dc,
+    ]),
+    element([
+        0x1ed27e7abe99d21f,
+        0x8c72001efea4816a,
+        0xe203a77bfa58129c,
+        0x249de90d5fc440b1,
+    ]),
+    element([
+        0xb80e343b619dcdcb,
+        0x7a32030b967b191d,
+        0x656a8f5772f0d362,
+        0x096a8efee49cd6f0,
+    ]),
+    element([
+        0x636a1296ab6236ed,
+        0x52a2e143cea95dd0,
+        0xd7dd3fc0a1974b16,
+        0x1f286966574bff11,
+    ]),
+    element([
+        0x10c38d3884c218d2,
+        0x6c9d3d9ce5b74024,
+        0xbd9866aa7911fafb,
+        0x2e06f4f15ecbfa64,
+    ]),
+    element([
+        0xa851c56948a6c568,
+        0xb484637f33032a14,
+        0x46222555bff63113,
+        0x25bb659d35e3486e,
+    ]),
+    element([
+        0x19da387d5e66a28e,
+        0x08efd48e806bf275,
+        0x06b5acf2a0452993,
+        0x2addcc9700f493e8,
+    ]),
+    element([
+        0x2967e0e947f62ecd,
+        0xa65d797fd023bc28,
+        0x1adf4d3d34b8723b,
+        0x2f921140a5d469db,
+    ]),
+    element([
+        0xc59e2e6182a6b6df,
+        0xc67e1c95802a37ba,
+        0x5dc66684d7f2a190,
+        0x2963c24f39f8350a,
+    ]),
+    element([
+        0xb52ac9eee2d1c829,
+        0xa914da33f52560c2,
+        0x5c57b5ef11304acc,
+        0x146c391336e69e57,
+    ]),
+    element([
+        0x5a268429131fc985,
+        0x00354bf0e677e310,
+        0x6fc0e388b1ce031d,
+        0x21153cfe85ab33a7,
+    ]),
+    element([
+        0x7dc883f140b45bcd,
+        0x17ba050486e560ff,
+        0xffe1a07d40664ea2,
+        0x03094637ba4e3729,
+    ]),
+    element([
+        0xde1a5a5fa215d024,
+        0x224c4b844fae7682,
+        0x48fc5412dd4366dd,
+        0x252a59dfdec9f602,
+    ]),
+    element([
+        0x12c569eb34cced29,
+        0xa1d235f73508409e,
+        0xf3b12dca1b95c049,
+        0x188d415b9eae73e0,
+    ]),
+    element([
+        0xa6437354883e412d,
+        0x09098b4be9049390,
+        0xf6ea33f61354d44f,
+        0x12f68caf8d05dff6,
+    ]),
+    element([
+        0xb8068c3e4bf93600,
+        0x9e03286a6f285f48,
+        0xf442fac1a4d13238,
+        0x11379e6978945277,
+    ]),
+    element([
+        0x3f8d2736d572338f,
+        0xf109bd06fba0949e,
+        0x462352243387c777,
+        0x1506060bf4aeeac2,
+    ]),
+    element([
+        0x414ae732a7927add,
+        0x25b82a880d67ffda,
+        0x8032bf44cc51adb8,
+        0x03b1de0fc6ab1325,
+    ]),
+    element([
+        0x4fe06bb8572e6167,
+        0x04e1339be25a506a,
+        0x7e7a5a3d96ff41da,
+        0x075d87f851c13a4e,
+    ]),
+    element([
+        0x7a20d6b9cca688ee,
+        0xa3d25447c1702ae9,
+        0x128c8b7d86448f56,
+        0x1afcaa8e9de99187,
+    ]),
+    element([
+        0x388e287069185b02,
+        0x8828a778f63a99dc,
+        0x2032681220778b80,
+        0x153a7f98c575e0c9,
+    ]),
+    element([
+        0x4497f974f08ded44,
+        0xa206a4e81727ebce,
+        0xf79b851f32069c83,
+        0x2cf2168135f6536a,
+    ]),
+    element([
+        0x2c86c0224792e609,
+        0xf146ff25e77a331a,
+        0x4c0208320d9640a4,
+        0x0deda06a0bdb6eb0,
+    ]),
+    element([
+        0x9ad8e334fc372468,
+        0x09c93ba861cdd5b7,
+        0xdcde18fcca43c619,
+        0x05f0ae44e568c210,
+    ]),
+    element([
+        0x5d6f103f11b10242,
+        0x0013163ef37c2445,
+        0xb9922e51def31662,
+        0x069849add5438d1e,
+    ]),
+    element([
+        0x110a5b138936ce08,
+        0x2ae5ab7e9ebb9743,
+        0x88a48e47a6d794ca,
+        0x2d3ab72e7106c740,
+    ]),
+    element([
+        0x7cbbc09f1f67e6d4,
+        0x4eba3f5312296d4f,
+        0xaaa7aed6ed3e823e,
+        0x2276a5b40e4fa6f6,
+    ]),
+    element([
+        0x5543f6a402a4a6b6,
+        0x267ea1949014df03,
+        0x6058ed67869f474c,
+        0x296b9cfb13571de5,
+    ]),
+    element([
+        0x0c3c513671297e0c,
+        0x75becaf034b209c8,
+        0xab6f47b3518618c4,
+        0x00e6bb3590a37c1a,
+    ]),
+    element([
+        0x3982d3f54d16ab00,
+        0xf56a83bbfad14ec6,
+        0x5e495a281f607dc1,
+        0x01e5dde4f0095473,
+    ]),
+    element([
+        0xbab30613387c10f9,
+        0x24a43c140bbf9704,
+        0x4812768fff2a2283,
+        0x056ca8dcb8a27267,
+    ]),
+    element([
+        0x16d99f370bd6627b,
+        0x32d49f4cc56ef085,
+        0x8b6382e804133da6,
+        0x1e8186b914942c03,
+    ]),
+    element([
+        0x75dbbd54832671df,
+        0x147ac5cbe950d6cd,
+        0x02b6d54b39eeb8f1,
+        0x28c64f5f52bdbe28,
+    ]),
+    element([
+        0xad24a689b3afc205,
+        0x960b8a73607cb69d,
+        0x7ee9adbfe7212506,
+        0x256c98d91d11bfa7,
+    ]),
+    element([
+        0x7ed19456f72e967f,
+        0x0bfe3e4c1446bcd8,
+        0x52518eb38c419449,
+        0x2d25d42b2cd9b0b2,
+    ]),
+    element([
+        0xfa71cbb97d3c4dd9,
+        0xb9b02e0523fe3a0a,
+        0x2e4e9dea58984fc7,
+        0x13aeb85d80162bd2,
+    ]),
+    element([
+        0x3bb0bc7252f1f32c,
+        0xe76e2b579dedaaff,
+        0x3b43ef4b0bcea9c0,
+        0x11a10d7fff3e04bc,
+    ]),
+];
+
+/// MDS Matrix in Row-Major Order for [`Spec4`](super::Spec4)
+pub static SPEC4_MDS_MATRIX: [Fp<ConstraintField>; 25] = [
+    element([
+        0xd745397409999999,
+        0xb4ada7d483c3efa8,
+        0xc49ca2f8e57f3161,
+        0x162a3754ac156cb3,
+    ]),
+    element([
+        0x7d695c480aaaaaaa,
+        0x3a880fcfaed9b4f4,
+        0xda7526dba9a9c517,
+        0x0a67cbb369deea8e,
+    ]),
+    element([
+        0xf41575289db6db6d,
+        0x07daec5e847b8b05,
+        0xea0fce347eecc0e2,
+        0x02017ed283b7fb4f,
+    ]),
+    element([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2000000000000000,
+    ]),
+    element([
+        0x80dce13d51c71c72,
+        0xec7d50101b0cc3ae,
+        0x0c839db6c77213a2,
+        0x2732bc1987605c7b,
+    ]),
+    element([
+        0x7d695c480aaaaaaa,
+        0x3a880fcfaed9b4f4,
+        0xda7526dba9a9c517,
+        0x0a67cbb369deea8e,
+    ]),
+    element([
+        0xf41575289db6db6d,
+        0x07daec5e847b8b05,
+        0xea0fce347eecc0e2,
+        0x02017ed283b7fb4f,
+    ]),
+    element([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2000000000000000,
+    ]),
+    element([
+        0x80dce13d51c71c72,
+        0xec7d50101b0cc3ae,
+        0x0c839db6c77213a2,
+        0x2732bc1987605c7b,
+    ]),
+    element([
+        0x0d939783fccccccd,
+        0x6e70c80e7ebeb01d,
+        0xbe767457b38044df,
+        0x234742e3c6a3866e,
+    ]),
+    element([
+        0xf41575289db6db6d,
+        0x07daec5e847b8b05,
+        0xea0fce347eecc0e2,
+        0x02017ed283b7fb4f,
+    ]),
+    element([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2000000000000000,
+    ]),
+    element([
+        0x80dce13d51c71c72,
+        0xec7d50101b0cc3ae,
+        0x0c839db6c77213a2,
+        0x2732bc1987605c7b,
+    ]),
+    element([
+        0x0d939783fccccccd,
+        0x6e70c80e7ebeb01d,
+        0xbe767457b38044df,
+        0x234742e3c6a3866e,
+    ]),
+    element([
+        0x9e265a3f9e8ba2e9,
+        0xf3b110cdf2a6f027,
+        0x1afe9ea62351d247,
+        0x2478727c6bf642f4,
+    ]),
+    element([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2000000000000000,
+    ]),
+    element([
+        0x80dce13d51c71c72,
+        0xec7d50101b0cc3ae,
+        0x0c839db6c77213a2,
+        0x2732bc1987605c7b,
+    ]),
+    element([
+        0x0d939783fccccccd,
+        0x6e70c80e7ebeb01d,
+        0xbe767457b38044df,
+        0x234742e3c6a3866e,
+    ]),
+    element([
+        0x9e265a3f9e8ba2e9,
+        0xf3b110cdf2a6f027,
+        0x1afe9ea62351d247,
+        0x2478727c6bf642f4,
+    ]),
+    element([
+        0x3eb4ae2405555555,
+        0x9d4407e7d76cda7a,
+        0x6d3a936dd4d4e28b,
+        0x0533e5d9b4ef7547,
+    ]),
+    element([
+        0x80dce13d51c71c72,
+        0xec7d50101b0cc3ae,
+        0x0c839db6c77213a2,
+        0x2732bc1987605c7b,
+    ]),
+    element([
+        0x0d939783fccccccd,
+        0x6e70c80e7ebeb01d,
+        0xbe767457b38044df,
+        0x234742e3c6a3866e,
+    ]),
+    element([
+        0x9e265a3f9e8ba2e9,
+        0xf3b110cdf2a6f027,
+        0x1afe9ea62351d247,
+        0x2478727c6bf642f4,
+    ]),
+    element([
+        0x3eb4ae2405555555,
+        0x9d4407e7d76cda7a,
+        0x6d3a936dd4d4e28b,
+        0x0533e5d9b4ef7547,
+    ]),
+    element([
+        0x7a2e512ca13b13b1,
+        0x80918f51bc866fde,
+        0xe9288d736bf5d7ea,
+        0x0886640cdfb97893,
+    ]),
+];
+
+/// Additive Round Keys for [`Spec5`](super::Spec5)
+pub static SPEC5_ADDITIVE_ROUND_KEYS: [Fp<ConstraintField>; 384] = [
+    element([
+        0xf63e1b83ca597d92,
+        0x4cc58b2a5cac7bd6,
+        0x1922fcd03eb2795f,
+        0x139f2e3602049cd8,
+    ]),
+    element([
+        0xc351c44c07766f7d,
+        0x36847529d1cc2f4e,
+        0xc3c341fb60b5e948,
+        0x22493fde403c0d1e,
+    ]),
+    element([
+        0xc64c511f238adb5d,
+        0x52a419675bb78017,
+        0xb1c7e991f2067666,
+        0x030ff556be7f2b25,
+    ]),
+    element([
+        0x262c3193f461ed6d,
+        0x8169e70e49fda17d,
+        0x5b33a4444ac233d9,
+        0x118cddbdae15c386,
+    ]),
+    element([
+        0x7cea6f29545432ea,
+        0xf68fd5c40c1a1fc1,
+        0x42eb78ce6c84beb0,
+        0x269de39f6fcac053,
+    ]),
+    element([
+        0x3d36e0ef2fd7835d,
+        0x83266e6e41c33c40,
+        0x6884ec85d467bfb6,
+        0x1295923a43aa6171,
+    ]),
+    element([
+        0xc023d76076d11643,
+        0x8ac42d04128a360d,
+        0x72203f40431c5f35,
+        0x242bb6a611f5ea98,
+    ]),
+    element([
+        0x530e8590d55cbd00,
+        0x567057bba85469f3,
+        0x981b14616a16a88a,
+        0x0e613bc4e93093ba,
+    ]),
+    element([
+        0x5f33168228db4914,
+        0xff990501f6ce3d5e,
+        0xd5bf144c8b129235,
+        0x22045f30b1b669a3,
+    ]),
+    element([
+        0x8490e7e4a5d74b0d,
+        0xacb70413915ce739,
+        0x87deb79c14b86dad,
+        0x082d1a14fe841465,
+    ]),
+    element([
+        0x63efe5f731b92a5d,
+        0xf27c86f5fae09958,
+        0xbc26eb3dafb3bd15,
+        0x13c320bfb815e921,
+    ]),
+    element([
+        0xa2ef2192f9e35b4a,
+        0x7092756725ed29fb,
+        0xb0fbfba04214061d,
+        0x0d137776f8a60367,
+    ]),
+    element([
+        0x89e16da1282889ac,
+        0x776158b3b71975ff,
+        0xd451184142b2db7f,
+        0x05a65d45005f920a,
+    ]),
+    element([
+        0x1da5ebff3b884c94,
+        0x8e5a5d8ece302e0f,
+        0x6cd5095d89e8447b,
+        0x2b040716fafacf2f,
+    ]),
+    element([
+        0xce90c91476e14dc4,
+        0xb89a919236615c38,
+        0x0f3aad5ce86392c3,
+        0x2d2cae59aed5efe8,
+    ]),
+    element([
+        0x6e7909ba28c3dc19,
+        0xd47c1fc70e7eadbc,
+        0xe91e626ff1296e9f,
+        0x15c070833410c529,
+    ]),
+    element([
+        0xc7df4e01d8a3a165,
+        0xf67a8150b6598db2,
+        0xe228cc12c3071b92,
+        0x1099db0c5182af8f,
+    ]),
+    element([
+        0x745c26cdf70faaac,
+        0xbd6aab96753ce581,
+        0xac3448e851e99ce2,
+        0x0d32641d1f021678,
+    ]),
+    element([
+        0x73d020187913e26f,
+        0x9e560928815aa6d4,
+        0x337bc1ae8cb1afe9,
+        0x2c2cf95ebcc1a1be,
+    ]),
+    element([
+        0xd231807e944d01df,
+        0x23069b4371a33876,
+        0xe74268284aab0f1e,
+        0x213397cf5b66bc41,
+    ]),
+    element([
+        0xaad0d9ec9c95938f,
+        0x60060261f652b7b9,
+        0xd76cd179d9001b36,
+        0x1e02ed05dc96d8c4,
+    ]),
+    element([
+        0xaee74b1461002a80,
+        0x2c9a6d3e0eb56be6,
+        0x0b0d536e4386506d,
+        0x2e15eb42958e7af7,
+    ]),
+    element([
+        0x1387851be7d85d82,
+        0x166aae14a915f46d,
+        0x712a7b76ff14de22,
+        0x22947fa82b96b061,
+    ]),
+    element([
+        0x10ab344b8669dfb8,
+        0x65d9ceedf1abbf2d,
+        0x8060618981f22d7d,
+        0x2828d1643c5847bc,
+    ]),
+    element([
+        0xa647133fcb21cc44,
+        0xa879b606e4594e28,
+        0x4b85fada77179bae,
+        0x139b47056cb39d65,
+    ]),
+    element([
+        0xf4490e8a3d978c00,
+        0xcaca32804115b289,
+        0x6723b96d66895c23,
+        0x12e2f171991befd0,
+    ]),
+    element([
+        0xcb7d63a06ddc63a8,
+        0x475c189864dd4b94,
+        0x62d71cb87cba4b30,
+        0x1e6a3a50404d4565,
+    ]),
+    element([
+        0x4c1e897eb28580c5,
+        0xdc201fd9e15899fd,
+        0x3bb32585710fc89a,
+        0x1a43c24961d6426d,
+    ]),
+    element([
+        0x95634f0dd9508632,
+        0xa7727fdd4323b415,
+        0x17a03474355df164,
+        0x1ebb010b4042e589,
+    ]),
+    element([
+        0x5386f2a6c40d22e6,
+        0x69f59c2c48899658,
+        0x219d9437e4e9e5e9,
+        0x064390be7261a440,
+    ]),
+    element([
+        0x75daf54cae54b276,
+        0x8553181624c81c19,
+        0xc100d9cd492dc22e,
+        0x26c8b388f8510877,
+    ]),
+    element([
+        0x15cddd913bb01680,
+        0xe9a94fda0d2536f1,
+        0xbc987326ad3ed603,
+        0x1f5be8c531296e00,
+    ]),
+    element([
+        0x043997ec5d4b3120,
+        0xc9b59a71787ff842,
+        0x58775fc432869530,
+        0x220bbc6625ec6317,
+    ]),
+    element([
+        0xbaf61847398ee0ae,
+        0x5676b0db33ff773c,
+        0xe5ca38810f1e3d26,
+        0x24f5b9d7efe18c57,
+    ]),
+    element([
+        0x5c2861e297a4af62,
+        0x8f311de58f467274,
+        0x038a979d0440259d,
+        0x1c4f1457f5c550e0,
+    ]),
+    element([
+        0x2e31a842fc7d5d96,
+        0x3646d2f52a994335,
+        0x2b289ec2dd13fcf9,
+        0x06b9cd967d3a10b1,
+    ]),
+    element([
+        0xc03cc60a2cbc6b6d,
+        0x2650672aef35a39f,
+        0x1d83529ca0fdf34c,
+        0x2b3401782348168e,
+    ]),
+    element([
+        0xf0e050b00c45b4f1,
+        0xbaa0a7c1feacf7a5,
+        0x50dc409187ecb506,
+        0x11fbf386971bd260,
+    ]),
+    element([
+        0xe3114f51ac3fdd6f,
+        0x88ad8c1404a5034a,
+        0x36ab24d9cc91a97c,
+        0x17759ef6dd314a86,
+    ]),
+    element([
+        0x47abccf1c4293b5f,
+        0xc61295d01237b65e,
+        0xd1ed29768f25aebf,
+        0x123ea89a8f1cf04d,
+    ]),
+    element([
+        0x2c3905cace4b1475,
+        0x91a2be100d5db3af,
+        0x4dd9e3054d5abe21,
+        0x166d30a8fd7e7ff0,
+    ]),
+    element([
+        0xb3243a1908ad1b86,
+        0xa8f99f2d274533b1,
+        0xb27a8ed858de1eac,
+        0x0685f6900918e74b,
+    ]),
+    element([
+        0x70f3ffb9a236659e,
+        0x6fd00668447d9601,
+        0x3a627c0969640dfc,
+        0x1dc3a2f9b68b52b9,
+    ]),
+    element([
+        0xc1b0d98ce3c3d86b,
+        0x4435e9990db100af,
+        0x3d5ed117b0ea0e40,
+        0x27db0cc6ba2c1453,
+    ]),
+    element([
+        0x09faf20e18f6874d,
+        0x9cf71dba2887a028,
+        0xb7ec5419b47309b0,
+        0x25a4da0d71f49500,
+    ]),
+    element([
+        0x8648adc154c4247a,
+        0xa8c18939b10b8b40,
+        0xedbf2bfbfdba18f3,
+        0x032d8835635b7828,
+    ]),
+    element([
+        0xd0de22f7d8fd1c02,
+        0xae11e5f6c5bcaccb,
+        0x75d415362136ca40,
+        0x05054ff67eb5779c,
+    ]),
+    element([
+        0xfb92a465168d4fb4,
+        0x8d94665a2ea25507,
+        0xbcc27b87161032ce,
+        0x19bc3732cbd2da7c,
+    ]),
+    element([
+        0x974ce5b713cdb169,
+        0x550ff66a2f44533c,
+        0xdd6268e46d51bba5,
+        0x0fbd6ef85228cb25,
+    ]),
+    element([
+        0xc248dcbad2dabd5d,
+        0x741ec04c61964af8,
+        0x91e30264a09ff0f7,
+        0x2194f15757032639,
+    ]),
+    element([
+        0xed4586481df1863d,
+        0x19cb0736ec59a580,
+        0xa033a2182cdbda58,
+        0x14bdfe69f2d458b1,
+    ]),
+    element([
+        0x128a5200d32617a5,
+        0x4e1f99643b4ea165,
+        0x82d7694a0b4c1ca3,
+        0x1bd8efc079197457,
+    ]),
+    element([
+        0x802e7f9f17f9445d,
+        0x848f6885147f11a2,
+        0x0a9962b6013c076d,
+        0x2f31fba02e505f4a,
+    ]),
+    element([
+        0xe498e8d991846a35,
+        0x692ed14567c9b60e,
+        0x3205a882de02fafd,
+        0x010b4bef8338170b,
+    ]),
+    element([
+        0xb2706956a43baaa0,
+        0x9b8975f511bf4094,
+        0xc0863feb7f979d91,
+        0x0df3fe34b7d08f1b,
+    ]),
+    element([
+        0x66fff838fd50be30,
+        0xfd9db58c3cffc93e,
+        0x983dcd42ee725d8f,
+        0x2255928ccaf7abf8,
+    ]),
+    element([
+        0xdd836b5973fb4102,
+        0xfa5fc68a8595da85,
+        0xd42326c68757f580,
+        0x1d7321ec9676931f,
+    ]),
+    element([
+        0x834a60b82483b523,
+        0x01c7551e5c75cae6,
+        0x2e711bdfbb24f323,
+        0x08b35d76124bd3a6,
+    ]),
+    element([
+        0xd2d75f39a914d673,
+        0x0623dbafd4893496,
+        0x2caf2e83a321616f,
+        0x0b58f181604b2f1d,
+    ]),
+    element([
+        0xb000aa3b941466ec,
+        0xc3b48ab7aea50f00,
+        0x9ea87dd5c3a3530b,
+        0x1a754e5b494de0c5,
+    ]),
+    element([
+        0xef87e31736fb5a23,
+        0x5f7a07952a32d326,
+        0x3dc4a1d356c6834d,
+        0x2c5244636e73fea2,
+    ]),
+    element([
+        0x1b2e0397c2898470,
+        0x903171f92bbdead1,
+        0x55abfb1f7532ffcf,
+        0x0c049d2ed1769e53,
+    ]),
+    element([
+        0x33e83331db3fd9b3,
+        0x5d9342e8699fe669,
+        0x5d5ac649930c8eff,
+        0x2f4e63b3eb36111a,
+    ]),
+    element([
+        0x614a968919af6b00,
+        0x3d194e977c9d42f9,
+        0x12e4061db210dc8c,
+        0x089647d7e5d7666d,
+    ]),
+    element([
+        0xbd42e7f469169d9f,
+        0x506548003d73d595,
+        0x5c2d67744a320274,
+        0x0d004115db4f6636,
+    ]),
+    element([
+        0x68e29d4bf3e62d7f,
+        0x66014a0813ceb226,
+        0x10dcee81c1008518,
+        0x158aae5b4c2a5afa,
+    ]),
+    element([
+        0x2dd7a099ee01713b,
+        0x360db80262188ed7,
+        0x0112dc555f96dfef,
+        0x2e1cfc9925a12573,
+    ]),
+    element([
+        0x9e7ee19d68b5f200,
+        0xefb492b012ebd8de,
+        0x575f225424dc5769,
+        0x05dccf02579a21aa,
+    ]),
+    element([
+        0x2f3d2022c65037ec,
+        0x776227972b8491c5,
+        0x5de69fb46deec684,
+        0x291df8909be69a8c,
+    ]),
+    element([
+        0x966c201053f23d5c,
+        0xe505b8ff57abb14d,
+        0x72c4f8d154530019,
+        0x0c1b4a4f402d6326,
+    ]),
+    element([
+        0x391cc28b50144f33,
+        0xde8d966ddfab5b64,
+        0xa6aca1e9b34e3b47,
+        0x1b8b6a9d257a5b63,
+    ]),
+    element([
+        0xb5f9dfc8f7a1b574,
+        0x5216e1056bf912b7,
+        0xc350291f4efce765,
+        0x25e472f3f5c2889b,
+    ]),
+    element([
+        0x89eb5c11b046bff7,
+        0x4654b541bf428f9f,
+        0xa1141ab779b17527,
+        0x080ef480f13b8553,
+    ]),
+    element([
+        0x82b921a3d2701447,
+        0x80b33a7e8a6ccb32,
+        0x30a457d3dcd4d065,
+        0x2fe577b2e4355748,
+    ]),
+    element([
+        0x357a267ffbaeb917,
+        0xdf1e989d7c9319f6,
+        0x3e106aaad563f10b,
+        0x0669e8ad9257b6c8,
+    ]),
+    element([
+        0x5d199bc62c2890d0,
+        0x71c82b32326ee131,
+        0xd2af3a2caa376850,
+        0x304a11ca43bbd04a,
+    ]),
+    element([
+        0x7edc61c90163d21f,
+        0x3194a21672d16eac,
+        0x71537b8fc91f8028,
+        0x25a3bbc651eca7a8,
+    ]),
+    element([
+        0xe90cd7773a2ff5d5,
+        0x3a18a87b52b5f948,
+        0x76eb474a9fd8a689,
+        0x1d37ca95747a1684,
+    ]),
+    element([
+        0xd7a4112238228ff6,
+        0xc8220b4a20689da3,
+        0xf3a6c2d6e57b63c5,
+        0x18fac6f13b38217b,
+    ]),
+    element([
+        0xb594a6b3517b69a9,
+        0x528dd7a4af6f2eb3,
+        0x0cb77d169ea4acfc,
+        0x2e4a549e97d76858,
+    ]),
+    element([
+        0xe7d71e8e6dc79f66,
+        0x1cb4a533da30eff0,
+        0x8b805004d127e467,
+        0x0ab630cb86d9fefe,
+    ]),
+    element([
+        0x9398af59971b349f,
+        0xcffd3e6f27cae093,
+        0x7a2be08901df72f6,
+        0x18521b684857ab2d,
+    ]),
+    element([
+        0x05d02c3e07ae96d9,
+        0xc1a8aa235380902c,
+        0xa152c056cb2769b3,
+        0x13371f6333a6ac8d,
+    ]),
+    element([
+        0x78a93db8398e1d00,
+        0x792a03830d27a2ad,
+        0xcfba0a19bf65de18,
+        0x1d7c211f8c5e8e3f,
+    ]),
+    element([
+        0x3bb43c92074500eb,
+        0x96396dba34c664de,
+        0x1aa3da9d19d6f6da,
+        0x1cf5a64d4c47f70e,
+    ]),
+    element([
+        0x9723bd53c36de51f,
+        0x88dab801e85c3c65,
+        0xba65e5cf59eb49c9,
+        0x11297de7900b3e27,
+    ]),
+    element([
+        0xbd4c6c4669236371,
+        0x7715dc5b4b226930,
+        0x19d818728890e677,
+        0x2f609babf5c44197,
+    ]),
+    element([
+        0x85b3b08b05b6f7d5,
+        0x8be1c0c8e1da8221,
+        0x2f04e463f837febe,
+        0x00cc0781acaae3e0,
+    ]),
+    element([
+        0x7542a6b056878ee7,
+        0x1e811a0a01aaa850,
+        0x00040fc9c71f8830,
+        0x20f46ffde6d4ff66,
+    ]),
+    element([
+        0x13906d57f096a269,
+        0xd5eb0a23630b79d9,
+        0x98b34a3e0b19bed1,
+        0x2235fd38e7ebbc94,
+    ]),
+    element([
+        0xa0684a3fe346bd4b,
+        0x5c86efb5e31fd9ab,
+        0xd2639c0e02c75505,
+        0x10a71117f9bff945,
+    ]),
+    element([
+        0x35a174a048a3f4c4,
+        0x75fff61169aaefc1,
+        0xd1da390fe16c42df,
+        0x302ce2020b47924b,
+    ]),
+    element([
+        0xfa592dce21290c6f,
+        0x438526b6afa03470,
+        0xd2e48c0635473665,
+        0x12dc871e89a2baac,
+    ]),
+    element([
+        0xe8fe93a34f93e45b,
+        0x324f44cf39c3d499,
+        0x72191b7dfdb38256,
+        0x231ad3e42311dbd6,
+    ]),
+    element([
+        0x7a7d08986e20767a,
+        0x4e236b68d8dff2bf,
+        0x168a67710a0b8130,
+        0x0df3f4cda50ee574,
+    ]),
+    element([
+        0x152d2f087481aadd,
+        0xccea110858a7181b,
+        0xc8d8852eefc2563a,
+        0x0e993995e2be0e07,
+    ]),
+    element([
+        0x43308ba09b8eba08,
+        0x71ef40494eca283d,
+        0x9db91edd3e9f4f35,
+        0x17e982b4d39c6cc9,
+    ]),
+    element([
+        0x5494c0d6201736a6,
+        0x27c009e8dfd4f325,
+        0x753d88a46a156c73,
+        0x0eaae717aceeef37,
+    ]),
+    element([
+        0x4bebec96c39f1c03,
+        0x9d0e8804b9645957,
+        0xd7873c8bb88d77f6,
+        0x0b26f9d36aaabccd,
+    ]),
+    element([
+        0xa915456cef94293c,
+        0x1dd656f7ef09c04f,
+        0x9ae6e96fb36efabc,
+        0x0353b2842b917b15,
+    ]),
+    element([
+        0x21adae444055e717,
+        0xa99f0a6f9cea68c2,
+        0x963b6ce9d6111c39,
+        0x0e84d28cb4f40332,
+    ]),
+    element([
+        0x2a3f828f7c3294ec,
+        0xb6be769161db00bc,
+        0x1629021db0561fd8,
+        0x0429f24ab6368c59,
+    ]),
+    element([
+        0xf64b9ecfd34043d1,
+        0x2fc3fc0c4dc47f3c,
+        0xba1ef6c63d856842,
+        0x245604875e07fea8,
+    ]),
+    element([
+        0x4c3c59d92d92daa1,
+        0xf8e56682a1ea70b4,
+        0x7e874843b4d6c5d9,
+        0x0ba442b6cb9a1465,
+    ]),
+    element([
+        0x07dedf84279a664f,
+        0x5ff982ca1bd85e64,
+        0x12a2181c0bf3ce1e,
+        0x0fbf1cef2655ac3e,
+    ]),
+    element([
+        0x2f861dca791a2c7d,
+        0x6da518328c0471e7,
+        0x70300f0275c7f284,
+        0x2c6fab806dc951a5,
+    ]),
+    element([
+        0x15cd3ecee12cfd08,
+        0x154f9503da54353a,
+        0x6457f41480c8abeb,
+        0x2b252f93905e0e0c,
+    ]),
+    element([
+        0x14b4f1ca5759ddd2,
+        0xd61549b823384ae8,
+        0xed792acca0df58f2,
+        0x0e4904a6200d3150,
+    ]),
+    element([
+        0xb67c1f5365cda5f9,
+        0x9bd4fd94608d3bd2,
+        0x4793844c1ad90e59,
+        0x1e0d012b54c68195,
+    ]),
+    element([
+        0x6be8138feb0efffe,
+        0x770764c9193a380f,
+        0xf69589725b640967,
+        0x29d7089e9aee2543,
+    ]),
+    element([
+        0x416aba643b3f30e0,
+        0x275e984e1333d1a3,
+        0x574b53417616dbff,
+        0x26f5c913cfde1b78,
+    ]),
+    element([
+        0xeecd13cc5a44b9e4,
+        0xce0eb790bfafc367,
+        0xda82b0c9ad75d7de,
+        0x04eb31f0b3835e3d,
+    ]),
+    element([
+        0x7c01a439fe023190,
+        0xa1ca945be03cbe7f,
+        0xf6db162b7d67d22d,
+        0x09f7a72362bff059,
+    ]),
+    element([
+        0x56b3621cacc6d452,
+        0xb31edadcfa9f9488,
+        0x26a41d4d6c93ace2,
+        0x11fe7c5c4e3b0a1b,
+    ]),
+    element([
+        0x24a3a8e2e0d57353,
+        0xed52997158e3ff49,
+        0xa11d58c4a18bca9c,
+        0x212554bea4e45cef,
+    ]),
+    element([
+        0x5b990b3a78435cc6,
+        0xfa4713fab582c313,
+        0xe238ba76ba63d060,
+        0x20dd6ccfe7b26aa8,
+    ]),
+    element([
+        0xde3a8577813b0395,
+        0x6979c96353b1dba2,
+        0xe299e7f03aa26f7d,
+        0x101899d05b47da82,
+    ]),
+    element([
+        0x1d562ab278f297a1,
+        0x007b8bc42600d86a,
+        0x42c400dca31eae30,
+        0x1d4f9752cf1cf68b,
+    ]),
+    element([
+        0x95eec1700b322b97,
+        0xc89d83a8296c3398,
+        0xa857cc8c796aba7a,
+        0x1b688e4d74c303a4,
+    ]),
+    element([
+        0x9f9036a1fcec3deb,
+        0x1300312b65396549,
+        0x1965452102251e18,
+        0x28b10e9d4e287c8a,
+    ]),
+    element([
+        0x7149abc3b63085e7,
+        0x83290f795ec17067,
+        0x7ad19d0bd5f5499d,
+        0x10595b0e04485566,
+    ]),
+    element([
+        0x30ff16de75943495,
+        0x83cdd575130e430e,
+        0x06e89ea06f523e4a,
+        0x1b47b12418900a26,
+    ]),
+    element([
+        0xbd97854b8e105ac9,
+        0xce457575ef84c416,
+        0xf7a77a1fb0885956,
+        0x1549fa43d67e9769,
+    ]),
+    element([
+        0xbdae0d14da5b4a9a,
+        0xf13e131061ce2bf7,
+        0xe60e9e21bb8c1131,
+        0x17eed94f96908100,
+    ]),
+    element([
+        0xd0e92155b4220b1b,
+        0x7faf7264bf3f00e6,
+        0xc2236cec48d3ead2,
+        0x076cc72a8012f460,
+    ]),
+    element([
+        0xdeec7b6a553ae79f,
+        0x0c15cab020e114f3,
+        0xc7eff0d4d2cb2fac,
+        0x16faee756f836556,
+    ]),
+    element([
+        0x27e06fee2ef64548,
+        0x6387f63fc60a1d3b,
+        0x81423f6207fc32c7,
+        0x0f9fa8048ee3affa,
+    ]),
+    element([
+        0xe5581e1e81a0c098,
+        0xd8b7ef823d949bae,
+        0x739f9766a9607e60,
+        0x12839c43c05e6255,
+    ]),
+    element([
+        0xcbd93f0717b55820,
+        0x6d286d3cac7e5270,
+        0x8b6029f452b98578,
+        0x11d38f2c069a328f,
+    ]),
+    element([
+        0x6e4b570d737e1bb2,
+        0x74d45e3e46ef2b76,
+        0x4d3f302436de3145,
+        0x2f62a7a517c69cc3,
+    ]),
+    element([
+        0x5b5b4622be2c9d57,
+        0xf90482468c889a5d,
+        0x3bb40976dcce25b4,
+        0x2b166f364b3a0575,
+    ]),
+    element([
+        0x4fe9194f23364242,
+        0x483291c6bbf62293,
+        0x973fdfd6d26e2dc2,
+        0x1ce60fd0daa83ff9,
+    ]),
+    element([
+        0x3aebeda39613caec,
+        0x7b458504a2b865cb,
+        0xa1ac9c3dfae194c5,
+        0x185edc3dc53dde23,
+    ]),
+    element([
+        0x7d2cdbdf15eca2ec,
+        0x5712851f46ad539b,
+        0xd7d13d904514589f,
+        0x002d576eecf6ab64,
+    ]),
+    element([
+        0x2ab0aa6f887e77a7,
+        0x9171a5e8f43fa790,
+        0x5939111e7d706d8d,
+        0x2fbc128e2c61d408,
+    ]),
+    element([
+        0x986f20580c8476d1,
+        0x7fe247732e6bdeba,
+        0x553f185a7538822f,
+        0x05c66befe457b27f,
+    ]),
+    element([
+        0xf79ee3b297d90df8,
+        0xe960b261d94b8505,
+        0x619514c7117666ec,
+        0x174baa8234dff371,
+    ]),
+    element([
+        0x38b54357129b689f,
+        0x46ef9218776d99a3,
+        0x7037ca6c666c56c4,
+        0x288852ec069ea946,
+    ]),
+    element([
+        0xf08afe539852cd2c,
+        0xb67cfa005d5a3be3,
+        0x5ff12e38bf19b3aa,
+        0x0ede89130ddd1d52,
+    ]),
+    element([
+        0x604757940ca91f35,
+        0x3b1ffcaeda7e7c37,
+        0xe8634276a6dd3094,
+        0x0b11c26d1d544f8d,
+    ]),
+    element([
+        0x16048176aa9d6235,
+        0xe46e5c7a8c9f5f31,
+        0x76500cef8ffed72b,
+        0x20c27fd8e19e131f,
+    ]),
+    element([
+        0x733ea7258fca21a5,
+        0x57eb7717d508f152,
+        0x319d9709418d22d0,
+        0x11ef69f5368195e1,
+    ]),
+    element([
+        0x4390a7f89c82cec9,
+        0x088cfd878e24697f,
+        0xf3bfa29834844cd2,
+        0x242619ad6af4f75a,
+    ]),
+    element([
+        0x0f010b413666d56c,
+        0x59275294a7415877,
+        0x4572e1507f34e149,
+        0x1911b78c90246578,
+    ]),
+    element([
+        0x947705d4f5ddf12a,
+        0x06c7f46efce9641b,
+        0x8676631e1373d668,
+        0x08177373f0a1e75e,
+    ]),
+    element([
+        0x7820827c188ed85d,
+        0x9f875ac4d9b84358,
+        0x90b3249b4f89e71a,
+        0x15b2a629eb76b48b,
+    ]),
+    element([
+        0xd16a3678e4afd2b5,
+        0x36a60a3d44335e13,
+        0x7cfc8cb44137b7c6,
+        0x033e70350c131bce,
+    ]),
+    element([
+        0x1e76a316a2a78f42,
+        0xcc7263480c05fdc0,
+        0xa10c11564b7fbcc2,
+        0x088d6a9e65a1664e,
+    ]),
+    element([
+        0x7b8981cf0d3cd3f8,
+        0xb59483de54252462,
+        0x9e089d51240f0047,
+        0x24ac5f06c7a0b476,
+    ]),
+    element([
+        0x68163524bdfea264,
+        0xcf4e4f2c67c4d94e,
+        0xb61a3b998cfa741f,
+        0x077d3e73829d3931,
+    ]),
+    element([
+        0x87934af581102bf2,
+        0x444a20f8383207b7,
+        0xbd0e5b7a7018ef8b,
+        0x18dcc06d43970a54,
+    ]),
+    element([
+        0xca2b3f68dcf2ee59,
+        0x9a68eff52d393ad3,
+        0xd723f1b4139aa65f,
+        0x27db44d03cf8e9a5,
+    ]),
+    element([
+        0x764c9e869e519575,
+        0x4f418f922d36afe2,
+        0x645ebfb7368c820c,
+        0x047ed55ff2c983cf,
+    ]),
+    element([
+        0x41afef497ef6514f,
+        0x478a1912ebd562e9,
+        0x334344dabded8cff,
+        0x147c04b4227adf5e,
+    ]),
+    element([
+        0xfbd387ee00bd6cc2,
+        0x9d07053cc3de48d4,
+        0xd23039d4d52a8e9a,
+        0x15dbcecb2c144724,
+    ]),
+    element([
+        0xdb81c8e06b335f86,
+        0xa284dfdeccfb22c5,
+        0x9de9c469e53bdfd1,
+        0x2bf48a937baef06b,
+    ]),
+    element([
+        0xbf95478a29efc971,
+        0x670d351c36fbaee9,
+        0x37329324d4840f09,
+        0x1234481a61eb4858,
+    ]),
+    element([
+        0xc4577f87627cf61e,
+        0xf1157e18143a8621,
+        0xbb8f397768d09bb6,
+        0x0e769021e303c5e0,
+    ]),
+    element([
+        0xe7150bd878939bb0,
+        0xa4735cc29fbb9b15,
+        0xb7f29c2d553c4365,
+        0x29294870b8b34bac,
+    ]),
+    element([
+        0xce592cf7b98b2952,
+        0xe9823508d93d2912,
+        0x7717838a4f0500e8,
+        0x282ce786572c9d6e,
+    ]),
+    element([
+        0xfe6f79e3ee302d70,
+        0x1fb2919ccf5dcd8d,
+        0xda42ffad89905fcd,
+        0x09ff01bf59698d1e,
+    ]),
+    element([
+        0x1a2ca9a20742ac7a,
+        0x2ae95a024eb781e7,
+        0xfa2eaef17f684fd0,
+        0x07964d47d1ae454d,
+    ]),
+    element([
+        0x08c6a8a2f7764e34,
+        0x9680c14ba8ac2de2,
+        0x610f524c37cdcf77,
+        0x1e1d49965d460fb2,
+    ]),
+    element([
+        0x53a861fdcb048086,
+        0x7c4fa5ba2d1530f7,
+        0x0b169014001269ed,
+        0x0b12c70e7764e6a8,
+    ]),
+    element([
+        0xfd3b5497a8429d17,
+        0x9f802f350de89dea,
+        0x62558f0aa9e0a67e,
+        0x1c3884204fc6f0ef,
+    ]),
+    element([
+        0x40e33156bcefa252,
+        0x12a2ec3b3f6564e5,
+        0x776d83ed018efb2b,
+        0x244ea3dd00a729d4,
+    ]),
+    element([
+        0x50835fc5256b8f8e,
+        0x1a9748e6e5c78300,
+        0x0a3b173e05f648c1,
+        0x00a6ddea5e1499f7,
+    ]),
+    element([
+        0x7c5dc46a6b73dac0,
+        0x4850e1ad8d766095,
+        0x5045fd27c544707b,
+        0x063e40905bebfa6d,
+    ]),
+    element([
+        0xc6415590d7d531ad,
+        0x24b311b1dece1abe,
+        0xe66c159047303caa,
+        0x2ee9ea7a0864533e,
+    ]),
+    element([
+        0x2c40cf520d8af326,
+        0x19150137e9351cac,
+        0xfc57d63754290983,
+        0x021179c75ef57d89,
+    ]),
+    element([
+        0x9d70def68798a20f,
+        0xd33a8981801f0466,
+        0xb6bd9630d46ae222,
+        0x1d2886417214aa26,
+    ]),
+    element([
+        0xfda07773e07f684f,
+        0x8b47ddda658bd8d6,
+        0x14dedaeb4830f3b1,
+        0x0b176ab6b7bbf0c8,
+    ]),
+    element([
+        0xdff66eabb5ea0ca0,
+        0x47efe94a8b70dc0e,
+        0x9e7c4e6680822997,
+        0x13989fa2b5a2e339,
+    ]),
+    element([
+        0x01dec2bad3ab1345,
+        0x76b7c26a054b6119,
+        0x3cc6af42b96cee8f,
+        0x1557942661b37032,
+    ]),
+    element([
+        0xa7e33c3406ae5344,
+        0x51cc1afae732d140,
+        0x08d31a10064085e4,
+        0x2a803e64020f8d02,
+    ]),
+    element([
+        0x31307ba1f27e5bc6,
+        0x74c3e1a15d3dc838,
+        0xc7b73a59664d4d15,
+        0x0110528aee178d34,
+    ]),
+    element([
+        0x3a514feaec89a787,
+        0x1732f6fc0d12698f,
+        0xd9f142864d3f69a0,
+        0x08f865588621f694,
+    ]),
+    element([
+        0x42a9ae78c138a555,
+        0x2d51262363b17bcf,
+        0x48ea38d2cffd05b2,
+        0x2cc5e436d1f62934,
+    ]),
+    element([
+        0x8468cb04146d4a5f,
+        0x255fb0260d40ebc2,
+        0x8cc301469390d7b9,
+        0x0017646c86a9f51d,
+    ]),
+    element([
+        0x2411419d26029cdd,
+        0xec81c3fe110052e6,
+        0xb42db88c5e98128d,
+        0x20f6399a8d8088a2,
+    ]),
+    element([
+        0x604b3894fb9a010a,
+        0x16ee8233cac8cb12,
+        0xac0790f0f314f4ee,
+        0x178410e00446f9a3,
+    ]),
+    element([
+        0x80df70c2cbd1e4ba,
+        0xcfc2cf625d3ce5dc,
+        0x1fbdc3d40c0af65c,
+        0x0256ede825eed48c,
+    ]),
+    element([
+        0x527030469c8d7b70,
+        0x1800e55f2e2c8bcf,
+        0xa6024263a3e0b56a,
+        0x1de84f0822c81452,
+    ]),
+    element([
+        0xe37901ee25dd7614,
+        0xe045527bc95bcbf1,
+        0x5d78647c5c4de338,
+        0x2ea3316834c13af1,
+    ]),
+    element([
+        0x559a9737486bbae3,
+        0x52ab7a5b0b7b0162,
+        0x806a1335ec92a70b,
+        0x1fa03cbee50ff75f,
+    ]),
+    element([
+        0x9adea13dc9343157,
+        0x35bdfbb29906e08c,
+        0xadda2db9fa242283,
+        0x21bf2180020e333e,
+    ]),
+    element([
+        0x092a39e82a570d08,
+        0x245bc9dba8b415f1,
+        0x71168cfd4badad82,
+        0x0092594a8263a814,
+    ]),
+    element([
+        0xa7b2860a593673fd,
+        0xede58f5d64895d83,
+        0x152728dc6f8e474c,
+        0x197e6840879e5663,
+    ]),
+    element([
+        0x5509daf5e071f7c8,
+        0x6bc99942a3c74220,
+        0x012878ecba6036ca,
+        0x0c12ff1a02b42794,
+    ]),
+    element([
+        0x6414253322ea75e7,
+        0x6998234429e89dec,
+        0xe17c425cd26b6213,
+        0x0b5ddc04cba37eae,
+    ]),
+    element([
+        0xc3525d6ac95919cf,
+        0x5464da738a74f28d,
+        0xfb4dec7fceb3d6d3,
+        0x107f868fcaf5df0d,
+    ]),
+    element([
+        0x60f660edca989224,
+        0xdb24d43d568c4194,
+        0x6492f2785a5caaa6,
+        0x2273308c4c52b56f,
+    ]),
+    element([
+        0x39edebdbafbc65ea,
+        0x2ebf766fec57b92a,
+        0xf26e698464a1c98c,
+        0x23f73098fb86739c,
+    ]),
+    element([
+        0x1ce807ec73794ec3,
+        0xb194d83c58ccbd27,
+        0xd13c48fa76f4ce3c,
+        0x2458e9ea4e723041,
+    ]),
+    element([
+        0xd511c0f3f1ea4cca,
+        0x3026be5f1810c2f5,
+        0x16412572512515c0,
+        0x14c834e564aaa85c,
+    ]),
+    element([
+        0xbf3e225d35a77284,
+        0x26dcc4c6570fb75d,
+        0xfe7d1b47fb8d6ac6,
+        0x122b969e6a9af79e,
+    ]),
+    element([
+        0xa84d6408e21af33d,
+        0xa5a1918517984b25,
+        0xed0cdadeb0441c87,
+        0x223c629756960ec6,
+    ]),
+    element([
+        0xbc7ad57bb55b1b28,
+        0xd23fcef8a4c86e85,
+        0xbbd90d010a6d37ae,
+        0x1387a872136e2b4d,
+    ]),
+    element([
+        0x3f0d3a7ac628aef0,
+        0xd77e5421c95edbf4,
+        0x42a9b04af91efc20,
+        0x07dce9631f93fd88,
+    ]),
+    element([
+        0x304e288626afcfed,
+        0xe5c5017b76f8c80f,
+        0x61e21c2b737341a5,
+        0x13fc5777a5da5010,
+    ]),
+    element([
+        0xbf1d5300b8885862,
+        0x7c75e431d1a98931,
+        0x81b178eeb8df6806,
+        0x086e15a7dfc7d55b,
+    ]),
+    element([
+        0xa2e658ec0cdcdb6b,
+        0xdda4b198937a1de6,
+        0x574e3d6b1881cef5,
+        0x2b6d3e7545b7b803,
+    ]),
+    element([
+        0x2b8bda1c144da7dd,
+        0x56241f4aaa9062d3,
+        0xeedeba293263434c,
+        0x2a1eaae5897881e3,
+    ]),
+    element([
+        0x4d5af885b2e03c79,
+        0xac98caa008afba8e,
+        0x51cfef2c567b7bc6,
+        0x1238f1ab902968de,
+    ]),
+    element([
+        0xda6f524ac10aa2c0,
+        0x40dcce9b85171d8a,
+        0x8ad92a1fcb9f218e,
+        0x1e1323cc34099ebf,
+    ]),
+    element([
+        0xcbce5703c851eff3,
+        0x327c8bc3b0fdf015,
+        0xbae0efc13ea012f3,
+        0x12c9c03be1e57ec3,
+    ]),
+    element([
+        0x8c98b8e88c989a55,
+        0xb22068452b7b04d7,
+        0xaca74a6f151c934b,
+        0x1a01429f53a9d3bc,
+    ]),
+    element([
+        0xfa6616010eb9772d,
+        0x7295ed989227e9dd,
+        0x0efc54e4c3f882d0,
+        0x235ed12cacbb17f4,
+    ]),
+    element([
+        0x7e76d00b222a46a4,
+        0x3bdd0823d52c077b,
+        0xe55d960423b2a83f,
+        0x1e731af42f7589ee,
+    ]),
+    element([
+        0x1dc87dec2c4941f0,
+        0x6a525b8f036bd04e,
+        0x934ec7077eef0d4f,
+        0x27b2c0afee7e73eb,
+    ]),
+    element([
+        0xbbbf17fc538bdebd,
+        0x12c918f965d39af7,
+        0x6de06fe3f3ab9e78,
+        0x09214b5d4d442809,
+    ]),
+    element([
+        0x9e2a1bbe38cb03c8,
+        0x410bbf9ebc06e7aa,
+        0x9abb8acdc5ddcf79,
+        0x159f5db983d7d3fe,
+    ]),
+    element([
+        0x185300d8a55b501e,
+        0x2b8963f9534c19b9,
+        0x8907671390e065c0,
+        0x1a26473d071481fb,
+    ]),
+    element([
+        0x66565132eac1ba65,
+        0xca0a94db73b622eb,
+        0x4896e4525ecedd16,
+        0x0976eec7ac88ffed,
+    ]),
+    element([
+        0xf818bff5e1f47216,
+        0xff00e9fdf21c86de,
+        0x251e011581ff0b53,
+        0x1ef61ae0993bea6d,
+    ]),
+    element([
+        0x452b4428d35358e2,
+        0xfbf37cdf43fbb321,
+        0xf655186a7a1a556a,
+        0x038321036b5dfd03,
+    ]),
+    element([
+        0xd8d17002f3c06ece,
+        0xdaa1a73d1e21d72f,
+        0x0e60d6524510d2da,
+        0x0926069bf967d2b1,
+    ]),
+    element([
+        0x02025c5cba136945,
+        0x4990ee98ef523947,
+        0x36d9d3c3be3288c0,
+        0x26cc9b91bfe56706,
+    ]),
+    element([
+        0xe09f992a697d4e84,
+        0x6c41102a06180116,
+        0xac52df8b15912b69,
+        0x2cc8173afb3c2ea5,
+    ]),
+    element([
+        0x3e7fbdb09c38cbdf,
+        0xbdd69fe70fb1c8b4,
+        0x64c70aeffcfc9297,
+        0x0d7278b7fb1a3125,
+    ]),
+    element([
+        0x9745d45d4b94880e,
+        0x9d3dfadd5f0b4d37,
+        0x6ffdc82c390b18c9,
+        0x00d2a8e1e5af7275,
+    ]),
+    element([
+        0xd202d255ac817569,
+        0xfbb96f9c603078ee,
+        0x1d8c2a62e69c477a,
+        0x207a5f87e89d415d,
+    ]),
+    element([
+        0xe174215abb4e4c28,
+        0x44e71e9b4fa6f5fd,
+        0xc02b58fd6f4fcfa0,
+        0x13c6fb06c9ef74ef,
+    ]),
+    element([
+        0xb3da917531f72052,
+        0xeb88ec4a3e030581,
+        0x6cd132660bd5d39e,
+        0x2bbc2c7575aaa8f5,
+    ]),
+    element([
+        0xdbd36963e2ce435c,
+        0x7579875c8d085678,
+        0xb0d1ccadd912aae6,
+        0x2d9e62653787fa37,
+    ]),
+    element([
+        0xe4f276c2f0da2433,
+        0x1f52f097f0e93b7d,
+        0xc364ee7887f1de70,
+        0x09377f1fd7e3279b,
+    ]),
+    element([
+        0x345276ad27f64c39,
+        0x4d624bdfdda14137,
+        0x853515a44c6612e6,
+        0x2aa50c6740e48dc4,
+    ]),
+    element([
+        0x85c9acfd829ecdb6,
+        0x02ce271a9922e310,
+        0x2af857ed713e2779,
+        0x0cde252e798c70b1,
+    ]),
+    element([
+        0xd96f1969122e11e9,
+        0xaae5fb72e53ae6b1,
+        0x3f7fa8ee7107b1b9,
+        0x121d9a02d30947ac,
+    ]),
+    element([
+        0xf55eb273c9c7e082,
+        0x02bdb85457dbcef8,
+        0x9b9211e4d41da53b,
+        0x14bddb091274a737,
+    ]),
+    element([
+        0xec552380abda7395,
+        0x5da8f228ad4962e8,
+        0xc6a07e7cc7cd8f43,
+        0x2c4afbc3e056e659,
+    ]),
+    element([
+        0xf1b82fa824a36e7a,
+        0x1c924f131eb36a73,
+        0x8cfc83c245930c25,
+        0x1871dc10fd7e8fc7,
+    ]),
+    element([
+        0x45bdff34765487b1,
+        0x28e5f0070292ddfa,
+        0xa4d5b1c44f7242a6,
+        0x1e8e742129491f48,
+    ]),
+    element([
+        0xfb36d20fadea1f33,
+        0xa7d4c086b2e98b9d,
+        0xf3825d1840b8eaec,
+        0x04e5b4de910b8447,
+    ]),
+    element([
+        0x4d172f5c11d0e490,
+        0xa2cb0671a48462bf,
+        0xdc72909170f278fd,
+        0x2cbffbabf7e44f03,
+    ]),
+    element([
+        0x1502dfe264327da7,
+        0x53aca5d2f22859c0,
+        0x248d22824247a00c,
+        0x25d63bcfc1ad8788,
+    ]),
+    element([
+        0x8ead3a9eafcd8df7,
+        0x16956df2affabca5,
+        0x87478c27171d0121,
+        0x06bd33f6b5cdf0ac,
+    ]),
+    element([
+        0xd8ec393d6736d37c,
+        0x408096227e336a36,
+        0xbd3a1c0b4aeab51e,
+        0x197b810f530cdcaf,
+    ]),
+    element([
+        0x06987d66a331573c,
+        0x1b11680bfd7a1c43,
+        0x13a4543ee9f7434a,
+        0x01834a01628c1253,
+    ]),
+    element([
+        0xf8ea895e7c0f19dd,
+        0xbb18c14b2ec90756,
+        0x1ef226cdd907018a,
+        0x2889bf2a7d4edb28,
+    ]),
+    element([
+        0x35451f5991f97fe6,
+        0x35551efab78c5c6e,
+        0xa35d1fecd1041984,
+        0x1e30f0a4f8acf585,
+    ]),
+    element([
+        0x52bf1ce13cf2a493,
+        0x52a6eaf1ef00bd9c,
+        0xeed470d3d7ad73ba,
+        0x23f3fc7927bf3d71,
+    ]),
+    element([
+        0x732f7a6adc5dfd3e,
+        0x6239478447b011d7,
+        0x5336bafbba55490d,
+        0x30519b8415e9afce,
+    ]),
+    element([
+        0x87ce8610479e2699,
+        0x939937f93e82ee76,
+        0xa170823d968fb910,
+        0x015454cbc17286f6,
+    ]),
+    element([
+        0xc864b8f2098efef4,
+        0x5b76e017315609d2,
+        0xad81c7f306d93d4f,
+        0x0ff0bf3afb00b81d,
+    ]),
+    element([
+        0x7bc5118b11161cfc,
+        0xf4d1ef27b4676aeb,
+        0xd66f12cc5e57ec82,
+        0x30095bfe63db41af,
+    ]),
+    element([
+        0xb8c9e38fe93a96e4,
+        0x8bb016afda9924b6,
+        0xfeffe900412ab0bf,
+        0x25bceaa118d9bef6,
+    ]),
+    element([
+        0x50267848a08ee693,
+        0xa87c6b6a2dc01751,
+        0x4f74d6abc70cde09,
+        0x15d71de64ba77b96,
+    ]),
+    element([
+        0x66fa4b6daf08327c,
+        0xa5eb3ad8e46cd54a,
+        0x036ee5f94b74c1f0,
+        0x0ae5ee6a94d7ad94,
+    ]),
+    element([
+        0xbd76e8f385682e0b,
+        0xafe1530322ad0cf5,
+        0x4a59950a4aa32c84,
+        0x2df3403043b5b54c,
+    ]),
+    element([
+        0x329d7e2e67260450,
+        0x877b421bf385648f,
+        0x6c14a42f160afc84,
+        0x0ce03be4be7eae85,
+    ]),
+    element([
+        0x04254d4150b5cf6b,
+        0xbbe8e4c9e71b3cd2,
+        0x1965d119be54f99b,
+        0x2aa9f3713a957f48,
+    ]),
+    element([
+        0x3e53bb051147320f,
+        0xf81901817be0225a,
+        0x4d9d48a6b09e38a1,
+        0x23335f230a3182ae,
+    ]),
+    element([
+        0x0a1f8df0042298d6,
+        0xfafe7bd3d25ed75f,
+        0x1aa7d5e05d821633,
+        0x174592390f8620bc,
+    ]),
+    element([
+        0xbde81d95d8f37d23,
+        0xf7e3f21ea73f4020,
+        0xb08a3872f3a5d84a,
+        0x14e90007cf98796f,
+    ]),
+    element([
+        0xb31f81c3d9e9ee63,
+        0xcbe52b4a0d5c063c,
+        0x757a9cf29fb2e7a4,
+        0x27821e0d2b88bf9a,
+    ]),
+    element([
+        0x9343fa31b1ebd6b2,
+        0x54644f599e14abcc,
+        0x0238af688208eeab,
+        0x2e28555a2dfc3f00,
+    ]),
+    element([
+        0xc099acd9053b6e0f,
+        0x6b701c6eebd8c2cc,
+        0x926a7ea10bc30620,
+        0x0d51cbc884e22247,
+    ]),
+    element([
+        0xdeda6b5e5c0411a3,
+        0x769cff58f70bafc3,
+        0xdf67655f722c0f42,
+        0x21ef522947706e72,
+    ]),
+    element([
+        0xab11ec90ecb1778e,
+        0x589a5002ee719702,
+        0xd6654e131754aacf,
+        0x19ac099aaef0d2d0,
+    ]),
+    element([
+        0xa1e8963534e7927f,
+        0xcee38a9f8a0e68a6,
+        0xc8032726af688921,
+        0x1d1e8cffebb78ca2,
+    ]),
+    element([
+        0xf0a94f1cb41d4f57,
+        0x50d9df5eaae9e06d,
+        0x4035dae65cc25e3b,
+        0x2a9497ea9cd2ea74,
+    ]),
+    element([
+        0x627d205e1ff11535,
+        0x88cd99084e734512,
+        0x8cd837f12e467ef4,
+        0x05dc6bb2d1ac267a,
+    ]),
+    element([
+        0xc726093e039b748d,
+        0xe06d85588cb9ab50,
+        0x1712077dbb9b0fed,
+        0x12dd30cfcae2938e,
+    ]),
+    element([
+        0x9696b066307758d4,
+        0x2f4590b4c7467ea7,
+        0x2c858117005065f5,
+        0x1e55030a2b2b8ee8,
+    ]),
+    element([
+        0xa40e1bee45719e5f,
+        0x51df4620ba3aaad8,
+        0x792971d474cbe453,
+        0x19ea3141339ed004,
+    ]),
+    element([
+        0x522625bdfcdfa42e,
+        0xda2a92472a6961dc,
+        0x3c10e83971a41fbe,
+        0x19be909dc9d91fcf,
+    ]),
+    element([
+        0x27cf9d7ce9422ed5,
+        0xf5abc5f7da49cd6b,
+        0xa0f1ba9e6707a5c2,
+        0x0b6d8dc78b027cd6,
+    ]),
+    element([
+        0xf7a0c7e8b14fb0ac,
+        0x117985b131707dfb,
+        0x5e44c7349072905c,
+        0x222f015f6eae085a,
+    ]),
+    element([
+        0x99ed018197fbd5f2,
+        0x23a4634e5be4b55e,
+        0x35c6a012f21f549c,
+        0x024ead0c05041609,
+    ]),
+    element([
+        0x20635b0a808fa227,
+        0x150d535ad59a8762,
+        0x0d41783358d4d83a,
+        0x19fc852f00be6050,
+    ]),
+    element([
+        0x20576ec4c4656a93,
+        0x4f083951fedf0216,
+        0xfc1ac98bee475be0,
+        0x0372393cb8d0b968,
+    ]),
+    element([
+        0xfa37c00f07b7e700,
+        0x2868ac7c9eca8d07,
+        0x83438cde8501ede4,
+        0x1cb297f85ecde0d6,
+    ]),
+    element([
+        0x34213d46db04213a,
+        0xc0a6305a1c4d762b,
+        0x34a9561cda54cecb,
+        0x15b2d1f920d71dc0,
+    ]),
+    element([
+        0x8d5ddb38358ee956,
+        0x97d22a38749ab8c5,
+        0xa78153a39af89dd0,
+        0x1a879641b2e2f47e,
+    ]),
+    element([
+        0x537d09c0c66b4816,
+        0x80905ebef4ef0aa9,
+        0xb695bc19938c7a36,
+        0x18c72fafa972dfb9,
+    ]),
+    element([
+        0xc84126c73f24dcff,
+        0xadba6bf2b4ab0d40,
+        0xfba3dca0513e1920,
+        0x10fc926182eb88ac,
+    ]),
+    element([
+        0x9dcca6be88c8d92d,
+        0xf6a3244aa1334add,
+        0x781bb48d3abad332,
+        0x27bc33552c7f1f6d,
+    ]),
+    element([
+        0x301c085784ea9042,
+        0xb79dea2e22ffaf7d,
+        0x3b9a63d1acbb7784,
+        0x0e2c50e19fe3b19e,
+    ]),
+    element([
+        0x2a743249b74784e7,
+        0x97d27fa7a56b26f9,
+        0x24f8ec648d94c4af,
+        0x0b97bbbcf7440941,
+    ]),
+    element([
+        0x512fa0ac50baa0bd,
+        0xde1ef54efb7ae4f0,
+        0xeeaee4d09181ad0c,
+        0x302bde5099a79a20,
+    ]),
+    element([
+        0x69890156dce432d5,
+        0x5aaf2a4ccfc3fa47,
+        0x0eab2b51975834f7,
+        0x0136238f3a7a010d,
+    ]),
+    element([
+        0xf5e95a78ed0b1828,
+        0x081e7f026c0313ac,
+        0x347675fdb823d58d,
+        0x10dd62dafcb1f7d3,
+    ]),
+    element([
+        0xbf0384e3cc9695ac,
+        0x49cd507b1176c3da,
+        0xc8c56ded9051e857,
+        0x043d6ad9eac9eaa7,
+    ]),
+    element([
+        0x85718ce1e87fddbd,
+        0x05d7074457f5960c,
+        0xb372127535101c7e,
+        0x23517b87cf35b3d1,
+    ]),
+    element([
+        0x8e78f89362d43647,
+        0xfccc0276006a70c3,
+        0x690c94689ee4d91e,
+        0x00e376b5c4bb7252,
+    ]),
+    element([
+        0xb40c49c2be549076,
+        0x9d5236b1e717c476,
+        0xb6d0b02ac6465c61,
+        0x0f667dbd2d1b265e,
+    ]),
+    element([
+        0xaaa6636e162a3d3c,
+        0x8241ef1063eea61b,
+        0xabfbe6a5a4ee5b34,
+        0x096335cf4e73ae5d,
+    ]),
+    element([
+        0xada76ec54857f192,
+        0xb892d73e4dcbdc54,
+        0x266964ea1ff72d78,
+        0x0bf9c5153cc283de,
+    ]),
+    element([
+        0xf477f1d0b49fd3e4,
+        0x2bcb5eab4c76ec40,
+        0x6d56987eb4fba12c,
+        0x2676d9e5a7de34c3,
+    ]),
+    element([
+        0xe192ce9901f216aa,
+        0x6676af0a4c5287f5,
+        0x57121c55b3f75032,
+        0x085f7936c18fdc83,
+    ]),
+    element([
+        0x0a8d8016d0c5c234,
+        0xa21e216031c299e8,
+        0xff5e42989d1b693c,
+        0x008a42e48f09e4bd,
+    ]),
+    element([
+        0x6e69d04387bde07d,
+        0xa158ad9ca77162a2,
+        0x5f251d7873665350,
+        0x1bb58bb2f5335e21,
+    ]),
+    element([
+        0x2139a9645c2b72dd,
+        0xd8cf7c5be734cc71,
+        0x86fe4da4a9076942,
+        0x2a2c5bdd4258327f,
+    ]),
+    element([
+        0x5bc3b1f2c317dc2f,
+        0xa8b34472e5083d70,
+        0xa86da52a2ca3a2d3,
+        0x1eb2eec1deaed0a3,
+    ]),
+    element([
+        0xbb1262b92fc7f102,
+        0x053e1dc51dfa17f2,
+        0xcf916ad019c63c52,
+        0x2445b383268a9343,
+    ]),
+    element([
+        0x12421e47bc1e4370,
+        0xece2f8d1fb381920,
+        0x3a6913fe6c751039,
+        0x037760e2a06e6929,
+    ]),
+    element([
+        0x145b89f2aafd35bb,
+        0x58c671daf8f7d309,
+        0xb79f850f48aea095,
+        0x15c65fb83b6e3aa8,
+    ]),
+    element([
+        0x4ffc44c74a728d05,
+        0x345cc8ea115626d5,
+        0x649b416f5f709d5d,
+        0x167c71b1df7e148e,
+    ]),
+    element([
+        0x0f0145ca6012aff9,
+        0xb623a49f64dad5bb,
+        0xe05b8882c82ece0b,
+        0x1a506720e8c2adfa,
+    ]),
+    element([
+        0xee80c9b4982de9d9,
+        0x48a947c577cf394c,
+        0xc7d83f7ce17a14b3,
+        0x2ad151e82b1ae5da,
+    ]),
+    element([
+        0x1ddaefe4327b866c,
+        0x40d22d7aac2ef71a,
+        0x1d281a967e208580,
+        0x2163a54d12d6c8eb,
+    ]),
+    element([
+        0x690b0c0fe05b5e18,
+        0x53bc59d4f4ea3c59,
+        0x8c840fa98c1224c6,
+        0x0fa5b44de4fe2494,
+    ]),
+    element([
+        0x670d2636c2e4f594,
+        0x9ac11264032f6032,
+        0xb6b5165454510080,
+        0x09aeb2e9e43ad361,
+    ]),
+    element([
+        0x0fc4db5d8001674d,
+        0x5357418c3f81221b,
+        0xff1d85a41fdaab1c,
+        0x199652ade5a97947,
+    ]),
+    element([
+        0x6feda32643462bca,
+        0xfa8c748794514287,
+        0x3f038115f4aeb776,
+        0x1771918cbc267bdc,
+    ]),
+    element([
+        0x47a6947c41f78e31,
+        0x272b97b31e14b7bf,
+        0x107e961319a0fdb5,
+        0x1cdb3c7a53751446,
+    ]),
+    element([
+        0x2d7f38a0db050874,
+        0xda95cd1c1cad29c4,
+        0xf54887cdf68e3413,
+        0x09d1ae0cc3ff8184,
+    ]),
+    element([
+        0x068ba2e415c43448,
+        0x9337227d930922c9,
+        0x526371c574c51716,
+        0x165609a20c332b0f,
+    ]),
+    element([
+        0xd559787e3ee31bb7,
+        0x27d15b5e600b40f9,
+        0xfa074325af8474d2,
+        0x2807e3c53c798e8f,
+    ]),
+    element([
+        0x1a0db9dfb652b7ba,
+        0x44238355e6a051fa,
+        0x271146b63dee11e2,
+        0x16b92f5108076bb8,
+    ]),
+    element([
+        0xb7f2693b66bd1eec,
+        0x5a34096c2c19276d,
+        0xcbd6aab77ca0ed7c,
+        0x25f93eab431ea855,
+    ]),
+    element([
+        0xee3c420f5e1b14ca,
+        0x6f51c12eb08ee204,
+        0x6d087503f4629166,
+        0x05949280f9e1624e,
+    ]),
+    element([
+        0x153e8ae2891fa14c,
+        0xc32a2463d7441219,
+        0xa5d178f741e0c71e,
+        0x2eb764cf9c91c31b,
+    ]),
+    element([
+        0x57e2d28f68c0e1a7,
+        0xe07d8c832481c224,
+        0xf78506cc18c214ae,
+        0x048a3b235b9c8fcf,
+    ]),
+    element([
+        0xfb5f3e94358f8c83,
+        0xf3a21d6cfd6ef160,
+        0x738bcae1b94c2b5a,
+        0x0f06d91dae2c3ae6,
+    ]),
+    element([
+        0xdba30e0fbdb777f6,
+        0x4e5742aa3c3676db,
+        0xa7d1b22d9ac4a055,
+        0x20d7d899936ee832,
+    ]),
+    element([
+        0x3d04d95f35869898,
+        0x42dfc35d0246c7cf,
+        0x5c0282a3de8e9ef3,
+        0x0122bd0fb211099f,
+    ]),
+    element([
+        0x69df9fb12a150b61,
+        0x2f851570a71b6c6f,
+        0x3916fcfc4843976f,
+        0x217ddfe2d3b26191,
+    ]),
+    element([
+        0x70479fa2fd82f88e,
+        0xe3758cb2ca7741f2,
+        0x0eb03cc1f3eded5b,
+        0x244ea07a69c572ab,
+    ]),
+    element([
+        0x5d3ee88bd5e2b3dc,
+        0x30f236384c1f0747,
+        0xa24f05abdaf628c1,
+        0x0e34191bb56c1dde,
+    ]),
+    element([
+        0xa2947b111dbf7e01,
+        0x14a509b1408a9efc,
+        0x6260cd81cc1600af,
+        0x0953a9e4329cb455,
+    ]),
+    element([
+        0xb35b5afb6082a0ba,
+        0x7067f6bcc342b8b0,
+        0x2cb0c7ffebd4732e,
+        0x1343d7affcff1b6a,
+    ]),
+    element([
+        0xfa9d0ecaf9862e0d,
+        0xf66d43c1d2947c04,
+        0x31f483caaf18c78a,
+        0x2cd6b6346ab0e692,
+    ]),
+    element([
+        0xb6075c5c0ec770b9,
+        0xa7445598572589cd,
+        0xf236a3255fcd4217,
+        0x24d20437769794b5,
+    ]),
+    element([
+        0x822a482e512bb691,
+        0x2d16e377d22d7ea0,
+        0x912c171a06472efa,
+        0x28899b65fb4f9544,
+    ]),
+    element([
+        0x49616fb038b2f078,
+        0xde03e32715b264b4,
+        0x1aa6bccf39e19930,
+        0x2543a9571e5eb142,
+    ]),
+    element([
+        0x0c07801f53a945ae,
+        0xe4139aeac6eb909e,
+        0x2f4b9ea99538b02b,
+        0x12821b857c9e7b8e,
+    ]),
+    element([
+        0xc087d58cef5b64e5,
+        0xaf308b822e19ae7f,
+        0x41a05f7708d76156,
+        0x162bbbe1e93e88e8,
+    ]),
+    element([
+        0x327385b7b1b15e7b,
+        0x7a7c6ab60816c5f0,
+        0x7f0b9aba9744c77c,
+        0x1491101a74804be5,
+    ]),
+    element([
+        0x0c791adb9e56bf91,
+        0x0f1609fe4b54c683,
+        0x25275f6644e5f946,
+        0x2a17ce4206f5b308,
+    ]),
+    element([
+        0x6d486c43df57f6cb,
+        0x4a0c81761cc39ff9,
+        0x81dd7c6dbb339fc4,
+        0x2fe92b3e60181de0,
+    ]),
+    element([
+        0x74b67ddd0c52325c,
+        0xe4efcaf13a4f725f,
+        0xedcea38f6ed8dc82,
+        0x2ccef7153685988d,
+    ]),
+    element([
+        0x312ab71a35ec6d05,
+        0x7cd5cc12a9c67b94,
+        0x6d8ea7dc06a18249,
+        0x25f58b01b3ccca8f,
+    ]),
+    element([
+        0xeeca9b0caec74db5,
+        0x804ed5a3c8464415,
+        0x57564a630008f5b5,
+        0x2282c319e1f3175f,
+    ]),
+    element([
+        0x610f27139c038aaa,
+        0x3a7d91c26292af0c,
+        0x71dc50c42083987f,
+        0x181ef8d3c0b97ff5,
+    ]),
+    element([
+        0xd8758c2e1158ead2,
+        0x57056387bc55df03,
+        0x844ccffe266240cb,
+        0x18162d9074744f99,
+    ]),
+    element([
+        0x4307a380e5eaef21,
+        0x3454dd3880db1e07,
+        0xc1574db01ce9cdbe,
+        0x253b480d2e316569,
+    ]),
+    element([
+        0x9f97a9312b200a62,
+        0xdcf4d4c848a9e4ed,
+        0xbcade72154de7b91,
+        0x295d3febfb03debc,
+    ]),
+    element([
+        0xd56828127a7a3caf,
+        0x6126389f9ec53276,
+        0xf72127261e1ede6d,
+        0x1432b269d74d9f2f,
+    ]),
+    element([
+        0x8d5b9c7e240fef45,
+        0x874197738cdecd03,
+        0x1ea1c79c9fbbe7fe,
+        0x2237915ce54bdbf1,
+    ]),
+    element([
+        0x24b945e77e08b78b,
+        0x85feae615be364b7,
+        0x08081af219c44daf,
+        0x18a2ecfc5e937bc0,
+    ]),
+    element([
+        0x6394d1471b805c82,
+        0xe7d8088adc89083d,
+        0x66a1eac73d021ab7,
+        0x162d3f2678a2ccd5,
+    ]),
+    element([
+        0xeb27f9cfb94a79f1,
+        0x5dd496baa26264fe,
+        0x835e787f94730bf7,
+        0x1ff7021f36b60398,
+    ]),
+    element([
+        0x3e34b23376f011e2,
+        0x93cd8512c8dd6386,
+        0xa8ff945134853582,
+        0x27f6deefd9ece910,
+    ]),
+    element([
+        0x9d4b34cb953aeb3f,
+        0x40fcfc50ddcb5df4,
+        0x4200e17de0cc6c29,
+        0x19b39afe06fb5bb2,
+    ]),
+    element([
+        0x686f6fe173700945,
+        0x3e4f4cb3469e6ac8,
+        0xd63a469743213664,
+        0x135ffbf239eaf2f5,
+    ]),
+    element([
+        0x7e4d1e40c6711eb9,
+        0xbca6ba75046f1305,
+        0x3efcd25b5b533542,
+        0x217ba974db0643b3,
+    ]),
+    element([
+        0x5969606fe8d1f22e,
+        0xde4185b2ce565c2f,
+        0xddaf587c4e1d3932,
+        0x0067e377276f35bd,
+    ]),
+    element([
+        0x2511c15f2fd313e8,
+        0xb32c50f9d9fa8709,
+        0xabbc7e8de441911f,
+        0x2ae805d8f044e7a5,
+    ]),
+    element([
+        0xd61ba78218949200,
+        0x3c1bf79862f07980,
+        0xcf84ff8729d201cc,
+        0x28b505742160fcc6,
+    ]),
+    element([
+        0xacb2908985623fe4,
+        0xf58e1f22609dba6e,
+        0x9fc73f0510bf8b7e,
+        0x234030e287d40b69,
+    ]),
+    element([
+        0xc6f6e137781dd151,
+        0xb6f5d356792c6fb2,
+        0xff143977048350d6,
+        0x2f67ea8d39d62cd1,
+    ]),
+    element([
+        0x3ac910aef6b4a544,
+        0xa4be1e3b71c73cb6,
+        0x6f0d19e809abced2,
+        0x2892f919e9074fe1,
+    ]),
+    element([
+        0x4ce42a67ed0f6926,
+        0x0139b5e26770c073,
+        0xecb6a12ddf978a76,
+        0x30329e14a890c0a8,
+    ]),
+    element([
+        0x5a504166257f7d59,
+        0x6ed88ff2a7513ec8,
+        0x1e0330a34ad27f73,
+        0x09d8c7db0369c0f5,
+    ]),
+    element([
+        0x480746026b5944ee,
+        0x55fe3bda90366620,
+        0x7276e2d360fae9bc,
+        0x1541585b70b14aa1,
+    ]),
+    element([
+        0x9509a8ce25e55b0e,
+        0x9364f585c864ff1d,
+        0x94c5a48eb7240325,
+        0x219987b3c1d4b332,
+    ]),
+    element([
+        0x00b5ddb9d8b70d04,
+        0x10ddac6419cf6c2a,
+        0xb3e882a91bb8949d,
+        0x2a930e4ffc8ac9d1,
+    ]),
+    element([
+        0xf33285d94c5eb7e0,
+        0x69182664a28fb9c8,
+        0x2ef28d37dc50981f,
+        0x1868ed2f4b4d1f7d,
+    ]),
+    element([
+        0x165e249bff77348b,
+        0x5c67dec34628ca67,
+        0x20595a65ec3a844a,
+        0x2ba509f5d277f120,
+    ]),
+    element([
+        0xf52067d6500c5192,
+        0x2e250b6695cd2c8e,
+        0x3ef241c21a5d30ca,
+        0x2483dfb4e414987d,
+    ]),
+    element([
+        0x2c67a7fbc4c7ed66,
+        0x5f9f4f35bb2b34bf,
+        0xfc4ec83c9c818772,
+        0x1660c07058535ffe,
+    ]),
+    element([
+        0xf51f644162a9b41e,
+        0x66467c207e56e2c0,
+        0x351201a7fed76dc3,
+        0x2a8b96fa5c5acc3f,
+    ]),
+    element([
+        0x100cdde3a915004f,
+        0xf5729e6737bfe714,
+        0x8026c69c2c060063,
+        0x1aa2d83a8a52fcd8,
+    ]),
+    element([
+        0x0f1bb3ab04298ad7,
+        0x57e33fa14398d1af,
+        0xdd05bfbc76814188,
+        0x05b55a8a59375bba,
+    ]),
+    element([
+        0xb8156b3ac4c66688,
+        0xda4e49720b7ff10a,
+        0x9150df23dca258bc,
+        0x20cc68799db9569a,
+    ]),
+    element([
+        0xf9b4ee9f25cd9b6e,
+        0xc0ed685c25b1705e,
+        0x5dd5f616289d01aa,
+        0x017d9760dee4c384,
+    ]),
+    element([
+        0xa81b85ca46b3aab6,
+        0x4935a3caca92a74f,
+        0x94ada30efb61df38,
+        0x19f23cbc5adfe1a6,
+    ]),
+    element([
+        0x5d38a58b9d9cf263,
+        0x8a526cf6f39b218f,
+        0xfc3386559a3a376f,
+        0x100c2399d9f15a4c,
+    ]),
+    element([
+        0x6964f1ce5aaef066,
+        0x051ac0a8af086da7,
+        0x24990b1793c47255,
+        0x18bc685acd60306e,
+    ]),
+    element([
+        0xf57df1ab1379f19a,
+        0xc4e8e9e39afd345f,
+        0x7ae996245c0b8cba,
+        0x10886de6e695f1ca,
+    ]),
+    element([
+        0xb32714f6b6838f69,
+        0x9c2090ec4a33ef99,
+        0x09fa2bdeee559c05,
+        0x2cf8217e5dad6238,
+    ]),
+    element([
+        0x2fd5100ae2c3cf85,
+        0x7b4e6bb31a7d6490,
+        0x0739e6c88cfaff7e,
+        0x11a98348d3cf71e9,
+    ]),
+    element([
+        0x85d214c31b4ff236,
+        0x8880165df14a3bac,
+        0x7a74dae90c39806d,
+        0x2a3e075dc7599b69,
+    ]),
+    element([
+        0x16a5946513d29031,
+        0x0c7024c1715b04cb,
+        0x76de7ca8a4bada34,
+        0x1cc898cfe515d846,
+    ]),
+    element([
+        0x02cebcf095f5727d,
+        0xbe1aa6b3715c5848,
+        0x83865aba09bf65a5,
+        0x0ac484462f5d83f8,
+    ]),
+    element([
+        0x7cd561a44b4f13e6,
+        0x1be1c30e8292dfe7,
+        0xaf02ccb3d1510e2f,
+        0x1e1eb07e518eaaab,
+    ]),
+    element([
+        0xd5cf097681afc048,
+        0x2e82f85a32681f31,
+        0xd3af85acef2a6738,
+        0x2416af9d79ad9088,
+    ]),
+    element([
+        0x1b9768bc75d88128,
+        0x24ecd1d1a5faa4f5,
+        0xafc596b7b0f4d060,
+        0x1431b44f2e08f66a,
+    ]),
+    element([
+        0x7c8da2ca02e48654,
+        0xd7ba080c67080598,
+        0xe7a97338751d2030,
+        0x14e09bb118875957,
+    ]),
+    element([
+        0x9ba3981424067239,
+        0xe9b0a5d50c8c2c74,
+        0xb2bd9c8af877d503,
+        0x08a4c869ea5dce60,
+    ]),
+    element([
+        0x316d2e326d612292,
+        0x649fdd88101c9508,
+        0x5f01f43c7bce8a0c,
+        0x10bcc1d8650f7b87,
+    ]),
+    element([
+        0x1a0c235253046673,
+        0xbe84ef88b4c48dbe,
+        0x510e9cc69073fc9b,
+        0x0b4c8974498fd2c7,
+    ]),
+];
+
+/// MDS Matrix in Row-Major Order for [`Spec5`](super::Spec5)
+pub static SPEC5_MDS_MATRIX: [Fp<ConstraintField>; 36] = [
+    element([
+        0x7d695c480aaaaaaa,
+        0x3a880fcfaed9b4f4,
+        0xda7526dba9a9c517,
+        0x0a67cbb369deea8e,
+    ]),
+    element([
+        0xf41575289db6db6d,
+        0x07daec5e847b8b05,
+        0xea0fce347eecc0e2,
+        0x02017ed283b7fb4f,
+    ]),
+    element([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2000000000000000,
+    ]),
+    element([
+        0x80dce13d51c71c72,
+        0xec7d50101b0cc3ae,
+        0x0c839db6c77213a2,
+        0x2732bc1987605c7b,
+    ]),
+    element([
+        0x0d939783fccccccd,
+        0x6e70c80e7ebeb01d,
+        0xbe767457b38044df,
+        0x234742e3c6a3866e,
+    ]),
+    element([
+        0x9e265a3f9e8ba2e9,
+        0xf3b110cdf2a6f027,
+        0x1afe9ea62351d247,
+        0x2478727c6bf642f4,
+    ]),
+    element([
+        0xf41575289db6db6d,
+        0x07daec5e847b8b05,
+        0xea0fce347eecc0e2,
+        0x02017ed283b7fb4f,
+    ]),
+    element([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2000000000000000,
+    ]),
+    element([
+        0x80dce13d51c71c72,
+        0xec7d50101b0cc3ae,
+        0x0c839db6c77213a2,
+        0x2732bc1987605c7b,
+    ]),
+    element([
+        0x0d939783fccccccd,
+        0x6e70c80e7ebeb01d,
+        0xbe767457b38044df,
+        0x234742e3c6a3866e,
+    ]),
+    element([
+        0x9e265a3f9e8ba2e9,
+        0xf3b110cdf2a6f027,
+        0x1afe9ea62351d247,
+        0x2478727c6bf642f4,
+    ]),
+    element([
+        0x3eb4ae2405555555,
+        0x9d4407e7d76cda7a,
+        0x6d3a936dd4d4e28b,
+        0x0533e5d9b4ef7547,
+    ]),
+    element([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2000000000000000,
+    ]),
+    element([
+        0x80dce13d51c71c72,
+        0xec7d50101b0cc3ae,
+        0x0c839db6c77213a2,
+        0x2732bc1987605c7b,
+    ]),
+    element([
+        0x0d939783fccccccd,
+        0x6e70c80e7ebeb01d,
+        0xbe767457b38044df,
+        0x234742e3c6a3866e,
+    ]),
+    element([
+        0x9e265a3f9e8ba2e9,
+        0xf3b110cdf2a6f027,
+        0x1afe9ea62351d247,
+        0x2478727c6bf642f4,
+    ]),
+    element([
+        0x3eb4ae2405555555,
+        0x9d4407e7d76cda7a,
+        0x6d3a936dd4d4e28b,
+        0x0533e5d9b4ef7547,
+    ]),
+    element([
+        0x7a2e512ca13b13b1,
+        0x80918f51bc866fde,
+        0xe9288d736bf5d7ea,
+        0x0886640cdfb97893,
+    ]),
+    element([
+        0x80dce13d51c71c72,
+        0xec7d50101b0cc3ae,
+        0x0c839db6c77213a2,
+        0x2732bc1987605c7b,
+    ]),
+    element([
+        0x0d939783fccccccd,
+        0x6e70c80e7ebeb01d,
+        0xbe767457b38044df,
+        0x234742e3c6a3866e,
+    ]),
+    element([
+        0x9e265a3f9e8ba2e9,
+        0xf3b110cdf2a6f027,
+        0x1afe9ea62351d247,
+        0x2478727c6bf642f4,
+    ]),
+    element([
+        0x3eb4ae2405555555,
+        0x9d4407e7d76cda7a,
+        0x6d3a936dd4d4e28b,
+        0x0533e5d9b4ef7547,
+    ]),
+    element([
+        0x7a2e512ca13b13b1,
+        0x80918f51bc866fde,
+        0xe9288d736bf5d7ea,
+        0x0886640cdfb97893,
+    ]),
+    element([
+        0x9bfbb55e46db6db7,
+        0x98076a537f1a7dcb,
+        0xd13009f580370c9f,
+        0x1932e6a2b274cdbc,
+    ]),
+    element([
+        0x0d939783fccccccd,
+        0x6e70c80e7ebeb01d,
+        0xbe767457b38044df,
+        0x234742e3c6a3866e,
+    ]),
+    element([
+        0x9e265a3f9e8ba2e9,
+        0xf3b110cdf2a6f027,
+        0x1afe9ea62351d247,
+        0x2478727c6bf642f4,
+    ]),
+    element([
+        0x3eb4ae2405555555,
+        0x9d4407e7d76cda7a,
+        0x6d3a936dd4d4e28b,
+        0x0533e5d9b4ef7547,
+    ]),
+    element([
+        0x7a2e512ca13b13b1,
+        0x80918f51bc866fde,
+        0xe9288d736bf5d7ea,
+        0x0886640cdfb97893,
+    ]),
+    element([
+        0x9bfbb55e46db6db7,
+        0x98076a537f1a7dcb,
+        0xd13009f580370c9f,
+        0x1932e6a2b274cdbc,
+    ]),
+    element([
+        0xb3b7ba57fdddddde,
+        0xf44b3009a9d47568,
+        0xd44ef83a77aad894,
+        0x1784d742846d0449,
+    ]),
+    element([
+        0x9e265a3f9e8ba2e9,
+        0xf3b110cdf2a6f027,
+        0x1afe9ea62351d247,
+        0x2478727c6bf642f4,
+    ]),
+    element([
+        0x3eb4ae2405555555,
+        0x9d4407e7d76cda7a,
+        0x6d3a936dd4d4e28b,
+        0x0533e5d9b4ef7547,
+    ]),
+    element([
+        0x7a2e512ca13b13b1,
+        0x80918f51bc866fde,
+        0xe9288d736bf5d7ea,
+        0x0886640cdfb97893,
+    ]),
+    element([
+        0x9bfbb55e46db6db7,
+        0x98076a537f1a7dcb,
+        0xd13009f580370c9f,
+        0x1932e6a2b274cdbc,
+    ]),
+    element([
+        0xb3b7ba57fdddddde,
+        0xf44b3009a9d47568,
+        0xd44ef83a77aad894,
+        0x1784d742846d0449,
+    ]),
+    element([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1000000000000000,
+    ]),
+];
@@ -45,19 +45,19 @@ use manta_crypto::{
     arkworks::{
         algebra::{affine_point_as_bytes, ScalarVar},
         constraint::{fp::Fp, rem_mod_prime, Boolean, FpVar},
-        ff::{try_into_u128, PrimeField},
+        ff::try_into_u128,
         serialize::{CanonicalSerialize, SerializationError},
     },
     eclair::{
         alloc::{Allocate, Constant},
         num::U128,
     },
-    encryption::{self, EmptyHeader},
-    hash,
+    encryption, hash,
     hash::ArrayHashFunction,
     merkle_tree,
     rand::{Rand, RngCore, Sample},
     signature::schnorr,
+    transcript::{Absorb, Blake2sTranscript, Challenge},
 };
 use manta_util::{
     codec::{Decode, DecodeError, Encode, Read, Write},
@@ -267,34 +267,115 @@ impl Constant<Compiler> for GroupGeneratorVar {
     }
 }
 
+/// Domain Tag Scheme
+///
+/// Identifies the hash function a domain tag is used for.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DomainTagScheme {
+    /// UTXO Commitment Scheme
+    UtxoCommitmentScheme = 1,
+
+    /// Viewing Key Derivation Function
+    ViewingKeyDerivationFunction = 2,
+
+    /// UTXO Accumulator Item Hash
+    UtxoAccumulatorItemHash = 3,
+
+    /// Merkle Tree Inner Hash
+    InnerHash = 4,
+
+    /// Nullifier Commitment Scheme
+    NullifierCommitmentScheme = 5,
+}
+
+/// Domain Tags Configuration
+pub trait DomainTags {
+    /// Domain Tag Version
+    const VERSION: u8;
+
+    /// Returns the domain tag used by the hash function of `scheme`.
+    fn domain_tag(scheme: DomainTagScheme) -> Fp<ConstraintField>;
+}
+
+/// Legacy Domain Tags
+///
+/// Uses the all-zero domain tag for every scheme. The published MantaPay parameters and proving
+/// keys were generated with these tags, so they must be used to stay compatible with them.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LegacyDomainTags;
+
+impl DomainTags for LegacyDomainTags {
+    const VERSION: u8 = 0;
+
+    #[inline]
+    fn domain_tag(scheme: DomainTagScheme) -> Fp<ConstraintField> {
+        let _ = scheme;
+        Fp(0u8.into())
+    }
+}
+
+/// Versioned Domain Tags
+///
+/// Uses a distinct domain tag for every scheme, built as `VERSION * 2^8 + scheme`, so that the
+/// outputs of two different schemes can never collide and the tags can be rotated by bumping
+/// the version.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VersionedDomainTags;
+
+impl DomainTags for VersionedDomainTags {
+    const VERSION: u8 = 1;
+
+    #[inline]
+    fn domain_tag(scheme: DomainTagScheme) -> Fp<ConstraintField> {
+        Fp((((Self::VERSION as u64) << 8) | scheme as u64).into())
+    }
+}
+
+/// Domain Tags Configuration Type
+pub trait DomainTagsType {
+    /// Domain Tags Type
+    type DomainTags: DomainTags;
+}
+
+/// Returns the domain tag of [`Config`] for `scheme`.
+#[inline]
+pub fn domain_tag(scheme: DomainTagScheme) -> Fp<ConstraintField> {
+    <<Config as DomainTagsType>::DomainTags as DomainTags>::domain_tag(scheme)
+}
+
 /// Utxo Commitment Scheme Domain Tag
 #[cfg_attr(
     feature = "serde",
     derive(Deserialize, Serialize),
     serde(crate = "manta_util::serde", deny_unknown_fields)
 )]
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct UtxoCommitmentSchemeDomainTag;
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UtxoCommitmentSchemeDomainTag<T = LegacyDomainTags>(PhantomData<T>);
 
-impl poseidon::hash::DomainTag<Poseidon5> for UtxoCommitmentSchemeDomainTag {
+impl<T> poseidon::hash::DomainTag<Poseidon5> for UtxoCommitmentSchemeDomainTag<T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn domain_tag() -> <Poseidon5 as ParameterFieldType>::ParameterField {
-        Fp(0u8.into()) // FIXME: Use a real domain tag
+        T::domain_tag(DomainTagScheme::UtxoCommitmentScheme)
     }
 }
 
-impl<COM> Constant<COM> for UtxoCommitmentSchemeDomainTag {
+impl<T, COM> Constant<COM> for UtxoCommitmentSchemeDomainTag<T> {
     type Type = Self;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut COM) -> Self {
         let _ = (this, compiler);
-        Self
+        Self::default()
     }
 }
 
 /// Utxo Commitment Scheme Type
-type UtxoCommitmentSchemeType<COM = ()> = Hasher<Poseidon5, UtxoCommitmentSchemeDomainTag, 5, COM>;
+type UtxoCommitmentSchemeType<COM = (), T = LegacyDomainTags> =
+    Hasher<Poseidon5, UtxoCommitmentSchemeDomainTag<T>, 5, COM>;
 
 /// Utxo Commitment Scheme
 #[cfg_attr(
@@ -302,8 +383,8 @@ type UtxoCommitmentSchemeType<COM = ()> = Hasher<Poseidon5, UtxoCommitmentScheme
     derive(Deserialize, Serialize),
     serde(
         bound(
-            deserialize = "UtxoCommitmentSchemeType<COM>: Deserialize<'de>",
-            serialize = "UtxoCommitmentSchemeType<COM>: Serialize",
+            deserialize = "UtxoCommitmentSchemeType<COM, T>: Deserialize<'de>",
+            serialize = "UtxoCommitmentSchemeType<COM, T>: Serialize",
         ),
         crate = "manta_util::serde",
         deny_unknown_fields
@@ -311,19 +392,23 @@ type UtxoCommitmentSchemeType<COM = ()> = Hasher<Poseidon5, UtxoCommitmentScheme
 )]
 #[derive(derivative::Derivative)]
 #[derivative(
-    Clone(bound = "UtxoCommitmentSchemeType<COM>: Clone"),
-    Copy(bound = "UtxoCommitmentSchemeType<COM>: Copy"),
-    Debug(bound = "UtxoCommitmentSchemeType<COM>: Debug"),
-    Default(bound = "UtxoCommitmentSchemeType<COM>: Default"),
-    Eq(bound = "UtxoCommitmentSchemeType<COM>: Eq"),
-    Hash(bound = "UtxoCommitmentSchemeType<COM>: core::hash::Hash"),
-    PartialEq(bound = "UtxoCommitmentSchemeType<COM>: PartialEq")
+    Clone(bound = "UtxoCommitmentSchemeType<COM, T>: Clone"),
+    Copy(bound = "UtxoCommitmentSchemeType<COM, T>: Copy"),
+    Debug(bound = "UtxoCommitmentSchemeType<COM, T>: Debug"),
+    Default(bound = "UtxoCommitmentSchemeType<COM, T>: Default"),
+    Eq(bound = "UtxoCommitmentSchemeType<COM, T>: Eq"),
+    Hash(bound = "UtxoCommitmentSchemeType<COM, T>: core::hash::Hash"),
+    PartialEq(bound = "UtxoCommitmentSchemeType<COM, T>: PartialEq")
 )]
-pub struct UtxoCommitmentScheme<COM = ()>(UtxoCommitmentSchemeType<COM>)
+pub struct UtxoCommitmentScheme<COM = (), T = LegacyDomainTags>(UtxoCommitmentSchemeType<COM, T>)
 where
-    Poseidon5: poseidon::Specification<COM>;
+    Poseidon5: poseidon::Specification<COM>,
+    T: DomainTags;
 
-impl Encode for UtxoCommitmentScheme {
+impl<T> Encode for UtxoCommitmentScheme<(), T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn encode<W>(&self, writer: W) -> Result<(), W::Error>
     where
@@ -333,7 +418,10 @@ impl Encode for UtxoCommitmentScheme {
     }
 }
 
-impl Decode for UtxoCommitmentScheme {
+impl<T> Decode for UtxoCommitmentScheme<(), T>
+where
+    T: DomainTags,
+{
     type Error = <Fp<ConstraintField> as Decode>::Error;
 
     #[inline]
@@ -345,7 +433,10 @@ impl Decode for UtxoCommitmentScheme {
     }
 }
 
-impl Sample for UtxoCommitmentScheme {
+impl<T> Sample for UtxoCommitmentScheme<(), T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn sample<R>(distribution: (), rng: &mut R) -> Self
     where
@@ -355,8 +446,11 @@ impl Sample for UtxoCommitmentScheme {
     }
 }
 
-impl Constant<Compiler> for UtxoCommitmentScheme<Compiler> {
-    type Type = UtxoCommitmentScheme;
+impl<T> Constant<Compiler> for UtxoCommitmentScheme<Compiler, T>
+where
+    T: DomainTags,
+{
+    type Type = UtxoCommitmentScheme<(), T>;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut Compiler) -> Self {
@@ -364,7 +458,10 @@ impl Constant<Compiler> for UtxoCommitmentScheme<Compiler> {
     }
 }
 
-impl protocol::UtxoCommitmentScheme for UtxoCommitmentScheme {
+impl<T> protocol::UtxoCommitmentScheme for UtxoCommitmentScheme<(), T>
+where
+    T: DomainTags,
+{
     type AssetId = AssetId;
     type AssetValue = AssetValue;
     type ReceivingKey = ReceivingKey;
@@ -393,7 +490,10 @@ impl protocol::UtxoCommitmentScheme for UtxoCommitmentScheme {
     }
 }
 
-impl protocol::UtxoCommitmentScheme<Compiler> for UtxoCommitmentScheme<Compiler> {
+impl<T> protocol::UtxoCommitmentScheme<Compiler> for UtxoCommitmentScheme<Compiler, T>
+where
+    T: DomainTags,
+{
     type AssetId = AssetIdVar;
     type AssetValue = AssetValueVar;
     type ReceivingKey = ReceivingKeyVar;
@@ -428,29 +528,33 @@ impl protocol::UtxoCommitmentScheme<Compiler> for UtxoCommitmentScheme<Compiler>
     derive(Deserialize, Serialize),
     serde(crate = "manta_util::serde", deny_unknown_fields)
 )]
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct ViewingKeyDerivationFunctionDomainTag;
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ViewingKeyDerivationFunctionDomainTag<T = LegacyDomainTags>(PhantomData<T>);
 
-impl poseidon::hash::DomainTag<Poseidon2> for ViewingKeyDerivationFunctionDomainTag {
+impl<T> poseidon::hash::DomainTag<Poseidon2> for ViewingKeyDerivationFunctionDomainTag<T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn domain_tag() -> <Poseidon2 as ParameterFieldType>::ParameterField {
-        Fp(0u8.into()) // FIXME: Use a real domain tag
+        T::domain_tag(DomainTagScheme::ViewingKeyDerivationFunction)
     }
 }
 
-impl<COM> Constant<COM> for ViewingKeyDerivationFunctionDomainTag {
+impl<T, COM> Constant<COM> for ViewingKeyDerivationFunctionDomainTag<T> {
     type Type = Self;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut COM) -> Self {
         let _ = (this, compiler);
-        Self
+        Self::default()
     }
 }
 
 /// Viewing Key Derivation Function Type
-type ViewingKeyDerivationFunctionType<COM = ()> =
-    Hasher<Poseidon2, ViewingKeyDerivationFunctionDomainTag, 2, COM>;
+type ViewingKeyDerivationFunctionType<COM = (), T = LegacyDomainTags> =
+    Hasher<Poseidon2, ViewingKeyDerivationFunctionDomainTag<T>, 2, COM>;
 
 /// Viewing Key Derivation Function
 #[cfg_attr(
@@ -458,8 +562,8 @@ type ViewingKeyDerivationFunctionType<COM = ()> =
     derive(Deserialize, Serialize),
     serde(
         bound(
-            deserialize = "ViewingKeyDerivationFunctionType<COM>: Deserialize<'de>",
-            serialize = "ViewingKeyDerivationFunctionType<COM>: Serialize",
+            deserialize = "ViewingKeyDerivationFunctionType<COM, T>: Deserialize<'de>",
+            serialize = "ViewingKeyDerivationFunctionType<COM, T>: Serialize",
         ),
         crate = "manta_util::serde",
         deny_unknown_fields
@@ -467,19 +571,25 @@ type ViewingKeyDerivationFunctionType<COM = ()> =
 )]
 #[derive(derivative::Derivative)]
 #[derivative(
-    Clone(bound = "ViewingKeyDerivationFunctionType<COM>: Clone"),
-    Copy(bound = "ViewingKeyDerivationFunctionType<COM>: Copy"),
-    Debug(bound = "ViewingKeyDerivationFunctionType<COM>: Debug"),
-    Default(bound = "ViewingKeyDerivationFunctionType<COM>: Default"),
-    Eq(bound = "ViewingKeyDerivationFunctionType<COM>: Eq"),
-    Hash(bound = "ViewingKeyDerivationFunctionType<COM>: core::hash::Hash"),
-    PartialEq(bound = "ViewingKeyDerivationFunctionType<COM>: PartialEq")
+    Clone(bound = "ViewingKeyDerivationFunctionType<COM, T>: Clone"),
+    Copy(bound = "ViewingKeyDerivationFunctionType<COM, T>: Copy"),
+    Debug(bound = "ViewingKeyDerivationFunctionType<COM, T>: Debug"),
+    Default(bound = "ViewingKeyDerivationFunctionType<COM, T>: Default"),
+    Eq(bound = "ViewingKeyDerivationFunctionType<COM, T>: Eq"),
+    Hash(bound = "ViewingKeyDerivationFunctionType<COM, T>: core::hash::Hash"),
+    PartialEq(bound = "ViewingKeyDerivationFunctionType<COM, T>: PartialEq")
 )]
-pub struct ViewingKeyDerivationFunction<COM = ()>(ViewingKeyDerivationFunctionType<COM>)
+pub struct ViewingKeyDerivationFunction<COM = (), T = LegacyDomainTags>(
+    ViewingKeyDerivationFunctionType<COM, T>,
+)
 where
-    Poseidon2: poseidon::Specification<COM>;
+    Poseidon2: poseidon::Specification<COM>,
+    T: DomainTags;
 
-impl Encode for ViewingKeyDerivationFunction {
+impl<T> Encode for ViewingKeyDerivationFunction<(), T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn encode<W>(&self, writer: W) -> Result<(), W::Error>
     where
@@ -489,7 +599,10 @@ impl Encode for ViewingKeyDerivationFunction {
     }
 }
 
-impl Decode for ViewingKeyDerivationFunction {
+impl<T> Decode for ViewingKeyDerivationFunction<(), T>
+where
+    T: DomainTags,
+{
     type Error = <Fp<ConstraintField> as Decode>::Error;
 
     #[inline]
@@ -501,7 +614,10 @@ impl Decode for ViewingKeyDerivationFunction {
     }
 }
 
-impl Sample for ViewingKeyDerivationFunction {
+impl<T> Sample for ViewingKeyDerivationFunction<(), T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn sample<R>(distribution: (), rng: &mut R) -> Self
     where
@@ -511,8 +627,11 @@ impl Sample for ViewingKeyDerivationFunction {
     }
 }
 
-impl Constant<Compiler> for ViewingKeyDerivationFunction<Compiler> {
-    type Type = ViewingKeyDerivationFunction;
+impl<T> Constant<Compiler> for ViewingKeyDerivationFunction<Compiler, T>
+where
+    T: DomainTags,
+{
+    type Type = ViewingKeyDerivationFunction<(), T>;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut Compiler) -> Self {
@@ -520,7 +639,10 @@ impl Constant<Compiler> for ViewingKeyDerivationFunction<Compiler> {
     }
 }
 
-impl protocol::ViewingKeyDerivationFunction for ViewingKeyDerivationFunction {
+impl<T> protocol::ViewingKeyDerivationFunction for ViewingKeyDerivationFunction<(), T>
+where
+    T: DomainTags,
+{
     type ProofAuthorizationKey = ProofAuthorizationKey;
     type ViewingKey = ViewingKey;
 
@@ -544,7 +666,11 @@ impl protocol::ViewingKeyDerivationFunction for ViewingKeyDerivationFunction {
     }
 }
 
-impl protocol::ViewingKeyDerivationFunction<Compiler> for ViewingKeyDerivationFunction<Compiler> {
+impl<T> protocol::ViewingKeyDerivationFunction<Compiler>
+    for ViewingKeyDerivationFunction<Compiler, T>
+where
+    T: DomainTags,
+{
     type ProofAuthorizationKey = ProofAuthorizationKeyVar;
     type ViewingKey = ViewingKeyVar;
 
@@ -561,6 +687,17 @@ impl protocol::ViewingKeyDerivationFunction<Compiler> for ViewingKeyDerivationFu
     }
 }
 
+/// Note Ciphertext Format Version
+///
+/// Latest version of the incoming, light incoming, and outgoing note ciphertext formats. New notes
+/// are always encrypted at this version, while notes of any earlier version can still be decrypted.
+pub const NOTE_VERSION: u8 = 0;
+
+/// Note Header
+///
+/// Header of the incoming, light incoming, and outgoing notes carrying their [`NOTE_VERSION`].
+pub type NoteHeader<COM = ()> = encryption::VersionHeader<NOTE_VERSION, COM>;
+
 /// Incoming Encryption Scheme Converter
 #[cfg_attr(
     feature = "serde",
@@ -569,45 +706,71 @@ impl protocol::ViewingKeyDerivationFunction<Compiler> for ViewingKeyDerivationFu
 )]
 #[derive(derivative::Derivative)]
 #[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct IncomingEncryptionSchemeConverter<COM = ()>(PhantomData<COM>);
+pub struct IncomingEncryptionSchemeConverter<COM = (), T = LegacyDomainTags>(PhantomData<(COM, T)>);
 
-impl encryption::HeaderType for IncomingEncryptionSchemeConverter {
-    type Header = encryption::EmptyHeader;
+impl<T> encryption::HeaderType for IncomingEncryptionSchemeConverter<(), T>
+where
+    T: DomainTags,
+{
+    type Header = NoteHeader;
 }
 
-impl encryption::HeaderType for IncomingEncryptionSchemeConverter<Compiler> {
-    type Header = encryption::EmptyHeader<Compiler>;
+impl<T> encryption::HeaderType for IncomingEncryptionSchemeConverter<Compiler, T>
+where
+    T: DomainTags,
+{
+    type Header = NoteHeader<Compiler>;
 }
 
-impl encryption::convert::header::Header for IncomingEncryptionSchemeConverter {
+impl<T> encryption::convert::header::Header for IncomingEncryptionSchemeConverter<(), T>
+where
+    T: DomainTags,
+{
     type TargetHeader = encryption::Header<IncomingPoseidonEncryptionScheme>;
 
     #[inline]
     fn as_target(source: &Self::Header, _: &mut ()) -> Self::TargetHeader {
-        let _ = source;
-        vec![]
+        match source.version() {
+            0 => vec![],
+            version => vec![Fp(ConstraintField::from(version))],
+        }
     }
 }
 
-impl encryption::convert::header::Header<Compiler> for IncomingEncryptionSchemeConverter<Compiler> {
+impl<T> encryption::convert::header::Header<Compiler>
+    for IncomingEncryptionSchemeConverter<Compiler, T>
+where
+    T: DomainTags,
+{
     type TargetHeader = encryption::Header<IncomingPoseidonEncryptionScheme<Compiler>>;
 
     #[inline]
-    fn as_target(source: &Self::Header, _: &mut Compiler) -> Self::TargetHeader {
-        let _ = source;
-        vec![]
+    fn as_target(source: &Self::Header, compiler: &mut Compiler) -> Self::TargetHeader {
+        match source.version() {
+            0 => vec![],
+            version => vec![Fp(ConstraintField::from(version)).as_constant(compiler)],
+        }
     }
 }
 
-impl encryption::EncryptionKeyType for IncomingEncryptionSchemeConverter {
+impl<T> encryption::EncryptionKeyType for IncomingEncryptionSchemeConverter<(), T>
+where
+    T: DomainTags,
+{
     type EncryptionKey = Group;
 }
 
-impl encryption::EncryptionKeyType for IncomingEncryptionSchemeConverter<Compiler> {
+impl<T> encryption::EncryptionKeyType for IncomingEncryptionSchemeConverter<Compiler, T>
+where
+    T: DomainTags,
+{
     type EncryptionKey = GroupVar;
 }
 
-impl encryption::convert::key::Encryption for IncomingEncryptionSchemeConverter {
+impl<T> encryption::convert::key::Encryption for IncomingEncryptionSchemeConverter<(), T>
+where
+    T: DomainTags,
+{
     type TargetEncryptionKey = encryption::EncryptionKey<IncomingPoseidonEncryptionScheme>;
 
     #[inline]
@@ -616,8 +779,10 @@ impl encryption::convert::key::Encryption for IncomingEncryptionSchemeConverter
     }
 }
 
-impl encryption::convert::key::Encryption<Compiler>
-    for IncomingEncryptionSchemeConverter<Compiler>
+impl<T> encryption::convert::key::Encryption<Compiler>
+    for IncomingEncryptionSchemeConverter<Compiler, T>
+where
+    T: DomainTags,
 {
     type TargetEncryptionKey =
         encryption::EncryptionKey<IncomingPoseidonEncryptionScheme<Compiler>>;
@@ -628,15 +793,24 @@ impl encryption::convert::key::Encryption<Compiler>
     }
 }
 
-impl encryption::DecryptionKeyType for IncomingEncryptionSchemeConverter {
+impl<T> encryption::DecryptionKeyType for IncomingEncryptionSchemeConverter<(), T>
+where
+    T: DomainTags,
+{
     type DecryptionKey = Group;
 }
 
-impl encryption::DecryptionKeyType for IncomingEncryptionSchemeConverter<Compiler> {
+impl<T> encryption::DecryptionKeyType for IncomingEncryptionSchemeConverter<Compiler, T>
+where
+    T: DomainTags,
+{
     type DecryptionKey = GroupVar;
 }
 
-impl encryption::convert::key::Decryption for IncomingEncryptionSchemeConverter {
+impl<T> encryption::convert::key::Decryption for IncomingEncryptionSchemeConverter<(), T>
+where
+    T: DomainTags,
+{
     type TargetDecryptionKey = encryption::DecryptionKey<IncomingPoseidonEncryptionScheme>;
 
     #[inline]
@@ -645,8 +819,10 @@ impl encryption::convert::key::Decryption for IncomingEncryptionSchemeConverter
     }
 }
 
-impl encryption::convert::key::Decryption<Compiler>
-    for IncomingEncryptionSchemeConverter<Compiler>
+impl<T> encryption::convert::key::Decryption<Compiler>
+    for IncomingEncryptionSchemeConverter<Compiler, T>
+where
+    T: DomainTags,
 {
     type TargetDecryptionKey =
         encryption::DecryptionKey<IncomingPoseidonEncryptionScheme<Compiler>>;
@@ -657,15 +833,24 @@ impl encryption::convert::key::Decryption<Compiler>
     }
 }
 
-impl encryption::PlaintextType for IncomingEncryptionSchemeConverter {
-    type Plaintext = protocol::IncomingPlaintext<Config>;
+impl<T> encryption::PlaintextType for IncomingEncryptionSchemeConverter<(), T>
+where
+    T: DomainTags,
+{
+    type Plaintext = protocol::IncomingPlaintext<Config<(), T>>;
 }
 
-impl encryption::PlaintextType for IncomingEncryptionSchemeConverter<Compiler> {
-    type Plaintext = protocol::IncomingPlaintext<Config<Compiler>, Compiler>;
+impl<T> encryption::PlaintextType for IncomingEncryptionSchemeConverter<Compiler, T>
+where
+    T: DomainTags,
+{
+    type Plaintext = protocol::IncomingPlaintext<Config<Compiler, T>, Compiler>;
 }
 
-impl encryption::convert::plaintext::Forward for IncomingEncryptionSchemeConverter {
+impl<T> encryption::convert::plaintext::Forward for IncomingEncryptionSchemeConverter<(), T>
+where
+    T: DomainTags,
+{
     type TargetPlaintext = encryption::Plaintext<IncomingPoseidonEncryptionScheme>;
 
     #[inline]
@@ -684,8 +869,10 @@ impl encryption::convert::plaintext::Forward for IncomingEncryptionSchemeConvert
     }
 }
 
-impl encryption::convert::plaintext::Forward<Compiler>
-    for IncomingEncryptionSchemeConverter<Compiler>
+impl<T> encryption::convert::plaintext::Forward<Compiler>
+    for IncomingEncryptionSchemeConverter<Compiler, T>
+where
+    T: DomainTags,
 {
     type TargetPlaintext = encryption::Plaintext<IncomingPoseidonEncryptionScheme<Compiler>>;
 
@@ -705,11 +892,17 @@ impl encryption::convert::plaintext::Forward<Compiler>
     }
 }
 
-impl encryption::DecryptedPlaintextType for IncomingEncryptionSchemeConverter {
+impl<T> encryption::DecryptedPlaintextType for IncomingEncryptionSchemeConverter<(), T>
+where
+    T: DomainTags,
+{
     type DecryptedPlaintext = Option<<Self as encryption::PlaintextType>::Plaintext>;
 }
 
-impl encryption::convert::plaintext::Reverse for IncomingEncryptionSchemeConverter {
+impl<T> encryption::convert::plaintext::Reverse for IncomingEncryptionSchemeConverter<(), T>
+where
+    T: DomainTags,
+{
     type TargetDecryptedPlaintext =
         encryption::DecryptedPlaintext<IncomingPoseidonEncryptionScheme>;
 
@@ -731,8 +924,11 @@ impl encryption::convert::plaintext::Reverse for IncomingEncryptionSchemeConvert
     }
 }
 
-impl<COM> Constant<COM> for IncomingEncryptionSchemeConverter<COM> {
-    type Type = IncomingEncryptionSchemeConverter;
+impl<COM, T> Constant<COM> for IncomingEncryptionSchemeConverter<COM, T>
+where
+    T: DomainTags,
+{
+    type Type = IncomingEncryptionSchemeConverter<(), T>;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut COM) -> Self {
@@ -746,16 +942,17 @@ pub type IncomingPoseidonEncryptionScheme<COM = ()> =
     poseidon::encryption::FixedDuplexer<1, Poseidon3, COM>;
 
 /// Incoming Base Encryption Scheme
-pub type IncomingBaseEncryptionScheme<COM = ()> = encryption::convert::key::Converter<
-    encryption::convert::header::Converter<
-        encryption::convert::plaintext::Converter<
-            IncomingPoseidonEncryptionScheme<COM>,
-            IncomingEncryptionSchemeConverter<COM>,
+pub type IncomingBaseEncryptionScheme<COM = (), T = LegacyDomainTags> =
+    encryption::convert::key::Converter<
+        encryption::convert::header::Converter<
+            encryption::convert::plaintext::Converter<
+                IncomingPoseidonEncryptionScheme<COM>,
+                IncomingEncryptionSchemeConverter<COM, T>,
+            >,
+            IncomingEncryptionSchemeConverter<COM, T>,
         >,
-        IncomingEncryptionSchemeConverter<COM>,
-    >,
-    IncomingEncryptionSchemeConverter<COM>,
->;
+        IncomingEncryptionSchemeConverter<COM, T>,
+    >;
 
 /// AES Plaintext Size
 pub const AES_PLAINTEXT_SIZE: usize = 80;
@@ -881,13 +1078,19 @@ impl<COM> encryption::Decrypt for IncomingAESEncryptionScheme<COM> {
 )]
 #[derive(derivative::Derivative)]
 #[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct IncomingAESConverter<COM = ()>(PhantomData<COM>);
+pub struct IncomingAESConverter<COM = (), T = LegacyDomainTags>(PhantomData<(COM, T)>);
 
-impl<COM> encryption::HeaderType for IncomingAESConverter<COM> {
-    type Header = encryption::EmptyHeader<COM>;
+impl<COM, T> encryption::HeaderType for IncomingAESConverter<COM, T>
+where
+    T: DomainTags,
+{
+    type Header = NoteHeader<COM>;
 }
 
-impl<COM> encryption::convert::header::Header<COM> for IncomingAESConverter<COM> {
+impl<COM, T> encryption::convert::header::Header<COM> for IncomingAESConverter<COM, T>
+where
+    T: DomainTags,
+{
     type TargetHeader = encryption::Header<IncomingAESEncryptionScheme<COM>>;
 
     #[inline]
@@ -896,15 +1099,24 @@ impl<COM> encryption::convert::header::Header<COM> for IncomingAESConverter<COM>
     }
 }
 
-impl encryption::EncryptionKeyType for IncomingAESConverter {
+impl<T> encryption::EncryptionKeyType for IncomingAESConverter<(), T>
+where
+    T: DomainTags,
+{
     type EncryptionKey = Group;
 }
 
-impl encryption::EncryptionKeyType for IncomingAESConverter<Compiler> {
+impl<T> encryption::EncryptionKeyType for IncomingAESConverter<Compiler, T>
+where
+    T: DomainTags,
+{
     type EncryptionKey = GroupVar;
 }
 
-impl encryption::convert::key::Encryption for IncomingAESConverter {
+impl<T> encryption::convert::key::Encryption for IncomingAESConverter<(), T>
+where
+    T: DomainTags,
+{
     type TargetEncryptionKey = encryption::EncryptionKey<IncomingAESEncryptionScheme>;
 
     #[inline]
@@ -916,7 +1128,10 @@ impl encryption::convert::key::Encryption for IncomingAESConverter {
     }
 }
 
-impl encryption::convert::key::Encryption<Compiler> for IncomingAESConverter<Compiler> {
+impl<T> encryption::convert::key::Encryption<Compiler> for IncomingAESConverter<Compiler, T>
+where
+    T: DomainTags,
+{
     type TargetEncryptionKey = encryption::EncryptionKey<IncomingAESEncryptionScheme<Compiler>>;
 
     #[inline]
@@ -928,15 +1143,24 @@ impl encryption::convert::key::Encryption<Compiler> for IncomingAESConverter<Com
     }
 }
 
-impl encryption::DecryptionKeyType for IncomingAESConverter {
+impl<T> encryption::DecryptionKeyType for IncomingAESConverter<(), T>
+where
+    T: DomainTags,
+{
     type DecryptionKey = Group;
 }
 
-impl encryption::DecryptionKeyType for IncomingAESConverter<Compiler> {
+impl<T> encryption::DecryptionKeyType for IncomingAESConverter<Compiler, T>
+where
+    T: DomainTags,
+{
     type DecryptionKey = GroupVar;
 }
 
-impl encryption::convert::key::Decryption for IncomingAESConverter {
+impl<T> encryption::convert::key::Decryption for IncomingAESConverter<(), T>
+where
+    T: DomainTags,
+{
     type TargetDecryptionKey = encryption::DecryptionKey<IncomingAESEncryptionScheme>;
 
     #[inline]
@@ -948,7 +1172,10 @@ impl encryption::convert::key::Decryption for IncomingAESConverter {
     }
 }
 
-impl encryption::convert::key::Decryption<Compiler> for IncomingAESConverter<Compiler> {
+impl<T> encryption::convert::key::Decryption<Compiler> for IncomingAESConverter<Compiler, T>
+where
+    T: DomainTags,
+{
     type TargetDecryptionKey = encryption::DecryptionKey<IncomingAESEncryptionScheme<Compiler>>;
 
     #[inline]
@@ -960,15 +1187,24 @@ impl encryption::convert::key::Decryption<Compiler> for IncomingAESConverter<Com
     }
 }
 
-impl encryption::PlaintextType for IncomingAESConverter {
-    type Plaintext = protocol::IncomingPlaintext<Config>;
+impl<T> encryption::PlaintextType for IncomingAESConverter<(), T>
+where
+    T: DomainTags,
+{
+    type Plaintext = protocol::IncomingPlaintext<Config<(), T>>;
 }
 
-impl encryption::PlaintextType for IncomingAESConverter<Compiler> {
-    type Plaintext = protocol::IncomingPlaintext<Config<Compiler>, Compiler>;
+impl<T> encryption::PlaintextType for IncomingAESConverter<Compiler, T>
+where
+    T: DomainTags,
+{
+    type Plaintext = protocol::IncomingPlaintext<Config<Compiler, T>, Compiler>;
 }
 
-impl encryption::convert::plaintext::Forward for IncomingAESConverter {
+impl<T> encryption::convert::plaintext::Forward for IncomingAESConverter<(), T>
+where
+    T: DomainTags,
+{
     type TargetPlaintext = encryption::Plaintext<IncomingAESEncryptionScheme>;
 
     #[inline]
@@ -988,7 +1224,10 @@ impl encryption::convert::plaintext::Forward for IncomingAESConverter {
     }
 }
 
-impl encryption::convert::plaintext::Forward<Compiler> for IncomingAESConverter<Compiler> {
+impl<T> encryption::convert::plaintext::Forward<Compiler> for IncomingAESConverter<Compiler, T>
+where
+    T: DomainTags,
+{
     type TargetPlaintext = encryption::Plaintext<IncomingAESEncryptionScheme<Compiler>>;
 
     #[inline]
@@ -1000,11 +1239,17 @@ impl encryption::convert::plaintext::Forward<Compiler> for IncomingAESConverter<
     }
 }
 
-impl encryption::DecryptedPlaintextType for IncomingAESConverter {
+impl<T> encryption::DecryptedPlaintextType for IncomingAESConverter<(), T>
+where
+    T: DomainTags,
+{
     type DecryptedPlaintext = Option<<Self as encryption::PlaintextType>::Plaintext>;
 }
 
-impl encryption::convert::plaintext::Reverse for IncomingAESConverter {
+impl<T> encryption::convert::plaintext::Reverse for IncomingAESConverter<(), T>
+where
+    T: DomainTags,
+{
     type TargetDecryptedPlaintext = encryption::DecryptedPlaintext<IncomingAESEncryptionScheme>;
 
     #[inline]
@@ -1019,7 +1264,7 @@ impl encryption::convert::plaintext::Reverse for IncomingAESConverter {
         let asset_id = Fp::<ConstraintField>::from_vec(asset_id_bytes)
             .expect("Error while converting the bytes into a field element.");
         let asset_value = u128::from_le_bytes(asset_value_bytes);
-        let source_plaintext = protocol::IncomingPlaintext::<Config>::new(
+        let source_plaintext = protocol::IncomingPlaintext::<Config<(), T>>::new(
             utxo_randomness,
             asset::Asset {
                 id: asset_id,
@@ -1030,8 +1275,11 @@ impl encryption::convert::plaintext::Reverse for IncomingAESConverter {
     }
 }
 
-impl<COM> Constant<COM> for IncomingAESConverter<COM> {
-    type Type = IncomingAESConverter;
+impl<COM, T> Constant<COM> for IncomingAESConverter<COM, T>
+where
+    T: DomainTags,
+{
+    type Type = IncomingAESConverter<(), T>;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut COM) -> Self {
@@ -1041,15 +1289,15 @@ impl<COM> Constant<COM> for IncomingAESConverter<COM> {
 }
 
 /// Incoming Base AES
-pub type IncomingBaseAES<COM = ()> = encryption::convert::key::Converter<
+pub type IncomingBaseAES<COM = (), T = LegacyDomainTags> = encryption::convert::key::Converter<
     encryption::convert::header::Converter<
         encryption::convert::plaintext::Converter<
             IncomingAESEncryptionScheme<COM>,
-            IncomingAESConverter<COM>,
+            IncomingAESConverter<COM, T>,
         >,
-        IncomingAESConverter<COM>,
+        IncomingAESConverter<COM, T>,
     >,
-    IncomingAESConverter<COM>,
+    IncomingAESConverter<COM, T>,
 >;
 
 /// Utxo Accumulator Item Hash Domain Tag
@@ -1058,29 +1306,33 @@ pub type IncomingBaseAES<COM = ()> = encryption::convert::key::Converter<
     derive(Deserialize, Serialize),
     serde(crate = "manta_util::serde", deny_unknown_fields)
 )]
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct UtxoAccumulatorItemHashDomainTag;
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UtxoAccumulatorItemHashDomainTag<T = LegacyDomainTags>(PhantomData<T>);
 
-impl poseidon::hash::DomainTag<Poseidon4> for UtxoAccumulatorItemHashDomainTag {
+impl<T> poseidon::hash::DomainTag<Poseidon4> for UtxoAccumulatorItemHashDomainTag<T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn domain_tag() -> <Poseidon4 as ParameterFieldType>::ParameterField {
-        Fp(0u8.into()) // FIXME: Use a real domain tag
+        T::domain_tag(DomainTagScheme::UtxoAccumulatorItemHash)
     }
 }
 
-impl<COM> Constant<COM> for UtxoAccumulatorItemHashDomainTag {
+impl<T, COM> Constant<COM> for UtxoAccumulatorItemHashDomainTag<T> {
     type Type = Self;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut COM) -> Self {
         let _ = (this, compiler);
-        Self
+        Self::default()
     }
 }
 
 /// Utxo Accumulator Item Hash Type
-type UtxoAccumulatorItemHashType<COM = ()> =
-    Hasher<Poseidon4, UtxoAccumulatorItemHashDomainTag, 4, COM>;
+type UtxoAccumulatorItemHashType<COM = (), T = LegacyDomainTags> =
+    Hasher<Poseidon4, UtxoAccumulatorItemHashDomainTag<T>, 4, COM>;
 
 /// Utxo Accumulator Item Hash
 #[cfg_attr(
@@ -1088,8 +1340,8 @@ type UtxoAccumulatorItemHashType<COM = ()> =
     derive(Deserialize, Serialize),
     serde(
         bound(
-            deserialize = "UtxoAccumulatorItemHashType<COM>: Deserialize<'de>",
-            serialize = "UtxoAccumulatorItemHashType<COM>: Serialize",
+            deserialize = "UtxoAccumulatorItemHashType<COM, T>: Deserialize<'de>",
+            serialize = "UtxoAccumulatorItemHashType<COM, T>: Serialize",
         ),
         crate = "manta_util::serde",
         deny_unknown_fields
@@ -1097,19 +1349,25 @@ type UtxoAccumulatorItemHashType<COM = ()> =
 )]
 #[derive(derivative::Derivative)]
 #[derivative(
-    Clone(bound = "UtxoAccumulatorItemHashType<COM>: Clone"),
-    Copy(bound = "UtxoAccumulatorItemHashType<COM>: Copy"),
-    Debug(bound = "UtxoAccumulatorItemHashType<COM>: Debug"),
-    Default(bound = "UtxoAccumulatorItemHashType<COM>: Default"),
-    Eq(bound = "UtxoAccumulatorItemHashType<COM>: Eq"),
-    Hash(bound = "UtxoAccumulatorItemHashType<COM>: core::hash::Hash"),
-    PartialEq(bound = "UtxoAccumulatorItemHashType<COM>: PartialEq")
+    Clone(bound = "UtxoAccumulatorItemHashType<COM, T>: Clone"),
+    Copy(bound = "UtxoAccumulatorItemHashType<COM, T>: Copy"),
+    Debug(bound = "UtxoAccumulatorItemHashType<COM, T>: Debug"),
+    Default(bound = "UtxoAccumulatorItemHashType<COM, T>: Default"),
+    Eq(bound = "UtxoAccumulatorItemHashType<COM, T>: Eq"),
+    Hash(bound = "UtxoAccumulatorItemHashType<COM, T>: core::hash::Hash"),
+    PartialEq(bound = "UtxoAccumulatorItemHashType<COM, T>: PartialEq")
 )]
-pub struct UtxoAccumulatorItemHash<COM = ()>(UtxoAccumulatorItemHashType<COM>)
+pub struct UtxoAccumulatorItemHash<COM = (), T = LegacyDomainTags>(
+    UtxoAccumulatorItemHashType<COM, T>,
+)
 where
-    Poseidon4: poseidon::Specification<COM>;
+    Poseidon4: poseidon::Specification<COM>,
+    T: DomainTags;
 
-impl Encode for UtxoAccumulatorItemHash {
+impl<T> Encode for UtxoAccumulatorItemHash<(), T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn encode<W>(&self, writer: W) -> Result<(), W::Error>
     where
@@ -1119,7 +1377,10 @@ impl Encode for UtxoAccumulatorItemHash {
     }
 }
 
-impl Decode for UtxoAccumulatorItemHash {
+impl<T> Decode for UtxoAccumulatorItemHash<(), T>
+where
+    T: DomainTags,
+{
     type Error = <Fp<ConstraintField> as Decode>::Error;
 
     #[inline]
@@ -1131,7 +1392,10 @@ impl Decode for UtxoAccumulatorItemHash {
     }
 }
 
-impl Sample for UtxoAccumulatorItemHash {
+impl<T> Sample for UtxoAccumulatorItemHash<(), T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn sample<R>(distribution: (), rng: &mut R) -> Self
     where
@@ -1141,8 +1405,11 @@ impl Sample for UtxoAccumulatorItemHash {
     }
 }
 
-impl Constant<Compiler> for UtxoAccumulatorItemHash<Compiler> {
-    type Type = UtxoAccumulatorItemHash;
+impl<T> Constant<Compiler> for UtxoAccumulatorItemHash<Compiler, T>
+where
+    T: DomainTags,
+{
+    type Type = UtxoAccumulatorItemHash<(), T>;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut Compiler) -> Self {
@@ -1150,11 +1417,14 @@ impl Constant<Compiler> for UtxoAccumulatorItemHash<Compiler> {
     }
 }
 
-impl ItemHashFunction<Utxo> for UtxoAccumulatorItemHash {
+impl<T> ItemHashFunction<protocol::Utxo<Config<(), T>>> for UtxoAccumulatorItemHash<(), T>
+where
+    T: DomainTags,
+{
     type Item = UtxoAccumulatorItem;
 
     #[inline]
-    fn item_hash(&self, value: &Utxo, compiler: &mut ()) -> Self::Item {
+    fn item_hash(&self, value: &protocol::Utxo<Config<(), T>>, compiler: &mut ()) -> Self::Item {
         self.0.hash(
             [
                 &Fp(value.is_transparent.into()),
@@ -1167,11 +1437,19 @@ impl ItemHashFunction<Utxo> for UtxoAccumulatorItemHash {
     }
 }
 
-impl ItemHashFunction<UtxoVar, Compiler> for UtxoAccumulatorItemHash<Compiler> {
+impl<T> ItemHashFunction<protocol::Utxo<Config<Compiler, T>, Compiler>, Compiler>
+    for UtxoAccumulatorItemHash<Compiler, T>
+where
+    T: DomainTags,
+{
     type Item = UtxoAccumulatorItemVar;
 
     #[inline]
-    fn item_hash(&self, value: &UtxoVar, compiler: &mut Compiler) -> Self::Item {
+    fn item_hash(
+        &self,
+        value: &protocol::Utxo<Config<Compiler, T>, Compiler>,
+        compiler: &mut Compiler,
+    ) -> Self::Item {
         self.0.hash(
             [
                 &value.is_transparent.clone().into(),
@@ -1196,34 +1474,41 @@ pub type LeafHashVar = merkle_tree::IdentityLeafHash<UtxoAccumulatorItemVar, Com
     derive(Deserialize, Serialize),
     serde(crate = "manta_util::serde", deny_unknown_fields)
 )]
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct InnerHashDomainTag;
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InnerHashDomainTag<T = LegacyDomainTags>(PhantomData<T>);
 
-impl poseidon::hash::DomainTag<Poseidon2> for InnerHashDomainTag {
+impl<T> poseidon::hash::DomainTag<Poseidon2> for InnerHashDomainTag<T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn domain_tag() -> <Poseidon2 as ParameterFieldType>::ParameterField {
-        Fp(0u8.into()) // FIXME: Use a real domain tag
+        T::domain_tag(DomainTagScheme::InnerHash)
     }
 }
 
-impl<COM> Constant<COM> for InnerHashDomainTag {
+impl<T, COM> Constant<COM> for InnerHashDomainTag<T> {
     type Type = Self;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut COM) -> Self {
         let _ = (this, compiler);
-        Self
+        Self::default()
     }
 }
 
 /// Inner Hash Configuration
 #[derive(derivative::Derivative)]
 #[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct InnerHash<COM = ()>(PhantomData<COM>);
+pub struct InnerHash<COM = (), T = LegacyDomainTags>(PhantomData<(COM, T)>);
 
-impl merkle_tree::InnerHash for InnerHash {
+impl<T> merkle_tree::InnerHash for InnerHash<(), T>
+where
+    T: DomainTags,
+{
     type LeafDigest = UtxoAccumulatorItem;
-    type Parameters = Hasher<Poseidon2, InnerHashDomainTag, 2>;
+    type Parameters = Hasher<Poseidon2, InnerHashDomainTag<T>, 2>;
     type Output = Fp<ConstraintField>;
 
     #[inline]
@@ -1247,9 +1532,12 @@ impl merkle_tree::InnerHash for InnerHash {
     }
 }
 
-impl merkle_tree::InnerHash<Compiler> for InnerHash<Compiler> {
+impl<T> merkle_tree::InnerHash<Compiler> for InnerHash<Compiler, T>
+where
+    T: DomainTags,
+{
     type LeafDigest = UtxoAccumulatorItemVar;
-    type Parameters = Hasher<Poseidon2, InnerHashDomainTag, 2, Compiler>;
+    type Parameters = Hasher<Poseidon2, InnerHashDomainTag<T>, 2, Compiler>;
     type Output = FpVar<ConstraintField>;
 
     #[inline]
@@ -1273,40 +1561,91 @@ impl merkle_tree::InnerHash<Compiler> for InnerHash<Compiler> {
     }
 }
 
-/// Merkle Tree Configuration
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct MerkleTreeConfiguration;
+/// Default Height of the UTXO Accumulator Merkle Trees
+pub const MERKLE_TREE_HEIGHT: usize = 20;
+
+/// Default Width of the UTXO Accumulator Merkle Forest
+pub const MERKLE_FOREST_WIDTH: usize = 256;
+
+/// Parameterized Merkle Tree Configuration
+///
+/// The UTXO accumulator is a forest of `FOREST_WIDTH` merkle trees of height `HEIGHT`, so it can
+/// hold `FOREST_WIDTH * 2^(HEIGHT - 1)` UTXOs. The same `HEIGHT` is used for the native and the
+/// in-circuit configuration, so the membership proofs checked by the transfer circuits always
+/// match the accumulator the parameters were generated for.
+///
+/// `FOREST_WIDTH` must be a power of two no larger than `256`, and the inner hash of the trees uses
+/// the domain tags `T`.
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MerkleTreeConfig<const HEIGHT: usize, const FOREST_WIDTH: usize, T = LegacyDomainTags>(
+    PhantomData<T>,
+);
+
+impl<const HEIGHT: usize, const FOREST_WIDTH: usize, T> MerkleTreeConfig<HEIGHT, FOREST_WIDTH, T> {
+    /// Height of the Merkle Trees
+    pub const HEIGHT: usize = HEIGHT;
 
-impl MerkleTreeConfiguration {
     /// Width of the Merkle Forest
-    pub const FOREST_WIDTH: usize = 256;
+    pub const FOREST_WIDTH: usize = {
+        assert!(
+            FOREST_WIDTH.is_power_of_two() && FOREST_WIDTH <= 256,
+            "The forest width must be a power of two no larger than 256."
+        );
+        FOREST_WIDTH
+    };
 }
 
-impl merkle_tree::HashConfiguration for MerkleTreeConfiguration {
+/// Merkle Tree Configuration
+///
+/// This is the accumulator configuration used by [`Config`], and with it by the transfer circuits
+/// and the parameter generation. Deployments which need a different accumulator capacity should
+/// change [`MERKLE_TREE_HEIGHT`] and [`MERKLE_FOREST_WIDTH`] and regenerate the parameters.
+pub type MerkleTreeConfiguration = MerkleTreeConfig<MERKLE_TREE_HEIGHT, MERKLE_FOREST_WIDTH>;
+
+impl<const HEIGHT: usize, const FOREST_WIDTH: usize, T> merkle_tree::HashConfiguration
+    for MerkleTreeConfig<HEIGHT, FOREST_WIDTH, T>
+where
+    T: DomainTags,
+{
     type LeafHash = LeafHash;
-    type InnerHash = InnerHash;
+    type InnerHash = InnerHash<(), T>;
 }
 
-impl merkle_tree::HashConfiguration<Compiler> for MerkleTreeConfiguration {
+impl<const HEIGHT: usize, const FOREST_WIDTH: usize, T> merkle_tree::HashConfiguration<Compiler>
+    for MerkleTreeConfig<HEIGHT, FOREST_WIDTH, T>
+where
+    T: DomainTags,
+{
     type LeafHash = LeafHashVar;
-    type InnerHash = InnerHash<Compiler>;
+    type InnerHash = InnerHash<Compiler, T>;
 }
 
-impl merkle_tree::Configuration for MerkleTreeConfiguration {
-    const HEIGHT: usize = 20;
+impl<const HEIGHT: usize, const FOREST_WIDTH: usize, T> merkle_tree::Configuration
+    for MerkleTreeConfig<HEIGHT, FOREST_WIDTH, T>
+where
+    T: DomainTags,
+{
+    const HEIGHT: usize = HEIGHT;
 }
 
-impl merkle_tree::Configuration<Compiler> for MerkleTreeConfiguration {
-    const HEIGHT: usize = 20;
+impl<const HEIGHT: usize, const FOREST_WIDTH: usize, T> merkle_tree::Configuration<Compiler>
+    for MerkleTreeConfig<HEIGHT, FOREST_WIDTH, T>
+where
+    T: DomainTags,
+{
+    const HEIGHT: usize = HEIGHT;
 }
 
-impl<COM> Constant<COM> for MerkleTreeConfiguration {
+impl<COM, const HEIGHT: usize, const FOREST_WIDTH: usize, T> Constant<COM>
+    for MerkleTreeConfig<HEIGHT, FOREST_WIDTH, T>
+{
     type Type = Self;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut COM) -> Self {
         let _ = (this, compiler);
-        Self
+        Self::default()
     }
 }
 
@@ -1316,7 +1655,11 @@ pub type UtxoAccumulatorModel = merkle_tree::Parameters<MerkleTreeConfiguration>
 /// UTXO Accumulator Model Variable
 pub type UtxoAccumulatorModelVar = merkle_tree::Parameters<MerkleTreeConfiguration, Compiler>;
 
-impl merkle_tree::forest::Configuration for MerkleTreeConfiguration {
+impl<const HEIGHT: usize, const FOREST_WIDTH: usize, T> merkle_tree::forest::Configuration
+    for MerkleTreeConfig<HEIGHT, FOREST_WIDTH, T>
+where
+    T: DomainTags,
+{
     type Index = u8;
 
     #[inline]
@@ -1332,12 +1675,16 @@ impl merkle_tree::forest::Configuration for MerkleTreeConfiguration {
         hasher
             .finalize_variable(&mut result)
             .expect("Hashing is not allowed to fail.");
-        result[0]
+        (usize::from(result[0]) & (Self::FOREST_WIDTH - 1)) as u8
     }
 }
 
 #[cfg(any(feature = "parameters", test))]
-impl merkle_tree::test::HashParameterSampling for MerkleTreeConfiguration {
+impl<const HEIGHT: usize, const FOREST_WIDTH: usize, T> merkle_tree::test::HashParameterSampling
+    for MerkleTreeConfig<HEIGHT, FOREST_WIDTH, T>
+where
+    T: DomainTags,
+{
     type LeafHashParameterDistribution = ();
     type InnerHashParameterDistribution = ();
 
@@ -1370,29 +1717,33 @@ impl merkle_tree::test::HashParameterSampling for MerkleTreeConfiguration {
     derive(Deserialize, Serialize),
     serde(crate = "manta_util::serde", deny_unknown_fields)
 )]
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct NullifierCommitmentSchemeDomainTag;
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NullifierCommitmentSchemeDomainTag<T = LegacyDomainTags>(PhantomData<T>);
 
-impl poseidon::hash::DomainTag<Poseidon3> for NullifierCommitmentSchemeDomainTag {
+impl<T> poseidon::hash::DomainTag<Poseidon3> for NullifierCommitmentSchemeDomainTag<T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn domain_tag() -> <Poseidon3 as ParameterFieldType>::ParameterField {
-        Fp(0u8.into()) // FIXME: Use a real domain tag
+        T::domain_tag(DomainTagScheme::NullifierCommitmentScheme)
     }
 }
 
-impl<COM> Constant<COM> for NullifierCommitmentSchemeDomainTag {
+impl<T, COM> Constant<COM> for NullifierCommitmentSchemeDomainTag<T> {
     type Type = Self;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut COM) -> Self {
         let _ = (this, compiler);
-        Self
+        Self::default()
     }
 }
 
 /// Nullifier Commitment Scheme Type
-type NullifierCommitmentSchemeType<COM = ()> =
-    Hasher<Poseidon3, NullifierCommitmentSchemeDomainTag, 3, COM>;
+type NullifierCommitmentSchemeType<COM = (), T = LegacyDomainTags> =
+    Hasher<Poseidon3, NullifierCommitmentSchemeDomainTag<T>, 3, COM>;
 
 /// Nullifier Commitment Scheme
 #[cfg_attr(
@@ -1400,8 +1751,8 @@ type NullifierCommitmentSchemeType<COM = ()> =
     derive(Deserialize, Serialize),
     serde(
         bound(
-            deserialize = "NullifierCommitmentSchemeType<COM>: Deserialize<'de>",
-            serialize = "NullifierCommitmentSchemeType<COM>: Serialize",
+            deserialize = "NullifierCommitmentSchemeType<COM, T>: Deserialize<'de>",
+            serialize = "NullifierCommitmentSchemeType<COM, T>: Serialize",
         ),
         crate = "manta_util::serde",
         deny_unknown_fields
@@ -1409,19 +1760,68 @@ type NullifierCommitmentSchemeType<COM = ()> =
 )]
 #[derive(derivative::Derivative)]
 #[derivative(
-    Clone(bound = "NullifierCommitmentSchemeType<COM>: Clone"),
-    Copy(bound = "NullifierCommitmentSchemeType<COM>: Copy"),
-    Debug(bound = "NullifierCommitmentSchemeType<COM>: Debug"),
-    Default(bound = "NullifierCommitmentSchemeType<COM>: Default"),
-    Eq(bound = "NullifierCommitmentSchemeType<COM>: Eq"),
-    Hash(bound = "NullifierCommitmentSchemeType<COM>: core::hash::Hash"),
-    PartialEq(bound = "NullifierCommitmentSchemeType<COM>: PartialEq")
+    Clone(bound = "NullifierCommitmentSchemeType<COM, T>: Clone"),
+    Copy(bound = "NullifierCommitmentSchemeType<COM, T>: Copy"),
+    Debug(bound = "NullifierCommitmentSchemeType<COM, T>: Debug"),
+    Default(bound = "NullifierCommitmentSchemeType<COM, T>: Default"),
+    Eq(bound = "NullifierCommitmentSchemeType<COM, T>: Eq"),
+    Hash(bound = "NullifierCommitmentSchemeType<COM, T>: core::hash::Hash"),
+    PartialEq(bound = "NullifierCommitmentSchemeType<COM, T>: PartialEq")
 )]
-pub struct NullifierCommitmentScheme<COM = ()>(NullifierCommitmentSchemeType<COM>)
+pub struct NullifierCommitmentScheme<COM = (), T = LegacyDomainTags>(
+    NullifierCommitmentSchemeType<COM, T>,
+)
+where
+    Poseidon3: poseidon::Specification<COM>,
+    T: DomainTags;
+
+/// Deployment Identifier
+///
+/// Identifies the network a MantaPay deployment runs on. See
+/// [`NullifierCommitmentScheme::with_deployment`] for more.
+pub type DeploymentId = u32;
+
+/// Legacy Deployment Identifier
+///
+/// Deployment identifier which leaves the nullifier domain tag unchanged, matching the published
+/// parameters and proving keys.
+pub const LEGACY_DEPLOYMENT: DeploymentId = 0;
+
+/// Returns the nullifier commitment scheme domain tag of the domain tags `T` for `deployment`.
+///
+/// The `deployment` is placed above the sixteen bits used by [`VersionedDomainTags`] so that it
+/// never collides with the domain tag of another scheme.
+#[inline]
+pub fn nullifier_domain_tag<T>(deployment: DeploymentId) -> Fp<ConstraintField>
+where
+    T: DomainTags,
+{
+    Fp(T::domain_tag(DomainTagScheme::NullifierCommitmentScheme).0
+        + ConstraintField::from((deployment as u64) << 16))
+}
+
+impl<T> NullifierCommitmentScheme<(), T>
 where
-    Poseidon3: poseidon::Specification<COM>;
+    T: DomainTags,
+{
+    /// Mixes the `deployment` identifier into the domain tag of `self`, so that the same spending
+    /// key used on two deployments never yields identical nullifiers.
+    ///
+    /// # Note
+    ///
+    /// The domain tag is part of the encoded parameters and of the circuit constants, so every
+    /// deployment other than [`LEGACY_DEPLOYMENT`] needs its own parameters and proving keys.
+    #[inline]
+    pub fn with_deployment(mut self, deployment: DeploymentId) -> Self {
+        self.0.set_domain_tag(nullifier_domain_tag::<T>(deployment));
+        self
+    }
+}
 
-impl Encode for NullifierCommitmentScheme {
+impl<T> Encode for NullifierCommitmentScheme<(), T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn encode<W>(&self, writer: W) -> Result<(), W::Error>
     where
@@ -1431,7 +1831,10 @@ impl Encode for NullifierCommitmentScheme {
     }
 }
 
-impl Decode for NullifierCommitmentScheme {
+impl<T> Decode for NullifierCommitmentScheme<(), T>
+where
+    T: DomainTags,
+{
     type Error = <Fp<ConstraintField> as Decode>::Error;
 
     #[inline]
@@ -1443,7 +1846,10 @@ impl Decode for NullifierCommitmentScheme {
     }
 }
 
-impl Sample for NullifierCommitmentScheme {
+impl<T> Sample for NullifierCommitmentScheme<(), T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn sample<R>(distribution: (), rng: &mut R) -> Self
     where
@@ -1453,8 +1859,11 @@ impl Sample for NullifierCommitmentScheme {
     }
 }
 
-impl Constant<Compiler> for NullifierCommitmentScheme<Compiler> {
-    type Type = NullifierCommitmentScheme;
+impl<T> Constant<Compiler> for NullifierCommitmentScheme<Compiler, T>
+where
+    T: DomainTags,
+{
+    type Type = NullifierCommitmentScheme<(), T>;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut Compiler) -> Self {
@@ -1462,7 +1871,10 @@ impl Constant<Compiler> for NullifierCommitmentScheme<Compiler> {
     }
 }
 
-impl protocol::NullifierCommitmentScheme for NullifierCommitmentScheme {
+impl<T> protocol::NullifierCommitmentScheme for NullifierCommitmentScheme<(), T>
+where
+    T: DomainTags,
+{
     type ProofAuthorizationKey = ProofAuthorizationKey;
     type UtxoAccumulatorItem = Fp<ConstraintField>;
     type Commitment = Fp<ConstraintField>;
@@ -1485,7 +1897,10 @@ impl protocol::NullifierCommitmentScheme for NullifierCommitmentScheme {
     }
 }
 
-impl protocol::NullifierCommitmentScheme<Compiler> for NullifierCommitmentScheme<Compiler> {
+impl<T> protocol::NullifierCommitmentScheme<Compiler> for NullifierCommitmentScheme<Compiler, T>
+where
+    T: DomainTags,
+{
     type ProofAuthorizationKey = ProofAuthorizationKeyVar;
     type UtxoAccumulatorItem = FpVar<ConstraintField>;
     type Commitment = FpVar<ConstraintField>;
@@ -1635,7 +2050,7 @@ impl<COM> encryption::Decrypt for OutgoingAESEncryptionScheme<COM> {
 pub struct OutgoingAESConverter<COM = ()>(PhantomData<COM>);
 
 impl<COM> encryption::HeaderType for OutgoingAESConverter<COM> {
-    type Header = encryption::EmptyHeader<COM>;
+    type Header = NoteHeader<COM>;
 }
 
 impl<COM> encryption::convert::header::Header<COM> for OutgoingAESConverter<COM> {
@@ -1804,11 +2219,15 @@ pub type OutgoingBaseAES<COM = ()> = encryption::convert::key::Converter<
     derive(Deserialize, Serialize),
     serde(crate = "manta_util::serde", deny_unknown_fields)
 )]
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct AddressPartitionFunction;
+#[derive(derivative::Derivative)]
+#[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AddressPartitionFunction<T = LegacyDomainTags>(PhantomData<T>);
 
-impl protocol::AddressPartitionFunction for AddressPartitionFunction {
-    type Address = Address;
+impl<T> protocol::AddressPartitionFunction for AddressPartitionFunction<T>
+where
+    T: DomainTags,
+{
+    type Address = protocol::Address<Config<(), T>>;
     type Partition = u8;
 
     #[inline]
@@ -1830,7 +2249,10 @@ impl protocol::AddressPartitionFunction for AddressPartitionFunction {
     }
 }
 
-impl Encode for AddressPartitionFunction {
+impl<T> Encode for AddressPartitionFunction<T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn encode<W>(&self, writer: W) -> Result<(), W::Error>
     where
@@ -1841,7 +2263,10 @@ impl Encode for AddressPartitionFunction {
     }
 }
 
-impl Decode for AddressPartitionFunction {
+impl<T> Decode for AddressPartitionFunction<T>
+where
+    T: DomainTags,
+{
     type Error = ();
 
     #[inline]
@@ -1850,18 +2275,78 @@ impl Decode for AddressPartitionFunction {
         R: Read,
     {
         let _ = reader;
-        Ok(Self)
+        Ok(Self::default())
     }
 }
 
-impl Sample for AddressPartitionFunction {
+impl<T> Sample for AddressPartitionFunction<T>
+where
+    T: DomainTags,
+{
     #[inline]
     fn sample<R>(distribution: (), rng: &mut R) -> Self
     where
         R: RngCore + ?Sized,
     {
         let _ = (distribution, rng);
-        Self
+        Self::default()
+    }
+}
+
+/// Detection Key Derivation Function
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DetectionKeyDerivationFunction;
+
+impl protocol::DetectionKeyDerivationFunction for DetectionKeyDerivationFunction {
+    type ViewingKey = EmbeddedScalar;
+    type DetectionKey = EmbeddedScalar;
+
+    #[inline]
+    fn detection_key(&self, viewing_key: &Self::ViewingKey) -> Self::DetectionKey {
+        let mut transcript = Blake2sTranscript::new(b"manta-pay/1.0.0/detection-key");
+        let mut buffer = Vec::new();
+        viewing_key
+            .0
+            .serialize_unchecked(&mut buffer)
+            .expect("Serializing is not allowed to fail.");
+        transcript.absorb(b"viewing-key", buffer.as_slice(), &mut ());
+        transcript.challenge(b"detection-key", &mut ())
+    }
+}
+
+/// Detection Tag Function
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DetectionTagFunction;
+
+impl protocol::DetectionTagFunction for DetectionTagFunction {
+    type SharedSecret = Group;
+    type Tag = u16;
+
+    #[inline]
+    fn tag(&self, shared_secret: &Self::SharedSecret) -> Self::Tag {
+        let mut hasher = Blake2sVar::new(2).unwrap();
+        hasher.update(b"manta-v1.0.0/detection-tag-function");
+        let mut buffer = Vec::new();
+        shared_secret
+            .0
+            .serialize_unchecked(&mut buffer)
+            .expect("Serializing is not allowed to fail.");
+        hasher.update(&buffer);
+        let mut result = [0; 2];
+        hasher
+            .finalize_variable(&mut result)
+            .expect("Hashing is not allowed to fail.");
+        u16::from_le_bytes(result)
     }
 }
 
@@ -1889,19 +2374,58 @@ impl schnorr::HashFunction for SchnorrHashFunction {
         message: &Self::Message,
         _: &mut (),
     ) -> EmbeddedScalar {
-        let mut hasher = Blake2s256::new();
-        Digest::update(&mut hasher, b"manta-pay/1.0.0/Schnorr-hash");
-        Digest::update(
-            &mut hasher,
-            affine_point_as_bytes::<GroupCurve>(&verifying_key.0),
+        let mut transcript = Blake2sTranscript::new(b"manta-pay/1.0.0/Schnorr-hash");
+        transcript.absorb(
+            b"verifying-key",
+            affine_point_as_bytes::<GroupCurve>(&verifying_key.0).as_slice(),
+            &mut (),
         );
-        Digest::update(
-            &mut hasher,
-            affine_point_as_bytes::<GroupCurve>(&nonce_point.0),
+        transcript.absorb(
+            b"nonce-point",
+            affine_point_as_bytes::<GroupCurve>(&nonce_point.0).as_slice(),
+            &mut (),
         );
-        Digest::update(&mut hasher, message);
-        let bytes: [u8; 32] = hasher.finalize().into();
-        Fp(EmbeddedScalarField::from_le_bytes_mod_order(&bytes))
+        transcript.absorb(b"message", message.as_slice(), &mut ());
+        transcript.challenge(b"challenge", &mut ())
+    }
+}
+
+impl SchnorrHashFunction {
+    /// Computes the message digest of `message` for [`PrehashedHashFunction::hash_prehashed`].
+    ///
+    /// [`PrehashedHashFunction::hash_prehashed`]: schnorr::PrehashedHashFunction::hash_prehashed
+    #[inline]
+    pub fn digest(message: &[u8]) -> [u8; 32] {
+        let mut transcript = Blake2sTranscript::new(b"manta-pay/1.0.0/Schnorr-digest");
+        transcript.absorb(b"message", message, &mut ());
+        transcript.challenge_bytes(b"digest")
+    }
+}
+
+impl schnorr::PrehashedHashFunction for SchnorrHashFunction {
+    type Digest = [u8; 32];
+
+    #[inline]
+    fn hash_prehashed(
+        &self,
+        verifying_key: &Group,
+        nonce_point: &Group,
+        digest: &Self::Digest,
+        _: &mut (),
+    ) -> EmbeddedScalar {
+        let mut transcript = Blake2sTranscript::new(b"manta-pay/1.0.0/Schnorr-prehashed");
+        transcript.absorb(
+            b"verifying-key",
+            affine_point_as_bytes::<GroupCurve>(&verifying_key.0).as_slice(),
+            &mut (),
+        );
+        transcript.absorb(
+            b"nonce-point",
+            affine_point_as_bytes::<GroupCurve>(&nonce_point.0).as_slice(),
+            &mut (),
+        );
+        transcript.absorb(b"digest", digest, &mut ());
+        transcript.challenge(b"challenge", &mut ())
     }
 }
 
@@ -1941,12 +2465,23 @@ impl Sample for SchnorrHashFunction {
 }
 
 /// MantaPay Configuration
+///
+/// The domain tags of the Poseidon hash functions are selected by `T`, which defaults to the
+/// [`LegacyDomainTags`] for compatibility with the published parameters and proving keys.
+/// Switching to [`VersionedDomainTags`] requires regenerating all of them.
 #[derive(derivative::Derivative)]
 #[derivative(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Config<COM = ()>(PhantomData<COM>);
+pub struct Config<COM = (), T = LegacyDomainTags>(PhantomData<(COM, T)>);
+
+impl<COM, T> DomainTagsType for Config<COM, T>
+where
+    T: DomainTags,
+{
+    type DomainTags = T;
+}
 
-impl<COM> Constant<COM> for Config<COM> {
-    type Type = Config;
+impl<COM, T> Constant<COM> for Config<COM, T> {
+    type Type = Config<(), T>;
 
     #[inline]
     fn new_constant(this: &Self::Type, compiler: &mut COM) -> Self {
@@ -1955,62 +2490,77 @@ impl<COM> Constant<COM> for Config<COM> {
     }
 }
 
-impl protocol::BaseConfiguration for Config {
+impl<T> protocol::BaseConfiguration for Config<(), T>
+where
+    T: DomainTags,
+{
     type Bool = bool;
     type AssetId = AssetId;
     type AssetValue = AssetValue;
     type Scalar = EmbeddedScalar;
     type Group = Group;
     type GroupGenerator = GroupGenerator;
-    type UtxoCommitmentScheme = UtxoCommitmentScheme;
-    type ViewingKeyDerivationFunction = ViewingKeyDerivationFunction;
-    type IncomingHeader = EmptyHeader;
+    type UtxoCommitmentScheme = UtxoCommitmentScheme<(), T>;
+    type ViewingKeyDerivationFunction = ViewingKeyDerivationFunction<(), T>;
+    type IncomingHeader = NoteHeader;
     type IncomingCiphertext =
         <Self::IncomingBaseEncryptionScheme as encryption::CiphertextType>::Ciphertext;
-    type IncomingBaseEncryptionScheme = IncomingBaseEncryptionScheme;
-    type LightIncomingHeader = EmptyHeader;
-    type LightIncomingBaseEncryptionScheme = IncomingBaseAES;
+    type IncomingBaseEncryptionScheme = IncomingBaseEncryptionScheme<(), T>;
+    type LightIncomingHeader = NoteHeader;
+    type LightIncomingBaseEncryptionScheme = IncomingBaseAES<(), T>;
     type LightIncomingCiphertext =
         <Self::LightIncomingBaseEncryptionScheme as encryption::CiphertextType>::Ciphertext;
-    type UtxoAccumulatorItemHash = UtxoAccumulatorItemHash;
-    type UtxoAccumulatorModel = UtxoAccumulatorModel;
-    type NullifierCommitmentScheme = NullifierCommitmentScheme;
-    type OutgoingHeader = EmptyHeader;
+    type UtxoAccumulatorItemHash = UtxoAccumulatorItemHash<(), T>;
+    type UtxoAccumulatorModel =
+        merkle_tree::Parameters<MerkleTreeConfig<MERKLE_TREE_HEIGHT, MERKLE_FOREST_WIDTH, T>>;
+    type NullifierCommitmentScheme = NullifierCommitmentScheme<(), T>;
+    type OutgoingHeader = NoteHeader;
     type OutgoingCiphertext =
         <Self::OutgoingBaseEncryptionScheme as encryption::CiphertextType>::Ciphertext;
     type OutgoingBaseEncryptionScheme = OutgoingBaseAES;
 }
 
-impl protocol::BaseConfiguration<Compiler> for Config<Compiler> {
+impl<T> protocol::BaseConfiguration<Compiler> for Config<Compiler, T>
+where
+    T: DomainTags,
+{
     type Bool = Boolean<ConstraintField>;
     type AssetId = AssetIdVar;
     type AssetValue = AssetValueVar;
     type Scalar = EmbeddedScalarVar;
     type Group = GroupVar;
     type GroupGenerator = GroupGeneratorVar;
-    type UtxoCommitmentScheme = UtxoCommitmentScheme<Compiler>;
-    type ViewingKeyDerivationFunction = ViewingKeyDerivationFunction<Compiler>;
-    type IncomingHeader = EmptyHeader<Compiler>;
+    type UtxoCommitmentScheme = UtxoCommitmentScheme<Compiler, T>;
+    type ViewingKeyDerivationFunction = ViewingKeyDerivationFunction<Compiler, T>;
+    type IncomingHeader = NoteHeader<Compiler>;
     type IncomingCiphertext =
         <Self::IncomingBaseEncryptionScheme as encryption::CiphertextType>::Ciphertext;
-    type IncomingBaseEncryptionScheme = IncomingBaseEncryptionScheme<Compiler>;
-    type LightIncomingHeader = EmptyHeader<Compiler>;
+    type IncomingBaseEncryptionScheme = IncomingBaseEncryptionScheme<Compiler, T>;
+    type LightIncomingHeader = NoteHeader<Compiler>;
     type LightIncomingCiphertext =
         <Self::LightIncomingBaseEncryptionScheme as encryption::CiphertextType>::Ciphertext;
     type LightIncomingBaseEncryptionScheme =
-        encryption::UnsafeNoEncrypt<IncomingBaseAES<Compiler>, Compiler>;
-    type UtxoAccumulatorItemHash = UtxoAccumulatorItemHash<Compiler>;
-    type UtxoAccumulatorModel = UtxoAccumulatorModelVar;
-    type NullifierCommitmentScheme = NullifierCommitmentScheme<Compiler>;
-    type OutgoingHeader = EmptyHeader<Compiler>;
+        encryption::UnsafeNoEncrypt<IncomingBaseAES<Compiler, T>, Compiler>;
+    type UtxoAccumulatorItemHash = UtxoAccumulatorItemHash<Compiler, T>;
+    type UtxoAccumulatorModel = merkle_tree::Parameters<
+        MerkleTreeConfig<MERKLE_TREE_HEIGHT, MERKLE_FOREST_WIDTH, T>,
+        Compiler,
+    >;
+    type NullifierCommitmentScheme = NullifierCommitmentScheme<Compiler, T>;
+    type OutgoingHeader = NoteHeader<Compiler>;
     type OutgoingCiphertext =
         <Self::OutgoingBaseEncryptionScheme as encryption::CiphertextType>::Ciphertext;
     type OutgoingBaseEncryptionScheme =
         encryption::UnsafeNoEncrypt<OutgoingBaseAES<Compiler>, Compiler>;
 }
 
-impl protocol::Configuration for Config {
-    type AddressPartitionFunction = AddressPartitionFunction;
+impl<T> protocol::Configuration for Config<(), T>
+where
+    T: DomainTags,
+{
+    type AddressPartitionFunction = AddressPartitionFunction<T>;
+    type DetectionKeyDerivationFunction = DetectionKeyDerivationFunction;
+    type DetectionTagFunction = DetectionTagFunction;
     type SchnorrHashFunction = SchnorrHashFunction;
 }
 
@@ -2108,8 +2658,11 @@ impl From<Checkpoint> for RawCheckpoint {
 pub mod test {
     use crate::config::{
         utxo::{
-            Config, IncomingBaseAES, IncomingBaseEncryptionScheme, OutgoingBaseAES,
-            AES_CIPHERTEXT_SIZE, OUT_AES_CIPHERTEXT_SIZE,
+            Config, DetectionTagFunction, DomainTagScheme, DomainTags, DomainTagsType,
+            IncomingBaseAES, IncomingBaseEncryptionScheme, LegacyDomainTags, MerkleTreeConfig,
+            NoteHeader, NullifierCommitmentScheme, OutgoingBaseAES, VersionedDomainTags,
+            AES_CIPHERTEXT_SIZE, LEGACY_DEPLOYMENT, MERKLE_FOREST_WIDTH, MERKLE_TREE_HEIGHT,
+            NOTE_VERSION, OUT_AES_CIPHERTEXT_SIZE,
         },
         ConstraintField, EmbeddedScalar, Group,
     };
@@ -2117,25 +2670,187 @@ pub mod test {
         asset,
         transfer::utxo::{
             protocol::{
-                self, AddressPartitionFunction, UtxoCommitmentScheme, ViewingKeyDerivationFunction,
-                Visibility,
+                self, AddressPartitionFunction, NullifierCommitmentScheme as _,
+                UtxoCommitmentScheme, ViewingKeyDerivationFunction, Visibility,
             },
-            UtxoReconstruct,
+            NoteDetect, UtxoReconstruct,
         },
     };
     use manta_crypto::{
+        accumulator::Accumulator,
         algebra::{HasGenerator, ScalarMul},
         arkworks::constraint::fp::Fp,
-        encryption::{Decrypt, EmptyHeader, Encrypt},
-        rand::{OsRng, Sample},
+        encryption::{Decrypt, Encrypt, SupportedHeader},
+        merkle_tree::{
+            self,
+            forest::{Configuration as _, TreeArrayMerkleForest},
+        },
+        rand::{ChaCha20Rng, OsRng, Sample, SeedableRng},
     };
 
+    /// Checks that the versioned domain tags are distinct for every scheme and that the legacy
+    /// domain tags are all zero.
+    #[test]
+    fn domain_tags_are_distinct() {
+        let schemes = [
+            DomainTagScheme::UtxoCommitmentScheme,
+            DomainTagScheme::ViewingKeyDerivationFunction,
+            DomainTagScheme::UtxoAccumulatorItemHash,
+            DomainTagScheme::InnerHash,
+            DomainTagScheme::NullifierCommitmentScheme,
+        ];
+        for (i, lhs) in schemes.iter().enumerate() {
+            assert_eq!(LegacyDomainTags::domain_tag(*lhs), Fp(0u8.into()));
+            for rhs in &schemes[i + 1..] {
+                assert_ne!(
+                    VersionedDomainTags::domain_tag(*lhs),
+                    VersionedDomainTags::domain_tag(*rhs)
+                );
+            }
+        }
+    }
+
+    /// Samples the parameters of [`Config`] with the domain tags `T` from `seed` and checks that
+    /// every Poseidon hash function uses the domain tag of `T` for its scheme.
+    #[inline]
+    fn sample_with_domain_tags<T>(seed: [u8; 32]) -> protocol::Parameters<Config<(), T>>
+    where
+        T: DomainTags,
+    {
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        let parameters = protocol::Parameters::<Config<(), T>>::gen(&mut rng);
+        let model = merkle_tree::Parameters::<
+            MerkleTreeConfig<MERKLE_TREE_HEIGHT, MERKLE_FOREST_WIDTH, T>,
+        >::gen(&mut rng);
+        for (tag, scheme) in [
+            (
+                parameters.base.utxo_commitment_scheme.0.domain_tag(),
+                DomainTagScheme::UtxoCommitmentScheme,
+            ),
+            (
+                parameters
+                    .base
+                    .viewing_key_derivation_function
+                    .0
+                    .domain_tag(),
+                DomainTagScheme::ViewingKeyDerivationFunction,
+            ),
+            (
+                parameters.base.utxo_accumulator_item_hash.0.domain_tag(),
+                DomainTagScheme::UtxoAccumulatorItemHash,
+            ),
+            (model.inner.domain_tag(), DomainTagScheme::InnerHash),
+            (
+                parameters.base.nullifier_commitment_scheme.0.domain_tag(),
+                DomainTagScheme::NullifierCommitmentScheme,
+            ),
+        ] {
+            assert_eq!(
+                tag,
+                &T::domain_tag(scheme),
+                "Wrong domain tag for {scheme:?}."
+            );
+        }
+        parameters
+    }
+
+    /// Checks that [`Config`] uses the [`LegacyDomainTags`] by default, and that the legacy and
+    /// versioned configurations hash the same inputs to different UTXO commitments.
+    #[test]
+    fn config_domain_tags() {
+        assert_eq!(
+            <<Config as DomainTagsType>::DomainTags as DomainTags>::VERSION,
+            LegacyDomainTags::VERSION
+        );
+        assert_eq!(
+            <<Config<(), VersionedDomainTags> as DomainTagsType>::DomainTags as DomainTags>::VERSION,
+            VersionedDomainTags::VERSION
+        );
+        let mut rng = OsRng;
+        let seed = <[u8; 32]>::gen(&mut rng);
+        let legacy = sample_with_domain_tags::<LegacyDomainTags>(seed);
+        let versioned = sample_with_domain_tags::<VersionedDomainTags>(seed);
+        let randomness = Fp::<ConstraintField>::gen(&mut rng);
+        let asset_id = Fp::<ConstraintField>::gen(&mut rng);
+        let asset_value = u128::gen(&mut rng);
+        let receiving_key = Group::gen(&mut rng);
+        assert_ne!(
+            legacy.base.utxo_commitment_scheme.commit(
+                &randomness,
+                &asset_id,
+                &asset_value,
+                &receiving_key,
+                &mut ()
+            ),
+            versioned.base.utxo_commitment_scheme.commit(
+                &randomness,
+                &asset_id,
+                &asset_value,
+                &receiving_key,
+                &mut ()
+            ),
+            "Different domain tags must give different UTXO commitments."
+        );
+    }
+
+    /// Checks that a custom [`MerkleTreeConfig`] shards leaves within its own forest width and
+    /// proves membership against its own height.
+    #[test]
+    fn custom_merkle_tree_configuration() {
+        type Small = MerkleTreeConfig<4, 4>;
+        let mut rng = OsRng;
+        let model = merkle_tree::Parameters::<Small>::gen(&mut rng);
+        let mut forest =
+            TreeArrayMerkleForest::<Small, merkle_tree::full::Full<Small>, 4>::new(model);
+        assert_eq!(forest.capacity(), 4 << 3);
+        for _ in 0..8 {
+            let item = Fp::<ConstraintField>::gen(&mut rng);
+            assert!(usize::from(Small::tree_index(&item)) < Small::FOREST_WIDTH);
+            assert!(forest.insert(&item), "The forest should not be full.");
+            assert!(forest
+                .prove(&item)
+                .expect("Inserted items must be provable.")
+                .verify(forest.model(), &item, &mut ()));
+        }
+    }
+
+    /// Checks that the deployment identifier separates the nullifiers of the same spending key and
+    /// that the legacy deployment leaves the nullifier commitment scheme unchanged.
+    #[test]
+    fn nullifier_deployment_separation() {
+        let mut rng = OsRng;
+        let scheme = <NullifierCommitmentScheme>::gen(&mut rng);
+        let proof_authorization_key = Group::gen(&mut rng);
+        let item = Fp::<ConstraintField>::gen(&mut rng);
+        assert_eq!(
+            scheme,
+            scheme.clone().with_deployment(LEGACY_DEPLOYMENT),
+            "The legacy deployment must not change the scheme."
+        );
+        let nullifiers = [1, 2].map(|deployment| {
+            scheme.clone().with_deployment(deployment).commit(
+                &proof_authorization_key,
+                &item,
+                &mut (),
+            )
+        });
+        assert_ne!(
+            nullifiers[0], nullifiers[1],
+            "Nullifiers must differ across deployments."
+        );
+        assert_ne!(
+            nullifiers[0],
+            scheme.commit(&proof_authorization_key, &item, &mut ()),
+            "Nullifiers must differ from the legacy deployment."
+        );
+    }
+
     /// Checks that encryption of light incoming notes is well-executed for [`Config`].
     #[test]
     fn check_encryption_light_incoming_notes() {
         let mut rng = OsRng;
         let encryption_key = Group::gen(&mut rng);
-        let header = EmptyHeader::default();
+        let header = NoteHeader::default();
         let base_aes = IncomingBaseAES::default();
         let utxo_commitment_randomness = Fp::<ConstraintField>::gen(&mut rng);
         let asset_id = Fp::<ConstraintField>::gen(&mut rng);
@@ -2176,7 +2891,7 @@ pub mod test {
     fn check_encryption_poseidon() {
         let mut rng = OsRng;
         let encryption_key = Group::gen(&mut rng);
-        let header = EmptyHeader::default();
+        let header = NoteHeader::default();
         let base_poseidon = IncomingBaseEncryptionScheme::gen(&mut rng);
         let utxo_commitment_randomness = Fp::<ConstraintField>::gen(&mut rng);
         let asset_id = Fp::<ConstraintField>::gen(&mut rng);
@@ -2205,12 +2920,61 @@ pub mod test {
         assert_eq!(new_asset_value, asset_value, "Asset value is not the same.");
     }
 
-    /// Checks UTXOs associated with notes are consistent.
-    /// Checks that address partition function is working correctly, while opening notes.
+    /// Checks that incoming notes are decrypted according to the version in their header and that
+    /// versions newer than [`NOTE_VERSION`] are not supported.
     #[test]
-    fn check_note_consistency() {
+    fn check_note_versions() {
         let mut rng = OsRng;
-        let parameters = protocol::Parameters::<Config>::gen(&mut rng);
+        let encryption_key = Group::gen(&mut rng);
+        let base_poseidon = IncomingBaseEncryptionScheme::gen(&mut rng);
+        let plaintext = protocol::IncomingPlaintext::<Config>::new(
+            Fp::<ConstraintField>::gen(&mut rng),
+            asset::Asset {
+                id: Fp::<ConstraintField>::gen(&mut rng),
+                value: u128::gen(&mut rng),
+            },
+        );
+        let latest = NoteHeader::default();
+        let next = NoteHeader::new(NOTE_VERSION + 1);
+        assert_eq!(
+            latest.version(),
+            NOTE_VERSION,
+            "Default header must be the latest."
+        );
+        assert!(
+            latest.is_supported(),
+            "The latest version must be supported."
+        );
+        assert!(
+            !next.is_supported(),
+            "Future versions must not be supported."
+        );
+        let ciphertext = base_poseidon.encrypt(&encryption_key, &(), &latest, &plaintext, &mut ());
+        assert_eq!(
+            base_poseidon
+                .decrypt(&encryption_key, &latest, &ciphertext, &mut ())
+                .expect("Decryption under the encryption header must succeed.")
+                .utxo_commitment_randomness,
+            plaintext.utxo_commitment_randomness,
+            "Randomness is not the same."
+        );
+        assert!(
+            base_poseidon
+                .decrypt(&encryption_key, &next, &ciphertext, &mut ())
+                .is_none(),
+            "Decryption under a different version must fail."
+        );
+    }
+
+    /// Checks UTXOs associated with notes are consistent for [`Config`] with the domain tags `T`.
+    /// Checks that address partition function is working correctly, while opening notes.
+    #[inline]
+    fn note_consistency<T>()
+    where
+        T: DomainTags,
+    {
+        let mut rng = OsRng;
+        let parameters = sample_with_domain_tags::<T>(<[u8; 32]>::gen(&mut rng));
         let group_generator = parameters.base.group_generator.generator();
         let spending_key = EmbeddedScalar::gen(&mut rng);
         let receiving_key = parameters.address_from_spending_key(&spending_key);
@@ -2233,10 +2997,13 @@ pub mod test {
             Visibility::Opaque
         };
         let plaintext =
-            protocol::IncomingPlaintext::<Config>::new(utxo_commitment_randomness, asset);
-        let secret = protocol::MintSecret::<Config>::new(
+            protocol::IncomingPlaintext::<Config<(), T>>::new(utxo_commitment_randomness, asset);
+        let incoming_randomness =
+            protocol::IncomingRandomness::<Config<(), T>>::sample(((), ()), &mut rng);
+        let ephemeral_secret_key = incoming_randomness.ephemeral_secret_key;
+        let secret = protocol::MintSecret::<Config<(), T>>::new(
             receiving_key.receiving_key,
-            protocol::IncomingRandomness::<Config>::sample(((), ()), &mut rng),
+            incoming_randomness,
             plaintext,
         );
         let base_poseidon = parameters.base.incoming_base_encryption_scheme.clone();
@@ -2246,10 +3013,31 @@ pub mod test {
             .partition(&receiving_key);
         let incoming_note = secret.incoming_note(group_generator, &base_poseidon, &mut ());
         let light_incoming_note = secret.light_incoming_note(group_generator, &base_aes, &mut ());
-        let full_incoming_note = protocol::FullIncomingNote::<Config>::new(
+        let detection_tag = protocol::DetectionTagFunction::tag(
+            &DetectionTagFunction,
+            &receiving_key
+                .detection_key
+                .scalar_mul(&ephemeral_secret_key, &mut ()),
+        );
+        let mut full_incoming_note = protocol::FullIncomingNote::<Config<(), T>>::new(
             address_partition,
             incoming_note,
             light_incoming_note,
+            detection_tag,
+        );
+        let detection_key = parameters.derive_detection_key(&decryption_key);
+        assert_ne!(
+            detection_key, decryption_key,
+            "The detection key must not be the decryption key."
+        );
+        assert!(
+            parameters.detect_note(&detection_key, &full_incoming_note),
+            "The note should be detected by its recipient."
+        );
+        full_incoming_note.version = 0;
+        assert!(
+            parameters.detect_note(&EmbeddedScalar::gen(&mut rng), &full_incoming_note),
+            "Notes without a detection tag should be detected by every detection key."
         );
         let utxo_commitment = parameters.base.utxo_commitment_scheme.commit(
             &utxo_commitment_randomness,
@@ -2258,7 +3046,7 @@ pub mod test {
             &receiving_key.receiving_key,
             &mut (),
         );
-        let utxo = protocol::Utxo::<Config>::new(
+        let utxo = protocol::Utxo::<Config<(), T>>::new(
             is_transparent,
             associated_data.public(&asset),
             utxo_commitment,
@@ -2274,13 +3062,21 @@ pub mod test {
         assert_eq!(asset.id, new_asset.id, "Asset id is not the same.");
     }
 
+    /// Checks UTXOs associated with notes are consistent for both the legacy and the versioned
+    /// domain tags.
+    #[test]
+    fn check_note_consistency() {
+        note_consistency::<LegacyDomainTags>();
+        note_consistency::<VersionedDomainTags>();
+    }
+
     /// Checks encryption is properly executed, i.e. that the ciphertext size is consistent with all the parameters, and that
     /// decryption is the inverse of encryption.
     #[test]
     fn check_outgoing_encryption() {
         let mut rng = OsRng;
         let encryption_key = Group::gen(&mut rng);
-        let header = EmptyHeader::default();
+        let header = NoteHeader::default();
         let base_aes = OutgoingBaseAES::default();
         let asset_id = Fp::<ConstraintField>::gen(&mut rng);
         let asset_value = u128::gen(&mut rng);
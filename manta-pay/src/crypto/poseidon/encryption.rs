@@ -28,8 +28,8 @@ use manta_crypto::{
             Allocate, Allocator, Constant, Var, Variable,
         },
         bool::{Assert, Bool},
-        num::Zero,
-        ops::BitAnd,
+        num::{One, Zero},
+        ops::{BitAnd, ConstantTimeEq},
         Has,
     },
     permutation::{
@@ -37,6 +37,7 @@ use manta_crypto::{
         sponge::{Read, Write},
     },
     rand::{Rand, RngCore, Sample},
+    transcript::SpongeTranscript,
 };
 use manta_util::{
     codec::{self, Decode, DecodeError, Encode},
@@ -51,6 +52,12 @@ use manta_util::serde::{Deserialize, Serialize};
 pub type FixedDuplexer<const N: usize, S, COM = ()> =
     duplex::Duplexer<Permutation<S, COM>, FixedEncryption<N, S, COM>, COM>;
 
+/// Poseidon Fiat-Shamir Transcript
+///
+/// Absorbs labels and messages as [`PlaintextBlock`]s and squeezes challenges as [`Tag`]s.
+pub type Transcript<S, COM = ()> =
+    SpongeTranscript<Permutation<S, COM>, PlaintextBlock<S, COM>, COM>;
+
 /// Block Element
 pub trait BlockElement<COM = ()> {
     /// Adds `self` to `rhs`.
@@ -464,6 +471,95 @@ pub type FixedPlaintext<const N: usize, S, COM = ()> = BlockArray<PlaintextBlock
 /// Fixed Ciphertext Type
 pub type FixedCiphertext<const N: usize, S, COM = ()> = BlockArray<CiphertextBlock<S, COM>, N>;
 
+impl<S, COM, const N: usize> BlockArray<PlaintextBlock<S, COM>, N>
+where
+    S: Specification<COM>,
+{
+    /// Returns the number of field elements in every block, which is the rate of the sponge.
+    #[inline]
+    pub fn rate() -> usize {
+        S::WIDTH - 1
+    }
+
+    /// Returns the maximum number of field elements that can be [`pad`](Self::pad)-ded into `N`
+    /// blocks, reserving one element for the padding marker.
+    #[inline]
+    pub fn capacity() -> usize {
+        N * Self::rate() - 1
+    }
+
+    /// Builds the `N`-block plaintext for `message` by appending a one and then filling the rest
+    /// of the last block with zeros, returning `None` if `message` is longer than
+    /// [`capacity`](Self::capacity).
+    ///
+    /// The padding is injective, so [`unpad`](Self::unpad) recovers `message` exactly even if it
+    /// ends with zeros.
+    #[inline]
+    pub fn pad(message: &[S::Field], compiler: &mut COM) -> Option<Self>
+    where
+        S::Field: Clone + One<COM> + Zero<COM>,
+    {
+        if message.len() > Self::capacity() {
+            return None;
+        }
+        let mut elements = Vec::with_capacity(N * Self::rate());
+        elements.extend_from_slice(message);
+        elements.push(One::one(compiler));
+        elements.resize_with(N * Self::rate(), || Zero::zero(compiler));
+        Some(
+            elements
+                .chunks(Self::rate())
+                .map(|block| PlaintextBlock(block.into()))
+                .collect(),
+        )
+    }
+
+    /// Returns `true` if every block of `self` has exactly [`rate`](Self::rate) elements.
+    #[inline]
+    pub fn is_well_formed(&self) -> bool {
+        self.iter().all(|block| block.0.len() == Self::rate())
+    }
+}
+
+impl<S, const N: usize> BlockArray<PlaintextBlock<S>, N>
+where
+    S: Specification,
+    S::Field: Clone + One<Verification = bool> + Zero<Verification = bool>,
+{
+    /// Recovers the message from a plaintext built with [`pad`](Self::pad), returning `None` if
+    /// `self` is not well-formed or does not end with a valid padding.
+    #[inline]
+    pub fn unpad(&self) -> Option<Vec<S::Field>> {
+        if !self.is_well_formed() {
+            return None;
+        }
+        let mut elements = self
+            .iter()
+            .flat_map(|block| block.0.iter().cloned())
+            .collect::<Vec<_>>();
+        while elements.last()?.is_zero(&mut ()) {
+            elements.pop();
+        }
+        elements.pop()?.is_one(&mut ()).then_some(elements)
+    }
+}
+
+impl<S, COM, const N: usize> BlockArray<CiphertextBlock<S, COM>, N>
+where
+    S: Specification<COM>,
+{
+    /// Returns `true` if every block of `self` has exactly as many elements as the rate of the
+    /// sponge.
+    ///
+    /// Duplexing a ciphertext with blocks of the wrong length either panics or ignores some of
+    /// its elements, so ciphertexts from untrusted sources should be checked before they are
+    /// decrypted.
+    #[inline]
+    pub fn is_well_formed(&self) -> bool {
+        self.iter().all(|block| block.0.len() == S::WIDTH - 1)
+    }
+}
+
 /// Authentication Tag
 #[cfg_attr(
     feature = "serde",
@@ -694,20 +790,21 @@ where
     }
 }
 
-impl<const N: usize, S> Verify<Permutation<S>> for FixedEncryption<N, S>
+impl<const N: usize, S, COM> Verify<Permutation<S, COM>, COM> for FixedEncryption<N, S, COM>
 where
-    S: Specification,
-    S::Field: Clone + PartialEq + BlockElement,
+    COM: Has<bool>,
+    S: Specification<COM>,
+    S::Field: Clone + BlockElement<COM> + ConstantTimeEq<S::Field, COM>,
 {
-    type Verification = bool;
+    type Verification = Bool<COM>;
 
     #[inline]
     fn verify(
         &self,
         encryption_tag: &Self::Tag,
         decryption_tag: &Self::Tag,
-        _: &mut (),
+        compiler: &mut COM,
     ) -> Self::Verification {
-        encryption_tag == decryption_tag
+        encryption_tag.0.ct_eq(&decryption_tag.0, compiler)
     }
 }
@@ -17,7 +17,7 @@
 //! Poseidon Hash Implementation
 
 use crate::crypto::poseidon::{
-    Field, FieldGeneration, ParameterFieldType, Permutation, Specification,
+    Field, FieldGeneration, ParameterFieldType, Permutation, Precomputed, Specification,
 };
 use alloc::vec::Vec;
 use core::{fmt::Debug, hash::Hash, marker::PhantomData};
@@ -108,6 +108,29 @@ where
         Self::new(permutation, S::from_parameter(T::domain_tag()))
     }
 
+    /// Builds a new [`Hasher`] over the [`Permutation::precomputed`] permutation using `T` to
+    /// generate the domain tag.
+    #[inline]
+    pub fn precomputed() -> Self
+    where
+        S: Precomputed,
+        S::ParameterField: Clone + 'static,
+    {
+        Self::from_permutation(Permutation::precomputed())
+    }
+
+    /// Returns the domain tag of `self`.
+    #[inline]
+    pub fn domain_tag(&self) -> &S::Field {
+        &self.domain_tag
+    }
+
+    /// Replaces the domain tag of `self` with `domain_tag`.
+    #[inline]
+    pub fn set_domain_tag(&mut self, domain_tag: S::Field) {
+        self.domain_tag = domain_tag;
+    }
+
     /// Computes the hash over `input` in the given `compiler` and returns the untruncated state.
     #[inline]
     pub fn hash_untruncated(&self, input: [&S::Field; ARITY], compiler: &mut COM) -> Vec<S::Field> {
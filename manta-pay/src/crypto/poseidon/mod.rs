@@ -39,6 +39,7 @@ pub mod matrix;
 pub mod mds;
 pub mod preprocessing;
 pub mod round_constants;
+pub mod validation;
 
 #[cfg(feature = "arkworks")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "arkworks")))]
@@ -111,6 +112,21 @@ pub trait ParameterFieldType {
     type ParameterField;
 }
 
+/// Precomputed Parameters
+///
+/// Specifications implementing this trait embed the parameters of their [`Permutation`] as
+/// static data, which must be equal to the parameters generated by its [`Sample`] implementation.
+pub trait Precomputed: Constants + ParameterFieldType
+where
+    Self::ParameterField: 'static,
+{
+    /// Returns the additive round keys.
+    fn additive_round_keys() -> &'static [Self::ParameterField];
+
+    /// Returns the MDS matrix in row-major order.
+    fn mds_matrix() -> &'static [Self::ParameterField];
+}
+
 /// Poseidon Permutation Specification
 pub trait Specification<COM = ()>: Constants + ParameterFieldType {
     /// Field Type used for Permutation State
@@ -349,6 +365,17 @@ where
         }
     }
 
+    /// Builds a new [`Permutation`] from the [`Precomputed`] parameters of `S`, avoiding the
+    /// generation of the round constants and MDS matrix done by [`Sample`].
+    #[inline]
+    pub fn precomputed() -> Self
+    where
+        S: Precomputed,
+        S::ParameterField: Clone + 'static,
+    {
+        Self::new(S::additive_round_keys().into(), S::mds_matrix().into())
+    }
+
     /// Returns the additive keys for the given `round`.
     #[inline]
     pub fn additive_keys(&self, round: usize) -> &[S::ParameterField] {
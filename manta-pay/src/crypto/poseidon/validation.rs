@@ -0,0 +1,376 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Poseidon Parameter Validation
+//!
+//! The parameters generated by [`Sample`](manta_crypto::rand::Sample) follow the Poseidon paper,
+//! but parameters loaded from elsewhere can be arbitrarily weak. This module checks loaded
+//! parameters against the known attack criteria:
+//!
+//! - the number of rounds must resist the attacks in [`security`] for the modulus of the field,
+//! - the MDS matrix must be maximum distance separable, so every square submatrix is invertible,
+//! - no non-trivial subspace of the state may keep the S-box of the partial rounds inactive
+//!   forever, which would make the partial rounds linear on that subspace,
+//! - the additive round keys of any two rounds must differ, to prevent slide attacks.
+//!
+//! [`security`]: crate::crypto::poseidon::constants::security
+
+use crate::crypto::poseidon::{Field, FieldGeneration, Permutation, Specification};
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt::{self, Display};
+
+#[cfg(feature = "std")]
+use crate::crypto::poseidon::constants::{
+    self,
+    security::{Full, SecurityCondition},
+};
+
+/// Poseidon Parameter Error
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ParameterError {
+    /// Invalid Parameter Size
+    ///
+    /// The number of additive round keys or MDS matrix entries does not match the specification.
+    InvalidSize,
+
+    /// Odd Number of Full Rounds
+    ///
+    /// The full rounds are split evenly before and after the partial rounds.
+    OddFullRounds,
+
+    /// Insufficient Number of Full Rounds
+    ///
+    /// The specification has fewer full rounds than the `minimum` required to resist the known
+    /// attacks for its width, number of partial rounds, and field.
+    InsufficientRounds {
+        /// Number of Full Rounds of the Specification
+        full_rounds: usize,
+
+        /// Minimum Secure Number of Full Rounds
+        minimum: usize,
+    },
+
+    /// Singular MDS Submatrix
+    ///
+    /// Some square submatrix of the MDS matrix is singular, so the linear layer does not have the
+    /// maximal branch number.
+    NotMaximumDistanceSeparable,
+
+    /// Invariant Subspace
+    ///
+    /// There is a subspace of the given `dimension` on which the S-box of every partial round is
+    /// inactive, so any number of partial rounds is linear on that subspace.
+    InvariantSubspace {
+        /// Dimension of the Invariant Subspace
+        dimension: usize,
+    },
+
+    /// Repeated Round Keys
+    ///
+    /// The additive round keys of the two given rounds are equal.
+    RepeatedRoundKeys {
+        /// First Round
+        first: usize,
+
+        /// Second Round
+        second: usize,
+    },
+}
+
+impl Display for ParameterError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidSize => write!(f, "Parameters do not have the size of the specification."),
+            Self::OddFullRounds => write!(f, "The number of full rounds must be even."),
+            Self::InsufficientRounds {
+                full_rounds,
+                minimum,
+            } => write!(
+                f,
+                "{full_rounds} full rounds are insecure, at least {minimum} are required."
+            ),
+            Self::NotMaximumDistanceSeparable => {
+                write!(f, "The MDS matrix has a singular square submatrix.")
+            }
+            Self::InvariantSubspace { dimension } => write!(
+                f,
+                "The partial rounds are linear on a subspace of dimension {dimension}."
+            ),
+            Self::RepeatedRoundKeys { first, second } => write!(
+                f,
+                "Rounds {first} and {second} have the same additive round keys."
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for ParameterError {}
+
+/// Returns the rank of the matrix with the given `rows`.
+#[inline]
+fn rank<F>(mut rows: Vec<Vec<F>>) -> usize
+where
+    F: Field,
+{
+    let columns = rows.first().map_or(0, Vec::len);
+    let mut rank = 0;
+    for column in 0..columns {
+        if let Some(pivot) = (rank..rows.len()).find(|i| !rows[*i][column].is_zero()) {
+            rows.swap(rank, pivot);
+            let inverse = rows[rank][column]
+                .inverse()
+                .expect("The pivot is guaranteed to be non-zero.");
+            let (head, tail) = rows.split_at_mut(rank + 1);
+            let pivot_row = &head[rank];
+            for row in tail {
+                if row[column].is_zero() {
+                    continue;
+                }
+                let factor = row[column].mul(&inverse);
+                for (j, pivot_entry) in pivot_row.iter().enumerate().skip(column) {
+                    let difference = factor.mul(pivot_entry);
+                    row[j] = row[j].sub(&difference);
+                }
+            }
+            rank += 1;
+        }
+    }
+    rank
+}
+
+/// Returns the indices of the set bits of `mask` below `width`.
+#[inline]
+fn indices(mask: usize, width: usize) -> impl Iterator<Item = usize> {
+    (0..width).filter(move |i| mask & (1 << i) != 0)
+}
+
+/// Returns `true` if every square submatrix of the `width x width` row-major `matrix` is
+/// invertible.
+///
+/// # Note
+///
+/// This check enumerates all the square submatrices, whose number grows like `binomial(2w, w)`, so
+/// it is only practical for the small widths used by Poseidon.
+#[inline]
+pub fn is_maximum_distance_separable<F>(matrix: &[F], width: usize) -> bool
+where
+    F: Clone + Field,
+{
+    for rows in 1..(1usize << width) {
+        let size = rows.count_ones();
+        for columns in (1..(1usize << width)).filter(|columns| columns.count_ones() == size) {
+            let submatrix = indices(rows, width)
+                .map(|i| {
+                    indices(columns, width)
+                        .map(|j| matrix[width * i + j].clone())
+                        .collect()
+                })
+                .collect();
+            if rank(submatrix) != size as usize {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Returns the dimension of the largest subspace of states on which the S-box of the partial rounds
+/// stays inactive under repeated multiplication by the `width x width` row-major `matrix`.
+///
+/// A state `x` keeps the S-box on its first element inactive for every partial round whenever
+/// `e_0 M^j x = 0` for all `j`, so this subspace is the kernel of the Krylov matrix with rows
+/// `e_0 M^j` for `j < width`.
+#[inline]
+pub fn inactive_subspace_dimension<F>(matrix: &[F], width: usize) -> usize
+where
+    F: Clone + Field,
+{
+    let mut row = vec![F::zero(); width];
+    row[0] = F::one();
+    let mut krylov = Vec::with_capacity(width);
+    for _ in 0..width {
+        let next = (0..width)
+            .map(|j| {
+                row.iter().enumerate().fold(F::zero(), |sum, (i, entry)| {
+                    sum.add(&entry.mul(&matrix[width * i + j]))
+                })
+            })
+            .collect();
+        krylov.push(core::mem::replace(&mut row, next));
+    }
+    width - rank(krylov)
+}
+
+impl<S, COM> Permutation<S, COM>
+where
+    S: Specification<COM>,
+    S::ParameterField: Clone + Field + FieldGeneration + PartialEq,
+{
+    /// Builds a new [`Permutation`] from `additive_round_keys` and `mds_matrix`, returning an
+    /// error if they do not have the right size or fail [`validate`](Self::validate).
+    #[inline]
+    pub fn new_validated(
+        additive_round_keys: Box<[S::ParameterField]>,
+        mds_matrix: Box<[S::ParameterField]>,
+    ) -> Result<Self, ParameterError> {
+        if additive_round_keys.len() != Self::ADDITIVE_ROUND_KEYS_COUNT
+            || mds_matrix.len() != Self::MDS_MATRIX_SIZE
+        {
+            return Err(ParameterError::InvalidSize);
+        }
+        let permutation = Self::new_unchecked(additive_round_keys, mds_matrix);
+        permutation.validate()?;
+        Ok(permutation)
+    }
+
+    /// Checks `self` against the known attack criteria on Poseidon parameters, returning the first
+    /// weakness found. See the [module-level documentation](self) for the list of criteria.
+    ///
+    /// # Note
+    ///
+    /// The number of rounds is only checked with the `std` feature enabled, since the security
+    /// bounds use floating-point arithmetic.
+    #[inline]
+    pub fn validate(&self) -> Result<(), ParameterError> {
+        if S::FULL_ROUNDS % 2 != 0 {
+            return Err(ParameterError::OddFullRounds);
+        }
+        #[cfg(feature = "std")]
+        {
+            let minimum = Full::full_rounds_lower_bound(
+                S::WIDTH as f32,
+                S::PARTIAL_ROUNDS as f32,
+                S::ParameterField::MODULUS_BITS as f32,
+                constants::Constants::SECURITY_LEVEL as f32,
+            );
+            if S::FULL_ROUNDS < minimum {
+                return Err(ParameterError::InsufficientRounds {
+                    full_rounds: S::FULL_ROUNDS,
+                    minimum,
+                });
+            }
+        }
+        if !is_maximum_distance_separable(&self.mds_matrix, S::WIDTH) {
+            return Err(ParameterError::NotMaximumDistanceSeparable);
+        }
+        let dimension = inactive_subspace_dimension(&self.mds_matrix, S::WIDTH);
+        if dimension != 0 {
+            return Err(ParameterError::InvariantSubspace { dimension });
+        }
+        for first in 0..Self::ROUNDS {
+            for second in (first + 1)..Self::ROUNDS {
+                if self.additive_keys(first) == self.additive_keys(second) {
+                    return Err(ParameterError::RepeatedRoundKeys { first, second });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        config::{poseidon::Spec, ConstraintField},
+        crypto::poseidon::Constants,
+    };
+    use manta_crypto::{
+        arkworks::constraint::fp::Fp,
+        rand::{OsRng, Sample},
+    };
+
+    /// Weak Poseidon Specification with too few Rounds
+    struct WeakSpec;
+
+    impl Constants for WeakSpec {
+        const WIDTH: usize = 3;
+        const FULL_ROUNDS: usize = 2;
+        const PARTIAL_ROUNDS: usize = 4;
+    }
+
+    impl crate::crypto::poseidon::arkworks::Specification for WeakSpec {
+        type Field = ConstraintField;
+
+        const SBOX_EXPONENT: u64 = 5;
+    }
+
+    /// Checks that the sampled parameters of the Manta Pay specifications are valid.
+    #[test]
+    fn sampled_parameters_are_valid() {
+        assert_eq!(Permutation::<Spec<2>>::gen(&mut OsRng).validate(), Ok(()));
+        assert_eq!(Permutation::<Spec<3>>::gen(&mut OsRng).validate(), Ok(()));
+        assert_eq!(Permutation::<Spec<4>>::gen(&mut OsRng).validate(), Ok(()));
+        assert_eq!(Permutation::<Spec<5>>::gen(&mut OsRng).validate(), Ok(()));
+    }
+
+    /// Checks that specifications with too few rounds are rejected.
+    #[test]
+    fn insufficient_rounds_are_rejected() {
+        assert!(matches!(
+            Permutation::<WeakSpec>::gen(&mut OsRng).validate(),
+            Err(ParameterError::InsufficientRounds { full_rounds: 2, .. })
+        ));
+    }
+
+    /// Checks that weak MDS matrices and repeated round keys are rejected.
+    #[test]
+    fn weak_parameters_are_rejected() {
+        let permutation = Permutation::<Spec<2>>::gen(&mut OsRng);
+        let keys = permutation.additive_round_keys.clone();
+        let identity = (0..9)
+            .map(|i| if i % 4 == 0 { Fp::one() } else { Fp::zero() })
+            .collect::<Vec<Fp<ConstraintField>>>();
+        assert_eq!(
+            Permutation::<Spec<2>>::new_validated(keys.clone(), identity.into()),
+            Err(ParameterError::NotMaximumDistanceSeparable)
+        );
+        let mut repeated = keys.clone();
+        let (first, second) = repeated.split_at_mut(3);
+        second[..3].clone_from_slice(first);
+        assert_eq!(
+            Permutation::<Spec<2>>::new_validated(repeated, permutation.mds_matrix.clone()),
+            Err(ParameterError::RepeatedRoundKeys {
+                first: 0,
+                second: 1
+            })
+        );
+        assert_eq!(
+            Permutation::<Spec<2>>::new_validated(keys[1..].into(), permutation.mds_matrix),
+            Err(ParameterError::InvalidSize)
+        );
+    }
+
+    /// Checks that an MDS matrix whose partial rounds are linear on a subspace is detected.
+    #[test]
+    fn inactive_subspace_is_detected() {
+        let one = Fp::<ConstraintField>::one();
+        let two = one.add(&one);
+        let zero = Fp::zero();
+        let block_diagonal = [
+            two, zero, zero, //
+            zero, two, one, //
+            zero, one, two, //
+        ];
+        assert_eq!(inactive_subspace_dimension(&block_diagonal, 3), 2);
+        let permutation = Permutation::<Spec<2>>::gen(&mut OsRng);
+        assert_eq!(inactive_subspace_dimension(&permutation.mds_matrix, 3), 0);
+    }
+}
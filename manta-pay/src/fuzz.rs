@@ -0,0 +1,172 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Fuzzing Targets
+//!
+//! Ledger nodes parse [`TransferPost`]s and notes from bytes chosen by whoever submits a
+//! transaction, so decoding and validating them must reject malformed inputs without panicking.
+//! The methods of [`Targets`] take raw bytes and run them through the same decoding and validation
+//! paths as a node, and are meant to be called from `cargo-fuzz` targets, for example:
+//!
+//! ```text
+//! #![no_main]
+//!
+//! use libfuzzer_sys::fuzz_target;
+//! use manta_pay::fuzz::Targets;
+//! use std::sync::OnceLock;
+//!
+//! static TARGETS: OnceLock<Targets> = OnceLock::new();
+//!
+//! fuzz_target!(|data: &[u8]| TARGETS.get_or_init(Targets::new).transfer_post(data));
+//! ```
+
+use crate::{
+    config::{
+        utxo::{IncomingNote, LightIncomingNote},
+        MultiVerifyingContext, Note, Parameters, TransferPost, Utxo,
+    },
+    parameters::{load_transfer_parameters, ParametersVersion},
+};
+use manta_accounting::transfer::{
+    canonical::TransferShape,
+    utxo::{auth::DeriveContext, DeriveDecryptionKey, DeriveMint, NoteDetect, UtxoReconstruct},
+};
+use manta_crypto::rand::{ChaCha20Rng, Rand, SeedableRng};
+
+/// Fuzzing Targets
+///
+/// Holds the parameters and verifying contexts of the latest [`ParametersVersion`], and the keys
+/// of a fixed account which receives a template note, so that mutated notes reach the decryption
+/// code instead of being rejected for belonging to another account.
+pub struct Targets {
+    /// Transfer Parameters
+    parameters: Parameters,
+
+    /// Verifying Contexts
+    verifying_context: MultiVerifyingContext,
+
+    /// Decryption Key of the Fuzzing Account
+    decryption_key: <Parameters as DeriveDecryptionKey>::DecryptionKey,
+
+    /// Template UTXO for the Fuzzing Account
+    utxo: Utxo,
+
+    /// Template Note for the Fuzzing Account
+    note: Note,
+}
+
+impl Targets {
+    /// Seed of the Fuzzing Account
+    pub const SEED: [u8; 32] = *b"manta-pay fuzzing targets seed..";
+
+    /// Builds the fuzzing [`Targets`] for the latest [`ParametersVersion`].
+    #[inline]
+    pub fn new() -> Self {
+        let parameters = load_transfer_parameters();
+        let mut rng = ChaCha20Rng::from_seed(Self::SEED);
+        let spending_key = rng.gen();
+        let mut authorization_context = parameters.derive_context(&spending_key);
+        let decryption_key = parameters.derive_decryption_key(&mut authorization_context);
+        let (_, utxo, note) = parameters.derive_mint(
+            parameters.address_from_spending_key(&spending_key),
+            rng.gen(),
+            Default::default(),
+            &mut rng,
+        );
+        Self {
+            parameters,
+            verifying_context: ParametersVersion::LATEST.load_verifying_context(),
+            decryption_key,
+            utxo,
+            note,
+        }
+    }
+
+    /// Returns the template UTXO and note for the fuzzing account, which can be encoded to seed
+    /// the corpora of [`note`](Self::note) and [`ciphertext`](Self::ciphertext).
+    #[inline]
+    pub fn template(&self) -> (&Utxo, &Note) {
+        (&self.utxo, &self.note)
+    }
+
+    /// Decodes `data` as a JSON or `bincode` encoded [`TransferPost`] and validates it.
+    #[inline]
+    pub fn transfer_post(&self, data: &[u8]) {
+        if let Ok(post) = serde_json::from_slice::<TransferPost>(data) {
+            self.validate(&post);
+        }
+        if let Ok(post) = bincode::deserialize::<TransferPost>(data) {
+            self.validate(&post);
+        }
+    }
+
+    /// Validates the shape and the proof of `post`, returning `true` if both are valid.
+    #[inline]
+    pub fn validate(&self, post: &TransferPost) -> bool {
+        match TransferShape::from_post(post) {
+            Some(shape) => matches!(
+                post.has_valid_proof(self.verifying_context.select(shape)),
+                Ok(true)
+            ),
+            _ => false,
+        }
+    }
+
+    /// Decodes `data` as a `bincode` encoded UTXO and [`Note`] pair, and tries to detect and open
+    /// the note with the keys of the fuzzing account, returning `true` if it opens.
+    #[inline]
+    pub fn note(&self, data: &[u8]) -> bool {
+        match bincode::deserialize::<(Utxo, Note)>(data) {
+            Ok((utxo, note)) => self.open(&utxo, note),
+            _ => false,
+        }
+    }
+
+    /// Decodes `data` as a `bincode` encoded pair of incoming and light incoming ciphertexts, and
+    /// tries to open the template note with its ciphertexts replaced by them, returning `true` if
+    /// it opens.
+    #[inline]
+    pub fn ciphertext(&self, data: &[u8]) -> bool {
+        match bincode::deserialize::<(IncomingNote, LightIncomingNote)>(data) {
+            Ok((incoming_note, light_incoming_note)) => {
+                let mut note = self.note.clone();
+                note.incoming_note = incoming_note;
+                note.light_incoming_note = light_incoming_note;
+                self.open(&self.utxo, note)
+            }
+            _ => false,
+        }
+    }
+
+    /// Tries to detect and open `note` against `utxo`, returning `true` if it opens.
+    #[inline]
+    fn open(&self, utxo: &Utxo, note: Note) -> bool {
+        let _ = self.parameters.detect_note(
+            &self.parameters.derive_detection_key(&self.decryption_key),
+            &note,
+        );
+        self.parameters
+            .open_with_check(&self.decryption_key, utxo, note)
+            .is_some()
+    }
+}
+
+impl Default for Targets {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -24,8 +24,13 @@
 //!
 //! [`BIP-0044`]: https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki
 
-use alloc::{format, string::String, vec::Vec};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::{
+    fmt::{self, Display},
     hash::{Hash, Hasher},
     marker::PhantomData,
 };
@@ -224,6 +229,157 @@ where
     )
 }
 
+/// Maximum Number of Suggestions for an Unknown Mnemonic Word
+pub const MAX_WORD_SUGGESTIONS: usize = 3;
+
+/// Maximum Edit Distance between an Unknown Mnemonic Word and its Suggestions
+///
+/// Shorter words allow fewer edits, one for every three letters of the unknown word.
+pub const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Unknown Mnemonic Word
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UnknownWord {
+    /// Index of the Word in the Phrase, starting at zero
+    pub index: usize,
+
+    /// Unknown Word
+    pub word: String,
+
+    /// Words of the Word List closest to `word`, closest first
+    pub suggestions: Vec<String>,
+}
+
+impl UnknownWord {
+    /// Builds the [`UnknownWord`] at `index` in the phrase, suggesting the closest words of the
+    /// English word list.
+    #[inline]
+    fn new(index: usize, word: &str) -> Self {
+        let max_distance = MAX_SUGGESTION_DISTANCE.min(word.chars().count() / 3);
+        let mut candidates = bip0039::Language::English
+            .words_by_prefix("")
+            .iter()
+            .filter_map(|candidate| {
+                let distance = suggestion_distance(word, candidate);
+                (distance <= max_distance).then_some((distance, *candidate))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_unstable();
+        Self {
+            index,
+            word: word.to_string(),
+            suggestions: candidates
+                .into_iter()
+                .take(MAX_WORD_SUGGESTIONS)
+                .map(|(_, candidate)| candidate.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Returns the distance used to rank `candidate` as a suggestion for the unknown `word`.
+///
+/// Since every word of the English word list is uniquely identified by its first four letters,
+/// candidates which share them with `word` are ranked first. Otherwise, the distance is the
+/// Levenshtein distance between the lowercase `word` and `candidate`.
+#[inline]
+fn suggestion_distance(word: &str, candidate: &str) -> usize {
+    let word = word.to_lowercase();
+    if word.len() >= 4 && word.is_char_boundary(4) && candidate.starts_with(&word[..4]) {
+        return 0;
+    }
+    let candidate = candidate.as_bytes();
+    let mut previous = (0..=candidate.len()).collect::<Vec<_>>();
+    for (i, a) in word.bytes().enumerate() {
+        let mut current = Vec::with_capacity(previous.len());
+        current.push(i + 1);
+        for (j, b) in candidate.iter().enumerate() {
+            current.push(
+                (previous[j] + usize::from(a != *b))
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1),
+            );
+        }
+        previous = current;
+    }
+    previous[candidate.len()]
+}
+
+/// Mnemonic Error
+///
+/// Describes why a phrase is not a valid [`Mnemonic`] precisely enough for wallet restore
+/// interfaces to point the user at the mistake.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MnemonicError {
+    /// Unknown Words
+    ///
+    /// Every word of the phrase which is not in the word list, in the order of the phrase.
+    UnknownWords(Vec<UnknownWord>),
+
+    /// Invalid Word Count
+    ///
+    /// The phrase has the given number of words instead of 12, 15, 18, 21 or 24.
+    InvalidWordCount(usize),
+
+    /// Invalid Checksum
+    ///
+    /// Every word is in the word list but the checksum does not match, so at least one word is
+    /// wrong or the words are out of order.
+    InvalidChecksum,
+}
+
+impl Display for MnemonicError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownWords(words) => {
+                write!(f, "The mnemonic contains unknown words:")?;
+                for word in words {
+                    write!(f, " word {} `{}`", word.index + 1, word.word)?;
+                    if !word.suggestions.is_empty() {
+                        write!(f, " (did you mean {}?)", word.suggestions.join(", "))?;
+                    }
+                    write!(f, ";")?;
+                }
+                Ok(())
+            }
+            Self::InvalidWordCount(count) => write!(
+                f,
+                "The mnemonic has {count} words instead of 12, 15, 18, 21 or 24."
+            ),
+            Self::InvalidChecksum => write!(
+                f,
+                "The mnemonic checksum does not match. Check the words and their order."
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for MnemonicError {}
+
+impl From<Error> for MnemonicError {
+    #[inline]
+    fn from(err: Error) -> Self {
+        match err {
+            Error::BadWordCount(count) => Self::InvalidWordCount(count),
+            Error::UnknownWord(word) => Self::UnknownWords(Vec::from([UnknownWord::new(0, &word)])),
+            _ => Self::InvalidChecksum,
+        }
+    }
+}
+
 /// Mnemonic
 #[cfg_attr(
     feature = "serde",
@@ -239,9 +395,26 @@ pub struct Mnemonic(
 
 impl Mnemonic {
     /// Create a new BIP0039 mnemonic phrase from the given string.
+    ///
+    /// Unknown words are reported before a wrong word count, which is reported before a wrong
+    /// checksum. See [`MnemonicError`] for more.
     #[inline]
-    pub fn new(phrase: &str) -> Result<Self, Error> {
-        bip0039::Mnemonic::from_phrase(phrase).map(Self)
+    pub fn new(phrase: &str) -> Result<Self, MnemonicError> {
+        let unknown_words = phrase
+            .split_whitespace()
+            .enumerate()
+            .filter(|(_, word)| {
+                bip0039::Language::English
+                    .words_by_prefix(word)
+                    .first()
+                    .is_none_or(|candidate| candidate != word)
+            })
+            .map(|(index, word)| UnknownWord::new(index, word))
+            .collect::<Vec<_>>();
+        if !unknown_words.is_empty() {
+            return Err(MnemonicError::UnknownWords(unknown_words));
+        }
+        Ok(Self(bip0039::Mnemonic::from_phrase(phrase)?))
     }
 
     /// Samples a random 12 word [`Mnemonic`] using the entropy returned from `rng`.
@@ -299,7 +472,7 @@ impl Hash for Mnemonic {
 }
 
 impl TryFrom<String> for Mnemonic {
-    type Error = Error;
+    type Error = MnemonicError;
 
     #[inline]
     fn try_from(string: String) -> Result<Self, Self::Error> {
@@ -310,7 +483,9 @@ impl TryFrom<String> for Mnemonic {
 /// Testing framework
 #[cfg(test)]
 pub mod test {
-    use crate::key::{Calamari, CoinType, KeySecret, Manta, Testnet};
+    use crate::key::{
+        Calamari, CoinType, KeySecret, Manta, Mnemonic, MnemonicError, Testnet, UnknownWord,
+    };
     use manta_crypto::rand::{ChaCha12Rng, SeedableRng};
 
     /// Hardcoded testnet secret key value in bytes for the BIP32 0.3.0 version
@@ -354,4 +529,44 @@ pub mod test {
         secret_key_generation::<Manta>(SECRET_KEY_MANTA);
         secret_key_generation::<Calamari>(SECRET_KEY_CALAMARI);
     }
+
+    /// Tests that invalid mnemonic phrases are reported with the matching diagnostics.
+    #[test]
+    fn mnemonic_diagnostics() {
+        let phrase = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+        assert!(Mnemonic::new(phrase).is_ok());
+        assert_eq!(
+            Mnemonic::new("botom drive obey lake curtain smoke basket hold race lonely fit wxyz"),
+            Err(MnemonicError::UnknownWords(vec![
+                UnknownWord {
+                    index: 0,
+                    word: "botom".into(),
+                    suggestions: vec!["bottom".into()],
+                },
+                UnknownWord {
+                    index: 11,
+                    word: "wxyz".into(),
+                    suggestions: vec![],
+                },
+            ]))
+        );
+        assert_eq!(
+            Mnemonic::new(
+                "bottom drive obey lake curtain smoke basket hold race lonely walkin walk"
+            ),
+            Err(MnemonicError::UnknownWords(vec![UnknownWord {
+                index: 10,
+                word: "walkin".into(),
+                suggestions: vec!["walk".into(), "napkin".into()],
+            }]))
+        );
+        assert_eq!(
+            Mnemonic::new("bottom drive obey lake curtain smoke basket hold race lonely fit"),
+            Err(MnemonicError::InvalidWordCount(11))
+        );
+        assert_eq!(
+            Mnemonic::new("drive bottom obey lake curtain smoke basket hold race lonely fit walk"),
+            Err(MnemonicError::InvalidChecksum)
+        );
+    }
 }
@@ -25,10 +25,18 @@ extern crate alloc;
 
 pub mod crypto;
 
+#[cfg(feature = "groth16")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "groth16")))]
+pub mod address;
+
 #[cfg(feature = "groth16")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "groth16")))]
 pub mod config;
 
+#[cfg(feature = "fuzz")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fuzz")))]
+pub mod fuzz;
+
 #[cfg(feature = "key")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "key")))]
 pub mod key;
@@ -49,6 +57,10 @@ pub mod simulation;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "test")))]
 pub mod test;
 
+#[cfg(feature = "wasm")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "wasm")))]
+pub mod wasm;
+
 #[doc(inline)]
 pub use manta_accounting;
 
@@ -18,22 +18,46 @@
 
 use crate::config::{
     utxo::protocol::BaseParameters, FullParametersRef, MultiProvingContext, MultiVerifyingContext,
-    Parameters, PrivateTransfer, ProofSystemError, ToPrivate, ToPublic, UtxoAccumulatorModel,
-    VerifyingContext,
+    Parameters, PrivateTransfer, ProofSystemError, ToPrivate, ToPublic, TransferPost,
+    UtxoAccumulatorModel, VerifyingContext, VersionedVerifier,
 };
+use alloc::vec::Vec;
 use core::fmt::Debug;
+use manta_accounting::transfer::{canonical::TransferShape, input::InputLayout, CircuitVersion};
 use manta_crypto::rand::{ChaCha20Rng, Rand, SeedableRng};
-use manta_parameters::Get;
-use manta_util::codec::Decode;
+use manta_parameters::{Get, HasChecksum};
+use manta_util::codec::{Decode, Encode};
 
 #[cfg(feature = "download")]
-use manta_parameters::Download;
+use {
+    crate::config::Config,
+    manta_accounting::wallet::signer::SharedProvingContextLoader,
+    manta_parameters::{
+        mirror::{default_mirrors, Mirror},
+        Download,
+    },
+    std::sync::Arc,
+};
+
+#[cfg(feature = "compression")]
+use {
+    alloc::string::ToString,
+    manta_crypto::arkworks::{
+        groth16::ProvingKey,
+        serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError},
+    },
+    manta_parameters::container::{ContainerReader, ContainerWriter},
+};
 
 #[cfg(feature = "std")]
 use {
     crate::config::ProvingContext,
     manta_util::codec::IoReader,
-    std::{fs::File, path::Path},
+    std::{
+        fs::{self, File},
+        io,
+        path::{Path, PathBuf},
+    },
 };
 
 /// Parameter Generation Seed
@@ -104,6 +128,427 @@ pub fn generate() -> Result<
     generate_from_seed(SEED)
 }
 
+/// Name of the Checksum File written by [`generate_into_directory`]
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+pub const CHECKSUM_FILE: &str = "checksums.b3";
+
+/// Generated Parameter Artifact
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Artifact {
+    /// Path of the Artifact relative to the Target Directory
+    pub path: PathBuf,
+
+    /// BLAKE3 Checksum of the Artifact
+    pub checksum: [u8; 32],
+}
+
+/// Generates the protocol parameters starting from `seed` and writes them into `directory` with
+/// the same layout as [`manta_parameters`], returning the list of written [`Artifact`]s.
+///
+/// Every artifact is fully overwritten, so that running this function twice with the same `seed`
+/// produces byte-identical files. A [`CHECKSUM_FILE`] in the format of `b3sum` is also written
+/// into `directory`, so the artifacts can be audited with `b3sum --check`.
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[inline]
+pub fn generate_into_directory(seed: [u8; 32], directory: &Path) -> io::Result<Vec<Artifact>> {
+    let (proving_context, verifying_context, parameters, utxo_accumulator_model) =
+        generate_from_seed(seed).map_err(|err| io::Error::other(format!("{err:?}")))?;
+    let artifacts = parameter_artifacts(&parameters, &utxo_accumulator_model)
+        .into_iter()
+        .chain([
+            (
+                "proving/to-private.lfs",
+                proving_context.to_private.to_vec(),
+            ),
+            (
+                "proving/private-transfer.lfs",
+                proving_context.private_transfer.to_vec(),
+            ),
+            ("proving/to-public.lfs", proving_context.to_public.to_vec()),
+        ])
+        .chain(verifying_artifacts(&verifying_context));
+    let mut written = Vec::new();
+    let mut checksums = String::new();
+    for (path, data) in artifacts {
+        let full_path = directory.join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&full_path, &data)?;
+        let checksum = manta_parameters::checksum(&data);
+        for byte in checksum {
+            checksums.push_str(&format!("{byte:02x}"));
+        }
+        checksums.push_str(&format!("  {path}\n"));
+        written.push(Artifact {
+            path: PathBuf::from(path),
+            checksum,
+        });
+    }
+    fs::write(directory.join(CHECKSUM_FILE), checksums)?;
+    Ok(written)
+}
+
+/// Encodes `parameters` and `utxo_accumulator_model` into the artifacts of [`manta_parameters`],
+/// keyed by their path relative to the data directory.
+#[inline]
+fn parameter_artifacts(
+    parameters: &Parameters,
+    utxo_accumulator_model: &UtxoAccumulatorModel,
+) -> [(&'static str, Vec<u8>); 11] {
+    let Parameters {
+        base:
+            BaseParameters {
+                group_generator,
+                utxo_commitment_scheme,
+                incoming_base_encryption_scheme,
+                light_incoming_base_encryption_scheme,
+                viewing_key_derivation_function,
+                utxo_accumulator_item_hash,
+                nullifier_commitment_scheme,
+                outgoing_base_encryption_scheme,
+            },
+        address_partition_function,
+        schnorr_hash_function,
+    } = parameters;
+    [
+        ("parameters/group-generator.dat", group_generator.to_vec()),
+        (
+            "parameters/utxo-commitment-scheme.dat",
+            utxo_commitment_scheme.to_vec(),
+        ),
+        (
+            "parameters/incoming-base-encryption-scheme.dat",
+            incoming_base_encryption_scheme.to_vec(),
+        ),
+        (
+            "parameters/light-incoming-base-encryption-scheme.dat",
+            light_incoming_base_encryption_scheme.to_vec(),
+        ),
+        (
+            "parameters/viewing-key-derivation-function.dat",
+            viewing_key_derivation_function.to_vec(),
+        ),
+        (
+            "parameters/utxo-accumulator-item-hash.dat",
+            utxo_accumulator_item_hash.to_vec(),
+        ),
+        (
+            "parameters/nullifier-commitment-scheme.dat",
+            nullifier_commitment_scheme.to_vec(),
+        ),
+        (
+            "parameters/outgoing-base-encryption-scheme.dat",
+            outgoing_base_encryption_scheme.to_vec(),
+        ),
+        (
+            "parameters/address-partition-function.dat",
+            address_partition_function.to_vec(),
+        ),
+        (
+            "parameters/schnorr-hash-function.dat",
+            schnorr_hash_function.to_vec(),
+        ),
+        (
+            "parameters/utxo-accumulator-model.dat",
+            utxo_accumulator_model.to_vec(),
+        ),
+    ]
+}
+
+/// Encodes `verifying_context` into the artifacts of [`manta_parameters`], keyed by their path
+/// relative to the data directory.
+#[inline]
+fn verifying_artifacts(verifying_context: &MultiVerifyingContext) -> [(&'static str, Vec<u8>); 3] {
+    [
+        (
+            "verifying/to-private.dat",
+            verifying_context.to_private.to_vec(),
+        ),
+        (
+            "verifying/private-transfer.dat",
+            verifying_context.private_transfer.to_vec(),
+        ),
+        (
+            "verifying/to-public.dat",
+            verifying_context.to_public.to_vec(),
+        ),
+    ]
+}
+
+/// Parameter Checksum Registry Entry
+///
+/// Pairs the path of an artifact relative to the [`manta_parameters`] data directory with its
+/// BLAKE3 checksum.
+pub type ChecksumEntry = (&'static str, &'static [u8; 32]);
+
+/// Checksums of the [`ParametersVersion::V1`] Artifacts
+const V1_CHECKSUMS: [ChecksumEntry; 17] = {
+    use manta_parameters::pay::{parameters, proving, verifying};
+    [
+        (
+            "parameters/group-generator.dat",
+            parameters::GroupGenerator::CHECKSUM,
+        ),
+        (
+            "parameters/utxo-commitment-scheme.dat",
+            parameters::UtxoCommitmentScheme::CHECKSUM,
+        ),
+        (
+            "parameters/incoming-base-encryption-scheme.dat",
+            parameters::IncomingBaseEncryptionScheme::CHECKSUM,
+        ),
+        (
+            "parameters/light-incoming-base-encryption-scheme.dat",
+            parameters::LightIncomingBaseEncryptionScheme::CHECKSUM,
+        ),
+        (
+            "parameters/viewing-key-derivation-function.dat",
+            parameters::ViewingKeyDerivationFunction::CHECKSUM,
+        ),
+        (
+            "parameters/utxo-accumulator-item-hash.dat",
+            parameters::UtxoAccumulatorItemHash::CHECKSUM,
+        ),
+        (
+            "parameters/nullifier-commitment-scheme.dat",
+            parameters::NullifierCommitmentScheme::CHECKSUM,
+        ),
+        (
+            "parameters/outgoing-base-encryption-scheme.dat",
+            parameters::OutgoingBaseEncryptionScheme::CHECKSUM,
+        ),
+        (
+            "parameters/address-partition-function.dat",
+            parameters::AddressPartitionFunction::CHECKSUM,
+        ),
+        (
+            "parameters/schnorr-hash-function.dat",
+            parameters::SchnorrHashFunction::CHECKSUM,
+        ),
+        (
+            "parameters/utxo-accumulator-model.dat",
+            parameters::UtxoAccumulatorModel::CHECKSUM,
+        ),
+        ("proving/to-private.lfs", proving::ToPrivate::CHECKSUM),
+        (
+            "proving/private-transfer.lfs",
+            proving::PrivateTransfer::CHECKSUM,
+        ),
+        ("proving/to-public.lfs", proving::ToPublic::CHECKSUM),
+        ("verifying/to-private.dat", verifying::ToPrivate::CHECKSUM),
+        (
+            "verifying/private-transfer.dat",
+            verifying::PrivateTransfer::CHECKSUM,
+        ),
+        ("verifying/to-public.dat", verifying::ToPublic::CHECKSUM),
+    ]
+};
+
+/// Parameters Version
+///
+/// Every circuit upgrade of the protocol publishes a new set of parameters and proving/verifying
+/// keys. Each version is identified by the checksums of its artifacts, so that a serialized
+/// parameter set or a [`TransferPost`] can be matched to the keys it was produced with.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ParametersVersion {
+    /// Initial Parameters
+    ///
+    /// These are the parameters currently published in [`manta_parameters`], which use the legacy
+    /// Poseidon domain tags.
+    V1,
+}
+
+impl ParametersVersion {
+    /// Latest Parameters Version
+    pub const LATEST: Self = Self::V1;
+
+    /// All Known Parameters Versions, from Oldest to Newest
+    pub const ALL: [Self; 1] = [Self::V1];
+
+    /// Returns the [`CircuitVersion`] tag of the [`TransferPost`]s proven with the keys of
+    /// `self`.
+    #[inline]
+    pub const fn circuit_version(self) -> CircuitVersion {
+        match self {
+            Self::V1 => 0,
+        }
+    }
+
+    /// Returns the public input layout of the `shape` circuit published with `self`.
+    #[inline]
+    pub const fn input_layout(self, shape: TransferShape) -> InputLayout {
+        match self {
+            Self::V1 => InputLayout::from_transfer_shape(shape),
+        }
+    }
+
+    /// Returns the checksum registry for `self`.
+    #[inline]
+    pub fn checksums(self) -> &'static [ChecksumEntry] {
+        match self {
+            Self::V1 => &V1_CHECKSUMS,
+        }
+    }
+
+    /// Returns the checksum of the artifact at `path` for `self`, if `path` is part of its
+    /// registry.
+    #[inline]
+    pub fn checksum(self, path: &str) -> Option<&'static [u8; 32]> {
+        self.checksums()
+            .iter()
+            .find(|(entry, _)| *entry == path)
+            .map(|(_, checksum)| *checksum)
+    }
+
+    /// Returns the newest version whose registry contains every artifact in `artifacts` with a
+    /// matching checksum, or `None` if there is no such version or `artifacts` is empty.
+    #[inline]
+    pub fn detect<'a, I>(artifacts: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (&'a str, &'a [u8; 32])>,
+    {
+        let artifacts = artifacts.into_iter().collect::<Vec<_>>();
+        if artifacts.is_empty() {
+            return None;
+        }
+        Self::ALL.into_iter().rev().find(|version| {
+            artifacts
+                .iter()
+                .all(|(path, checksum)| version.checksum(path) == Some(*checksum))
+        })
+    }
+
+    /// Detects the version of `parameters` and `utxo_accumulator_model`.
+    #[inline]
+    pub fn detect_parameters(
+        parameters: &Parameters,
+        utxo_accumulator_model: &UtxoAccumulatorModel,
+    ) -> Option<Self> {
+        Self::detect_encoded(&parameter_artifacts(parameters, utxo_accumulator_model))
+    }
+
+    /// Detects the version of `verifying_context`.
+    #[inline]
+    pub fn detect_verifying_context(verifying_context: &MultiVerifyingContext) -> Option<Self> {
+        Self::detect_encoded(&verifying_artifacts(verifying_context))
+    }
+
+    /// Detects the version of the encoded `artifacts`.
+    #[inline]
+    fn detect_encoded(artifacts: &[(&'static str, Vec<u8>)]) -> Option<Self> {
+        let checksums = artifacts
+            .iter()
+            .map(|(path, data)| (*path, manta_parameters::checksum(data)))
+            .collect::<Vec<_>>();
+        Self::detect(checksums.iter().map(|(path, checksum)| (*path, checksum)))
+    }
+
+    /// Loads the transfer [`Parameters`] for `self`.
+    #[inline]
+    pub fn load_transfer_parameters(self) -> Parameters {
+        match self {
+            Self::V1 => load_transfer_parameters(),
+        }
+    }
+
+    /// Loads the [`UtxoAccumulatorModel`] for `self`.
+    #[inline]
+    pub fn load_utxo_accumulator_model(self) -> UtxoAccumulatorModel {
+        match self {
+            Self::V1 => load_utxo_accumulator_model(),
+        }
+    }
+
+    /// Loads the [`MultiVerifyingContext`] for `self`.
+    #[inline]
+    pub fn load_verifying_context(self) -> MultiVerifyingContext {
+        match self {
+            Self::V1 => MultiVerifyingContext {
+                to_private: load_to_private_verifying_context(),
+                private_transfer: load_private_transfer_verifying_context(),
+                to_public: load_to_public_verifying_context(),
+            },
+        }
+    }
+}
+
+/// Versioned Verifying Contexts
+///
+/// Keeps the [`MultiVerifyingContext`] of several [`ParametersVersion`]s in memory so that ledger
+/// nodes can validate [`TransferPost`]s produced before and after a circuit upgrade.
+#[derive(Clone, Debug, Default)]
+pub struct VersionedVerifyingContexts {
+    /// Verifying Contexts, from Oldest to Newest Version
+    contexts: Vec<(ParametersVersion, MultiVerifyingContext)>,
+}
+
+impl VersionedVerifyingContexts {
+    /// Loads the verifying contexts of all the [`ParametersVersion`]s in `versions`.
+    #[inline]
+    pub fn load<I>(versions: I) -> Self
+    where
+        I: IntoIterator<Item = ParametersVersion>,
+    {
+        let mut contexts = Self::default();
+        for version in versions {
+            contexts.insert(version, version.load_verifying_context());
+        }
+        contexts
+    }
+
+    /// Loads the verifying contexts of every known [`ParametersVersion`].
+    #[inline]
+    pub fn load_all() -> Self {
+        Self::load(ParametersVersion::ALL)
+    }
+
+    /// Inserts `verifying_context` for `version`, replacing the previous one if it exists.
+    #[inline]
+    pub fn insert(&mut self, version: ParametersVersion, verifying_context: MultiVerifyingContext) {
+        match self.contexts.binary_search_by_key(&version, |(v, _)| *v) {
+            Ok(index) => self.contexts[index].1 = verifying_context,
+            Err(index) => self.contexts.insert(index, (version, verifying_context)),
+        }
+    }
+
+    /// Returns the verifying context for `version` if it has been loaded.
+    #[inline]
+    pub fn get(&self, version: ParametersVersion) -> Option<&MultiVerifyingContext> {
+        self.contexts
+            .binary_search_by_key(&version, |(v, _)| *v)
+            .ok()
+            .map(|index| &self.contexts[index].1)
+    }
+
+    /// Builds a [`VersionedVerifier`] which dispatches on the [`CircuitVersion`] tag of every
+    /// [`TransferPost`] to the verifying contexts in `self`.
+    #[inline]
+    pub fn verifier(&self) -> VersionedVerifier {
+        let mut verifier = VersionedVerifier::default();
+        for (version, context) in &self.contexts {
+            verifier.insert(version.circuit_version(), context.clone());
+        }
+        verifier
+    }
+
+    /// Returns the newest [`ParametersVersion`] under which `post` has a valid proof, or `None`
+    /// if `post` has an invalid shape or no loaded version accepts it.
+    #[inline]
+    pub fn detect_post(&self, post: &TransferPost) -> Option<ParametersVersion> {
+        let shape = TransferShape::from_post(post)?;
+        self.contexts
+            .iter()
+            .rev()
+            .find(|(_, context)| matches!(post.has_valid_proof(context.select(shape)), Ok(true)))
+            .map(|(version, _)| *version)
+    }
+}
+
 /// Loads parameters from [`manta-parameters`], using `directory` as a temporary directory to store files.
 #[cfg(feature = "download")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "download")))]
@@ -171,6 +616,56 @@ pub fn try_load_proving_context(directory: &Path) -> MultiProvingContext {
     decode_proving_context(&to_private_path, &private_transfer_path, &to_public_path)
 }
 
+/// Builds a [`SharedProvingContextLoader`] which downloads and decodes the proving context for a
+/// single [`TransferShape`] from [`manta_parameters`] the first time a signer needs it, using
+/// `directory` to store files.
+///
+/// Files which have been downloaded before and whose checksum matches the expected one are reused.
+/// See [`try_load_proving_context`] for the eager equivalent.
+#[cfg(feature = "download")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "download")))]
+#[inline]
+pub fn proving_context_loader(directory: PathBuf) -> SharedProvingContextLoader<Config> {
+    proving_context_loader_with_mirrors(directory, default_mirrors())
+}
+
+/// Builds a [`SharedProvingContextLoader`] like [`proving_context_loader`] which downloads the
+/// proving contexts from the first of the `mirrors` that serves them, resuming interrupted
+/// downloads and verifying their checksums. Use [`cache_directory`] as `directory` to share the
+/// downloaded files between runs.
+///
+/// [`cache_directory`]: manta_parameters::mirror::cache_directory
+#[cfg(feature = "download")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "download")))]
+#[inline]
+pub fn proving_context_loader_with_mirrors(
+    directory: PathBuf,
+    mirrors: Vec<Mirror>,
+) -> SharedProvingContextLoader<Config> {
+    Arc::new(move |shape| {
+        use manta_parameters::pay::proving;
+        fs::create_dir_all(&directory).ok()?;
+        let path = match shape {
+            TransferShape::ToPrivate => {
+                let path = directory.join("to-private.dat");
+                proving::ToPrivate::download_from_mirrors_if_invalid(&mirrors, &path).ok()?;
+                path
+            }
+            TransferShape::PrivateTransfer => {
+                let path = directory.join("private-transfer.dat");
+                proving::PrivateTransfer::download_from_mirrors_if_invalid(&mirrors, &path).ok()?;
+                path
+            }
+            TransferShape::ToPublic => {
+                let path = directory.join("to-public.dat");
+                proving::ToPublic::download_from_mirrors_if_invalid(&mirrors, &path).ok()?;
+                path
+            }
+        };
+        ProvingContext::decode(IoReader(io::BufReader::new(File::open(path).ok()?))).ok()
+    })
+}
+
 /// Decodes [`MultiProvingContext`] by loading from `to_private_path`, `private_transfer_path`, and
 /// `to_public_path`.
 #[cfg(feature = "std")]
@@ -182,22 +677,134 @@ pub fn decode_proving_context(
     to_public_path: &Path,
 ) -> MultiProvingContext {
     MultiProvingContext {
-        to_private: ProvingContext::decode(IoReader(
+        to_private: ProvingContext::decode(IoReader(io::BufReader::new(
             File::open(to_private_path).expect("Unable to open ToPrivate proving context file."),
-        ))
+        )))
         .expect("Unable to decode ToPrivate proving context."),
-        private_transfer: ProvingContext::decode(IoReader(
+        private_transfer: ProvingContext::decode(IoReader(io::BufReader::new(
             File::open(private_transfer_path)
                 .expect("Unable to open PrivateTransfer proving context file."),
-        ))
+        )))
         .expect("Unable to decode PrivateTransfer proving context."),
-        to_public: ProvingContext::decode(IoReader(
+        to_public: ProvingContext::decode(IoReader(io::BufReader::new(
             File::open(to_public_path).expect("Unable to open ToPublic proving context file."),
-        ))
+        )))
         .expect("Unable to decode ToPublic proving context."),
     }
 }
 
+/// Proving Key Sections of the Compressed Proving Context Container
+///
+/// Every field of the Groth16 proving key is stored in its own section, in the order of this list.
+#[cfg(feature = "compression")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "compression")))]
+pub const PROVING_CONTEXT_SECTIONS: [&str; 8] = [
+    "vk",
+    "beta_g1",
+    "delta_g1",
+    "a_query",
+    "b_g1_query",
+    "b_g2_query",
+    "h_query",
+    "l_query",
+];
+
+/// Converts an arkworks serialization `error` into an I/O error.
+#[cfg(feature = "compression")]
+#[inline]
+fn serialization_error(error: SerializationError) -> io::Error {
+    match error {
+        SerializationError::IoError(err) => err,
+        err => io::Error::new(io::ErrorKind::InvalidData, err.to_string()),
+    }
+}
+
+/// Writes `proving_context` into `writer` as a [`container`](manta_parameters::container) with one section for every entry of
+/// [`PROVING_CONTEXT_SECTIONS`], returning `writer` when it is done.
+///
+/// Curve points are stored in compressed form, which together with the zstd frames of the
+/// container roughly halves the size of the [`Encode`] format of the proving context.
+#[cfg(feature = "compression")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "compression")))]
+#[inline]
+pub fn encode_compressed_proving_context<W>(
+    proving_context: &ProvingContext,
+    writer: W,
+) -> io::Result<W>
+where
+    W: io::Write,
+{
+    /// Serializes `value` with compressed points and writes it as the section `name`.
+    #[inline]
+    fn write<T, W>(writer: &mut ContainerWriter<W>, name: &str, value: &T) -> io::Result<()>
+    where
+        T: CanonicalSerialize,
+        W: io::Write,
+    {
+        let mut buffer = Vec::with_capacity(value.serialized_size());
+        CanonicalSerialize::serialize(value, &mut buffer).map_err(serialization_error)?;
+        writer.write_section(name, &buffer)
+    }
+    let proving_key = proving_context.proving_key();
+    let [vk, beta_g1, delta_g1, a_query, b_g1_query, b_g2_query, h_query, l_query] =
+        PROVING_CONTEXT_SECTIONS;
+    let mut writer = ContainerWriter::new(writer)?;
+    write(&mut writer, vk, &proving_key.vk)?;
+    write(&mut writer, beta_g1, &proving_key.beta_g1)?;
+    write(&mut writer, delta_g1, &proving_key.delta_g1)?;
+    write(&mut writer, a_query, &proving_key.a_query)?;
+    write(&mut writer, b_g1_query, &proving_key.b_g1_query)?;
+    write(&mut writer, b_g2_query, &proving_key.b_g2_query)?;
+    write(&mut writer, h_query, &proving_key.h_query)?;
+    write(&mut writer, l_query, &proving_key.l_query)?;
+    writer.finish()
+}
+
+/// Reads a proving context from the [`container`](manta_parameters::container) in `reader`, decompressing and verifying the
+/// checksum of one section at a time.
+///
+/// See [`encode_compressed_proving_context`] for the layout of the container.
+#[cfg(feature = "compression")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "compression")))]
+#[inline]
+pub fn decode_compressed_proving_context<R>(reader: R) -> io::Result<ProvingContext>
+where
+    R: io::Read,
+{
+    /// Reads the section `name` and deserializes it with compressed points.
+    #[inline]
+    fn read<T, R>(reader: &mut ContainerReader<R>, name: &str) -> io::Result<T>
+    where
+        T: CanonicalDeserialize,
+        R: io::Read,
+    {
+        let mut section = reader.expect_section(name)?;
+        let value = CanonicalDeserialize::deserialize(&mut section).map_err(serialization_error)?;
+        section.finish()?;
+        Ok(value)
+    }
+    let [vk, beta_g1, delta_g1, a_query, b_g1_query, b_g2_query, h_query, l_query] =
+        PROVING_CONTEXT_SECTIONS;
+    let mut reader = ContainerReader::new(reader)?;
+    let proving_key = ProvingKey {
+        vk: read(&mut reader, vk)?,
+        beta_g1: read(&mut reader, beta_g1)?,
+        delta_g1: read(&mut reader, delta_g1)?,
+        a_query: read(&mut reader, a_query)?,
+        b_g1_query: read(&mut reader, b_g1_query)?,
+        b_g2_query: read(&mut reader, b_g2_query)?,
+        h_query: read(&mut reader, h_query)?,
+        l_query: read(&mut reader, l_query)?,
+    };
+    if reader.next_section()?.is_some() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Unexpected section after the proving key.",
+        ));
+    }
+    Ok(ProvingContext::new(proving_key))
+}
+
 /// Loads the [`ToPrivate`] verifying contexts from [`manta_parameters`].
 #[inline]
 pub fn load_to_private_verifying_context() -> VerifyingContext {
@@ -19,7 +19,8 @@
 use crate::{
     config::{
         utxo::{self, MerkleTreeConfiguration},
-        AccountId, Address, Config, IdentifiedAsset, IdentityProof, Nullifier, Parameters,
+        AccountId, Address, Config, FullParametersRef, IdentifiedAsset, IdentityProof, Nullifier,
+        Parameters, ProofSystemError, ProvingContext, SpendingKey, TransferPost,
         UtxoAccumulatorModel, VerifyingContext,
     },
     key::{CoinType, KeySecret, Testnet},
@@ -30,10 +31,14 @@ use core::{cmp, mem};
 use manta_accounting::{
     asset::BTreeAssetMap,
     key::{AccountCollection, AccountIndex, DeriveAddresses},
-    transfer::{utxo::protocol, Identifier, IdentityVerificationError, SpendingKey},
+    transfer::{utxo::protocol, Identifier, IdentityVerificationError},
     wallet::{
         self,
-        signer::{self, SyncData},
+        signer::{
+            self,
+            prover::{BatchProver, ProofJob, SequentialProver},
+            SyncData,
+        },
     },
 };
 use manta_crypto::{
@@ -47,11 +52,18 @@ use manta_crypto::{
     rand::ChaCha20Rng,
 };
 
+#[cfg(feature = "rayon")]
+use {
+    alloc::sync::Arc,
+    manta_crypto::rand::Rand,
+    manta_util::rayon::{prelude::*, ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder},
+};
+
 impl<C> AccountCollection for KeySecret<C>
 where
     C: CoinType,
 {
-    type SpendingKey = SpendingKey<crate::config::Config>;
+    type SpendingKey = SpendingKey;
 
     #[inline]
     fn spending_key(&self, index: &AccountIndex) -> Self::SpendingKey {
@@ -92,6 +104,92 @@ impl wallet::signer::Configuration for Config {
     type NullifierMap = Vec<Nullifier>;
     type AssetMetadata = AssetMetadata;
     type Rng = ChaCha20Rng;
+    type Prover = Prover;
+}
+
+/// Signer Batch Prover
+///
+/// Generates the proofs of the transfers of a multi-post transaction. When the `rayon` feature is
+/// enabled, the proofs are generated concurrently, either on the global thread pool or on the one
+/// given to [`Prover::with_thread_pool`]. Otherwise, they are generated one after the other.
+#[derive(Clone, Debug, Default)]
+pub struct Prover {
+    /// Thread Pool
+    #[cfg(feature = "rayon")]
+    thread_pool: Option<Arc<ThreadPool>>,
+}
+
+#[cfg(feature = "rayon")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rayon")))]
+impl Prover {
+    /// Builds a new [`Prover`] which generates proofs on `thread_pool`.
+    #[inline]
+    pub fn with_thread_pool(thread_pool: Arc<ThreadPool>) -> Self {
+        Self {
+            thread_pool: Some(thread_pool),
+        }
+    }
+
+    /// Builds a new [`Prover`] which generates proofs on a dedicated thread pool with
+    /// `num_threads` threads.
+    #[inline]
+    pub fn with_num_threads(num_threads: usize) -> Result<Self, ThreadPoolBuildError> {
+        Ok(Self::with_thread_pool(Arc::new(
+            ThreadPoolBuilder::new().num_threads(num_threads).build()?,
+        )))
+    }
+
+    /// Returns the dedicated thread pool of `self`, if any.
+    #[inline]
+    pub fn thread_pool(&self) -> Option<&ThreadPool> {
+        self.thread_pool.as_deref()
+    }
+}
+
+impl BatchProver<Config> for Prover {
+    #[inline]
+    fn prove_batch<
+        const SOURCES: usize,
+        const SENDERS: usize,
+        const RECEIVERS: usize,
+        const SINKS: usize,
+    >(
+        &self,
+        parameters: FullParametersRef,
+        proving_context: &ProvingContext,
+        spending_key: Option<&SpendingKey>,
+        batch: Vec<ProofJob<Config, SOURCES, SENDERS, RECEIVERS, SINKS>>,
+        rng: &mut ChaCha20Rng,
+    ) -> Result<Vec<TransferPost>, ProofSystemError> {
+        #[cfg(feature = "rayon")]
+        if batch.len() > 1 {
+            // NOTE: Each job gets its own generator seeded from `rng`, in the order of `batch`, so
+            //       that the proofs do not depend on the scheduling of the thread pool.
+            let batch = batch
+                .into_iter()
+                .map(|job| {
+                    (
+                        job,
+                        rng.seed_rng::<ChaCha20Rng>()
+                            .expect("Seeding from a ChaCha20Rng never fails."),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let prove = move || {
+                batch
+                    .into_par_iter()
+                    .map(|(job, mut rng)| {
+                        job.prove(parameters, proving_context, spending_key, &mut rng)
+                    })
+                    .collect()
+            };
+            return match self.thread_pool() {
+                Some(thread_pool) => thread_pool.install(prove),
+                _ => prove(),
+            };
+        }
+        SequentialProver.prove_batch(parameters, proving_context, spending_key, batch, rng)
+    }
 }
 
 impl signer::Checkpoint<Config> for Checkpoint {
@@ -0,0 +1,415 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Ledger Fault Injection
+//!
+//! The [`FaultyLedger`] wraps a simulation [`Ledger`] and disturbs the traffic between the wallets
+//! and the ledger according to a [`FaultConfig`]. Every fault is drawn from a seeded [`TestRng`], so
+//! an adversarial schedule can be replayed from the seed of the simulation.
+
+use crate::{
+    config::{
+        utxo::{AssetId, AssetValue, Checkpoint},
+        AccountId, Config, TransferPost,
+    },
+    signer::InitialSyncData,
+    simulation::ledger::Ledger,
+};
+use alloc::{sync::Arc, vec::Vec};
+use core::convert::Infallible;
+use manta_accounting::{
+    asset::AssetList,
+    wallet::{
+        ledger::{self, ReadResponse, SyncConfig},
+        signer::SyncData,
+        test::PublicBalanceOracle,
+    },
+};
+use manta_crypto::rand::{Rand, TestRng};
+use manta_util::future::{LocalBoxFuture, LocalBoxFutureResult};
+use tokio::sync::RwLock;
+
+/// Fault Configuration
+///
+/// Every rate is the percentage of the corresponding events which are disturbed.
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FaultConfig {
+    /// Percentage of sync batches which are dropped in transit and have to be requested again
+    #[cfg_attr(
+        feature = "clap",
+        arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..100))
+    )]
+    pub dropped_sync_batches: u8,
+
+    /// Percentage of writes whose posts reach the ledger in a random order
+    #[cfg_attr(
+        feature = "clap",
+        arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=100))
+    )]
+    pub reordered_posts: u8,
+
+    /// Percentage of reads which are preceded by a reorganization of the blocks which no wallet
+    /// has read yet
+    #[cfg_attr(
+        feature = "clap",
+        arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=100))
+    )]
+    pub reorgs: u8,
+
+    /// Percentage of accepted writes whose posts are submitted to the ledger a second time
+    #[cfg_attr(
+        feature = "clap",
+        arg(long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=100))
+    )]
+    pub duplicate_submissions: u8,
+}
+
+impl FaultConfig {
+    /// Returns `true` if `self` injects any faults.
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        *self != Self::default()
+    }
+}
+
+/// Fault Report
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FaultReport {
+    /// Number of Dropped Sync Batches
+    pub dropped_sync_batches: usize,
+
+    /// Number of Writes whose Posts were Reordered
+    pub reordered_posts: usize,
+
+    /// Number of Reorganizations
+    pub reorgs: usize,
+
+    /// Number of Blocks which were Orphaned by a Reorganization and not Included Again
+    pub orphaned_blocks: usize,
+
+    /// Number of Duplicate Submissions
+    pub duplicate_submissions: usize,
+
+    /// Number of Duplicate Submissions which were Accepted by the Ledger
+    pub accepted_duplicates: usize,
+
+    /// Number of Accepted Writes which Spent an Already Spent Nullifier
+    pub double_spends: usize,
+}
+
+impl FaultReport {
+    /// Returns `true` if the ledger upheld its invariants under the injected faults, i.e. it never
+    /// accepted a duplicate submission or a post which spends an already spent nullifier.
+    #[inline]
+    pub fn is_sound(&self) -> bool {
+        self.accepted_duplicates == 0 && self.double_spends == 0
+    }
+}
+
+/// Block
+#[derive(Clone, Debug)]
+struct Block {
+    /// Submitting Account
+    account: AccountId,
+
+    /// Transfer Posts
+    posts: Vec<TransferPost>,
+}
+
+/// Unobserved Chain Tip
+#[derive(Debug)]
+struct Tip {
+    /// Ledger State before the First Block of the Tip
+    snapshot: Ledger,
+
+    /// Blocks which were Written after the Snapshot
+    blocks: Vec<Block>,
+}
+
+/// Faulty Ledger
+#[derive(Debug)]
+pub struct FaultyLedger {
+    /// Ledger
+    ledger: Ledger,
+
+    /// Blocks which have not been Read by any Wallet
+    tip: Option<Tip>,
+
+    /// Fault Configuration
+    config: FaultConfig,
+
+    /// Fault Schedule Random Number Generator
+    rng: TestRng,
+
+    /// Fault Report
+    report: FaultReport,
+}
+
+impl FaultyLedger {
+    /// Builds a new [`FaultyLedger`] which disturbs the traffic to `ledger` according to `config`,
+    /// drawing the faults from `rng`.
+    #[inline]
+    pub fn new(ledger: Ledger, config: FaultConfig, rng: TestRng) -> Self {
+        assert!(
+            config.dropped_sync_batches < 100,
+            "Dropping every sync batch would stall the wallets."
+        );
+        Self {
+            ledger,
+            tip: None,
+            config,
+            rng,
+            report: Default::default(),
+        }
+    }
+
+    /// Returns the underlying ledger.
+    #[inline]
+    pub fn ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    /// Returns the report of the faults injected so far.
+    #[inline]
+    pub fn report(&self) -> FaultReport {
+        self.report
+    }
+
+    /// Returns `true` with a probability of `percentage` percent.
+    #[inline]
+    fn chance(&mut self, percentage: u8) -> bool {
+        percentage > 0 && self.rng.gen_range(0..100) < percentage
+    }
+
+    /// Shuffles `items` in place.
+    #[inline]
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.rng.gen_range(0..=i));
+        }
+    }
+
+    /// Pushes `posts` to the ledger, recording a double spend if the ledger accepts a post whose
+    /// nullifier was already spent.
+    #[inline]
+    fn apply(&mut self, account: AccountId, posts: Vec<TransferPost>) -> bool {
+        let spends_spent_nullifier = posts
+            .iter()
+            .flat_map(|post| &post.body.sender_posts)
+            .any(|sender_post| self.ledger.nullifiers.contains(&sender_post.nullifier));
+        let is_accepted = self.ledger.push(account, posts);
+        if is_accepted && spends_spent_nullifier {
+            self.report.double_spends += 1;
+        }
+        is_accepted
+    }
+
+    /// Records the block with `posts` from `account` in the unobserved tip if reorganizations are
+    /// enabled, and then pushes it to the ledger.
+    #[inline]
+    fn apply_block(&mut self, account: AccountId, posts: Vec<TransferPost>) -> bool {
+        if self.config.reorgs > 0 {
+            self.tip
+                .get_or_insert_with(|| Tip {
+                    snapshot: self.ledger.clone(),
+                    blocks: Vec::new(),
+                })
+                .blocks
+                .push(Block {
+                    account,
+                    posts: posts.clone(),
+                });
+        }
+        self.apply(account, posts)
+    }
+
+    /// Marks every block as read, first reorganizing the unobserved tip if a reorganization is
+    /// scheduled. A reorganization rolls the ledger back to the state before the tip and includes
+    /// each of its blocks again in a random order with a probability of one half.
+    #[inline]
+    fn observe(&mut self) {
+        let tip = match self.tip.take() {
+            Some(tip) => tip,
+            _ => return,
+        };
+        if !self.chance(self.config.reorgs) {
+            return;
+        }
+        self.report.reorgs += 1;
+        self.ledger = tip.snapshot;
+        let mut blocks = tip.blocks;
+        self.shuffle(&mut blocks);
+        for block in blocks {
+            if !(self.chance(50) && self.apply(block.account, block.posts)) {
+                self.report.orphaned_blocks += 1;
+            }
+        }
+    }
+
+    /// Pushes `posts` from `account` to the ledger, possibly in a random order, and possibly
+    /// submitting them a second time once they are accepted.
+    #[inline]
+    pub fn push(&mut self, account: AccountId, mut posts: Vec<TransferPost>) -> bool {
+        if posts.len() > 1 && self.chance(self.config.reordered_posts) {
+            self.report.reordered_posts += 1;
+            self.shuffle(&mut posts);
+        }
+        let duplicate = posts.clone();
+        let is_accepted = self.apply_block(account, posts);
+        if is_accepted && self.chance(self.config.duplicate_submissions) {
+            self.report.duplicate_submissions += 1;
+            if self.apply_block(account, duplicate) {
+                self.report.accepted_duplicates += 1;
+            }
+        }
+        is_accepted
+    }
+
+    /// Pulls the data from the ledger later than the given `checkpoint`. See [`Ledger::pull`] for
+    /// more. Dropped batches arrive empty with the
+    /// [`should_continue`](ReadResponse::should_continue) flag set, so that the wallet requests
+    /// them again.
+    #[inline]
+    pub fn pull(
+        &mut self,
+        checkpoint: &Checkpoint,
+        batch_size: usize,
+    ) -> ReadResponse<SyncData<Config>> {
+        self.observe();
+        if self.chance(self.config.dropped_sync_batches) {
+            self.report.dropped_sync_batches += 1;
+            return ReadResponse {
+                should_continue: true,
+                data: Default::default(),
+                height: None,
+            };
+        }
+        self.ledger.pull(checkpoint, batch_size)
+    }
+
+    /// Pulls the data from the ledger necessary to perform an
+    /// [`initial_sync`](manta_accounting::wallet::signer::Connection::initial_sync).
+    #[inline]
+    pub fn initial_read(&mut self) -> ReadResponse<InitialSyncData> {
+        self.observe();
+        self.ledger.initial_read()
+    }
+
+    /// Returns the public balances of `account` if it exists.
+    #[inline]
+    pub fn public_balances(
+        &mut self,
+        account: AccountId,
+    ) -> Option<AssetList<AssetId, AssetValue>> {
+        self.observe();
+        self.ledger.public_balances(account)
+    }
+}
+
+/// Shared Faulty Ledger
+pub type SharedFaultyLedger = Arc<RwLock<FaultyLedger>>;
+
+/// Faulty Ledger Connection
+#[derive(Clone, Debug)]
+pub struct FaultyLedgerConnection {
+    /// Ledger Account
+    account: AccountId,
+
+    /// Ledger Accessor
+    ledger: SharedFaultyLedger,
+
+    /// Synchronization Configuration
+    sync_config: SyncConfig,
+}
+
+impl FaultyLedgerConnection {
+    /// Builds a new [`FaultyLedgerConnection`] for `account` and `ledger`.
+    #[inline]
+    pub fn new(account: AccountId, ledger: SharedFaultyLedger) -> Self {
+        Self {
+            account,
+            ledger,
+            sync_config: Default::default(),
+        }
+    }
+}
+
+impl ledger::Connection for FaultyLedgerConnection {
+    type Error = Infallible;
+}
+
+impl ledger::Configure for FaultyLedgerConnection {
+    #[inline]
+    fn sync_config(&self) -> &SyncConfig {
+        &self.sync_config
+    }
+
+    #[inline]
+    fn set_sync_config(&mut self, config: SyncConfig) {
+        self.sync_config = config;
+    }
+}
+
+impl ledger::Read<SyncData<Config>> for FaultyLedgerConnection {
+    type Checkpoint = Checkpoint;
+
+    #[inline]
+    fn read<'s>(
+        &'s mut self,
+        checkpoint: &'s Self::Checkpoint,
+    ) -> LocalBoxFutureResult<'s, ReadResponse<SyncData<Config>>, Self::Error> {
+        Box::pin(async move {
+            Ok(self
+                .ledger
+                .write()
+                .await
+                .pull(checkpoint, self.sync_config.batch_size))
+        })
+    }
+}
+
+impl ledger::Read<InitialSyncData> for FaultyLedgerConnection {
+    type Checkpoint = Checkpoint;
+
+    #[inline]
+    fn read<'s>(
+        &'s mut self,
+        checkpoint: &'s Self::Checkpoint,
+    ) -> LocalBoxFutureResult<'s, ReadResponse<InitialSyncData>, Self::Error> {
+        let _ = checkpoint;
+        Box::pin(async move { Ok(self.ledger.write().await.initial_read()) })
+    }
+}
+
+impl ledger::Write<Vec<TransferPost>> for FaultyLedgerConnection {
+    type Response = bool;
+
+    #[inline]
+    fn write(
+        &mut self,
+        posts: Vec<TransferPost>,
+    ) -> LocalBoxFutureResult<'_, Self::Response, Self::Error> {
+        Box::pin(async move { Ok(self.ledger.write().await.push(self.account, posts)) })
+    }
+}
+
+impl PublicBalanceOracle<Config> for FaultyLedgerConnection {
+    #[inline]
+    fn public_balances(&self) -> LocalBoxFuture<'_, Option<AssetList<AssetId, AssetValue>>> {
+        Box::pin(async move { self.ledger.write().await.public_balances(self.account) })
+    }
+}
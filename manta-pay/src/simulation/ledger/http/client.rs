@@ -23,10 +23,11 @@ use crate::{
     },
     simulation::ledger::{http::Request, AccountId, Checkpoint},
 };
+use alloc::sync::Arc;
 use manta_accounting::{
     asset::AssetList,
     wallet::{
-        ledger::{self, ReadResponse},
+        ledger::{self, PullRequest, ReadResponse, SyncConfig},
         signer::SyncData,
         test::PublicBalanceOracle,
     },
@@ -36,45 +37,88 @@ use manta_util::{
     http::reqwest::{self, Error, IntoUrl, KnownUrlClient},
     serde::{de::DeserializeOwned, Serialize},
 };
+use tokio::{sync::Semaphore, time::sleep};
 
 /// HTTP Ledger Client
+///
+/// Clones of a [`Client`] share the bound on the number of requests in flight given by its
+/// [`SyncConfig`].
+#[derive(Clone)]
 pub struct Client {
     /// Account Id
     account: AccountId,
 
     /// Client Connection
     client: KnownUrlClient,
+
+    /// Synchronization Configuration
+    sync_config: SyncConfig,
+
+    /// Requests in Flight Limiter
+    in_flight: Arc<Semaphore>,
 }
 
 impl Client {
     /// Builds a new HTTP [`Client`] that connects to `server_url`.
     #[inline]
     pub fn new<U>(account: AccountId, server_url: U) -> Result<Self, Error>
+    where
+        U: IntoUrl,
+    {
+        Self::with_sync_config(account, server_url, Default::default())
+    }
+
+    /// Builds a new HTTP [`Client`] that connects to `server_url` using `sync_config`.
+    #[inline]
+    pub fn with_sync_config<U>(
+        account: AccountId,
+        server_url: U,
+        sync_config: SyncConfig,
+    ) -> Result<Self, Error>
     where
         U: IntoUrl,
     {
         Ok(Self {
             account,
             client: KnownUrlClient::new(server_url)?,
+            in_flight: Arc::new(Semaphore::new(sync_config.max_in_flight.max(1))),
+            sync_config,
         })
     }
 
-    /// Sends a POST of type `command` with query string `request`.
+    /// Sends a POST of type `command` with query string `request`, retrying according to the
+    /// [`RetryPolicy`](ledger::RetryPolicy) of `self`.
     #[inline]
     pub async fn post_request<T, R>(&self, command: &str, request: T) -> reqwest::Result<R>
     where
         T: Serialize,
         R: DeserializeOwned,
     {
-        self.client
-            .post(
-                command,
-                &Request {
-                    account: self.account,
-                    request,
-                },
-            )
-            .await
+        let request = Request {
+            account: self.account,
+            request,
+        };
+        let mut failures = 0;
+        loop {
+            let result = {
+                let _permit = self
+                    .in_flight
+                    .acquire()
+                    .await
+                    .expect("The semaphore is never closed.");
+                self.client.post(command, &request).await
+            };
+            match result {
+                Err(err) => {
+                    failures += 1;
+                    match self.sync_config.retry.backoff(failures) {
+                        Some(backoff) => sleep(backoff).await,
+                        _ => return Err(err),
+                    }
+                }
+                result => return result,
+            }
+        }
     }
 }
 
@@ -82,6 +126,23 @@ impl ledger::Connection for Client {
     type Error = Error;
 }
 
+impl ledger::Configure for Client {
+    #[inline]
+    fn sync_config(&self) -> &SyncConfig {
+        &self.sync_config
+    }
+
+    /// Replaces the [`SyncConfig`] of `self` with `config`. If the bound on the number of requests
+    /// in flight changes, `self` stops sharing it with its existing clones.
+    #[inline]
+    fn set_sync_config(&mut self, config: SyncConfig) {
+        if config.max_in_flight != self.sync_config.max_in_flight {
+            self.in_flight = Arc::new(Semaphore::new(config.max_in_flight.max(1)));
+        }
+        self.sync_config = config;
+    }
+}
+
 impl ledger::Read<SyncData<Config>> for Client {
     type Checkpoint = Checkpoint;
 
@@ -90,7 +151,13 @@ impl ledger::Read<SyncData<Config>> for Client {
         &'s mut self,
         checkpoint: &'s Self::Checkpoint,
     ) -> LocalBoxFutureResult<'s, ReadResponse<SyncData<Config>>, Self::Error> {
-        Box::pin(self.post_request("pull", checkpoint))
+        Box::pin(self.post_request(
+            "pull",
+            PullRequest {
+                checkpoint: *checkpoint,
+                batch_size: self.sync_config.batch_size,
+            },
+        ))
     }
 }
 
@@ -27,7 +27,10 @@ use alloc::sync::Arc;
 use core::future::Future;
 use manta_accounting::{
     asset::AssetList,
-    wallet::{ledger::ReadResponse, signer::SyncData},
+    wallet::{
+        ledger::{PullRequest, ReadResponse},
+        signer::SyncData,
+    },
 };
 use manta_util::{
     http::tide::{self, listener::ToListener, Body, Response},
@@ -46,15 +49,18 @@ impl State {
         Self(Arc::new(RwLock::new(ledger)))
     }
 
-    /// Pulls data from the ledger at the given `checkpoint`.
+    /// Pulls a batch of data from the ledger at the checkpoint given in `request`.
     #[inline]
     async fn pull(
         self,
         account: AccountId,
-        checkpoint: Checkpoint,
+        request: PullRequest<Checkpoint>,
     ) -> ReadResponse<SyncData<Config>> {
         let _ = account;
-        self.0.read().await.pull(&checkpoint)
+        self.0
+            .read()
+            .await
+            .pull(&request.checkpoint, request.batch_size)
     }
 
     /// Pushes data to the ledger with the given `account` and `posts`.
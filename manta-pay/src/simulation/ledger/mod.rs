@@ -51,13 +51,13 @@ use manta_accounting::{
         TransferPostingKeyRef, UtxoAccumulatorOutput,
     },
     wallet::{
-        ledger::{self, ReadResponse},
+        ledger::{self, ReadResponse, SyncConfig},
         signer::SyncData,
         test::PublicBalanceOracle,
     },
 };
 use manta_crypto::{
-    accumulator::ItemHashFunction,
+    accumulator::{ItemHashFunction, RootEvictionPolicy, RootHistory, RootWindow},
     constraint::ProofSystem as _,
     merkle_tree::{
         self,
@@ -75,6 +75,8 @@ use {
     manta_util::serde::{Deserialize, Serialize},
 };
 
+pub mod fault;
+
 #[cfg(feature = "http")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "http")))]
 pub mod http;
@@ -82,6 +84,12 @@ pub mod http;
 /// Merkle Forest Index
 pub type MerkleForestIndex = <MerkleTreeConfiguration as Configuration>::Index;
 
+/// Returns the [`MerkleForestIndex`] of the `i`-th tree of the UTXO forest.
+#[inline]
+fn forest_index(i: usize) -> MerkleForestIndex {
+    <MerkleForestIndex as FixedIndex<{ MerkleTreeConfiguration::FOREST_WIDTH }>>::from_index(i)
+}
+
 /// UTXO Merkle Forest Type
 pub type UtxoMerkleForest = merkle_tree::forest::TreeArrayMerkleForest<
     MerkleTreeConfiguration,
@@ -127,7 +135,7 @@ impl<L, R> AsRef<R> for WrapPair<L, R> {
     derive(Deserialize, Serialize),
     serde(crate = "manta_util::serde", deny_unknown_fields)
 )]
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Ledger {
     /// Nullifier
     nullifiers: IndexSet<Nullifier>,
@@ -141,6 +149,13 @@ pub struct Ledger {
     /// UTXO Forest
     utxo_forest: UtxoMerkleForest,
 
+    /// Recent Roots of the UTXO Forest
+    ///
+    /// Membership proofs against a root which was replaced recently are still accepted, so that
+    /// transactions built concurrently against the same state do not invalidate each other.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    root_window: RootWindow<UtxoAccumulatorOutput<Config>>,
+
     /// Account Table
     accounts: HashMap<AccountId, HashMap<AssetId, AssetValue>>,
 
@@ -170,15 +185,35 @@ impl Ledger {
             nullifiers: Default::default(),
             utxos: Default::default(),
             shards: (0..MerkleTreeConfiguration::FOREST_WIDTH)
-                .map(move |i| (MerkleForestIndex::from_index(i), Default::default()))
+                .map(move |i| (forest_index(i), Default::default()))
                 .collect(),
             utxo_forest: UtxoMerkleForest::new(utxo_accumulator_model),
+            root_window: Default::default(),
             accounts: Default::default(),
             verifying_context,
             parameters,
         }
     }
 
+    /// Replaces the [`RootEvictionPolicy`] of the window of recent UTXO forest roots which are
+    /// accepted in membership proofs.
+    #[inline]
+    pub fn set_root_eviction_policy(&mut self, policy: RootEvictionPolicy) {
+        self.root_window.set_policy(policy);
+    }
+
+    /// Returns `true` if `root` is the current root of a tree of the UTXO forest or one of the
+    /// roots it replaced recently.
+    #[inline]
+    pub fn is_recent_root(&self, root: &UtxoAccumulatorOutput<Config>) -> bool {
+        self.utxo_forest
+            .forest
+            .as_ref()
+            .iter()
+            .any(|tree| tree.root() == root)
+            || self.root_window.is_recent_root(root)
+    }
+
     /// Returns the public balances of `account` if it exists.
     #[inline]
     pub fn public_balances(&self, account: AccountId) -> Option<AssetList<AssetId, AssetValue>> {
@@ -198,13 +233,24 @@ impl Ledger {
         self.accounts.entry(account).or_default().insert(id, value);
     }
 
-    /// Pulls the data from the ledger later than the given `checkpoint`.
+    /// Pulls the data from the ledger later than the given `checkpoint`, returning at most
+    /// `batch_size` UTXOs and at most `batch_size` nullifiers. The
+    /// [`should_continue`](ReadResponse::should_continue) flag is set if there is more data left.
     #[inline]
-    pub fn pull(&self, checkpoint: &Checkpoint) -> ReadResponse<SyncData<Config>> {
+    pub fn pull(
+        &self,
+        checkpoint: &Checkpoint,
+        batch_size: usize,
+    ) -> ReadResponse<SyncData<Config>> {
+        let mut should_continue = false;
         let mut receivers = Vec::new();
         for (i, mut index) in checkpoint.receiver_index.iter().copied().enumerate() {
-            let shard = &self.shards[&MerkleForestIndex::from_index(i)];
+            let shard = &self.shards[&forest_index(i)];
             while let Some(entry) = shard.get_index(index) {
+                if receivers.len() == batch_size {
+                    should_continue = true;
+                    break;
+                }
                 receivers.push(entry.clone());
                 index += 1;
             }
@@ -213,14 +259,18 @@ impl Ledger {
             .nullifiers
             .iter()
             .skip(checkpoint.sender_index)
+            .take(batch_size)
             .cloned()
-            .collect();
+            .collect::<Vec<_>>();
+        should_continue |=
+            checkpoint.sender_index.saturating_add(senders.len()) < self.nullifiers.len();
         ReadResponse {
-            should_continue: false,
+            should_continue,
             data: SyncData {
                 utxo_note_data: receivers,
                 nullifier_data: senders,
             },
+            height: None,
         }
     }
 
@@ -268,7 +318,7 @@ impl Ledger {
     pub fn initial_read(&self) -> ReadResponse<InitialSyncData> {
         let mut utxos = Vec::new();
         for (i, mut index) in (0..MerkleTreeConfiguration::FOREST_WIDTH).enumerate() {
-            let shard = &self.shards[&MerkleForestIndex::from_index(i)];
+            let shard = &self.shards[&forest_index(i)];
             while let Some(entry) = shard.get_index(index) {
                 utxos.push(entry.0);
                 index += 1;
@@ -294,6 +344,7 @@ impl Ledger {
                 membership_proof_data,
                 nullifier_count: senders.len() as u128,
             },
+            height: None,
         }
     }
 
@@ -493,12 +544,11 @@ impl SenderLedger<Parameters> for Ledger {
         if output == Default::default() {
             return Ok(Wrap(output));
         }
-        for tree in self.utxo_forest.forest.as_ref() {
-            if tree.root() == &output {
-                return Ok(Wrap(output));
-            }
+        if self.is_recent_root(&output) {
+            Ok(Wrap(output))
+        } else {
+            Err(SenderLedgerError::InvalidUtxoAccumulatorOutput)
         }
-        Err(SenderLedgerError::InvalidUtxoAccumulatorOutput)
     }
 
     #[inline]
@@ -537,12 +587,17 @@ impl ReceiverLedger<Parameters> for Ledger {
     ) -> Result<(), Self::Error> {
         let _ = super_key;
         let utxo_hash = self.parameters.item_hash(&utxo.0, &mut ());
+        let tree_index = MerkleTreeConfiguration::tree_index(&utxo_hash);
         self.shards
-            .get_mut(&MerkleTreeConfiguration::tree_index(&utxo_hash))
+            .get_mut(&tree_index)
             .ok_or(ReceiverLedgerError::UnexpectedError)?
             .insert((utxo.0, note));
         self.utxos.insert(utxo.0);
         self.utxo_forest.push(&utxo_hash);
+        self.root_window.push(
+            self.utxos.len() as u64,
+            *self.utxo_forest.forest.get(tree_index).root(),
+        );
         Ok(())
     }
 }
@@ -925,13 +980,20 @@ pub struct LedgerConnection {
 
     /// Ledger Accessor
     ledger: SharedLedger,
+
+    /// Synchronization Configuration
+    sync_config: SyncConfig,
 }
 
 impl LedgerConnection {
     /// Builds a new [`LedgerConnection`] for `account` and `ledger`.
     #[inline]
     pub fn new(account: AccountId, ledger: SharedLedger) -> Self {
-        Self { account, ledger }
+        Self {
+            account,
+            ledger,
+            sync_config: Default::default(),
+        }
     }
 }
 
@@ -939,6 +1001,18 @@ impl ledger::Connection for LedgerConnection {
     type Error = Infallible;
 }
 
+impl ledger::Configure for LedgerConnection {
+    #[inline]
+    fn sync_config(&self) -> &SyncConfig {
+        &self.sync_config
+    }
+
+    #[inline]
+    fn set_sync_config(&mut self, config: SyncConfig) {
+        self.sync_config = config;
+    }
+}
+
 impl ledger::Read<SyncData<Config>> for LedgerConnection {
     type Checkpoint = Checkpoint;
 
@@ -947,7 +1021,13 @@ impl ledger::Read<SyncData<Config>> for LedgerConnection {
         &'s mut self,
         checkpoint: &'s Self::Checkpoint,
     ) -> LocalBoxFutureResult<'s, ReadResponse<SyncData<Config>>, Self::Error> {
-        Box::pin(async move { Ok(self.ledger.read().await.pull(checkpoint)) })
+        Box::pin(async move {
+            Ok(self
+                .ledger
+                .read()
+                .await
+                .pull(checkpoint, self.sync_config.batch_size))
+        })
     }
 }
 
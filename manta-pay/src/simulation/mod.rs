@@ -27,7 +27,10 @@ use crate::{
         base::{Signer, UtxoAccumulator},
         functions, InitialSyncData,
     },
-    simulation::ledger::{Ledger, LedgerConnection},
+    simulation::ledger::{
+        fault::{FaultConfig, FaultyLedger, FaultyLedgerConnection},
+        Ledger, LedgerConnection,
+    },
 };
 use alloc::{format, sync::Arc};
 use core::fmt::Debug;
@@ -44,7 +47,7 @@ use manta_accounting::{
 };
 use manta_crypto::{
     accumulator::Accumulator,
-    rand::{ChaCha20Rng, CryptoRng, RngCore, SeedableRng},
+    rand::{ChaCha20Rng, CryptoRng, RngCore, SeedableRng, TestRng},
 };
 use tokio::{
     io::{self, AsyncWriteExt},
@@ -116,6 +119,20 @@ pub struct Simulation {
 
     /// Starting Balance
     pub starting_balance: AssetValue,
+
+    /// Simulation Seed
+    ///
+    /// If no seed is given, it is read from the environment or drawn at random, and then printed
+    /// so that the simulation can be reproduced. See [`TestRng::from_env`] for more.
+    #[cfg_attr(feature = "clap", arg(long))]
+    pub seed: Option<u64>,
+
+    /// Ledger Faults
+    ///
+    /// The faults are drawn from the simulation seed, so a failing adversarial schedule can be
+    /// replayed with the same seed.
+    #[cfg_attr(feature = "clap", command(flatten))]
+    pub faults: FaultConfig,
 }
 
 impl Simulation {
@@ -129,6 +146,15 @@ impl Simulation {
         }
     }
 
+    /// Returns the random number generator of the simulation seeded with [`seed`](Self::seed).
+    #[inline]
+    pub fn rng(&self) -> TestRng {
+        match self.seed {
+            Some(seed) => TestRng::new(seed),
+            _ => TestRng::from_env(),
+        }
+    }
+
     /// Sets the correct public balances for `ledger` to set up the simulation.
     #[inline]
     pub fn setup(&self, ledger: &mut Ledger) {
@@ -163,13 +189,44 @@ impl Simulation {
             parameters.clone(),
         );
         self.setup(&mut ledger);
-        let ledger = Arc::new(RwLock::new(ledger));
-        self.run_with(
-            move |i| LedgerConnection::new(account_id_from_u64(i as u64), ledger.clone()),
+        let mut simulation_rng = self.rng();
+        if !self.faults.is_enabled() {
+            let ledger = Arc::new(RwLock::new(ledger));
+            return self
+                .run_with_rng(
+                    move |i| LedgerConnection::new(account_id_from_u64(i as u64), ledger.clone()),
+                    move |_| {
+                        sample_signer(proving_context, parameters, utxo_accumulator_model, rng)
+                    },
+                    move |i| account_id_from_u64(i as u64),
+                    simulation_rng,
+                )
+                .await;
+        }
+        let ledger = Arc::new(RwLock::new(FaultyLedger::new(
+            ledger,
+            self.faults,
+            simulation_rng.fork(),
+        )));
+        let connection_ledger = ledger.clone();
+        self.run_with_rng(
+            move |i| {
+                FaultyLedgerConnection::new(
+                    account_id_from_u64(i as u64),
+                    connection_ledger.clone(),
+                )
+            },
             move |_| sample_signer(proving_context, parameters, utxo_accumulator_model, rng),
             move |i| account_id_from_u64(i as u64),
+            simulation_rng,
         )
-        .await
+        .await;
+        let report = ledger.read().await.report();
+        let _ = write_stdout(format!("{report:?}\n").as_bytes()).await;
+        assert!(
+            report.is_sound(),
+            "ERROR: The ledger accepted a duplicate submission or a double spend: {report:?}."
+        );
     }
 
     /// Runs the simulation with the given ledger connections and signer connections.
@@ -197,10 +254,41 @@ impl Simulation {
         GS: FnMut(usize) -> S,
         GP: FnMut(usize) -> AccountId,
         Error<Config, L, S>: Debug,
+    {
+        self.run_with_rng(ledger, signer, public_account, self.rng())
+            .await
+    }
+
+    /// Runs the simulation with the given ledger connections and signer connections, drawing the
+    /// actor schedules from `rng`.
+    #[inline]
+    async fn run_with_rng<L, S, GL, GS, GP>(
+        &self,
+        ledger: GL,
+        signer: GS,
+        public_account: GP,
+        mut rng: TestRng,
+    ) where
+        L: wallet::test::Ledger<Config>
+            + PublicBalanceOracle<Config>
+            + wallet::ledger::Read<
+                InitialSyncData,
+                Checkpoint = <L as wallet::ledger::Read<SyncData<Config>>>::Checkpoint,
+            >,
+        S: wallet::signer::Connection<
+            Config,
+            Checkpoint = <L as wallet::ledger::Read<SyncData<Config>>>::Checkpoint,
+        >,
+        S::Checkpoint: signer::Checkpoint<Config>,
+        S::Error: Debug,
+        GL: FnMut(usize) -> L,
+        GS: FnMut(usize) -> S,
+        GP: FnMut(usize) -> AccountId,
+        Error<Config, L, S>: Debug,
     {
         assert!(
             self.config()
-                .run::<_, _, _, AssetList<AssetId, AssetValue>, _, _, _, _, _, _, _>(ledger, signer, public_account, |_| ChaCha20Rng::from_entropy(), |event| {
+                .run::<_, _, _, AssetList<AssetId, AssetValue>, _, _, _, _, _, _, _>(ledger, signer, public_account, move |_| rng.fork(), |event| {
                     let event = format!("{event:?}\n");
                     async move {
                         let _ = write_stdout(event.as_bytes()).await;
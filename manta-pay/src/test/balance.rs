@@ -19,12 +19,16 @@
 use crate::config::{AssetId, AssetValue};
 use manta_accounting::{
     asset,
-    wallet::balance::{
-        self,
-        test::{assert_full_withdraw_should_remove_entry, assert_valid_withdraw},
+    wallet::{
+        balance::{
+            self,
+            test::{assert_full_withdraw_should_remove_entry, assert_valid_withdraw},
+            BalanceHistory, BalanceState,
+        },
+        report::{AccountingReport, Direction},
     },
 };
-use manta_crypto::rand::OsRng;
+use manta_crypto::rand::{OsRng, Rand};
 
 /// Asset List Type
 type AssetList = asset::AssetList<AssetId, AssetValue>;
@@ -97,3 +101,142 @@ fn hash_map_valid_withdraw() {
 fn hash_map_full_withdraw() {
     assert_full_withdraw_should_remove_entry::<_, _, HashMapBalanceState, _>(&mut OsRng);
 }
+
+/// Tests the aggregate queries and the snapshots of a balance state.
+#[test]
+fn aggregate_balance_queries() {
+    let mut rng = OsRng;
+    let mut ids = [rng.gen(), rng.gen(), rng.gen()];
+    ids.sort();
+    let [first, second, third]: [AssetId; 3] = ids;
+    let mut state = AssetList::new();
+    state.deposit(asset::Asset::new(third, 50));
+    state.deposit(asset::Asset::new(first, 20));
+    state.deposit(asset::Asset::new(second, 50));
+    state.deposit(asset::Asset::new(first, 5));
+    assert_eq!(state.nonzero_assets().len(), 3);
+    assert_eq!(
+        state.assets_by_value(),
+        vec![
+            asset::Asset::new(second, 50),
+            asset::Asset::new(third, 50),
+            asset::Asset::new(first, 25),
+        ]
+    );
+    let totals = state.totals_by_class(|id| (*id != third).then_some(*id == first));
+    assert_eq!(totals.get(&true), Some(&25));
+    assert_eq!(totals.get(&false), Some(&50));
+    let snapshot = state.snapshot();
+    assert_eq!(
+        snapshot,
+        BTreeMapBalanceState::from_iter([(third, 50), (second, 50), (first, 25)]).snapshot(),
+        "Snapshots should not depend on the balance state type."
+    );
+    assert_eq!(snapshot.balance(&first), 25);
+    assert_eq!(
+        snapshot.iter().map(|asset| asset.id).collect::<Vec<_>>(),
+        ids
+    );
+    state.withdraw(&asset::Asset::new(first, 25));
+    assert_eq!(state.snapshot().balance(&first), 0);
+    assert_eq!(state.snapshot().len(), 2);
+}
+
+/// Tests that a [`BalanceHistory`] answers point-in-time queries, skips unchanged snapshots and
+/// follows rollbacks.
+#[test]
+fn balance_history_point_in_time_queries() {
+    let mut rng = OsRng;
+    let id: AssetId = rng.gen();
+    let mut state = BTreeMapBalanceState::new();
+    let mut history = BalanceHistory::<u64, AssetId, AssetValue>::new();
+    history.record(0, &state);
+    state.deposit(asset::Asset::new(id, 10));
+    history.record(5, &state);
+    history.record(7, &state);
+    assert_eq!(
+        history.len(),
+        2,
+        "Unchanged balances should not be recorded twice."
+    );
+    state.withdraw(asset::Asset::new(id, 4));
+    history.record(9, &state);
+    assert_eq!(history.balance_at(&0, &id), Some(0));
+    assert_eq!(history.balance_at(&4, &id), Some(0));
+    assert_eq!(history.balance_at(&7, &id), Some(10));
+    assert_eq!(history.balance_at(&8, &id), Some(10));
+    assert_eq!(history.balance_at(&100, &id), Some(6));
+    history.rollback(&6);
+    assert_eq!(history.balance_at(&100, &id), Some(10));
+    history.prune_before(&6);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.balance_at(&4, &id), None);
+    assert_eq!(history.balance_at(&6, &id), Some(10));
+}
+
+/// Tests that an [`AccountingReport`] lists every balance change of a [`BalanceHistory`] with its
+/// direction and running balance, and that it is exported as CSV and JSON.
+#[test]
+fn accounting_report_from_history() {
+    let mut rng = OsRng;
+    let first: AssetId = rng.gen();
+    let second: AssetId = rng.gen();
+    let mut state = BTreeMapBalanceState::new();
+    let mut history = BalanceHistory::<u64, AssetId, AssetValue>::new();
+    state.deposit(asset::Asset::new(first, 100));
+    history.record(1, &state);
+    state.deposit(asset::Asset::new(first, 20));
+    state.deposit(asset::Asset::new(second, 5));
+    history.record(2, &state);
+    state.withdraw(asset::Asset::new(first, 120));
+    history.record(3, &state);
+    let mut report = AccountingReport::from_history(&history);
+    assert_eq!(
+        report.opening().map(|(checkpoint, _)| *checkpoint),
+        Some(1),
+        "The first snapshot holds the opening balances."
+    );
+    assert_eq!(report.entries().len(), 3);
+    let first_entries = report.entries_for(&first).collect::<Vec<_>>();
+    assert_eq!(first_entries.len(), 2);
+    assert_eq!(first_entries[0].direction, Direction::Incoming);
+    assert_eq!(
+        (first_entries[0].amount, first_entries[0].balance),
+        (20, 120)
+    );
+    assert_eq!(first_entries[1].direction, Direction::Outgoing);
+    assert_eq!(
+        (first_entries[1].amount, first_entries[1].balance),
+        (120, 0)
+    );
+    assert!(report.disclose(&3, &first, "Payroll, Inc.".into()));
+    assert!(!report.disclose(&3, &second, "Nobody".into()));
+    let json = serde_json::to_string(&report).expect("Serializing reports is not allowed to fail.");
+    assert_eq!(
+        serde_json::from_str::<AccountingReport<u64, AssetId, AssetValue>>(&json)
+            .expect("Deserializing reports is not allowed to fail."),
+        report
+    );
+    let csv = report
+        .map(
+            |checkpoint| checkpoint * 10,
+            |id| if id == first { "FIRST" } else { "SECOND" },
+            |value| value,
+        )
+        .to_csv();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("checkpoint,asset_id,direction,amount,balance,counterparty")
+    );
+    let mut rows = lines.collect::<Vec<_>>();
+    rows.sort();
+    assert_eq!(
+        rows,
+        [
+            "20,FIRST,incoming,20,120,",
+            "20,SECOND,incoming,5,5,",
+            "30,FIRST,outgoing,120,0,\"Payroll, Inc.\"",
+        ]
+    );
+}
@@ -19,7 +19,8 @@
 //! Checks if the current circuit implementation is compatible with precomputed parameters.
 
 use crate::{
-    parameters::load_parameters,
+    config::{CompressedVerifyingKey, Parameters, UtxoAccumulatorModel},
+    parameters::{load_parameters, ParametersVersion, VersionedVerifyingContexts},
     signer::base::UtxoAccumulator,
     test::payment::{
         private_transfer::prove as prove_private_transfer, to_private::prove as prove_to_private,
@@ -27,6 +28,7 @@ use crate::{
     },
 };
 use manta_crypto::rand::{OsRng, Rand};
+use manta_util::codec::Encode;
 
 /// Tests that the circuit is compatible with the current known parameters in `manta-parameters`.
 #[test]
@@ -50,12 +52,111 @@ fn compatibility() {
         &mut rng,
     )
     .assert_valid_proof(&verifying_context.private_transfer);
-    let _ = &prove_to_public(
+    let post = prove_to_public(
         &proving_context.to_public,
         &parameters,
         &mut utxo_accumulator,
         rng.gen(),
         &mut rng,
+    );
+    let _ = post.assert_valid_proof(&verifying_context.to_public);
+    assert_eq!(
+        VersionedVerifyingContexts::load_all().detect_post(&post),
+        Some(ParametersVersion::LATEST),
+        "The post should be detected as produced with the latest parameters."
+    );
+}
+
+/// Tests that the parameters in `manta-parameters` are registered as the latest
+/// [`ParametersVersion`] and that other parameters are not.
+#[test]
+fn parameters_version_detection() {
+    let mut rng = OsRng;
+    let version = ParametersVersion::LATEST;
+    assert_eq!(
+        ParametersVersion::detect_parameters(
+            &version.load_transfer_parameters(),
+            &version.load_utxo_accumulator_model()
+        ),
+        Some(version),
+    );
+    assert_eq!(
+        ParametersVersion::detect_verifying_context(&version.load_verifying_context()),
+        Some(version),
+    );
+    assert_eq!(
+        ParametersVersion::detect_parameters(
+            &rng.gen::<_, Parameters>(),
+            &rng.gen::<_, UtxoAccumulatorModel>()
+        ),
+        None,
+        "Sampled parameters should not match any registered version."
+    );
+    assert_eq!(ParametersVersion::detect([]), None);
+}
+
+/// Tests that the compressed encoding of the verifying contexts in `manta-parameters` round-trips
+/// and is smaller than their prepared encoding.
+#[test]
+fn compressed_verifying_keys() {
+    let contexts = ParametersVersion::LATEST.load_verifying_context();
+    for context in [
+        &contexts.to_private,
+        &contexts.private_transfer,
+        &contexts.to_public,
+    ] {
+        let compressed = context.to_compressed();
+        let bytes = compressed.to_bytes();
+        assert_eq!(bytes, compressed.to_vec());
+        assert!(
+            bytes.len() < context.to_vec().len(),
+            "The compressed encoding should be smaller than the prepared encoding."
+        );
+        let decoded = CompressedVerifyingKey::from_bytes(&bytes)
+            .expect("Decoding a valid compressed verifying key is not allowed to fail.");
+        assert_eq!(decoded, compressed);
+        assert_eq!(
+            decoded
+                .into_verifying_context()
+                .expect("Preparing a valid verifying key is not allowed to fail.")
+                .verifying_key(),
+            context.verifying_key()
+        );
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(CompressedVerifyingKey::from_bytes(&trailing).is_err());
+        assert!(CompressedVerifyingKey::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
+
+/// Tests that proving contexts round-trip through the compressed container and that the container
+/// is substantially smaller than their uncompressed encoding.
+#[cfg(feature = "compression")]
+#[test]
+fn compressed_proving_context() {
+    use crate::{
+        config::{FullParametersRef, ToPrivate},
+        parameters::{decode_compressed_proving_context, encode_compressed_proving_context},
+    };
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let utxo_accumulator_model: UtxoAccumulatorModel = rng.gen();
+    let (proving_context, _) = ToPrivate::generate_context(
+        &(),
+        FullParametersRef::new(&parameters, &utxo_accumulator_model),
+        &mut rng,
     )
-    .assert_valid_proof(&verifying_context.to_public);
+    .expect("Unable to generate the proving context.");
+    let container = encode_compressed_proving_context(&proving_context, Vec::new())
+        .expect("Writing to a vector is not allowed to fail.");
+    assert!(
+        10 * container.len() < 6 * proving_context.to_vec().len(),
+        "The container should be much smaller than the uncompressed encoding."
+    );
+    assert_eq!(
+        decode_compressed_proving_context(container.as_slice())
+            .expect("Decoding a valid container is not allowed to fail."),
+        proving_context
+    );
+    assert!(decode_compressed_proving_context(&container[..container.len() - 1]).is_err());
 }
@@ -0,0 +1,67 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Fuzzing Targets Testing
+
+use crate::fuzz::Targets;
+use manta_crypto::rand::{OsRng, Rand, RngCore};
+
+/// Number of Mutated Inputs per Target
+const MUTATIONS: usize = 256;
+
+/// Flips a few random bits of `data`, and sometimes truncates it.
+#[inline]
+fn mutate<R>(data: &[u8], rng: &mut R) -> Vec<u8>
+where
+    R: RngCore + ?Sized,
+{
+    let mut data = data.to_vec();
+    for _ in 0..rng.gen_range(1..4usize) {
+        let index = rng.gen_range(0..data.len());
+        data[index] ^= 1 << rng.gen_range(0..8u32);
+    }
+    if rng.gen::<_, bool>() {
+        data.truncate(rng.gen_range(0..data.len()));
+    }
+    data
+}
+
+/// Checks that the fuzzing targets open the template note and do not panic on mutations of it
+/// or on random bytes.
+#[test]
+fn targets_survive_malformed_inputs() {
+    let mut rng = OsRng;
+    let targets = Targets::new();
+    let (utxo, note) = targets.template();
+    let note_bytes =
+        bincode::serialize(&(utxo, note)).expect("Serializing is not allowed to fail.");
+    let ciphertext_bytes = bincode::serialize(&(&note.incoming_note, &note.light_incoming_note))
+        .expect("Serializing is not allowed to fail.");
+    assert!(targets.note(&note_bytes), "The template note should open.");
+    assert!(
+        targets.ciphertext(&ciphertext_bytes),
+        "The template ciphertexts should open."
+    );
+    for _ in 0..MUTATIONS {
+        targets.note(&mutate(&note_bytes, &mut rng));
+        targets.ciphertext(&mutate(&ciphertext_bytes, &mut rng));
+        let mut random = vec![0; rng.gen_range(0..512usize)];
+        rng.fill_bytes(&mut random);
+        targets.transfer_post(&random);
+        targets.note(&random);
+        targets.ciphertext(&random);
+    }
+}
@@ -0,0 +1,352 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Golden Test Vectors
+//!
+//! The [`Golden`] vectors pin the outputs of the hash functions whose constants are not covered
+//! by the proving keys: the permutation of every Poseidon specification, the domain tags, the
+//! Schnorr hash function and the viewing-key derivation with the published parameters. They are
+//! published at [`Golden::PATH`] and checked by recomputing every output from its published
+//! input, so that any change to the round constants, MDS matrices, domain tags or transcript
+//! labels shows up as a failing test and as a diff of the published file.
+
+use crate::{
+    config::{
+        poseidon::{Spec2, Spec3, Spec4, Spec5},
+        utxo::{
+            domain_tag, Config, DomainTagScheme, DomainTags, DomainTagsType, SchnorrHashFunction,
+            ViewingKeyDerivationFunction,
+        },
+        ConstraintField, EmbeddedScalar, Group,
+    },
+    crypto::poseidon::{self, Permutation, Precomputed, State},
+    parameters::load_transfer_parameters,
+};
+use alloc::vec::Vec;
+use manta_accounting::transfer::utxo::protocol::ViewingKeyDerivationFunction as _;
+use manta_crypto::{
+    arkworks::constraint::fp::Fp,
+    permutation::PseudorandomPermutation,
+    rand::{ChaCha20Rng, Rand, SeedableRng},
+    signature::schnorr::{HashFunction, PrehashedHashFunction},
+};
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Field Element Type
+type Field = Fp<ConstraintField>;
+
+/// Domain Tag Schemes in the Order of their Discriminants
+pub const DOMAIN_TAG_SCHEMES: [DomainTagScheme; 5] = [
+    DomainTagScheme::UtxoCommitmentScheme,
+    DomainTagScheme::ViewingKeyDerivationFunction,
+    DomainTagScheme::UtxoAccumulatorItemHash,
+    DomainTagScheme::InnerHash,
+    DomainTagScheme::NullifierCommitmentScheme,
+];
+
+/// Applies the precomputed Poseidon permutation of `S` to `input`.
+#[inline]
+fn permute<S>(input: &[Field]) -> Vec<Field>
+where
+    S: Precomputed<ParameterField = Field> + poseidon::Specification<Field = Field>,
+{
+    let mut state = State::<S>::new(input.into());
+    Permutation::<S>::precomputed().permute(&mut state, &mut ());
+    state.iter().cloned().collect()
+}
+
+/// Poseidon Permutation Vector
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct PoseidonVector {
+    /// Arity of the Poseidon Specification
+    pub arity: usize,
+
+    /// Input State
+    pub input: Vec<Field>,
+
+    /// Permuted State
+    pub output: Vec<Field>,
+}
+
+impl PoseidonVector {
+    /// Builds the vector for the specification of the given `arity` by permuting `input`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if there is no specification of the given `arity` or if `input` is not a
+    /// state of its width.
+    #[inline]
+    pub fn new(arity: usize, input: Vec<Field>) -> Self {
+        let output = match arity {
+            2 => permute::<Spec2>(&input),
+            3 => permute::<Spec3>(&input),
+            4 => permute::<Spec4>(&input),
+            5 => permute::<Spec5>(&input),
+            _ => panic!("There is no Poseidon specification of arity {arity}."),
+        };
+        Self {
+            arity,
+            input,
+            output,
+        }
+    }
+}
+
+/// Domain Tag Vector
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct DomainTagVector {
+    /// Discriminant of the [`DomainTagScheme`]
+    pub scheme: u8,
+
+    /// Domain Tag
+    pub tag: Field,
+}
+
+impl DomainTagVector {
+    /// Builds the vector for the domain tag of `scheme`.
+    #[inline]
+    pub fn new(scheme: DomainTagScheme) -> Self {
+        Self {
+            scheme: scheme as u8,
+            tag: domain_tag(scheme),
+        }
+    }
+}
+
+/// Schnorr Hash Function Vector
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct SchnorrVector {
+    /// Verifying Key
+    pub verifying_key: Group,
+
+    /// Nonce Point
+    pub nonce_point: Group,
+
+    /// Message
+    pub message: Vec<u8>,
+
+    /// Challenge over the Message
+    pub challenge: EmbeddedScalar,
+
+    /// Message Digest
+    pub digest: [u8; 32],
+
+    /// Challenge over the Message Digest
+    pub prehashed_challenge: EmbeddedScalar,
+}
+
+impl SchnorrVector {
+    /// Builds the vector by hashing `message` with `verifying_key` and `nonce_point`.
+    #[inline]
+    pub fn new(verifying_key: Group, nonce_point: Group, message: Vec<u8>) -> Self {
+        let challenge = SchnorrHashFunction.hash(&verifying_key, &nonce_point, &message, &mut ());
+        let digest = SchnorrHashFunction::digest(&message);
+        let prehashed_challenge =
+            SchnorrHashFunction.hash_prehashed(&verifying_key, &nonce_point, &digest, &mut ());
+        Self {
+            verifying_key,
+            nonce_point,
+            message,
+            challenge,
+            digest,
+            prehashed_challenge,
+        }
+    }
+}
+
+/// Viewing-Key Derivation Vector
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct ViewingKeyVector {
+    /// Proof Authorization Key
+    pub proof_authorization_key: Group,
+
+    /// Viewing Key
+    pub viewing_key: EmbeddedScalar,
+}
+
+impl ViewingKeyVector {
+    /// Builds the vector by deriving the viewing key of `proof_authorization_key` with `function`.
+    #[inline]
+    pub fn new(function: &ViewingKeyDerivationFunction, proof_authorization_key: Group) -> Self {
+        Self {
+            viewing_key: function.viewing_key(&proof_authorization_key, &mut ()),
+            proof_authorization_key,
+        }
+    }
+}
+
+/// Golden Test Vectors
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct Golden {
+    /// Seed the Inputs were Generated from
+    pub seed: [u8; 32],
+
+    /// Poseidon Permutation Vectors
+    pub poseidon: Vec<PoseidonVector>,
+
+    /// Version of the Domain Tags
+    pub domain_tag_version: u8,
+
+    /// Domain Tag Vectors, one for each [`DomainTagScheme`]
+    pub domain_tags: Vec<DomainTagVector>,
+
+    /// Schnorr Hash Function Vectors
+    pub schnorr: Vec<SchnorrVector>,
+
+    /// Viewing-Key Derivation Vectors
+    pub viewing_keys: Vec<ViewingKeyVector>,
+}
+
+impl Golden {
+    /// Default Seed
+    pub const SEED: [u8; 32] = *b"manta-pay golden test vectors v1";
+
+    /// Path of the Published Vectors relative to the Crate Root
+    pub const PATH: &'static str = "src/test/golden.json";
+
+    /// Environment Variable which Regenerates the Published Vectors when Set
+    pub const BLESS_VARIABLE: &'static str = "MANTA_BLESS_GOLDEN";
+
+    /// Number of Vectors for each Poseidon Specification, the Schnorr Hash Function and the
+    /// Viewing-Key Derivation
+    pub const COUNT: usize = 2;
+
+    /// Arities of the Poseidon Specifications
+    pub const ARITIES: [usize; 4] = [2, 3, 4, 5];
+
+    /// Generates the golden vectors from inputs sampled from `seed`.
+    #[inline]
+    pub fn generate(seed: [u8; 32]) -> Self {
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        let mut poseidon = Vec::with_capacity(Self::ARITIES.len() * Self::COUNT);
+        for arity in Self::ARITIES {
+            for _ in 0..Self::COUNT {
+                let input = (0..=arity).map(|_| rng.gen()).collect();
+                poseidon.push(PoseidonVector::new(arity, input));
+            }
+        }
+        let mut schnorr = Vec::with_capacity(Self::COUNT);
+        for _ in 0..Self::COUNT {
+            let message = (0..32).map(|_| rng.gen()).collect();
+            schnorr.push(SchnorrVector::new(rng.gen(), rng.gen(), message));
+        }
+        let function = load_transfer_parameters()
+            .base
+            .viewing_key_derivation_function;
+        let viewing_keys = (0..Self::COUNT)
+            .map(|_| ViewingKeyVector::new(&function, rng.gen()))
+            .collect();
+        Self {
+            seed,
+            poseidon,
+            domain_tag_version: <<Config as DomainTagsType>::DomainTags as DomainTags>::VERSION,
+            domain_tags: DOMAIN_TAG_SCHEMES
+                .into_iter()
+                .map(DomainTagVector::new)
+                .collect(),
+            schnorr,
+            viewing_keys,
+        }
+    }
+
+    /// Recomputes every output of `self` from its input with the current implementation.
+    #[inline]
+    pub fn recompute(&self) -> Self {
+        let function = load_transfer_parameters()
+            .base
+            .viewing_key_derivation_function;
+        Self {
+            seed: self.seed,
+            poseidon: self
+                .poseidon
+                .iter()
+                .map(|vector| PoseidonVector::new(vector.arity, vector.input.clone()))
+                .collect(),
+            domain_tag_version: <<Config as DomainTagsType>::DomainTags as DomainTags>::VERSION,
+            domain_tags: DOMAIN_TAG_SCHEMES
+                .into_iter()
+                .map(DomainTagVector::new)
+                .collect(),
+            schnorr: self
+                .schnorr
+                .iter()
+                .map(|vector| {
+                    SchnorrVector::new(
+                        vector.verifying_key,
+                        vector.nonce_point,
+                        vector.message.clone(),
+                    )
+                })
+                .collect(),
+            viewing_keys: self
+                .viewing_keys
+                .iter()
+                .map(|vector| ViewingKeyVector::new(&function, vector.proof_authorization_key))
+                .collect(),
+        }
+    }
+
+    /// Serializes `self` into pretty-printed JSON.
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "serde_json")))]
+    #[inline]
+    pub fn to_json(&self) -> serde_json::Result<alloc::string::String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{env, fs, path::Path};
+
+    /// Checks that the published golden vectors match the current implementation. Setting
+    /// [`Golden::BLESS_VARIABLE`] regenerates them instead, which must only be done together with
+    /// an intentional change to the hash functions.
+    #[test]
+    fn golden_vectors_are_unchanged() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(Golden::PATH);
+        if env::var_os(Golden::BLESS_VARIABLE).is_some() {
+            let golden = Golden::generate(Golden::SEED);
+            fs::write(
+                &path,
+                golden.to_json().expect("Unable to serialize the vectors."),
+            )
+            .expect("Unable to write the golden vectors.");
+            return;
+        }
+        let published =
+            serde_json::from_str::<Golden>(&fs::read_to_string(&path).unwrap_or_else(|err| {
+                panic!(
+                    "Unable to read the golden vectors at {}: {err}. Set `{}=1` to generate them.",
+                    path.display(),
+                    Golden::BLESS_VARIABLE
+                )
+            }))
+            .expect("Unable to deserialize the golden vectors.");
+        assert_eq!(
+            published.recompute(),
+            published,
+            "The hash functions no longer match the published golden vectors."
+        );
+    }
+}
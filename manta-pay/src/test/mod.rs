@@ -22,6 +22,13 @@ pub mod balance;
 #[cfg(test)]
 pub mod compatibility;
 
+#[cfg(all(feature = "fuzz", test))]
+pub mod fuzz;
+
+#[cfg(all(feature = "parameters", feature = "serde"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "parameters", feature = "serde"))))]
+pub mod golden;
+
 #[cfg(test)]
 pub mod transfer;
 
@@ -29,7 +36,18 @@ pub mod transfer;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "groth16")))]
 pub mod payment;
 
+pub mod property;
+
 #[cfg(all(feature = "groth16", feature = "simulation"))]
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "groth16", feature = "simulation"))))]
 #[cfg(test)]
 pub mod signer;
+
+#[cfg(all(feature = "groth16", feature = "simulation"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "groth16", feature = "simulation"))))]
+#[cfg(test)]
+pub mod simulation;
+
+#[cfg(all(feature = "groth16", feature = "serde"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "groth16", feature = "serde"))))]
+pub mod vectors;
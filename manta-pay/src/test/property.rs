@@ -0,0 +1,370 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Protocol Property Testing
+//!
+//! Invariants of the UTXO protocol which must hold for every transfer [`Configuration`], checked
+//! against inputs drawn by a seeded [`Runner`]. When a case fails, the runner reports its seed, so
+//! that the case can be replayed with [`Runner::replay`].
+
+use alloc::vec::Vec;
+use manta_accounting::transfer::{
+    canonical::ToPrivate,
+    utxo::{
+        auth::DeriveContext, DeriveAddress, DeriveDecryptionKey, DeriveMint, DeriveSpend, Spend,
+        UtxoReconstruct,
+    },
+    Address, Asset, Configuration, FullParametersRef, Identifier, Note, Nullifier, Parameters,
+    Receiver, SpendingKey, Utxo, UtxoAccumulatorItem, UtxoAccumulatorModel,
+};
+use manta_crypto::{
+    accumulator::{ItemHashFunction, Model, OptimizedAccumulator},
+    constraint::Satisfied,
+    rand::{ChaCha20Rng, CryptoRng, OsRng, Rand, RngCore, Sample, SeedableRng},
+};
+
+/// Property Test Runner
+///
+/// Draws the seed of every case from the seed of the runner, and samples the input of each case
+/// from a [`ChaCha20Rng`] seeded with the seed of the case.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Runner {
+    /// Runner Seed
+    seed: [u8; 32],
+
+    /// Number of Cases
+    cases: usize,
+}
+
+impl Runner {
+    /// Default Number of Cases
+    pub const DEFAULT_CASES: usize = 8;
+
+    /// Builds a new [`Runner`] for `cases` cases with a random seed.
+    #[inline]
+    pub fn new(cases: usize) -> Self {
+        Self::from_seed(OsRng.gen(), cases)
+    }
+
+    /// Builds a new [`Runner`] for `cases` cases with the given `seed`.
+    #[inline]
+    pub fn from_seed(seed: [u8; 32], cases: usize) -> Self {
+        Self { seed, cases }
+    }
+
+    /// Returns the seed of `self`.
+    #[inline]
+    pub fn seed(&self) -> [u8; 32] {
+        self.seed
+    }
+
+    /// Runs `property` against an input sampled with `sample` for every case of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics with the seed of the first case for which `property` returns `false`.
+    #[inline]
+    pub fn run<T, S, P>(&self, mut sample: S, mut property: P)
+    where
+        S: FnMut(&mut ChaCha20Rng) -> T,
+        P: FnMut(T, &mut ChaCha20Rng) -> bool,
+    {
+        let mut rng = ChaCha20Rng::from_seed(self.seed);
+        for case in 0..self.cases {
+            let seed = rng.gen();
+            assert!(
+                Self::replay(seed, &mut sample, &mut property),
+                "Property failed in case {case} with seed {seed:?}."
+            );
+        }
+    }
+
+    /// Replays the case with the given `seed`, returning `true` if `property` holds for it.
+    #[inline]
+    pub fn replay<T, S, P>(seed: [u8; 32], mut sample: S, mut property: P) -> bool
+    where
+        S: FnMut(&mut ChaCha20Rng) -> T,
+        P: FnMut(T, &mut ChaCha20Rng) -> bool,
+    {
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        let input = sample(&mut rng);
+        property(input, &mut rng)
+    }
+}
+
+impl Default for Runner {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CASES)
+    }
+}
+
+/// Samples an [`Address`] with its [`SpendingKey`].
+#[inline]
+pub fn sample_account<C, R>(parameters: &Parameters<C>, rng: &mut R) -> (SpendingKey<C>, Address<C>)
+where
+    C: Configuration,
+    SpendingKey<C>: Sample,
+    R: RngCore + ?Sized,
+{
+    let spending_key = rng.gen();
+    let address = parameters.derive_address(
+        &parameters.derive_decryption_key(&mut parameters.derive_context(&spending_key)),
+    );
+    (spending_key, address)
+}
+
+/// Samples an [`Asset`] with a random identifier and value.
+#[inline]
+pub fn sample_asset<C, R>(rng: &mut R) -> Asset<C>
+where
+    C: Configuration,
+    C::AssetId: Sample,
+    C::AssetValue: Sample,
+    R: RngCore + ?Sized,
+{
+    Asset::<C>::new(rng.gen(), rng.gen())
+}
+
+/// Samples a sequence of between one and `max_len` UTXO accumulator items, each of which is paired
+/// with whether it should be inserted as provable.
+#[inline]
+pub fn sample_insert_sequence<C, R>(
+    parameters: &Parameters<C>,
+    max_len: usize,
+    rng: &mut R,
+) -> Vec<(UtxoAccumulatorItem<C>, bool)>
+where
+    C: Configuration,
+    C::AssetId: Sample,
+    C::AssetValue: Sample,
+    SpendingKey<C>: Sample,
+    R: RngCore + ?Sized,
+{
+    let len = 1 + (rng.next_u32() as usize) % max_len.max(1);
+    (0..len)
+        .map(|_| {
+            let (_, address) = sample_account::<C, _>(parameters, rng);
+            let (_, utxo, _) =
+                parameters.derive_mint(address, sample_asset::<C, _>(rng), Default::default(), rng);
+            (
+                parameters
+                    .utxo_accumulator_item_hash()
+                    .item_hash(&utxo, &mut ()),
+                rng.gen(),
+            )
+        })
+        .collect()
+}
+
+/// Checks that a [`ToPrivate`] transfer whose receiver gets the value of its source satisfies
+/// the validity constraints, and that one whose receiver gets any other value does not.
+#[inline]
+pub fn check_balance_preservation<C, R>(
+    parameters: &Parameters<C>,
+    utxo_accumulator_model: &UtxoAccumulatorModel<C>,
+    rng: &mut R,
+) -> bool
+where
+    C: Configuration,
+    C::AssetId: Sample,
+    C::AssetValue: Sample,
+    C::Compiler: Satisfied,
+    Address<C>: Clone,
+    SpendingKey<C>: Sample,
+    R: CryptoRng + RngCore + ?Sized,
+{
+    let full_parameters = FullParametersRef::<C>::new(parameters, utxo_accumulator_model);
+    let (_, address) = sample_account::<C, _>(parameters, rng);
+    let asset = sample_asset::<C, _>(rng);
+    let received_asset = Asset::<C>::new(asset.id.clone(), rng.gen());
+    let is_balanced = received_asset.value == asset.value;
+    let balanced = ToPrivate::<C>::from_address(
+        parameters,
+        address.clone(),
+        asset.clone(),
+        Default::default(),
+        rng,
+    );
+    let unbalanced = ToPrivate::<C>::build(
+        asset,
+        Receiver::<C>::sample(parameters, address, received_asset, Default::default(), rng),
+    );
+    balanced.known_constraints(full_parameters).is_satisfied()
+        && unbalanced.known_constraints(full_parameters).is_satisfied() == is_balanced
+}
+
+/// Checks that spending each of `count` UTXOs minted to the same account reconstructs the UTXO,
+/// always gives the same nullifier for the same UTXO, and gives different nullifiers for different
+/// UTXOs.
+#[inline]
+pub fn check_nullifier_uniqueness<C, R>(
+    parameters: &Parameters<C>,
+    count: usize,
+    rng: &mut R,
+) -> bool
+where
+    C: Configuration,
+    C::AssetId: Sample,
+    C::AssetValue: Sample,
+    Address<C>: Clone,
+    Identifier<C>: Clone,
+    Nullifier<C>: PartialEq,
+    SpendingKey<C>: Sample,
+    Utxo<C>: PartialEq,
+    R: RngCore + ?Sized,
+{
+    let (spending_key, address) = sample_account::<C, _>(parameters, rng);
+    let mut authorization_context = parameters.derive_context(&spending_key);
+    let decryption_key = parameters.derive_decryption_key(&mut authorization_context);
+    let mut nullifiers = Vec::<Nullifier<C>>::with_capacity(count);
+    for _ in 0..count {
+        let (_, utxo, note) = parameters.derive_mint(
+            address.clone(),
+            sample_asset::<C, _>(rng),
+            Default::default(),
+            rng,
+        );
+        let (identifier, asset) = match parameters.open_with_check(&decryption_key, &utxo, note) {
+            Some(opened) => opened,
+            _ => return false,
+        };
+        let (_, spent_utxo, nullifier) = parameters.derive_spend(
+            &mut authorization_context,
+            identifier.clone(),
+            asset.clone(),
+            rng,
+        );
+        let (_, _, repeated_nullifier) =
+            parameters.derive_spend(&mut authorization_context, identifier, asset, rng);
+        if spent_utxo != utxo || nullifier != repeated_nullifier || nullifiers.contains(&nullifier)
+        {
+            return false;
+        }
+        nullifiers.push(nullifier);
+    }
+    true
+}
+
+/// Checks that a note minted to an account opens to the minted asset with the decryption key of
+/// that account, and does not open with the decryption key of another account.
+#[inline]
+pub fn check_note_round_trip<C, R>(parameters: &Parameters<C>, rng: &mut R) -> bool
+where
+    C: Configuration,
+    C::AssetId: Sample,
+    C::AssetValue: Sample,
+    Note<C>: Clone,
+    SpendingKey<C>: Sample,
+    R: RngCore + ?Sized,
+{
+    let (spending_key, address) = sample_account::<C, _>(parameters, rng);
+    let (other_spending_key, _) = sample_account::<C, _>(parameters, rng);
+    let asset = sample_asset::<C, _>(rng);
+    let (_, utxo, note) = parameters.derive_mint(address, asset.clone(), Default::default(), rng);
+    let decryption_key =
+        parameters.derive_decryption_key(&mut parameters.derive_context(&spending_key));
+    let other_decryption_key =
+        parameters.derive_decryption_key(&mut parameters.derive_context(&other_spending_key));
+    matches!(
+        parameters.open_with_check(&decryption_key, &utxo, note.clone()),
+        Some((_, opened_asset)) if opened_asset == asset
+    ) && parameters
+        .open_with_check(&other_decryption_key, &utxo, note)
+        .is_none()
+}
+
+/// Inserts every item of `items` into `accumulator`, as provable if it is paired with `true`, and
+/// checks that every provable item has a valid membership proof after all the insertions.
+#[inline]
+pub fn check_membership_proofs<A>(accumulator: &mut A, items: &[(A::Item, bool)]) -> bool
+where
+    A: OptimizedAccumulator,
+    A::Model: Model<Verification = bool>,
+{
+    for (item, is_provable) in items {
+        let is_inserted = if *is_provable {
+            accumulator.insert(item)
+        } else {
+            accumulator.insert_nonprovable(item)
+        };
+        if !is_inserted {
+            return false;
+        }
+    }
+    items
+        .iter()
+        .filter(|(_, is_provable)| *is_provable)
+        .all(|(item, _)| {
+            accumulator
+                .prove(item)
+                .map(|proof| proof.verify(accumulator.model(), item, &mut ()))
+                .unwrap_or(false)
+        })
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        config::{utxo::UtxoAccumulatorModel, Config, Parameters},
+        test::payment::UtxoAccumulator,
+    };
+
+    /// Samples the transfer parameters and the UTXO accumulator model.
+    #[inline]
+    fn sample_parameters(rng: &mut ChaCha20Rng) -> (Parameters, UtxoAccumulatorModel) {
+        (rng.gen(), rng.gen())
+    }
+
+    /// Checks balance preservation of [`ToPrivate`] transfers.
+    #[test]
+    fn balance_preservation() {
+        Runner::new(4).run(sample_parameters, |(parameters, model), rng| {
+            check_balance_preservation::<Config, _>(&parameters, &model, rng)
+        });
+    }
+
+    /// Checks the uniqueness of nullifiers.
+    #[test]
+    fn nullifier_uniqueness() {
+        Runner::default().run(sample_parameters, |(parameters, _), rng| {
+            check_nullifier_uniqueness::<Config, _>(&parameters, 4, rng)
+        });
+    }
+
+    /// Checks that notes open only for their receiver.
+    #[test]
+    fn note_round_trip() {
+        Runner::default().run(sample_parameters, |(parameters, _), rng| {
+            check_note_round_trip::<Config, _>(&parameters, rng)
+        });
+    }
+
+    /// Checks membership proofs after random insertion sequences.
+    #[test]
+    fn membership_proofs() {
+        Runner::default().run(
+            |rng| {
+                let (parameters, model) = sample_parameters(rng);
+                let items = sample_insert_sequence::<Config, _>(&parameters, 32, rng);
+                (model, items)
+            },
+            |(model, items), _| check_membership_proofs(&mut UtxoAccumulator::new(model), &items),
+        );
+    }
+}
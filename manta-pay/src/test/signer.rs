@@ -17,12 +17,14 @@
 //! Signer Testing Suite
 
 use crate::{
-    config::{Asset, Config},
+    config::{utxo::Checkpoint, Asset, Config, ProofInputBuilder, ToPublic, WithdrawalTag},
+    key::KeySecret,
     key::Mnemonic,
-    parameters::load_parameters,
+    parameters::{load_parameters, load_transfer_parameters, load_utxo_accumulator_model},
     signer::{
-        base::identity_verification,
+        base::{identity_verification, Signer, UtxoAccumulator},
         functions::{address_from_mnemonic, authorization_context_from_mnemonic},
+        SignRequest, SyncRequest,
     },
     simulation::{
         ledger::{Ledger, LedgerConnection, SharedLedger},
@@ -31,18 +33,55 @@ use crate::{
 };
 use alloc::sync::Arc;
 use manta_accounting::{
-    transfer::{canonical::Transaction, IdentifiedAsset, Identifier},
-    wallet::{signer::ConsolidationPrerequest, test::PublicBalanceOracle, Wallet},
+    key::AccountTable,
+    transfer::{
+        self,
+        canonical::{generate_context, Transaction, TransactionData, TransferShape},
+        input::InputLayout,
+        utxo::{DeriveDecryptionKey, DeriveMint, DeriveSpend, UtxoReconstruct},
+        FullParametersRef, IdentifiedAsset, Identifier,
+    },
+    wallet::{
+        ledger::{Configure, SyncConfig},
+        signer::{
+            churn::ChurnPolicy,
+            confirmation::{ConfirmationPolicy, SpendableBalance},
+            diff::{new_roots, AccumulatorDiff},
+            functions::item_hash,
+            hook::{HookRejection, SignerHook},
+            policy::{
+                AssetLimit, PolicyViolation, RecipientRule, SpendingPolicy, SpendingPolicyState,
+                SECONDS_PER_DAY,
+            },
+            progress::SyncProgress,
+            resolver::{NameResolutionError, StaticNameResolver},
+            validation::RequestProblem,
+            witness::AccumulatorWitnessRefresher,
+            BalanceUpdate, Connection, ConsolidationPrerequest, EvictionPolicy, SignError,
+            StorageState, SyncData, SyncError,
+        },
+        test::{memory::InMemoryLedger, PublicBalanceOracle},
+        Wallet,
+    },
 };
 use manta_crypto::{
+    accumulator::{test::assert_provable_membership, Accumulator, BatchInsertion},
     algebra::HasGenerator,
     arkworks::constraint::fp::Fp,
-    rand::{fuzz::Fuzz, OsRng, Rand},
+    rand::{fuzz::Fuzz, ChaCha20Rng, FromEntropy, OsRng, Rand, SeedableRng},
 };
 use manta_util::vec::VecExt;
-use std::{env, fs::OpenOptions, io::Error};
+use std::{
+    env,
+    fs::OpenOptions,
+    io::Error,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use tokio::sync::RwLock;
 
+#[cfg(feature = "rayon")]
+use crate::signer::base::Prover;
+
 /// Test Wallet type
 type TestWallet = Wallet<Config, LedgerConnection>;
 
@@ -163,6 +202,60 @@ fn transaction_data_test() {
     );
 }
 
+/// Checks that a [`Signer`](manta_accounting::wallet::signer::Signer) built from a loader only
+/// loads the proving contexts it needs and evicts the least recently used ones.
+#[test]
+fn lazy_proving_context_test() {
+    let mut rng = OsRng;
+    let directory = tempfile::tempdir().expect("Unable to generate temporary test directory.");
+    let (proving_context, _, parameters, utxo_accumulator_model) =
+        load_parameters(directory.path()).expect("Failed to load parameters");
+    let loads = Arc::new(AtomicUsize::new(0));
+    let loader = {
+        let loads = loads.clone();
+        move |shape| {
+            loads.fetch_add(1, Ordering::SeqCst);
+            Some(proving_context.select(shape).clone())
+        }
+    };
+    let mut signer = Signer::from_loader(
+        parameters,
+        Arc::new(loader),
+        EvictionPolicy::LeastRecentlyUsed(1),
+        UtxoAccumulator::empty(&utxo_accumulator_model),
+        FromEntropy::from_entropy(),
+    );
+    signer.load_accounts(AccountTable::new(KeySecret::sample(&mut rng)));
+    let is_loaded = |signer: &Signer, shape| signer.parameters().proving_context.is_loaded(shape);
+    assert_eq!(
+        loads.load(Ordering::SeqCst),
+        0,
+        "Nothing is loaded up front."
+    );
+    signer
+        .sign(Transaction::ToPrivate(rng.gen()))
+        .expect("Signing a ToPrivate transaction is not allowed to fail.");
+    assert!(is_loaded(&signer, TransferShape::ToPrivate));
+    assert!(!is_loaded(&signer, TransferShape::PrivateTransfer));
+    assert!(!is_loaded(&signer, TransferShape::ToPublic));
+    signer
+        .identity_proof(
+            IdentifiedAsset::<Config>::new(Identifier::<Config>::new(false, rng.gen()), rng.gen()),
+            rng.gen(),
+        )
+        .expect("Error producing identity proof");
+    assert!(!is_loaded(&signer, TransferShape::ToPrivate));
+    assert!(is_loaded(&signer, TransferShape::ToPublic));
+    assert_eq!(loads.load(Ordering::SeqCst), 2);
+    signer
+        .preload(&[TransferShape::ToPrivate, TransferShape::PrivateTransfer])
+        .expect("Preloading is not allowed to fail.");
+    assert!(is_loaded(&signer, TransferShape::ToPrivate));
+    assert!(is_loaded(&signer, TransferShape::PrivateTransfer));
+    assert!(!is_loaded(&signer, TransferShape::ToPublic));
+    assert_eq!(loads.load(Ordering::SeqCst), 4);
+}
+
 /// Checks that both methods to derive a receiving key from a [`Mnemonic`] give
 /// the same result.
 #[test]
@@ -415,3 +508,1229 @@ async fn to_public_test() {
         "Public balance not preserved"
     );
 }
+
+/// Builds a signer for the accounts sampled from `seed` which never loads proving contexts.
+#[inline]
+fn offline_signer(seed: [u8; 32]) -> Signer {
+    let mut signer = Signer::from_loader(
+        load_transfer_parameters(),
+        Arc::new(|_| None),
+        EvictionPolicy::default(),
+        UtxoAccumulator::empty(&load_utxo_accumulator_model()),
+        FromEntropy::from_entropy(),
+    );
+    signer.load_accounts(AccountTable::new(KeySecret::sample(
+        &mut ChaCha20Rng::from_seed(seed),
+    )));
+    signer
+}
+
+/// Synchronizes `signer` with `data` retrieved from `origin_checkpoint`, returning the new
+/// checkpoint of `signer`.
+#[inline]
+fn sync_offline_signer(
+    signer: &mut Signer,
+    origin_checkpoint: Checkpoint,
+    data: SyncData<Config>,
+) -> Checkpoint {
+    signer
+        .sync(SyncRequest {
+            origin_checkpoint,
+            data,
+            height: None,
+        })
+        .expect("Synchronizing with consistent data is not allowed to fail.")
+        .checkpoint
+}
+
+/// Tests that merging the state of two devices with the same seed keeps the newest checkpoint,
+/// does not bring back spent assets and does not change an up-to-date state.
+#[test]
+fn merge_signer_states() {
+    let mut rng = OsRng;
+    let seed = rng.gen();
+    let mut desktop = offline_signer(seed);
+    let mut mobile = offline_signer(seed);
+    let parameters = desktop.parameters().parameters.clone();
+    let address = desktop.address().expect("The signer has accounts.");
+    let mut authorization_context = *desktop
+        .authorization_context()
+        .expect("The signer has accounts.");
+    let (_, first_utxo, first_note) =
+        parameters.derive_mint(address, rng.gen(), Default::default(), &mut rng);
+    let (_, second_utxo, second_note) =
+        parameters.derive_mint(address, rng.gen(), Default::default(), &mut rng);
+    let decryption_key = parameters.derive_decryption_key(&mut authorization_context);
+    let (identifier, asset) = parameters
+        .open_with_check(&decryption_key, &first_utxo, first_note.clone())
+        .expect("The note was minted for this address.");
+    let (_, _, first_nullifier) =
+        parameters.derive_spend(&mut authorization_context, identifier, asset, &mut rng);
+    let first_data = SyncData::<Config> {
+        utxo_note_data: vec![(first_utxo, first_note)],
+        nullifier_data: Vec::new(),
+    };
+    let checkpoint = sync_offline_signer(&mut desktop, Default::default(), first_data.clone());
+    sync_offline_signer(&mut mobile, Default::default(), first_data);
+    sync_offline_signer(
+        &mut desktop,
+        checkpoint,
+        SyncData {
+            utxo_note_data: vec![(second_utxo, second_note)],
+            nullifier_data: vec![first_nullifier],
+        },
+    );
+    let desktop_state = StorageState::from_signer(&desktop);
+    let stale_state = StorageState::from_signer(&mobile);
+    mobile
+        .merge(desktop_state.clone())
+        .expect("The signer has accounts.");
+    assert_eq!(
+        StorageState::from_signer(&mobile),
+        desktop_state,
+        "Merging a newer state should catch up without bringing back spent assets."
+    );
+    desktop
+        .merge(stale_state)
+        .expect("The signer has accounts.");
+    assert_eq!(
+        StorageState::from_signer(&desktop),
+        desktop_state,
+        "Merging an older state should not change the newer one."
+    );
+}
+
+/// Tests that the signer rejects transactions violating its spending policy before proving them
+/// and that the policy is persisted in its storage state.
+#[test]
+fn spending_policy_is_enforced() {
+    let mut rng = OsRng;
+    let mut signer = offline_signer(rng.gen());
+    let address = signer.address().expect("The signer has accounts.");
+    let asset_id = rng.gen();
+    let denied = rng.gen();
+    signer.set_spending_policy(SpendingPolicy {
+        limits: vec![AssetLimit {
+            id: asset_id,
+            per_transaction: Some(100),
+            per_day: Some(150),
+        }],
+        accounts: RecipientRule::Deny(vec![denied]),
+        ..Default::default()
+    });
+    assert_eq!(
+        signer
+            .sign(Transaction::PrivateTransfer(
+                Asset::new(asset_id, 101),
+                address
+            ))
+            .expect_err("The transaction exceeds the per-transaction limit."),
+        SignError::PolicyViolation(PolicyViolation::TransactionLimit(Asset::new(asset_id, 101)))
+    );
+    assert_eq!(
+        signer
+            .sign(Transaction::ToPublic(Asset::new(asset_id, 1), denied))
+            .expect_err("The recipient is denied."),
+        SignError::PolicyViolation(PolicyViolation::RecipientNotAllowed)
+    );
+    assert_eq!(
+        signer
+            .sign(Transaction::PrivateTransfer(
+                Asset::new(asset_id, 100),
+                address
+            ))
+            .expect_err("The offline signer has no proving contexts."),
+        SignError::MissingProvingContext(TransferShape::PrivateTransfer),
+        "Transactions allowed by the policy should reach the proving step."
+    );
+    let mut state = SpendingPolicyState::new(signer.spending_policy().clone());
+    state.advance_day(1);
+    state.record(Asset::new(asset_id, 100));
+    let transaction = Transaction::ToPublic(Asset::new(asset_id, 51), rng.gen());
+    assert_eq!(
+        state.check(&transaction),
+        Err(PolicyViolation::DailyLimit(Asset::new(asset_id, 51)))
+    );
+    state.advance_day(0);
+    assert_eq!(
+        state.spent_today(&asset_id),
+        100,
+        "Days cannot go backwards."
+    );
+    state.advance_day(2);
+    assert_eq!(
+        state.check(&transaction),
+        Ok(Some(Asset::new(asset_id, 51))),
+        "The daily totals should be reset on a new day."
+    );
+    let mut restored = offline_signer(rng.gen());
+    restored.set_storage(&signer.get_storage());
+    assert_eq!(
+        restored.spending_policy(),
+        signer.spending_policy(),
+        "The spending policy should be persisted in the storage state."
+    );
+}
+
+/// Tests that labels are attached to notes and transactions, reported in balance queries and
+/// persisted in the storage state of the signer.
+#[test]
+fn note_labels_are_persisted() {
+    let mut rng = OsRng;
+    let mut signer = offline_signer(rng.gen());
+    let parameters = signer.parameters().parameters.clone();
+    let address = signer.address().expect("The signer has accounts.");
+    let mut authorization_context = *signer
+        .authorization_context()
+        .expect("The signer has accounts.");
+    let decryption_key = parameters.derive_decryption_key(&mut authorization_context);
+    let asset_id = rng.gen();
+    let mut identifiers = Vec::new();
+    let mut utxo_note_data = Vec::new();
+    for value in [30, 50, 70] {
+        let (_, utxo, note) = parameters.derive_mint(
+            address,
+            Asset::new(asset_id, value),
+            Default::default(),
+            &mut rng,
+        );
+        let (identifier, _) = parameters
+            .open_with_check(&decryption_key, &utxo, note.clone())
+            .expect("The note was minted for this address.");
+        identifiers.push(identifier);
+        utxo_note_data.push((utxo, note));
+    }
+    sync_offline_signer(
+        &mut signer,
+        Default::default(),
+        SyncData {
+            utxo_note_data,
+            nullifier_data: Vec::new(),
+        },
+    );
+    assert!(signer.add_note_label(identifiers[0], "rent".into()));
+    assert!(signer.add_note_label(identifiers[2], "rent".into()));
+    assert!(signer.add_note_label(identifiers[2], "savings".into()));
+    assert!(
+        !signer.add_note_label(identifiers[0], "rent".into()),
+        "Labels are not duplicated."
+    );
+    assert_eq!(
+        signer.label_balance("rent"),
+        vec![Asset::new(asset_id, 100)]
+    );
+    assert_eq!(
+        signer.label_balance("savings"),
+        vec![Asset::new(asset_id, 70)]
+    );
+    assert!(signer.label_balance("food").is_empty());
+    let labeled = signer.labeled_asset_list().0;
+    assert_eq!(labeled.len(), 3);
+    for (asset, labels) in &labeled {
+        assert_eq!(labels, signer.labels().note_labels(&asset.identifier));
+    }
+    assert!(signer.remove_note_label(&identifiers[2], "savings"));
+    assert!(!signer.remove_note_label(&identifiers[2], "savings"));
+    let transaction_data =
+        TransactionData::<Config>::ToPrivate(identifiers[1], Asset::new(asset_id, 50));
+    assert!(signer.add_transaction_label(&transaction_data, "salary".into()));
+    let mut restored = offline_signer(rng.gen());
+    restored.set_storage(&signer.get_storage());
+    assert_eq!(
+        restored.labels(),
+        signer.labels(),
+        "The labels should be persisted in the storage state."
+    );
+    assert_eq!(
+        restored.labels().transaction_labels(&transaction_data),
+        ["salary".to_string()]
+    );
+    assert_eq!(
+        restored.label_balance("rent"),
+        vec![Asset::new(asset_id, 100)]
+    );
+}
+
+/// Tests that the signer reports its synchronization progress at the requested interval and at
+/// the end of each request.
+#[test]
+fn sync_progress_is_reported() {
+    let mut rng = OsRng;
+    let mut signer = offline_signer(rng.gen());
+    let parameters = signer.parameters().parameters.clone();
+    let address = signer.address().expect("The signer has accounts.");
+    let other_address = offline_signer(rng.gen())
+        .address()
+        .expect("The signer has accounts.");
+    let utxo_note_data = [
+        address,
+        other_address,
+        other_address,
+        address,
+        other_address,
+    ]
+    .into_iter()
+    .map(|address| {
+        let (_, utxo, note) =
+            parameters.derive_mint(address, rng.gen(), Default::default(), &mut rng);
+        (utxo, note)
+    })
+    .collect();
+    let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let recorded = events.clone();
+    signer.set_sync_progress(
+        2.try_into().expect("The interval is not zero."),
+        Arc::new(move |progress| recorded.lock().unwrap().push(progress)),
+    );
+    sync_offline_signer(
+        &mut signer,
+        Default::default(),
+        SyncData {
+            utxo_note_data,
+            nullifier_data: Vec::new(),
+        },
+    );
+    let progress = |processed_utxo_index, new_notes_found| SyncProgress {
+        processed_utxo_index,
+        total: 5,
+        new_notes_found,
+    };
+    assert_eq!(
+        *events.lock().unwrap(),
+        [progress(2, 1), progress(4, 2), progress(5, 2)]
+    );
+    signer.clear_sync_progress();
+}
+
+/// Tests that applying an accumulator diff leaves the signer in the same state as synchronizing
+/// with the full data, and that inconsistent diffs are rejected without changing its state.
+#[test]
+fn accumulator_diff_matches_full_sync() {
+    let mut rng = OsRng;
+    let seed = rng.gen();
+    let mut full = offline_signer(seed);
+    let mut incremental = offline_signer(seed);
+    let parameters = full.parameters().parameters.clone();
+    let address = full.address().expect("The signer has accounts.");
+    let other_address = offline_signer(rng.gen())
+        .address()
+        .expect("The signer has accounts.");
+    let data = SyncData::<Config> {
+        utxo_note_data: [address, other_address, other_address, address]
+            .into_iter()
+            .map(|address| {
+                let (_, utxo, note) =
+                    parameters.derive_mint(address, rng.gen(), Default::default(), &mut rng);
+                (utxo, note)
+            })
+            .collect(),
+        nullifier_data: Vec::new(),
+    };
+    let detected = incremental.detect_notes(&data.note_filters(&parameters));
+    let mut diff = AccumulatorDiff::from_sync_data(
+        &parameters,
+        Default::default(),
+        data.clone(),
+        &detected,
+        Vec::new(),
+        Vec::new(),
+    );
+    assert_eq!(diff.note_count(), detected.len());
+    let items = diff
+        .leaves
+        .iter()
+        .map(|leaf| leaf.item(&parameters))
+        .collect::<Vec<_>>();
+    let mut ledger_accumulator = UtxoAccumulator::empty(&load_utxo_accumulator_model());
+    ledger_accumulator.batch_insert_nonprovable(&items);
+    diff.roots = new_roots(&ledger_accumulator, items).expect("The forest has a root per tree.");
+    let initial_state = StorageState::from_signer(&incremental);
+    let mut tampered = diff.clone();
+    tampered.roots[0] = Default::default();
+    let mut removed = diff.clone();
+    removed.removed_checkpoints.push(Default::default());
+    for inconsistent_diff in [tampered, removed] {
+        assert!(matches!(
+            incremental.sync_diff(inconsistent_diff),
+            Err(SyncError::InconsistentSynchronization { .. })
+        ));
+        assert_eq!(StorageState::from_signer(&incremental), initial_state);
+    }
+    let checkpoint = sync_offline_signer(&mut full, Default::default(), data);
+    let response = incremental
+        .sync_diff(diff)
+        .expect("Applying a consistent diff is not allowed to fail.");
+    assert_eq!(response.checkpoint, checkpoint);
+    assert_eq!(
+        StorageState::from_signer(&incremental),
+        StorageState::from_signer(&full),
+        "Applying the diff should reach the same state as the full synchronization."
+    );
+}
+
+/// Signer hook which forbids spending more than one UTXO of an asset in a single transaction.
+struct ForbidMixing;
+
+impl SignerHook<Config> for ForbidMixing {
+    #[inline]
+    fn check_selection(
+        &self,
+        _: &Asset,
+        selection: &[(Identifier<Config>, u128)],
+    ) -> Result<(), HookRejection> {
+        if selection.len() > 1 {
+            return Err(HookRejection(1));
+        }
+        Ok(())
+    }
+}
+
+/// Signer hook which rejects every set of posts.
+struct RejectPosts;
+
+impl SignerHook<Config> for RejectPosts {
+    #[inline]
+    fn check_posts(
+        &self,
+        _: &transfer::AssetId<Config>,
+        _: &[transfer::TransferPost<Config>],
+    ) -> Result<(), HookRejection> {
+        Err(HookRejection(2))
+    }
+}
+
+/// Tests that the signer runs the hooks registered for the asset of a transaction on its coin
+/// selection and its posts, and skips the hooks registered for other assets.
+#[test]
+fn signer_hooks_are_enforced() {
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let utxo_accumulator_model = rng.gen();
+    let (proving_context, _) = generate_context::<Config, _>(
+        &(),
+        FullParametersRef::<Config>::new(&parameters, &utxo_accumulator_model),
+        &mut rng,
+    )
+    .expect("Unable to create proving and verifying contexts.");
+    let mut signer = Signer::new(
+        parameters.clone(),
+        proving_context,
+        UtxoAccumulator::empty(&utxo_accumulator_model),
+        FromEntropy::from_entropy(),
+    );
+    signer.load_accounts(AccountTable::new(KeySecret::sample(&mut rng)));
+    let address = signer.address().expect("The signer has accounts.");
+    let mixed_id = rng.gen();
+    let posted_id = rng.gen();
+    let utxo_note_data = (0..2)
+        .map(|_| {
+            let (_, utxo, note) = parameters.derive_mint(
+                address,
+                Asset::new(mixed_id, 50),
+                Default::default(),
+                &mut rng,
+            );
+            (utxo, note)
+        })
+        .collect();
+    sync_offline_signer(
+        &mut signer,
+        Default::default(),
+        SyncData {
+            utxo_note_data,
+            nullifier_data: Vec::new(),
+        },
+    );
+    signer.register_hook(Some(mixed_id), Arc::new(ForbidMixing));
+    signer.register_hook(Some(posted_id), Arc::new(RejectPosts));
+    assert_eq!(
+        signer
+            .sign(Transaction::PrivateTransfer(
+                Asset::new(mixed_id, 80),
+                address
+            ))
+            .expect_err("The transfer needs to spend both UTXOs."),
+        SignError::HookRejection(HookRejection(1))
+    );
+    assert_eq!(
+        signer
+            .sign(Transaction::ToPrivate(Asset::new(posted_id, 10)))
+            .expect_err("Every post for this asset is rejected."),
+        SignError::HookRejection(HookRejection(2))
+    );
+    signer
+        .sign(Transaction::ToPrivate(Asset::new(rng.gen(), 10)))
+        .expect("No hook is registered for this asset.");
+}
+
+/// Tests that every post of a transaction which needs a batch join is proven and that the posts
+/// are returned in the order in which they have to be posted.
+#[test]
+fn batched_posts_are_proven_in_order() {
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let utxo_accumulator_model = rng.gen();
+    let (proving_context, verifying_context) = generate_context::<Config, _>(
+        &(),
+        FullParametersRef::<Config>::new(&parameters, &utxo_accumulator_model),
+        &mut rng,
+    )
+    .expect("Unable to create proving and verifying contexts.");
+    let mut signer = Signer::new(
+        parameters.clone(),
+        proving_context,
+        UtxoAccumulator::empty(&utxo_accumulator_model),
+        FromEntropy::from_entropy(),
+    );
+    signer.load_accounts(AccountTable::new(KeySecret::sample(&mut rng)));
+    #[cfg(feature = "rayon")]
+    signer.set_prover(Prover::with_num_threads(2).expect("Unable to build the thread pool."));
+    let address = signer.address().expect("The signer has accounts.");
+    let asset_id = rng.gen();
+    let utxo_note_data = (0..3)
+        .map(|_| {
+            let (_, utxo, note) = parameters.derive_mint(
+                address,
+                Asset::new(asset_id, 50),
+                Default::default(),
+                &mut rng,
+            );
+            (utxo, note)
+        })
+        .collect();
+    sync_offline_signer(
+        &mut signer,
+        Default::default(),
+        SyncData {
+            utxo_note_data,
+            nullifier_data: Vec::new(),
+        },
+    );
+    let posts = signer
+        .sign(Transaction::PrivateTransfer(
+            Asset::new(asset_id, 120),
+            address,
+        ))
+        .expect("The signer owns enough assets for this transfer.")
+        .posts;
+    assert_eq!(posts.len(), 2, "The transfer needs exactly one join.");
+    let values = posts
+        .into_iter()
+        .map(|post| {
+            assert!(
+                post.has_valid_proof(&verifying_context.private_transfer)
+                    .expect("Unable to verify the proof."),
+                "Every post must have a valid proof."
+            );
+            let mut values = signer
+                .transaction_data(post)
+                .expect("The signer is able to decrypt its own posts.")
+                .open()
+                .into_iter()
+                .map(|(_, asset)| asset.value)
+                .collect::<Vec<_>>();
+            values.sort();
+            values
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(values, [vec![0, 100], vec![30, 120]]);
+}
+
+/// Tests that the senders of a transaction are proven against the membership proofs returned by
+/// the witness refresher of the signer instead of its own stale ones.
+#[test]
+fn stale_membership_proofs_are_refreshed() {
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let utxo_accumulator_model = rng.gen();
+    let (proving_context, verifying_context) = generate_context::<Config, _>(
+        &(),
+        FullParametersRef::<Config>::new(&parameters, &utxo_accumulator_model),
+        &mut rng,
+    )
+    .expect("Unable to create proving and verifying contexts.");
+    let mut signer = Signer::new(
+        parameters.clone(),
+        proving_context,
+        UtxoAccumulator::empty(&utxo_accumulator_model),
+        FromEntropy::from_entropy(),
+    );
+    signer.load_accounts(AccountTable::new(KeySecret::sample(&mut rng)));
+    let address = signer.address().expect("The signer has accounts.");
+    let asset_id = rng.gen();
+    let (_, utxo, note) = parameters.derive_mint(
+        address,
+        Asset::new(asset_id, 100),
+        Default::default(),
+        &mut rng,
+    );
+    let item = item_hash::<Config>(&parameters, &utxo);
+    sync_offline_signer(
+        &mut signer,
+        Default::default(),
+        SyncData {
+            utxo_note_data: vec![(utxo, note)],
+            nullifier_data: Vec::new(),
+        },
+    );
+    let mut stale = UtxoAccumulator::empty(&utxo_accumulator_model);
+    let stale_output = assert_provable_membership(&mut stale, &item);
+    let mut fresh = stale.clone();
+    while fresh.output_from(&item) == Some(stale_output) {
+        let (_, utxo, _) = parameters.derive_mint(
+            address,
+            Asset::new(asset_id, 1),
+            Default::default(),
+            &mut rng,
+        );
+        assert!(fresh.insert(&item_hash::<Config>(&parameters, &utxo)));
+    }
+    let fresh_output = fresh
+        .output_from(&item)
+        .expect("The item was inserted into the accumulator.");
+    let sign = |signer: &mut Signer| {
+        let posts = signer
+            .sign(Transaction::PrivateTransfer(
+                Asset::new(asset_id, 50),
+                address,
+            ))
+            .expect("The signer owns enough assets for this transfer.")
+            .posts;
+        assert_eq!(posts.len(), 1, "The transfer does not need any join.");
+        assert!(
+            posts[0]
+                .has_valid_proof(&verifying_context.private_transfer)
+                .expect("Unable to verify the proof."),
+            "The post must have a valid proof."
+        );
+        posts[0].body.sender_posts[0].utxo_accumulator_output
+    };
+    assert_eq!(sign(&mut signer), stale_output);
+    signer.set_witness_refresher(Arc::new(AccumulatorWitnessRefresher::new(fresh)));
+    assert_eq!(sign(&mut signer), fresh_output);
+    signer.clear_witness_refresher();
+    assert_eq!(sign(&mut signer), stale_output);
+}
+
+/// Tests that the signer only issues self-transfers when they are due and within the daily limit
+/// and the budget of its churn policy.
+#[test]
+fn churn_policy_is_rate_limited() {
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let utxo_accumulator_model = rng.gen();
+    let (proving_context, _) = generate_context::<Config, _>(
+        &(),
+        FullParametersRef::<Config>::new(&parameters, &utxo_accumulator_model),
+        &mut rng,
+    )
+    .expect("Unable to create proving and verifying contexts.");
+    let mut signer = Signer::new(
+        parameters.clone(),
+        proving_context,
+        UtxoAccumulator::empty(&utxo_accumulator_model),
+        FromEntropy::from_entropy(),
+    );
+    signer.load_accounts(AccountTable::new(KeySecret::sample(&mut rng)));
+    let address = signer.address().expect("The signer has accounts.");
+    let asset_id = rng.gen();
+    let (_, utxo, note) = parameters.derive_mint(
+        address,
+        Asset::new(asset_id, 100),
+        Default::default(),
+        &mut rng,
+    );
+    sync_offline_signer(
+        &mut signer,
+        Default::default(),
+        SyncData {
+            utxo_note_data: vec![(utxo, note)],
+            nullifier_data: Vec::new(),
+        },
+    );
+    signer.set_churn_policy(Some(ChurnPolicy {
+        id: asset_id,
+        value: 10,
+        min_interval: 60,
+        max_interval: 60,
+        per_day: 1,
+        budget: Some(2),
+    }));
+    let churn = |signer: &mut Signer, now| {
+        signer
+            .churn(now)
+            .expect("The signer owns enough assets for the self-transfers.")
+            .map(|response| response.posts.len())
+    };
+    assert_eq!(
+        churn(&mut signer, 0),
+        None,
+        "The first poll only schedules."
+    );
+    assert_eq!(signer.churn_schedule().next(), Some(60));
+    assert_eq!(churn(&mut signer, 30), None, "No self-transfer is due yet.");
+    assert_eq!(churn(&mut signer, 60), Some(1));
+    assert_eq!(churn(&mut signer, 120), None, "The daily limit is reached.");
+    assert_eq!(churn(&mut signer, SECONDS_PER_DAY + 180), Some(1));
+    assert!(signer.churn_schedule().is_exhausted());
+    assert_eq!(
+        churn(&mut signer, 2 * SECONDS_PER_DAY),
+        None,
+        "The budget is used up."
+    );
+    assert_eq!(signer.churn_schedule().total(), 2);
+}
+
+/// Tests that received notes are only spent once they have the number of confirmations required
+/// for their asset and that they are reported as pending until then.
+#[test]
+fn confirmation_depth_is_enforced() {
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let utxo_accumulator_model = rng.gen();
+    let (proving_context, _) = generate_context::<Config, _>(
+        &(),
+        FullParametersRef::<Config>::new(&parameters, &utxo_accumulator_model),
+        &mut rng,
+    )
+    .expect("Unable to create proving and verifying contexts.");
+    let mut signer = Signer::new(
+        parameters.clone(),
+        proving_context,
+        UtxoAccumulator::empty(&utxo_accumulator_model),
+        FromEntropy::from_entropy(),
+    );
+    signer.load_accounts(AccountTable::new(KeySecret::sample(&mut rng)));
+    let address = signer.address().expect("The signer has accounts.");
+    let asset_id = rng.gen();
+    let other_id = rng.gen();
+    signer.set_confirmation_policy(ConfirmationPolicy {
+        default: 0,
+        assets: vec![(asset_id, 1)],
+    });
+    let mut mint = |asset| {
+        let (_, utxo, note) = parameters.derive_mint(address, asset, Default::default(), &mut rng);
+        SyncData::<Config> {
+            utxo_note_data: vec![(utxo, note)],
+            nullifier_data: Vec::new(),
+        }
+    };
+    let first = mint(Asset::new(asset_id, 50));
+    let second = mint(Asset::new(other_id, 20));
+    let checkpoint = sync_offline_signer(&mut signer, Default::default(), first);
+    assert_eq!(
+        signer.spendable_balance(&asset_id),
+        SpendableBalance {
+            available: 0,
+            pending: 50
+        }
+    );
+    assert_eq!(
+        signer
+            .sign(Transaction::PrivateTransfer(
+                Asset::new(asset_id, 30),
+                address
+            ))
+            .expect_err("The note is waiting for a confirmation."),
+        SignError::InsufficientBalance(Asset::new(asset_id, 30))
+    );
+    sync_offline_signer(&mut signer, checkpoint, second);
+    assert_eq!(
+        signer.spendable_balance(&asset_id),
+        SpendableBalance {
+            available: 50,
+            pending: 0
+        }
+    );
+    assert_eq!(
+        signer.spendable_balance(&other_id),
+        SpendableBalance {
+            available: 20,
+            pending: 0
+        },
+        "Assets without a depth of their own use the default depth."
+    );
+    let mut restored = offline_signer(rng.gen());
+    restored.set_storage(&signer.get_storage());
+    assert_eq!(
+        restored.confirmation_policy(),
+        signer.confirmation_policy(),
+        "The confirmation policy should be persisted in the storage state."
+    );
+    signer
+        .sign(Transaction::PrivateTransfer(
+            Asset::new(asset_id, 30),
+            address,
+        ))
+        .expect("The note has enough confirmations.");
+}
+
+/// Tests that the signer keeps the block height it is synchronized to and the heights at which
+/// its notes were received, and that spent notes are forgotten.
+#[test]
+fn sync_height_is_tracked() {
+    let mut rng = OsRng;
+    let mut signer = offline_signer(rng.gen());
+    let parameters = signer.parameters().parameters.clone();
+    let address = signer.address().expect("The signer has accounts.");
+    let mut authorization_context = *signer
+        .authorization_context()
+        .expect("The signer has accounts.");
+    let decryption_key = parameters.derive_decryption_key(&mut authorization_context);
+    let mut notes = Vec::new();
+    for _ in 0..2 {
+        let (_, utxo, note) =
+            parameters.derive_mint(address, rng.gen(), Default::default(), &mut rng);
+        let (identifier, asset) = parameters
+            .open_with_check(&decryption_key, &utxo, note.clone())
+            .expect("The note was minted for this address.");
+        notes.push((identifier, asset, utxo, note));
+    }
+    let (first, first_asset, first_utxo, first_note) = notes[0].clone();
+    let (second, _, second_utxo, second_note) = notes[1].clone();
+    let (_, _, first_nullifier) =
+        parameters.derive_spend(&mut authorization_context, first, first_asset, &mut rng);
+    let sync = |signer: &mut Signer, origin_checkpoint, data, height| {
+        signer
+            .sync(SyncRequest {
+                origin_checkpoint,
+                data,
+                height: Some(height),
+            })
+            .expect("Synchronizing with consistent data is not allowed to fail.")
+            .checkpoint
+    };
+    let checkpoint = sync(
+        &mut signer,
+        Default::default(),
+        SyncData {
+            utxo_note_data: vec![(first_utxo, first_note)],
+            nullifier_data: Vec::new(),
+        },
+        5,
+    );
+    assert_eq!(signer.sync_height().height(), Some(5));
+    assert_eq!(signer.sync_height().note_height(&first), Some(5));
+    let checkpoint = sync(
+        &mut signer,
+        checkpoint,
+        SyncData {
+            utxo_note_data: vec![(second_utxo, second_note)],
+            nullifier_data: vec![first_nullifier],
+        },
+        9,
+    );
+    assert_eq!(
+        signer.sync_height().note_height(&first),
+        None,
+        "Spent notes should be forgotten."
+    );
+    assert_eq!(signer.sync_height().note_height(&second), Some(9));
+    let checkpoint = sync(&mut signer, checkpoint, Default::default(), 14);
+    assert_eq!(
+        signer.sync_height().notes_older_than(5).collect::<Vec<_>>(),
+        vec![&second]
+    );
+    assert_eq!(signer.sync_height().notes_older_than(6).count(), 0);
+    sync(&mut signer, checkpoint, Default::default(), 3);
+    assert_eq!(
+        signer.sync_height().height(),
+        Some(14),
+        "Heights should never go backwards."
+    );
+    let mut restored = offline_signer(rng.gen());
+    restored.set_storage(&signer.get_storage());
+    assert_eq!(
+        restored.sync_height(),
+        signer.sync_height(),
+        "The heights should be persisted in the storage state."
+    );
+}
+
+/// Tests that the nullifiers broadcast by the ledger mark the notes they spend as spent before
+/// the next synchronization, which then withdraws them.
+#[test]
+fn observed_nullifiers_mark_notes_as_spent() {
+    let mut rng = OsRng;
+    let mut signer = offline_signer(rng.gen());
+    let parameters = signer.parameters().parameters.clone();
+    let address = signer.address().expect("The signer has accounts.");
+    let mut authorization_context = *signer
+        .authorization_context()
+        .expect("The signer has accounts.");
+    let decryption_key = parameters.derive_decryption_key(&mut authorization_context);
+    let asset_id = rng.gen();
+    let mut ledger = InMemoryLedger::<Config>::new(parameters.clone());
+    let mut notes = Vec::new();
+    for value in [40, 60] {
+        let (_, utxo, note) = parameters.derive_mint(
+            address,
+            Asset::new(asset_id, value),
+            Default::default(),
+            &mut rng,
+        );
+        let (identifier, asset) = parameters
+            .open_with_check(&decryption_key, &utxo, note.clone())
+            .expect("The note was minted for this address.");
+        notes.push(IdentifiedAsset::<Config>::new(identifier, asset));
+        ledger.push_data(SyncData {
+            utxo_note_data: vec![(utxo, note)],
+            nullifier_data: Vec::new(),
+        });
+    }
+    let checkpoint = sync_offline_signer(
+        &mut signer,
+        Default::default(),
+        ledger.pull(&Default::default(), usize::MAX).data,
+    );
+    ledger.subscribe_nullifiers();
+    let (_, _, nullifier) = parameters.derive_spend(
+        &mut authorization_context,
+        notes[0].identifier,
+        notes[0].asset,
+        &mut rng,
+    );
+    ledger.push_data(SyncData {
+        utxo_note_data: Vec::new(),
+        nullifier_data: vec![nullifier],
+    });
+    let nullifiers = ledger.next_nullifiers();
+    assert_eq!(nullifiers.len(), 1);
+    assert!(ledger.next_nullifiers().is_empty());
+    assert_eq!(
+        signer
+            .observe_nullifiers(nullifiers.clone())
+            .expect("The signer has accounts."),
+        vec![notes[0]]
+    );
+    assert!(
+        signer
+            .observe_nullifiers(nullifiers)
+            .expect("The signer has accounts.")
+            .is_empty(),
+        "Spends are only reported once."
+    );
+    assert_eq!(signer.observed_spends().notes(), [notes[0].identifier]);
+    assert_eq!(
+        signer.spendable_balance(&asset_id),
+        SpendableBalance {
+            available: 60,
+            pending: 0
+        }
+    );
+    let response = signer
+        .sync(SyncRequest {
+            data: ledger.pull(&checkpoint, usize::MAX).data,
+            origin_checkpoint: checkpoint,
+            height: None,
+        })
+        .expect("Synchronizing with consistent data is not allowed to fail.");
+    assert_eq!(
+        response.balance_update,
+        BalanceUpdate::Partial {
+            deposit: Vec::new(),
+            withdraw: vec![notes[0].asset],
+        },
+        "The synchronization which brings the nullifier withdraws the note."
+    );
+    assert!(signer.observed_spends().is_empty());
+}
+
+/// Tests that validating a request reports every problem which would make signing fail, without
+/// building any proof.
+#[test]
+fn request_problems_are_reported() {
+    let mut rng = OsRng;
+    let mut signer = offline_signer(rng.gen());
+    let parameters = signer.parameters().parameters.clone();
+    let address = signer.address().expect("The signer has accounts.");
+    let asset_id = rng.gen();
+    let mut ledger = InMemoryLedger::<Config>::new(parameters.clone());
+    for value in [40, 60] {
+        let (_, utxo, note) = parameters.derive_mint(
+            address,
+            Asset::new(asset_id, value),
+            Default::default(),
+            &mut rng,
+        );
+        ledger.push_data(SyncData {
+            utxo_note_data: vec![(utxo, note)],
+            nullifier_data: Vec::new(),
+        });
+    }
+    sync_offline_signer(
+        &mut signer,
+        Default::default(),
+        ledger.pull(&Default::default(), usize::MAX).data,
+    );
+    signer.set_spending_policy(SpendingPolicy {
+        limits: vec![AssetLimit {
+            id: asset_id,
+            per_transaction: Some(120),
+            per_day: None,
+        }],
+        ..Default::default()
+    });
+    let requested = Asset::new(asset_id, 150);
+    assert_eq!(
+        signer.validate_request(&Transaction::PrivateTransfer(requested, Default::default())),
+        vec![
+            RequestProblem::MissingProvingContext(TransferShape::PrivateTransfer),
+            RequestProblem::InvalidAddress,
+            RequestProblem::PolicyViolation(PolicyViolation::TransactionLimit(requested)),
+            RequestProblem::InsufficientBalance {
+                requested,
+                balance: SpendableBalance {
+                    available: 100,
+                    pending: 0
+                }
+            },
+        ]
+    );
+    signer.register_hook(Some(asset_id), Arc::new(ForbidMixing));
+    assert_eq!(
+        signer.validate_request(&Transaction::PrivateTransfer(
+            Asset::new(asset_id, 80),
+            address
+        )),
+        vec![
+            RequestProblem::MissingProvingContext(TransferShape::PrivateTransfer),
+            RequestProblem::HookRejection(HookRejection(1)),
+        ]
+    );
+    assert_eq!(
+        signer.validate_request(&Transaction::PrivateTransfer(
+            Asset::new(asset_id, 50),
+            address
+        )),
+        vec![RequestProblem::MissingProvingContext(
+            TransferShape::PrivateTransfer
+        )]
+    );
+    assert_eq!(
+        signer.spendable_balance(&asset_id),
+        SpendableBalance {
+            available: 100,
+            pending: 0
+        },
+        "Validating requests does not spend any asset."
+    );
+}
+
+/// Tests that tagged withdrawals carry their tag and that the tag is bound to the proof.
+#[test]
+fn tagged_withdrawals_bind_the_tag() {
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let utxo_accumulator_model = rng.gen();
+    let full_parameters = FullParametersRef::<Config>::new(&parameters, &utxo_accumulator_model);
+    let (proving_context, _) = generate_context::<Config, _>(&(), full_parameters, &mut rng)
+        .expect("Unable to create proving and verifying contexts.");
+    let (tagged_proving_context, tagged_verifying_context) =
+        ToPublic::generate_tagged_context(&(), full_parameters, &mut rng)
+            .expect("Unable to create tagged proving and verifying contexts.");
+    let mut signer = Signer::new(
+        parameters.clone(),
+        proving_context,
+        UtxoAccumulator::empty(&utxo_accumulator_model),
+        FromEntropy::from_entropy(),
+    );
+    signer.load_accounts(AccountTable::new(KeySecret::sample(&mut rng)));
+    let address = signer.address().expect("The signer has accounts.");
+    let asset_id = rng.gen();
+    let (_, utxo, note) = parameters.derive_mint(
+        address,
+        Asset::new(asset_id, 100),
+        Default::default(),
+        &mut rng,
+    );
+    sync_offline_signer(
+        &mut signer,
+        Default::default(),
+        SyncData {
+            utxo_note_data: vec![(utxo, note)],
+            nullifier_data: Vec::new(),
+        },
+    );
+    let public_account = rng.gen();
+    let tag: WithdrawalTag = rng.gen();
+    assert!(
+        matches!(
+            signer.sign_tagged_to_public(Asset::new(asset_id, 40), public_account, tag),
+            Err(SignError::MissingProvingContext(TransferShape::ToPublic))
+        ),
+        "Tagged withdrawals require the tagged proving context."
+    );
+    signer.set_tagged_to_public(tagged_proving_context);
+    let mut posts = signer
+        .sign_tagged_to_public(Asset::new(asset_id, 40), public_account, tag)
+        .expect("The signer owns enough assets for this withdrawal.")
+        .posts;
+    assert_eq!(posts.len(), 1, "The withdrawal does not need any joins.");
+    let mut post = posts.remove(0);
+    assert_eq!(post.body.tag, Some(tag));
+    assert!(InputLayout::from_post(&post).has_tag);
+    assert_eq!(
+        ProofInputBuilder::from_post(&post, TransferShape::ToPublic)
+            .build()
+            .expect("The post should match the tagged ToPublic layout."),
+        post.generate_proof_input()
+    );
+    assert!(
+        post.has_valid_proof(&tagged_verifying_context)
+            .expect("Unable to verify the proof."),
+        "The tagged post must have a valid proof."
+    );
+    post.body.tag = Some(rng.gen());
+    assert!(
+        !post
+            .has_valid_proof(&tagged_verifying_context)
+            .expect("Unable to verify the proof."),
+        "Changing the tag must invalidate the proof."
+    );
+}
+
+/// Tests that sign requests which name their recipient are sent to the address the name resolves
+/// to.
+#[tokio::test]
+async fn recipient_names_are_resolved() {
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let utxo_accumulator_model = rng.gen();
+    let (proving_context, _) = generate_context::<Config, _>(
+        &(),
+        FullParametersRef::<Config>::new(&parameters, &utxo_accumulator_model),
+        &mut rng,
+    )
+    .expect("Unable to create proving and verifying contexts.");
+    let mut signer = Signer::new(
+        parameters.clone(),
+        proving_context,
+        UtxoAccumulator::empty(&utxo_accumulator_model),
+        FromEntropy::from_entropy(),
+    );
+    signer.load_accounts(AccountTable::new(KeySecret::sample(&mut rng)));
+    let mut recipient = Signer::from_loader(
+        parameters.clone(),
+        Arc::new(|_| None),
+        EvictionPolicy::default(),
+        UtxoAccumulator::empty(&utxo_accumulator_model),
+        FromEntropy::from_entropy(),
+    );
+    recipient.load_accounts(AccountTable::new(KeySecret::sample(&mut rng)));
+    let address = signer.address().expect("The signer has accounts.");
+    let asset_id = rng.gen();
+    let (_, utxo, note) = parameters.derive_mint(
+        address,
+        Asset::new(asset_id, 100),
+        Default::default(),
+        &mut rng,
+    );
+    sync_offline_signer(
+        &mut signer,
+        Default::default(),
+        SyncData {
+            utxo_note_data: vec![(utxo, note)],
+            nullifier_data: Vec::new(),
+        },
+    );
+    let request = |transaction, name: &str| SignRequest {
+        transaction,
+        metadata: None,
+        recipient_name: Some(name.into()),
+    };
+    let private_transfer = Transaction::PrivateTransfer(Asset::new(asset_id, 40), address);
+    assert!(matches!(
+        Connection::sign(&mut signer, request(private_transfer, "bob"))
+            .await
+            .expect("Signing with the local signer cannot fail."),
+        Err(SignError::NameResolution(
+            NameResolutionError::MissingResolver
+        ))
+    ));
+    signer.set_name_resolver(Arc::new(StaticNameResolver::from_iter([(
+        "bob".into(),
+        recipient.address().expect("The recipient has accounts."),
+    )])));
+    assert!(matches!(
+        Connection::sign(&mut signer, request(private_transfer, "carol"))
+            .await
+            .expect("Signing with the local signer cannot fail."),
+        Err(SignError::NameResolution(NameResolutionError::UnknownName))
+    ));
+    assert!(matches!(
+        Connection::sign(
+            &mut signer,
+            request(
+                Transaction::ToPublic(Asset::new(asset_id, 40), rng.gen()),
+                "bob"
+            )
+        )
+        .await
+        .expect("Signing with the local signer cannot fail."),
+        Err(SignError::NameResolution(
+            NameResolutionError::UnsupportedTransaction
+        ))
+    ));
+    let mut posts = Connection::sign(&mut signer, request(private_transfer, "bob"))
+        .await
+        .expect("Signing with the local signer cannot fail.")
+        .expect("The signer owns enough assets for this transfer.")
+        .posts;
+    assert_eq!(posts.len(), 1, "The transfer does not need any joins.");
+    let received = recipient
+        .transaction_data(posts.remove(0))
+        .expect("The recipient is able to decrypt the transfer sent to its name.")
+        .open()
+        .into_iter()
+        .map(|(_, asset)| asset.value)
+        .collect::<Vec<_>>();
+    assert_eq!(received, [40]);
+}
+
+/// Tests that a wallet synchronizes with an [`InMemoryLedger`] in batches of blocks.
+#[tokio::test]
+async fn in_memory_ledger_sync() {
+    let mut rng = OsRng;
+    let mut signer = offline_signer(rng.gen());
+    let parameters = signer.parameters().parameters.clone();
+    let address = signer.address().expect("The signer has accounts.");
+    let asset_id = rng.gen();
+    let mut ledger = InMemoryLedger::<Config>::new(parameters.clone());
+    let mut expected_balance = 0;
+    for _ in 0..5 {
+        let value = rng.gen_range(1..u32::MAX as u128);
+        let (_, utxo, note) = parameters.derive_mint(
+            address,
+            Asset::new(asset_id, value),
+            Default::default(),
+            &mut rng,
+        );
+        ledger.push_data(SyncData {
+            utxo_note_data: vec![(utxo, note)],
+            nullifier_data: Vec::new(),
+        });
+        expected_balance += value;
+    }
+    ledger.set_sync_config(SyncConfig {
+        batch_size: 2,
+        ..Default::default()
+    });
+    let mut wallet = Wallet::<Config, _>::new(ledger, signer);
+    assert!(
+        wallet
+            .sync_partial()
+            .await
+            .expect("Synchronizing with the in-memory ledger is not allowed to fail.")
+            .is_continue(),
+        "The first batch should not contain the whole ledger."
+    );
+    assert_eq!(wallet.signer().sync_height().height(), Some(2));
+    wallet
+        .sync()
+        .await
+        .expect("Synchronizing with the in-memory ledger is not allowed to fail.");
+    assert_eq!(wallet.balance(&asset_id), expected_balance);
+    assert_eq!(wallet.checkpoint(), wallet.ledger().checkpoint());
+    assert_eq!(wallet.signer().sync_height().height(), Some(5));
+}
@@ -0,0 +1,220 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Simulation Testing Suite
+
+use crate::{
+    config::{
+        utxo::AssetId, AccountId, Asset, Config, FullParametersRef, MultiProvingContext,
+        Parameters, Transaction, UtxoAccumulatorModel,
+    },
+    signer::base::Signer,
+    simulation::{
+        ledger::{
+            fault::{FaultConfig, FaultyLedger, FaultyLedgerConnection, SharedFaultyLedger},
+            Ledger,
+        },
+        sample_signer, Simulation,
+    },
+};
+use alloc::sync::Arc;
+use manta_accounting::{
+    transfer::canonical::generate_context,
+    wallet::{test::PublicBalanceOracle, Wallet},
+};
+use manta_crypto::rand::{OsRng, Rand, TestRng};
+use tokio::sync::RwLock;
+
+/// Test Wallet Type
+type TestWallet = Wallet<Config, FaultyLedgerConnection, Signer>;
+
+/// Asset Id used by the Tests
+const ASSET_ID: u128 = 8;
+
+/// Starting Public Balance of the Test Account
+const STARTING_BALANCE: u128 = 1000;
+
+/// Samples parameters and contexts, and builds a [`FaultyLedger`] with `config` in which `account`
+/// holds [`STARTING_BALANCE`] public assets, together with a wallet posting from `account`.
+#[inline]
+async fn faulty_wallet(
+    account: AccountId,
+    config: FaultConfig,
+    seed: u64,
+) -> (SharedFaultyLedger, TestWallet) {
+    let mut rng = OsRng;
+    let parameters: Parameters = rng.gen();
+    let utxo_accumulator_model: UtxoAccumulatorModel = rng.gen();
+    let (proving_context, verifying_context): (MultiProvingContext, _) = generate_context(
+        &(),
+        FullParametersRef::new(&parameters, &utxo_accumulator_model),
+        &mut rng,
+    )
+    .expect("Unable to create proving and verifying contexts.");
+    let mut ledger = Ledger::new(
+        utxo_accumulator_model.clone(),
+        verifying_context,
+        parameters.clone(),
+    );
+    ledger.set_public_balance(account, AssetId::from(ASSET_ID), STARTING_BALANCE);
+    let ledger = Arc::new(RwLock::new(FaultyLedger::new(
+        ledger,
+        config,
+        TestRng::new(seed),
+    )));
+    let wallet = Wallet::start(
+        FaultyLedgerConnection::new(account, ledger.clone()),
+        sample_signer(
+            &proving_context,
+            &parameters,
+            &utxo_accumulator_model,
+            &mut rng,
+        ),
+    )
+    .await
+    .expect("Starting a wallet on an empty ledger is not allowed to fail.");
+    (ledger, wallet)
+}
+
+/// Deposits `value` assets from the public account of `wallet`.
+#[inline]
+async fn deposit(wallet: &mut TestWallet, value: u128) -> bool {
+    wallet
+        .post(
+            Transaction::ToPrivate(Asset::new(AssetId::from(ASSET_ID), value)),
+            None,
+        )
+        .await
+        .expect("Error posting ToPrivate")
+}
+
+/// Returns the public balance of the account of `wallet`.
+#[inline]
+async fn public_balance(wallet: &TestWallet) -> u128 {
+    wallet
+        .ledger()
+        .public_balances()
+        .await
+        .map(|balances| balances.value(&AssetId::from(ASSET_ID)))
+        .unwrap_or_default()
+}
+
+/// Tests that submitting the same posts a second time is rejected by the ledger.
+#[tokio::test]
+async fn duplicate_submissions_are_rejected() {
+    let account = OsRng.gen();
+    let config = FaultConfig {
+        duplicate_submissions: 100,
+        ..Default::default()
+    };
+    let (ledger, mut wallet) = faulty_wallet(account, config, 0).await;
+    assert!(
+        deposit(&mut wallet, 300).await,
+        "The deposit must be accepted."
+    );
+    wallet.sync().await.expect("Sync error");
+    assert_eq!(wallet.balance(&AssetId::from(ASSET_ID)), 300);
+    assert_eq!(public_balance(&wallet).await, STARTING_BALANCE - 300);
+    let report = ledger.read().await.report();
+    assert_eq!(report.duplicate_submissions, 1);
+    assert!(report.is_sound(), "Unsound ledger: {report:?}.");
+}
+
+/// Tests that the wallet recovers its balance when most of the sync batches are dropped.
+#[tokio::test]
+async fn dropped_sync_batches_are_requested_again() {
+    let account = OsRng.gen();
+    let config = FaultConfig {
+        dropped_sync_batches: 90,
+        ..Default::default()
+    };
+    let (ledger, mut wallet) = faulty_wallet(account, config, 1).await;
+    for value in [100, 200, 300] {
+        assert!(
+            deposit(&mut wallet, value).await,
+            "The deposit must be accepted."
+        );
+    }
+    wallet.sync().await.expect("Sync error");
+    assert_eq!(wallet.balance(&AssetId::from(ASSET_ID)), 600);
+    assert_eq!(public_balance(&wallet).await, STARTING_BALANCE - 600);
+    assert!(ledger.read().await.report().is_sound());
+}
+
+/// Tests that reorganizing blocks which were not read yet conserves the total balance of the
+/// account. Every deposit syncs the wallet first, so each of the three deposits is reorganized
+/// before it is read.
+#[tokio::test]
+async fn reorgs_conserve_balances() {
+    let account = OsRng.gen();
+    let config = FaultConfig {
+        reorgs: 100,
+        ..Default::default()
+    };
+    let (ledger, mut wallet) = faulty_wallet(account, config, 2).await;
+    for value in [100, 200, 300] {
+        assert!(
+            deposit(&mut wallet, value).await,
+            "The deposit must be accepted."
+        );
+    }
+    wallet.sync().await.expect("Sync error");
+    let private_balance = wallet.balance(&AssetId::from(ASSET_ID));
+    assert_eq!(
+        private_balance + public_balance(&wallet).await,
+        STARTING_BALANCE,
+        "Funds were created or destroyed by the reorganization."
+    );
+    let report = ledger.read().await.report();
+    assert_eq!(report.reorgs, 3);
+    assert!(report.is_sound(), "Unsound ledger: {report:?}.");
+}
+
+/// Runs the full simulation with every fault enabled.
+#[ignore] // We don't run this test on the CI because it takes a long time to run.
+#[tokio::test]
+async fn faulty_simulation() {
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let utxo_accumulator_model = rng.gen();
+    let (proving_context, verifying_context) = generate_context(
+        &(),
+        FullParametersRef::new(&parameters, &utxo_accumulator_model),
+        &mut rng,
+    )
+    .expect("Unable to create proving and verifying contexts.");
+    Simulation {
+        actor_count: 3,
+        actor_lifetime: 20,
+        asset_id_count: 2,
+        starting_balance: 1_000_000,
+        seed: Some(0),
+        faults: FaultConfig {
+            dropped_sync_batches: 20,
+            reordered_posts: 20,
+            reorgs: 20,
+            duplicate_submissions: 20,
+        },
+    }
+    .run(
+        &parameters,
+        &utxo_accumulator_model,
+        &proving_context,
+        verifying_context,
+        &mut rng,
+    )
+    .await
+}
@@ -17,17 +17,30 @@
 //! Manta Pay Transfer Testing
 
 use crate::{
+    config::utxo::SchnorrHashFunction,
     config::{
-        FullParametersRef, Parameters, PrivateTransfer, ProofSystem, ToPrivate, ToPublic,
-        TransferPost,
+        Asset, Authorization, Config, FullParametersRef, MultiVerifyingContext, Parameters,
+        PreparedVerifyingKeyCache, PrivateTransfer, ProofInputBuilder, ProofSystem, SpendingKey,
+        ToPrivate, ToPublic, TransferPost, VersionedVerifier, VersionedVerifierError,
     },
     test::payment::UtxoAccumulator,
 };
-use manta_accounting::transfer::{test::validity_check_with_fuzzing, BodyWithAccountsRef};
+use manta_accounting::transfer::{
+    canonical::{generate_context, TransferShape},
+    input::{InputComponent, InputLayout, InputLayoutError},
+    test::{validity_check_with_fuzzing, TransferDistribution},
+    utxo::auth::{DeriveContext, ProvePartialAuthorization},
+    BodyWithAccountsRef,
+};
+use manta_accounting::wallet::signer::airgap::{
+    split, AirGapRequest, AirGapResponse, Frame, FrameError, Reassembler,
+};
 use manta_crypto::{
     accumulator::Accumulator,
+    algebra::Group,
     constraint::{measure::Measure, ProofSystem as _},
     rand::{fuzz::Fuzz, OsRng, Rand, Sample},
+    signature::{Derive, Sign, SignPrehashed, Verify, VerifyPrehashed},
 };
 
 /// Tests the generation of proving/verifying contexts for [`ToPrivate`].
@@ -108,6 +121,162 @@ fn to_public() {
     );
 }
 
+/// Tests that the [`PreparedVerifyingKeyCache`] prepares a verifying key once and accepts a valid
+/// [`ToPrivate`] proof.
+#[test]
+fn prepared_verifying_key_cache() {
+    let mut rng = OsRng;
+    let parameters: Parameters = rng.gen();
+    let utxo_accumulator_model = rng.gen();
+    let full_parameters = FullParametersRef::new(&parameters, &utxo_accumulator_model);
+    let (proving_context, _) = ToPrivate::generate_context(&(), full_parameters, &mut rng)
+        .expect("Unable to generate ToPrivate context.");
+    let post = ToPrivate::from_address(
+        &parameters,
+        rng.gen(),
+        Asset::new(rng.gen(), rng.gen()),
+        Default::default(),
+        &mut rng,
+    )
+    .into_post(
+        full_parameters,
+        &proving_context,
+        None,
+        Vec::new(),
+        &mut rng,
+    )
+    .expect("Unable to build ToPrivate proof.")
+    .expect("ToPrivate transactions do not require authorization.");
+    let verifying_key = proving_context.verifying_key();
+    let mut cache = PreparedVerifyingKeyCache::new(1);
+    assert!(cache.get(verifying_key).is_none());
+    for _ in 0..2 {
+        let context = cache
+            .prepare(verifying_key)
+            .expect("Unable to prepare verifying key.");
+        assert!(
+            post.has_valid_proof(context)
+                .expect("Unable to verify ToPrivate proof."),
+            "The ToPrivate proof should have been valid."
+        );
+        assert_eq!(cache.len(), 1);
+    }
+    assert!(cache.get(verifying_key).is_some());
+}
+
+/// Tests that the [`VersionedVerifier`] checks every [`TransferPost`] against the verifying
+/// contexts of the circuit version it is tagged with.
+#[test]
+fn versioned_verifier() {
+    let mut rng = OsRng;
+    let parameters: Parameters = rng.gen();
+    let utxo_accumulator_model = rng.gen();
+    let full_parameters = FullParametersRef::new(&parameters, &utxo_accumulator_model);
+    let mut generate_contexts = || {
+        let (proving_context, verifying_context) =
+            ToPrivate::generate_context(&(), full_parameters, &mut rng)
+                .expect("Unable to generate ToPrivate context.");
+        (
+            proving_context,
+            MultiVerifyingContext {
+                to_private: verifying_context.clone(),
+                private_transfer: verifying_context.clone(),
+                to_public: verifying_context,
+            },
+        )
+    };
+    let (old_proving_context, old_verifying_contexts) = generate_contexts();
+    let (new_proving_context, new_verifying_contexts) = generate_contexts();
+    let mut prove = |proving_context, version| {
+        ToPrivate::from_address(
+            &parameters,
+            rng.gen(),
+            Asset::new(rng.gen(), rng.gen()),
+            Default::default(),
+            &mut rng,
+        )
+        .into_post(full_parameters, proving_context, None, Vec::new(), &mut rng)
+        .expect("Unable to build ToPrivate proof.")
+        .expect("ToPrivate transactions do not require authorization.")
+        .with_version(version)
+    };
+    let old_post = prove(&old_proving_context, 0);
+    let new_post = prove(&new_proving_context, 1);
+    let mut verifier = VersionedVerifier::new(1, new_verifying_contexts);
+    assert_eq!(
+        verifier.verify(&old_post),
+        Err(VersionedVerifierError::UnknownVersion(0))
+    );
+    assert_eq!(verifier.verify(&new_post), Ok(true));
+    verifier.insert(0, old_verifying_contexts);
+    assert_eq!(verifier.versions().collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(verifier.verify(&old_post), Ok(true));
+    assert_eq!(
+        verifier.verify(&old_post.clone().with_version(1)),
+        Ok(false)
+    );
+    verifier.remove(0);
+    assert_eq!(
+        verifier.verify(&old_post),
+        Err(VersionedVerifierError::UnknownVersion(0))
+    );
+}
+
+/// Tests that the [`ProofInputBuilder`] assembles the same public input as
+/// [`TransferPost::generate_proof_input`] and reports components that do not match the layout.
+#[test]
+fn proof_input_builder() {
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let mut utxo_accumulator = UtxoAccumulator::new(rng.gen());
+    let (proving_context, verifying_context) = PrivateTransfer::generate_context(
+        &(),
+        FullParametersRef::new(&parameters, utxo_accumulator.model()),
+        &mut rng,
+    )
+    .expect("Unable to create proving and verifying contexts.");
+    let post = PrivateTransfer::sample_post(
+        &proving_context,
+        &parameters,
+        &mut utxo_accumulator,
+        Some(&rng.gen()),
+        &mut rng,
+    )
+    .expect("Random Private Transfer should have produced a proof.")
+    .expect("Private Transfers with a spending key should have produced a post.");
+    let input = ProofInputBuilder::from_post(&post, TransferShape::PrivateTransfer)
+        .build()
+        .expect("The post should match the PrivateTransfer layout.");
+    assert_eq!(input, post.generate_proof_input());
+    assert!(
+        ProofSystem::verify(&verifying_context, &input, &post.body.proof)
+            .expect("Unable to verify proof."),
+        "The assembled input should verify against the post proof."
+    );
+    assert_eq!(
+        InputLayout::from_post(&post),
+        TransferShape::PrivateTransfer.into()
+    );
+    let sender = &post.body.sender_posts[0];
+    let authorization_key = &post
+        .authorization_signature
+        .as_ref()
+        .expect("Private Transfers require authorization.")
+        .authorization_key;
+    assert_eq!(
+        ProofInputBuilder::new(post.version, TransferShape::PrivateTransfer.into())
+            .sender(&sender.utxo_accumulator_output, &sender.nullifier)
+            .authorization_key(authorization_key)
+            .build(),
+        Err(InputLayoutError {
+            version: post.version,
+            component: InputComponent::Senders,
+            expected: 2,
+            found: 1,
+        })
+    );
+}
+
 /// Checks that an empty message will produce a valid signature.
 #[test]
 fn check_empty_message_signature() {
@@ -124,6 +293,76 @@ fn check_empty_message_signature() {
     );
 }
 
+/// Checks that prehashed signatures verify against their digest and are not interchangeable
+/// with signatures over the full message.
+#[test]
+fn check_prehashed_signature() {
+    let mut rng = OsRng;
+    let scheme = Parameters::gen(&mut rng).signature_scheme();
+    let signing_key = rng.gen();
+    let message = (0..1024).map(|_| rng.gen()).collect::<Vec<u8>>();
+    let digest = SchnorrHashFunction::digest(&message);
+    assert!(
+        manta_crypto::signature::test::prehashed_correctness(
+            &scheme,
+            &signing_key,
+            &rng.gen(),
+            &digest,
+            &mut (),
+        ),
+        "Unable to verify prehashed signature correctly."
+    );
+    let verifying_key = scheme.derive(&signing_key, &mut ());
+    let signature = scheme.sign(&signing_key, &rng.gen(), &message, &mut ());
+    assert!(
+        !scheme.verify_prehashed(&verifying_key, &digest, &signature, &mut ()),
+        "Message signatures must not verify as prehashed signatures."
+    );
+    let signature = scheme.sign_prehashed(&signing_key, &rng.gen(), &digest, &mut ());
+    assert!(
+        !scheme.verify(&verifying_key, &digest.to_vec(), &signature, &mut ()),
+        "Prehashed signatures must not verify as message signatures."
+    );
+}
+
+/// Checks that the partial authorizations of a 2-of-2 spending key combine into an authorization
+/// for the full spending key and that partial authorizations under different randomness are
+/// rejected.
+#[test]
+fn check_partial_authorization() {
+    let mut rng = OsRng;
+    let parameters = Parameters::gen(&mut rng);
+    let shares: [SpendingKey; 2] = [rng.gen(), rng.gen()];
+    let spending_key = shares[0].add(&shares[1], &mut ());
+    assert!(
+        manta_accounting::transfer::utxo::auth::test::partial_authorization_correctness(
+            &parameters,
+            &shares,
+            &spending_key,
+            &mut rng,
+        ),
+        "Combined partial authorizations should authorize the full spending key."
+    );
+    let randomness = parameters.sample_authorization_randomness(&mut rng);
+    let other_randomness = parameters.sample_authorization_randomness(&mut rng);
+    assert!(
+        Authorization::from_partial_authorizations(
+            &parameters,
+            &randomness,
+            [
+                parameters.prove_partial(&shares[0], &randomness),
+                parameters.prove_partial(&shares[1], &other_randomness),
+            ],
+        )
+        .is_none(),
+        "Partial authorizations under different randomness must not combine."
+    );
+    assert!(
+        Authorization::from_partial_authorizations(&parameters, &randomness, []).is_none(),
+        "At least one partial authorization is required."
+    );
+}
+
 /// Checks that a random [`PrivateTransfer`] produces a valid transaction signature.
 #[test]
 fn private_transfer_check_signature() {
@@ -158,6 +397,178 @@ fn private_transfer_check_signature() {
     );
 }
 
+/// Checks that the calibrated proof estimates match the constraint systems and the encoded posts
+/// of each transfer shape.
+#[test]
+fn estimate_proofs() {
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let mut utxo_accumulator = UtxoAccumulator::new(rng.gen());
+    let full_parameters = FullParametersRef::new(&parameters, utxo_accumulator.model());
+    let (proving_context, _) = generate_context::<Config, _>(&(), full_parameters, &mut rng)
+        .expect("Unable to create proving and verifying contexts.");
+    let estimates = proving_context
+        .estimate(
+            &parameters,
+            &mut UtxoAccumulator::new(utxo_accumulator.model().clone()),
+            &rng.gen(),
+            rng.gen(),
+            |post| bincode::serialize(post).unwrap().len(),
+            &mut rng,
+        )
+        .expect("Calibration proofs should succeed.");
+    assert_eq!(
+        estimates.to_private.constraints,
+        ToPrivate::unknown_constraints(full_parameters).measure()
+    );
+    assert_eq!(
+        estimates.private_transfer.constraints,
+        PrivateTransfer::unknown_constraints(full_parameters).measure()
+    );
+    assert_eq!(
+        estimates.to_public.constraints,
+        ToPublic::unknown_constraints(full_parameters).measure()
+    );
+    let post = PrivateTransfer::sample_post(
+        &proving_context.private_transfer,
+        &parameters,
+        &mut utxo_accumulator,
+        Some(&rng.gen()),
+        &mut rng,
+    )
+    .expect("Random Private Transfer should have produced a proof.")
+    .expect("Random Private Transfer should have generated a TransferPost.");
+    assert_eq!(
+        estimates.private_transfer.post_size,
+        bincode::serialize(&post).unwrap().len()
+    );
+    let post = ToPublic::sample_post(
+        &proving_context.to_public,
+        &parameters,
+        &mut utxo_accumulator,
+        Some(&rng.gen()),
+        &mut rng,
+    )
+    .expect("Random ToPublic should have produced a proof.")
+    .expect("Random ToPublic should have generated a TransferPost.");
+    assert_eq!(
+        estimates.to_public.post_size,
+        bincode::serialize(&post).unwrap().len()
+    );
+    assert_eq!(
+        estimates.proving_time([TransferShape::ToPrivate, TransferShape::ToPublic]),
+        estimates.to_private.proving_time + estimates.to_public.proving_time
+    );
+}
+
+/// Sends `message` across a simulated air gap as frames of at most `max_frame_size` bytes,
+/// delivering them in reverse order and with duplicates.
+#[inline]
+fn send_over_air_gap(message: &[u8], max_frame_size: usize) -> Vec<u8> {
+    let frames = split(message, max_frame_size).expect("Splitting the message should succeed.");
+    let mut reassembler = Reassembler::new();
+    for frame in frames.iter().rev().chain(frames.first()) {
+        let bytes = frame.to_bytes();
+        assert!(
+            bytes.len() <= max_frame_size,
+            "Frames must respect the size bound."
+        );
+        reassembler
+            .push_bytes(&bytes)
+            .expect("Valid frames should be accepted.");
+    }
+    assert_eq!(reassembler.received(), frames.len());
+    reassembler
+        .finish()
+        .expect("Reassembling the message should succeed.")
+}
+
+/// Checks that a [`ToPublic`] proven by a device without the spending key can be signed on an
+/// air-gapped device and posted.
+#[test]
+fn air_gapped_to_public_signing() {
+    let mut rng = OsRng;
+    let parameters = rng.gen();
+    let mut utxo_accumulator = UtxoAccumulator::new(rng.gen());
+    let (proving_context, verifying_context) = ToPublic::generate_context(
+        &(),
+        FullParametersRef::new(&parameters, utxo_accumulator.model()),
+        &mut rng,
+    )
+    .expect("Unable to create proving and verifying contexts.");
+    let spending_key = rng.gen();
+    let authorization_context = parameters.derive_context(&spending_key);
+    let authorization = Authorization::from_context(&parameters, authorization_context, &mut rng);
+    let transfer = ToPublic::sample(
+        TransferDistribution::new(&parameters, &mut utxo_accumulator, Some(authorization)),
+        &mut rng,
+    );
+    let unsigned_post = transfer
+        .into_unsigned_post(
+            FullParametersRef::new(&parameters, utxo_accumulator.model()),
+            &proving_context,
+            Vec::from([rng.gen()]),
+            &mut rng,
+        )
+        .expect("Proof generation should succeed.")
+        .expect("ToPublic requires an authorization.");
+    let request = AirGapRequest::<Config>(Vec::from([unsigned_post]));
+    let request: AirGapRequest<Config> = bincode::deserialize(&send_over_air_gap(
+        &bincode::serialize(&request).expect("Serializing the request should succeed."),
+        256,
+    ))
+    .expect("Deserializing the request should succeed.");
+    assert!(
+        request
+            .sign(&parameters, &rng.gen(), &mut rng)
+            .0
+            .iter()
+            .all(Option::is_none),
+        "Only the spending key of the authorization can sign the request."
+    );
+    let response = request.sign(&parameters, &spending_key, &mut rng);
+    let response: AirGapResponse<Config> = bincode::deserialize(&send_over_air_gap(
+        &bincode::serialize(&response).expect("Serializing the response should succeed."),
+        256,
+    ))
+    .expect("Deserializing the response should succeed.");
+    let posts = request
+        .finish(&parameters, response)
+        .expect("The response should sign every post.");
+    for post in posts {
+        post.assert_valid_proof(&verifying_context);
+        assert!(
+            post.has_valid_authorization_signature(&parameters).is_ok(),
+            "Invalid signature."
+        );
+    }
+}
+
+/// Checks that corrupted, foreign and missing frames are rejected during reassembly.
+#[test]
+fn air_gap_frames_are_checked() {
+    let mut rng = OsRng;
+    let message = (0..1000).map(|_| rng.gen()).collect::<Vec<u8>>();
+    let frames = split(&message, 100).expect("Splitting the message should succeed.");
+    let mut corrupted = frames[0].to_bytes();
+    corrupted[10] ^= 1;
+    assert_eq!(
+        Frame::from_bytes(&corrupted),
+        Err(FrameError::InvalidFrameChecksum)
+    );
+    let mut reassembler = Reassembler::new();
+    reassembler
+        .push(frames[0].clone())
+        .expect("Valid frames should be accepted.");
+    let other = split(&[0; 10], 100).expect("Splitting the message should succeed.");
+    assert_eq!(
+        reassembler.push(other[0].clone()),
+        Err(FrameError::MessageMismatch)
+    );
+    assert_eq!(reassembler.finish(), Err(FrameError::Incomplete));
+    assert_eq!(split(&message, 12), Err(FrameError::FrameSizeTooSmall));
+}
+
 /// Checks that a random [`ToPublic`] produces a valid transaction signature.
 #[test]
 fn to_public_check_signature() {
@@ -204,6 +615,7 @@ fn to_public_check_signature() {
         authorization_signature: post.authorization_signature,
         body: post.body.clone(),
         sink_accounts: fuzzed_account,
+        version: post.version,
     };
     assert!(
         new_post
@@ -0,0 +1,411 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Cross-Implementation Test Vectors
+//!
+//! Implementations of the protocol in other languages can check themselves against this crate by
+//! deriving the same addresses, UTXOs, nullifiers and note ciphertexts, and by accepting the same
+//! [`TransferPost`]s. The [`Vectors`] are drawn from a [`ChaCha20Rng`] with a fixed seed, so
+//! generating them twice with the same seed and parameters gives byte-identical JSON, which uses
+//! the same `serde` representation as the signer clients.
+
+use crate::{
+    config::{
+        utxo::UtxoAccumulatorModel, Address, Asset, MultiProvingContext, Note, Nullifier,
+        Parameters, SpendingKey, TransferPost, Utxo,
+    },
+    test::payment::{self, UtxoAccumulator},
+};
+use alloc::vec::Vec;
+use manta_accounting::transfer::{
+    canonical::TransferShape,
+    utxo::{auth::DeriveContext, DeriveDecryptionKey, DeriveMint, DeriveSpend, UtxoReconstruct},
+};
+use manta_crypto::rand::{ChaCha20Rng, Rand, SeedableRng};
+use manta_util::serde::{Deserialize, Serialize};
+
+#[cfg(feature = "key")]
+use {
+    crate::{
+        config::{EmbeddedScalar, Group},
+        key::{path_string, Calamari, CoinType, CoinTypeId, KeySecret, Manta, Mnemonic, Testnet},
+    },
+    alloc::string::String,
+    manta_accounting::{
+        key::AccountCollection, transfer::utxo::protocol::ViewingKeyDerivationFunction,
+    },
+    manta_crypto::algebra::{HasGenerator, ScalarMul},
+};
+
+/// Address Vector
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct AddressVector {
+    /// Spending Key
+    pub spending_key: SpendingKey,
+
+    /// Address derived from the Spending Key
+    pub address: Address,
+}
+
+/// UTXO Vector
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct UtxoVector {
+    /// Receiving Address
+    pub address: Address,
+
+    /// Minted Asset
+    pub asset: Asset,
+
+    /// UTXO Commitment
+    pub utxo: Utxo,
+
+    /// Note with the Incoming and Light Incoming Ciphertexts
+    pub note: Note,
+}
+
+/// Nullifier Vector
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct NullifierVector {
+    /// Spending Key of the Owner
+    pub spending_key: SpendingKey,
+
+    /// Spent UTXO
+    pub utxo: Utxo,
+
+    /// Note of the Spent UTXO
+    pub note: Note,
+
+    /// Nullifier of the Spent UTXO
+    pub nullifier: Nullifier,
+}
+
+/// Transfer Post Vector
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct TransferPostVector {
+    /// Transfer Shape
+    pub shape: TransferShape,
+
+    /// Transfer Post with a Valid Proof
+    pub post: TransferPost,
+}
+
+/// Key-Derivation Vector
+///
+/// Follows the key-derivation pipeline of the signer for one mnemonic and coin type, from the
+/// mnemonic phrase down to the address of the default account.
+#[cfg(feature = "key")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "key")))]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct KeyVector {
+    /// [`BIP-0044`] Coin Type Id
+    ///
+    /// [`BIP-0044`]: https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki
+    pub coin_type: CoinTypeId,
+
+    /// Derivation Path of the Default Account
+    pub derivation_path: String,
+
+    /// Mnemonic Phrase
+    pub mnemonic: Mnemonic,
+
+    /// Mnemonic Password
+    pub password: String,
+
+    /// [`BIP-0039`] Seed of the Mnemonic
+    ///
+    /// [`BIP-0039`]: https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki
+    pub seed: Vec<u8>,
+
+    /// Spending Key of the Default Account
+    pub spending_key: SpendingKey,
+
+    /// Proof Authorization Key
+    pub proof_authorization_key: Group,
+
+    /// Viewing Key
+    pub viewing_key: EmbeddedScalar,
+
+    /// Address
+    pub address: Address,
+}
+
+#[cfg(feature = "key")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "key")))]
+impl KeyVector {
+    /// Number of Mnemonics sampled by [`from_seeds`](Self::from_seeds) when no seeds are given
+    pub const DEFAULT_SEED_COUNT: u64 = 8;
+
+    /// Derives the keys of the default account for `mnemonic` locked with `password` under the
+    /// coin type `C`.
+    #[inline]
+    pub fn new<C>(mnemonic: Mnemonic, password: &str, parameters: &Parameters) -> Self
+    where
+        C: CoinType,
+    {
+        let key_secret = KeySecret::<C>::new(mnemonic.clone(), password);
+        let spending_key = key_secret.spending_key(&Default::default());
+        let proof_authorization_key = parameters
+            .base
+            .group_generator
+            .generator()
+            .scalar_mul(&spending_key, &mut ());
+        let viewing_key = parameters
+            .base
+            .viewing_key_derivation_function
+            .viewing_key(&proof_authorization_key, &mut ());
+        Self {
+            coin_type: C::COIN_TYPE_ID,
+            derivation_path: path_string::<C>(Default::default()),
+            seed: mnemonic.to_seed(password).to_vec(),
+            mnemonic,
+            password: password.into(),
+            spending_key,
+            proof_authorization_key,
+            viewing_key,
+            address: parameters.address_from_spending_key(&spending_key),
+        }
+    }
+
+    /// Samples a mnemonic from each of the `seeds` and derives its keys with no password under
+    /// every coin type.
+    #[inline]
+    pub fn from_seeds<I>(seeds: I, parameters: &Parameters) -> Vec<Self>
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        let mut vectors = Vec::new();
+        for seed in seeds {
+            let mnemonic = Mnemonic::sample(&mut ChaCha20Rng::seed_from_u64(seed));
+            vectors.push(Self::new::<Testnet>(mnemonic.clone(), "", parameters));
+            vectors.push(Self::new::<Manta>(mnemonic.clone(), "", parameters));
+            vectors.push(Self::new::<Calamari>(mnemonic, "", parameters));
+        }
+        vectors
+    }
+}
+
+/// Test Vectors
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct Vectors {
+    /// Seed the Vectors were Generated from
+    pub seed: [u8; 32],
+
+    /// Address Vectors
+    pub addresses: Vec<AddressVector>,
+
+    /// UTXO Vectors
+    pub utxos: Vec<UtxoVector>,
+
+    /// Nullifier Vectors
+    pub nullifiers: Vec<NullifierVector>,
+
+    /// Transfer Post Vectors, one for each [`TransferShape`]
+    pub transfer_posts: Vec<TransferPostVector>,
+}
+
+impl Vectors {
+    /// Default Seed
+    pub const SEED: [u8; 32] = *b"manta-pay cross-implementation v";
+
+    /// Number of Address, UTXO and Nullifier Vectors
+    pub const COUNT: usize = 4;
+
+    /// Generates the test vectors from `seed` with `parameters`, `utxo_accumulator_model` and
+    /// `proving_context`.
+    #[inline]
+    pub fn generate(
+        parameters: &Parameters,
+        utxo_accumulator_model: &UtxoAccumulatorModel,
+        proving_context: &MultiProvingContext,
+        seed: [u8; 32],
+    ) -> Self {
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        let mut addresses = Vec::with_capacity(Self::COUNT);
+        let mut utxos = Vec::with_capacity(Self::COUNT);
+        let mut nullifiers = Vec::with_capacity(Self::COUNT);
+        for _ in 0..Self::COUNT {
+            let spending_key = rng.gen::<_, SpendingKey>();
+            let address = parameters.address_from_spending_key(&spending_key);
+            let asset = Asset::new(rng.gen(), rng.gen());
+            let (_, utxo, note) =
+                parameters.derive_mint(address, asset, Default::default(), &mut rng);
+            let mut authorization_context = parameters.derive_context(&spending_key);
+            let decryption_key = parameters.derive_decryption_key(&mut authorization_context);
+            let (identifier, asset) = parameters
+                .open_with_check(&decryption_key, &utxo, note.clone())
+                .expect("The note was minted for this address.");
+            let (_, _, nullifier) =
+                parameters.derive_spend(&mut authorization_context, identifier, asset, &mut rng);
+            addresses.push(AddressVector {
+                spending_key,
+                address,
+            });
+            utxos.push(UtxoVector {
+                address,
+                asset,
+                utxo,
+                note: note.clone(),
+            });
+            nullifiers.push(NullifierVector {
+                spending_key,
+                utxo,
+                note,
+                nullifier,
+            });
+        }
+        let mut utxo_accumulator = UtxoAccumulator::new(utxo_accumulator_model.clone());
+        let transfer_posts = Vec::from([
+            TransferPostVector {
+                shape: TransferShape::ToPrivate,
+                post: payment::to_private::prove(
+                    &proving_context.to_private,
+                    parameters,
+                    utxo_accumulator_model,
+                    &mut rng,
+                ),
+            },
+            TransferPostVector {
+                shape: TransferShape::PrivateTransfer,
+                post: payment::private_transfer::prove(
+                    &proving_context.private_transfer,
+                    parameters,
+                    &mut utxo_accumulator,
+                    &mut rng,
+                ),
+            },
+            TransferPostVector {
+                shape: TransferShape::ToPublic,
+                post: payment::to_public::prove(
+                    &proving_context.to_public,
+                    parameters,
+                    &mut utxo_accumulator,
+                    rng.gen(),
+                    &mut rng,
+                ),
+            },
+        ]);
+        Self {
+            seed,
+            addresses,
+            utxos,
+            nullifiers,
+            transfer_posts,
+        }
+    }
+
+    /// Generates the test vectors from `seed` with the parameters generated from
+    /// [`SEED`](crate::parameters::SEED).
+    #[cfg(feature = "parameters")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parameters")))]
+    #[inline]
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        let (proving_context, _, parameters, utxo_accumulator_model) =
+            crate::parameters::generate().expect("Unable to generate the protocol parameters.");
+        Self::generate(&parameters, &utxo_accumulator_model, &proving_context, seed)
+    }
+
+    /// Serializes `self` into pretty-printed JSON.
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "serde_json")))]
+    #[inline]
+    pub fn to_json(&self) -> serde_json::Result<alloc::string::String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parameters;
+
+    /// Checks that the vectors are reproducible from their seed and internally consistent.
+    #[test]
+    fn vectors_are_deterministic() {
+        let (proving_context, verifying_context, parameters, utxo_accumulator_model) =
+            parameters::generate().expect("Unable to generate the protocol parameters.");
+        let vectors = Vectors::generate(
+            &parameters,
+            &utxo_accumulator_model,
+            &proving_context,
+            Vectors::SEED,
+        );
+        assert_eq!(
+            vectors.to_json().unwrap(),
+            Vectors::generate(
+                &parameters,
+                &utxo_accumulator_model,
+                &proving_context,
+                Vectors::SEED,
+            )
+            .to_json()
+            .unwrap(),
+            "Generating the vectors twice from the same seed must give the same JSON."
+        );
+        assert_eq!(
+            serde_json::from_str::<Vectors>(&vectors.to_json().unwrap()).unwrap(),
+            vectors
+        );
+        for (address, utxo) in vectors.addresses.iter().zip(&vectors.utxos) {
+            assert_eq!(
+                parameters.address_from_spending_key(&address.spending_key),
+                address.address
+            );
+            assert_eq!(address.address, utxo.address);
+        }
+        for vector in &vectors.transfer_posts {
+            assert_eq!(TransferShape::from_post(&vector.post), Some(vector.shape));
+            vector
+                .post
+                .assert_valid_proof(verifying_context.select(vector.shape));
+        }
+    }
+
+    /// Checks that the key vectors of the signer coin type follow the same pipeline as the signer.
+    #[cfg(feature = "key")]
+    #[test]
+    fn key_vectors_match_signer() {
+        use crate::signer::functions::{address_from_mnemonic, viewing_key_from_mnemonic};
+        let (_, _, parameters, _) =
+            parameters::generate().expect("Unable to generate the protocol parameters.");
+        let vectors = KeyVector::from_seeds(0..2, &parameters);
+        assert_eq!(vectors.len(), 6);
+        for vector in vectors
+            .iter()
+            .filter(|vector| vector.coin_type == Testnet::COIN_TYPE_ID)
+        {
+            assert_eq!(
+                address_from_mnemonic(vector.mnemonic.clone(), &parameters),
+                vector.address
+            );
+            assert_eq!(
+                viewing_key_from_mnemonic(vector.mnemonic.clone(), &parameters),
+                vector.viewing_key
+            );
+            assert_eq!(
+                parameters.address_from_spending_key(&vector.spending_key),
+                vector.address
+            );
+        }
+        assert_eq!(vectors, KeyVector::from_seeds(0..2, &parameters));
+    }
+}
@@ -0,0 +1,354 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! WebAssembly Bindings
+//!
+//! This module exposes the [`Signer`] to JavaScript through [`wasm_bindgen`]. Requests and
+//! responses are JSON strings which use the same `serde` representation as the HTTP and WebSocket
+//! signer clients, and randomness is drawn from `crypto.getRandomValues` through the `js` backend
+//! of `getrandom`.
+//!
+//! # Responsiveness
+//!
+//! A single Groth16 proof takes several seconds to generate and cannot be interrupted, so the
+//! proving methods return promises which yield back to the JavaScript event loop with a zero-delay
+//! `setTimeout` before and after every proof, and between the transactions of a batch. This lets
+//! the browser render progress in between proofs. Proving contexts are only decoded when they are
+//! first needed, and [`WasmSigner::preload`] decodes them ahead of time as a separate step. To keep
+//! the page fully responsive while a proof is being generated, run the signer inside a Web
+//! Worker.
+
+use crate::{
+    config::{Address, ProvingContext},
+    key::Mnemonic,
+    parameters::{load_transfer_parameters, load_utxo_accumulator_model},
+    signer::{
+        base::Signer,
+        functions::{
+            accounts_from_mnemonic, address_from_mnemonic, authorization_context_from_mnemonic,
+            get_storage,
+        },
+        SignRequest, SignResult, StorageStateOption, SyncRequest,
+    },
+};
+use alloc::{format, rc::Rc, string::String, sync::Arc, vec::Vec};
+use core::cell::RefCell;
+use js_sys::{Function, Promise, Reflect};
+use manta_accounting::{
+    transfer::canonical::TransferShape,
+    wallet::signer::{EvictionPolicy, SharedProvingContextLoader},
+};
+use manta_crypto::{accumulator::Accumulator, rand::FromEntropy};
+use manta_util::{
+    codec::Decode,
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+};
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+
+/// Signer Creation Request
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct CreateSignerRequest {
+    /// Mnemonic Phrase of the Signer Accounts
+    pub mnemonic: Mnemonic,
+
+    /// Storage State to restore, as returned by [`WasmSigner::storage_state`]
+    #[serde(default)]
+    pub storage_state: StorageStateOption,
+}
+
+/// Encoded Proving Contexts
+///
+/// The proving contexts are kept in their encoded form and decoded on demand by the signer.
+#[derive(Clone, Debug, Default)]
+pub struct EncodedProvingContexts {
+    /// Encoded [`ToPrivate`](crate::config::ToPrivate) Proving Context
+    pub to_private: Vec<u8>,
+
+    /// Encoded [`PrivateTransfer`](crate::config::PrivateTransfer) Proving Context
+    pub private_transfer: Vec<u8>,
+
+    /// Encoded [`ToPublic`](crate::config::ToPublic) Proving Context
+    pub to_public: Vec<u8>,
+}
+
+impl EncodedProvingContexts {
+    /// Builds a [`SharedProvingContextLoader`] which decodes the proving contexts in `self`.
+    #[inline]
+    pub fn into_loader(self) -> SharedProvingContextLoader<crate::config::Config> {
+        let contexts = Arc::new(self);
+        Arc::new(move |shape| {
+            let bytes = match shape {
+                TransferShape::ToPrivate => &contexts.to_private,
+                TransferShape::PrivateTransfer => &contexts.private_transfer,
+                TransferShape::ToPublic => &contexts.to_public,
+            };
+            ProvingContext::decode(bytes.as_slice()).ok()
+        })
+    }
+}
+
+/// Converts `error` into a JavaScript error value.
+#[inline]
+fn js_error<E>(error: E) -> JsValue
+where
+    E: core::fmt::Display,
+{
+    JsError::new(&format!("{error}")).into()
+}
+
+/// Deserializes the JSON string `request` into a `T`.
+#[inline]
+fn parse<T>(request: &str) -> Result<T, JsValue>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_str(request).map_err(js_error)
+}
+
+/// Serializes `response` into a JSON string.
+#[inline]
+fn to_json<T>(response: &T) -> Result<String, JsValue>
+where
+    T: Serialize,
+{
+    serde_json::to_string(response).map_err(js_error)
+}
+
+/// Yields to the JavaScript event loop, resuming on the next macrotask so that the browser can
+/// render and handle input in between.
+#[inline]
+pub async fn yield_now() -> Result<(), JsValue> {
+    let promise = Promise::new(&mut |resolve, _| {
+        let global = js_sys::global();
+        match Reflect::get(&global, &JsValue::from_str("setTimeout"))
+            .and_then(|set_timeout| set_timeout.dyn_into::<Function>())
+        {
+            Ok(set_timeout) => {
+                let _ = set_timeout.call2(&global, &resolve, &JsValue::from(0));
+            }
+            _ => {
+                let _ = resolve.call0(&JsValue::UNDEFINED);
+            }
+        }
+    });
+    JsFuture::from(promise).await.map(|_| ())
+}
+
+/// WebAssembly Signer
+///
+/// The signer is shared between the promises returned by its proving methods, which must not be
+/// polled concurrently. Await each promise before calling another method of the same signer.
+#[wasm_bindgen]
+pub struct WasmSigner(Rc<RefCell<Signer>>);
+
+impl WasmSigner {
+    /// Builds a new [`WasmSigner`] from `request` which decodes the proving contexts from
+    /// `proving_contexts` when they are first needed.
+    #[inline]
+    pub fn from_request(
+        request: CreateSignerRequest,
+        proving_contexts: EncodedProvingContexts,
+    ) -> Self {
+        let mut signer = Signer::from_loader(
+            load_transfer_parameters(),
+            proving_contexts.into_loader(),
+            EvictionPolicy::default(),
+            Accumulator::empty(&load_utxo_accumulator_model()),
+            FromEntropy::from_entropy(),
+        );
+        if let Some(state) = &request.storage_state {
+            state.update_signer(&mut signer);
+        }
+        let authorization_context = authorization_context_from_mnemonic(
+            request.mnemonic.clone(),
+            signer.transfer_parameters(),
+        );
+        signer.load_accounts(accounts_from_mnemonic(request.mnemonic));
+        signer.load_authorization_context(authorization_context);
+        Self(Rc::new(RefCell::new(signer)))
+    }
+
+    /// Returns the default address of `self`.
+    #[inline]
+    pub fn default_address(&self) -> Option<Address> {
+        self.0.borrow_mut().address()
+    }
+
+    /// Signs every request in `requests`, yielding to the event loop around every proof.
+    #[inline]
+    async fn sign_all(
+        signer: Rc<RefCell<Signer>>,
+        requests: Vec<SignRequest>,
+    ) -> Result<Vec<SignResult>, JsValue> {
+        let mut results = Vec::with_capacity(requests.len());
+        for request in requests {
+            yield_now().await?;
+            let transaction = match signer.borrow().resolve_request(request) {
+                Ok(transaction) => transaction,
+                Err(err) => {
+                    results.push(Err(err));
+                    continue;
+                }
+            };
+            signer
+                .borrow_mut()
+                .preload(&[transaction.shape()])
+                .map_err(|err| js_error(format!("{err:?}")))?;
+            yield_now().await?;
+            results.push(signer.borrow_mut().sign(transaction));
+        }
+        Ok(results)
+    }
+}
+
+#[wasm_bindgen]
+impl WasmSigner {
+    /// Builds a new signer from the JSON-encoded creation `request` and the encoded proving
+    /// contexts for the three transfer shapes.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        request: &str,
+        to_private: Vec<u8>,
+        private_transfer: Vec<u8>,
+        to_public: Vec<u8>,
+    ) -> Result<WasmSigner, JsValue> {
+        Ok(Self::from_request(
+            parse(request)?,
+            EncodedProvingContexts {
+                to_private,
+                private_transfer,
+                to_public,
+            },
+        ))
+    }
+
+    /// Decodes the proving context for the JSON-encoded transfer `shape` ahead of time, returning
+    /// a promise which resolves once it is in memory.
+    pub fn preload(&self, shape: &str) -> Promise {
+        let signer = self.0.clone();
+        let shape = parse::<TransferShape>(shape);
+        future_to_promise(async move {
+            let shape = shape?;
+            yield_now().await?;
+            signer
+                .borrow_mut()
+                .preload(&[shape])
+                .map_err(|err| js_error(format!("{err:?}")))?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Synchronizes the signer with the JSON-encoded sync `request`, returning the JSON-encoded
+    /// sync result.
+    pub fn sync(&self, request: &str) -> Result<String, JsValue> {
+        to_json(&self.0.borrow_mut().sync(parse::<SyncRequest>(request)?))
+    }
+
+    /// Signs the JSON-encoded sign `request`, returning a promise which resolves to the
+    /// JSON-encoded sign result.
+    pub fn sign(&self, request: &str) -> Promise {
+        let signer = self.0.clone();
+        let request = parse::<SignRequest>(request);
+        future_to_promise(async move {
+            let mut results = Self::sign_all(signer, Vec::from([request?])).await?;
+            Ok(JsValue::from_str(&to_json(&results.remove(0))?))
+        })
+    }
+
+    /// Signs every request in the JSON-encoded array of sign `requests` in order, returning a
+    /// promise which resolves to the JSON-encoded array of sign results.
+    ///
+    /// The signer yields to the event loop between the transactions of the batch.
+    #[wasm_bindgen(js_name = signBatch)]
+    pub fn sign_batch(&self, requests: &str) -> Promise {
+        let signer = self.0.clone();
+        let requests = parse::<Vec<SignRequest>>(requests);
+        future_to_promise(async move {
+            let results = Self::sign_all(signer, requests?).await?;
+            Ok(JsValue::from_str(&to_json(&results)?))
+        })
+    }
+
+    /// Returns the JSON-encoded default address of the signer.
+    pub fn address(&self) -> Result<String, JsValue> {
+        to_json(&self.default_address())
+    }
+
+    /// Returns the JSON-encoded storage state of the signer, which can be persisted and passed
+    /// back in the creation request to avoid synchronizing from scratch.
+    #[wasm_bindgen(js_name = storageState)]
+    pub fn storage_state(&self) -> Result<String, JsValue> {
+        to_json(&get_storage(&self.0.borrow()))
+    }
+}
+
+/// Derives the JSON-encoded default address of the JSON-encoded `mnemonic` phrase without building
+/// a signer.
+#[wasm_bindgen(js_name = addressFromMnemonic)]
+pub fn wasm_address_from_mnemonic(mnemonic: &str) -> Result<String, JsValue> {
+    to_json(&address_from_mnemonic(
+        parse(mnemonic)?,
+        &load_transfer_parameters(),
+    ))
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::signer::SyncResult;
+
+    /// Test Mnemonic Phrase
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    /// Checks that the signer bindings which do not call into JavaScript work on any target.
+    #[test]
+    fn signer_bindings() {
+        let request = to_json(&CreateSignerRequest {
+            mnemonic: Mnemonic::new(MNEMONIC).expect("The mnemonic phrase is valid."),
+            storage_state: None,
+        })
+        .unwrap();
+        let signer = WasmSigner::new(&request, Vec::new(), Vec::new(), Vec::new())
+            .expect("The request is valid.");
+        let mnemonic = to_json(&MNEMONIC).unwrap();
+        assert_eq!(
+            signer.address().unwrap(),
+            wasm_address_from_mnemonic(&mnemonic).unwrap()
+        );
+        let sync = signer
+            .sync(
+                &to_json(&SyncRequest {
+                    origin_checkpoint: Default::default(),
+                    data: Default::default(),
+                    height: None,
+                })
+                .unwrap(),
+            )
+            .unwrap();
+        parse::<SyncResult>(&sync)
+            .unwrap()
+            .expect("Synchronizing with empty data is not allowed to fail.");
+        assert!(!signer
+            .0
+            .borrow()
+            .parameters()
+            .proving_context
+            .is_loaded(TransferShape::ToPrivate));
+    }
+}
@@ -20,11 +20,17 @@ extern crate alloc;
 
 use clap::{Parser, Subcommand};
 use dialoguer::{theme::ColorfulTheme, Input};
-use manta_trusted_setup::groth16::ceremony::{
-    config::ppot::{client_contribute, display_on_error, get_client_keys, register, Config},
-    CeremonyError,
+use manta_trusted_setup::groth16::{
+    backend::{CommandBackend, CpuBackend, SharedBackend},
+    ceremony::config::ppot::{
+        client_contribute, display_on_error, get_client_keys, register, Config,
+    },
 };
-use manta_util::Array;
+use manta_util::{
+    error::{Context, Result},
+    Array,
+};
+use std::{path::PathBuf, sync::Arc};
 
 /// Welcome Message
 pub const TITLE: &str = r" __  __             _          _____               _           _
@@ -48,7 +54,15 @@ pub enum Command {
     Register,
 
     /// Contribute to the Trusted Setup Ceremony
-    Contribute,
+    Contribute {
+        /// Program Computing the Scalar Multiplications of the Contribution
+        ///
+        /// Offloads the bulk of the contribution to an external program, for instance one running
+        /// on a GPU, instead of the built-in CPU implementation. See `CommandBackend` for the
+        /// input and output format of the program.
+        #[clap(long)]
+        msm_command: Option<PathBuf>,
+    },
 }
 
 /// Command Line Arguments
@@ -66,38 +80,36 @@ pub struct Arguments {
 impl Arguments {
     /// Takes command line arguments and executes the corresponding operations.
     #[inline]
-    pub fn run(self) -> Result<(), CeremonyError<Config>> {
+    pub fn run(self) -> Result<()> {
         println!("{TITLE}");
         match self.command {
             Command::Register => {
                 let twitter_account = Input::with_theme(&ColorfulTheme::default())
                     .with_prompt("Your twitter account")
                     .interact_text()
-                    .expect("Unable to get a valid twitter account.");
+                    .context("Unable to get a valid twitter account.")?;
                 let email = Input::with_theme(&ColorfulTheme::default())
                     .with_prompt("Your email address")
                     .interact_text()
-                    .expect("Unable to get a valid email.");
+                    .context("Unable to get a valid email.")?;
                 register(twitter_account, email);
                 Ok(())
             }
-            Command::Contribute => {
-                let (sk, pk) = match get_client_keys() {
-                    Ok(keys) => keys,
-                    Err(e) => panic!("Error while extracting the client keys: {e}"),
-                };
-                match tokio::runtime::Builder::new_multi_thread()
+            Command::Contribute { msm_command } => {
+                let (sk, pk) =
+                    get_client_keys().context("Error while extracting the client keys")?;
+                let runtime = tokio::runtime::Builder::new_multi_thread()
                     .worker_threads(4)
                     .enable_io()
                     .enable_time()
                     .build()
-                {
-                    Ok(runtime) => {
-                        let pk = Array::from_unchecked(*pk.as_bytes());
-                        runtime.block_on(client_contribute::<Config>(sk, pk, self.url))
-                    }
-                    Err(e) => panic!("I/O Error while setting up the tokio Runtime: {e:?}"),
-                }
+                    .context("I/O Error while setting up the tokio Runtime")?;
+                let pk = Array::from_unchecked(*pk.as_bytes());
+                let backend: SharedBackend<_> = match msm_command {
+                    Some(path) => Arc::new(CommandBackend::new(path)),
+                    _ => Arc::new(CpuBackend),
+                };
+                Ok(runtime.block_on(client_contribute::<Config>(sk, pk, self.url, backend))?)
             }
         }
     }
@@ -24,8 +24,8 @@ use clap::Parser;
 use manta_trusted_setup::groth16::ceremony::{
     config::ppot::{Config, Registry},
     coordinator::prepare,
-    CeremonyError,
 };
+use manta_util::error::{Error, Result};
 use std::path::PathBuf;
 
 /// Preparer CLI
@@ -41,14 +41,24 @@ pub struct Arguments {
 impl Arguments {
     /// Prepares for phase 2 ceremony
     #[inline]
-    pub fn run(self) -> Result<(), CeremonyError<Config>> {
+    pub fn run(self) -> Result<()> {
+        if !self.phase_one_param_path.is_file() {
+            return Err(Error::new(format_args!(
+                "Phase 1 parameters not found at {:?}",
+                self.phase_one_param_path
+            )));
+        }
+        if !self.recovery_directory.is_dir() {
+            return Err(Error::new(format_args!(
+                "Recovery directory {:?} does not exist",
+                self.recovery_directory
+            )));
+        }
         prepare::<Config, Registry>(self.phase_one_param_path, self.recovery_directory);
         Ok(())
     }
 }
 
-fn main() {
-    Arguments::parse()
-        .run()
-        .expect("Preparation error occurred");
+fn main() -> Result<()> {
+    Arguments::parse().run()
 }
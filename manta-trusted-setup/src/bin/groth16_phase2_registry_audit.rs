@@ -0,0 +1,75 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Trusted Setup Ceremony Registry Audit
+//! Re-verifies every verifying key and signature in a registry file and reports invalid or
+//! duplicate entries, so the registry can be checked before the ceremony starts.
+
+use clap::Parser;
+use manta_trusted_setup::{
+    ceremony::registry::csv::{audit, Error as RecordError},
+    groth16::ceremony::config::ppot::{Participant, Record},
+};
+use manta_util::error::{Context, Error, Result};
+use std::path::PathBuf;
+
+/// Registry Audit CLI
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Path to the registry file
+    registry_path: PathBuf,
+}
+
+impl Arguments {
+    /// Audits the registry.
+    #[inline]
+    pub fn run(self) -> Result<()> {
+        let audit = audit::<_, Participant, Record, _>(&self.registry_path)
+            .map_err(|e| Error::from_std(&e))
+            .with_context(|| format!("Unable to read the registry at {:?}", self.registry_path))?;
+        for entry in &audit.invalid {
+            match &entry.error {
+                RecordError::Parse(e) => println!("Line {}: invalid entry: {e}", entry.line),
+                RecordError::Csv(e) => println!("Line {}: unreadable entry: {e}", entry.line),
+            }
+        }
+        for entry in &audit.duplicates {
+            println!(
+                "Verifying key {} appears on lines {:?}",
+                bs58::encode(entry.identifier).into_string(),
+                entry.lines
+            );
+        }
+        println!(
+            "{} valid entries, {} invalid entries, {} duplicate verifying keys",
+            audit.valid,
+            audit.invalid.len(),
+            audit.duplicates.len()
+        );
+        if audit.is_clean() {
+            Ok(())
+        } else {
+            Err(Error::new(format_args!(
+                "The registry at {:?} failed the audit",
+                self.registry_path
+            )))
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    Arguments::parse().run()
+}
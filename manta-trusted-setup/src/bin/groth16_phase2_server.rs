@@ -16,23 +16,31 @@
 
 //! Trusted Setup Ceremony Server
 
+use async_std::prelude::FutureExt;
 use clap::Parser;
-use manta_trusted_setup::groth16::ceremony::{
-    config::ppot::{Config, Participant},
-    server::Server,
-    CeremonyError,
+use manta_trusted_setup::{
+    ceremony::storage::{FileSystem, Storage},
+    groth16::ceremony::{
+        config::ppot::{keys_from_secret, Config, Participant},
+        log::{report, Level},
+        server::Server,
+    },
 };
 use manta_util::{
+    error::{Context, Result},
     http::tide::{self, execute},
     Array,
 };
-use std::{collections::HashMap, path::PathBuf, time::Duration};
+use std::{collections::HashMap, fs, path::PathBuf, time::Duration};
+
+#[cfg(feature = "s3")]
+use manta_trusted_setup::ceremony::storage::s3::{Bucket, Credentials};
 
 /// Registry type
 type Registry = HashMap<Array<u8, 32>, Participant>;
 
 /// Current server configuration
-type S = Server<Config, Registry, 2, 3>;
+type S<St> = Server<Config, Registry, 2, 3, St>;
 
 /// Contribution time limit in seconds
 const TIME_LIMIT: u64 = 60;
@@ -48,44 +56,156 @@ pub struct Arguments {
 
     /// Path to html file to serve to web browsers
     homepage_path: String,
+
+    /// S3-compatible bucket to recover/save state from instead of the recovery directory
+    #[cfg(feature = "s3")]
+    #[clap(long)]
+    bucket: Option<String>,
+
+    /// Key prefix of the ceremony artifacts inside of the bucket
+    #[cfg(feature = "s3")]
+    #[clap(long, default_value = "")]
+    prefix: String,
+
+    /// Endpoint URL of the S3-compatible object storage
+    #[cfg(feature = "s3")]
+    #[clap(long, default_value = "https://s3.amazonaws.com")]
+    endpoint: String,
+
+    /// Region of the S3-compatible bucket
+    #[cfg(feature = "s3")]
+    #[clap(long, default_value = "us-east-1")]
+    region: String,
+
+    /// Path to the write-ahead log of the participant queue, defaults to `queue_log` inside of
+    /// the recovery directory
+    #[clap(long)]
+    queue_log: Option<PathBuf>,
+
+    /// Path to a file holding the secret of the server key, which enables signed contribution
+    /// receipts and the signed challenge publication feed
+    #[clap(long)]
+    server_key: Option<PathBuf>,
+
+    /// Path to the challenge publication feed, defaults to `feed.jsonl` inside of the recovery
+    /// directory
+    #[clap(long)]
+    feed: Option<PathBuf>,
 }
 
 impl Arguments {
     /// Runs a server.
     #[inline]
-    pub async fn run(self) -> Result<(), CeremonyError<Config>> {
-        let server = S::recover(
-            PathBuf::from(self.recovery_dir_path),
+    pub async fn run(self) -> Result<()> {
+        #[cfg(feature = "s3")]
+        if let Some(bucket) = self.bucket.clone() {
+            let credentials = Credentials::from_env()
+                .context("Missing AWS_ACCESS_KEY_ID or AWS_SECRET_ACCESS_KEY.")?;
+            let storage = Bucket::new(
+                self.endpoint.clone(),
+                bucket,
+                self.prefix.clone(),
+                self.region.clone(),
+                credentials,
+            );
+            return self.serve(storage).await;
+        }
+        let storage = FileSystem::new(&self.recovery_dir_path);
+        self.serve(storage).await
+    }
+
+    /// Runs a server which recovers/saves state using `storage`.
+    #[inline]
+    async fn serve<St>(self, storage: St) -> Result<()>
+    where
+        St: Clone + Storage + Send + Sync + 'static,
+    {
+        let queue_log = self
+            .queue_log
+            .unwrap_or_else(|| PathBuf::from(&self.recovery_dir_path).join("queue_log"));
+        let server = S::<St>::recover(
+            storage,
             PathBuf::from(self.registry_path),
             Duration::from_secs(TIME_LIMIT),
         )
-        .expect("Unable to recover from file");
-
-        println!("Network is running!");
-        let mut api = tide::Server::with_state(server);
-        api.at("/").serve_file(&self.homepage_path).map_err(|_| {
-            CeremonyError::<Config>::Network {
-                message: "Cannot load landing page.".to_string(),
+        .context("Unable to recover from storage")?
+        .with_queue_log(&queue_log)
+        .with_context(|| format!("Unable to open the participant queue log at {queue_log:?}"))?;
+        let server = match &self.server_key {
+            Some(server_key) => {
+                let secret = fs::read_to_string(server_key)
+                    .with_context(|| format!("Unable to read the server key at {server_key:?}"))?;
+                let (signing_key, verifying_key) = keys_from_secret(&secret)
+                    .map_err(|e| format!("{e:?}"))
+                    .context("Invalid server key secret")?;
+                let feed = self
+                    .feed
+                    .clone()
+                    .unwrap_or_else(|| PathBuf::from(&self.recovery_dir_path).join("feed.jsonl"));
+                report(
+                    Level::Info,
+                    format_args!(
+                        "Signing receipts and the challenge feed with {}",
+                        bs58::encode(verifying_key.as_bytes()).into_string()
+                    ),
+                );
+                server
+                    .with_signing_key(
+                        signing_key,
+                        Array::from_unchecked(*verifying_key.as_bytes()),
+                    )
+                    .with_feed(&feed)
+                    .with_context(|| format!("Unable to open the challenge feed at {feed:?}"))?
             }
-        })?;
+            _ => server,
+        };
+
+        report(Level::Info, "Network is running!");
+        let mut api = tide::Server::with_state(server.clone());
+        api.at("/")
+            .serve_file(&self.homepage_path)
+            .with_context(|| format!("Cannot load landing page from {:?}", self.homepage_path))?;
         api.at("/start")
             .post(|r| execute(r, Server::start_endpoint));
         api.at("/query")
             .post(|r| execute(r, Server::query_endpoint));
+        api.at("/round")
+            .post(|r| execute(r, Server::round_endpoint));
         api.at("/update")
             .post(|r| execute(r, Server::update_endpoint));
+        api.at("/feed").post(|r| execute(r, Server::feed_endpoint));
 
-        api.listen("127.0.0.1:8080")
-            .await
-            .expect("Should create a listener.");
-        Ok(())
+        let listen = async {
+            api.listen("127.0.0.1:8080")
+                .await
+                .context("Unable to listen on 127.0.0.1:8080")
+        };
+        let shutdown = async {
+            tokio::signal::ctrl_c()
+                .await
+                .context("Unable to listen for the shutdown signal")?;
+            report(
+                Level::Info,
+                "Shutting down, waiting for the participant holding the lock.",
+            );
+            let token = server
+                .shutdown(Duration::from_secs(TIME_LIMIT))
+                .await
+                .map_err(|e| format!("{e:?}"))
+                .context("Unable to shut down the server")?;
+            let token = serde_json::to_string(&token)
+                .map_err(|e| e.to_string())
+                .context("Unable to serialize the resume token")?;
+            println!("{token}");
+            Ok(())
+        };
+        listen.race(shutdown).await
     }
 }
 
 #[async_std::main]
-async fn main() {
-    Arguments::parse()
-        .run()
-        .await
-        .expect("Server error occurred");
+async fn main() -> Result<()> {
+    #[cfg(feature = "telemetry")]
+    manta_trusted_setup::groth16::ceremony::log::init_telemetry();
+    Arguments::parse().run().await
 }
@@ -0,0 +1,165 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Trusted Setup Ceremony Transcript Export
+//! Packages the transcript of a completed ceremony into a single archive named after the digest of
+//! its manifest, and writes the manifest next to it, so that the transcript can be mirrored and
+//! verified independently.
+
+use clap::Parser;
+use manta_trusted_setup::ceremony::{
+    storage::{FileSystem, Storage},
+    transcript::{export, Manifest},
+};
+use manta_util::error::{Context, Error, Result};
+use std::{
+    fs::{self, File},
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "s3")]
+use manta_trusted_setup::ceremony::storage::s3::{Bucket, Credentials};
+
+/// Transcript Export CLI
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Directory containing ceremony transcript
+    path: PathBuf,
+
+    /// Directory to write the archive and its manifest to
+    output: PathBuf,
+
+    /// Files or directories to add to the archive, like the contribution hashes or the final keys
+    /// written by the verifier, stored under their path relative to their parent directory
+    #[clap(long)]
+    include: Vec<PathBuf>,
+
+    /// S3-compatible bucket to read the ceremony transcript from instead of the transcript
+    /// directory
+    #[cfg(feature = "s3")]
+    #[clap(long)]
+    bucket: Option<String>,
+
+    /// Key prefix of the ceremony transcript inside of the bucket
+    #[cfg(feature = "s3")]
+    #[clap(long, default_value = "")]
+    prefix: String,
+
+    /// Endpoint URL of the S3-compatible object storage
+    #[cfg(feature = "s3")]
+    #[clap(long, default_value = "https://s3.amazonaws.com")]
+    endpoint: String,
+
+    /// Region of the S3-compatible bucket
+    #[cfg(feature = "s3")]
+    #[clap(long, default_value = "us-east-1")]
+    region: String,
+}
+
+impl Arguments {
+    /// Exports the transcript.
+    #[inline]
+    pub fn run(self) -> Result<()> {
+        let mut extra = Vec::new();
+        for path in &self.include {
+            let parent = path.parent().unwrap_or_else(|| Path::new(""));
+            collect_files(path, parent, &mut extra)?;
+        }
+        let manifest = self.export(extra)?;
+        println!(
+            "Exported {} artifacts of {} rounds over the circuits {:?}.",
+            manifest.entries.len(),
+            manifest.rounds,
+            manifest.circuits
+        );
+        println!("Transcript identifier: {}", manifest.id());
+        Ok(())
+    }
+    /// Writes the archive of the transcript together with the `extra` files, reading the
+    /// transcript from the bucket if there is one or from the transcript directory otherwise.
+    #[inline]
+    fn export(&self, extra: Vec<(String, Vec<u8>)>) -> Result<Manifest> {
+        #[cfg(feature = "s3")]
+        if let Some(bucket) = &self.bucket {
+            let credentials = Credentials::from_env()
+                .context("Missing AWS_ACCESS_KEY_ID or AWS_SECRET_ACCESS_KEY.")?;
+            let storage = Bucket::new(
+                self.endpoint.clone(),
+                bucket.clone(),
+                self.prefix.clone(),
+                self.region.clone(),
+                credentials,
+            );
+            return write_archive(&storage, &self.output, extra);
+        }
+        write_archive(&FileSystem::new(&self.path), &self.output, extra)
+    }
+}
+
+/// Adds the file at `path`, or every file below it if it is a directory, to `files` under its
+/// path relative to `base`.
+fn collect_files(path: &Path, base: &Path, files: &mut Vec<(String, Vec<u8>)>) -> Result<()> {
+    if path.is_dir() {
+        let mut entries = fs::read_dir(path)
+            .and_then(|entries| entries.collect::<Result<Vec<_>, _>>())
+            .with_context(|| format!("Unable to read directory {path:?}"))?;
+        entries.sort_by_key(|entry| entry.path());
+        for entry in entries {
+            collect_files(&entry.path(), base, files)?;
+        }
+        return Ok(());
+    }
+    let key = path
+        .strip_prefix(base)
+        .ok()
+        .and_then(|key| {
+            key.components()
+                .map(|component| component.as_os_str().to_str())
+                .collect::<Option<Vec<_>>>()
+        })
+        .map(|components| components.join("/"))
+        .ok_or_else(|| Error::new(format_args!("Unsupported file path {path:?}")))?;
+    let data = fs::read(path).with_context(|| format!("Unable to read {path:?}"))?;
+    files.push((key, data));
+    Ok(())
+}
+
+/// Exports the transcript in `storage` together with the `extra` files into `output`.
+fn write_archive<S>(storage: &S, output: &Path, extra: Vec<(String, Vec<u8>)>) -> Result<Manifest>
+where
+    S: Storage,
+{
+    fs::create_dir_all(output).with_context(|| format!("Unable to create {output:?}"))?;
+    let partial = output.join("ceremony_transcript.partial");
+    let file =
+        File::create(&partial).with_context(|| format!("Unable to create file {partial:?}"))?;
+    let (_, manifest) = export(storage, BufWriter::new(file), extra)
+        .context("Unable to export the ceremony transcript")?;
+    let id = manifest.id();
+    let archive = output.join(format!("ceremony_transcript_{id}.mtsx"));
+    fs::rename(&partial, &archive)
+        .with_context(|| format!("Unable to move the archive to {archive:?}"))?;
+    let manifest_path = output.join(format!("ceremony_transcript_{id}.manifest.json"));
+    fs::write(&manifest_path, manifest.to_vec())
+        .with_context(|| format!("Unable to write the manifest to {manifest_path:?}"))?;
+    println!("Archive was written to {archive:?}");
+    Ok(manifest)
+}
+
+fn main() -> Result<()> {
+    Arguments::parse().run()
+}
@@ -18,25 +18,38 @@
 
 use clap::Parser;
 use core::fmt::Debug;
-use manta_crypto::arkworks::serialize::HasSerialization;
+use manta_crypto::arkworks::{
+    groth16::{ProvingContext, VerifyingContext},
+    serialize::{HasDeserialization, HasSerialization},
+};
 use manta_trusted_setup::{
-    ceremony::util::deserialize_from_file,
+    ceremony::storage::{deserialize_from_storage, FileSystem, Storage},
     groth16::{
         ceremony::{
-            config::ppot::Config, message::ContributeResponse, server::filename_format, Ceremony,
-            CeremonyError, UnexpectedError,
+            config::ppot::{check_transfer_keys, Config},
+            log::{self, Level},
+            message::ContributeResponse,
+            server::key_format,
+            Ceremony,
         },
-        mpc::{util::extract_keys, verify_transform, Proof, State},
+        mpc::{util::extract_keys, verify_transform, Proof, ProvingKeyHasher, State},
     },
 };
-use manta_util::Array;
+use manta_util::{
+    error::{Context, Error, Result},
+    serde::Serialize,
+    Array,
+};
 use std::{
-    fs::File,
-    io::{BufRead, BufReader, Write},
+    fs::{self, File},
+    io::{BufRead, BufReader, Lines, Write},
     path::{Path, PathBuf},
     time::{Duration, Instant},
 };
 
+#[cfg(feature = "s3")]
+use manta_trusted_setup::ceremony::storage::s3::{Bucket, Credentials};
+
 /// Verification CLI
 #[derive(Debug, Parser)]
 pub struct Arguments {
@@ -44,179 +57,528 @@ pub struct Arguments {
     path: String,
 
     /// Starting round for verification
-    start: u64,
+    start: Option<u64>,
+
+    /// Starting round for verification, equivalent to the positional starting round
+    #[clap(long, conflicts_with = "start")]
+    from: Option<u64>,
+
+    /// Last round to verify, inclusive, instead of verifying until the end of the transcript
+    #[clap(long)]
+    to: Option<u64>,
+
+    /// Comma-separated list of circuits to verify instead of every circuit in the transcript
+    #[clap(long, value_delimiter = ',')]
+    circuits: Vec<String>,
+
+    /// Path to write a machine-readable JSON verification report to
+    #[clap(long)]
+    report: Option<PathBuf>,
+
+    /// S3-compatible bucket to read the ceremony transcript from, in which case the output files
+    /// are still written to the transcript directory
+    #[cfg(feature = "s3")]
+    #[clap(long)]
+    bucket: Option<String>,
+
+    /// Key prefix of the ceremony transcript inside of the bucket
+    #[cfg(feature = "s3")]
+    #[clap(long, default_value = "")]
+    prefix: String,
+
+    /// Endpoint URL of the S3-compatible object storage
+    #[cfg(feature = "s3")]
+    #[clap(long, default_value = "https://s3.amazonaws.com")]
+    endpoint: String,
+
+    /// Region of the S3-compatible bucket
+    #[cfg(feature = "s3")]
+    #[clap(long, default_value = "us-east-1")]
+    region: String,
 }
 
 impl Arguments {
     /// Runs a server.
     #[inline]
-    pub fn run(self) -> Result<(), CeremonyError<Config>> {
-        let path = PathBuf::from(self.path);
-        verify_ceremony(&path, self.start)?;
-        println!("Computing contribution hashes.");
-        contribution_hashes(&path);
-        println!(
-            "Verification complete. Contribution hashes were written to {:?}",
-            path.join("contribution_hashes.txt")
-        );
-        Ok(())
+    pub fn run(self) -> Result<()> {
+        let path = PathBuf::from(&self.path);
+        let range = Range {
+            from: self.from.or(self.start).unwrap_or_default(),
+            to: self.to,
+        };
+        if let Some(to) = range.to {
+            if to <= range.from {
+                return Err(Error::new(format_args!(
+                    "The last round {to} must come after the starting round {}",
+                    range.from
+                )));
+            }
+        }
+        let mut report = self.report.as_ref().map(|_| Report::new(range));
+        let result = self.verify(&path, range, report.as_mut());
+        if result.is_ok() && !self.circuits.is_empty() {
+            log::report(
+                Level::Info,
+                "Skipping contribution hashes since only some circuits were verified.",
+            );
+        } else if result.is_ok() {
+            log::report(Level::Info, "Computing contribution hashes.");
+            contribution_hashes(&path, report.as_mut())
+                .context("Unable to compute the contribution hashes")?;
+            log::report(
+                Level::Info,
+                format_args!(
+                    "Verification complete. Contribution hashes were written to {:?}",
+                    path.join("contribution_hashes.txt")
+                ),
+            );
+        }
+        if let (Some(report), Some(report_path)) = (report, self.report) {
+            report.write(&report_path)?;
+            log::report(
+                Level::Info,
+                format_args!("Verification report was written to {report_path:?}"),
+            );
+        }
+        result
+    }
+
+    /// Verifies the rounds in `range` of the ceremony transcript, reading it from the bucket if
+    /// there is one or from `path` otherwise, and writing the output files to `path`.
+    #[inline]
+    fn verify(&self, path: &Path, range: Range, report: Option<&mut Report>) -> Result<()> {
+        #[cfg(feature = "s3")]
+        if let Some(bucket) = &self.bucket {
+            let credentials = Credentials::from_env()
+                .context("Missing AWS_ACCESS_KEY_ID or AWS_SECRET_ACCESS_KEY.")?;
+            let storage = Bucket::new(
+                self.endpoint.clone(),
+                bucket.clone(),
+                self.prefix.clone(),
+                self.region.clone(),
+                credentials,
+            );
+            return verify_ceremony::<Config, _, _>(
+                path,
+                &storage,
+                range,
+                &self.circuits,
+                check_transfer_keys,
+                report,
+            );
+        }
+        verify_ceremony::<Config, _, _>(
+            path,
+            &FileSystem::new(path),
+            range,
+            &self.circuits,
+            check_transfer_keys,
+            report,
+        )
+    }
+}
+
+/// Range of Rounds to Verify
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(crate = "manta_util::serde")]
+pub struct Range {
+    /// Starting Round
+    ///
+    /// The state and challenge of this round are loaded as the boundary of the verification and
+    /// the first verified contribution is the one for the following round.
+    from: u64,
+
+    /// Last Verified Round
+    ///
+    /// If no last round is given, contributions are verified until the end of the transcript.
+    to: Option<u64>,
+}
+
+impl Range {
+    /// Returns `true` if the contribution in `round` should be verified.
+    #[inline]
+    pub fn contains(&self, round: u64) -> bool {
+        round > self.from && !matches!(self.to, Some(to) if round > to)
+    }
+}
+
+/// Verification Report for a Single Round
+#[derive(Debug, Serialize)]
+#[serde(crate = "manta_util::serde")]
+pub struct RoundReport {
+    /// Round Number
+    round: u64,
+
+    /// Challenge Hash after this Round
+    challenge: String,
+
+    /// Hash of the State after this Round
+    state_hash: String,
+
+    /// Verification Time
+    verification_time: Duration,
+}
+
+/// Verification Report for a Single Circuit
+#[derive(Debug, Serialize)]
+#[serde(crate = "manta_util::serde")]
+pub struct CircuitReport {
+    /// Circuit Name
+    name: String,
+
+    /// Verified Rounds
+    rounds: Vec<RoundReport>,
+
+    /// Total Verification Time
+    verification_time: Duration,
+}
+
+/// Contribution Hash for a Single Round
+#[derive(Debug, Serialize)]
+#[serde(crate = "manta_util::serde")]
+pub struct ContributionReport {
+    /// Round Number
+    round: u64,
+
+    /// Contribution Hash
+    contribution_hash: String,
+}
+
+/// Verification Failure
+#[derive(Debug, Serialize)]
+#[serde(crate = "manta_util::serde")]
+pub struct Failure {
+    /// Circuit Name
+    circuit: String,
+
+    /// Round Number
+    round: u64,
+
+    /// Error Message
+    message: String,
+}
+
+/// Verification Report
+#[derive(Debug, Serialize)]
+#[serde(crate = "manta_util::serde")]
+pub struct Report {
+    /// Verified Rounds
+    range: Range,
+
+    /// Per-Circuit Reports
+    circuits: Vec<CircuitReport>,
+
+    /// Per-Round Contribution Hashes
+    contributions: Vec<ContributionReport>,
+
+    /// Verification Failures
+    failures: Vec<Failure>,
+}
+
+impl Report {
+    /// Builds a new empty [`Report`] for a verification of the rounds in `range`.
+    #[inline]
+    pub fn new(range: Range) -> Self {
+        Self {
+            range,
+            circuits: Vec::new(),
+            contributions: Vec::new(),
+            failures: Vec::new(),
+        }
+    }
+
+    /// Writes `self` as JSON to the file at `path`.
+    #[inline]
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| Error::from_std(&e))
+            .context("Unable to serialize the verification report")?;
+        fs::write(path, json)
+            .with_context(|| format!("Unable to write the verification report to {path:?}"))
     }
 }
 
-fn main() {
-    Arguments::parse().run().unwrap();
+fn main() -> Result<()> {
+    #[cfg(feature = "telemetry")]
+    log::init_telemetry();
+    Arguments::parse().run()
 }
 
-fn verify_ceremony<C>(path: &Path, start: u64) -> Result<(), CeremonyError<C>>
+/// Verifies the ceremony transcript in `storage` for the rounds in `range` of the given `circuits`,
+/// or of every circuit if `circuits` is empty, writing the output files to `path`. The final keys
+/// of every circuit are checked with `check_keys` after they have been extracted.
+fn verify_ceremony<C, S, F>(
+    path: &Path,
+    storage: &S,
+    range: Range,
+    circuits: &[String],
+    check_keys: F,
+    mut report: Option<&mut Report>,
+) -> Result<()>
 where
-    C: Ceremony<Challenge = Array<u8, 64>>,
+    C: Ceremony<Challenge = Array<u8, 64>> + ProvingKeyHasher<C>,
+    S: Storage,
+    C::Output: AsRef<[u8]>,
+    C::G2Prepared: HasDeserialization,
     for<'s> C::G2Prepared: HasSerialization<'s>,
+    F: Fn(&str, &ProvingContext<C::Pairing>, &VerifyingContext<C::Pairing>) -> bool,
 {
-    // Need to read from files, so get circuit names
-    let names: Vec<String> =
-        deserialize_from_file(path.join(r"circuit_names")).expect("Circuit names file is missing.");
-    println!("Will verify contributions to {names:?}");
+    // Need to read from storage, so get circuit names
+    let mut names: Vec<String> = deserialize_from_storage(storage, "circuit_names")
+        .context("Unable to read the circuit names")?;
+    if !circuits.is_empty() {
+        if let Some(unknown) = circuits.iter().find(|circuit| !names.contains(circuit)) {
+            return Err(Error::new(format_args!(
+                "Unknown circuit {unknown:?}, expected one of {names:?}"
+            )));
+        }
+        names.retain(|name| circuits.contains(name));
+    }
+    log::report(
+        Level::Info,
+        format_args!("Will verify contributions to {names:?}"),
+    );
     // Keep track of verification times
     let mut verification_times = Vec::<Duration>::new();
 
     // Check each circuit
     for name in names.clone() {
-        println!("Checking contributions to circuit {}", name.clone());
-        let mut challenge_output =
-            File::create(path.join(format!("{}_computed_challenges", name.clone())))
-                .expect("Unable to create output file");
+        #[cfg(feature = "telemetry")]
+        let _span = tracing::info_span!("circuit", name = %name).entered();
+        log::report(
+            Level::Info,
+            format_args!("Checking contributions to circuit {}", name.clone()),
+        );
+        let challenge_path = path.join(format!("{name}_computed_challenges"));
+        let mut challenge_output = File::create(&challenge_path)
+            .with_context(|| format!("Unable to create output file {challenge_path:?}"))?;
+        let start = range.from;
         let mut round = start;
         let now = Instant::now();
+        let mut round_reports = Vec::new();
         // Load starting round
-        let mut state: State<C> = deserialize_from_file(filename_format(
-            path,
-            name.clone(),
-            "state".to_string(),
-            start,
-        ))
-        .map_err(|e| {
-            CeremonyError::Unexpected(UnexpectedError::Serialization {
-                message: format!("{e:?}"),
-            })
-        })?;
-        let mut challenge: C::Challenge = deserialize_from_file(filename_format(
-            path,
-            name.clone(),
-            "challenge".to_string(),
-            start,
-        ))
-        .map_err(|e| {
-            CeremonyError::Unexpected(UnexpectedError::Serialization {
-                message: format!("{e:?}"),
-            })
-        })?;
-
-        // Check until no more files are found
+        let starting_round = deserialize_from_storage::<State<C>, _>(
+            storage,
+            &key_format(name.clone(), "state".to_string(), start),
+        )
+        .and_then(|state| {
+            deserialize_from_storage::<C::Challenge, _>(
+                storage,
+                &key_format(name.clone(), "challenge".to_string(), start),
+            )
+            .map(|challenge| (state, challenge))
+        });
+        let (mut state, mut challenge) = match starting_round {
+            Ok(starting_round) => starting_round,
+            Err(e) => {
+                let err = Error::from(e).context(format_args!(
+                    "Unable to load round {start} of circuit {name}"
+                ));
+                if let Some(report) = report.as_deref_mut() {
+                    report.failures.push(Failure {
+                        circuit: name,
+                        round: start,
+                        message: format!("{err:#}"),
+                    });
+                }
+                return Err(err);
+            }
+        };
+
+        // Check until no more artifacts are found
         loop {
             round += 1;
-            let proof_result: Result<Proof<C>, _> = deserialize_from_file(filename_format(
-                path,
-                name.clone(),
-                "proof".to_string(),
-                round,
-            ));
-            let next_state_result: Result<State<C>, _> = deserialize_from_file(filename_format(
-                path,
-                name.clone(),
-                "state".to_string(),
-                round,
-            ));
+            if !range.contains(round) {
+                log::report(
+                    Level::Info,
+                    format_args!(
+                        "Reached the last requested round of {name}, skipping key extraction."
+                    ),
+                );
+                round -= 1;
+                break;
+            }
+            let proof_result: Result<Proof<C>, _> = deserialize_from_storage(
+                storage,
+                &key_format(name.clone(), "proof".to_string(), round),
+            );
+            let next_state_result: Result<State<C>, _> = deserialize_from_storage(
+                storage,
+                &key_format(name.clone(), "state".to_string(), round),
+            );
             match (proof_result, next_state_result) {
                 (Ok(proof), Ok(next_state)) => {
                     if round % 50 == 0 {
-                        println!("Verifying round {round}");
+                        log::report(Level::Info, format_args!("Verifying round {round}"));
                     }
-                    (challenge, state) = verify_transform(&challenge, &state, next_state, proof)
-                        .map_err(|e| {
-                            println!("Encountered error {e:?} in round {round}");
-                            CeremonyError::BadRequest
-                        })?;
+                    let round_start = Instant::now();
+                    (challenge, state) =
+                        match verify_transform(&challenge, &state, next_state, proof) {
+                            Ok(next) => next,
+                            Err(e) => {
+                                log::report(
+                                    Level::Warn,
+                                    format_args!("Encountered error {e:?} in round {round}"),
+                                );
+                                if let Some(report) = report.as_deref_mut() {
+                                    report.failures.push(Failure {
+                                        circuit: name.clone(),
+                                        round,
+                                        message: format!("{e:?}"),
+                                    });
+                                }
+                                return Err(Error::new(format_args!("{e:?}")).context(
+                                    format_args!(
+                                        "Contribution to circuit {name} in round {round} is invalid"
+                                    ),
+                                ));
+                            }
+                        };
                     writeln!(challenge_output, "{} round {round}", hex::encode(challenge))
-                        .expect("Unable to write challenge hash to file");
+                        .with_context(|| {
+                            format!("Unable to write challenge hash to {challenge_path:?}")
+                        })?;
+                    if report.is_some() {
+                        round_reports.push(RoundReport {
+                            round,
+                            challenge: hex::encode(challenge),
+                            state_hash: hex::encode(C::hash(&state.0)),
+                            verification_time: round_start.elapsed(),
+                        });
+                    }
                 }
                 _ => {
-                    println!("Writing final {name} prover and verifier key to file.");
-                    extract_keys(&path.join("keys"), name.clone(), Some(state))
-                        .expect("Key extraction error");
+                    log::report(
+                        Level::Info,
+                        format_args!("Writing final {name} prover and verifier key to file."),
+                    );
+                    let keys =
+                        extract_keys(&path.join("keys"), name.clone(), Some(state), |pk, vk| {
+                            check_keys(&name, pk, vk)
+                        })
+                        .with_context(|| format!("Unable to extract the keys for {name}"))?;
+                    log::report(
+                        Level::Info,
+                        format_args!(
+                            "Keys for {name} passed the proof round-trip, with hashes {} and {}.",
+                            hex::encode(keys.proving_key_hash),
+                            hex::encode(keys.verifying_key_hash)
+                        ),
+                    );
+                    round -= 1;
                     break;
                 }
             }
         }
 
         verification_times.push(now.elapsed());
-        println!(
-            "Checked {} contributions to {name} in {:?}",
-            round - 1,
-            now.elapsed()
+        if let Some(report) = report.as_deref_mut() {
+            report.circuits.push(CircuitReport {
+                name: name.clone(),
+                rounds: round_reports,
+                verification_time: now.elapsed(),
+            });
+        }
+        log::report(
+            Level::Info,
+            format_args!(
+                "Checked {} contributions to {name} in {:?}",
+                round - start,
+                now.elapsed()
+            ),
         );
     }
-    println!("All checks successful.");
+    log::report(Level::Info, "All checks successful.");
     for (name, time) in names.iter().zip(verification_times.iter()) {
-        println!("Verified contributions to {name} in {time:?}");
+        log::report(
+            Level::Info,
+            format_args!("Verified contributions to {name} in {time:?}"),
+        );
     }
     Ok(())
 }
 
-/// Combines the challenge hashes from each individual circuit to form the overall
-/// contribution hash that participants published as a commitment to their
-/// contribution.
-fn contribution_hashes(path: &Path) {
-    let private_transfer_challenges = BufReader::new(
-        File::open(path.join("private_transfer_computed_challenges")).expect("Unable to open file"),
-    )
-    .lines();
-    let to_private_challenges = BufReader::new(
-        File::open(path.join("to_private_computed_challenges")).expect("Unable to open file"),
+/// Opens the challenge hashes computed for `circuit` in `path` as an iterator over lines.
+fn challenge_lines(path: &Path, circuit: &str) -> Result<Lines<BufReader<File>>> {
+    let file_path = path.join(format!("{circuit}_computed_challenges"));
+    Ok(BufReader::new(
+        File::open(&file_path).with_context(|| format!("Unable to open {file_path:?}"))?,
     )
-    .lines();
-    let to_public_challenges = BufReader::new(
-        File::open(path.join("to_public_computed_challenges")).expect("Unable to open file"),
-    )
-    .lines();
-    let mut output =
-        File::create(path.join("contribution_hashes.txt")).expect("Unable to create output file");
+    .lines())
+}
+
+/// Parses a challenge hash `line`, written as `hash_as_hex round n`, into the challenge and its
+/// round number.
+fn parse_challenge_line(line: &str) -> Result<(Array<u8, 64>, u64)> {
+    let parts = line.split(' ').collect::<Vec<_>>();
+    let (hash, round) = match parts.as_slice() {
+        [hash, "round", round] => (hash, round),
+        _ => {
+            return Err(Error::new(format_args!(
+                "Malformed challenge line {line:?}"
+            )))
+        }
+    };
+    let hash = hex::decode(hash)
+        .map_err(Error::new)
+        .with_context(|| format!("Invalid challenge hash in line {line:?}"))?;
+    if hash.len() != 64 {
+        return Err(Error::new(format_args!(
+            "Challenge hash in line {line:?} is not 64 bytes long"
+        )));
+    }
+    let round = round
+        .parse()
+        .map_err(Error::new)
+        .with_context(|| format!("Invalid round number in line {line:?}"))?;
+    Ok((Array::from_vec(hash), round))
+}
 
+/// Combines the challenge hashes from each individual circuit to form the overall
+/// contribution hash that participants published as a commitment to their
+/// contribution. The hashes are also recorded into `report` if one is provided.
+fn contribution_hashes(path: &Path, mut report: Option<&mut Report>) -> Result<()> {
+    let private_transfer_challenges = challenge_lines(path, "private_transfer")?;
+    let to_private_challenges = challenge_lines(path, "to_private")?;
+    let to_public_challenges = challenge_lines(path, "to_public")?;
+    let output_path = path.join("contribution_hashes.txt");
+    let mut output = File::create(&output_path)
+        .with_context(|| format!("Unable to create output file {output_path:?}"))?;
     for ((private_transfer, to_private), to_public) in private_transfer_challenges
         .zip(to_private_challenges)
         .zip(to_public_challenges)
     {
-        match ((private_transfer, to_private), to_public) {
-            ((Ok(private_transfer), Ok(to_private)), Ok(to_public)) => {
-                // Hashes were written as "hash_as_hex round n"
-                let private_transfer: Vec<&str> = private_transfer.split(' ').collect();
-                let to_private: Vec<&str> = to_private.split(' ').collect();
-                let to_public: Vec<&str> = to_public.split(' ').collect();
-                // Check that all hashes correspond to same contribution round:
-                assert_eq!(to_private[2], to_public[2]);
-                assert_eq!(to_private[2], private_transfer[2]);
-                let index = to_private[2]
-                    .parse::<u64>()
-                    .expect("Unexpected value for round number");
-
-                let contribution_response = ContributeResponse::<Config> {
-                    index,
-                    challenge: Vec::<Array<u8, 64>>::from([
-                        Array::from_vec(hex::decode(to_private[0]).unwrap()),
-                        Array::from_vec(hex::decode(to_public[0]).unwrap()),
-                        Array::from_vec(hex::decode(private_transfer[0]).unwrap()),
-                    ]),
-                };
-                let contribution_hash =
-                    <Config as Ceremony>::contribution_hash(&contribution_response);
-                writeln!(
-                    output,
-                    "{} round {}",
-                    hex::encode(contribution_hash),
-                    private_transfer[2]
-                )
-                .expect("Unable to write challenge hash to file");
-            }
-            _ => println!("Read error occurred"),
+        let (private_transfer, private_transfer_round) =
+            parse_challenge_line(&private_transfer.context("Unable to read challenge")?)?;
+        let (to_private, to_private_round) =
+            parse_challenge_line(&to_private.context("Unable to read challenge")?)?;
+        let (to_public, to_public_round) =
+            parse_challenge_line(&to_public.context("Unable to read challenge")?)?;
+        if to_private_round != to_public_round || to_private_round != private_transfer_round {
+            return Err(Error::new(format_args!(
+                "Challenge hashes are out of sync: rounds {to_private_round}, {to_public_round} \
+                 and {private_transfer_round} do not match"
+            )));
+        }
+        let contribution_response = ContributeResponse::<Config> {
+            index: to_private_round,
+            challenge: Vec::from([to_private, to_public, private_transfer]),
+            receipt: None,
+        };
+        let contribution_hash = <Config as Ceremony>::contribution_hash(&contribution_response);
+        writeln!(
+            output,
+            "{} round {}",
+            hex::encode(contribution_hash),
+            to_private_round
+        )
+        .with_context(|| format!("Unable to write contribution hash to {output_path:?}"))?;
+        if let Some(report) = report.as_deref_mut() {
+            report.contributions.push(ContributionReport {
+                round: to_private_round,
+                contribution_hash: hex::encode(contribution_hash),
+            });
         }
     }
+    Ok(())
 }
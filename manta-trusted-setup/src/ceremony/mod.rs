@@ -20,6 +20,21 @@ pub mod participant;
 pub mod registry;
 pub mod signature;
 
+#[cfg(all(feature = "bincode", feature = "std"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "bincode", feature = "std"))))]
+pub mod queue;
+
+#[cfg(all(feature = "bincode", feature = "std"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "bincode", feature = "std"))))]
+pub mod storage;
+
+#[cfg(all(feature = "bincode", feature = "serde_json", feature = "std"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(feature = "bincode", feature = "serde_json", feature = "std")))
+)]
+pub mod transcript;
+
 #[cfg(all(feature = "bincode", feature = "std"))]
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "bincode", feature = "std"))))]
 pub mod util;
@@ -16,6 +16,11 @@
 
 //! Trusted Setup Ceremony Participants
 
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use manta_util::serde::{Deserialize, Serialize};
+
 /// Participant
 pub trait Participant {
     /// Identifier Type
@@ -57,3 +62,81 @@ pub trait Priority {
     /// Reduces the priority.
     fn reduce_priority(&mut self);
 }
+
+/// Activation Window
+///
+/// The window of time, in seconds since the UNIX epoch, during which the participants of a cohort
+/// are allowed to interact with the ceremony. A missing bound leaves that side of the window open,
+/// so the default window is always active.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ActivationWindow {
+    /// Opening Time
+    pub start: Option<u64>,
+
+    /// Closing Time
+    pub end: Option<u64>,
+}
+
+impl ActivationWindow {
+    /// Builds a new [`ActivationWindow`] from `start` and `end`.
+    #[inline]
+    pub fn new(start: Option<u64>, end: Option<u64>) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns `true` if `now` lies inside of `self`. The opening time is inclusive and the closing
+    /// time is exclusive.
+    #[inline]
+    pub fn contains(&self, now: u64) -> bool {
+        !matches!(self.start, Some(start) if now < start)
+            && !matches!(self.end, Some(end) if end <= now)
+    }
+}
+
+impl fmt::Display for ActivationWindow {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => write!(f, "from {start} until {end}"),
+            (Some(start), None) => write!(f, "from {start}"),
+            (None, Some(end)) => write!(f, "until {end}"),
+            (None, None) => write!(f, "at any time"),
+        }
+    }
+}
+
+/// Cohort
+pub trait Cohort {
+    /// Returns the [`ActivationWindow`] of the cohort `self` was registered into.
+    fn activation_window(&self) -> ActivationWindow;
+
+    /// Returns `true` if the cohort of `self` is active at time `now`.
+    #[inline]
+    fn is_active(&self, now: u64) -> bool {
+        self.activation_window().contains(now)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Checks that the activation window bounds are inclusive at the start and exclusive at the
+    /// end, and that missing bounds are left open.
+    #[test]
+    fn activation_window_bounds() {
+        let window = ActivationWindow::new(Some(10), Some(20));
+        assert!(!window.contains(9));
+        assert!(window.contains(10));
+        assert!(window.contains(19));
+        assert!(!window.contains(20));
+        assert!(ActivationWindow::new(None, Some(20)).contains(0));
+        assert!(ActivationWindow::new(Some(10), None).contains(u64::MAX));
+        assert!(ActivationWindow::default().contains(0));
+    }
+}
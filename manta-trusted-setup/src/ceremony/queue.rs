@@ -0,0 +1,370 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Persistent Participant Queue
+//!
+//! A [`PersistentQueue`] is a multi-level FIFO queue which records every mutation to an
+//! append-only write-ahead log before applying it in memory. Reopening the log replays the
+//! mutations, so the order of the queue survives a restart of the process that owns it. Without a
+//! log, the queue behaves like a plain [`MultiVecDeque`].
+
+use manta_util::{
+    collections::vec_deque::MultiVecDeque,
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// Number of log entries that are always tolerated before the log is compacted
+pub const MIN_COMPACTION_ENTRIES: usize = 1024;
+
+/// Queue Log Entry
+#[derive(Deserialize, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+enum Entry<T> {
+    /// Push `item` to the back of the queue at `level`
+    Push {
+        /// Priority Level
+        level: usize,
+
+        /// Queued Item
+        item: T,
+    },
+
+    /// Pop the front of the queue
+    Pop,
+}
+
+/// Queue Write-Ahead Log
+#[derive(Debug)]
+struct Log {
+    /// Log File
+    file: File,
+
+    /// Log Path
+    path: PathBuf,
+
+    /// Number of Entries in the Log
+    entries: usize,
+}
+
+impl Log {
+    /// Appends `entry` to the log and waits for it to reach the disk.
+    #[inline]
+    fn append<T>(&mut self, entry: &Entry<T>) -> bincode::Result<()>
+    where
+        T: Serialize,
+    {
+        self.file.write_all(&bincode::serialize(entry)?)?;
+        self.file.sync_data()?;
+        self.entries += 1;
+        Ok(())
+    }
+}
+
+/// Persistent Multi-Level Queue
+///
+/// See the [module-level documentation](self) for more.
+#[derive(Debug)]
+pub struct PersistentQueue<T, const N: usize> {
+    /// Queue
+    queue: MultiVecDeque<T, N>,
+
+    /// Write-Ahead Log
+    log: Option<Log>,
+}
+
+impl<T, const N: usize> PersistentQueue<T, N> {
+    /// Builds a new empty [`PersistentQueue`] which is only kept in memory.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens the queue whose write-ahead log is stored at `path`, replaying the logged mutations
+    /// or creating an empty log if there is none yet. A partially written entry at the end of the
+    /// log, left behind by a crash in the middle of an append, is discarded.
+    #[inline]
+    pub fn open<P>(path: P) -> bincode::Result<Self>
+    where
+        P: AsRef<Path>,
+        T: DeserializeOwned,
+    {
+        let path = path.as_ref().to_path_buf();
+        let data = match fs::read(&path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err.into()),
+        };
+        let mut queue = MultiVecDeque::new();
+        let mut remaining = data.as_slice();
+        let mut entries = 0;
+        while !remaining.is_empty() {
+            let mut reader = remaining;
+            match bincode::deserialize_from(&mut reader) {
+                Ok(Entry::Push { level, item }) => {
+                    if level >= N {
+                        return Err(Box::new(bincode::ErrorKind::Custom(format!(
+                            "Logged queue level {level} is out of bounds for {N} levels."
+                        ))));
+                    }
+                    queue.push_back(level, item)
+                }
+                Ok(Entry::Pop) => {
+                    queue.pop_front();
+                }
+                _ => break,
+            }
+            remaining = reader;
+            entries += 1;
+        }
+        let valid_len = (data.len() - remaining.len()) as u64;
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        if !remaining.is_empty() {
+            file.set_len(valid_len)?;
+            file.sync_data()?;
+        }
+        Ok(Self {
+            queue,
+            log: Some(Log {
+                file,
+                path,
+                entries,
+            }),
+        })
+    }
+
+    /// Returns a shared reference to the underlying queue.
+    #[inline]
+    pub fn queue(&self) -> &MultiVecDeque<T, N> {
+        &self.queue
+    }
+
+    /// Returns the path of the write-ahead log if `self` is persistent.
+    #[inline]
+    pub fn log_path(&self) -> Option<&Path> {
+        self.log.as_ref().map(|log| log.path.as_path())
+    }
+
+    /// Returns the total number of elements in `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if `self` has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Returns a shared reference to the first element in the queue.
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.queue.front()
+    }
+
+    /// Finds the position of `item` assuming it was inserted at the given `level`.
+    #[inline]
+    pub fn position(&self, level: usize, item: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.queue.position(level, item)
+    }
+
+    /// Pushes `item` to the back of the queue at the given `level`, logging the push first.
+    #[inline]
+    pub fn push_back(&mut self, level: usize, item: T) -> bincode::Result<()>
+    where
+        T: Serialize,
+    {
+        assert!(level < N, "Queue level {level} is out of bounds.");
+        let entry = Entry::Push { level, item };
+        if let Some(log) = self.log.as_mut() {
+            log.append(&entry)?;
+        }
+        if let Entry::Push { level, item } = entry {
+            self.queue.push_back(level, item);
+        }
+        self.compact_if_needed()
+    }
+
+    /// Pushes back `item` at `level` if `item` is missing. Returns the position of `item` in both
+    /// cases, and `true` if the item was missing and `false` otherwise.
+    #[inline]
+    pub fn push_back_if_missing(&mut self, level: usize, item: T) -> bincode::Result<(bool, usize)>
+    where
+        T: PartialEq + Serialize,
+    {
+        match self.queue.position(level, &item) {
+            Some(position) => Ok((false, position)),
+            None => {
+                self.push_back(level, item)?;
+                let leading = (0..level)
+                    .map(|level| self.queue.at_level(level).len())
+                    .sum::<usize>();
+                Ok((true, leading + self.queue.at_level(level).len() - 1))
+            }
+        }
+    }
+
+    /// Removes the element at the front of the queue if `self` is not empty, logging the removal
+    /// first.
+    #[inline]
+    pub fn pop_front(&mut self) -> bincode::Result<Option<T>>
+    where
+        T: Serialize,
+    {
+        if self.queue.is_empty() {
+            return Ok(None);
+        }
+        if let Some(log) = self.log.as_mut() {
+            log.append(&Entry::<T>::Pop)?;
+        }
+        let front = self.queue.pop_front();
+        self.compact_if_needed()?;
+        Ok(front)
+    }
+
+    /// Rewrites the write-ahead log so that it only contains the pushes for the elements which are
+    /// currently in the queue. The new log is written next to the old one and then moved over it,
+    /// so a crash during compaction leaves one of the two complete logs behind.
+    #[inline]
+    pub fn compact(&mut self) -> bincode::Result<()>
+    where
+        T: Serialize,
+    {
+        let log = match self.log.as_mut() {
+            Some(log) => log,
+            _ => return Ok(()),
+        };
+        let mut staging_path = log.path.clone().into_os_string();
+        staging_path.push(".compact");
+        let staging_path = PathBuf::from(staging_path);
+        let mut staging = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&staging_path)?;
+        let mut entries = 0;
+        for level in 0..N {
+            for item in self.queue.at_level(level) {
+                staging.write_all(&bincode::serialize(&Entry::Push { level, item })?)?;
+                entries += 1;
+            }
+        }
+        staging.sync_all()?;
+        fs::rename(&staging_path, &log.path)?;
+        log.file = OpenOptions::new().append(true).open(&log.path)?;
+        log.entries = entries;
+        Ok(())
+    }
+
+    /// Compacts the write-ahead log once it holds more than twice as many entries as are needed to
+    /// rebuild the queue, ignoring logs smaller than [`MIN_COMPACTION_ENTRIES`].
+    #[inline]
+    fn compact_if_needed(&mut self) -> bincode::Result<()>
+    where
+        T: Serialize,
+    {
+        match &self.log {
+            Some(log) if log.entries > MIN_COMPACTION_ENTRIES.max(2 * self.queue.len()) => {
+                self.compact()
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for PersistentQueue<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            queue: Default::default(),
+            log: None,
+        }
+    }
+}
+
+impl<T, const N: usize> PartialEq for PersistentQueue<T, N>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.queue == rhs.queue
+    }
+}
+
+impl<T, const N: usize> Eq for PersistentQueue<T, N> where T: Eq {}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that reopening the log restores the queue order, including after a torn write at the
+    /// end of the log.
+    #[test]
+    fn queue_order_survives_reopening() {
+        let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let path = directory.path().join("queue");
+        let mut queue = PersistentQueue::<u64, 2>::open(&path).unwrap();
+        assert_eq!(queue.push_back_if_missing(1, 10).unwrap(), (true, 0));
+        assert_eq!(queue.push_back_if_missing(0, 20).unwrap(), (true, 0));
+        assert_eq!(queue.push_back_if_missing(1, 30).unwrap(), (true, 2));
+        assert_eq!(queue.push_back_if_missing(1, 10).unwrap(), (false, 1));
+        assert_eq!(queue.pop_front().unwrap(), Some(20));
+        drop(queue);
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(&[0, 0])
+            .unwrap();
+        let mut queue = PersistentQueue::<u64, 2>::open(&path).unwrap();
+        assert_eq!(queue.front(), Some(&10));
+        assert_eq!(queue.position(1, &30), Some(1));
+        assert_eq!(queue.push_back_if_missing(0, 40).unwrap(), (true, 0));
+        drop(queue);
+        let mut queue = PersistentQueue::<u64, 2>::open(&path).unwrap();
+        assert_eq!(queue.pop_front().unwrap(), Some(40));
+        assert_eq!(queue.pop_front().unwrap(), Some(10));
+        assert_eq!(queue.pop_front().unwrap(), Some(30));
+        assert_eq!(queue.pop_front().unwrap(), None);
+    }
+
+    /// Tests that compacting the log preserves the queue order.
+    #[test]
+    fn compaction_preserves_order() {
+        let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let path = directory.path().join("queue");
+        let mut queue = PersistentQueue::<u64, 2>::open(&path).unwrap();
+        for item in 0..(2 * MIN_COMPACTION_ENTRIES as u64) {
+            queue.push_back((item % 2) as usize, item).unwrap();
+            if item % 3 == 0 {
+                queue.pop_front().unwrap();
+            }
+        }
+        queue.compact().unwrap();
+        let reopened = PersistentQueue::<u64, 2>::open(&path).unwrap();
+        assert_eq!(reopened, queue);
+    }
+}
@@ -17,9 +17,10 @@
 //! Trusted Setup Ceremony Registry CSV Compatibility
 
 use crate::ceremony::registry::Registry;
-use core::fmt::Debug;
+use core::{fmt::Debug, hash::Hash};
 use manta_util::serde::de::DeserializeOwned;
 use std::{
+    collections::{hash_map::Entry, HashMap},
     fs::{File, OpenOptions},
     io::{Seek, SeekFrom},
     path::Path,
@@ -93,6 +94,9 @@ where
                 registry.insert(identifier, participant);
             }
             Err(e) => {
+                #[cfg(feature = "telemetry")]
+                tracing::warn!(line = number + 2, "Parsing error {e:?}");
+                #[cfg(not(feature = "telemetry"))]
                 println!("Line: {} Parsing error {e:?}", number + 2);
             }
         };
@@ -100,6 +104,112 @@ where
     Ok(registry.len() - length)
 }
 
+/// Invalid Registry Entry
+#[derive(Debug)]
+pub struct InvalidEntry<E> {
+    /// Line of the Registry File where the Entry Starts
+    pub line: u64,
+
+    /// Error Encountered when Reading or Parsing the Entry
+    pub error: Error<E>,
+}
+
+/// Duplicate Registry Entry
+#[derive(Debug)]
+pub struct DuplicateEntry<I> {
+    /// Duplicated Identifier
+    pub identifier: I,
+
+    /// Lines of the Registry File where the Identifier Appears
+    pub lines: Vec<u64>,
+}
+
+/// Registry Audit
+#[derive(Debug)]
+pub struct Audit<I, E> {
+    /// Number of Entries which Parsed Successfully
+    pub valid: usize,
+
+    /// Entries which could not be Read or Parsed
+    pub invalid: Vec<InvalidEntry<E>>,
+
+    /// Identifiers which Appear in more than one Valid Entry
+    pub duplicates: Vec<DuplicateEntry<I>>,
+}
+
+impl<I, E> Audit<I, E> {
+    /// Returns `true` if the audited registry has neither invalid nor duplicate entries.
+    #[inline]
+    pub fn is_clean(&self) -> bool {
+        self.invalid.is_empty() && self.duplicates.is_empty()
+    }
+}
+
+/// Audits the registry file at `path` by re-parsing every entry with `T` as the record type,
+/// which re-verifies the registration signatures, and collecting the entries which are invalid
+/// and the identifiers which appear in more than one entry. Unlike [`load`], which keeps the last
+/// entry for each identifier and skips invalid entries, this reports every problem with the line
+/// it occurs on.
+#[inline]
+pub fn audit<I, V, T, P>(path: P) -> Result<Audit<I, T::Error>, csv::Error>
+where
+    I: Clone + Eq + Hash,
+    T: Record<I, V>,
+    P: AsRef<Path>,
+{
+    let mut reader = csv::Reader::from_reader(File::open(path)?);
+    let headers = reader.headers()?.clone();
+    let mut valid = 0;
+    let mut invalid = Vec::new();
+    let mut lines = HashMap::<I, Vec<u64>>::new();
+    let mut order = Vec::new();
+    let mut row = csv::StringRecord::new();
+    loop {
+        match reader.read_record(&mut row) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) if e.is_io_error() => return Err(e),
+            Err(e) => {
+                invalid.push(InvalidEntry {
+                    line: e.position().map(|p| p.line()).unwrap_or_default(),
+                    error: Error::Csv(e),
+                });
+                continue;
+            }
+        }
+        let line = row.position().map(|p| p.line()).unwrap_or_default();
+        match row
+            .deserialize::<T>(Some(&headers))
+            .map_err(Error::Csv)
+            .and_then(|record| record.parse().map_err(Error::Parse))
+        {
+            Ok((identifier, _)) => {
+                valid += 1;
+                match lines.entry(identifier) {
+                    Entry::Occupied(mut entry) => entry.get_mut().push(line),
+                    Entry::Vacant(entry) => {
+                        order.push(entry.key().clone());
+                        entry.insert(vec![line]);
+                    }
+                }
+            }
+            Err(error) => invalid.push(InvalidEntry { line, error }),
+        }
+    }
+    let duplicates = order
+        .into_iter()
+        .filter_map(|identifier| {
+            let lines = lines.remove(&identifier)?;
+            (lines.len() > 1).then_some(DuplicateEntry { identifier, lines })
+        })
+        .collect();
+    Ok(Audit {
+        valid,
+        invalid,
+        duplicates,
+    })
+}
+
 /// Build an append-only CSV writer from a file path.
 /// Missing files are created.
 pub fn append_only_csv_writer<E, P>(path: P) -> Result<csv::Writer<File>, E>
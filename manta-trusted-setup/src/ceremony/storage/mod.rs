@@ -0,0 +1,198 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Trusted Setup Ceremony Artifact Storage
+//!
+//! Ceremony artifacts like round states, challenges, proofs, and registries are addressed by a
+//! flat string key, for example `to_private_state_12`. A [`Storage`] maps these keys onto a
+//! backing store like a local directory or an object-storage bucket.
+
+use core::fmt::Debug;
+use manta_util::serde::{de::DeserializeOwned, Serialize};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "s3")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "s3")))]
+pub mod s3;
+
+/// Artifact Storage
+pub trait Storage {
+    /// Error Type
+    type Error: Debug;
+
+    /// Reads the artifact stored under `key`.
+    fn read(&self, key: &str) -> Result<Vec<u8>, Self::Error>;
+
+    /// Writes `data` as the artifact stored under `key`, replacing any existing artifact.
+    fn write(&self, key: &str, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// Returns `true` if there is an artifact stored under `key`.
+    fn contains(&self, key: &str) -> Result<bool, Self::Error>;
+}
+
+impl<S> Storage for &S
+where
+    S: Storage + ?Sized,
+{
+    type Error = S::Error;
+
+    #[inline]
+    fn read(&self, key: &str) -> Result<Vec<u8>, Self::Error> {
+        (**self).read(key)
+    }
+
+    #[inline]
+    fn write(&self, key: &str, data: &[u8]) -> Result<(), Self::Error> {
+        (**self).write(key, data)
+    }
+
+    #[inline]
+    fn contains(&self, key: &str) -> Result<bool, Self::Error> {
+        (**self).contains(key)
+    }
+}
+
+/// Storage Serialization Error
+#[derive(Debug)]
+pub enum Error<E> {
+    /// Storage Backend Error
+    Storage(E),
+
+    /// Serialization Error
+    Serialization(bincode::Error),
+}
+
+impl<E> From<Error<E>> for manta_util::error::Error
+where
+    E: Debug,
+{
+    #[inline]
+    fn from(err: Error<E>) -> Self {
+        match err {
+            Error::Storage(err) => Self::new(format_args!("Storage error: {err:?}")),
+            Error::Serialization(err) => Self::from_std(&*err).context("Serialization error"),
+        }
+    }
+}
+
+/// Deserializes an element of type `T` from the artifact stored under `key` in `storage`.
+#[inline]
+pub fn deserialize_from_storage<T, S>(storage: &S, key: &str) -> Result<T, Error<S::Error>>
+where
+    T: DeserializeOwned,
+    S: Storage,
+{
+    bincode::deserialize(&storage.read(key).map_err(Error::Storage)?).map_err(Error::Serialization)
+}
+
+/// Serializes `data` into the artifact stored under `key` in `storage`.
+#[inline]
+pub fn serialize_into_storage<T, S>(storage: &S, key: &str, data: &T) -> Result<(), Error<S::Error>>
+where
+    T: Serialize,
+    S: Storage,
+{
+    storage
+        .write(
+            key,
+            &bincode::serialize(data).map_err(Error::Serialization)?,
+        )
+        .map_err(Error::Storage)
+}
+
+/// Local Filesystem Storage
+///
+/// Each artifact is stored as a file named after its key inside of the root directory.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FileSystem {
+    /// Root Directory
+    root: PathBuf,
+}
+
+impl FileSystem {
+    /// Builds a new [`FileSystem`] storage rooted at `root`.
+    #[inline]
+    pub fn new<P>(root: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { root: root.into() }
+    }
+
+    /// Returns the root directory of `self`.
+    #[inline]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the path of the file storing the artifact under `key`.
+    #[inline]
+    pub fn path(&self, key: &str) -> PathBuf {
+        self.root.join(key)
+    }
+}
+
+impl Storage for FileSystem {
+    type Error = io::Error;
+
+    #[inline]
+    fn read(&self, key: &str) -> Result<Vec<u8>, Self::Error> {
+        fs::read(self.path(key))
+    }
+
+    #[inline]
+    fn write(&self, key: &str, data: &[u8]) -> Result<(), Self::Error> {
+        let path = self.path(key);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)?
+            .write_all(data)
+    }
+
+    #[inline]
+    fn contains(&self, key: &str) -> Result<bool, Self::Error> {
+        Ok(self.path(key).is_file())
+    }
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that artifacts written to a [`FileSystem`] storage can be read back.
+    #[test]
+    fn file_system_round_trip() {
+        let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let storage = FileSystem::new(directory.path());
+        assert!(!storage.contains("round_number").unwrap());
+        serialize_into_storage(&storage, "round_number", &7u64).unwrap();
+        assert!(storage.contains("round_number").unwrap());
+        assert_eq!(
+            deserialize_from_storage::<u64, _>(&storage, "round_number").unwrap(),
+            7
+        );
+    }
+}
@@ -0,0 +1,489 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Ceremony Transcript Archives
+//!
+//! A completed ceremony is exported as a single archive which holds every artifact of the
+//! transcript together with a manifest. Artifacts are content-addressed by their BLAKE2b digest,
+//! so identical artifacts are only stored once, and the manifest records the digest of every
+//! artifact, which makes the digest of the manifest an identifier for the whole archive. The
+//! layout of an archive is
+//!
+//! ```text
+//! MAGIC || VERSION || blob* || manifest || len(manifest) as u64
+//! ```
+//!
+//! where the length is little-endian and the manifest is JSON. Since the manifest comes last, an
+//! archive can be written in a single pass over the artifacts.
+
+use crate::ceremony::storage::Storage;
+use blake2::{Blake2b512, Digest};
+use core::fmt::Debug;
+use manta_util::serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    io::{self, Read, Seek, SeekFrom, Write},
+};
+
+/// Archive Magic Bytes
+pub const MAGIC: [u8; 4] = *b"MTSX";
+
+/// Archive Format Version
+pub const VERSION: u8 = 1;
+
+/// Returns an [`InvalidData`](io::ErrorKind::InvalidData) error with the given `message`.
+#[inline]
+fn invalid_data<M>(message: M) -> io::Error
+where
+    M: Into<String>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// Returns `true` if `key` is a valid artifact key, a non-empty `/`-separated relative path whose
+/// components are neither empty nor `.` or `..`, so that archives can be unpacked into a
+/// directory without escaping it.
+#[inline]
+pub fn is_valid_key(key: &str) -> bool {
+    !key.contains('\\')
+        && key
+            .split('/')
+            .all(|component| !matches!(component, "" | "." | ".."))
+}
+
+/// Returns the hex-encoded BLAKE2b digest of `data`.
+#[inline]
+pub fn digest(data: &[u8]) -> String {
+    hex::encode(Blake2b512::digest(data))
+}
+
+/// Manifest Entry
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct Entry {
+    /// Artifact Key
+    pub key: String,
+
+    /// Hex-Encoded BLAKE2b Digest of the Artifact
+    pub digest: String,
+
+    /// Offset of the Artifact in the Archive
+    pub offset: u64,
+
+    /// Length of the Artifact
+    pub len: u64,
+}
+
+/// Transcript Manifest
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct Manifest {
+    /// Circuit Names
+    pub circuits: Vec<String>,
+
+    /// Number of Contribution Rounds
+    pub rounds: u64,
+
+    /// Artifacts in the Order they were Added
+    pub entries: Vec<Entry>,
+}
+
+impl Manifest {
+    /// Returns the entry stored under `key` if it exists.
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.key == key)
+    }
+
+    /// Serializes `self` into the JSON encoding stored in archives.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("Serializing the manifest cannot fail.")
+    }
+
+    /// Returns the identifier of the archive with `self` as its manifest, the hex-encoded BLAKE2b
+    /// digest of its JSON encoding.
+    #[inline]
+    pub fn id(&self) -> String {
+        digest(&self.to_vec())
+    }
+}
+
+/// Transcript Archive Writer
+pub struct ArchiveWriter<W>
+where
+    W: Write,
+{
+    /// Underlying Writer
+    writer: W,
+
+    /// Offset of the Next Artifact
+    offset: u64,
+
+    /// Offsets and Lengths of the Artifacts Written so far by Digest
+    blobs: HashMap<String, (u64, u64)>,
+
+    /// Manifest
+    manifest: Manifest,
+}
+
+impl<W> ArchiveWriter<W>
+where
+    W: Write,
+{
+    /// Starts a new archive in `writer` for a ceremony over `circuits` with `rounds`
+    /// contribution rounds.
+    #[inline]
+    pub fn new(mut writer: W, circuits: Vec<String>, rounds: u64) -> io::Result<Self> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        Ok(Self {
+            writer,
+            offset: (MAGIC.len() + 1) as u64,
+            blobs: HashMap::new(),
+            manifest: Manifest {
+                circuits,
+                rounds,
+                entries: Vec::new(),
+            },
+        })
+    }
+
+    /// Adds `data` to the archive as the artifact stored under `key`, only writing it if no
+    /// artifact with the same digest has been written before.
+    #[inline]
+    pub fn add(&mut self, key: &str, data: &[u8]) -> io::Result<()> {
+        if !is_valid_key(key) || self.manifest.get(key).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid or duplicate artifact key {key:?}."),
+            ));
+        }
+        let digest = digest(data);
+        let (offset, len) = match self.blobs.get(&digest) {
+            Some(blob) => *blob,
+            _ => {
+                self.writer.write_all(data)?;
+                let blob = (self.offset, data.len() as u64);
+                self.offset += blob.1;
+                self.blobs.insert(digest.clone(), blob);
+                blob
+            }
+        };
+        self.manifest.entries.push(Entry {
+            key: key.into(),
+            digest,
+            offset,
+            len,
+        });
+        Ok(())
+    }
+
+    /// Writes the manifest to the end of the archive and returns the underlying writer and the
+    /// manifest.
+    #[inline]
+    pub fn finish(mut self) -> io::Result<(W, Manifest)> {
+        let manifest = self.manifest.to_vec();
+        self.writer.write_all(&manifest)?;
+        self.writer
+            .write_all(&(manifest.len() as u64).to_le_bytes())?;
+        self.writer.flush()?;
+        Ok((self.writer, self.manifest))
+    }
+}
+
+/// Transcript Archive Reader
+pub struct ArchiveReader<R>
+where
+    R: Read + Seek,
+{
+    /// Underlying Reader
+    reader: R,
+
+    /// Manifest
+    manifest: Manifest,
+}
+
+impl<R> ArchiveReader<R>
+where
+    R: Read + Seek,
+{
+    /// Opens the archive in `reader`, checking its magic bytes and version and reading its
+    /// manifest.
+    #[inline]
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let mut prefix = [0; 5];
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_exact(&mut prefix)?;
+        if prefix[..4] != MAGIC {
+            return Err(invalid_data("Missing archive magic bytes."));
+        }
+        if prefix[4] != VERSION {
+            return Err(invalid_data("Unsupported archive version."));
+        }
+        let end = reader.seek(SeekFrom::End(-8))?;
+        let mut len = [0; 8];
+        reader.read_exact(&mut len)?;
+        let len = u64::from_le_bytes(len);
+        let start = end
+            .checked_sub(len)
+            .filter(|start| *start >= prefix.len() as u64)
+            .ok_or_else(|| invalid_data("Invalid manifest length."))?;
+        reader.seek(SeekFrom::Start(start))?;
+        let mut manifest = vec![0; len as usize];
+        reader.read_exact(&mut manifest)?;
+        let manifest: Manifest = serde_json::from_slice(&manifest)
+            .map_err(|e| invalid_data(format!("Invalid manifest: {e}")))?;
+        if let Some(entry) = manifest.entries.iter().find(|entry| {
+            !is_valid_key(&entry.key) || entry.offset.saturating_add(entry.len) > start
+        }) {
+            return Err(invalid_data(format!(
+                "Artifact {:?} has an invalid key or lies outside of the archive.",
+                entry.key
+            )));
+        }
+        Ok(Self { reader, manifest })
+    }
+
+    /// Returns the manifest of the archive.
+    #[inline]
+    pub fn manifest(&self) -> &Manifest {
+        &self.manifest
+    }
+
+    /// Reads the artifact described by `entry`, checking it against its digest.
+    #[inline]
+    fn read_entry(&mut self, entry: &Entry) -> io::Result<Vec<u8>> {
+        self.reader.seek(SeekFrom::Start(entry.offset))?;
+        let mut data = vec![0; entry.len as usize];
+        self.reader.read_exact(&mut data)?;
+        if digest(&data) != entry.digest {
+            return Err(invalid_data(format!(
+                "Artifact {:?} does not match its digest.",
+                entry.key
+            )));
+        }
+        Ok(data)
+    }
+
+    /// Reads the artifact stored under `key`, checking it against its digest.
+    #[inline]
+    pub fn read(&mut self, key: &str) -> io::Result<Vec<u8>> {
+        let entry = self
+            .manifest
+            .get(key)
+            .cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{key:?}")))?;
+        self.read_entry(&entry)
+    }
+
+    /// Checks every artifact in the archive against its digest.
+    #[inline]
+    pub fn verify(&mut self) -> io::Result<()> {
+        for entry in self.manifest.entries.clone() {
+            self.read_entry(&entry)?;
+        }
+        Ok(())
+    }
+
+    /// Writes every artifact in the archive into `storage` under its key, checking each one
+    /// against its digest first.
+    #[inline]
+    pub fn unpack<S>(&mut self, storage: &S) -> Result<(), Error<S::Error>>
+    where
+        S: Storage,
+    {
+        for entry in self.manifest.entries.clone() {
+            let data = self.read_entry(&entry).map_err(Error::Io)?;
+            storage.write(&entry.key, &data).map_err(Error::Storage)?;
+        }
+        Ok(())
+    }
+}
+
+/// Transcript Export Error
+#[derive(Debug)]
+pub enum Error<E> {
+    /// Storage Backend Error
+    Storage(E),
+
+    /// Archive Reading or Writing Error
+    Io(io::Error),
+
+    /// Inconsistent Transcript Error
+    Inconsistent(String),
+}
+
+impl<E> From<Error<E>> for manta_util::error::Error
+where
+    E: Debug,
+{
+    #[inline]
+    fn from(err: Error<E>) -> Self {
+        match err {
+            Error::Storage(err) => Self::new(format_args!("Storage error: {err:?}")),
+            Error::Io(err) => Self::from(err),
+            Error::Inconsistent(message) => Self::new(message),
+        }
+    }
+}
+
+/// Returns the number of contribution rounds stored in `storage` for the circuit `name`.
+#[inline]
+fn count_rounds<S>(storage: &S, name: &str) -> Result<u64, Error<S::Error>>
+where
+    S: Storage,
+{
+    let mut round = 0;
+    while storage
+        .contains(&format!("{name}_state_{}", round + 1))
+        .map_err(Error::Storage)?
+    {
+        round += 1;
+    }
+    Ok(round)
+}
+
+/// Exports the ceremony transcript in `storage` as an archive into `writer`, returning the
+/// manifest of the archive.
+///
+/// The archive holds the circuit names, and for every circuit the states and challenges of all
+/// rounds and the proofs of all contributions, together with the registry snapshots and the
+/// contributor of every round which are present in `storage`. Additional artifacts like the
+/// contribution hashes or the final keys computed by the verifier can be passed in `extra` as
+/// pairs of keys and data.
+#[inline]
+pub fn export<S, W, I>(storage: &S, writer: W, extra: I) -> Result<(W, Manifest), Error<S::Error>>
+where
+    S: Storage,
+    W: Write,
+    I: IntoIterator<Item = (String, Vec<u8>)>,
+{
+    let names = storage.read("circuit_names").map_err(Error::Storage)?;
+    let circuits: Vec<String> = bincode::deserialize(&names)
+        .map_err(|e| Error::Inconsistent(format!("Invalid circuit names: {e}")))?;
+    let mut rounds = None;
+    for name in &circuits {
+        let count = count_rounds(storage, name)?;
+        match rounds {
+            Some(rounds) if rounds != count => {
+                return Err(Error::Inconsistent(format!(
+                    "Circuit {name} has {count} rounds instead of {rounds}."
+                )))
+            }
+            _ => rounds = Some(count),
+        }
+    }
+    let rounds = rounds.unwrap_or_default();
+    let mut archive = ArchiveWriter::new(writer, circuits.clone(), rounds).map_err(Error::Io)?;
+    archive.add("circuit_names", &names).map_err(Error::Io)?;
+    for name in &circuits {
+        for round in 0..=rounds {
+            let mut kinds = vec!["state", "challenge"];
+            if round > 0 {
+                kinds.push("proof");
+            }
+            for kind in kinds {
+                let key = format!("{name}_{kind}_{round}");
+                let data = storage.read(&key).map_err(Error::Storage)?;
+                archive.add(&key, &data).map_err(Error::Io)?;
+            }
+        }
+    }
+    for round in 0..=rounds {
+        for kind in ["registry", "contributor"] {
+            let key = format!("_{kind}_{round}");
+            if storage.contains(&key).map_err(Error::Storage)? {
+                let data = storage.read(&key).map_err(Error::Storage)?;
+                archive.add(&key, &data).map_err(Error::Io)?;
+            }
+        }
+    }
+    for (key, data) in extra {
+        archive.add(&key, &data).map_err(Error::Io)?;
+    }
+    archive.finish().map_err(Error::Io)
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ceremony::storage::FileSystem;
+    use std::io::Cursor;
+
+    /// Tests that an exported transcript can be unpacked into the same artifacts and that
+    /// identical artifacts are only stored once.
+    #[test]
+    fn export_and_unpack_round_trip() {
+        let source = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let storage = FileSystem::new(source.path());
+        let names = vec!["a".to_string(), "b".to_string()];
+        storage
+            .write("circuit_names", &bincode::serialize(&names).unwrap())
+            .unwrap();
+        for name in &names {
+            for round in 0..3 {
+                storage
+                    .write(&format!("{name}_state_{round}"), &[round; 16])
+                    .unwrap();
+                storage
+                    .write(&format!("{name}_challenge_{round}"), name.as_bytes())
+                    .unwrap();
+                if round > 0 {
+                    storage
+                        .write(
+                            &format!("{name}_proof_{round}"),
+                            &[name.as_bytes()[0], round],
+                        )
+                        .unwrap();
+                    storage
+                        .write(&format!("_contributor_{round}"), &[round])
+                        .unwrap();
+                }
+            }
+        }
+        storage.write("keys/a_pk", b"key").unwrap();
+        let extra = [("keys/a_pk".to_string(), b"key".to_vec())];
+        let (archive, manifest) = export(&storage, Vec::new(), extra).expect("Export failed.");
+        assert_eq!(manifest.rounds, 2);
+        assert_eq!(manifest.entries.len(), 1 + 2 * 8 + 2 + 1);
+        let a = manifest.get("a_state_1").unwrap();
+        let b = manifest.get("b_state_1").unwrap();
+        assert_eq!((a.offset, a.digest.clone()), (b.offset, b.digest.clone()));
+        let mut reader =
+            ArchiveReader::new(Cursor::new(archive.clone())).expect("Invalid archive.");
+        assert_eq!(reader.manifest().id(), manifest.id());
+        reader.verify().expect("Archive must verify.");
+        let target = tempfile::tempdir().expect("Unable to create temporary directory.");
+        reader
+            .unpack(&FileSystem::new(target.path()))
+            .expect("Unpacking failed.");
+        for entry in &manifest.entries {
+            assert_eq!(
+                std::fs::read(target.path().join(&entry.key)).unwrap(),
+                storage.read(&entry.key).unwrap()
+            );
+        }
+        assert!(!is_valid_key("../escape") && !is_valid_key("/root") && !is_valid_key(""));
+        let mut tampered = archive;
+        tampered[MAGIC.len() + 1] ^= 1;
+        assert!(ArchiveReader::new(Cursor::new(tampered))
+            .expect("Manifest is intact.")
+            .verify()
+            .is_err());
+    }
+}
@@ -19,10 +19,12 @@
 use manta_util::serde::{de::DeserializeOwned, Serialize};
 use std::{
     fs::{File, OpenOptions},
+    io::{BufReader, BufWriter},
     path::Path,
 };
 
-/// Serializes `data` to a file at `path` with the given `open_options`.
+/// Serializes `data` to a file at `path` with the given `open_options`, streaming the encoding
+/// through a buffered writer.
 #[inline]
 pub fn serialize_into_file<T, P>(
     open_options: &mut OpenOptions,
@@ -33,15 +35,16 @@ where
     P: AsRef<Path>,
     T: Serialize,
 {
-    bincode::serialize_into(open_options.open(path)?, data)
+    bincode::serialize_into(BufWriter::new(open_options.open(path)?), data)
 }
 
-/// Deserializes an element of type `T` from the file at `path`.
+/// Deserializes an element of type `T` from the file at `path`, streaming it through a buffered
+/// reader.
 #[inline]
 pub fn deserialize_from_file<T, P>(path: P) -> bincode::Result<T>
 where
     P: AsRef<Path>,
     T: DeserializeOwned,
 {
-    bincode::deserialize_from(File::open(path)?)
+    bincode::deserialize_from(BufReader::new(File::open(path)?))
 }
@@ -0,0 +1,173 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Scalar Multiplication Backends
+//!
+//! Almost all the time of a contribution is spent multiplying the `l_query` and `h_query` points
+//! of the proving key by the same scalar. The [`ScalarMulBackend`] `trait` lets participants
+//! replace the [`CpuBackend`] used by default, for instance with a GPU implementation, which can
+//! be linked into the client or run as an external program with the [`CommandBackend`].
+
+use crate::util::batch_mul_fixed_scalar;
+use alloc::sync::Arc;
+use manta_crypto::arkworks::ec::AffineCurve;
+
+#[cfg(feature = "std")]
+use {
+    alloc::{format, vec::Vec},
+    manta_crypto::arkworks::serialize::{CanonicalDeserialize, CanonicalSerialize},
+    std::{
+        io::{self, Write},
+        path::PathBuf,
+        process::{Command, Stdio},
+        thread,
+    },
+};
+
+/// Scalar Multiplication Backend Error
+#[derive(Debug)]
+pub enum BackendError {
+    /// The backend returned a different number of points than it was given.
+    LengthMismatch,
+
+    /// Serialization Error
+    Serialization,
+
+    /// I/O Error
+    #[cfg(feature = "std")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+    Io(io::Error),
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl From<io::Error> for BackendError {
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Scalar Multiplication Backend
+pub trait ScalarMulBackend<G>
+where
+    G: AffineCurve,
+{
+    /// Multiplies each point in `points` by the fixed `scalar` in-place.
+    fn batch_mul_fixed_scalar(
+        &self,
+        points: &mut [G],
+        scalar: G::ScalarField,
+    ) -> Result<(), BackendError>;
+}
+
+/// Shared Scalar Multiplication Backend
+pub type SharedBackend<G> = Arc<dyn ScalarMulBackend<G> + Send + Sync>;
+
+/// CPU Backend
+///
+/// Multiplies the points one by one, in parallel when the `rayon` feature is enabled.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CpuBackend;
+
+impl<G> ScalarMulBackend<G> for CpuBackend
+where
+    G: AffineCurve,
+{
+    #[inline]
+    fn batch_mul_fixed_scalar(
+        &self,
+        points: &mut [G],
+        scalar: G::ScalarField,
+    ) -> Result<(), BackendError> {
+        batch_mul_fixed_scalar(points, scalar);
+        Ok(())
+    }
+}
+
+/// External Command Backend
+///
+/// Runs the program at [`path`](Self::path) for every batch of points. The program receives the
+/// compressed scalar followed by the uncompressed vector of points on its standard input, both in
+/// the arkworks canonical serialization, and must write the uncompressed vector of multiplied
+/// points to its standard output before exiting successfully.
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CommandBackend {
+    /// Path to the Program
+    pub path: PathBuf,
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl CommandBackend {
+    /// Builds a new [`CommandBackend`] running the program at `path`.
+    #[inline]
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl<G> ScalarMulBackend<G> for CommandBackend
+where
+    G: AffineCurve,
+{
+    #[inline]
+    fn batch_mul_fixed_scalar(
+        &self,
+        points: &mut [G],
+        scalar: G::ScalarField,
+    ) -> Result<(), BackendError> {
+        let mut input = Vec::new();
+        scalar
+            .serialize(&mut input)
+            .map_err(|_| BackendError::Serialization)?;
+        points
+            .to_vec()
+            .serialize_uncompressed(&mut input)
+            .map_err(|_| BackendError::Serialization)?;
+        let mut child = Command::new(&self.path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let mut stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::BrokenPipe))?;
+        let writer = thread::spawn(move || stdin.write_all(&input));
+        let output = child.wait_with_output()?;
+        writer
+            .join()
+            .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))??;
+        if !output.status.success() {
+            return Err(io::Error::other(format!(
+                "Scalar multiplication program exited with {}.",
+                output.status
+            ))
+            .into());
+        }
+        let result = Vec::<G>::deserialize_uncompressed(output.stdout.as_slice())
+            .map_err(|_| BackendError::Serialization)?;
+        if result.len() != points.len() {
+            return Err(BackendError::LengthMismatch);
+        }
+        points.copy_from_slice(&result);
+        Ok(())
+    }
+}
@@ -19,14 +19,18 @@
 use crate::{
     ceremony::signature::{SignedMessage, Signer},
     groth16::{
+        backend::{CpuBackend, SharedBackend},
         ceremony::{
-            message::{ContributeRequest, ContributeResponse, QueryRequest, QueryResponse},
+            message::{
+                ContributeRequest, ContributeResponse, QueryRequest, QueryResponse, RoundRequest,
+                RoundResponse,
+            },
             Ceremony, CeremonyError, Metadata, Round, UnexpectedError,
         },
         mpc,
     },
 };
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
 use manta_crypto::rand::OsRng;
 use manta_util::{
     http::reqwest::{self, IntoUrl, KnownUrlClient},
@@ -62,6 +66,18 @@ pub enum Continue {
     /// Computing State Update
     ComputingUpdate,
 
+    /// State Update Progress
+    Progress {
+        /// Number of Circuits with a Finished Contribution
+        completed_circuits: usize,
+
+        /// Total Number of Circuits
+        circuit_count: usize,
+
+        /// Overall Percentage of the Contribution which has been Computed
+        percent: u8,
+    },
+
     /// Sending State Update
     SendingUpdate,
 
@@ -85,6 +101,9 @@ where
 
     /// Ceremony Metadata
     metadata: Metadata,
+
+    /// Scalar Multiplication Backend
+    backend: SharedBackend<C::G1>,
 }
 
 impl<C> Client<C>
@@ -102,9 +121,16 @@ where
             signer,
             client,
             metadata,
+            backend: Arc::new(CpuBackend),
         }
     }
 
+    /// Runs the scalar multiplications of the contributions of `self` with `backend`.
+    #[inline]
+    pub fn set_backend(&mut self, backend: SharedBackend<C::G1>) {
+        self.backend = backend;
+    }
+
     /// Updates the client's nonce to the `expected_nonce` returned by the server.
     #[inline]
     fn update_nonce(&mut self, expected_nonce: C::Nonce) -> Result<(), CeremonyError<C>> {
@@ -196,25 +222,57 @@ where
         }
     }
 
-    /// Computes the state update for the ceremony and signs the update request message.
+    /// Computes the state update for the ceremony and signs the update request message, reporting
+    /// [`Continue::Progress`] to `process_continuation` along the way.
     #[inline]
-    fn compute_update(
+    fn compute_update<F>(
         &mut self,
         hasher: &C::Hasher,
         mut round: Round<C>,
+        process_continuation: &mut F,
     ) -> Result<SignedMessage<C, C::Identifier, ContributeRequest<C>>, CeremonyError<C>>
     where
         ContributeRequest<C>: Serialize,
+        F: FnMut(&Metadata, Continue),
     {
         let mut rng = OsRng;
         let mut proof = Vec::new();
-        for i in 0..round.state.len() {
+        let circuit_count = round.state.len();
+        let total = round
+            .state
+            .iter()
+            .map(mpc::contribution_work)
+            .sum::<usize>()
+            .max(1);
+        let mut finished = 0;
+        let mut last_percent = None;
+        for i in 0..circuit_count {
+            let work = mpc::contribution_work(&round.state[i]);
             proof.push(
-                mpc::contribute(hasher, &round.challenge[i], &mut round.state[i], &mut rng)
-                    .ok_or_else(|| {
-                        CeremonyError::Unexpected(UnexpectedError::FailedContribution)
-                    })?,
+                mpc::contribute_with_backend(
+                    hasher,
+                    &round.challenge[i],
+                    &mut round.state[i],
+                    &mut rng,
+                    &*self.backend,
+                    |completed| {
+                        let percent = ((finished + completed) * 100 / total) as u8;
+                        if last_percent != Some(percent) {
+                            last_percent = Some(percent);
+                            process_continuation(
+                                &self.metadata,
+                                Continue::Progress {
+                                    completed_circuits: i + usize::from(completed == work),
+                                    circuit_count,
+                                    percent,
+                                },
+                            );
+                        }
+                    },
+                )
+                .ok_or_else(|| CeremonyError::Unexpected(UnexpectedError::FailedContribution))?,
             );
+            finished += work;
         }
         self.sign(ContributeRequest {
             state: round.state.into(),
@@ -267,7 +325,8 @@ where
             Err(err) => return Err(err),
         };
         process_continuation(&self.metadata, Continue::ComputingUpdate);
-        let update = self.compute_update(&C::Hasher::default(), state)?;
+        let update =
+            self.compute_update(&C::Hasher::default(), state, &mut process_continuation)?;
         process_continuation(&self.metadata, Continue::SendingUpdate);
         match self.send_update(&update).await {
             Ok(response) => Ok(Update::Break(response)),
@@ -286,6 +345,37 @@ pub async fn contribute<C, U, F>(
     signing_key: C::SigningKey,
     identifier: C::Identifier,
     server_url: U,
+    process_continuation: F,
+) -> Result<ContributeResponse<C>, CeremonyError<C>>
+where
+    C: Ceremony,
+    C::Identifier: Serialize,
+    C::Nonce: DeserializeOwned + Serialize,
+    C::Signature: Serialize,
+    QueryResponse<C>: DeserializeOwned,
+    ContributeRequest<C>: Serialize,
+    ContributeResponse<C>: DeserializeOwned,
+    U: IntoUrl,
+    F: FnMut(&Metadata, Continue),
+{
+    contribute_with_backend(
+        signing_key,
+        identifier,
+        server_url,
+        Arc::new(CpuBackend),
+        process_continuation,
+    )
+    .await
+}
+
+/// Runs the contribution protocol like [`contribute`], computing the contribution with the
+/// scalar multiplication `backend`.
+#[inline]
+pub async fn contribute_with_backend<C, U, F>(
+    signing_key: C::SigningKey,
+    identifier: C::Identifier,
+    server_url: U,
+    backend: SharedBackend<C::G1>,
     mut process_continuation: F,
 ) -> Result<ContributeResponse<C>, CeremonyError<C>>
 where
@@ -305,6 +395,7 @@ where
         KnownUrlClient::new(server_url).map_err(into_ceremony_error)?,
     )
     .await?;
+    client.set_backend(backend);
     process_continuation(&client.metadata, Continue::Started);
     loop {
         match client.try_contribute(&mut process_continuation).await {
@@ -317,3 +408,20 @@ where
         }
     }
 }
+
+/// Fetches the public transcript data for `round` from the ceremony server at `server_url`, so that
+/// third-party verifiers can follow the ceremony while it is running.
+#[inline]
+pub async fn round<C, U>(server_url: U, round: u64) -> Result<RoundResponse<C>, CeremonyError<C>>
+where
+    C: Ceremony,
+    C::Nonce: DeserializeOwned,
+    RoundResponse<C>: DeserializeOwned,
+    U: IntoUrl,
+{
+    KnownUrlClient::new(server_url)
+        .map_err(into_ceremony_error)?
+        .post::<_, Result<RoundResponse<C>, CeremonyError<C>>>("round", &RoundRequest { round })
+        .await
+        .map_err(into_ceremony_error)?
+}
@@ -18,15 +18,18 @@
 
 use crate::{
     ceremony::{
-        participant, registry,
+        participant::{self, ActivationWindow},
+        registry,
         registry::csv::append_only_csv_writer,
         signature::{sign, verify, Nonce as _, RawMessage, SignatureScheme},
     },
     groth16::{
+        backend::SharedBackend,
         ceremony::{
             client::{self, Continue},
+            log::{report, Level},
             message::ContributeResponse,
-            Ceremony, CeremonyError, Circuits,
+            Ceremony, CeremonyError, Circuits, UnexpectedError,
         },
         kzg::{self, Accumulator, Contribution, Size},
         mpc::{Configuration, Proof, ProvingKeyHasher, State},
@@ -68,7 +71,17 @@ use manta_util::{
     serde::{de::DeserializeOwned, Deserialize, Serialize},
     Array,
 };
-use std::{collections::HashMap, fs::File, path::PathBuf};
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "coordinator")]
+use manta_pay::{
+    config::{ProvingContext, VerifyingContext},
+    test::payment::UtxoAccumulator,
+};
 
 type Signature = Ed25519<RawMessage<u64>>;
 type VerifyingKey = signature::VerifyingKey<Signature>;
@@ -126,6 +139,109 @@ impl From<String> for Priority {
     }
 }
 
+/// Identity Provider
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(
+    bound(deserialize = "", serialize = ""),
+    crate = "manta_util::serde",
+    deny_unknown_fields
+)]
+pub enum IdentityProvider {
+    /// GitHub
+    GitHub,
+
+    /// Discord
+    Discord,
+}
+
+impl IdentityProvider {
+    /// Returns the name of `self` as written in the registry.
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::GitHub => "github",
+            Self::Discord => "discord",
+        }
+    }
+}
+
+impl TryFrom<&str> for IdentityProvider {
+    type Error = String;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "github" => Ok(Self::GitHub),
+            "discord" => Ok(Self::Discord),
+            _ => Err(format!("Unknown identity provider {value}.")),
+        }
+    }
+}
+
+/// Social Identity
+///
+/// Account of a participant with an OAuth [`IdentityProvider`], bound to their verifying key by an
+/// attestation signed by the identity attester of the ceremony.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(
+    bound(deserialize = "", serialize = ""),
+    crate = "manta_util::serde",
+    deny_unknown_fields
+)]
+pub struct Identity {
+    /// Identity Provider
+    pub provider: IdentityProvider,
+
+    /// Account Identifier at the Provider
+    pub account: String,
+}
+
+impl Identity {
+    /// Builds a new [`Identity`].
+    #[inline]
+    pub fn new(provider: IdentityProvider, account: String) -> Self {
+        Self { provider, account }
+    }
+
+    /// Returns the message signed by the identity attester to bind `self` to the base58-encoded
+    /// `verifying_key`.
+    #[inline]
+    pub fn attestation_message(&self, verifying_key: &str) -> String {
+        format!(
+            "manta-trusted-setup-identity:{}:{}, manta-trusted-setup-verifying-key:{verifying_key}",
+            self.provider.as_str(),
+            self.account
+        )
+    }
+}
+
+impl fmt::Display for Identity {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.provider.as_str(), self.account)
+    }
+}
+
+/// Attests that the participant with the given `verifying_key` owns `identity`, returning the
+/// base58-encoded attestation to store in their [`Record`].
+///
+/// This is run by the OAuth service of the ceremony with `attester_key` once the participant has
+/// signed in with the provider of `identity`.
+#[inline]
+pub fn attest_identity(
+    attester_key: &ed25519::SecretKey,
+    identity: &Identity,
+    verifying_key: &VerifyingKey,
+) -> Option<String> {
+    sign::<Signature, _>(
+        attester_key,
+        0,
+        &identity.attestation_message(&bs58::encode(&verifying_key[..]).into_string()),
+    )
+    .ok()
+    .map(|signature| bs58::encode(signature).into_string())
+}
+
 /// Participant
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(
@@ -157,6 +273,14 @@ pub struct Participant {
 
     /// Boolean on whether this participant has contributed
     contributed: bool,
+
+    /// Activation Window of the Cohort
+    #[serde(default)]
+    cohort: ActivationWindow,
+
+    /// Attested Social Identity
+    #[serde(default)]
+    identity: Option<Identity>,
 }
 
 impl fmt::Display for Participant {
@@ -175,6 +299,7 @@ impl Participant {
         priority: Priority,
         nonce: Nonce,
         contributed: bool,
+        cohort: ActivationWindow,
     ) -> Self {
         Self {
             verifying_key,
@@ -182,14 +307,29 @@ impl Participant {
             priority,
             nonce,
             contributed,
+            cohort,
+            identity: None,
         }
     }
 
+    /// Binds `self` to the attested social `identity`.
+    #[inline]
+    pub fn with_identity(mut self, identity: Option<Identity>) -> Self {
+        self.identity = identity;
+        self
+    }
+
     /// Gets `twitter`.
     #[inline]
     pub fn twitter(&self) -> &str {
         &self.twitter
     }
+
+    /// Returns the attested social identity of `self`, if any.
+    #[inline]
+    pub fn identity(&self) -> Option<&Identity> {
+        self.identity.as_ref()
+    }
 }
 
 impl participant::Participant for Participant {
@@ -228,6 +368,13 @@ impl participant::Participant for Participant {
     }
 }
 
+impl participant::Cohort for Participant {
+    #[inline]
+    fn activation_window(&self) -> ActivationWindow {
+        self.cohort
+    }
+}
+
 impl participant::Priority for Participant {
     type Priority = Priority;
 
@@ -264,6 +411,26 @@ pub struct Record {
 
     /// Signature
     signature: String,
+
+    /// Cohort Opening Time
+    #[serde(default)]
+    cohort_start: Option<u64>,
+
+    /// Cohort Closing Time
+    #[serde(default)]
+    cohort_end: Option<u64>,
+
+    /// Identity Provider
+    #[serde(default)]
+    identity_provider: Option<String>,
+
+    /// Identity Account
+    #[serde(default)]
+    identity_account: Option<String>,
+
+    /// Identity Attestation
+    #[serde(default)]
+    identity_attestation: Option<String>,
 }
 
 impl Record {
@@ -281,8 +448,83 @@ impl Record {
             priority,
             verifying_key,
             signature,
+            cohort_start: None,
+            cohort_end: None,
+            identity_provider: None,
+            identity_account: None,
+            identity_attestation: None,
         }
     }
+
+    /// Assigns `self` to the cohort with activation `window`.
+    #[inline]
+    pub fn with_cohort(mut self, window: ActivationWindow) -> Self {
+        self.cohort_start = window.start;
+        self.cohort_end = window.end;
+        self
+    }
+
+    /// Binds `self` to `identity` with the base58-encoded `attestation` returned by
+    /// [`attest_identity`].
+    #[inline]
+    pub fn with_identity(mut self, identity: &Identity, attestation: String) -> Self {
+        self.identity_provider = Some(identity.provider.as_str().to_string());
+        self.identity_account = Some(identity.account.clone());
+        self.identity_attestation = Some(attestation);
+        self
+    }
+
+    /// Returns the social identity claimed by `self`, if any, failing if the identity columns are
+    /// only partially filled.
+    #[inline]
+    pub fn identity(&self) -> Result<Option<Identity>, String> {
+        match (&self.identity_provider, &self.identity_account) {
+            (None, None) => Ok(None),
+            (Some(provider), Some(account)) => Ok(Some(Identity::new(
+                provider.as_str().try_into()?,
+                account.clone(),
+            ))),
+            _ => Err("Incomplete identity.".to_string()),
+        }
+    }
+
+    /// Verifies the identity attestation of `self` against the `attester` verifying key,
+    /// returning the attested identity, or `None` if `self` claims no identity.
+    #[inline]
+    pub fn verify_identity(&self, attester: &VerifyingKey) -> Result<Option<Identity>, String> {
+        let identity = match self.identity()? {
+            Some(identity) => identity,
+            _ => return Ok(None),
+        };
+        let attestation: ed25519::Signature = ed25519::signature_from_bytes(
+            bs58::decode(
+                self.identity_attestation
+                    .as_ref()
+                    .ok_or_else(|| "Missing identity attestation.".to_string())?,
+            )
+            .into_vec()
+            .map_err(|_| "Cannot decode identity attestation.".to_string())?
+            .try_into()
+            .map_err(|_| "Cannot decode identity attestation to array.".to_string())?,
+        )
+        .map_err(|_| "Byte conversion failed on identity attestation.".to_string())?;
+        verify::<Signature, _>(
+            attester,
+            0,
+            &identity.attestation_message(&self.verifying_key),
+            &attestation,
+        )
+        .map_err(|_| "Cannot verify identity attestation.".to_string())?;
+        Ok(Some(identity))
+    }
+
+    /// Drops the social identity claimed by `self`.
+    #[inline]
+    fn clear_identity(&mut self) {
+        self.identity_provider = None;
+        self.identity_account = None;
+        self.identity_attestation = None;
+    }
 }
 
 impl registry::csv::Record<VerifyingKey, Participant> for Record {
@@ -290,16 +532,23 @@ impl registry::csv::Record<VerifyingKey, Participant> for Record {
 
     #[inline]
     fn parse(self) -> Result<(VerifyingKey, Participant), Self::Error> {
+        let identity = self.identity()?;
         let verifying_key = ed25519::public_key_from_bytes(
             bs58::decode(self.verifying_key)
                 .into_vec()
                 .map_err(|e| {
-                    println!("Error decoding verifying key {e:?}");
+                    report(
+                        Level::Warn,
+                        format_args!("Error decoding verifying key {e:?}"),
+                    );
                     "Cannot decode signature.".to_string()
                 })?
                 .try_into()
                 .map_err(|e| {
-                    println!("Array conversion failed on verifying key {e:?}");
+                    report(
+                        Level::Warn,
+                        format_args!("Array conversion failed on verifying key {e:?}"),
+                    );
                     "Cannot decode to array.".to_string()
                 })?,
         )
@@ -309,12 +558,15 @@ impl registry::csv::Record<VerifyingKey, Participant> for Record {
             bs58::decode(self.signature)
                 .into_vec()
                 .map_err(|e| {
-                    println!("Error decoding signature {e:?}");
+                    report(Level::Warn, format_args!("Error decoding signature {e:?}"));
                     "Cannot decode signature.".to_string()
                 })?
                 .try_into()
                 .map_err(|e| {
-                    println!("Array conversion failed on signature {e:?}");
+                    report(
+                        Level::Warn,
+                        format_args!("Array conversion failed on signature {e:?}"),
+                    );
                     "Cannot decode to array.".to_string()
                 })?,
         )
@@ -337,7 +589,9 @@ impl registry::csv::Record<VerifyingKey, Participant> for Record {
                 self.priority.into(),
                 OsRng.gen::<_, u16>() as u64,
                 false,
-            ),
+                ActivationWindow::new(self.cohort_start, self.cohort_end),
+            )
+            .with_identity(identity),
         ))
     }
 }
@@ -374,7 +628,10 @@ pub fn set_header(
     match reader.byte_headers() {
         Ok(headers) => {
             if headers != expected_headers {
-                println!("Actual headers were \n{headers:?}");
+                report(
+                    Level::Warn,
+                    format_args!("Actual headers were \n{headers:?}"),
+                );
                 Err(RegistrationProcessingError::WrongHeaders)
             } else {
                 assert_eq!(expected_headers.len(), short_headers.len());
@@ -386,64 +643,293 @@ pub fn set_header(
     }
 }
 
+/// Malformed Registration Row
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MalformedRow {
+    /// Line of the Raw Registration Data where the Row Starts
+    pub line: u64,
+
+    /// Reason the Row was Rejected
+    pub reason: String,
+}
+
+/// Summary of Processed Registration Data
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RegistrationSummary {
+    /// Number of Successfully Parsed Rows
+    pub successful: usize,
+
+    /// Number of Successfully Parsed Rows whose Priority was Assigned by the Priority List
+    pub prioritized: usize,
+
+    /// Rows which could not be Parsed
+    pub malformed: Vec<MalformedRow>,
+}
+
+impl Display for RegistrationSummary {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} registrations parsed ({} from the priority list), {} malformed",
+            self.successful,
+            self.prioritized,
+            self.malformed.len()
+        )?;
+        for row in &self.malformed {
+            write!(f, "\n  line {}: {}", row.line, row.reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// Priority List
+///
+/// Priority levels assigned to registrants by an external scoring system, for instance an
+/// anti-Sybil score, keyed by verifying key or by Twitter account.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PriorityList {
+    /// Priorities by Verifying Key
+    verifying_keys: HashMap<VerifyingKey, Priority>,
+
+    /// Priorities by Twitter Account
+    twitter_accounts: HashMap<String, Priority>,
+}
+
+impl PriorityList {
+    /// Loads a [`PriorityList`] from the CSV file at `path` with an `account` and a `priority`
+    /// column. Accounts which decode to a base58-encoded verifying key are matched against the
+    /// verifying key of the registrants and all other accounts against their Twitter account,
+    /// ignoring case and a leading `@`. Priorities are either `high` or `normal`.
+    #[inline]
+    pub fn load<P>(path: P) -> Result<Self, RegistrationProcessingError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path)?;
+        let mut reader = Reader::from_reader(&file);
+        set_header(
+            &mut reader,
+            vec!["account", "priority"],
+            vec!["account", "priority"],
+        )?;
+        let mut list = Self::default();
+        for row in reader.records() {
+            let row = row.map_err(|_| RegistrationProcessingError::BadDataFormat)?;
+            let (account, priority) = match (row.get(0), row.get(1)) {
+                (Some(account), Some(priority)) => (account.trim(), priority.trim()),
+                _ => return Err(RegistrationProcessingError::BadDataFormat),
+            };
+            let priority = match priority.to_lowercase().as_str() {
+                "high" => Priority::High,
+                "normal" => Priority::Normal,
+                _ => {
+                    report(
+                        Level::Warn,
+                        format_args!("Unknown priority {priority:?} for account {account:?}"),
+                    );
+                    return Err(RegistrationProcessingError::BadDataFormat);
+                }
+            };
+            match bs58::decode(account)
+                .into_vec()
+                .ok()
+                .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            {
+                Some(bytes) => list.insert_verifying_key(Array::from_unchecked(bytes), priority),
+                _ => list.insert_twitter_account(account, priority),
+            };
+        }
+        Ok(list)
+    }
+
+    /// Assigns `priority` to the registrant with `verifying_key`, returning the previous
+    /// priority if there was one.
+    #[inline]
+    pub fn insert_verifying_key(
+        &mut self,
+        verifying_key: VerifyingKey,
+        priority: Priority,
+    ) -> Option<Priority> {
+        self.verifying_keys.insert(verifying_key, priority)
+    }
+
+    /// Assigns `priority` to the registrant with the Twitter `account`, returning the previous
+    /// priority if there was one.
+    #[inline]
+    pub fn insert_twitter_account(
+        &mut self,
+        account: &str,
+        priority: Priority,
+    ) -> Option<Priority> {
+        self.twitter_accounts
+            .insert(Self::normalize_twitter_account(account), priority)
+    }
+
+    /// Returns the priority of the registrant with `verifying_key` and Twitter `account`. The
+    /// verifying key takes precedence over the Twitter account.
+    #[inline]
+    pub fn get(&self, verifying_key: &VerifyingKey, account: &str) -> Option<Priority> {
+        self.verifying_keys.get(verifying_key).copied().or_else(|| {
+            self.twitter_accounts
+                .get(&Self::normalize_twitter_account(account))
+                .copied()
+        })
+    }
+
+    /// Returns the number of entries in `self`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.verifying_keys.len() + self.twitter_accounts.len()
+    }
+
+    /// Returns `true` if `self` has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Normalizes the Twitter `account` by removing a leading `@` and ignoring case.
+    #[inline]
+    fn normalize_twitter_account(account: &str) -> String {
+        account.trim().trim_start_matches('@').to_lowercase()
+    }
+}
+
 /// Extracts all [`Record`]s from a CSV file of raw registration
 /// data and appends these to a CSV file containing only these `Record`s
 /// at the specified path. A [`Registry`] can be loaded from the
 /// output file. Appends to another CSV file of malformed registry entries
 /// in case ceremony coordinators wish to examine these.
-/// Returns the pair (number successfully parsed, number malformed).
-/// Participants are given default priority.
+/// Rows are read, verified and written one at a time, so the output file
+/// grows as the input is processed, and rows which cannot be read or whose
+/// signature does not verify are reported in the returned summary together
+/// with their line numbers. Participants are given default priority unless
+/// they appear in the [`PriorityList`] loaded from the `priority_list` file,
+/// in which case the priority from the list is recorded in the output.
+/// Social identities claimed by the rows are verified against the `attester`
+/// verifying key, and each identity can only be bound to one verifying key,
+/// so rows with an invalid attestation or an identity which is already bound
+/// to another verifying key are rejected. Without an `attester`, identities
+/// cannot be verified and are dropped from the output.
 /// NOTE: This function does not truncate the output files, it appends.
 pub fn extract_registry<R>(
     path_to_in: PathBuf,
     path_to_out: PathBuf,
     expected_headers: Vec<&str>,
     short_headers: Vec<&str>,
-    priority_list: HashMap<Array<u8, 32>, Priority>,
-) -> Result<(usize, usize), RegistrationProcessingError>
+    priority_list: Option<PathBuf>,
+    attester: Option<VerifyingKey>,
+) -> Result<RegistrationSummary, RegistrationProcessingError>
 where
-    R: DeserializeOwned + Into<Record> + Clone,
+    R: DeserializeOwned + Into<Record>,
 {
-    let file_in = File::open(path_to_in).expect("Unable to open raw registry data");
+    let priority_list = match priority_list {
+        Some(path) => PriorityList::load(path)?,
+        _ => PriorityList::default(),
+    };
+    let file_in = File::open(path_to_in)?;
     let mut reader = Reader::from_reader(&file_in);
     set_header(&mut reader, expected_headers, short_headers)?;
+    let headers = reader
+        .headers()
+        .map_err(|_| RegistrationProcessingError::BadDataFormat)?
+        .clone();
 
-    let mut writer = append_only_csv_writer::<RegistrationProcessingError, _>(path_to_out.clone())
-        .expect("Error opening output file");
+    let mut writer = append_only_csv_writer::<RegistrationProcessingError, _>(&path_to_out)?;
     let mut writer_malformed = append_only_csv_writer::<RegistrationProcessingError, _>(
-        path_to_out
-            .parent()
-            .expect("Path should have a parent")
-            .join("malformed_registry_submissions.csv"),
-    )
-    .expect("Error opening output file");
-
-    let mut num_successful = 0;
-    let mut num_malformed = 0;
-    for (i, record) in reader.deserialize::<R>().flatten().enumerate() {
-        match <Record as registry::csv::Record<_, _>>::parse(record.clone().into()) {
-            Ok((verifying_key, _)) => {
-                let mut record: Record = record.into();
-                if let Some(priority) = priority_list.get(&verifying_key) {
+        path_to_out.with_file_name("malformed_registry_submissions.csv"),
+    )?;
+
+    let mut summary = RegistrationSummary::default();
+    let mut identities = HashMap::<Identity, VerifyingKey>::new();
+    let mut row = StringRecord::new();
+    loop {
+        match reader.read_record(&mut row) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) if e.is_io_error() => return Err(RegistrationProcessingError::BadDataFormat),
+            Err(e) => {
+                let line = e.position().map(|p| p.line()).unwrap_or_default();
+                report(
+                    Level::Warn,
+                    format_args!("Encountered error {e} when reading entry on line {line}"),
+                );
+                summary.malformed.push(MalformedRow {
+                    line,
+                    reason: e.to_string(),
+                });
+                continue;
+            }
+        }
+        let line = row.position().map(|p| p.line()).unwrap_or_default();
+        let record: Record = match row.deserialize::<R>(Some(&headers)) {
+            Ok(record) => record.into(),
+            Err(e) => {
+                report(
+                    Level::Warn,
+                    format_args!("Encountered error {e} when reading entry on line {line}"),
+                );
+                summary.malformed.push(MalformedRow {
+                    line,
+                    reason: e.to_string(),
+                });
+                continue;
+            }
+        };
+        let parsed = <Record as registry::csv::Record<_, _>>::parse(record.clone()).and_then(
+            |(verifying_key, _)| {
+                let identity = match &attester {
+                    Some(attester) => record.verify_identity(attester)?,
+                    _ => None,
+                };
+                if let Some(identity) = identity {
+                    match identities.get(&identity) {
+                        Some(bound) if *bound != verifying_key => {
+                            return Err(format!(
+                                "Identity {identity} is already bound to another verifying key."
+                            ))
+                        }
+                        _ => {
+                            identities.insert(identity, verifying_key);
+                        }
+                    }
+                }
+                Ok(verifying_key)
+            },
+        );
+        match parsed {
+            Ok(verifying_key) => {
+                let mut record = record;
+                if attester.is_none() {
+                    record.clear_identity();
+                }
+                if let Some(priority) = priority_list.get(&verifying_key, &record.twitter) {
                     record.priority = priority.into();
+                    summary.prioritized += 1;
                 } else {
                     record.priority = Priority::Normal.into();
                 }
-                num_successful += 1;
+                summary.successful += 1;
                 writer
                     .serialize(record)
-                    .map_err(|_| RegistrationProcessingError::WriteError)?
+                    .map_err(|_| RegistrationProcessingError::WriteError)?;
+                writer.flush()?;
             }
             Err(e) => {
-                println!("Encountered error {e:?} when reading entry {}", i + 2);
-                num_malformed += 1;
+                report(
+                    Level::Warn,
+                    format_args!("Encountered error {e:?} when reading entry on line {line}"),
+                );
+                summary.malformed.push(MalformedRow { line, reason: e });
                 writer_malformed
-                    .serialize(record.into())
-                    .map_err(|_| RegistrationProcessingError::WriteError)?
+                    .serialize(record)
+                    .map_err(|_| RegistrationProcessingError::WriteError)?;
+                writer_malformed.flush()?;
             }
         }
     }
-    Ok((num_successful, num_malformed))
+    Ok(summary)
 }
 
 /// The registry used in this ceremony
@@ -519,7 +1005,15 @@ pub fn get_client_keys() -> Result<(ed25519::SecretKey, ed25519::PublicKey), Cli
         })
         .interact_text()
         .map_err(|_| ClientKeyError::InvalidSecret)?;
-    let mnemonic = Mnemonic::from_phrase(text.trim(), Language::English)
+    keys_from_secret(&text)
+}
+
+/// Recovers the keys from the `secret` mnemonic phrase given out by [`register`].
+#[inline]
+pub fn keys_from_secret(
+    secret: &str,
+) -> Result<(ed25519::SecretKey, ed25519::PublicKey), ClientKeyError> {
+    let mnemonic = Mnemonic::from_phrase(secret.trim(), Language::English)
         .map_err(|_| ClientKeyError::MnemonicFailure)?;
     let seed_bytes = Seed::new(&mnemonic, "manta-trusted-setup")
         .as_bytes()
@@ -562,20 +1056,28 @@ impl fmt::Display for ClientKeyError {
     }
 }
 
+impl From<ClientKeyError> for manta_util::error::Error {
+    #[inline]
+    fn from(err: ClientKeyError) -> Self {
+        Self::new(err)
+    }
+}
+
 /// Contributes to the server.
 #[inline]
 pub async fn client_contribute<C>(
     signing_key: C::SigningKey,
     identifier: C::Identifier,
     url: String,
+    backend: SharedBackend<C::G1>,
 ) -> Result<(), CeremonyError<C>>
 where
     C: Ceremony,
     C::Challenge: Debug + DeserializeOwned,
     C::ContributionHash: AsRef<[u8]> + Debug,
-    C::Identifier: Serialize,
+    C::Identifier: DeserializeOwned + Serialize,
     C::Nonce: Clone + Debug + DeserializeOwned + Serialize,
-    C::Signature: Serialize,
+    C::Signature: DeserializeOwned + Serialize,
     C::ContributionHash: AsRef<[u8]>,
 {
     println!(
@@ -587,73 +1089,87 @@ where
 
     let mut downloading_state = false;
 
-    let response =
-        client::contribute(
-            signing_key,
-            identifier,
-            url.as_str(),
-            |metadata, state| match state {
-                Continue::Started => {
-                    println!("\n");
-                }
-                Continue::Position(position) => {
-                    if !downloading_state {
-                        let _ = term.clear_last_lines(2);
-                        if position == 0 {
-                            println!("{} Waiting in queue...", style("[1/6]").bold());
-                            println!(
-                                "{} Receiving data from Server... \
+    let mut showing_progress = false;
+
+    let response = client::contribute_with_backend(
+        signing_key,
+        identifier,
+        url.as_str(),
+        backend,
+        |metadata, state| match state {
+            Continue::Started => {
+                println!("\n");
+            }
+            Continue::Position(position) => {
+                if !downloading_state {
+                    let _ = term.clear_last_lines(2);
+                    if position == 0 {
+                        println!("{} Waiting in queue...", style("[1/6]").bold());
+                        println!(
+                            "{} Receiving data from Server... \
                              This may take a few minutes.",
-                                style("[2/6]").bold()
-                            );
-                            downloading_state = true;
-                        } else if position <= u32::MAX.into() {
-                            let minutes =
-                                metadata.contribution_time_limit.as_secs() * position / 60;
-                            println!(
-                                "{} Waiting in queue... There are {} people ahead of you.\n      \
+                            style("[2/6]").bold()
+                        );
+                        downloading_state = true;
+                    } else if position <= u32::MAX.into() {
+                        let minutes = metadata.contribution_time_limit.as_secs() * position / 60;
+                        println!(
+                            "{} Waiting in queue... There are {} people ahead of you.\n      \
                              Estimated Waiting Time: {}.",
-                                style("[1/6]").bold(),
-                                style(position).bold().red(),
-                                style(format!("{minutes:?} min")).bold().red(),
-                            );
-                        } else {
-                            println!(
-                                "{} Waiting in queue... There are many people ahead of you. \
+                            style("[1/6]").bold(),
+                            style(position).bold().red(),
+                            style(format!("{minutes:?} min")).bold().red(),
+                        );
+                    } else {
+                        println!(
+                            "{} Waiting in queue... There are many people ahead of you. \
                              Estimated Waiting Time: forever.",
-                                style("[1/6]").bold(),
-                            );
-                        }
+                            style("[1/6]").bold(),
+                        );
                     }
                 }
-                Continue::ComputingUpdate => {
-                    downloading_state = false;
-                    println!(
-                        "{} Computing contributions. This may take up to 10 minutes.",
-                        style("[3/6]").bold()
-                    );
-                }
-                Continue::SendingUpdate => {
-                    println!(
-                        "{} Contribution Computed. Sending data to server.",
-                        style("[4/6]").bold()
-                    );
-                    println!(
-                        "{} Awaiting confirmation from server.",
-                        style("[5/6]").bold()
-                    );
-                }
-                Continue::Timeout => {
-                    downloading_state = false;
+            }
+            Continue::ComputingUpdate => {
+                downloading_state = false;
+                println!(
+                    "{} Computing contributions. This may take up to 10 minutes.",
+                    style("[3/6]").bold()
+                );
+            }
+            Continue::Progress {
+                completed_circuits,
+                circuit_count,
+                percent,
+            } => {
+                if showing_progress {
                     let _ = term.clear_last_lines(1);
-                    println!(
-                        "{} You have timed out. Waiting in queue again ... \n\n",
-                        style("[WARN]").bold().yellow()
-                    );
                 }
-            },
-        )
-        .await?;
+                showing_progress = true;
+                println!("      {completed_circuits}/{circuit_count} circuits, {percent}%");
+            }
+            Continue::SendingUpdate => {
+                showing_progress = false;
+                println!(
+                    "{} Contribution Computed. Sending data to server.",
+                    style("[4/6]").bold()
+                );
+                println!(
+                    "{} Awaiting confirmation from server.",
+                    style("[5/6]").bold()
+                );
+            }
+            Continue::Timeout => {
+                downloading_state = false;
+                showing_progress = false;
+                let _ = term.clear_last_lines(1);
+                println!(
+                    "{} You have timed out. Waiting in queue again ... \n\n",
+                    style("[WARN]").bold().yellow()
+                );
+            }
+        },
+    )
+    .await?;
     let contribution_hash = hex::encode(C::contribution_hash(&response));
     let tweet = style(format!(
         "I made contribution number {} to the #MantaNetworkTrustedSetup! \
@@ -668,6 +1184,25 @@ where
         style("[6/6]").bold(),
         tweet,
     );
+    if let Some(receipt) = response.receipt {
+        let path = format!("contribution_receipt_{}.json", response.index);
+        let receipt = serde_json::to_string_pretty(&receipt).map_err(|e| {
+            CeremonyError::Unexpected(UnexpectedError::Serialization {
+                message: format!("{e:?}"),
+            })
+        })?;
+        match fs::write(&path, &receipt) {
+            Ok(()) => println!(
+                "Your signed contribution receipt was saved to {}. Keep it to prove your \
+                 contribution later on.",
+                path.bold()
+            ),
+            Err(_) => println!(
+                "Unable to save your signed contribution receipt. Keep the following text to \
+                 prove your contribution later on:\n{receipt}"
+            ),
+        }
+    }
     Ok(())
 }
 
@@ -913,6 +1448,51 @@ impl Circuits<<Self as Pairing>::Scalar> for Config {
     }
 }
 
+/// Checks the keys extracted for the circuit called `name` by proving and verifying a randomly
+/// sampled transfer of the same shape, built with the transfer parameters the ceremony circuits
+/// were compiled with. Returns `false` if `name` is not a ceremony circuit or if the proof could
+/// not be built or does not verify.
+#[cfg(feature = "coordinator")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "coordinator")))]
+#[inline]
+pub fn check_transfer_keys(
+    name: &str,
+    proving_context: &ProvingContext,
+    verifying_context: &VerifyingContext,
+) -> bool {
+    let parameters = load_transfer_parameters();
+    let mut utxo_accumulator = UtxoAccumulator::new(load_utxo_accumulator_model());
+    let mut rng = OsRng;
+    let result = match name {
+        "to_private" => ToPrivate::sample_and_check_proof_with_context(
+            proving_context,
+            verifying_context,
+            &parameters,
+            &mut utxo_accumulator,
+            None,
+            &mut rng,
+        ),
+        "to_public" => ToPublic::sample_and_check_proof_with_context(
+            proving_context,
+            verifying_context,
+            &parameters,
+            &mut utxo_accumulator,
+            Some(&rng.gen()),
+            &mut rng,
+        ),
+        "private_transfer" => PrivateTransfer::sample_and_check_proof_with_context(
+            proving_context,
+            verifying_context,
+            &parameters,
+            &mut utxo_accumulator,
+            Some(&rng.gen()),
+            &mut rng,
+        ),
+        _ => return false,
+    };
+    matches!(result, Ok(true))
+}
+
 /// Generates a dummy R1CS circuit.
 #[inline]
 pub fn dummy_circuit(cs: &mut R1CS<<Config as Pairing>::Scalar>) {
@@ -924,14 +1504,15 @@ pub fn dummy_circuit(cs: &mut R1CS<<Config as Pairing>::Scalar>) {
         .expect("enforce_equal is not allowed to fail");
 }
 
-/// Displays whenever `result` is an `Err`-variant and formats the error.
+/// Displays whenever `result` is an `Err`-variant and formats the error, including any chain of
+/// underlying causes.
 #[inline]
 pub fn display_on_error<T, E>(result: Result<T, E>)
 where
     E: Display,
 {
     if let Err(e) = result {
-        println!("{} {e}", style("[ERROR]").bold().red());
+        println!("{} {e:#}", style("[ERROR]").bold().red());
     }
 }
 
@@ -948,4 +1529,305 @@ mod test {
             "mantalorian@manta.network".to_string(),
         );
     }
+
+    /// Returns a base58-encoded verifying key and signature for a registration with the twitter
+    /// account `valid` and the email `valid@manta.network`.
+    fn signed_registration() -> (String, String) {
+        signed_registration_with(7, "valid")
+    }
+
+    /// Returns a base58-encoded verifying key generated from `seed` and a signature for a
+    /// registration with the twitter account `twitter` and the email `{twitter}@manta.network`.
+    fn signed_registration_with(seed: u8, twitter: &str) -> (String, String) {
+        let (secret_key, public_key) =
+            generate_keys(&[seed; SECRET_KEY_LENGTH]).expect("Should generate a key pair.");
+        let signature = sign::<Signature, _>(
+            &secret_key,
+            0,
+            &format!(
+                "manta-trusted-setup-twitter:{twitter}, manta-trusted-setup-email:{twitter}@manta.network"
+            ),
+        )
+        .expect("Signing message should succeed.");
+        (
+            bs58::encode(public_key).into_string(),
+            bs58::encode(signature).into_string(),
+        )
+    }
+
+    /// Tests that [`extract_registry`] records the priorities of the priority list, matching
+    /// registrants by verifying key before their Twitter account, and rejects priority lists with
+    /// unknown priority levels.
+    #[test]
+    fn extract_registry_assigns_listed_priorities() {
+        let headers = vec!["twitter", "email", "priority", "verifying_key", "signature"];
+        let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let path_to_in = directory.path().join("raw.csv");
+        let mut raw = headers.join(",");
+        let mut keys = Vec::new();
+        for (seed, twitter) in [(1, "alice"), (2, "bob"), (3, "carol")] {
+            let (verifying_key, signature) = signed_registration_with(seed, twitter);
+            raw.push_str(&format!(
+                "\n{twitter},{twitter}@manta.network,high,{verifying_key},{signature}"
+            ));
+            keys.push(verifying_key);
+        }
+        std::fs::write(&path_to_in, raw).expect("Unable to write raw registry data.");
+        let priority_list = directory.path().join("priorities.csv");
+        std::fs::write(
+            &priority_list,
+            format!(
+                "account,priority\n\
+                 {},high\n\
+                 @Bob,high\n\
+                 carol,high\n\
+                 {},normal\n",
+                keys[0], keys[2]
+            ),
+        )
+        .expect("Unable to write priority list.");
+        let path_to_out = directory.path().join("registry.csv");
+        let summary = extract_registry::<Record>(
+            path_to_in.clone(),
+            path_to_out.clone(),
+            headers.clone(),
+            headers.clone(),
+            Some(priority_list.clone()),
+            None,
+        )
+        .expect("Extracting the registry should succeed.");
+        assert_eq!(summary.successful, 3);
+        assert_eq!(summary.prioritized, 3);
+        let mut registry = Registry::new();
+        registry::csv::load_append_entries::<_, _, Record, _, _>(&path_to_out, &mut registry)
+            .expect("Unable to load the registry.");
+        let priorities = registry
+            .values()
+            .map(|participant| (participant.twitter().to_string(), participant.priority))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(priorities["alice"], Priority::High);
+        assert_eq!(priorities["bob"], Priority::High);
+        assert_eq!(priorities["carol"], Priority::Normal);
+        std::fs::write(&priority_list, "account,priority\nalice,urgent\n")
+            .expect("Unable to write priority list.");
+        assert!(matches!(
+            extract_registry::<Record>(
+                path_to_in,
+                directory.path().join("rejected.csv"),
+                headers.clone(),
+                headers,
+                Some(priority_list),
+                None,
+            ),
+            Err(RegistrationProcessingError::BadDataFormat)
+        ));
+    }
+
+    /// Tests that [`extract_registry`] keeps valid rows and reports malformed rows with their
+    /// line numbers.
+    #[test]
+    fn extract_registry_reports_malformed_rows() {
+        let (verifying_key, signature) = signed_registration();
+        let headers = vec!["twitter", "email", "priority", "verifying_key", "signature"];
+        let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let path_to_in = directory.path().join("raw.csv");
+        std::fs::write(
+            &path_to_in,
+            format!(
+                "{}\n\
+                 valid,valid@manta.network,normal,{verifying_key},{signature}\n\
+                 missing,columns\n\
+                 forged,forged@manta.network,normal,{verifying_key},{signature}\n",
+                headers.join(",")
+            ),
+        )
+        .expect("Unable to write raw registry data.");
+        let path_to_out = directory.path().join("registry.csv");
+        let summary = extract_registry::<Record>(
+            path_to_in,
+            path_to_out.clone(),
+            headers.clone(),
+            headers,
+            None,
+            None,
+        )
+        .expect("Extracting the registry should succeed.");
+        assert_eq!(summary.successful, 1);
+        assert_eq!(
+            summary
+                .malformed
+                .iter()
+                .map(|row| row.line)
+                .collect::<Vec<_>>(),
+            vec![3, 4]
+        );
+        let registry = std::fs::read_to_string(path_to_out).expect("Unable to read registry.");
+        assert_eq!(registry.lines().count(), 2);
+        assert!(registry.contains(&verifying_key));
+    }
+
+    /// Tests that [`extract_registry`] keeps the attested identities, rejects forged attestations
+    /// and identities bound to another verifying key, and drops identities without an attester.
+    #[test]
+    fn extract_registry_verifies_identity_attestations() {
+        let (attester_key, attester) =
+            generate_keys(&[3; SECRET_KEY_LENGTH]).expect("Should generate a key pair.");
+        let attester = Array::from_unchecked(*attester.as_bytes());
+        let mut rows = Vec::new();
+        for (seed, account) in [(5, "alice"), (6, "mallory"), (7, "alice")] {
+            let (secret_key, public_key) =
+                generate_keys(&[seed; SECRET_KEY_LENGTH]).expect("Should generate a key pair.");
+            let verifying_key = Array::from_unchecked(*public_key.as_bytes());
+            let twitter = format!("participant{seed}");
+            let email = format!("{twitter}@manta.network");
+            let signature = sign::<Signature, _>(
+                &secret_key,
+                0,
+                &format!(
+                    "manta-trusted-setup-twitter:{twitter}, manta-trusted-setup-email:{email}"
+                ),
+            )
+            .expect("Signing message should succeed.");
+            let identity = Identity::new(IdentityProvider::GitHub, account.to_string());
+            let attestation = if account == "mallory" {
+                attest_identity(&secret_key, &identity, &verifying_key)
+            } else {
+                attest_identity(&attester_key, &identity, &verifying_key)
+            }
+            .expect("Attesting the identity should succeed.");
+            rows.push(
+                Record::new(
+                    twitter,
+                    email,
+                    "normal".to_string(),
+                    bs58::encode(&verifying_key[..]).into_string(),
+                    bs58::encode(signature).into_string(),
+                )
+                .with_identity(&identity, attestation),
+            );
+        }
+        let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let path_to_in = directory.path().join("raw.csv");
+        let mut writer = csv::Writer::from_path(&path_to_in).expect("Unable to create raw data.");
+        for row in &rows {
+            writer.serialize(row).expect("Unable to write raw data.");
+        }
+        writer.flush().expect("Unable to write raw data.");
+        let headers = vec![
+            "twitter",
+            "email",
+            "priority",
+            "verifying_key",
+            "signature",
+            "cohort_start",
+            "cohort_end",
+            "identity_provider",
+            "identity_account",
+            "identity_attestation",
+        ];
+        let path_to_out = directory.path().join("registry.csv");
+        let summary = extract_registry::<Record>(
+            path_to_in.clone(),
+            path_to_out.clone(),
+            headers.clone(),
+            headers.clone(),
+            None,
+            Some(attester),
+        )
+        .expect("Extracting the registry should succeed.");
+        assert_eq!(summary.successful, 1);
+        assert_eq!(
+            summary
+                .malformed
+                .iter()
+                .map(|row| row.line)
+                .collect::<Vec<_>>(),
+            vec![3, 4]
+        );
+        let registry = registry::csv::load::<_, _, Record, Registry, _>(&path_to_out)
+            .expect("Loading the registry should succeed.");
+        assert_eq!(
+            registry
+                .values()
+                .map(|participant| participant.identity().cloned())
+                .collect::<Vec<_>>(),
+            vec![Some(Identity::new(
+                IdentityProvider::GitHub,
+                "alice".to_string()
+            ))]
+        );
+        let unverified_out = directory.path().join("unverified.csv");
+        let summary = extract_registry::<Record>(
+            path_to_in,
+            unverified_out.clone(),
+            headers.clone(),
+            headers,
+            None,
+            None,
+        )
+        .expect("Extracting the registry should succeed.");
+        assert_eq!(summary.successful, 3);
+        let registry = registry::csv::load::<_, _, Record, Registry, _>(&unverified_out)
+            .expect("Loading the registry should succeed.");
+        assert!(registry
+            .values()
+            .all(|participant| participant.identity().is_none()));
+    }
+
+    /// Tests that [`registry::csv::audit`] reports invalid entries and duplicate verifying keys.
+    #[test]
+    fn audit_reports_invalid_and_duplicate_entries() {
+        let (verifying_key, signature) = signed_registration();
+        let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let path = directory.path().join("registry.csv");
+        std::fs::write(
+            &path,
+            format!(
+                "twitter,email,priority,verifying_key,signature\n\
+                 valid,valid@manta.network,normal,{verifying_key},{signature}\n\
+                 forged,forged@manta.network,normal,{verifying_key},{signature}\n\
+                 valid,valid@manta.network,high,{verifying_key},{signature}\n"
+            ),
+        )
+        .expect("Unable to write registry.");
+        let audit = registry::csv::audit::<_, Participant, Record, _>(&path)
+            .expect("Auditing the registry should succeed.");
+        assert!(!audit.is_clean());
+        assert_eq!(audit.valid, 2);
+        assert_eq!(
+            audit
+                .invalid
+                .iter()
+                .map(|entry| entry.line)
+                .collect::<Vec<_>>(),
+            vec![3]
+        );
+        assert_eq!(audit.duplicates.len(), 1);
+        assert_eq!(audit.duplicates[0].lines, vec![2, 4]);
+    }
+
+    /// Tests that [`check_transfer_keys`] accepts keys compiled for the named circuit and rejects
+    /// them for any other circuit.
+    #[cfg(feature = "coordinator")]
+    #[test]
+    fn check_transfer_keys_matches_circuit() {
+        use manta_crypto::constraint::ProofSystem as _;
+        let cs = ToPrivate::unknown_constraints(FullParametersRef::new(
+            &load_transfer_parameters(),
+            &load_utxo_accumulator_model(),
+        ));
+        let (proving_context, verifying_context) =
+            manta_pay::config::ProofSystem::compile(&(), cs, &mut OsRng)
+                .expect("Unable to compile the ToPrivate circuit.");
+        assert!(check_transfer_keys(
+            "to_private",
+            &proving_context,
+            &verifying_context
+        ));
+        assert!(!check_transfer_keys(
+            "to_public",
+            &proving_context,
+            &verifying_context
+        ));
+    }
 }
@@ -18,15 +18,18 @@
 
 use crate::{
     ceremony::{
-        participant::{Participant, Priority},
+        participant::{Cohort, Participant, Priority},
+        queue::PersistentQueue,
         registry::{self, Registry},
         signature::{Nonce, SignedMessage},
-        util::{deserialize_from_file, serialize_into_file},
+        storage::{self, deserialize_from_storage, serialize_into_storage, Storage},
+        util::serialize_into_file,
     },
     groth16::{
         ceremony::{
-            server::filename_format, Ceremony, CeremonyError, Circuits, Configuration, Metadata,
-            Queue, Round, UnexpectedError,
+            log::{report, Level},
+            server::{filename_format, key_format},
+            Ceremony, CeremonyError, Circuits, Configuration, Metadata, Round, UnexpectedError,
         },
         kzg,
         kzg::Accumulator,
@@ -36,7 +39,7 @@ use crate::{
     },
     mpc::ChallengeType,
 };
-use core::{fmt::Debug, mem};
+use core::{fmt::Debug, mem, time::Duration};
 use manta_crypto::arkworks::{
     bn254::{G1Affine, G2Affine},
     pairing::Pairing,
@@ -45,16 +48,23 @@ use manta_crypto::arkworks::{
 use manta_util::{time::lock::Timed, Array, BoxArray};
 use std::{
     fs::OpenOptions,
-    path::{Path, PathBuf},
+    path::PathBuf,
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
 };
 
 #[cfg(feature = "serde")]
 use manta_util::serde::{Deserialize, Serialize};
 
+/// Lock Check
+///
+/// Flag set when the lock was updated while checking it, and the result of the check for the
+/// participant, as returned by [`LockQueue::has_lock`].
+pub type LockCheck<C> = (bool, Result<(), CeremonyError<C>>);
+
 /// Queue and Participant Lock
 #[derive(derivative::Derivative)]
 #[derivative(
-    Clone(bound = ""),
     Debug(bound = "C::Identifier: Debug"),
     Default(bound = ""),
     Eq(bound = ""),
@@ -65,19 +75,34 @@ where
     C: Ceremony,
 {
     /// Participant Queue
-    queue: Queue<C, LEVEL_COUNT>,
+    queue: PersistentQueue<C::Identifier, LEVEL_COUNT>,
 
     /// Participant Lock
     participant_lock: Timed<Option<C::Identifier>>,
+
+    /// Draining Flag
+    ///
+    /// While draining, the lock is not handed to the next participant in the queue.
+    draining: bool,
 }
 
 impl<C, const LEVEL_COUNT: usize> LockQueue<C, LEVEL_COUNT>
 where
     C: Ceremony,
 {
+    /// Builds a new [`LockQueue`] with an empty lock over `queue`.
+    #[inline]
+    pub fn new(queue: PersistentQueue<C::Identifier, LEVEL_COUNT>) -> Self {
+        Self {
+            queue,
+            participant_lock: Default::default(),
+            draining: false,
+        }
+    }
+
     /// Returns a mutable reference to `queue`.
     #[inline]
-    pub fn queue_mut(&mut self) -> &mut Queue<C, LEVEL_COUNT> {
+    pub fn queue_mut(&mut self) -> &mut PersistentQueue<C::Identifier, LEVEL_COUNT> {
         &mut self.queue
     }
 
@@ -87,6 +112,19 @@ where
         &mut self.participant_lock
     }
 
+    /// Stops handing the lock to the participants in the queue. The participant currently holding
+    /// the lock can still finish their contribution.
+    #[inline]
+    pub fn drain(&mut self) {
+        self.draining = true;
+    }
+
+    /// Returns `true` if the lock is no longer handed to the participants in the queue.
+    #[inline]
+    pub fn is_draining(&self) -> bool {
+        self.draining
+    }
+
     /// Checks if the lock is expired or if nobody is in it.
     #[inline]
     pub fn has_expired(&self, metadata: &Metadata) -> bool {
@@ -96,67 +134,96 @@ where
     }
 
     /// Checks if the lock is expired. If so, it updates it.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the removal from the queue cannot be written to its log.
     #[inline]
     pub fn update_lock<R>(
         &mut self,
         metadata: &Metadata,
         registry: &mut R,
-    ) -> (bool, Option<C::Identifier>)
+    ) -> Result<(bool, Option<C::Identifier>), CeremonyError<C>>
     where
+        C::Identifier: Serialize,
         R: Registry<C::Identifier, C::Participant>,
     {
         if self.has_expired(metadata) {
-            (true, self.update_expired_lock(registry))
+            Ok((true, self.update_expired_lock(registry)?))
         } else {
-            (false, None)
+            Ok((false, None))
         }
     }
 
-    /// Checks whether 'participant' has the lock. Returns an Error if not.
+    /// Checks whether 'participant' has the lock. The inner result is an error if not.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the lock had to be updated and the removal from the queue
+    /// cannot be written to its log.
     #[inline]
     pub fn has_lock<R>(
         &mut self,
         participant: &C::Identifier,
         metadata: &Metadata,
         registry: &mut R,
-    ) -> (bool, Result<(), CeremonyError<C>>)
+    ) -> Result<LockCheck<C>, CeremonyError<C>>
     where
+        C::Identifier: Serialize,
         R: Registry<C::Identifier, C::Participant>,
     {
-        let (has_been_updated, identifier) = self.update_lock(metadata, registry);
+        let (has_been_updated, identifier) = self.update_lock(metadata, registry)?;
         if let Some(p) = identifier {
             if p == *participant {
-                return (has_been_updated, Err(CeremonyError::Timeout));
+                return Ok((has_been_updated, Err(CeremonyError::Timeout)));
             }
         };
         match self.participant_lock.get() {
             Some(p) => {
                 if p == participant {
-                    (has_been_updated, Ok(()))
+                    Ok((has_been_updated, Ok(())))
                 } else {
-                    (has_been_updated, Err(CeremonyError::NotYourTurn))
+                    Ok((has_been_updated, Err(CeremonyError::NotYourTurn)))
                 }
             }
-            _ => (has_been_updated, Err(CeremonyError::NotYourTurn)),
+            _ => Ok((has_been_updated, Err(CeremonyError::NotYourTurn))),
         }
     }
 
     /// Updates the expired lock by reducing the priority of its participant and setting its
-    /// contained value to the new front of the queue. The previous participant in the lock is
-    /// returned.
+    /// contained value to the new front of the queue, or emptying it if the lock is being
+    /// [`drain`](Self::drain)ed. The previous participant in the lock is returned.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the removal from the queue cannot be written to its log,
+    /// in which case the lock is left unchanged.
     #[inline]
-    pub fn update_expired_lock<R>(&mut self, registry: &mut R) -> Option<C::Identifier>
+    pub fn update_expired_lock<R>(
+        &mut self,
+        registry: &mut R,
+    ) -> Result<Option<C::Identifier>, CeremonyError<C>>
     where
+        C::Identifier: Serialize,
         R: Registry<C::Identifier, C::Participant>,
     {
-        self.participant_lock.mutate(|p| {
+        let next = if self.draining {
+            None
+        } else {
+            self.queue.pop_front().map_err(|e| {
+                CeremonyError::Unexpected(UnexpectedError::Serialization {
+                    message: format!("{e:?}"),
+                })
+            })?
+        };
+        Ok(self.participant_lock.mutate(|p| {
             if let Some(identifier) = p {
                 if let Some(participant) = registry.get_mut(identifier) {
                     participant.reduce_priority();
                 }
             }
-            mem::replace(p, self.queue.pop_front())
-        })
+            mem::replace(p, next)
+        }))
     }
 }
 
@@ -267,115 +334,179 @@ where
 
     /// Updates the MPC state and challenge using client's contribution. If the contribution is
     /// valid, the participant will be removed from the waiting queue, and cannot participate in
-    /// this ceremony again. Then it saves State, Challenge, Proof and round number into data files.
+    /// this ceremony again. Then it saves State, Challenge, Proof and round number into `storage`.
+    /// The round is only advanced once the save has succeeded, so that a failed save leaves `self`
+    /// at the last saved round.
     ///
     /// # Registration
     ///
     /// This method requires that `participant` is already registered. This method assumes the state
     /// was deserialized without checks and performs these checks here.
     #[inline]
-    pub fn update(
+    pub fn update<S>(
         &mut self,
         state: BoxArray<State<C>, CIRCUIT_COUNT>,
         proof: BoxArray<Proof<C>, CIRCUIT_COUNT>,
-        recovery_directory: PathBuf,
+        storage: &S,
     ) -> Result<(u64, BoxArray<C::Challenge, CIRCUIT_COUNT>), CeremonyError<C>>
     where
         C::Challenge: Clone + Serialize,
+        S: Storage,
     {
+        let mut next_state = Vec::with_capacity(CIRCUIT_COUNT);
+        let mut next_challenge = Vec::with_capacity(CIRCUIT_COUNT);
         for (i, (state, proof)) in state.into_iter().zip(proof.clone().into_iter()).enumerate() {
             C::check_state(&state).map_err(|_| CeremonyError::BadRequest)?;
-            let next_challenge = C::challenge(&self.challenge[i], &self.state[i], &state, &proof);
-            self.state[i] = verify_transform(&self.challenge[i], &self.state[i], state, proof)
-                .map_err(|_| CeremonyError::BadRequest)?
-                .1;
-            self.challenge[i] = next_challenge;
+            next_challenge.push(C::challenge(
+                &self.challenge[i],
+                &self.state[i],
+                &state,
+                &proof,
+            ));
+            next_state.push(
+                verify_transform(&self.challenge[i], &self.state[i], state, proof)
+                    .map_err(|_| CeremonyError::BadRequest)?
+                    .1,
+            );
         }
+        let next_state = BoxArray::try_from_vec(next_state)
+            .map_err(|_| CeremonyError::Unexpected(UnexpectedError::IncorrectStateSize))?;
+        let next_challenge = BoxArray::try_from_vec(next_challenge)
+            .map_err(|_| CeremonyError::Unexpected(UnexpectedError::IncorrectStateSize))?;
+        let round = self.round + 1;
+        Self::save(
+            storage,
+            round,
+            next_state.as_slice(),
+            next_challenge.as_slice(),
+            Some(&proof),
+        )?;
+        self.state = next_state;
+        self.challenge = next_challenge;
         self.latest_proof = Some(proof);
         self.increment_round();
-        let round = self.round;
-        self.save(recovery_directory, round);
         Ok((round, self.challenge.clone()))
     }
 
-    /// Saves State, Challenge and Proof
+    /// Saves `state`, `challenge` and `proof` of `round` into `storage`, retrying every write up to
+    /// [`SAVE_ATTEMPTS`] times.
     #[inline]
-    fn save(&self, recovery_directory: PathBuf, round: u64)
+    fn save<S>(
+        storage: &S,
+        round: u64,
+        state: &[State<C>],
+        challenge: &[C::Challenge],
+        proof: Option<&BoxArray<Proof<C>, CIRCUIT_COUNT>>,
+    ) -> Result<(), CeremonyError<C>>
     where
         C::Challenge: Serialize,
+        S: Storage,
     {
-        assert_eq!(round, self.round());
-        let names: Vec<String> = deserialize_from_file(recovery_directory.join(r"circuit_names"))
-            .expect("Cannot open circuit name file.");
-
-        for ((state, challenge), name) in self
-            .state()
-            .iter()
-            .zip(self.challenge().iter())
-            .zip(names.iter())
-        {
-            serialize_into_file(
-                OpenOptions::new().write(true).truncate(true).create(true),
-                &filename_format(
-                    &recovery_directory,
-                    name.clone(),
-                    "state".to_string(),
-                    round,
-                ),
+        let names: Vec<String> = deserialize_from_storage(storage, "circuit_names")
+            .map_err(|err| storage_error(&err))?;
+        for ((state, challenge), name) in state.iter().zip(challenge.iter()).zip(names.iter()) {
+            save_with_retry(
+                storage,
+                &key_format(name.clone(), "state".to_string(), round),
                 state,
-            )
-            .expect("Writing state to disk should succeed.");
-
-            serialize_into_file(
-                OpenOptions::new().write(true).truncate(true).create(true),
-                &filename_format(
-                    &recovery_directory,
-                    name.clone(),
-                    "challenge".to_string(),
-                    round,
-                ),
-                &challenge,
-            )
-            .expect("Writing challenge to disk should succeed.");
+            )?;
+            save_with_retry(
+                storage,
+                &key_format(name.clone(), "challenge".to_string(), round),
+                challenge,
+            )?;
         }
-
-        if round > 0 {
-            for (proof, name) in self
-                .latest_proof()
-                .as_ref()
-                .unwrap()
-                .iter()
-                .zip(names.iter())
-            {
-                serialize_into_file(
-                    OpenOptions::new().write(true).truncate(true).create(true),
-                    &filename_format(
-                        &recovery_directory,
-                        name.clone(),
-                        "proof".to_string(),
-                        round,
-                    ),
+        if let Some(proof) = proof {
+            for (proof, name) in proof.iter().zip(names.iter()) {
+                save_with_retry(
+                    storage,
+                    &key_format(name.clone(), "proof".to_string(), round),
                     proof,
-                )
-                .expect("Writing proof to disk should succeed.");
+                )?;
             }
         }
+        save_with_retry(storage, "round_number", &round)
+    }
+}
 
-        serialize_into_file(
-            OpenOptions::new().write(true).truncate(true).create(true),
-            &recovery_directory.join(r"round_number"),
-            &round,
-        )
-        .expect("Must serialize round number to file");
+/// Number of Attempts for each Write of [`StateChallengeProof::update`] to the Storage
+pub const SAVE_ATTEMPTS: u32 = 3;
+
+/// Delay before the First Retry of a Failed Write to the Storage
+///
+/// The delay is doubled after every failed attempt.
+pub const SAVE_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Converts a storage `err` into a [`CeremonyError`].
+#[inline]
+fn storage_error<C, E>(err: &storage::Error<E>) -> CeremonyError<C>
+where
+    C: Ceremony,
+    E: Debug,
+{
+    CeremonyError::Unexpected(UnexpectedError::Storage {
+        message: format!("{err:?}"),
+    })
+}
+
+/// Serializes `data` into the artifact stored under `key` in `storage`, retrying up to
+/// [`SAVE_ATTEMPTS`] times so that transient network errors of remote storages do not abort the
+/// round.
+#[inline]
+fn save_with_retry<C, T, S>(storage: &S, key: &str, data: &T) -> Result<(), CeremonyError<C>>
+where
+    C: Ceremony,
+    T: Serialize,
+    S: Storage,
+{
+    let mut delay = SAVE_RETRY_DELAY;
+    let mut attempt = 1;
+    loop {
+        match serialize_into_storage(storage, key, data) {
+            Ok(()) => return Ok(()),
+            Err(err) if attempt < SAVE_ATTEMPTS => {
+                report(
+                    Level::Warn,
+                    format_args!(
+                        "[ERROR] Unable to save `{key}` (attempt {attempt}/{SAVE_ATTEMPTS}): {err:?}"
+                    ),
+                );
+                thread::sleep(delay);
+                delay *= 2;
+                attempt += 1;
+            }
+            Err(err) => return Err(storage_error(&err)),
+        }
     }
 }
 
-/// Preprocesses a request by checking the nonce and verifying the signature.
+/// Preprocesses a request by checking the nonce, the cohort activation window and verifying the
+/// signature.
 #[inline]
 pub fn preprocess_request<C, R, T>(
     registry: &mut R,
     request: &SignedMessage<C, C::Identifier, T>,
 ) -> Result<C::Priority, CeremonyError<C>>
+where
+    T: Serialize,
+    C: Ceremony,
+    R: Registry<C::Identifier, C::Participant>,
+{
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    preprocess_request_at(registry, request, now)
+}
+
+/// Preprocesses a request at time `now`, given in seconds since the UNIX epoch, by checking the
+/// nonce, the cohort activation window and verifying the signature.
+#[inline]
+pub fn preprocess_request_at<C, R, T>(
+    registry: &mut R,
+    request: &SignedMessage<C, C::Identifier, T>,
+    now: u64,
+) -> Result<C::Priority, CeremonyError<C>>
 where
     T: Serialize,
     C: Ceremony,
@@ -387,6 +518,11 @@ where
     if participant.has_contributed() {
         return Err(CeremonyError::AlreadyContributed);
     }
+    if !participant.is_active(now) {
+        return Err(CeremonyError::NotActive {
+            window: participant.activation_window(),
+        });
+    }
     let participant_nonce = participant.nonce();
     if !participant_nonce.is_valid() {
         return Err(CeremonyError::Unexpected(UnexpectedError::AllNoncesUsed));
@@ -481,7 +617,7 @@ where
     let round_number = 0u64;
     let mut names = Vec::new();
     for (circuit, name) in C::circuits().into_iter() {
-        println!("Creating proving key for {name}");
+        report(Level::Info, format_args!("Creating proving key for {name}"));
         names.push(name.clone());
         let (challenge, state): (<C as ChallengeType>::Challenge, State<C>) =
             initialize(&powers, circuit);
@@ -536,20 +672,115 @@ where
 
 /// Saves registry
 #[inline]
-pub fn save_registry<R, C>(registry: &R, recovery_directory: &Path, round: u64)
+pub fn save_registry<R, C, S>(registry: &R, storage: &S, round: u64)
 where
     R: Registry<C::Identifier, C::Participant> + Serialize,
     C: Ceremony,
+    S: Storage,
 {
-    serialize_into_file(
-        OpenOptions::new().write(true).create(true),
-        &filename_format(
-            recovery_directory,
-            "".to_string(),
-            "registry".to_string(),
-            round,
-        ),
+    serialize_into_storage(
+        storage,
+        &key_format("".to_string(), "registry".to_string(), round),
         registry,
     )
-    .expect("Writing registry to disk should succeed.")
+    .expect("Writing registry to storage should succeed.")
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        ceremony::storage::FileSystem,
+        groth16::{ceremony::config::ppot::Config, mpc::contribute, test::dummy_prover_key},
+        util::BlakeHasher,
+    };
+    use core::cell::Cell;
+    use manta_crypto::rand::OsRng;
+    use std::io;
+
+    /// Storage which fails the first `failures` writes
+    struct FlakyStorage {
+        /// Underlying Storage
+        storage: FileSystem,
+
+        /// Number of Writes which still Fail
+        failures: Cell<usize>,
+    }
+
+    impl Storage for FlakyStorage {
+        type Error = io::Error;
+
+        #[inline]
+        fn read(&self, key: &str) -> Result<Vec<u8>, Self::Error> {
+            self.storage.read(key)
+        }
+
+        #[inline]
+        fn write(&self, key: &str, data: &[u8]) -> Result<(), Self::Error> {
+            match self.failures.get() {
+                0 => self.storage.write(key, data),
+                failures => {
+                    self.failures.set(failures - 1);
+                    Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "Simulated network error.",
+                    ))
+                }
+            }
+        }
+
+        #[inline]
+        fn contains(&self, key: &str) -> Result<bool, Self::Error> {
+            self.storage.contains(key)
+        }
+    }
+
+    /// Checks that transient storage failures are retried, and that a save which keeps failing returns
+    /// an error without advancing the round, so that the contribution can be submitted again.
+    #[test]
+    fn failed_saves_do_not_advance_the_round() {
+        let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let storage = FlakyStorage {
+            storage: FileSystem::new(directory.path()),
+            failures: Cell::new(0),
+        };
+        serialize_into_storage(&storage, "circuit_names", &vec!["dummy".to_string()])
+            .expect("Writing to the storage should succeed.");
+        let state = State::<Config>(dummy_prover_key());
+        let challenge = Array::from_unchecked([0; 64]);
+        let mut sclp = StateChallengeProof::<Config, 1>::new(
+            BoxArray::from_unchecked([state.clone()]),
+            BoxArray::from_unchecked([challenge]),
+        );
+        let mut next = state;
+        let proof = contribute(&BlakeHasher::default(), &challenge, &mut next, &mut OsRng)
+            .expect("The contribution should succeed.");
+        storage.failures.set(SAVE_ATTEMPTS as usize);
+        assert!(matches!(
+            sclp.update(
+                BoxArray::from_unchecked([next.clone()]),
+                BoxArray::from_unchecked([proof.clone()]),
+                &storage,
+            ),
+            Err(CeremonyError::Unexpected(UnexpectedError::Storage { .. }))
+        ));
+        assert_eq!(sclp.round(), 0);
+        assert_eq!(sclp.challenge()[0], challenge);
+        storage.failures.set(1);
+        let (round, _) = sclp
+            .update(
+                BoxArray::from_unchecked([next]),
+                BoxArray::from_unchecked([proof]),
+                &storage,
+            )
+            .expect("A transient storage failure should be retried.");
+        assert_eq!(round, 1);
+        assert_eq!(sclp.round(), 1);
+        assert_eq!(
+            deserialize_from_storage::<u64, _>(&storage, "round_number")
+                .expect("Reading from the storage should succeed."),
+            1
+        );
+    }
 }
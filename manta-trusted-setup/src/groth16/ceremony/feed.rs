@@ -0,0 +1,245 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Challenge Publication Feed
+//!
+//! Right after a contribution is verified, the server appends a [`FeedEntry`] with the round
+//! number, the hash of the contributor and the hash of every new challenge to an append-only file
+//! of JSON lines. Every entry is signed with the key of the server, so that mirrors of the feed
+//! can be checked with [`verify_entry`] and the ceremony can be monitored in real time without
+//! trusting the mirror.
+
+use crate::{
+    ceremony::{
+        signature::{SignedMessage, Signer, VerificationError},
+        transcript::digest,
+    },
+    groth16::ceremony::Ceremony,
+};
+use manta_util::serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+};
+
+/// Feed Entry
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(crate = "manta_util::serde", deny_unknown_fields)]
+pub struct FeedEntry {
+    /// Round Number
+    pub round: u64,
+
+    /// Hex-Encoded BLAKE2b Digest of the Contributor Identifier
+    pub contributor_hash: String,
+
+    /// Hex-Encoded BLAKE2b Digest of the Challenge of each Circuit after this Round
+    pub challenge_hash: Vec<String>,
+}
+
+impl FeedEntry {
+    /// Builds the [`FeedEntry`] for the contribution of `contributor` in `round` which resulted in
+    /// `challenges`. The hashes are taken over the `bincode` encodings of the identifier and of
+    /// the challenges.
+    #[inline]
+    pub fn new<C>(
+        round: u64,
+        contributor: &C::Identifier,
+        challenges: &[C::Challenge],
+    ) -> Result<Self, bincode::Error>
+    where
+        C: Ceremony,
+        C::Challenge: Serialize,
+        C::Identifier: Serialize,
+    {
+        Ok(Self {
+            round,
+            contributor_hash: digest(&bincode::serialize(contributor)?),
+            challenge_hash: challenges
+                .iter()
+                .map(|challenge| Ok(digest(&bincode::serialize(challenge)?)))
+                .collect::<Result<_, bincode::Error>>()?,
+        })
+    }
+}
+
+/// Signed Feed Entry
+///
+/// The identifier of the message is the identifier of the server and the signature is computed
+/// with the default nonce.
+pub type SignedFeedEntry<C> = SignedMessage<C, <C as Ceremony>::Identifier, FeedEntry>;
+
+/// Verifies that `entry` was signed by the server with `verifying_key`.
+#[inline]
+pub fn verify_entry<C>(
+    entry: &SignedFeedEntry<C>,
+    verifying_key: &C::VerifyingKey,
+) -> Result<(), VerificationError<C::Error>>
+where
+    C: Ceremony,
+{
+    entry.verify(Default::default(), verifying_key)
+}
+
+/// Reads the signed entries of the feed stored at `path`.
+#[inline]
+pub fn read_feed<C, P>(path: P) -> io::Result<Vec<SignedFeedEntry<C>>>
+where
+    C: Ceremony,
+    C::Identifier: DeserializeOwned,
+    C::Signature: DeserializeOwned,
+    P: AsRef<Path>,
+{
+    let mut entries = Vec::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            entries.push(serde_json::from_str(&line)?);
+        }
+    }
+    Ok(entries)
+}
+
+/// Challenge Publication Feed
+pub struct Feed<C>
+where
+    C: Ceremony,
+{
+    /// Published Entries
+    entries: Vec<SignedFeedEntry<C>>,
+
+    /// Feed File
+    file: File,
+}
+
+impl<C> Feed<C>
+where
+    C: Ceremony,
+{
+    /// Opens the feed stored at `path`, creating it if it does not exist.
+    #[inline]
+    pub fn open<P>(path: P) -> io::Result<Self>
+    where
+        C::Identifier: DeserializeOwned,
+        C::Signature: DeserializeOwned,
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let file = OpenOptions::new().append(true).create(true).open(path)?;
+        Ok(Self {
+            entries: read_feed(path)?,
+            file,
+        })
+    }
+
+    /// Returns all the entries published so far.
+    #[inline]
+    pub fn entries(&self) -> &[SignedFeedEntry<C>] {
+        &self.entries
+    }
+
+    /// Returns the entries published for `round` and all later rounds.
+    #[inline]
+    pub fn since(&self, round: u64) -> &[SignedFeedEntry<C>] {
+        let start = self
+            .entries
+            .partition_point(|entry| entry.message().round < round);
+        &self.entries[start..]
+    }
+
+    /// Signs `entry` with the server `signer` and appends it to the feed. The entry is synced to
+    /// disk before it is returned.
+    #[inline]
+    pub fn publish(
+        &mut self,
+        entry: FeedEntry,
+        signer: &Signer<C, C::Identifier>,
+    ) -> io::Result<&SignedFeedEntry<C>>
+    where
+        C::Identifier: Clone + Serialize,
+        C::Signature: Serialize,
+    {
+        let signed = signer.sign(entry).map_err(io::Error::other)?;
+        let mut line = serde_json::to_vec(&signed)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        self.file.sync_data()?;
+        self.entries.push(signed);
+        Ok(self.entries.last().expect("An entry was just pushed."))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::groth16::ceremony::config::ppot::{generate_keys, Challenge, Config};
+    use manta_util::Array;
+
+    /// Tests that published entries are signed by the server, survive reopening the feed, and
+    /// that tampered entries are rejected.
+    #[test]
+    fn published_entries_are_signed_and_persisted() {
+        let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let path = directory.path().join("feed.jsonl");
+        let (signing_key, verifying_key) = generate_keys(&[7; 32]).unwrap();
+        let server = Array::from_unchecked(*verifying_key.as_bytes());
+        let contributor = Array::from_unchecked([1; 32]);
+        let signer = Signer::new(Default::default(), signing_key, server);
+        let mut feed = Feed::<Config>::open(&path).unwrap();
+        for round in 1..=3 {
+            let entry = FeedEntry::new::<Config>(
+                round,
+                &contributor,
+                &[Challenge::from_unchecked([round as u8; 64])],
+            )
+            .unwrap();
+            feed.publish(entry, &signer).unwrap();
+        }
+        drop(feed);
+        let feed = Feed::<Config>::open(&path).unwrap();
+        assert_eq!(feed.entries().len(), 3);
+        assert_eq!(
+            feed.since(2)
+                .iter()
+                .map(|entry| entry.message().round)
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(
+            read_feed::<Config, _>(&path)
+                .unwrap()
+                .into_iter()
+                .map(SignedMessage::into_message)
+                .collect::<Vec<_>>(),
+            feed.entries()
+                .iter()
+                .map(|entry| entry.message().clone())
+                .collect::<Vec<_>>()
+        );
+        for entry in feed.entries() {
+            assert!(verify_entry(entry, &server).is_ok());
+        }
+        let entry = &feed.entries()[0];
+        let mut message = entry.message().clone();
+        message.round = 4;
+        let tampered = SignedFeedEntry::<Config>::new_unchecked(
+            *entry.signature(),
+            *entry.identifier(),
+            message,
+        );
+        assert!(verify_entry(&tampered, &server).is_err());
+    }
+}
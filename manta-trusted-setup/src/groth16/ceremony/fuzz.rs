@@ -0,0 +1,126 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Ceremony Message Fuzzing Targets
+//!
+//! The ceremony server decodes the JSON bodies of `query` and `update` requests and checks their
+//! signatures before looking at the participant registry, so these steps run on bytes chosen by
+//! anyone who can reach the server. The targets in this module run the same steps for the
+//! [`ppot`](super::config::ppot) ceremony and are meant to be called from `cargo-fuzz` targets:
+//!
+//! ```text
+//! #![no_main]
+//!
+//! use libfuzzer_sys::fuzz_target;
+//! use manta_trusted_setup::groth16::ceremony::fuzz;
+//!
+//! fuzz_target!(|data: &[u8]| {
+//!     fuzz::contribute_request(data);
+//! });
+//! ```
+//!
+//! The first eight bytes of the input are read as the little-endian nonce which the signature is
+//! checked against, and the rest is the JSON request body.
+
+use crate::{
+    ceremony::signature::SignedMessage,
+    groth16::ceremony::{
+        config::ppot::Config,
+        message::{ContributeRequest, QueryRequest},
+        Ceremony,
+    },
+};
+use manta_crypto::signature::VerifyingKeyType;
+use manta_util::serde::{de::DeserializeOwned, Serialize};
+
+/// Verifying Key Type
+type VerifyingKey = <Config as VerifyingKeyType>::VerifyingKey;
+
+/// Splits `data` into a nonce and a request body, decodes the body as a [`SignedMessage`] and
+/// checks its signature against its own identifier, returning the message if it is valid.
+#[inline]
+fn verified_message<T>(data: &[u8]) -> Option<T>
+where
+    T: DeserializeOwned + Serialize,
+{
+    if data.len() < 8 {
+        return None;
+    }
+    let (nonce, body) = data.split_at(8);
+    let nonce = u64::from_le_bytes(nonce.try_into().expect("The nonce has eight bytes."));
+    let request = serde_json::from_slice::<SignedMessage<Config, VerifyingKey, T>>(body).ok()?;
+    request.verify(nonce, request.identifier()).ok()?;
+    Some(request.into_message())
+}
+
+/// Decodes and verifies a `query` request, returning `true` if its signature is valid.
+#[inline]
+pub fn query_request(data: &[u8]) -> bool {
+    verified_message::<QueryRequest>(data).is_some()
+}
+
+/// Decodes and verifies an `update` request and checks the states it carries, returning `true` if
+/// its signature and all of its states are valid.
+#[inline]
+pub fn contribute_request(data: &[u8]) -> bool {
+    match verified_message::<ContributeRequest<Config>>(data) {
+        Some(request) => request
+            .state
+            .iter()
+            .all(|state| Config::check_state(state).is_ok()),
+        _ => false,
+    }
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::groth16::ceremony::config::ppot::generate_keys;
+    use manta_crypto::rand::{OsRng, Rand, RngCore};
+    use manta_util::Array;
+
+    /// Checks that a correctly signed query request is accepted, and that mutations of it and
+    /// random inputs are rejected without panicking.
+    #[test]
+    fn query_request_target() {
+        let mut rng = OsRng;
+        let (signing_key, verifying_key) =
+            generate_keys(&rng.gen::<_, [u8; 32]>()).expect("The seed is long enough.");
+        let nonce = rng.next_u64();
+        let request = SignedMessage::<Config, VerifyingKey, _>::generate(
+            &signing_key,
+            nonce,
+            Array::from_unchecked(verifying_key.to_bytes()),
+            QueryRequest,
+        )
+        .expect("Signing is not allowed to fail.");
+        let mut data = nonce.to_le_bytes().to_vec();
+        data.extend(serde_json::to_vec(&request).expect("Serializing is not allowed to fail."));
+        assert!(query_request(&data));
+        assert!(!contribute_request(&data));
+        for _ in 0..256 {
+            let mut mutated = data.clone();
+            let index = rng.next_u32() as usize % mutated.len();
+            mutated[index] ^= 1 << (rng.next_u32() % 8);
+            let _ = query_request(&mutated);
+            let mut random = vec![0; rng.next_u32() as usize % 256];
+            rng.fill_bytes(&mut random);
+            assert!(!query_request(&random));
+            assert!(!contribute_request(&random));
+        }
+    }
+}
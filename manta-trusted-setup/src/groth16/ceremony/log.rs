@@ -41,6 +41,7 @@ pub enum Level {
 
 impl Level {
     /// Returns the loggging prefix for `self` as a static string.
+    #[cfg_attr(feature = "telemetry", allow(dead_code))]
     #[inline]
     const fn as_prefix(&self) -> &'static str {
         match self {
@@ -53,23 +54,86 @@ impl Level {
 }
 
 /// Prints the `display` as a log line to the `writer` with the given logging `level`.
+///
+/// With the `telemetry` feature, the line is emitted as a [`tracing`] event in the current span
+/// instead, and the `writer` is left untouched.
 #[inline]
 pub async fn log<W, D>(writer: &mut W, level: Level, display: D) -> io::Result<()>
 where
     W: AsyncWrite + Unpin,
     D: fmt::Display,
 {
-    writer
-        .write_all(
-            format!(
-                "{} {}: {}\n",
-                level.as_prefix(),
-                chrono::offset::Utc::now(),
-                display
+    #[cfg(feature = "telemetry")]
+    {
+        let _ = writer;
+        event(level, display);
+        Ok(())
+    }
+    #[cfg(not(feature = "telemetry"))]
+    {
+        writer
+            .write_all(
+                format!(
+                    "{} {}: {}\n",
+                    level.as_prefix(),
+                    chrono::offset::Utc::now(),
+                    display
+                )
+                .as_bytes(),
             )
-            .as_bytes(),
+            .await
+    }
+}
+
+/// Reports `display` with the given logging `level` from synchronous code.
+///
+/// With the `telemetry` feature, this emits a [`tracing`] event in the current span, and prints
+/// a line to standard output otherwise.
+#[inline]
+pub fn report<D>(level: Level, display: D)
+where
+    D: fmt::Display,
+{
+    #[cfg(feature = "telemetry")]
+    event(level, display);
+    #[cfg(not(feature = "telemetry"))]
+    {
+        let _ = level;
+        println!("{display}");
+    }
+}
+
+/// Emits `message` as a [`tracing`] event with the given logging `level`.
+#[cfg(feature = "telemetry")]
+#[inline]
+fn event<D>(level: Level, message: D)
+where
+    D: fmt::Display,
+{
+    match level {
+        Level::Trace => tracing::trace!("{message}"),
+        Level::Info => tracing::info!("{message}"),
+        Level::Warn => tracing::warn!("{message}"),
+        Level::Error => tracing::error!("{message}"),
+    }
+}
+
+/// Installs a global [`tracing`] subscriber which formats events to standard output, filtered by
+/// the `RUST_LOG` environment variable and defaulting to the `info` level.
+///
+/// # Panics
+///
+/// This function panics if a global subscriber was already installed.
+#[cfg(feature = "telemetry")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "telemetry")))]
+#[inline]
+pub fn init_telemetry() {
+    use tracing_subscriber::{fmt, EnvFilter};
+    fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
         )
-        .await
+        .init();
 }
 
 /// Logs a single log line to the default writer of the given `$level`.
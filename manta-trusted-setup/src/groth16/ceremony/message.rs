@@ -17,7 +17,7 @@
 //! Groth16 Trusted Setup Ceremony Messaging Protocol
 
 use crate::groth16::{
-    ceremony::{Ceremony, Round},
+    ceremony::{receipt::SignedReceipt, Ceremony, Round},
     mpc::{Proof, State},
 };
 use alloc::vec::Vec;
@@ -88,8 +88,16 @@ where
     derive(Deserialize, Serialize),
     serde(
         bound(
-            deserialize = "C::Challenge: Deserialize<'de>",
-            serialize = "C::Challenge: Serialize",
+            deserialize = r"
+                C::Challenge: Deserialize<'de>,
+                C::Identifier: Deserialize<'de>,
+                C::Signature: Deserialize<'de>,
+            ",
+            serialize = r"
+                C::Challenge: Serialize,
+                C::Identifier: Serialize,
+                C::Signature: Serialize,
+            ",
         ),
         crate = "manta_util::serde",
         deny_unknown_fields
@@ -104,4 +112,77 @@ where
 
     /// Current Challenge
     pub challenge: Vec<C::Challenge>,
+
+    /// Contribution Receipt
+    ///
+    /// This is `None` if the server does not sign receipts.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub receipt: Option<SignedReceipt<C>>,
+}
+
+/// Round Request
+///
+/// Requests the public transcript data of a past round from the server. This request does not need
+/// to be signed since the transcript is public.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RoundRequest {
+    /// Round Number
+    pub round: u64,
+}
+
+/// Feed Request
+///
+/// Requests the signed challenge publication feed from the server, starting at round `since`. This
+/// request does not need to be signed since the feed is public.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FeedRequest {
+    /// First Round of the Requested Entries
+    pub since: u64,
+}
+
+/// Response for [`RoundRequest`]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(
+        bound(
+            deserialize = "C::Challenge: Deserialize<'de>, C::Identifier: Deserialize<'de>",
+            serialize = "C::Challenge: Serialize, C::Identifier: Serialize",
+        ),
+        crate = "manta_util::serde",
+        deny_unknown_fields
+    )
+)]
+pub struct RoundResponse<C>
+where
+    C: Ceremony,
+{
+    /// Round Number
+    pub round: u64,
+
+    /// Contributor of this Round
+    ///
+    /// This is `None` for the initial round or if the contributor was not recorded.
+    pub contributor: Option<C::Identifier>,
+
+    /// Hash of the State of each Circuit after this Round
+    pub state_hash: Vec<Vec<u8>>,
+
+    /// Challenge of each Circuit after this Round
+    pub challenge: Vec<C::Challenge>,
+
+    /// Proof of each Circuit for this Round
+    ///
+    /// This is empty for the initial round.
+    pub proof: Vec<Proof<C>>,
 }
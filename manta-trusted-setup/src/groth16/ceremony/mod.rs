@@ -18,7 +18,7 @@
 
 use crate::{
     ceremony::{
-        participant::{Participant, Priority},
+        participant::{ActivationWindow, Cohort, Participant, Priority},
         signature::SignatureScheme,
     },
     groth16::{
@@ -40,6 +40,7 @@ use manta_util::{
 pub mod config;
 pub mod log;
 pub mod message;
+pub mod receipt;
 
 #[cfg(feature = "client")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "client")))]
@@ -49,10 +50,22 @@ pub mod client;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "coordinator")))]
 pub mod coordinator;
 
+#[cfg(feature = "coordinator")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "coordinator")))]
+pub mod feed;
+
+#[cfg(feature = "fuzz")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fuzz")))]
+pub mod fuzz;
+
 #[cfg(feature = "coordinator")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "coordinator")))]
 pub mod server;
 
+#[cfg(all(feature = "coordinator", feature = "test"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "coordinator", feature = "test"))))]
+pub mod test;
+
 /// Participant Queue Type
 pub type Queue<C, const LEVEL_COUNT: usize> =
     MultiVecDeque<<C as Ceremony>::Identifier, LEVEL_COUNT>;
@@ -70,7 +83,8 @@ pub trait Ceremony: Configuration + SignatureScheme {
             Identifier = Self::Identifier,
             VerifyingKey = Self::VerifyingKey,
             Nonce = Self::Nonce,
-        > + Priority<Priority = Self::Priority>;
+        > + Priority<Priority = Self::Priority>
+        + Cohort;
 
     /// State deserialization error type
     type SerializationError;
@@ -174,6 +188,21 @@ where
     /// Timed out
     Timeout,
 
+    /// Cohort Not Active
+    NotActive {
+        /// Activation Window of the Participant's Cohort
+        window: ActivationWindow,
+    },
+
+    /// Unknown Round
+    UnknownRound {
+        /// Latest Round
+        ///
+        /// We return the latest round so that clients following the ceremony know which rounds
+        /// are available.
+        latest: u64,
+    },
+
     /// Network Error
     Network {
         /// Optional Error Message Display String
@@ -208,6 +237,15 @@ where
                 f,
                 "Unable to connect to the ceremony server: timeout. Please try again later.",
             ),
+            Self::NotActive { window } => write!(
+                f,
+                "Your registration cohort is not active right now. \
+                 It can contribute {window} (seconds since the UNIX epoch).",
+            ),
+            Self::UnknownRound { latest } => write!(
+                f,
+                "The requested round is not available yet. The latest round is {latest}.",
+            ),
             Self::Network { message } => {
                 write!(f, "Unable to connect to the ceremony server: {message}")
             }
@@ -230,6 +268,17 @@ where
 {
 }
 
+impl<C> From<CeremonyError<C>> for manta_util::error::Error
+where
+    C: Ceremony,
+    C::Nonce: Debug,
+{
+    #[inline]
+    fn from(err: CeremonyError<C>) -> Self {
+        Self::new(err)
+    }
+}
+
 /// Unexpected Error
 #[cfg_attr(
     feature = "serde",
@@ -258,4 +307,23 @@ pub enum UnexpectedError {
 
     /// Task Error
     TaskError,
+
+    /// Extracted Keys Failed the Proof and Verification Round-Trip
+    KeyCheckFailed,
+
+    /// The Server has no Signing Key
+    MissingServerKey,
+
+    /// Storage Error
+    Storage {
+        /// Error Message Display String
+        message: String,
+    },
+}
+
+impl From<UnexpectedError> for manta_util::error::Error {
+    #[inline]
+    fn from(err: UnexpectedError) -> Self {
+        Self::new(format_args!("Unexpected ceremony error: {err:?}"))
+    }
 }
@@ -0,0 +1,190 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Contribution Receipts
+//!
+//! After a successful contribution the server hands the participant a [`Receipt`] signed with the
+//! key of the server. Participants can store it and later prove that they contributed to a given
+//! round by presenting it, since anyone can check it with [`verify_receipt`] and compare it to the
+//! public transcript with [`Receipt::matches_round`].
+
+use crate::{
+    ceremony::signature::{SignedMessage, VerificationError},
+    groth16::{
+        ceremony::{
+            message::{ContributeResponse, RoundResponse},
+            Ceremony,
+        },
+        mpc::{ProvingKeyHasher, State},
+    },
+};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use manta_util::serde::{Deserialize, Serialize};
+
+/// Contribution Receipt
+#[derive(derivative::Derivative, Deserialize, Serialize)]
+#[derivative(
+    Clone(bound = "C::Identifier: Clone"),
+    Debug(bound = "C::Identifier: Debug"),
+    Eq(bound = "C::Identifier: Eq"),
+    PartialEq(bound = "C::Identifier: PartialEq")
+)]
+#[serde(
+    bound(
+        deserialize = "C::Identifier: Deserialize<'de>",
+        serialize = "C::Identifier: Serialize",
+    ),
+    crate = "manta_util::serde",
+    deny_unknown_fields
+)]
+pub struct Receipt<C>
+where
+    C: Ceremony,
+{
+    /// Round Number of the Contribution
+    pub round: u64,
+
+    /// Contributor
+    pub contributor: C::Identifier,
+
+    /// Hash of the State of each Circuit after the Contribution
+    pub state_hash: Vec<Vec<u8>>,
+
+    /// Contribution Hash
+    ///
+    /// This is the hash of the [`ContributeResponse`] which participants share publicly after
+    /// contributing.
+    pub contribution_hash: Vec<u8>,
+
+    /// Time of the Contribution in Seconds since the UNIX Epoch
+    pub timestamp: u64,
+}
+
+impl<C> Receipt<C>
+where
+    C: Ceremony,
+{
+    /// Builds the [`Receipt`] for the contribution of `contributor` answered with `response`
+    /// which resulted in `state` at `timestamp`.
+    #[inline]
+    pub fn new(
+        contributor: C::Identifier,
+        response: &ContributeResponse<C>,
+        state: &[State<C>],
+        timestamp: u64,
+    ) -> Self
+    where
+        C: ProvingKeyHasher<C>,
+        C::ContributionHash: AsRef<[u8]>,
+        C::Output: AsRef<[u8]>,
+    {
+        Self {
+            round: response.index,
+            contributor,
+            state_hash: state
+                .iter()
+                .map(|state| C::hash(&state.0).as_ref().to_vec())
+                .collect(),
+            contribution_hash: C::contribution_hash(response).as_ref().to_vec(),
+            timestamp,
+        }
+    }
+
+    /// Returns `true` if `self` describes the contribution recorded in the public transcript
+    /// data of `round`.
+    #[inline]
+    pub fn matches_round(&self, round: &RoundResponse<C>) -> bool
+    where
+        C::Identifier: PartialEq,
+    {
+        self.round == round.round
+            && round.contributor.as_ref() == Some(&self.contributor)
+            && self.state_hash == round.state_hash
+    }
+}
+
+/// Signed Contribution Receipt
+///
+/// The identifier of the message is the identifier of the server and the signature is computed
+/// with the default nonce.
+pub type SignedReceipt<C> = SignedMessage<C, <C as Ceremony>::Identifier, Receipt<C>>;
+
+/// Verifies that `receipt` was signed by the server with `verifying_key`.
+#[inline]
+pub fn verify_receipt<C>(
+    receipt: &SignedReceipt<C>,
+    verifying_key: &C::VerifyingKey,
+) -> Result<(), VerificationError<C::Error>>
+where
+    C: Ceremony,
+    C::Identifier: Serialize,
+{
+    receipt.verify(Default::default(), verifying_key)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        ceremony::signature::Signer,
+        groth16::ceremony::config::ppot::{generate_keys, Challenge, Config},
+    };
+    use manta_util::Array;
+
+    /// Tests that receipts signed by the server survive the contribution response round-trip, are
+    /// verified against the server key, and match the public transcript data of their round.
+    #[test]
+    fn receipts_are_verified_against_the_transcript() {
+        let (signing_key, verifying_key) = generate_keys(&[3; 32]).unwrap();
+        let server = Array::from_unchecked(*verifying_key.as_bytes());
+        let signer = Signer::<Config, _>::new(Default::default(), signing_key, server);
+        let contributor = Array::from_unchecked([1; 32]);
+        let mut response = ContributeResponse::<Config> {
+            index: 5,
+            challenge: vec![Challenge::from_unchecked([5; 64])],
+            receipt: None,
+        };
+        response.receipt = Some(
+            signer
+                .sign(Receipt::new(contributor, &response, &[], 1_700_000_000))
+                .unwrap(),
+        );
+        let response: ContributeResponse<Config> =
+            serde_json::from_str(&serde_json::to_string(&response).unwrap()).unwrap();
+        let receipt = response.receipt.as_ref().unwrap();
+        assert!(verify_receipt(receipt, &server).is_ok());
+        assert_eq!(
+            receipt.message().contribution_hash,
+            Config::contribution_hash(&response).to_vec()
+        );
+        let mut round = RoundResponse::<Config> {
+            round: 5,
+            contributor: Some(contributor),
+            state_hash: Vec::new(),
+            challenge: response.challenge.clone(),
+            proof: Vec::new(),
+        };
+        assert!(receipt.message().matches_round(&round));
+        round.contributor = Some(Array::from_unchecked([2; 32]));
+        assert!(!receipt.message().matches_round(&round));
+        let mut message = receipt.message().clone();
+        message.timestamp += 1;
+        let forged = SignedReceipt::<Config>::new_unchecked(*receipt.signature(), server, message);
+        assert!(verify_receipt(&forged, &server).is_err());
+        assert!(verify_receipt(receipt, &contributor).is_err());
+    }
+}
@@ -19,22 +19,28 @@
 use crate::{
     ceremony::{
         participant::Participant,
+        queue::PersistentQueue,
         registry::{
             self,
             csv::{load_append_entries, Record},
             Registry,
         },
-        signature::SignedMessage,
-        util::deserialize_from_file,
+        signature::{SignedMessage, Signer},
+        storage::{deserialize_from_storage, serialize_into_storage, FileSystem, Storage},
     },
     groth16::{
         ceremony::{
             coordinator::{preprocess_request, save_registry, LockQueue, StateChallengeProof},
-            log::{info, warn},
-            message::{ContributeRequest, ContributeResponse, QueryRequest, QueryResponse},
+            feed::{Feed, FeedEntry, SignedFeedEntry},
+            log::{info, report, warn, Level},
+            message::{
+                ContributeRequest, ContributeResponse, FeedRequest, QueryRequest, QueryResponse,
+                RoundRequest, RoundResponse,
+            },
+            receipt::Receipt,
             Ceremony, CeremonyError, CeremonySize, Metadata, UnexpectedError,
         },
-        mpc::{Proof, State, StateSize},
+        mpc::{Proof, ProvingKeyHasher, State, StateSize},
     },
 };
 use alloc::sync::Arc;
@@ -44,23 +50,72 @@ use core::{
 };
 use manta_util::{
     into_array_unchecked,
-    serde::{de::DeserializeOwned, Serialize},
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
     BoxArray,
 };
 use parking_lot::Mutex;
 use std::{
     io::Error,
     path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
 };
-use tokio::task;
+use tokio::{task, time::Instant};
+
+/// Polling Interval of the Participant Lock during a [`Server::shutdown`]
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Resume Token
+///
+/// Machine-readable summary of a [`Server::shutdown`] which describes the artifacts the next
+/// [`Server::recover`] resumes the ceremony from.
+#[derive(derivative::Derivative, Deserialize, Serialize)]
+#[derivative(
+    Clone(bound = "C::Identifier: Clone"),
+    Debug(bound = "C::Identifier: Debug"),
+    Eq(bound = "C::Identifier: Eq"),
+    PartialEq(bound = "C::Identifier: PartialEq")
+)]
+#[serde(
+    bound(
+        deserialize = "C::Identifier: Deserialize<'de>",
+        serialize = "C::Identifier: Serialize",
+    ),
+    crate = "manta_util::serde",
+    deny_unknown_fields
+)]
+pub struct ResumeToken<C>
+where
+    C: Ceremony,
+{
+    /// Round Number the Ceremony Resumes from
+    pub round: u64,
+
+    /// Storage Key of the Registry Saved for `round`
+    pub registry: String,
+
+    /// Number of Participants Waiting in the Queue
+    pub queue_length: usize,
+
+    /// Path to the Write-Ahead Log of the Queue
+    ///
+    /// This is `None` if the queue was only kept in memory, in which case the participants in
+    /// the queue have to query the server again after it resumes.
+    pub queue_log: Option<PathBuf>,
+
+    /// Participant Holding the Lock when the Shutdown Timed Out
+    ///
+    /// This is `None` if the lock was released before the shutdown timeout.
+    pub interrupted: Option<C::Identifier>,
+}
 
 /// Server
 #[derive(derivative::Derivative)]
-#[derivative(Clone(bound = ""))]
-pub struct Server<C, R, const LEVEL_COUNT: usize, const CIRCUIT_COUNT: usize>
+#[derivative(Clone(bound = "S: Clone"))]
+pub struct Server<C, R, const LEVEL_COUNT: usize, const CIRCUIT_COUNT: usize, S = FileSystem>
 where
     C: Ceremony,
     R: registry::Configuration<Identifier = C::Identifier, Participant = C::Participant>,
+    S: Storage,
 {
     /// Lock and Queue
     lock_queue: Arc<Mutex<LockQueue<C, LEVEL_COUNT>>>,
@@ -74,27 +129,35 @@ where
     /// Ceremony Metadata
     metadata: Metadata,
 
-    /// Recovery Directory Path
-    recovery_directory: PathBuf,
+    /// Recovery Storage
+    storage: S,
 
     /// Registry Path
     registry_path: PathBuf,
+
+    /// Server Signer
+    #[allow(clippy::type_complexity)] // NOTE: This is a private field so it doesn't matter.
+    signer: Option<Arc<Mutex<Signer<C, C::Identifier>>>>,
+
+    /// Challenge Publication Feed
+    feed: Option<Arc<Mutex<Feed<C>>>>,
 }
 
-impl<C, R, const LEVEL_COUNT: usize, const CIRCUIT_COUNT: usize>
-    Server<C, R, LEVEL_COUNT, CIRCUIT_COUNT>
+impl<C, R, const LEVEL_COUNT: usize, const CIRCUIT_COUNT: usize, S>
+    Server<C, R, LEVEL_COUNT, CIRCUIT_COUNT, S>
 where
     C: Ceremony,
     R: registry::Configuration<Identifier = C::Identifier, Participant = C::Participant>,
+    S: Storage,
 {
     /// Builds a ['Server`] with initial `state`, `challenge`, a loaded `registry`, and a
-    /// `recovery_directory`.
+    /// recovery `storage`.
     #[inline]
     pub fn new(
         state: BoxArray<State<C>, CIRCUIT_COUNT>,
         challenge: BoxArray<C::Challenge, CIRCUIT_COUNT>,
         registry: R::Registry,
-        recovery_directory: PathBuf,
+        storage: S,
         metadata: Metadata,
         registry_path: PathBuf,
     ) -> Self {
@@ -107,16 +170,18 @@ where
             registry: Arc::new(Mutex::new(registry)),
             sclp: Arc::new(Mutex::new(StateChallengeProof::new(state, challenge))),
             metadata,
-            recovery_directory,
+            storage,
             registry_path,
+            signer: None,
+            feed: None,
         }
     }
 
-    /// Recovers from disk files at `path` and uses `path` as the backup directory.
+    /// Recovers from the artifacts in `storage` and uses `storage` as the backup location.
     #[allow(clippy::redundant_async_block)] // Necessary to spawn a task with a server clone
     #[inline]
     pub fn recover(
-        path: PathBuf,
+        storage: S,
         registry_path: PathBuf,
         contribution_time_limit: Duration,
     ) -> Result<Self, CeremonyError<C>>
@@ -124,24 +189,32 @@ where
         C::Challenge: DeserializeOwned + Send,
         C::Identifier: Copy + Debug + Send,
         C::Nonce: Send,
+        C::Signature: Send,
+        C::SigningKey: Send,
         R::Registry: DeserializeOwned + Send,
+        S: Clone + Send + Sync,
         <R::Record as Record<C::Identifier, C::Participant>>::Error: Debug,
         C: 'static,
         R: 'static,
+        S: 'static,
     {
-        let round_number: u64 = deserialize_from_file(path.join(r"round_number")).map_err(|e| {
-            CeremonyError::Unexpected(UnexpectedError::Serialization {
-                message: format!("{e:?}"),
-            })
-        })?;
-        println!("Recovering a ceremony at round {round_number:?}");
+        let round_number: u64 =
+            deserialize_from_storage(&storage, "round_number").map_err(|e| {
+                CeremonyError::Unexpected(UnexpectedError::Serialization {
+                    message: format!("{e:?}"),
+                })
+            })?;
+        report(
+            Level::Info,
+            format_args!("Recovering a ceremony at round {round_number:?}"),
+        );
         let names: Vec<String> =
-            deserialize_from_file(path.join(r"circuit_names")).map_err(|e| {
+            deserialize_from_storage(&storage, "circuit_names").map_err(|e| {
                 CeremonyError::Unexpected(UnexpectedError::Serialization {
                     message: format!("{e:?}"),
                 })
             })?;
-        println!("Circuit names: {names:?}");
+        report(Level::Info, format_args!("Circuit names: {names:?}"));
         if names.len() != CIRCUIT_COUNT {
             return Err(CeremonyError::Unexpected(
                 UnexpectedError::IncorrectStateSize,
@@ -151,24 +224,20 @@ where
         let mut challenges = Vec::<C::Challenge>::new();
         let mut proofs = Vec::<Proof<C>>::new();
         for name in names.into_iter() {
-            let state: State<C> = deserialize_from_file(filename_format(
-                &path,
-                name.clone(),
-                "state".to_string(),
-                round_number,
-            ))
+            let state: State<C> = deserialize_from_storage(
+                &storage,
+                &key_format(name.clone(), "state".to_string(), round_number),
+            )
             .map_err(|e| {
                 CeremonyError::Unexpected(UnexpectedError::Serialization {
                     message: format!("{e:?}"),
                 })
             })?;
             states.push(state);
-            let challenge: C::Challenge = deserialize_from_file(filename_format(
-                &path,
-                name.clone(),
-                "challenge".to_string(),
-                round_number,
-            ))
+            let challenge: C::Challenge = deserialize_from_storage(
+                &storage,
+                &key_format(name.clone(), "challenge".to_string(), round_number),
+            )
             .map_err(|e| {
                 CeremonyError::Unexpected(UnexpectedError::Serialization {
                     message: format!("{e:?}"),
@@ -176,12 +245,10 @@ where
             })?;
             challenges.push(challenge);
             if round_number > 0 {
-                let latest_proof: Proof<C> = deserialize_from_file(filename_format(
-                    &path,
-                    name,
-                    "proof".to_string(),
-                    round_number,
-                ))
+                let latest_proof: Proof<C> = deserialize_from_storage(
+                    &storage,
+                    &key_format(name, "proof".to_string(), round_number),
+                )
                 .map_err(|e| {
                     CeremonyError::Unexpected(UnexpectedError::Serialization {
                         message: format!("{e:?}"),
@@ -194,12 +261,10 @@ where
             0 => None,
             _ => Some(BoxArray::from(into_array_unchecked(proofs))),
         };
-        let registry: R::Registry = deserialize_from_file(filename_format(
-            &path,
-            "".to_string(),
-            "registry".to_string(),
-            round_number,
-        ))
+        let registry: R::Registry = deserialize_from_storage(
+            &storage,
+            &key_format("".to_string(), "registry".to_string(), round_number),
+        )
         .map_err(|e| {
             CeremonyError::Unexpected(UnexpectedError::Serialization {
                 message: format!("{e:?}"),
@@ -216,20 +281,165 @@ where
                 round_number,
             ))),
             metadata,
-            recovery_directory: path,
+            storage,
             registry_path,
+            signer: None,
+            feed: None,
         };
         let server_clone = server.clone();
         task::spawn(async move { server_clone.update_registry().await });
         Ok(server)
     }
 
+    /// Replaces the participant queue of `self` with the persistent queue whose write-ahead log is
+    /// stored at `path`, so that the queue order survives restarts of the server. The participant
+    /// lock is not persisted, so a participant holding the lock before a restart has to queue up
+    /// again.
+    #[inline]
+    pub fn with_queue_log<P>(self, path: P) -> Result<Self, CeremonyError<C>>
+    where
+        P: AsRef<Path>,
+        C::Identifier: DeserializeOwned,
+    {
+        let queue = PersistentQueue::open(path).map_err(|e| {
+            CeremonyError::Unexpected(UnexpectedError::Serialization {
+                message: format!("{e:?}"),
+            })
+        })?;
+        *self.lock_queue.lock() = LockQueue::new(queue);
+        Ok(self)
+    }
+
+    /// Signs contribution receipts and feed entries with `signing_key` on behalf of the server
+    /// with `identifier`. The signatures are computed with the default nonce.
+    #[inline]
+    pub fn with_signing_key(
+        mut self,
+        signing_key: C::SigningKey,
+        identifier: C::Identifier,
+    ) -> Self {
+        self.signer = Some(Arc::new(Mutex::new(Signer::new(
+            Default::default(),
+            signing_key,
+            identifier,
+        ))));
+        self
+    }
+
+    /// Publishes a [`FeedEntry`] signed with the server key to the feed stored at `path` after
+    /// every verified contribution. The entries already stored at `path` are served by the
+    /// [`feed`](Self::feed) endpoint as well.
+    ///
+    /// # Signing Key
+    ///
+    /// The server key has to be set with [`with_signing_key`](Self::with_signing_key) before
+    /// calling this method.
+    #[inline]
+    pub fn with_feed<P>(mut self, path: P) -> Result<Self, CeremonyError<C>>
+    where
+        C::Identifier: DeserializeOwned,
+        C::Signature: DeserializeOwned,
+        P: AsRef<Path>,
+    {
+        if self.signer.is_none() {
+            return Err(CeremonyError::Unexpected(UnexpectedError::MissingServerKey));
+        }
+        let feed = Feed::open(path).map_err(|e| {
+            CeremonyError::Unexpected(UnexpectedError::Serialization {
+                message: format!("{e:?}"),
+            })
+        })?;
+        self.feed = Some(Arc::new(Mutex::new(feed)));
+        Ok(self)
+    }
+
+    /// Shuts down the ceremony without losing any progress. The lock is no longer handed to the
+    /// participants in the queue, the participant holding the lock is given until `timeout` to
+    /// finish their contribution, and then the registry and the queue are flushed to storage. The
+    /// returned [`ResumeToken`] describes the state [`recover`](Self::recover) resumes from.
+    ///
+    /// Requests can still be served while this method is waiting for the lock, so that the
+    /// contribution in progress can be submitted.
+    #[inline]
+    pub async fn shutdown(&self, timeout: Duration) -> Result<ResumeToken<C>, CeremonyError<C>>
+    where
+        C::Identifier: Clone + Serialize,
+        R::Registry: Serialize,
+    {
+        self.lock_queue.lock().drain();
+        let _ = info!("[ACTION] Draining the lock for shutdown.");
+        let deadline = Instant::now() + timeout;
+        loop {
+            if self.lock_queue.lock().has_expired(&self.metadata) {
+                break;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+            tokio::time::sleep(SHUTDOWN_POLL_INTERVAL.min(deadline - now)).await;
+        }
+        let token = self.flush()?;
+        let _ = info!(
+            "[ACTION] Shut down at round {} with {} participants in the queue.",
+            token.round, token.queue_length
+        );
+        Ok(token)
+    }
+
+    /// Saves the registry, the round number and a compacted queue log to storage and returns the
+    /// [`ResumeToken`] describing them.
+    #[inline]
+    fn flush(&self) -> Result<ResumeToken<C>, CeremonyError<C>>
+    where
+        C::Identifier: Clone + Serialize,
+        R::Registry: Serialize,
+    {
+        let sclp = self.sclp.lock();
+        let registry = self.registry.lock();
+        let mut lock_queue = self.lock_queue.lock();
+        let round = sclp.round();
+        save_registry::<R::Registry, C, _>(&registry, &self.storage, round);
+        serialize_into_storage(&self.storage, "round_number", &round).map_err(|e| {
+            CeremonyError::Unexpected(UnexpectedError::Serialization {
+                message: format!("{e:?}"),
+            })
+        })?;
+        let interrupted = if lock_queue.has_expired(&self.metadata) {
+            None
+        } else {
+            lock_queue.participant_lock().get().clone()
+        };
+        let queue = lock_queue.queue_mut();
+        queue.compact().map_err(|e| {
+            CeremonyError::Unexpected(UnexpectedError::Serialization {
+                message: format!("{e:?}"),
+            })
+        })?;
+        Ok(ResumeToken {
+            round,
+            registry: key_format("".to_string(), "registry".to_string(), round),
+            queue_length: queue.len(),
+            queue_log: queue.log_path().map(Path::to_path_buf),
+            interrupted,
+        })
+    }
+
     /// Returns the metadata for this ceremony.
     #[inline]
     pub fn metadata(&self) -> &Metadata {
         &self.metadata
     }
 
+    /// Returns a snapshot of the participant registry.
+    #[inline]
+    pub fn registry(&self) -> R::Registry
+    where
+        R::Registry: Clone,
+    {
+        self.registry.lock().clone()
+    }
+
     /// Processes a `start` request and returns the ceremony metadata.
     #[inline]
     pub async fn start(
@@ -259,13 +469,14 @@ where
     ) -> Result<(bool, bool, QueryResponse<C>, C::Participant), CeremonyError<C>>
     where
         C::Challenge: Clone,
+        C::Identifier: Serialize,
         C::Participant: Clone,
     {
         let mut registry = self.registry.lock();
         let priority = preprocess_request::<C, _, _>(&mut *registry, &request)?;
         let mut lock_queue = self.lock_queue.lock();
         let identifier = request.into_identifier();
-        let has_lock = lock_queue.has_lock(&identifier, &self.metadata, &mut *registry);
+        let has_lock = lock_queue.has_lock(&identifier, &self.metadata, &mut *registry)?;
         let participant = registry
             .get(&identifier)
             .expect("Getting participant from valid identifier is not supposed to fail.")
@@ -280,7 +491,12 @@ where
         }
         let (enqueued, position) = lock_queue
             .queue_mut()
-            .push_back_if_missing(priority.into(), identifier);
+            .push_back_if_missing(priority.into(), identifier)
+            .map_err(|e| {
+                CeremonyError::Unexpected(UnexpectedError::Serialization {
+                    message: format!("{e:?}"),
+                })
+            })?;
         Ok((
             enqueued,
             has_lock.0,
@@ -290,6 +506,10 @@ where
     }
 
     /// Queries the server state and logs any changes to the lock and the queue.
+    #[cfg_attr(
+        feature = "telemetry",
+        tracing::instrument(skip_all, fields(participant = tracing::field::Empty))
+    )]
     #[inline]
     pub async fn query_endpoint(
         self,
@@ -297,10 +517,14 @@ where
     ) -> Result<Result<QueryResponse<C>, CeremonyError<C>>, Error>
     where
         C::Challenge: Clone,
+        C::Identifier: Serialize,
         C::Participant: Clone + Display,
     {
         let response = match self.query(request).await {
             Ok((enqueued, lock_changed, response, participant)) => {
+                #[cfg(feature = "telemetry")]
+                tracing::Span::current()
+                    .record("participant", tracing::field::display(&participant));
                 if lock_changed {
                     let _ = info!("[ACTION] Lock updated.");
                 }
@@ -378,14 +602,18 @@ where
         request: SignedMessage<C, C::Identifier, ContributeRequest<C>>,
     ) -> Result<ContributeResponse<C>, CeremonyError<C>>
     where
-        C: 'static,
+        C: ProvingKeyHasher<C> + 'static,
         C::Challenge: Clone + Send + Serialize,
         C::ContributionHash: AsRef<[u8]>,
-        C::Identifier: Send,
+        C::Identifier: Send + Serialize,
         C::Nonce: Send,
         C::Participant: Clone + Display,
+        C::Signature: Send + Serialize,
+        C::Output: AsRef<[u8]>,
+        C::SigningKey: Send,
         R: 'static,
         R::Registry: Send + Serialize,
+        S: Clone + Send + 'static,
     {
         let _ = info!("[REQUEST] Preprocessing `update` request: checking signature and nonce.");
         let (identifier, message, participant, has_been_updated) = {
@@ -395,7 +623,7 @@ where
             let has_lock =
                 self.lock_queue
                     .lock()
-                    .has_lock(&identifier, &self.metadata, &mut *registry);
+                    .has_lock(&identifier, &self.metadata, &mut *registry)?;
             has_lock.1?;
             let participant = registry
                 .get(&identifier)
@@ -403,6 +631,8 @@ where
                 .clone();
             (identifier, message, participant, has_lock.0)
         };
+        #[cfg(feature = "telemetry")]
+        tracing::Span::current().record("participant", tracing::field::display(&participant));
         if has_been_updated {
             let _ = info!("[ACTION] Lock updated.");
         }
@@ -410,22 +640,58 @@ where
             "[REQUEST] processing `update` from participant: {}.",
             participant
         );
+        let state = BoxArray::try_from_vec(message.state).map_err(|_| CeremonyError::BadRequest)?;
+        let proof = BoxArray::try_from_vec(message.proof).map_err(|_| CeremonyError::BadRequest)?;
         let sclp = self.sclp.clone();
-        let recovery_directory = self.recovery_directory.clone();
-
-        let (round, challenge) = task::spawn_blocking(move || {
-            sclp.lock().update(
-                BoxArray::from_vec(message.state),
-                BoxArray::from_vec(message.proof),
-                recovery_directory,
-            )
-        })
-        .await
-        .map_err(|_| CeremonyError::Unexpected(UnexpectedError::TaskError))??;
+        let storage = self.storage.clone();
+        let signer = self.signer.clone();
+        let contributor = identifier.clone();
+        let (round, challenge, receipt) =
+            task::spawn_blocking(move || -> Result<_, CeremonyError<C>> {
+                let mut sclp = sclp.lock();
+                let (round, challenge) = sclp.update(state, proof, &storage)?;
+                save_state_hash(&storage, round, &state_hash(sclp.state().as_slice()));
+                let receipt = signer.and_then(|signer| {
+                    let response = ContributeResponse {
+                        index: round,
+                        challenge: challenge.to_vec(),
+                        receipt: None,
+                    };
+                    let timestamp = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map(|duration| duration.as_secs())
+                        .unwrap_or_default();
+                    signer
+                        .lock()
+                        .sign(Receipt::new(
+                            contributor,
+                            &response,
+                            sclp.state().as_slice(),
+                            timestamp,
+                        ))
+                        .map_err(|e| {
+                            report(
+                                Level::Warn,
+                                format_args!(
+                                    "[ERROR] Unable to sign the receipt of round {round}: {e:?}"
+                                ),
+                            )
+                        })
+                        .ok()
+                });
+                Ok((round, challenge, receipt))
+            })
+            .await
+            .map_err(|_| CeremonyError::Unexpected(UnexpectedError::TaskError))??;
+        #[cfg(feature = "telemetry")]
+        tracing::Span::current().record("round", round);
         let registry = self.registry.clone();
         let lock_queue = self.lock_queue.clone();
-        let recovery_directory = self.recovery_directory.clone();
-        task::spawn_blocking(move || -> Result<(), CeremonyError<C>> {
+        let storage = self.storage.clone();
+        let signer = self.signer.clone();
+        let feed = self.feed.clone();
+        let feed_challenge = challenge.clone();
+        let published = task::spawn_blocking(move || -> Result<_, CeremonyError<C>> {
             let mut registry = registry.lock();
             match registry.get_mut(&identifier) {
                 Some(participant) => participant.set_contributed(),
@@ -435,16 +701,45 @@ where
                     ))
                 }
             }
-            lock_queue.lock().update_expired_lock(&mut *registry);
-            save_registry::<R::Registry, C>(&registry, &recovery_directory, round);
-            Ok(())
+            lock_queue.lock().update_expired_lock(&mut *registry)?;
+            save_registry::<R::Registry, C, _>(&registry, &storage, round);
+            serialize_into_storage(
+                &storage,
+                &key_format("".to_string(), "contributor".to_string(), round),
+                &identifier,
+            )
+            .expect("Writing contributor to storage should succeed.");
+            drop(registry);
+            Ok(feed.zip(signer).map(|(feed, signer)| {
+                FeedEntry::new::<C>(round, &identifier, feed_challenge.as_slice())
+                    .map_err(|e| format!("{e:?}"))
+                    .and_then(|entry| {
+                        feed.lock()
+                            .publish(entry, &signer.lock())
+                            .map(|_| ())
+                            .map_err(|e| format!("{e:?}"))
+                    })
+            }))
         })
         .await
         .map_err(|_| CeremonyError::Unexpected(UnexpectedError::TaskError))??;
         let _ = info!("[ACTION] Lock updated.");
+        match published {
+            Some(Ok(())) => {
+                let _ = info!("[ACTION] Published round {} to the feed.", round);
+            }
+            Some(Err(e)) => {
+                let _ = warn!(
+                    "[ERROR] Unable to publish round {} to the feed: {}",
+                    round, e
+                );
+            }
+            _ => {}
+        }
         let contribute_response = ContributeResponse {
             index: round,
             challenge: challenge.to_vec(),
+            receipt,
         };
         let _ = info!(
             "[RESPONSE] responding to successful `update` number {} from participant \n\
@@ -459,20 +754,34 @@ where
 
     /// Processes a request to update the MPC state and removes the participant if the state was
     /// updated successfully. If the update succeeds, the current coordinator is saved to disk.
+    #[cfg_attr(
+        feature = "telemetry",
+        tracing::instrument(
+            skip_all,
+            fields(
+                participant = tracing::field::Empty,
+                round = tracing::field::Empty
+            )
+        )
+    )]
     #[inline]
     pub async fn update_endpoint(
         self,
         request: SignedMessage<C, C::Identifier, ContributeRequest<C>>,
     ) -> Result<Result<ContributeResponse<C>, CeremonyError<C>>, Error>
     where
-        C: 'static,
+        C: ProvingKeyHasher<C> + 'static,
         C::Challenge: Clone + Send + Serialize,
         C::ContributionHash: AsRef<[u8]>,
-        C::Identifier: Send,
+        C::Identifier: Send + Serialize,
         C::Nonce: Debug + Send,
         C::Participant: Clone + Display,
+        C::Signature: Send + Serialize,
+        C::Output: AsRef<[u8]>,
+        C::SigningKey: Send,
         R: 'static,
         R::Registry: Send + Serialize,
+        S: Clone + Send + 'static,
     {
         let response = self.update(request).await;
         match &response {
@@ -489,6 +798,96 @@ where
         };
         Ok(response)
     }
+
+    /// Returns the public transcript data of the past round in `request`.
+    #[inline]
+    pub async fn round(self, request: RoundRequest) -> Result<RoundResponse<C>, CeremonyError<C>>
+    where
+        C: ProvingKeyHasher<C> + 'static,
+        C::Challenge: DeserializeOwned + Send,
+        C::Identifier: DeserializeOwned + Send,
+        C::Nonce: Send,
+        C::Output: AsRef<[u8]>,
+        S: Clone + Send + 'static,
+    {
+        let latest = self.sclp.lock().round();
+        if request.round > latest {
+            return Err(CeremonyError::UnknownRound { latest });
+        }
+        let storage = self.storage.clone();
+        task::spawn_blocking(move || load_round(&storage, request.round))
+            .await
+            .map_err(|_| CeremonyError::Unexpected(UnexpectedError::TaskError))?
+    }
+
+    /// Returns the public transcript data of the past round in `request`.
+    #[cfg_attr(
+        feature = "telemetry",
+        tracing::instrument(skip_all, fields(round = request.round))
+    )]
+    #[inline]
+    pub async fn round_endpoint(
+        self,
+        request: RoundRequest,
+    ) -> Result<Result<RoundResponse<C>, CeremonyError<C>>, Error>
+    where
+        C: ProvingKeyHasher<C> + 'static,
+        C::Challenge: DeserializeOwned + Send,
+        C::Identifier: DeserializeOwned + Send,
+        C::Nonce: Debug + Send,
+        C::Output: AsRef<[u8]>,
+        S: Clone + Send + 'static,
+    {
+        let response = self.round(request).await;
+        match &response {
+            Ok(_) => {
+                let _ = info!(
+                    "[RESPONSE] Responding to query for round {}.",
+                    request.round
+                );
+            }
+            Err(CeremonyError::UnknownRound { .. }) => {}
+            Err(e) => {
+                let _ = warn!("[ERROR] Unable to load round {}: {:?}", request.round, e);
+            }
+        }
+        Ok(response)
+    }
+
+    /// Returns the signed feed entries for the round in `request` and all later rounds. The
+    /// response is empty if the server does not publish a feed.
+    #[inline]
+    pub async fn feed(
+        self,
+        request: FeedRequest,
+    ) -> Result<Vec<SignedFeedEntry<C>>, CeremonyError<C>>
+    where
+        C::Identifier: Clone,
+        C::Signature: Clone,
+    {
+        Ok(self
+            .feed
+            .as_ref()
+            .map(|feed| feed.lock().since(request.since).to_vec())
+            .unwrap_or_default())
+    }
+
+    /// Returns the signed feed entries for the round in `request` and all later rounds.
+    #[cfg_attr(
+        feature = "telemetry",
+        tracing::instrument(skip_all, fields(since = request.since))
+    )]
+    #[inline]
+    pub async fn feed_endpoint(
+        self,
+        request: FeedRequest,
+    ) -> Result<Result<Vec<SignedFeedEntry<C>>, CeremonyError<C>>, Error>
+    where
+        C::Identifier: Clone,
+        C::Signature: Clone,
+    {
+        Ok(self.feed(request).await)
+    }
 }
 
 /// Produces [`Metadata`] from a slice of [`State`]s and specified contribution time limit.
@@ -507,14 +906,175 @@ where
     }
 }
 
-/// Filename formatting for saving/recovering server. The `kind` may be
-/// `state`, `challenge`, `proof`, `registry`. For `registry` the `name`
-/// should be "".
+/// Returns the hash of every circuit of `state`.
+#[inline]
+pub fn state_hash<C>(state: &[State<C>]) -> Vec<Vec<u8>>
+where
+    C: Ceremony + ProvingKeyHasher<C>,
+    C::Output: AsRef<[u8]>,
+{
+    state
+        .iter()
+        .map(|state| C::hash(&state.0).as_ref().to_vec())
+        .collect()
+}
+
+/// Saves the `state_hash` of `round` into `storage`, so that [`load_round`] can serve it without
+/// deserializing and hashing the state again. Failures are only reported since [`load_round`]
+/// recomputes missing hashes.
+#[inline]
+fn save_state_hash<S>(storage: &S, round: u64, state_hash: &Vec<Vec<u8>>)
+where
+    S: Storage,
+{
+    if let Err(e) = serialize_into_storage(
+        storage,
+        &key_format("".to_string(), "state_hash".to_string(), round),
+        state_hash,
+    ) {
+        report(
+            Level::Warn,
+            format_args!("[ERROR] Unable to save the state hash of round {round}: {e:?}"),
+        );
+    }
+}
+
+/// Loads the public transcript data of `round` from `storage`.
+///
+/// The state hashes are read from the cache written by [`Server::update`]. They are only computed
+/// from the state for rounds saved without them, in which case they are cached for later requests.
+#[inline]
+pub fn load_round<C, S>(storage: &S, round: u64) -> Result<RoundResponse<C>, CeremonyError<C>>
+where
+    C: Ceremony + ProvingKeyHasher<C>,
+    C::Challenge: DeserializeOwned,
+    C::Identifier: DeserializeOwned,
+    C::Output: AsRef<[u8]>,
+    S: Storage,
+{
+    let serialization_error = |e| {
+        CeremonyError::Unexpected(UnexpectedError::Serialization {
+            message: format!("{e:?}"),
+        })
+    };
+    let names: Vec<String> =
+        deserialize_from_storage(storage, "circuit_names").map_err(serialization_error)?;
+    let cached_state_hash: Option<Vec<Vec<u8>>> = deserialize_from_storage(
+        storage,
+        &key_format("".to_string(), "state_hash".to_string(), round),
+    )
+    .ok()
+    .filter(|state_hash: &Vec<Vec<u8>>| state_hash.len() == names.len());
+    let mut state_hash = Vec::with_capacity(names.len());
+    let mut challenge = Vec::with_capacity(names.len());
+    let mut proof = Vec::with_capacity(names.len());
+    for name in names {
+        if cached_state_hash.is_none() {
+            let state: State<C> = deserialize_from_storage(
+                storage,
+                &key_format(name.clone(), "state".to_string(), round),
+            )
+            .map_err(serialization_error)?;
+            state_hash.push(C::hash(&state.0).as_ref().to_vec());
+        }
+        challenge.push(
+            deserialize_from_storage(
+                storage,
+                &key_format(name.clone(), "challenge".to_string(), round),
+            )
+            .map_err(serialization_error)?,
+        );
+        if round > 0 {
+            proof.push(
+                deserialize_from_storage(storage, &key_format(name, "proof".to_string(), round))
+                    .map_err(serialization_error)?,
+            );
+        }
+    }
+    let state_hash = match cached_state_hash {
+        Some(state_hash) => state_hash,
+        _ => {
+            save_state_hash(storage, round, &state_hash);
+            state_hash
+        }
+    };
+    let contributor = if round > 0 {
+        deserialize_from_storage(
+            storage,
+            &key_format("".to_string(), "contributor".to_string(), round),
+        )
+        .ok()
+    } else {
+        None
+    };
+    Ok(RoundResponse {
+        round,
+        contributor,
+        state_hash,
+        challenge,
+        proof,
+    })
+}
+
+/// Storage key formatting for saving/recovering server. The `kind` may be
+/// `state`, `challenge`, `proof`, `registry`, `contributor`, `state_hash`. For `registry`,
+/// `contributor` and `state_hash` the `name` should be "".
+#[inline]
+pub fn key_format(name: String, kind: String, round_number: u64) -> String {
+    format!("{name}_{kind}_{round_number}")
+}
+
+/// Filename formatting for saving/recovering server. See [`key_format`] for the
+/// format of the file name inside of `folder_path`.
 pub fn filename_format(
     folder_path: &Path,
     name: String,
     kind: String,
     round_number: u64,
 ) -> PathBuf {
-    folder_path.join(format!("{name}_{kind}_{round_number}"))
+    folder_path.join(key_format(name, kind, round_number))
+}
+
+/// Testing Suite
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::groth16::{
+        ceremony::config::ppot::{Challenge, Config},
+        test::dummy_prover_key,
+    };
+    use std::fs;
+
+    /// Checks that [`load_round`] serves the cached state hashes without reading the state, and
+    /// that it caches the state hashes of rounds which were saved without them.
+    #[test]
+    fn round_state_hash_is_cached() {
+        let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+        let storage = FileSystem::new(directory.path());
+        let name = "dummy".to_string();
+        serialize_into_storage(&storage, "circuit_names", &vec![name.clone()]).unwrap();
+        serialize_into_storage(
+            &storage,
+            &key_format(name.clone(), "challenge".to_string(), 0),
+            &Challenge::from_unchecked([0; 64]),
+        )
+        .unwrap();
+        let state = State::<Config>(dummy_prover_key());
+        serialize_into_storage(
+            &storage,
+            &key_format(name.clone(), "state".to_string(), 0),
+            &state,
+        )
+        .unwrap();
+        let expected = state_hash(&[state]);
+        assert_eq!(
+            load_round::<Config, _>(&storage, 0).unwrap().state_hash,
+            expected
+        );
+        fs::remove_file(storage.path(&key_format(name, "state".to_string(), 0))).unwrap();
+        assert_eq!(
+            load_round::<Config, _>(&storage, 0).unwrap().state_hash,
+            expected
+        );
+    }
 }
@@ -0,0 +1,518 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Coordinator Load Testing
+//!
+//! This module spins up an in-process ceremony [`Server`] and drives its `query` and `update`
+//! endpoints with many simulated participants running on their own threads. Participants compute
+//! real contributions to the state they receive, so the harness exercises the registry, queue,
+//! lock and verification logic of the server together.
+
+use crate::{
+    ceremony::{
+        participant::ActivationWindow,
+        signature::{SignedMessage, Signer},
+        storage::{serialize_into_storage, FileSystem},
+    },
+    groth16::{
+        ceremony::{
+            config::ppot::{generate_keys, Challenge, Config, Participant, Priority, Registry},
+            message::{ContributeRequest, QueryRequest, QueryResponse},
+            server::{compute_metadata, Server},
+            Ceremony, CeremonyError,
+        },
+        mpc::{self, State},
+    },
+    util::BlakeHasher,
+};
+use core::time::Duration;
+use manta_crypto::rand::{ChaCha20Rng, RngCore, SeedableRng};
+use manta_util::{Array, BoxArray};
+use std::{path::PathBuf, thread, time::Instant};
+use tokio::runtime::{Builder, Handle};
+
+/// Number of Priority Levels
+pub const LEVEL_COUNT: usize = 2;
+
+/// Number of Circuits
+pub const CIRCUIT_COUNT: usize = 1;
+
+/// Participant Identifier
+pub type Identifier = <Config as Ceremony>::Identifier;
+
+/// Signed Message Type
+type Message<T> = SignedMessage<Config, Identifier, T>;
+
+/// Ceremony Server under Test
+pub type LoadTestServer = Server<Config, Registry, LEVEL_COUNT, CIRCUIT_COUNT>;
+
+/// Load Test Configuration
+#[derive(Clone, Debug)]
+pub struct LoadTest {
+    /// Number of Honest Participants
+    pub participants: usize,
+
+    /// Number of High Priority Participants among the Honest Participants
+    pub high_priority: usize,
+
+    /// Number of High Priority Participants which Stall after Acquiring the Lock
+    pub stalling: usize,
+
+    /// Number of Malicious Messages sent to the Server
+    pub malicious_messages: usize,
+
+    /// Maximum Random Delay between two Requests of a Participant
+    ///
+    /// Participants waiting in the queue back off proportionally to their queue position, like
+    /// the ceremony client which only polls periodically.
+    pub max_delay: Duration,
+
+    /// Contribution Time Limit of the Server
+    ///
+    /// This limit should be generous compared to the time it takes to compute a contribution, so
+    /// that only the stalling participants time out.
+    pub contribution_time_limit: Duration,
+
+    /// Random Seed
+    pub seed: [u8; 32],
+}
+
+impl Default for LoadTest {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            participants: 16,
+            high_priority: 4,
+            stalling: 1,
+            malicious_messages: 64,
+            max_delay: Duration::from_millis(5),
+            contribution_time_limit: Duration::from_secs(5),
+            seed: [0; 32],
+        }
+    }
+}
+
+/// Load Test Report
+#[derive(Clone, Debug, Default)]
+pub struct LoadReport {
+    /// Number of Requests sent to the Server
+    pub requests: usize,
+
+    /// Number of Successful Contributions
+    pub contributions: usize,
+
+    /// Number of Malicious Messages which were Accepted
+    pub accepted_malicious: usize,
+
+    /// Number of Malicious Messages which were Rejected
+    pub rejected_malicious: usize,
+
+    /// Identifiers in the Order they Contributed
+    pub contribution_order: Vec<Identifier>,
+
+    /// Total Running Time
+    pub elapsed: Duration,
+}
+
+impl LoadReport {
+    /// Returns the number of requests the server processed per second.
+    #[inline]
+    pub fn throughput(&self) -> f64 {
+        self.requests as f64 / self.elapsed.as_secs_f64()
+    }
+}
+
+/// Simulated Participant Behavior
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Behavior {
+    /// Contributes as soon as it gets the lock
+    Honest,
+
+    /// Never contributes after it gets the lock
+    Stalling,
+}
+
+/// Participant Outcome
+///
+/// Returns the number of requests sent by the participant and the round of its contribution, if
+/// it contributed.
+type Outcome = (usize, Option<u64>);
+
+/// Builds a signer and its registry entry from `seed`. Both start at nonce `1` so that messages
+/// signed with nonce `0` can be used as replayed messages.
+#[inline]
+fn participant(seed: &[u8; 32], priority: Priority) -> (Signer<Config, Identifier>, Participant) {
+    let (signing_key, verifying_key) =
+        generate_keys(seed).expect("Seed has the correct length for key generation.");
+    let identifier = Array::from_unchecked(*verifying_key.as_bytes());
+    (
+        Signer::new(1, signing_key, identifier),
+        Participant::new(
+            identifier,
+            format!("participant-{}", bs58::encode(seed).into_string()),
+            priority,
+            1,
+            false,
+            ActivationWindow::default(),
+        ),
+    )
+}
+
+/// Sleeps for a random duration of at most `max_delay`.
+#[inline]
+fn random_delay<R>(rng: &mut R, max_delay: Duration)
+where
+    R: RngCore,
+{
+    let max = max_delay.as_micros() as u64;
+    if max > 0 {
+        thread::sleep(Duration::from_micros(rng.next_u64() % max));
+    }
+}
+
+/// Runs the query/update loop of a simulated participant against `server`.
+#[inline]
+fn run_participant(
+    runtime: Handle,
+    server: LoadTestServer,
+    mut signer: Signer<Config, Identifier>,
+    behavior: Behavior,
+    max_delay: Duration,
+    mut rng: ChaCha20Rng,
+) -> Outcome {
+    let hasher = BlakeHasher::default();
+    let mut requests = 0;
+    loop {
+        random_delay(&mut rng, max_delay);
+        let request = signer
+            .sign(QueryRequest)
+            .expect("Signing a query should succeed.");
+        signer.increment_nonce();
+        requests += 1;
+        match runtime.block_on(server.clone().query(request)) {
+            Ok((_, _, QueryResponse::State(mut round), _)) => {
+                if behavior == Behavior::Stalling {
+                    return (requests, None);
+                }
+                let proof = round
+                    .state
+                    .iter_mut()
+                    .zip(round.challenge.iter())
+                    .map(|(state, challenge)| {
+                        mpc::contribute(&hasher, challenge, state, &mut rng)
+                            .expect("Contributing to a valid state should succeed.")
+                    })
+                    .collect();
+                let request = signer
+                    .sign(ContributeRequest {
+                        state: round.state.into(),
+                        proof,
+                    })
+                    .expect("Signing a contribution should succeed.");
+                signer.increment_nonce();
+                requests += 1;
+                match runtime.block_on(server.clone().update(request)) {
+                    Ok(response) => return (requests, Some(response.index)),
+                    Err(CeremonyError::InvalidSignature { expected_nonce }) => {
+                        signer.set_valid_nonce(expected_nonce);
+                    }
+                    Err(CeremonyError::NotYourTurn | CeremonyError::Timeout) => {}
+                    Err(err) => panic!("Unexpected error for an honest contribution: {err:?}"),
+                }
+            }
+            Ok((_, _, QueryResponse::QueuePosition(position), _)) => {
+                random_delay(&mut rng, max_delay * position as u32);
+            }
+            Err(CeremonyError::InvalidSignature { expected_nonce }) => {
+                signer.set_valid_nonce(expected_nonce);
+            }
+            Err(CeremonyError::AlreadyContributed) => return (requests, None),
+            Err(err) => panic!("Unexpected error for an honest participant: {err:?}"),
+        }
+    }
+}
+
+/// Sends `count` malicious messages to `server`: forged signatures, replayed messages,
+/// unregistered identifiers and forged contributions. Returns the number of accepted and rejected
+/// messages.
+#[inline]
+fn run_attacker(
+    runtime: Handle,
+    server: LoadTestServer,
+    victims: Vec<(Identifier, Message<QueryRequest>)>,
+    count: usize,
+    max_delay: Duration,
+    mut rng: ChaCha20Rng,
+) -> (usize, usize) {
+    let mut seed = [0; 32];
+    rng.fill_bytes(&mut seed);
+    let (attacker, _) = participant(&seed, Priority::High);
+    let mut accepted = 0;
+    let mut rejected = 0;
+    for i in 0..count {
+        random_delay(&mut rng, max_delay);
+        let (victim, replayed) = &victims[(rng.next_u64() as usize) % victims.len()];
+        let result = match i % 4 {
+            0 => {
+                let forged = attacker
+                    .sign(QueryRequest)
+                    .expect("Signing a query should succeed.");
+                runtime
+                    .block_on(server.clone().query(SignedMessage::new_unchecked(
+                        *forged.signature(),
+                        *victim,
+                        QueryRequest,
+                    )))
+                    .map(|_| ())
+            }
+            1 => runtime
+                .block_on(server.clone().query(replayed.clone()))
+                .map(|_| ()),
+            2 => runtime
+                .block_on(
+                    server.clone().query(
+                        attacker
+                            .sign(QueryRequest)
+                            .expect("Signing a query should succeed."),
+                    ),
+                )
+                .map(|_| ()),
+            _ => {
+                let contribution = || ContributeRequest {
+                    state: Vec::new(),
+                    proof: Vec::new(),
+                };
+                let forged = attacker
+                    .sign(contribution())
+                    .expect("Signing a contribution should succeed.");
+                runtime
+                    .block_on(server.clone().update(SignedMessage::new_unchecked(
+                        *forged.signature(),
+                        *victim,
+                        contribution(),
+                    )))
+                    .map(|_| ())
+            }
+        };
+        match result {
+            Ok(_) => accepted += 1,
+            Err(_) => rejected += 1,
+        }
+    }
+    (accepted, rejected)
+}
+
+/// Runs the load test described by `config` against a server starting from the initial `state`
+/// of a single circuit and saving its artifacts to `storage`. Returns the report together with the
+/// final registry.
+#[inline]
+pub fn run(config: &LoadTest, state: State<Config>, storage: FileSystem) -> (LoadReport, Registry) {
+    assert!(
+        config.stalling <= config.high_priority && config.high_priority <= config.participants,
+        "Stalling participants are a subset of the high priority participants."
+    );
+    let mut rng = ChaCha20Rng::from_seed(config.seed);
+    let mut registry = Registry::new();
+    let mut signers = Vec::with_capacity(config.participants);
+    for i in 0..config.participants {
+        let mut seed = [0; 32];
+        rng.fill_bytes(&mut seed);
+        let priority = if i < config.high_priority {
+            Priority::High
+        } else {
+            Priority::Normal
+        };
+        let behavior = if i < config.stalling {
+            Behavior::Stalling
+        } else {
+            Behavior::Honest
+        };
+        let (signer, participant) = participant(&seed, priority);
+        registry.insert(*signer.identifier(), participant);
+        signers.push((signer, behavior));
+    }
+    let victims = signers
+        .iter()
+        .map(|(signer, _)| {
+            let replayed = SignedMessage::generate(
+                signer.signing_key(),
+                0,
+                *signer.identifier(),
+                QueryRequest,
+            )
+            .expect("Signing a query should succeed.");
+            (*signer.identifier(), replayed)
+        })
+        .collect::<Vec<_>>();
+    serialize_into_storage(&storage, "circuit_names", &vec!["dummy".to_string()])
+        .expect("Writing the circuit names to storage should succeed.");
+    let metadata = compute_metadata(
+        config.contribution_time_limit,
+        core::slice::from_ref(&state),
+    );
+    let server = LoadTestServer::new(
+        BoxArray::from_unchecked([state]),
+        BoxArray::from_unchecked([Challenge::from_unchecked([0; 64])]),
+        registry,
+        storage,
+        metadata,
+        PathBuf::new(),
+    );
+    let runtime = Builder::new_multi_thread()
+        .enable_time()
+        .build()
+        .expect("Building the server runtime should succeed.");
+    let start = Instant::now();
+    let attacker = {
+        let runtime = runtime.handle().clone();
+        let server = server.clone();
+        let count = config.malicious_messages;
+        let max_delay = config.max_delay;
+        let rng = ChaCha20Rng::seed_from_u64(rng.next_u64());
+        thread::spawn(move || run_attacker(runtime, server, victims, count, max_delay, rng))
+    };
+    let handles = signers
+        .into_iter()
+        .map(|(signer, behavior)| {
+            let runtime = runtime.handle().clone();
+            let server = server.clone();
+            let identifier = *signer.identifier();
+            let max_delay = config.max_delay;
+            let rng = ChaCha20Rng::seed_from_u64(rng.next_u64());
+            (
+                identifier,
+                thread::spawn(move || {
+                    run_participant(runtime, server, signer, behavior, max_delay, rng)
+                }),
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut report = LoadReport::default();
+    let mut contributions = Vec::new();
+    for (identifier, handle) in handles {
+        let (requests, round) = handle.join().expect("Participant thread should not panic.");
+        report.requests += requests;
+        if let Some(round) = round {
+            contributions.push((round, identifier));
+        }
+    }
+    let (accepted, rejected) = attacker.join().expect("Attacker thread should not panic.");
+    contributions.sort_unstable_by_key(|(round, _)| *round);
+    report.contributions = contributions.len();
+    report.contribution_order = contributions
+        .into_iter()
+        .map(|(_, identifier)| identifier)
+        .collect();
+    report.requests += config.malicious_messages;
+    report.accepted_malicious = accepted;
+    report.rejected_malicious = rejected;
+    report.elapsed = start.elapsed();
+    (report, server.registry())
+}
+
+/// Runs the default load test and checks that every honest participant contributed exactly once
+/// in consecutive rounds, that stalling participants were timed out and demoted, and that no
+/// malicious message was accepted.
+#[test]
+fn server_under_load() {
+    use crate::{
+        ceremony::participant::{Participant as _, Priority as _},
+        groth16::test::dummy_prover_key,
+    };
+    let config = LoadTest::default();
+    let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+    let (report, registry) = run(
+        &config,
+        State(dummy_prover_key()),
+        FileSystem::new(directory.path()),
+    );
+    let honest = config.participants - config.stalling;
+    assert_eq!(report.contributions, honest);
+    assert_eq!(report.contribution_order.len(), honest);
+    assert_eq!(report.accepted_malicious, 0);
+    assert_eq!(report.rejected_malicious, config.malicious_messages);
+    assert!(
+        registry
+            .values()
+            .filter(|participant| {
+                !participant.has_contributed() && participant.priority() == Priority::High
+            })
+            .count()
+            <= 1,
+        "Only the stalling participant holding the final lock can keep its priority."
+    );
+    assert_eq!(
+        registry
+            .values()
+            .filter(|participant| participant.has_contributed())
+            .count(),
+        honest
+    );
+}
+
+/// Checks that a draining lock is released by its participant but never handed to the next
+/// participant in the queue, so that the server can shut down without dropping anyone from the
+/// queue.
+#[test]
+fn draining_lock_is_not_reassigned() {
+    use crate::groth16::ceremony::{coordinator::LockQueue, Metadata};
+    let mut registry = Registry::new();
+    let (first, first_participant) = participant(&[1; 32], Priority::High);
+    let (second, second_participant) = participant(&[2; 32], Priority::High);
+    registry.insert(*first.identifier(), first_participant);
+    registry.insert(*second.identifier(), second_participant);
+    let metadata = Metadata {
+        ceremony_size: Default::default(),
+        contribution_time_limit: Duration::from_secs(60),
+    };
+    let mut lock_queue = LockQueue::<Config, LEVEL_COUNT>::default();
+    for identifier in [first.identifier(), second.identifier()] {
+        lock_queue
+            .queue_mut()
+            .push_back(0, *identifier)
+            .expect("Pushing to an in-memory queue cannot fail.");
+    }
+    lock_queue
+        .update_expired_lock(&mut registry)
+        .expect("Popping from an in-memory queue cannot fail.");
+    assert_eq!(
+        lock_queue.participant_lock().get(),
+        &Some(*first.identifier())
+    );
+    lock_queue.drain();
+    assert!(lock_queue.is_draining());
+    assert!(lock_queue
+        .has_lock(first.identifier(), &metadata, &mut registry)
+        .expect("Popping from an in-memory queue cannot fail.")
+        .1
+        .is_ok());
+    assert_eq!(
+        lock_queue
+            .update_expired_lock(&mut registry)
+            .expect("Popping from an in-memory queue cannot fail."),
+        Some(*first.identifier())
+    );
+    assert!(lock_queue.has_expired(&metadata));
+    assert!(matches!(
+        lock_queue
+            .has_lock(second.identifier(), &metadata, &mut registry)
+            .expect("Popping from an in-memory queue cannot fail.")
+            .1,
+        Err(CeremonyError::NotYourTurn)
+    ));
+    assert_eq!(lock_queue.participant_lock().get(), &None);
+    assert_eq!(lock_queue.queue_mut().front(), Some(second.identifier()));
+}
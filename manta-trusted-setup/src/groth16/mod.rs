@@ -16,6 +16,7 @@
 
 //! Groth16 Trusted Setup
 
+pub mod backend;
 pub mod kzg;
 pub mod mpc;
 
@@ -17,9 +17,12 @@
 //! Groth16 MPC
 
 use crate::{
-    groth16::kzg::{self, Accumulator},
+    groth16::{
+        backend::{CpuBackend, ScalarMulBackend},
+        kzg::{self, Accumulator},
+    },
     mpc,
-    util::{batch_into_projective, batch_mul_fixed_scalar, merge_pairs_affine},
+    util::{batch_into_projective, merge_pairs_affine},
 };
 use alloc::{vec, vec::Vec};
 use ark_groth16::{ProvingKey, VerifyingKey};
@@ -35,10 +38,13 @@ use manta_crypto::{
         pairing::{Pairing, PairingEngineExt},
         ratio::{HashToGroup, RatioProof},
         relations::r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError},
-        serialize::SerializationError,
+        serialize::{
+            ArkReader, ArkWriter, CanonicalDeserialize, CanonicalSerialize, SerializationError,
+        },
     },
     rand::{CryptoRng, RngCore},
 };
+use manta_util::codec::{self, DecodeError};
 
 #[cfg(feature = "serde")]
 use {
@@ -99,6 +105,43 @@ where
     }
 }
 
+impl<P> codec::Decode for State<P>
+where
+    P: Pairing + ?Sized,
+{
+    type Error = SerializationError;
+
+    #[inline]
+    fn decode<R>(reader: R) -> Result<Self, DecodeError<R::Error, Self::Error>>
+    where
+        R: codec::Read,
+    {
+        let mut reader = ArkReader::new(reader);
+        match CanonicalDeserialize::deserialize_unchecked(&mut reader) {
+            Ok(value) => reader
+                .finish()
+                .map(move |_| Self(value))
+                .map_err(DecodeError::Read),
+            Err(err) => Err(DecodeError::Decode(err)),
+        }
+    }
+}
+
+impl<P> codec::Encode for State<P>
+where
+    P: Pairing + ?Sized,
+{
+    #[inline]
+    fn encode<W>(&self, writer: W) -> Result<(), W::Error>
+    where
+        W: codec::Write,
+    {
+        let mut writer = ArkWriter::new(writer);
+        let _ = self.0.serialize_uncompressed(&mut writer);
+        writer.finish().map(move |_| ())
+    }
+}
+
 impl<P> From<ProvingKey<P::Pairing>> for State<P>
 where
     P: Pairing,
@@ -446,6 +489,19 @@ pub trait Configuration: Pairing + mpc::Types<Proof = Proof<Self>, State = State
     ) -> Self::Challenge;
 }
 
+/// Number of chunks the `l_query` and `h_query` multiplications are each split into by
+/// [`contribute_with_progress`].
+pub const PROGRESS_CHUNK_COUNT: usize = 16;
+
+/// Returns the amount of work, counted in group elements, that a contribution to `state` performs.
+#[inline]
+pub fn contribution_work<C>(state: &State<C>) -> usize
+where
+    C: Pairing,
+{
+    state.0.l_query.len() + state.0.h_query.len() + 2
+}
+
 /// Contributes to `state` with `hasher`, `challenge`, and `rng`, returning a [`proof`](Proof).
 #[inline]
 pub fn contribute<C, R>(
@@ -457,13 +513,61 @@ pub fn contribute<C, R>(
 where
     C: Configuration,
     R: CryptoRng + RngCore + ?Sized,
+{
+    contribute_with_progress(hasher, challenge, state, rng, |_| {})
+}
+
+/// Contributes to `state` with `hasher`, `challenge`, and `rng`, returning a [`proof`](Proof).
+/// While computing the contribution, `progress` is called with the amount of work completed so
+/// far, out of the total returned by [`contribution_work`].
+#[inline]
+pub fn contribute_with_progress<C, R, F>(
+    hasher: &C::Hasher,
+    challenge: &C::Challenge,
+    state: &mut State<C>,
+    rng: &mut R,
+    progress: F,
+) -> Option<Proof<C>>
+where
+    C: Configuration,
+    R: CryptoRng + RngCore + ?Sized,
+    F: FnMut(usize),
+{
+    contribute_with_backend(hasher, challenge, state, rng, &CpuBackend, progress)
+}
+
+/// Contributes to `state` with `hasher`, `challenge`, and `rng`, returning a [`proof`](Proof), or
+/// `None` if `backend` fails. The scalar multiplications of the `l_query` and `h_query` points
+/// are run by `backend`, and `progress` is called as in [`contribute_with_progress`].
+#[inline]
+pub fn contribute_with_backend<C, R, B, F>(
+    hasher: &C::Hasher,
+    challenge: &C::Challenge,
+    state: &mut State<C>,
+    rng: &mut R,
+    backend: &B,
+    mut progress: F,
+) -> Option<Proof<C>>
+where
+    C: Configuration,
+    R: CryptoRng + RngCore + ?Sized,
+    B: ScalarMulBackend<C::G1> + ?Sized,
+    F: FnMut(usize),
 {
     let delta = C::Scalar::rand(rng);
     let delta_inverse = delta.inverse()?;
-    batch_mul_fixed_scalar(&mut state.0.l_query, delta_inverse);
-    batch_mul_fixed_scalar(&mut state.0.h_query, delta_inverse);
+    let mut completed = 0;
+    for query in [&mut state.0.l_query, &mut state.0.h_query] {
+        let chunk_size = (query.len() / PROGRESS_CHUNK_COUNT).max(1);
+        for chunk in query.chunks_mut(chunk_size) {
+            backend.batch_mul_fixed_scalar(chunk, delta_inverse).ok()?;
+            completed += chunk.len();
+            progress(completed);
+        }
+    }
     state.0.delta_g1 = state.0.delta_g1.mul(delta).into_affine();
     state.0.vk.delta_g2 = state.0.vk.delta_g2.mul(delta).into_affine();
+    progress(completed + 2);
     RatioProof::prove(hasher, challenge, &delta, rng).map(Proof)
 }
 
@@ -570,45 +674,98 @@ where
 pub mod util {
     use super::*;
     use crate::{ceremony::util::deserialize_from_file, groth16::ceremony::UnexpectedError};
-    use manta_crypto::arkworks::{groth16::ProvingContext, serialize::HasSerialization};
-    use manta_util::codec::{Encode, IoWriter};
-    use std::{fs::OpenOptions, path::Path};
+    use blake2::{Blake2b512, Digest};
+    use manta_crypto::arkworks::{
+        groth16::{ProvingContext, VerifyingContext},
+        serialize::{HasDeserialization, HasSerialization},
+    };
+    use manta_util::codec::{Decode, Encode, IoReader, IoWriter};
+    use std::{
+        fs::{self, File, OpenOptions},
+        io::{BufReader, BufWriter, Write},
+        path::{Path, PathBuf},
+    };
+
+    /// Extracted Keys
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    pub struct ExtractedKeys {
+        /// Path of the Proving Key File
+        pub proving_key: PathBuf,
+
+        /// Path of the Verifying Key File
+        pub verifying_key: PathBuf,
+
+        /// BLAKE2b Hash of the Proving Key File
+        pub proving_key_hash: [u8; 64],
+
+        /// BLAKE2b Hash of the Verifying Key File
+        pub verifying_key_hash: [u8; 64],
+    }
+
+    /// Returns a [`UnexpectedError::Serialization`] error with the given `message`.
+    #[inline]
+    fn serialization_error(message: &str) -> UnexpectedError {
+        UnexpectedError::Serialization {
+            message: message.to_string(),
+        }
+    }
+
+    /// Encodes `value` into a new file at `path`, truncating any existing file, and returns the
+    /// BLAKE2b hash of the file.
+    #[inline]
+    fn write_key<T>(path: &Path, value: &T) -> Result<[u8; 64], UnexpectedError>
+    where
+        T: Encode,
+    {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(|_| serialization_error("Unable to create file at desired location."))?;
+        let mut writer = BufWriter::new(file);
+        value
+            .encode(IoWriter(&mut writer))
+            .map_err(|_| serialization_error("Unable to serialize key."))?;
+        writer
+            .flush()
+            .map_err(|_| serialization_error("Unable to write key."))?;
+        let bytes = fs::read(path).map_err(|_| serialization_error("Unable to read key back."))?;
+        Ok(Blake2b512::digest(bytes).into())
+    }
+
+    /// Decodes a value of type `T` from the file at `path`.
+    #[inline]
+    fn read_key<T>(path: &Path) -> Result<T, UnexpectedError>
+    where
+        T: Decode,
+    {
+        let file = File::open(path).map_err(|_| serialization_error("Unable to open key."))?;
+        T::decode(IoReader(BufReader::new(file)))
+            .map_err(|_| serialization_error("Unable to deserialize key."))
+    }
 
     /// Extracts prover key and verifier key from state located at `path` and writes
-    /// them to a file according to the provided `name`. The new files are located
+    /// them to the files `{name}_pk` and `{name}_vk`, and their BLAKE2b hashes in the format
+    /// used by `b2sum` to `{name}_key_hashes.txt`. The new files are located
     /// in the same directory as `path`.
     /// The state can be passed in as an argument instead of being read from the path.
-    pub fn extract_keys<C>(
+    /// Before declaring success, the keys are read back from their files and passed to `check`,
+    /// which should run a proof and verification round-trip for the circuit of the keys, and an
+    /// error is returned if `check` fails.
+    pub fn extract_keys<C, F>(
         path: &Path,
         name: String,
         state: Option<State<C>>,
-    ) -> Result<(), UnexpectedError>
+        check: F,
+    ) -> Result<ExtractedKeys, UnexpectedError>
     where
         C: Configuration,
+        C::G2Prepared: HasDeserialization,
         for<'s> C::G2Prepared: HasSerialization<'s>,
+        F: FnOnce(&ProvingContext<C::Pairing>, &VerifyingContext<C::Pairing>) -> bool,
     {
-        let pk_file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .open(
-                path.parent()
-                    .expect("Path has no parent")
-                    .join(format!("{name}_pk")),
-            )
-            .map_err(|_| UnexpectedError::Serialization {
-                message: "Unable to create file at desired location.".to_string(),
-            })?;
-        let vk_file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .open(
-                path.parent()
-                    .expect("Path has no parent")
-                    .join(format!("{name}_vk")),
-            )
-            .map_err(|_| UnexpectedError::Serialization {
-                message: "Unable to create file at desired location.".to_string(),
-            })?;
+        let directory = path.parent().expect("Path has no parent");
         let state: State<C> = match state {
             Some(state) => state,
             None => deserialize_from_file(path).map_err(|_| UnexpectedError::Serialization {
@@ -616,18 +773,33 @@ pub mod util {
             })?,
         };
         let proving_context = ProvingContext::new(state.0);
-        proving_context
-            .encode(IoWriter(pk_file))
-            .map_err(|_| UnexpectedError::Serialization {
-                message: "Unable to serialize prover key.".to_string(),
-            })?;
-        proving_context
+        let verifying_context = proving_context
             .get_verifying_context()
-            .expect("Should be able to extract verifying context.")
-            .encode(IoWriter(vk_file))
-            .map_err(|_| UnexpectedError::Serialization {
-                message: "Unable to serialize verifier key.".to_string(),
-            })?;
-        Ok(())
+            .expect("Should be able to extract verifying context.");
+        let proving_key = directory.join(format!("{name}_pk"));
+        let verifying_key = directory.join(format!("{name}_vk"));
+        let proving_key_hash = write_key(&proving_key, &proving_context)?;
+        let verifying_key_hash = write_key(&verifying_key, &verifying_context)?;
+        fs::write(
+            directory.join(format!("{name}_key_hashes.txt")),
+            format!(
+                "{}  {name}_pk\n{}  {name}_vk\n",
+                hex::encode(proving_key_hash),
+                hex::encode(verifying_key_hash)
+            ),
+        )
+        .map_err(|_| serialization_error("Unable to write key hashes."))?;
+        if !check(
+            &read_key::<ProvingContext<C::Pairing>>(&proving_key)?,
+            &read_key::<VerifyingContext<C::Pairing>>(&verifying_key)?,
+        ) {
+            return Err(UnexpectedError::KeyCheckFailed);
+        }
+        Ok(ExtractedKeys {
+            proving_key,
+            verifying_key,
+            proving_key_hash,
+            verifying_key_hash,
+        })
     }
 }
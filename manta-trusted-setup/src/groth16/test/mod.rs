@@ -18,8 +18,12 @@
 
 use crate::{
     groth16::{
+        backend::{BackendError, CpuBackend, ScalarMulBackend},
         kzg::{self, Accumulator, Configuration, Contribution, Size},
-        mpc::{self, contribute, initialize, verify_transform, verify_transform_all, Proof, State},
+        mpc::{
+            self, contribute, contribute_with_backend, contribute_with_progress, contribution_work,
+            initialize, verify_transform, verify_transform_all, Proof, State,
+        },
     },
     mpc::{ChallengeType, ContributionType, ProofType, StateType, Transcript},
     util::{BlakeHasher, HasDistribution, KZGBlakeHasher},
@@ -28,6 +32,7 @@ use alloc::vec::Vec;
 use ark_groth16::{Groth16, ProvingKey};
 use ark_snark::SNARK;
 use blake2::Digest;
+use core::{cell::Cell, convert::Infallible};
 use manta_crypto::{
     arkworks::{
         bn254::{Bn254, Fr, G1Affine, G2Affine},
@@ -45,7 +50,13 @@ use manta_crypto::{
     },
     rand::{CryptoRng, OsRng, RngCore, Sample},
 };
-use manta_util::into_array_unchecked;
+use manta_util::{
+    codec::{Decode, Encode},
+    into_array_unchecked,
+};
+
+#[cfg(feature = "bincode")]
+use manta_util::BorrowedBytes;
 
 /// Test MPC
 #[derive(Clone, Default)]
@@ -284,3 +295,153 @@ fn trusted_setup_phase_two_is_valid() {
     dummy_circuit(&mut cs);
     prove_and_verify_circuit(state.0, cs, &mut rng);
 }
+
+/// Tests that the progress reported while contributing is increasing, ends at the total amount of
+/// work, and that the contribution is still valid.
+#[test]
+fn contribution_progress_is_reported() {
+    let mut rng = OsRng;
+    let mut state = State::<Test>(dummy_prover_key());
+    let prev_state = state.clone();
+    let challenge = <Test as mpc::ProvingKeyHasher<Test>>::hash(&state.0);
+    let hasher = <Test as mpc::Configuration>::Hasher::default();
+    let total = contribution_work(&state);
+    let mut reported = Vec::new();
+    let proof = contribute_with_progress(&hasher, &challenge, &mut state, &mut rng, |completed| {
+        reported.push(completed)
+    })
+    .expect("Contribution should succeed.");
+    assert!(reported.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(reported.last(), Some(&total));
+    verify_transform(&challenge, &prev_state, state, proof).expect("Verify transform failed");
+}
+
+/// Scalar Multiplication Backend Counting the Multiplied Points
+#[derive(Default)]
+struct CountingBackend {
+    /// Number of Multiplied Points
+    points: Cell<usize>,
+
+    /// Fails every Multiplication if `true`
+    fail: bool,
+}
+
+impl ScalarMulBackend<G1Affine> for CountingBackend {
+    #[inline]
+    fn batch_mul_fixed_scalar(
+        &self,
+        points: &mut [G1Affine],
+        scalar: Fr,
+    ) -> Result<(), BackendError> {
+        if self.fail {
+            return Err(BackendError::LengthMismatch);
+        }
+        self.points.set(self.points.get() + points.len());
+        CpuBackend.batch_mul_fixed_scalar(points, scalar)
+    }
+}
+
+/// Tests that contributions run every query multiplication through the scalar multiplication
+/// backend, and fail when the backend does.
+#[test]
+fn contribution_uses_backend() {
+    let mut rng = OsRng;
+    let mut state = State::<Test>(dummy_prover_key());
+    let prev_state = state.clone();
+    let challenge = <Test as mpc::ProvingKeyHasher<Test>>::hash(&state.0);
+    let hasher = <Test as mpc::Configuration>::Hasher::default();
+    let backend = CountingBackend::default();
+    let proof =
+        contribute_with_backend(&hasher, &challenge, &mut state, &mut rng, &backend, |_| {})
+            .expect("Contribution should succeed.");
+    assert_eq!(backend.points.get(), contribution_work(&state) - 2);
+    verify_transform(&challenge, &prev_state, state, proof).expect("Verify transform failed");
+    let mut state = prev_state;
+    let backend = CountingBackend {
+        fail: true,
+        ..Default::default()
+    };
+    assert!(
+        contribute_with_backend(&hasher, &challenge, &mut state, &mut rng, &backend, |_| {})
+            .is_none()
+    );
+}
+
+/// Tests that a state streamed out in small chunks decodes back to the same proving key.
+#[test]
+fn state_chunked_codec_round_trip() {
+    let state = State::<Test>(dummy_prover_key());
+    let mut chunks = Vec::new();
+    state
+        .encode_chunks::<7, _, Infallible>(|chunk| {
+            chunks.push(chunk.to_vec());
+            Ok(())
+        })
+        .expect("Collecting chunks cannot fail.");
+    assert!(chunks.iter().all(|chunk| chunk.len() <= 7));
+    assert_eq!(chunks.concat(), state.to_vec());
+    let decoded = State::<Test>::decode_chunks(&chunks).expect("Unable to decode state.");
+    assert_eq!(
+        decoded.0, state.0,
+        "Decoded state must match the encoded state."
+    );
+}
+
+/// Tests that a state deserialized from a `bincode` buffer borrows its canonical encoding from the
+/// buffer and decodes to the same proving key as the owned fallback.
+#[cfg(feature = "bincode")]
+#[test]
+fn state_borrowed_deserialization() {
+    let state = State::<Test>(dummy_prover_key());
+    let buffer = bincode::serialize(&state).expect("Unable to serialize state.");
+    let bytes = bincode::deserialize::<BorrowedBytes>(&buffer).expect("Unable to read bytes.");
+    assert!(
+        bytes.is_borrowed(),
+        "Bytes must be borrowed from the buffer."
+    );
+    assert_eq!(bytes.as_ref(), state.to_vec());
+    let borrowed =
+        bincode::deserialize::<State<Test>>(&buffer).expect("Unable to deserialize state.");
+    let owned = bincode::deserialize_from::<_, State<Test>>(buffer.as_slice())
+        .expect("Unable to deserialize state.");
+    assert_eq!(borrowed.0, state.0);
+    assert_eq!(owned.0, state.0);
+}
+
+/// Tests that extracted keys are written with their hashes and are only accepted if they pass the
+/// proof and verification round-trip.
+#[cfg(all(feature = "bincode", feature = "std", feature = "tokio"))]
+#[test]
+fn extracted_keys_pass_round_trip() {
+    let directory = tempfile::tempdir().expect("Unable to create temporary directory.");
+    let path = directory.path().join("state");
+    let keys = mpc::util::extract_keys(
+        &path,
+        "dummy".into(),
+        Some(State::<Test>(dummy_prover_key())),
+        |proving_context, verifying_context| {
+            let mut cs = R1CS::for_proofs();
+            dummy_circuit(&mut cs);
+            let proof = Groth16::prove(proving_context.proving_key(), cs, &mut OsRng)
+                .expect("Unable to build proof.");
+            Groth16::verify_with_processed_vk(&verifying_context.0, &[field_new!(Fr, "6")], &proof)
+                .unwrap_or(false)
+        },
+    )
+    .expect("Extracting the keys should succeed.");
+    assert_eq!(
+        blake2::Blake2b512::digest(std::fs::read(&keys.proving_key).unwrap()).as_slice(),
+        keys.proving_key_hash
+    );
+    let hashes = std::fs::read_to_string(directory.path().join("dummy_key_hashes.txt")).unwrap();
+    assert!(hashes.contains(&hex::encode(keys.verifying_key_hash)));
+    assert!(matches!(
+        mpc::util::extract_keys(
+            &path,
+            "dummy".into(),
+            Some(State::<Test>(dummy_prover_key())),
+            |_, _| false
+        ),
+        Err(crate::groth16::ceremony::UnexpectedError::KeyCheckFailed)
+    ));
+}
@@ -386,6 +386,13 @@ impl<T, const N: usize> BoxArray<T, N> {
     pub fn from_vec(vec: Vec<T>) -> Self {
         Self::from_unchecked(vec.into_boxed_slice())
     }
+
+    /// Performs the [`TryInto`] conversion from `vec` into a boxed array, returning the boxed
+    /// slice back if `vec` does not have exactly `N` elements.
+    #[inline]
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Self, Box<[T]>> {
+        vec.into_boxed_slice().try_into().map(Self)
+    }
 }
 
 #[cfg(feature = "alloc")]
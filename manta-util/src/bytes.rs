@@ -17,7 +17,16 @@
 //! Utilities for Manipulating Bytes
 
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "serde-alloc")]
+use {
+    crate::serde::{
+        de::{self, SeqAccess, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    },
+    core::fmt,
+};
 
 /// Counts the number of bytes required to encode a number with the given number of `bits`.
 #[inline]
@@ -115,3 +124,130 @@ impl<const N: usize> IntoBytes<N> for [u8; N] {
         self
     }
 }
+
+/// Borrowed Bytes
+///
+/// This type holds a byte buffer which borrows from its source whenever the deserializer supports
+/// it, and owns the bytes otherwise. It serializes exactly like a `Vec<u8>`, so it can be used to
+/// deserialize any field whose serialized form is a byte vector, like the canonical encodings of
+/// large cryptographic objects, without copying the bytes out of the input buffer. Formats like
+/// `bincode` borrow when deserializing from a slice, while formats like `json`, which encode bytes
+/// as sequences, fall back to owning them.
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BorrowedBytes<'b>(
+    /// Byte Buffer
+    pub Cow<'b, [u8]>,
+);
+
+#[cfg(feature = "alloc")]
+impl<'b> BorrowedBytes<'b> {
+    /// Returns `true` if the underlying buffer is borrowed from the source of `self`.
+    #[inline]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.0, Cow::Borrowed(_))
+    }
+
+    /// Converts `self` into an owned byte vector, copying the bytes only if they are borrowed.
+    #[inline]
+    pub fn into_owned(self) -> Vec<u8> {
+        self.0.into_owned()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'b> AsRef<[u8]> for BorrowedBytes<'b> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'b> From<&'b [u8]> for BorrowedBytes<'b> {
+    #[inline]
+    fn from(bytes: &'b [u8]) -> Self {
+        Self(Cow::Borrowed(bytes))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Vec<u8>> for BorrowedBytes<'_> {
+    #[inline]
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(Cow::Owned(bytes))
+    }
+}
+
+#[cfg(feature = "serde-alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde-alloc")))]
+impl Serialize for BorrowedBytes<'_> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.as_ref().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde-alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde-alloc")))]
+impl<'de: 'b, 'b> Deserialize<'de> for BorrowedBytes<'b> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        /// Borrowed Bytes Visitor
+        struct BorrowedBytesVisitor;
+
+        impl<'de> Visitor<'de> for BorrowedBytesVisitor {
+            type Value = BorrowedBytes<'de>;
+
+            #[inline]
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a byte buffer")
+            }
+
+            #[inline]
+            fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(BorrowedBytes(Cow::Borrowed(v)))
+            }
+
+            #[inline]
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(BorrowedBytes(Cow::Owned(v.to_vec())))
+            }
+
+            #[inline]
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(BorrowedBytes(Cow::Owned(v)))
+            }
+
+            #[inline]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(1 << 20));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                Ok(BorrowedBytes(Cow::Owned(bytes)))
+            }
+        }
+
+        deserializer.deserialize_bytes(BorrowedBytesVisitor)
+    }
+}
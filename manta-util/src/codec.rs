@@ -319,6 +319,197 @@ where
     }
 }
 
+/// Iterator Reader
+///
+/// Reads bytes one at a time out of an iterator, which makes it possible to decode from byte
+/// sources that do not live in a contiguous buffer, even without an allocator.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IterReader<I>(
+    /// Byte Iterator
+    pub I,
+)
+where
+    I: Iterator<Item = u8>;
+
+impl<I> IterReader<I>
+where
+    I: Iterator<Item = u8>,
+{
+    /// Fills `output` with bytes from the underlying iterator, returning the number of bytes that
+    /// were written before the iterator ran out.
+    #[inline]
+    fn fill(&mut self, output: &mut [u8]) -> usize {
+        let mut written = 0;
+        for (target, byte) in output.iter_mut().zip(&mut self.0) {
+            *target = byte;
+            written += 1;
+        }
+        written
+    }
+}
+
+impl<I> Read for IterReader<I>
+where
+    I: Iterator<Item = u8>,
+{
+    type Error = Infallible;
+
+    #[inline]
+    fn read<T>(&mut self, output: &mut T) -> Result<usize, Self::Error>
+    where
+        T: AsMut<[u8]> + ?Sized,
+    {
+        Ok(self.fill(output.as_mut()))
+    }
+
+    #[inline]
+    fn read_exact<T>(&mut self, output: &mut T) -> Result<(), ReadExactError<Self::Error>>
+    where
+        T: AsMut<[u8]> + ?Sized,
+    {
+        let output = output.as_mut();
+        let written = self.fill(output);
+        if written < output.len() {
+            return Err(ReadExactError::UnexpectedEnd(output.len() - written));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, ReadExactError<Self::Error>> {
+        self.0.next().ok_or(ReadExactError::UnexpectedEnd(1))
+    }
+}
+
+impl<I> ReadExt for IterReader<I>
+where
+    I: Iterator<Item = u8>,
+{
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn read_all(&mut self, output: &mut Vec<u8>) -> Result<usize, Self::Error> {
+        let len = output.len();
+        output.extend(&mut self.0);
+        Ok(output.len() - len)
+    }
+}
+
+/// Chunked Reader
+///
+/// Reads bytes out of a sequence of chunks, only pulling the next chunk from the underlying
+/// iterator once the current one is exhausted. Large objects can then be decoded straight from a
+/// source that yields them in pieces, like a download or a memory-mapped file read in windows,
+/// without first collecting the pieces into one buffer.
+pub struct ChunkReader<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    /// Remaining Chunks
+    chunks: I,
+
+    /// Current Chunk
+    current: Option<I::Item>,
+
+    /// Position in the Current Chunk
+    position: usize,
+}
+
+impl<I> ChunkReader<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    /// Builds a new [`ChunkReader`] over `chunks`.
+    #[inline]
+    pub fn new<C>(chunks: C) -> Self
+    where
+        C: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            chunks: chunks.into_iter(),
+            current: None,
+            position: 0,
+        }
+    }
+
+    /// Fills `output` with bytes from the remaining chunks, returning the number of bytes that
+    /// were written before the chunks ran out.
+    #[inline]
+    fn fill(&mut self, output: &mut [u8]) -> usize {
+        let mut written = 0;
+        while written < output.len() {
+            if let Some(chunk) = &self.current {
+                let remaining = &chunk.as_ref()[self.position..];
+                if !remaining.is_empty() {
+                    let len = remaining.len().min(output.len() - written);
+                    output[written..written + len].copy_from_slice(&remaining[..len]);
+                    written += len;
+                    self.position += len;
+                    continue;
+                }
+            }
+            self.position = 0;
+            self.current = self.chunks.next();
+            if self.current.is_none() {
+                break;
+            }
+        }
+        written
+    }
+}
+
+impl<I> Read for ChunkReader<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Error = Infallible;
+
+    #[inline]
+    fn read<T>(&mut self, output: &mut T) -> Result<usize, Self::Error>
+    where
+        T: AsMut<[u8]> + ?Sized,
+    {
+        Ok(self.fill(output.as_mut()))
+    }
+
+    #[inline]
+    fn read_exact<T>(&mut self, output: &mut T) -> Result<(), ReadExactError<Self::Error>>
+    where
+        T: AsMut<[u8]> + ?Sized,
+    {
+        let output = output.as_mut();
+        let written = self.fill(output);
+        if written < output.len() {
+            return Err(ReadExactError::UnexpectedEnd(output.len() - written));
+        }
+        Ok(())
+    }
+}
+
+impl<I> ReadExt for ChunkReader<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn read_all(&mut self, output: &mut Vec<u8>) -> Result<usize, Self::Error> {
+        let len = output.len();
+        if let Some(chunk) = self.current.take() {
+            output.extend_from_slice(&chunk.as_ref()[self.position..]);
+        }
+        self.position = 0;
+        for chunk in &mut self.chunks {
+            output.extend_from_slice(chunk.as_ref());
+        }
+        Ok(output.len() - len)
+    }
+}
+
 /// Read-Exact Error
 ///
 /// This `enum` is the error state for the [`read_exact`](Read::read_exact) method of [`Read`].
@@ -477,6 +668,86 @@ where
     }
 }
 
+/// Chunked Writer
+///
+/// Collects written bytes into a fixed-size buffer of `N` bytes and hands every full buffer to
+/// `sink`, so that an encoding can be streamed out in bounded pieces without an allocator. Call
+/// [`finish`](Self::finish) once writing is done to flush the last partial chunk.
+#[derive(Clone, Copy, Debug)]
+pub struct ChunkWriter<F, const N: usize> {
+    /// Chunk Sink
+    sink: F,
+
+    /// Chunk Buffer
+    buffer: [u8; N],
+
+    /// Number of Bytes in the Buffer
+    len: usize,
+}
+
+impl<F, const N: usize> ChunkWriter<F, N> {
+    /// Builds a new [`ChunkWriter`] which sends chunks of `N` bytes to `sink`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `N` is zero.
+    #[inline]
+    pub fn new(sink: F) -> Self {
+        assert!(N > 0, "Chunks must be at least one byte long.");
+        Self {
+            sink,
+            buffer: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Sends the bytes that are currently buffered to the sink, even if they do not make up a
+    /// full chunk.
+    #[inline]
+    pub fn flush<E>(&mut self) -> Result<(), E>
+    where
+        F: FnMut(&[u8]) -> Result<(), E>,
+    {
+        if self.len > 0 {
+            (self.sink)(&self.buffer[..self.len])?;
+            self.len = 0;
+        }
+        Ok(())
+    }
+
+    /// Flushes the remaining buffered bytes and returns the sink.
+    #[inline]
+    pub fn finish<E>(mut self) -> Result<F, E>
+    where
+        F: FnMut(&[u8]) -> Result<(), E>,
+    {
+        self.flush()?;
+        Ok(self.sink)
+    }
+}
+
+impl<F, E, const N: usize> Write for ChunkWriter<F, N>
+where
+    F: FnMut(&[u8]) -> Result<(), E>,
+{
+    type Error = E;
+
+    #[inline]
+    fn write(&mut self, input: &mut &[u8]) -> Result<usize, Self::Error> {
+        let total = input.len();
+        while !input.is_empty() {
+            if self.len == N {
+                self.flush()?;
+            }
+            let len = (N - self.len).min(input.len());
+            self.buffer[self.len..self.len + len].copy_from_slice(&input[..len]);
+            self.len += len;
+            *input = &input[len..];
+        }
+        Ok(total)
+    }
+}
+
 /// Pipelined Reader/Writer
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Pipeline<T>(pub T);
@@ -578,6 +849,20 @@ pub trait Encode {
             .expect("Writing to a `Vec<u8>` cannot fail.");
         buffer
     }
+
+    /// Encodes `self` as a stream of chunks of at most `N` bytes, passing each chunk to `sink` as
+    /// soon as it is full so that the whole encoding is never buffered at once.
+    ///
+    /// See [`ChunkWriter`] for more.
+    #[inline]
+    fn encode_chunks<const N: usize, F, E>(&self, sink: F) -> Result<(), E>
+    where
+        F: FnMut(&[u8]) -> Result<(), E>,
+    {
+        let mut writer = ChunkWriter::<F, N>::new(sink);
+        self.encode(&mut writer)?;
+        writer.finish().map(move |_| ())
+    }
 }
 
 impl<T> Encode for PhantomData<T> {
@@ -777,6 +1062,20 @@ pub trait Decode: Sized {
         Self::decode(buffer)
             .map_err(move |err| err.decode().expect("Reading from `[u8]` cannot fail."))
     }
+
+    /// Decodes a value of type `Self` from a sequence of byte `chunks`, reading each chunk only
+    /// once the previous one has been consumed.
+    ///
+    /// See [`ChunkReader`] for more.
+    #[inline]
+    fn decode_chunks<I>(chunks: I) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        Self::decode(ChunkReader::new(chunks))
+            .map_err(move |err| err.decode().expect("Reading from chunks cannot fail."))
+    }
 }
 
 impl<T> Decode for PhantomData<T> {
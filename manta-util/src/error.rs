@@ -0,0 +1,272 @@
+// Copyright 2019-2022 Manta Network.
+// This file is part of manta-rs.
+//
+// manta-rs is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// manta-rs is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with manta-rs.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Error Utilities
+//!
+//! The [`Error`] type in this module is a chain of human-readable messages, where every link is
+//! the context in which the next one occurred, ending in the root cause. It is meant for the
+//! boundaries of the workspace, like binaries and wire protocols, where the typed errors of the
+//! individual crates are turned into actionable diagnostics. Since every link is just a message,
+//! the chain can be sent over the wire and rebuilt on the other side.
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::fmt::{self, Debug, Display};
+
+#[cfg(feature = "serde-alloc")]
+use crate::serde::{Deserialize, Serialize};
+
+/// Result Type with [`Error`] as the Default Error Type
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+/// Error with Context Chaining
+///
+/// See the [module-level documentation](self) for more.
+#[cfg_attr(
+    feature = "serde-alloc",
+    derive(Deserialize, Serialize),
+    serde(crate = "crate::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct Error {
+    /// Error Message
+    message: String,
+
+    /// Underlying Cause
+    source: Option<Box<Error>>,
+}
+
+impl Error {
+    /// Builds a new [`Error`] without an underlying cause from `message`.
+    #[inline]
+    pub fn new<M>(message: M) -> Self
+    where
+        M: Display,
+    {
+        Self {
+            message: message.to_string(),
+            source: None,
+        }
+    }
+
+    /// Builds a new [`Error`] from `err` and the chain of its underlying causes.
+    #[cfg(feature = "std")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn from_std<E>(err: &E) -> Self
+    where
+        E: std::error::Error + ?Sized,
+    {
+        Self {
+            message: err.to_string(),
+            source: err.source().map(|source| Box::new(Self::from_std(source))),
+        }
+    }
+
+    /// Wraps `self` in a new error with the given `message`, making `self` its underlying cause.
+    #[inline]
+    pub fn context<M>(self, message: M) -> Self
+    where
+        M: Display,
+    {
+        Self {
+            message: message.to_string(),
+            source: Some(Box::new(self)),
+        }
+    }
+
+    /// Returns the message of `self` without its underlying causes.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the underlying cause of `self` if there is one.
+    #[inline]
+    pub fn cause(&self) -> Option<&Self> {
+        self.source.as_deref()
+    }
+
+    /// Returns an iterator over `self` followed by its chain of underlying causes.
+    #[inline]
+    pub fn chain(&self) -> Chain<'_> {
+        Chain { next: Some(self) }
+    }
+
+    /// Returns the last error in the chain of underlying causes of `self`.
+    #[inline]
+    pub fn root_cause(&self) -> &Self {
+        self.chain()
+            .last()
+            .expect("The chain always contains `self`.")
+    }
+}
+
+impl Debug for Error {
+    /// Formats the whole chain of causes, which is how errors returned from `main` are reported.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(source) = self.cause() {
+            write!(f, "\n\nCaused by:")?;
+            for (i, cause) in source.chain().enumerate() {
+                write!(f, "\n    {i}: {}", cause.message)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Error {
+    /// Formats the message of `self`, or the whole chain separated by colons with the alternate
+    /// flag `{:#}`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if f.alternate() {
+            for cause in self.chain().skip(1) {
+                write!(f, ": {}", cause.message)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl From<&str> for Error {
+    #[inline]
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<String> for Error {
+    #[inline]
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            source: None,
+        }
+    }
+}
+
+impl From<fmt::Error> for Error {
+    #[inline]
+    fn from(err: fmt::Error) -> Self {
+        Self::new(err)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl From<std::io::Error> for Error {
+    #[inline]
+    fn from(err: std::io::Error) -> Self {
+        Self::from_std(&err)
+    }
+}
+
+/// Error Chain Iterator
+///
+/// This `struct` is created by the [`chain`](Error::chain) method on [`Error`]. See its
+/// documentation for more.
+#[derive(Clone, Debug)]
+pub struct Chain<'e> {
+    /// Next Error in the Chain
+    next: Option<&'e Error>,
+}
+
+impl<'e> Iterator for Chain<'e> {
+    type Item = &'e Error;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.next?;
+        self.next = next.cause();
+        Some(next)
+    }
+}
+
+/// Context Extension Trait
+///
+/// Attaches a context message to the failure case of a [`Result`](core::result::Result) or
+/// [`Option`], turning it into an [`Error`].
+pub trait Context<T> {
+    /// Converts the failure case of `self` into an [`Error`] with the given context `message`.
+    fn context<M>(self, message: M) -> Result<T>
+    where
+        M: Display;
+
+    /// Converts the failure case of `self` into an [`Error`] with the context message returned by
+    /// `f`, which is only called on failure.
+    fn with_context<M, F>(self, f: F) -> Result<T>
+    where
+        M: Display,
+        F: FnOnce() -> M;
+}
+
+impl<T, E> Context<T> for core::result::Result<T, E>
+where
+    E: Into<Error>,
+{
+    #[inline]
+    fn context<M>(self, message: M) -> Result<T>
+    where
+        M: Display,
+    {
+        self.map_err(move |err| err.into().context(message))
+    }
+
+    #[inline]
+    fn with_context<M, F>(self, f: F) -> Result<T>
+    where
+        M: Display,
+        F: FnOnce() -> M,
+    {
+        self.map_err(move |err| err.into().context(f()))
+    }
+}
+
+impl<T> Context<T> for Option<T> {
+    #[inline]
+    fn context<M>(self, message: M) -> Result<T>
+    where
+        M: Display,
+    {
+        self.ok_or_else(move || Error::new(message))
+    }
+
+    #[inline]
+    fn with_context<M, F>(self, f: F) -> Result<T>
+    where
+        M: Display,
+        F: FnOnce() -> M,
+    {
+        self.ok_or_else(move || Error::new(f()))
+    }
+}
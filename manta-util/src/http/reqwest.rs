@@ -25,6 +25,7 @@ pub use reqwest::*;
 /// Asynchronous HTTP Client
 ///
 /// This client is a wrapper around [`reqwest::Client`] with a known server URL.
+#[derive(Clone)]
 pub struct KnownUrlClient {
     /// Server URL
     pub server_url: Url,
@@ -45,6 +45,10 @@ pub mod time;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 pub mod collections;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub mod error;
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 pub mod vec;